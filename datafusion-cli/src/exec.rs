@@ -402,6 +402,8 @@ fn config_file_type_from_str(ext: &str) -> Option<ConfigFileType> {
     match ext.to_lowercase().as_str() {
         "csv" => Some(ConfigFileType::CSV),
         "json" => Some(ConfigFileType::JSON),
+        "avro" => Some(ConfigFileType::AVRO),
+        "arrow" => Some(ConfigFileType::ARROW),
         "parquet" => Some(ConfigFileType::PARQUET),
         _ => None,
     }
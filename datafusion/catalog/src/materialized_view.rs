@@ -0,0 +1,138 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Materialized view data source which stores the result of a LogicalPlan.
+
+use std::{any::Any, sync::Arc};
+
+use crate::Session;
+use crate::TableProvider;
+
+use arrow::datatypes::SchemaRef;
+use async_trait::async_trait;
+use datafusion_common::Statistics;
+use datafusion_common::error::Result;
+use datafusion_expr::{Expr, LogicalPlan, TableProviderFilterPushDown, TableType};
+use datafusion_physical_plan::ExecutionPlan;
+use parking_lot::RwLock;
+
+/// An implementation of `TableProvider` which stores the result of a logical
+/// plan.
+///
+/// Unlike a [`ViewTable`], the plan is not executed when the view is queried.
+/// Queries are answered by the table holding its stored result instead, which
+/// is only replaced when the view is refreshed with [`Self::set_data`].
+///
+/// [`ViewTable`]: crate::view::ViewTable
+#[derive(Debug)]
+pub struct MaterializedViewTable {
+    /// LogicalPlan of the view
+    logical_plan: LogicalPlan,
+    /// SQL used to create the view, if available
+    definition: Option<String>,
+    /// Location of the stored result, or `None` if it is stored in memory
+    location: Option<String>,
+    /// The stored result of the view
+    data: RwLock<Arc<dyn TableProvider>>,
+}
+
+impl MaterializedViewTable {
+    /// Create a new materialized view whose current result is stored in
+    /// `data`.
+    ///
+    /// Takes a `LogicalPlan`, the location of the stored result, if any, and
+    /// optionally the SQL text of the `CREATE` statement.
+    pub fn new(
+        logical_plan: LogicalPlan,
+        definition: Option<String>,
+        location: Option<String>,
+        data: Arc<dyn TableProvider>,
+    ) -> Self {
+        Self {
+            logical_plan,
+            definition,
+            location,
+            data: RwLock::new(data),
+        }
+    }
+
+    /// Get definition ref
+    pub fn definition(&self) -> Option<&String> {
+        self.definition.as_ref()
+    }
+
+    /// Get logical_plan ref
+    pub fn logical_plan(&self) -> &LogicalPlan {
+        &self.logical_plan
+    }
+
+    /// Get the location of the stored result, if it is not stored in memory
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// Get the table holding the stored result
+    pub fn data(&self) -> Arc<dyn TableProvider> {
+        Arc::clone(&self.data.read())
+    }
+
+    /// Replace the stored result, e.g. after recomputing it from the
+    /// logical plan
+    pub fn set_data(&self, data: Arc<dyn TableProvider>) {
+        *self.data.write() = data;
+    }
+}
+
+#[async_trait]
+impl TableProvider for MaterializedViewTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(self.logical_plan.schema().inner())
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::View
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.data().supports_filters_pushdown(filters)
+    }
+
+    fn statistics(&self) -> Option<Statistics> {
+        self.data().statistics()
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.data().scan(state, projection, filters, limit).await
+    }
+}
@@ -83,6 +83,23 @@ impl SchemaProvider for MemorySchemaProvider {
         Ok(self.tables.remove(name).map(|(_, table)| table))
     }
 
+    fn rename_table(
+        &self,
+        name: &str,
+        new_name: String,
+    ) -> datafusion_common::Result<()> {
+        if self.table_exist(new_name.as_str()) {
+            return exec_err!("The table {new_name} already exists");
+        }
+        match self.tables.remove(name) {
+            Some((_, table)) => {
+                self.tables.insert(new_name, table);
+                Ok(())
+            }
+            None => exec_err!("The table {name} doesn't exist"),
+        }
+    }
+
     fn table_exist(&self, name: &str) -> bool {
         self.tables.contains_key(name)
     }
@@ -18,26 +18,37 @@
 //! [`MemTable`] for querying `Vec<RecordBatch>` by DataFusion.
 
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::TableProvider;
 
 use arrow::array::{
-    Array, ArrayRef, BooleanArray, RecordBatch as ArrowRecordBatch, UInt64Array,
+    Array, ArrayRef, AsArray, BooleanArray, RecordBatch as ArrowRecordBatch, UInt64Array,
+    new_null_array,
 };
 use arrow::compute::kernels::zip::zip;
-use arrow::compute::{and, filter_record_batch};
-use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::compute::{and, cast, filter, filter_record_batch};
+use arrow::datatypes::{
+    DataType, Field, FieldRef, Schema, SchemaRef, UInt8Type, UInt64Type,
+};
 use arrow::record_batch::RecordBatch;
 use datafusion_common::error::Result;
-use datafusion_common::{Constraints, DFSchema, SchemaExt, not_impl_err, plan_err};
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::{
+    Column, Constraints, DFSchema, SchemaExt, exec_err, internal_err, not_impl_err,
+    plan_err,
+};
 use datafusion_common_runtime::JoinSet;
 use datafusion_datasource::memory::{MemSink, MemorySourceConfig};
-use datafusion_datasource::sink::DataSinkExec;
+use datafusion_datasource::sink::{DataSink, DataSinkExec};
 use datafusion_datasource::source::DataSourceExec;
-use datafusion_expr::dml::InsertOp;
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_expr::dml::{
+    InsertOp, MERGE_ACTION_COLUMN, MERGE_TARGET_ROW_COLUMN, MergeAction,
+};
 use datafusion_expr::{Expr, SortExpr, TableType};
 use datafusion_physical_expr::{
     LexOrdering, create_physical_expr, create_physical_sort_exprs,
@@ -73,6 +84,9 @@ pub struct MemTable {
     /// Optional pre-known sort order(s). Must be `SortExpr`s.
     /// inserting data into this table removes the order
     pub sort_order: Arc<Mutex<Vec<Vec<SortExpr>>>>,
+    /// Version of the data, incremented by every statement that modifies it.
+    /// Changes made directly to `batches` are not tracked
+    version: Arc<AtomicU64>,
 }
 
 impl MemTable {
@@ -106,6 +120,7 @@ impl MemTable {
             constraints: Constraints::default(),
             column_defaults: HashMap::new(),
             sort_order: Arc::new(Mutex::new(vec![])),
+            version: Arc::new(AtomicU64::new(0)),
         })
     }
 
@@ -298,6 +313,10 @@ impl TableProvider for MemTable {
             return not_impl_err!("{insert_op} not implemented for MemoryTable yet");
         }
         let sink = MemSink::try_new(self.batches.clone(), Arc::clone(&self.schema))?;
+        let sink = VersionedSink {
+            inner: sink,
+            version: Arc::clone(&self.version),
+        };
         Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), None)))
     }
 
@@ -305,6 +324,10 @@ impl TableProvider for MemTable {
         self.column_defaults.get(column)
     }
 
+    fn data_version(&self) -> Option<u64> {
+        Some(self.version.load(Ordering::Acquire))
+    }
+
     async fn delete_from(
         &self,
         state: &dyn Session,
@@ -365,6 +388,7 @@ impl TableProvider for MemTable {
 
             *partition = new_batches;
         }
+        self.version.fetch_add(1, Ordering::AcqRel);
 
         Ok(Arc::new(DmlResultExec::new(total_deleted)))
     }
@@ -499,9 +523,242 @@ impl TableProvider for MemTable {
 
             *partition = new_batches;
         }
+        self.version.fetch_add(1, Ordering::AcqRel);
 
         Ok(Arc::new(DmlResultExec::new(total_updated)))
     }
+
+    /// Returns an ExecutionPlan that applies the changes of a `MERGE INTO`
+    /// statement to this [`MemTable`].
+    ///
+    /// `input` must produce every row of the table, see
+    /// [`TableProvider::merge_into`]. The contents of the table are replaced
+    /// by the kept, updated and inserted rows once `input` is exhausted.
+    async fn merge_into(
+        &self,
+        _state: &dyn Session,
+        input: Arc<dyn ExecutionPlan>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // Merging rewrites the whole table, so any sort order may be messed up
+        *self.sort_order.lock() = vec![];
+
+        let input_schema = input.schema();
+        let [action_field, target_row_field, fields @ ..] = &input_schema.fields()[..]
+        else {
+            return plan_err!("MERGE input must have at least two columns");
+        };
+        if action_field.name() != MERGE_ACTION_COLUMN
+            || action_field.data_type() != &DataType::UInt8
+        {
+            return plan_err!(
+                "Expected first column of MERGE input to be {MERGE_ACTION_COLUMN} (UInt8), got {action_field}"
+            );
+        }
+        if target_row_field.name() != MERGE_TARGET_ROW_COLUMN
+            || target_row_field.data_type() != &DataType::UInt64
+        {
+            return plan_err!(
+                "Expected second column of MERGE input to be {MERGE_TARGET_ROW_COLUMN} (UInt64), got {target_row_field}"
+            );
+        }
+        self.schema()
+            .logically_equivalent_names_and_types(&Schema::new(fields.to_vec()))?;
+
+        let sink = MemMergeSink::try_new(self.batches.clone(), Arc::clone(&self.schema))?;
+        let sink = VersionedSink {
+            inner: sink,
+            version: Arc::clone(&self.version),
+        };
+        Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), None)))
+    }
+
+    /// Returns a new [`MemTable`] with `field` appended to its schema.
+    ///
+    /// The data of this table is copied, so plans which already scan this
+    /// table are not affected.
+    async fn add_column(
+        &self,
+        state: &dyn Session,
+        field: FieldRef,
+        default: Option<Expr>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        if self.schema.field_with_name(field.name()).is_ok() {
+            return plan_err!("Column '{}' already exists", field.name());
+        }
+
+        let mut fields = self.schema.fields().to_vec();
+        fields.push(Arc::clone(&field));
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+
+        // Default expressions don't reference any column
+        let default_expr = default
+            .as_ref()
+            .map(|expr| {
+                create_physical_expr(expr, &DFSchema::empty(), state.execution_props())
+            })
+            .transpose()?;
+        let partitions = self
+            .map_batches(|batch| {
+                let num_rows = batch.num_rows();
+                let array = match &default_expr {
+                    Some(expr) => {
+                        let array = expr.evaluate(batch)?.into_array(num_rows)?;
+                        if array.data_type() == field.data_type() {
+                            array
+                        } else {
+                            cast(&array, field.data_type())?
+                        }
+                    }
+                    None => new_null_array(field.data_type(), num_rows),
+                };
+                let mut columns = batch.columns().to_vec();
+                columns.push(array);
+                Ok(RecordBatch::try_new(Arc::clone(&schema), columns)?)
+            })
+            .await?;
+
+        let mut column_defaults = self.column_defaults.clone();
+        if let Some(default) = default {
+            column_defaults.insert(field.name().clone(), default);
+        }
+        let sort_order = self.sort_order.lock().clone();
+        let table = MemTable::try_new(schema, partitions)?
+            .with_constraints(self.constraints.clone())
+            .with_column_defaults(column_defaults)
+            .with_sort_order(sort_order);
+        Ok(Arc::new(table))
+    }
+
+    /// Returns a new [`MemTable`] without the column `name`.
+    ///
+    /// Constraints and sort orders referencing the column are removed.
+    async fn drop_column(
+        &self,
+        _state: &dyn Session,
+        name: &str,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let Ok(index) = self.schema.index_of(name) else {
+            return plan_err!("Column '{name}' does not exist");
+        };
+        let indices = (0..self.schema.fields().len())
+            .filter(|i| *i != index)
+            .collect::<Vec<_>>();
+        let schema = Arc::new(self.schema.project(&indices)?);
+        let partitions = self
+            .map_batches(|batch| Ok(batch.project(&indices)?))
+            .await?;
+
+        let constraints = self.constraints.project(&indices).unwrap_or_default();
+        let mut column_defaults = self.column_defaults.clone();
+        column_defaults.remove(name);
+        let sort_order = self
+            .sort_order
+            .lock()
+            .iter()
+            .filter(|sort_exprs| {
+                sort_exprs.iter().all(|sort_expr| {
+                    sort_expr.expr.column_refs().iter().all(|c| c.name != name)
+                })
+            })
+            .cloned()
+            .collect();
+        let table = MemTable::try_new(schema, partitions)?
+            .with_constraints(constraints)
+            .with_column_defaults(column_defaults)
+            .with_sort_order(sort_order);
+        Ok(Arc::new(table))
+    }
+
+    /// Returns a new [`MemTable`] with the column `name` renamed to `new_name`.
+    async fn rename_column(
+        &self,
+        _state: &dyn Session,
+        name: &str,
+        new_name: &str,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let Ok(index) = self.schema.index_of(name) else {
+            return plan_err!("Column '{name}' does not exist");
+        };
+        if self.schema.field_with_name(new_name).is_ok() {
+            return plan_err!("Column '{new_name}' already exists");
+        }
+
+        let mut fields = self.schema.fields().to_vec();
+        fields[index] = Arc::new(fields[index].as_ref().clone().with_name(new_name));
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+        let partitions = self
+            .map_batches(|batch| {
+                Ok(RecordBatch::try_new(
+                    Arc::clone(&schema),
+                    batch.columns().to_vec(),
+                )?)
+            })
+            .await?;
+
+        let mut column_defaults = self.column_defaults.clone();
+        if let Some(default) = column_defaults.remove(name) {
+            column_defaults.insert(new_name.to_string(), default);
+        }
+        let sort_order = self
+            .sort_order
+            .lock()
+            .iter()
+            .map(|sort_exprs| {
+                sort_exprs
+                    .iter()
+                    .map(|sort_expr| {
+                        let expr = sort_expr
+                            .expr
+                            .clone()
+                            .transform(|expr| match expr {
+                                Expr::Column(c) if c.name == name => {
+                                    Ok(Transformed::yes(Expr::Column(Column::from_name(
+                                        new_name,
+                                    ))))
+                                }
+                                _ => Ok(Transformed::no(expr)),
+                            })
+                            .data()?;
+                        Ok(sort_expr.with_expr(expr))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+        let table = MemTable::try_new(schema, partitions)?
+            .with_constraints(self.constraints.clone())
+            .with_column_defaults(column_defaults)
+            .with_sort_order(sort_order);
+        Ok(Arc::new(table))
+    }
+
+    async fn truncate(&self, _state: &dyn Session) -> Result<()> {
+        for partition_data in &self.batches {
+            partition_data.write().await.clear();
+        }
+        self.version.fetch_add(1, Ordering::AcqRel);
+        Ok(())
+    }
+}
+
+impl MemTable {
+    /// Applies `f` to every batch of this table, keeping the partitioning.
+    async fn map_batches(
+        &self,
+        f: impl Fn(&RecordBatch) -> Result<RecordBatch>,
+    ) -> Result<Vec<Vec<RecordBatch>>> {
+        let mut partitions = Vec::with_capacity(self.batches.len());
+        for partition_data in &self.batches {
+            let partition = partition_data.read().await;
+            partitions.push(partition.iter().map(&f).collect::<Result<_>>()?);
+        }
+        Ok(partitions)
+    }
 }
 
 /// Evaluate filter expressions against a batch and return a combined boolean mask.
@@ -544,6 +801,224 @@ fn evaluate_filters_to_mask(
     Ok(combined_mask)
 }
 
+/// Increments the version of a [`MemTable`] once `inner` wrote its input.
+struct VersionedSink<S> {
+    inner: S,
+    version: Arc<AtomicU64>,
+}
+
+impl<S: Debug> Debug for VersionedSink<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<S: DisplayAs> DisplayAs for VersionedSink<S> {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        self.inner.fmt_as(t, f)
+    }
+}
+
+#[async_trait]
+impl<S: DataSink> DataSink for VersionedSink<S> {
+    fn as_any(&self) -> &dyn Any {
+        self.inner.as_any()
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        self.inner.schema()
+    }
+
+    async fn write_all(
+        &self,
+        data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        let count = self.inner.write_all(data, context).await?;
+        self.version.fetch_add(1, Ordering::AcqRel);
+        Ok(count)
+    }
+}
+
+/// Replaces the contents of a [`MemTable`] with the result of a `MERGE INTO`
+/// statement.
+///
+/// The input rows are prefixed by their [`MergeAction`] and the number of
+/// their target row, rows which are deleted are dropped, all others are
+/// written to the table round robin. Unchanged target rows are written once,
+/// even if they are matched by several source rows. Fails if a target row is
+/// updated or deleted more than once. Returns the number of inserted, updated
+/// and deleted rows.
+struct MemMergeSink {
+    /// Partitions of the table, replaced once the input is exhausted
+    batches: Vec<PartitionData>,
+    /// Schema of the table
+    table_schema: SchemaRef,
+    /// Schema of the input, the table schema prefixed by the action and target
+    /// row columns
+    schema: SchemaRef,
+}
+
+impl Debug for MemMergeSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemMergeSink")
+            .field("num_partitions", &self.batches.len())
+            .finish()
+    }
+}
+
+impl DisplayAs for MemMergeSink {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let partition_count = self.batches.len();
+                write!(f, "MemoryTable merge (partitions={partition_count})")
+            }
+            DisplayFormatType::TreeRender => {
+                write!(f, "")
+            }
+        }
+    }
+}
+
+impl MemMergeSink {
+    fn try_new(batches: Vec<PartitionData>, table_schema: SchemaRef) -> Result<Self> {
+        if batches.is_empty() {
+            return plan_err!("Cannot merge into MemTable with zero partitions");
+        }
+
+        let fields = [
+            Arc::new(Field::new(MERGE_ACTION_COLUMN, DataType::UInt8, false)),
+            Arc::new(Field::new(MERGE_TARGET_ROW_COLUMN, DataType::UInt64, true)),
+        ]
+        .into_iter()
+        .chain(table_schema.fields().iter().cloned())
+        .collect::<Vec<_>>();
+        let schema = Arc::new(Schema::new(fields));
+
+        Ok(Self {
+            batches,
+            table_schema,
+            schema,
+        })
+    }
+}
+
+#[async_trait]
+impl DataSink for MemMergeSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    async fn write_all(
+        &self,
+        mut data: SendableRecordBatchStream,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        let num_partitions = self.batches.len();
+
+        let mut new_batches = vec![vec![]; num_partitions];
+        let mut i = 0;
+        let mut push_batch = |batch: RecordBatch| {
+            if batch.num_rows() > 0 {
+                new_batches[i].push(batch);
+                i = (i + 1) % num_partitions;
+            }
+        };
+        let mut rows_affected = 0;
+        // Target rows which are updated or deleted, and the unchanged rows
+        // with their target row, which are only known to be kept once the
+        // whole input was processed
+        let mut modified_rows = HashSet::new();
+        let mut unchanged = vec![];
+        while let Some(batch) = data.next().await.transpose()? {
+            let Some(actions) = batch.column(0).as_primitive_opt::<UInt8Type>() else {
+                return internal_err!("MERGE action column must be UInt8");
+            };
+            let Some(target_rows) = batch.column(1).as_primitive_opt::<UInt64Type>()
+            else {
+                return internal_err!("MERGE target row column must be UInt64");
+            };
+
+            let mut keep_mask = Vec::with_capacity(batch.num_rows());
+            let mut unchanged_mask = Vec::with_capacity(batch.num_rows());
+            for (value, target_row) in actions.iter().zip(target_rows.iter()) {
+                let action = value.and_then(MergeAction::from_u8);
+                let (keep, unchanged) = match action {
+                    Some(MergeAction::Keep) => (false, true),
+                    Some(MergeAction::Insert | MergeAction::Update) => (true, false),
+                    Some(MergeAction::Delete) => (false, false),
+                    None => return internal_err!("Invalid MERGE action {value:?}"),
+                };
+                if let (Some(MergeAction::Update | MergeAction::Delete), Some(row)) =
+                    (action, target_row)
+                    && !modified_rows.insert(row)
+                {
+                    return exec_err!(
+                        "Cardinality violation: MERGE updated or deleted a row of the target table more than once"
+                    );
+                }
+                if !unchanged {
+                    rows_affected += 1;
+                }
+                keep_mask.push(keep);
+                unchanged_mask.push(unchanged);
+            }
+
+            let table_batch = RecordBatch::try_new(
+                Arc::clone(&self.table_schema),
+                batch.columns()[2..].to_vec(),
+            )?;
+            push_batch(filter_record_batch(
+                &table_batch,
+                &BooleanArray::from(keep_mask),
+            )?);
+            let unchanged_mask = BooleanArray::from(unchanged_mask);
+            if unchanged_mask.true_count() > 0 {
+                let target_rows = filter(target_rows, &unchanged_mask)?;
+                let table_batch = filter_record_batch(&table_batch, &unchanged_mask)?;
+                unchanged.push((target_rows, table_batch));
+            }
+        }
+
+        // A target row matched by several source rows without an applicable
+        // clause is kept once, unless one of the matches modified it
+        let mut kept_rows = HashSet::new();
+        for (target_rows, batch) in unchanged {
+            let keep_mask = target_rows
+                .as_primitive::<UInt64Type>()
+                .iter()
+                .map(|row| {
+                    Some(row.is_some_and(|row| {
+                        !modified_rows.contains(&row) && kept_rows.insert(row)
+                    }))
+                })
+                .collect::<BooleanArray>();
+            push_batch(filter_record_batch(&batch, &keep_mask)?);
+        }
+
+        // Replace the contents of the table only after the whole input was
+        // processed, so that a failed merge leaves the table unchanged
+        for (target, batches) in self.batches.iter().zip(new_batches) {
+            *target.write().await = batches;
+        }
+
+        Ok(rows_affected)
+    }
+}
+
 /// Returns a single row with the count of affected rows.
 #[derive(Debug)]
 struct DmlResultExec {
@@ -622,8 +1097,8 @@ impl ExecutionPlan for DmlResultExec {
     fn execute(
         &self,
         _partition: usize,
-        _context: Arc<datafusion_execution::TaskContext>,
-    ) -> Result<datafusion_execution::SendableRecordBatchStream> {
+        _context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
         // Create a single batch with the count
         let count_array = UInt64Array::from(vec![self.rows_affected]);
         let batch = ArrowRecordBatch::try_new(
@@ -86,6 +86,16 @@ pub trait SchemaProvider: Debug + Sync + Send {
         exec_err!("schema provider does not support deregistering tables")
     }
 
+    /// If supported by the implementation, renames the `name` table of this
+    /// schema to `new_name`.
+    ///
+    /// Returns an error if no `name` table exists or if a table named
+    /// `new_name` was already registered.
+    #[expect(unused_variables)]
+    fn rename_table(&self, name: &str, new_name: String) -> Result<()> {
+        exec_err!("schema provider does not support renaming tables")
+    }
+
     /// Returns true if table exist in the schema provider, false otherwise.
     fn table_exist(&self, name: &str) -> bool;
 }
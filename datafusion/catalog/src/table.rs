@@ -21,7 +21,7 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use crate::session::Session;
-use arrow::datatypes::SchemaRef;
+use arrow::datatypes::{FieldRef, SchemaRef};
 use async_trait::async_trait;
 use datafusion_common::Result;
 use datafusion_common::{Constraints, Statistics, not_impl_err};
@@ -84,6 +84,17 @@ pub trait TableProvider: Debug + Sync + Send {
         None
     }
 
+    /// Get the version of the data of this table, if it is tracked.
+    ///
+    /// The version must change every time the data of the table is modified,
+    /// after the modification becomes visible to scans. The query result cache
+    /// only caches the results of queries whose tables all report a version, so
+    /// tables whose data can change outside of DataFusion (e.g. files in an
+    /// object store) should return `None`, which is the default.
+    fn data_version(&self) -> Option<u64> {
+        None
+    }
+
     /// Create an [`ExecutionPlan`] for scanning the table with optionally
     /// specified `projection`, `filter` and `limit`, described below.
     ///
@@ -353,6 +364,92 @@ pub trait TableProvider: Debug + Sync + Send {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         not_impl_err!("UPDATE not supported for {} table", self.table_type())
     }
+
+    /// Apply the changes of a `MERGE INTO` statement to this table.
+    ///
+    /// `input` produces every row of this table joined with the source of the
+    /// statement, as well as the source rows to insert. Its first column,
+    /// [`MERGE_ACTION_COLUMN`], holds the [`MergeAction`] to apply to the row
+    /// (as `UInt8`), the second, [`MERGE_TARGET_ROW_COLUMN`], identifies the
+    /// target row (as `UInt64`), and the remaining columns match the schema of
+    /// this table. For [`MergeAction::Update`] these are the new values of the
+    /// row.
+    ///
+    /// A target row matched by several source rows appears once per match.
+    /// Implementations must return an error if it is updated or deleted more
+    /// than once, and keep it only once if it is not modified.
+    ///
+    /// Returns an [`ExecutionPlan`] producing a single row with `count` (UInt64)
+    /// holding the number of inserted, updated and deleted rows.
+    ///
+    /// [`MERGE_ACTION_COLUMN`]: datafusion_expr::dml::MERGE_ACTION_COLUMN
+    /// [`MERGE_TARGET_ROW_COLUMN`]: datafusion_expr::dml::MERGE_TARGET_ROW_COLUMN
+    /// [`MergeAction`]: datafusion_expr::dml::MergeAction
+    /// [`MergeAction::Update`]: datafusion_expr::dml::MergeAction::Update
+    async fn merge_into(
+        &self,
+        _state: &dyn Session,
+        _input: Arc<dyn ExecutionPlan>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("MERGE not supported for {} table", self.table_type())
+    }
+
+    /// Add a column to this table (`ALTER TABLE ... ADD COLUMN`).
+    ///
+    /// Existing rows are filled with `default`, or NULL when it is `None`.
+    /// `default` doesn't reference any column and is also used for the column
+    /// in later inserts.
+    ///
+    /// Returns the altered table, which replaces this table in its
+    /// [`SchemaProvider`].
+    ///
+    /// [`SchemaProvider`]: crate::SchemaProvider
+    async fn add_column(
+        &self,
+        _state: &dyn Session,
+        _field: FieldRef,
+        _default: Option<Expr>,
+    ) -> Result<Arc<dyn TableProvider>> {
+        not_impl_err!("ADD COLUMN not supported for {} table", self.table_type())
+    }
+
+    /// Remove the column `name` from this table (`ALTER TABLE ... DROP COLUMN`).
+    ///
+    /// Returns the altered table, which replaces this table in its
+    /// [`SchemaProvider`].
+    ///
+    /// [`SchemaProvider`]: crate::SchemaProvider
+    async fn drop_column(
+        &self,
+        _state: &dyn Session,
+        _name: &str,
+    ) -> Result<Arc<dyn TableProvider>> {
+        not_impl_err!("DROP COLUMN not supported for {} table", self.table_type())
+    }
+
+    /// Rename the column `name` of this table to `new_name`
+    /// (`ALTER TABLE ... RENAME COLUMN`).
+    ///
+    /// Returns the altered table, which replaces this table in its
+    /// [`SchemaProvider`].
+    ///
+    /// [`SchemaProvider`]: crate::SchemaProvider
+    async fn rename_column(
+        &self,
+        _state: &dyn Session,
+        _name: &str,
+        _new_name: &str,
+    ) -> Result<Arc<dyn TableProvider>> {
+        not_impl_err!(
+            "RENAME COLUMN not supported for {} table",
+            self.table_type()
+        )
+    }
+
+    /// Remove all rows from this table (`TRUNCATE TABLE`).
+    async fn truncate(&self, _state: &dyn Session) -> Result<()> {
+        not_impl_err!("TRUNCATE not supported for {} table", self.table_type())
+    }
 }
 
 /// Arguments for scanning a table with [`TableProvider::scan_with_args`].
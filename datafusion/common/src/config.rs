@@ -555,6 +555,18 @@ config_namespace! {
         /// Default: 128 MB
         pub max_spill_file_size_bytes: usize, default = 128 * 1024 * 1024
 
+        /// Number of hash partitions the inputs of a hash join are split into
+        /// when its build side does not fit in memory.
+        ///
+        /// When the memory pool refuses to grow the build side of a `HashJoinExec`,
+        /// both the build and the probe side are partitioned by the hash of the join
+        /// keys and written to disk, and matching partitions are then joined one at a
+        /// time. Partitions that are still too large are partitioned again.
+        ///
+        /// Spilling requires a `DiskManager` with temporary files enabled. Set to 0 or 1
+        /// to disable hash join spilling.
+        pub hash_join_spill_partitions: usize, default = 16
+
         /// Number of files to read in parallel when inferring schema and statistics
         pub meta_fetch_concurrency: usize, default = 32
 
@@ -590,6 +602,11 @@ config_namespace! {
         /// Should DataFusion support recursive CTEs
         pub enable_recursive_ctes: bool, default = true
 
+        /// Maximum number of iterations of a recursive CTE, after which the
+        /// query fails. Guards against recursions that never end, such as a
+        /// `UNION ALL` walk of a cyclic graph. If NULL, there is no limit
+        pub recursive_cte_max_iterations: Option<usize>, default = None
+
         /// Attempt to eliminate sorts by packing & sorting files with non-overlapping
         /// statistics into the same file groups.
         /// Currently experimental
@@ -721,9 +738,28 @@ config_namespace! {
         /// nanosecond resolution.
         pub coerce_int96: Option<String>, transform = str::to_lowercase, default = None
 
+        /// (reading) If true, the columns and struct fields of the table schema
+        /// are matched to those of the parquet files by their `PARQUET:field_id`
+        /// metadata rather than by name, so that columns renamed or reordered by
+        /// writers that preserve field IDs are read correctly. Fields without a
+        /// field ID, and files written without field IDs, are matched by name.
+        /// The fields of structs nested in lists or maps are matched by name.
+        /// The table schema must carry the field IDs, for example by providing
+        /// it explicitly or setting `skip_metadata` to false
+        pub match_by_field_id: bool, default = false
+
         /// (reading) Use any available bloom filters when reading parquet files
         pub bloom_filter_on_read: bool, default = true
 
+        /// (reading) If true, the parquet reader fetches the dictionary pages
+        /// of the row groups left after statistics and bloom filter pruning,
+        /// for the columns compared to literals with `=` or `IN`, and skips
+        /// row groups whose column is entirely dictionary encoded and has none
+        /// of the literals in its dictionary. This costs one read per row group
+        /// and column, and helps for low cardinality columns whose min/max
+        /// statistics span most of their values
+        pub dictionary_pruning_on_read: bool, default = false
+
         /// (reading) The maximum predicate cache size, in bytes. When
         /// `pushdown_filters` is enabled, sets the maximum memory used to cache
         /// the results of predicate evaluation between filter evaluation and
@@ -1001,6 +1037,12 @@ config_namespace! {
         /// ```
         pub repartition_sorts: bool, default = true
 
+        /// When set to true, sorts executed in a per-partition fashion by `repartition_sorts` range
+        /// partition their input by the sort key first, with split points sampled from the input.
+        /// The sorted partitions are then concatenated rather than merged. The input of a sort is
+        /// buffered, and spilled if needed, while its split points are sampled
+        pub range_repartition_sorts: bool, default = false
+
         /// Partition count threshold for subset satisfaction optimization.
         ///
         /// When the current partition count is >= this threshold, DataFusion will
@@ -1049,6 +1091,17 @@ config_namespace! {
         /// process to reorder the join keys
         pub top_down_join_key_reordering: bool, default = true
 
+        /// When set to true, the physical plan optimizer will reorder chains of inner
+        /// equi-joins to keep intermediate results small, using the row counts and
+        /// distinct counts of the join inputs. All join orders are enumerated for up to
+        /// `join_reordering_dp_threshold` inputs, larger chains are joined greedily.
+        /// Joins are kept in the order of the query when statistics are not available
+        pub enable_join_reordering: bool, default = false
+
+        /// The maximum number of join inputs for which join reordering enumerates
+        /// all join orders. Chains of joins with more inputs are ordered greedily
+        pub join_reordering_dp_threshold: usize, default = 10
+
         /// When set to true, the physical plan optimizer will prefer HashJoin over SortMergeJoin.
         /// HashJoin can work more efficiently than SortMergeJoin but consumes more memory
         pub prefer_hash_join: bool, default = true
@@ -1066,6 +1119,12 @@ config_namespace! {
         /// will be collected into a single partition
         pub hash_join_single_partition_threshold_rows: usize, default = 1024 * 128
 
+        /// When set to true, hash joins with an input without size statistics choose their build side
+        /// and partition mode when executed, from the actual size of the inputs. Up to
+        /// `hash_join_single_partition_threshold` bytes of each input are buffered in memory before the
+        /// join starts
+        pub adaptive_join_selection: bool, default = false
+
         /// Maximum size in bytes for the build side of a hash join to be pushed down as an InList expression for dynamic filtering.
         /// Build sides larger than this will use hash table lookups instead.
         /// Set to 0 to always use hash table lookups.
@@ -1974,6 +2033,8 @@ pub enum ConfigFileType {
     #[cfg(feature = "parquet")]
     PARQUET,
     JSON,
+    AVRO,
+    ARROW,
 }
 
 /// Represents the configuration options available for handling different table formats within a data processing application.
@@ -1992,6 +2053,12 @@ pub struct TableOptions {
     /// Configuration options for JSON file handling.
     pub json: JsonOptions,
 
+    /// Configuration options for Avro file handling.
+    pub avro: AvroOptions,
+
+    /// Configuration options for Arrow IPC file handling.
+    pub arrow: ArrowOptions,
+
     /// The current file format that the table operations should assume. This option allows
     /// for dynamic switching between the supported file types (e.g., CSV, Parquet, JSON).
     pub current_format: Option<ConfigFileType>,
@@ -2015,11 +2082,15 @@ impl ConfigField for TableOptions {
                 ConfigFileType::PARQUET => self.parquet.visit(v, "format", ""),
                 ConfigFileType::CSV => self.csv.visit(v, "format", ""),
                 ConfigFileType::JSON => self.json.visit(v, "format", ""),
+                ConfigFileType::AVRO => self.avro.visit(v, "format", ""),
+                ConfigFileType::ARROW => self.arrow.visit(v, "format", ""),
             }
         } else {
             self.csv.visit(v, "csv", "");
             self.parquet.visit(v, "parquet", "");
             self.json.visit(v, "json", "");
+            self.avro.visit(v, "avro", "");
+            self.arrow.visit(v, "arrow", "");
         }
     }
 
@@ -2051,6 +2122,8 @@ impl ConfigField for TableOptions {
                     ConfigFileType::PARQUET => self.parquet.set(rem, value),
                     ConfigFileType::CSV => self.csv.set(rem, value),
                     ConfigFileType::JSON => self.json.set(rem, value),
+                    ConfigFileType::AVRO => self.avro.set(rem, value),
+                    ConfigFileType::ARROW => self.arrow.set(rem, value),
                 }
             }
             _ => _config_err!("Config value \"{key}\" not found on TableOptions"),
@@ -3033,6 +3106,51 @@ impl CsvOptions {
     }
 }
 
+/// The layout of the records in a JSON file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonFormatType {
+    /// One JSON object per line (NDJSON)
+    #[default]
+    NdJson,
+    /// A single top-level JSON array containing one object per record
+    Array,
+}
+
+impl FromStr for JsonFormatType {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ndjson" | "" => Ok(Self::NdJson),
+            "array" => Ok(Self::Array),
+            other => Err(DataFusionError::Configuration(format!(
+                "Invalid JSON format: {other}. Expected one of: ndjson, array"
+            ))),
+        }
+    }
+}
+
+impl ConfigField for JsonFormatType {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = JsonFormatType::from_str(value)?;
+        Ok(())
+    }
+}
+
+impl Display for JsonFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = match self {
+            Self::NdJson => "ndjson",
+            Self::Array => "array",
+        };
+        write!(f, "{str}")
+    }
+}
+
 config_namespace! {
     /// Options controlling JSON format
     pub struct JsonOptions {
@@ -3046,6 +3164,135 @@ config_namespace! {
         /// If not specified, the default level for the compression algorithm is used.
         pub compression_level: Option<u32>, default = None
         pub schema_infer_max_rec: Option<usize>, default = None
+        /// The layout of the records: `ndjson` for one JSON object per line, or
+        /// `array` for a single top-level array of objects (`[{...}, {...}]`).
+        /// Files using the `array` layout can not be split into byte ranges
+        /// for parallel reads.
+        pub format: JsonFormatType, default = JsonFormatType::NdJson
+    }
+}
+
+/// The codec used to compress the blocks of an Avro file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AvroCodec {
+    /// No compression
+    #[default]
+    Null,
+    Deflate,
+    Snappy,
+    Zstandard,
+    Bzip2,
+    Xz,
+}
+
+impl FromStr for AvroCodec {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "null" | "uncompressed" | "" => Ok(Self::Null),
+            "deflate" => Ok(Self::Deflate),
+            "snappy" => Ok(Self::Snappy),
+            "zstandard" | "zstd" => Ok(Self::Zstandard),
+            "bzip2" => Ok(Self::Bzip2),
+            "xz" => Ok(Self::Xz),
+            other => Err(DataFusionError::Configuration(format!(
+                "Invalid Avro codec: {other}. Expected one of: null, deflate, snappy, zstandard, bzip2, xz"
+            ))),
+        }
+    }
+}
+
+impl ConfigField for AvroCodec {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = AvroCodec::from_str(value)?;
+        Ok(())
+    }
+}
+
+impl Display for AvroCodec {
+    /// Writes the name of the codec as stored in the `avro.codec` metadata of
+    /// Avro files
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = match self {
+            Self::Null => "null",
+            Self::Deflate => "deflate",
+            Self::Snappy => "snappy",
+            Self::Zstandard => "zstandard",
+            Self::Bzip2 => "bzip2",
+            Self::Xz => "xz",
+        };
+        write!(f, "{str}")
+    }
+}
+
+config_namespace! {
+    /// Options controlling Avro format
+    pub struct AvroOptions {
+        /// The codec used to compress the blocks of written files: `null`,
+        /// `deflate`, `snappy`, `zstandard` (or `zstd`), `bzip2` or `xz`
+        pub codec: AvroCodec, default = AvroCodec::Null
+    }
+}
+
+/// The variant of the Arrow IPC format used by Arrow files
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArrowIpcFormat {
+    /// The IPC file format: the stream of messages framed by the `ARROW1`
+    /// magic, followed by a footer indexing the record batches
+    #[default]
+    File,
+    /// The IPC streaming format: the schema message followed by the record
+    /// batches, without a footer
+    Stream,
+}
+
+impl FromStr for ArrowIpcFormat {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "file" | "" => Ok(Self::File),
+            "stream" => Ok(Self::Stream),
+            other => Err(DataFusionError::Configuration(format!(
+                "Invalid Arrow IPC format: {other}. Expected one of: file, stream"
+            ))),
+        }
+    }
+}
+
+impl ConfigField for ArrowIpcFormat {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = ArrowIpcFormat::from_str(value)?;
+        Ok(())
+    }
+}
+
+impl Display for ArrowIpcFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = match self {
+            Self::File => "file",
+            Self::Stream => "stream",
+        };
+        write!(f, "{str}")
+    }
+}
+
+config_namespace! {
+    /// Options controlling Arrow IPC format
+    pub struct ArrowOptions {
+        /// The IPC format of written files: `file` for the IPC file format
+        /// (`.arrow`), or `stream` for the IPC streaming format (`.arrows`)
+        /// without a footer
+        pub format: ArrowIpcFormat, default = ArrowIpcFormat::File
     }
 }
 
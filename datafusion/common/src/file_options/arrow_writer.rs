@@ -17,17 +17,36 @@
 
 //! Options related to how Arrow files should be written
 
-#[derive(Clone, Debug)]
-pub struct ArrowWriterOptions {}
+use crate::{
+    config::{ArrowIpcFormat, ArrowOptions},
+    error::{DataFusionError, Result},
+};
+
+/// Options for writing Arrow IPC files
+#[derive(Clone, Debug, Default)]
+pub struct ArrowWriterOptions {
+    /// Whether files are written in the IPC file or streaming format
+    pub format: ArrowIpcFormat,
+}
 
 impl ArrowWriterOptions {
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Sets the IPC format of the written files
+    pub fn with_format(mut self, format: ArrowIpcFormat) -> Self {
+        self.format = format;
+        self
     }
 }
 
-impl Default for ArrowWriterOptions {
-    fn default() -> Self {
-        Self::new()
+impl TryFrom<&ArrowOptions> for ArrowWriterOptions {
+    type Error = DataFusionError;
+
+    fn try_from(value: &ArrowOptions) -> Result<Self> {
+        Ok(ArrowWriterOptions {
+            format: value.format,
+        })
     }
 }
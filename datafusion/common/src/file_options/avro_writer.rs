@@ -17,5 +17,28 @@
 
 //! Options related to how avro files should be written
 
-#[derive(Clone, Debug)]
-pub struct AvroWriterOptions {}
+use crate::{
+    config::{AvroCodec, AvroOptions},
+    error::{DataFusionError, Result},
+};
+
+/// Options for writing Avro files
+#[derive(Clone, Debug, Default)]
+pub struct AvroWriterOptions {
+    /// The codec used to compress the blocks of the written files
+    pub codec: AvroCodec,
+}
+
+impl AvroWriterOptions {
+    pub fn new(codec: AvroCodec) -> Self {
+        Self { codec }
+    }
+}
+
+impl TryFrom<&AvroOptions> for AvroWriterOptions {
+    type Error = DataFusionError;
+
+    fn try_from(value: &AvroOptions) -> Result<Self> {
+        Ok(AvroWriterOptions { codec: value.codec })
+    }
+}
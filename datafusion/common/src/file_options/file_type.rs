@@ -22,12 +22,16 @@ use std::fmt::Display;
 
 /// The default file extension of arrow files
 pub const DEFAULT_ARROW_EXTENSION: &str = ".arrow";
+/// The default file extension of arrow files in the IPC streaming format
+pub const DEFAULT_ARROW_STREAM_EXTENSION: &str = ".arrows";
 /// The default file extension of avro files
 pub const DEFAULT_AVRO_EXTENSION: &str = ".avro";
 /// The default file extension of csv files
 pub const DEFAULT_CSV_EXTENSION: &str = ".csv";
 /// The default file extension of json files
 pub const DEFAULT_JSON_EXTENSION: &str = ".json";
+/// The default file extension of orc files
+pub const DEFAULT_ORC_EXTENSION: &str = ".orc";
 /// The default file extension of parquet files
 pub const DEFAULT_PARQUET_EXTENSION: &str = ".parquet";
 
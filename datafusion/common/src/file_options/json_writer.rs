@@ -18,7 +18,7 @@
 //! Options related to how json files should be written
 
 use crate::{
-    config::JsonOptions,
+    config::{JsonFormatType, JsonOptions},
     error::{DataFusionError, Result},
     parsers::CompressionTypeVariant,
 };
@@ -28,6 +28,8 @@ use crate::{
 pub struct JsonWriterOptions {
     pub compression: CompressionTypeVariant,
     pub compression_level: Option<u32>,
+    /// The layout of the written records
+    pub format: JsonFormatType,
 }
 
 impl JsonWriterOptions {
@@ -35,6 +37,7 @@ impl JsonWriterOptions {
         Self {
             compression,
             compression_level: None,
+            format: JsonFormatType::default(),
         }
     }
 
@@ -46,8 +49,15 @@ impl JsonWriterOptions {
         Self {
             compression,
             compression_level: Some(compression_level),
+            format: JsonFormatType::default(),
         }
     }
+
+    /// Set the layout of the written records
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl TryFrom<&JsonOptions> for JsonWriterOptions {
@@ -57,6 +67,7 @@ impl TryFrom<&JsonOptions> for JsonWriterOptions {
         Ok(JsonWriterOptions {
             compression: value.compression,
             compression_level: value.compression_level,
+            format: value.format,
         })
     }
 }
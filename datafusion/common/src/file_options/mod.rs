@@ -32,8 +32,11 @@ mod tests {
 
     use crate::{
         Result,
-        config::{ConfigFileType, TableOptions},
-        file_options::{csv_writer::CsvWriterOptions, json_writer::JsonWriterOptions},
+        config::{ArrowIpcFormat, AvroCodec, ConfigFileType, TableOptions},
+        file_options::{
+            arrow_writer::ArrowWriterOptions, avro_writer::AvroWriterOptions,
+            csv_writer::CsvWriterOptions, json_writer::JsonWriterOptions,
+        },
         parsers::CompressionTypeVariant,
     };
 
@@ -317,4 +320,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_writeroptions_avro_from_statement_options() -> Result<()> {
+        let mut option_map: HashMap<String, String> = HashMap::new();
+        option_map.insert("format.codec".to_owned(), "zstd".to_owned());
+
+        let mut table_config = TableOptions::new();
+        table_config.set_config_format(ConfigFileType::AVRO);
+        table_config.alter_with_string_hash_map(&option_map)?;
+
+        let avro_options = AvroWriterOptions::try_from(&table_config.avro)?;
+        assert_eq!(avro_options.codec, AvroCodec::Zstandard);
+
+        option_map.insert("format.codec".to_owned(), "lz4".to_owned());
+        assert!(
+            table_config
+                .alter_with_string_hash_map(&option_map)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_writeroptions_arrow_from_statement_options() -> Result<()> {
+        let mut option_map: HashMap<String, String> = HashMap::new();
+        option_map.insert("format.format".to_owned(), "stream".to_owned());
+
+        let mut table_config = TableOptions::new();
+        table_config.set_config_format(ConfigFileType::ARROW);
+        table_config.alter_with_string_hash_map(&option_map)?;
+
+        let arrow_options = ArrowWriterOptions::try_from(&table_config.arrow)?;
+        assert_eq!(arrow_options.format, ArrowIpcFormat::Stream);
+
+        option_map.insert("format.format".to_owned(), "feather".to_owned());
+        assert!(
+            table_config
+                .alter_with_string_hash_map(&option_map)
+                .is_err()
+        );
+
+        Ok(())
+    }
 }
@@ -30,7 +30,9 @@ mod column;
 mod dfschema;
 mod functional_dependencies;
 mod join_type;
+mod match_recognize;
 mod param_value;
+mod sample;
 mod schema_reference;
 mod table_reference;
 mod unnest;
@@ -77,8 +79,9 @@ pub use error::{
     unqualified_field_not_found,
 };
 pub use file_options::file_type::{
-    DEFAULT_ARROW_EXTENSION, DEFAULT_AVRO_EXTENSION, DEFAULT_CSV_EXTENSION,
-    DEFAULT_JSON_EXTENSION, DEFAULT_PARQUET_EXTENSION, GetExt,
+    DEFAULT_ARROW_EXTENSION, DEFAULT_ARROW_STREAM_EXTENSION, DEFAULT_AVRO_EXTENSION,
+    DEFAULT_CSV_EXTENSION, DEFAULT_JSON_EXTENSION, DEFAULT_ORC_EXTENSION,
+    DEFAULT_PARQUET_EXTENSION, GetExt,
 };
 pub use functional_dependencies::{
     Constraint, Constraints, Dependency, FunctionalDependence, FunctionalDependencies,
@@ -87,9 +90,13 @@ pub use functional_dependencies::{
 };
 use hashbrown::DefaultHashBuilder;
 pub use join_type::{JoinConstraint, JoinSide, JoinType};
+pub use match_recognize::{
+    AfterMatchSkip, EmptyMatchesMode, MatchFunction, RowPattern, RowsPerMatch,
+};
 pub use nested_struct::cast_column;
 pub use null_equality::NullEquality;
 pub use param_value::ParamValues;
+pub use sample::SampleMethod;
 pub use scalar::{ScalarType, ScalarValue};
 pub use schema_reference::SchemaReference;
 pub use spans::{Location, Span, Spans};
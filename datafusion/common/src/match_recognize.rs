@@ -0,0 +1,263 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the options of `MATCH_RECOGNIZE` row pattern matching
+
+use std::fmt::{self, Display, Formatter};
+
+/// A row pattern of `MATCH_RECOGNIZE`: a regular expression whose symbols
+/// are pattern variables, each of which matches a row that satisfies the
+/// variable's `DEFINE` condition.
+///
+/// Quantifiers are greedy, and alternatives are tried from left to right:
+/// among all the matches starting at a row, the preferred one is the one a
+/// backtracking matcher would find first.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum RowPattern {
+    /// A pattern variable, such as `A`
+    Symbol(String),
+    /// `^`, matches the start of the partition
+    Start,
+    /// `$`, matches the end of the partition
+    End,
+    /// `p1 p2 ... pn`
+    Concat(Vec<RowPattern>),
+    /// `p1 | p2 | ... | pn`
+    Alternation(Vec<RowPattern>),
+    /// `p{min,max}`, where `p*`, `p+` and `p?` are `p{0,}`, `p{1,}` and
+    /// `p{0,1}`. There is no upper bound if `max` is `None`
+    Repetition {
+        pattern: Box<RowPattern>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl RowPattern {
+    /// Returns the pattern variables of this pattern, in order of first
+    /// appearance
+    pub fn symbols(&self) -> Vec<&str> {
+        let mut symbols = vec![];
+        self.collect_symbols(&mut symbols);
+        symbols
+    }
+
+    fn collect_symbols<'a>(&'a self, symbols: &mut Vec<&'a str>) {
+        match self {
+            Self::Symbol(symbol) => {
+                if !symbols.contains(&symbol.as_str()) {
+                    symbols.push(symbol);
+                }
+            }
+            Self::Start | Self::End => {}
+            Self::Concat(patterns) | Self::Alternation(patterns) => {
+                patterns.iter().for_each(|p| p.collect_symbols(symbols))
+            }
+            Self::Repetition { pattern, .. } => pattern.collect_symbols(symbols),
+        }
+    }
+
+    fn fmt_nested(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Concat(_) | Self::Alternation(_) | Self::Repetition { .. } => {
+                write!(f, "({self})")
+            }
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+impl Display for RowPattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Symbol(symbol) => write!(f, "{symbol}"),
+            Self::Start => write!(f, "^"),
+            Self::End => write!(f, "$"),
+            Self::Concat(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    match pattern {
+                        Self::Alternation(_) => pattern.fmt_nested(f)?,
+                        _ => write!(f, "{pattern}")?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Alternation(patterns) => {
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    write!(f, "{pattern}")?;
+                }
+                Ok(())
+            }
+            Self::Repetition { pattern, min, max } => {
+                pattern.fmt_nested(f)?;
+                match (min, max) {
+                    (0, None) => write!(f, "*"),
+                    (1, None) => write!(f, "+"),
+                    (0, Some(1)) => write!(f, "?"),
+                    (min, None) => write!(f, "{{{min},}}"),
+                    (min, Some(max)) if min == max => write!(f, "{{{min}}}"),
+                    (min, Some(max)) => write!(f, "{{{min},{max}}}"),
+                }
+            }
+        }
+    }
+}
+
+/// Which rows `MATCH_RECOGNIZE` returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum RowsPerMatch {
+    /// `ONE ROW PER MATCH`, the partition columns and the measures of
+    /// every match
+    OneRow,
+    /// `ALL ROWS PER MATCH`, the input columns and the running measures of
+    /// every row of every match
+    AllRows(EmptyMatchesMode),
+}
+
+impl Display for RowsPerMatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::OneRow => write!(f, "ONE ROW PER MATCH"),
+            Self::AllRows(mode) => write!(f, "ALL ROWS PER MATCH {mode}"),
+        }
+    }
+}
+
+/// Which rows `ALL ROWS PER MATCH` returns besides the rows of non-empty
+/// matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum EmptyMatchesMode {
+    /// `SHOW EMPTY MATCHES`, a row for every empty match, made of the row at
+    /// which the match starts and the measures of a match without rows
+    Show,
+    /// `OMIT EMPTY MATCHES`, no row for empty matches
+    Omit,
+    /// `WITH UNMATCHED ROWS`, a row for every empty match, and a row with
+    /// null measures for every row that is not part of any match
+    WithUnmatched,
+}
+
+impl Display for EmptyMatchesMode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Show => write!(f, "SHOW EMPTY MATCHES"),
+            Self::Omit => write!(f, "OMIT EMPTY MATCHES"),
+            Self::WithUnmatched => write!(f, "WITH UNMATCHED ROWS"),
+        }
+    }
+}
+
+/// Where `MATCH_RECOGNIZE` resumes looking for matches after a match
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum AfterMatchSkip {
+    /// `PAST LAST ROW`, at the row after the last row of the match
+    PastLastRow,
+    /// `TO NEXT ROW`, at the row after the first row of the match
+    ToNextRow,
+    /// `TO FIRST symbol`, at the first row of the match mapped to the
+    /// pattern variable
+    ToFirst(String),
+    /// `TO LAST symbol`, at the last row of the match mapped to the pattern
+    /// variable
+    ToLast(String),
+}
+
+impl Display for AfterMatchSkip {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::PastLastRow => write!(f, "AFTER MATCH SKIP PAST LAST ROW"),
+            Self::ToNextRow => write!(f, "AFTER MATCH SKIP TO NEXT ROW"),
+            Self::ToFirst(symbol) => write!(f, "AFTER MATCH SKIP TO FIRST {symbol}"),
+            Self::ToLast(symbol) => write!(f, "AFTER MATCH SKIP TO LAST {symbol}"),
+        }
+    }
+}
+
+/// How a measure of `MATCH_RECOGNIZE` is computed from the rows of a match,
+/// optionally restricted to the rows mapped to a pattern variable.
+///
+/// With `ALL ROWS PER MATCH`, the measures of a row are computed from the
+/// rows of the match up to that row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum MatchFunction {
+    /// `FIRST(expr)`, the value of an expression at the first row
+    First,
+    /// `LAST(expr)`, the value of an expression at the last row. A column
+    /// referenced outside of a function is the value at the last row
+    Last,
+    /// `COUNT(*)`, the number of rows
+    Count,
+    /// `MATCH_NUMBER()`, the number of the match in its partition, starting
+    /// at 1
+    MatchNumber,
+    /// `CLASSIFIER()`, the pattern variable the last row is mapped to
+    Classifier,
+}
+
+impl Display for MatchFunction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::First => write!(f, "FIRST"),
+            Self::Last => write!(f, "LAST"),
+            Self::Count => write!(f, "COUNT"),
+            Self::MatchNumber => write!(f, "MATCH_NUMBER"),
+            Self::Classifier => write!(f, "CLASSIFIER"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str) -> RowPattern {
+        RowPattern::Symbol(name.to_string())
+    }
+
+    fn repeat(pattern: RowPattern, min: u32, max: Option<u32>) -> RowPattern {
+        RowPattern::Repetition {
+            pattern: Box::new(pattern),
+            min,
+            max,
+        }
+    }
+
+    #[test]
+    fn row_pattern_display() {
+        let pattern = RowPattern::Concat(vec![
+            RowPattern::Start,
+            symbol("A"),
+            repeat(symbol("B"), 1, None),
+            RowPattern::Alternation(vec![symbol("C"), symbol("D")]),
+            repeat(
+                RowPattern::Concat(vec![symbol("E"), symbol("A")]),
+                2,
+                Some(3),
+            ),
+            repeat(symbol("F"), 0, Some(1)),
+            RowPattern::End,
+        ]);
+        assert_eq!(pattern.to_string(), "^ A B+ (C | D) (E A){2,3} F? $");
+        assert_eq!(pattern.symbols(), vec!["A", "B", "C", "D", "E", "F"]);
+    }
+}
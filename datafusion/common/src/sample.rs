@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the [`SampleMethod`] of `TABLESAMPLE`
+
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// How the rows of a sample, such as `TABLESAMPLE`, are chosen.
+///
+/// The fractions are in the range `[0, 1]`.
+#[derive(Debug, Clone, Copy)]
+pub enum SampleMethod {
+    /// Every row is included independently with the given probability
+    /// (`TABLESAMPLE BERNOULLI (n PERCENT)`)
+    Bernoulli(f64),
+    /// Every block of rows, such as a record batch or a row group of a Parquet
+    /// file, is included independently with the given probability
+    /// (`TABLESAMPLE SYSTEM (n PERCENT)`).
+    ///
+    /// This is less random than [`Self::Bernoulli`], but allows data sources
+    /// to skip reading the blocks that are not included.
+    System(f64),
+    /// A fixed number of rows chosen uniformly at random
+    /// (`TABLESAMPLE (n ROWS)`)
+    Rows(usize),
+}
+
+impl SampleMethod {
+    /// The fraction of rows included in the sample, if it is not a fixed
+    /// number of rows
+    pub fn fraction(&self) -> Option<f64> {
+        match self {
+            Self::Bernoulli(fraction) | Self::System(fraction) => Some(*fraction),
+            Self::Rows(_) => None,
+        }
+    }
+
+    fn key(&self) -> (u8, u64) {
+        match self {
+            Self::Bernoulli(fraction) => (0, fraction.to_bits()),
+            Self::System(fraction) => (1, fraction.to_bits()),
+            Self::Rows(rows) => (2, *rows as u64),
+        }
+    }
+}
+
+impl PartialEq for SampleMethod {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for SampleMethod {}
+
+impl PartialOrd for SampleMethod {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Bernoulli(a), Self::Bernoulli(b))
+            | (Self::System(a), Self::System(b)) => Some(a.total_cmp(b)),
+            _ => Some(self.key().cmp(&other.key())),
+        }
+    }
+}
+
+impl Hash for SampleMethod {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Display for SampleMethod {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Bernoulli(fraction) => write!(f, "BERNOULLI({fraction})"),
+            Self::System(fraction) => write!(f, "SYSTEM({fraction})"),
+            Self::Rows(rows) => write!(f, "ROWS({rows})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_method_display() {
+        assert_eq!(SampleMethod::Bernoulli(0.1).to_string(), "BERNOULLI(0.1)");
+        assert_eq!(SampleMethod::System(0.5).to_string(), "SYSTEM(0.5)");
+        assert_eq!(SampleMethod::Rows(10).to_string(), "ROWS(10)");
+    }
+
+    #[test]
+    fn sample_method_eq() {
+        assert_eq!(SampleMethod::System(0.5), SampleMethod::System(0.5));
+        assert_ne!(SampleMethod::System(0.5), SampleMethod::Bernoulli(0.5));
+        assert!(SampleMethod::System(0.1) < SampleMethod::System(0.5));
+    }
+}
@@ -19,7 +19,7 @@
 
 use std::fmt::{self, Debug, Display};
 
-use crate::{Result, ScalarValue};
+use crate::{Result, SampleMethod, ScalarValue};
 
 use crate::error::_plan_err;
 use arrow::datatypes::{DataType, Schema};
@@ -386,6 +386,31 @@ impl Statistics {
         self
     }
 
+    /// Calculates the statistics of a random sample of the rows, see
+    /// [`SampleMethod`]. Will always return inexact statistics.
+    pub fn with_sample(self, method: &SampleMethod) -> Self {
+        let mut stats = self.to_inexact();
+        let selectivity = match method {
+            SampleMethod::Bernoulli(fraction) | SampleMethod::System(fraction) => {
+                *fraction
+            }
+            SampleMethod::Rows(rows) => match stats.num_rows.get_value() {
+                Some(&num_rows) if num_rows > *rows => *rows as f64 / num_rows as f64,
+                Some(_) => 1.0,
+                None => {
+                    stats.num_rows = Precision::Inexact(*rows);
+                    stats.total_byte_size = Precision::Absent;
+                    return stats;
+                }
+            },
+        };
+        stats.num_rows = stats.num_rows.with_estimated_selectivity(selectivity);
+        stats.total_byte_size = stats
+            .total_byte_size
+            .with_estimated_selectivity(selectivity);
+        stats
+    }
+
     /// Project the statistics to the given column indices.
     ///
     /// For example, if we had statistics for columns `{"a", "b", "c"}`,
@@ -1472,6 +1497,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_sample() {
+        let original_stats = Statistics {
+            num_rows: Precision::Exact(1000),
+            total_byte_size: Precision::Exact(8000),
+            column_statistics: vec![ColumnStatistics {
+                null_count: Precision::Exact(10),
+                ..Default::default()
+            }],
+        };
+
+        let result = original_stats
+            .clone()
+            .with_sample(&SampleMethod::Bernoulli(0.1));
+        assert_eq!(result.num_rows, Precision::Inexact(100));
+        assert_eq!(result.total_byte_size, Precision::Inexact(800));
+        assert_eq!(
+            result.column_statistics[0].null_count,
+            Precision::Inexact(10)
+        );
+
+        let result = original_stats.clone().with_sample(&SampleMethod::Rows(250));
+        assert_eq!(result.num_rows, Precision::Inexact(250));
+        assert_eq!(result.total_byte_size, Precision::Inexact(2000));
+
+        let result = original_stats.with_sample(&SampleMethod::Rows(5000));
+        assert_eq!(result.num_rows, Precision::Inexact(1000));
+
+        let result = Statistics::default().with_sample(&SampleMethod::Rows(10));
+        assert_eq!(result.num_rows, Precision::Inexact(10));
+        assert_eq!(result.total_byte_size, Precision::Absent);
+    }
+
     #[test]
     fn test_with_fetch_skip_all_rows() {
         // Test when skip >= num_rows (all rows are skipped)
@@ -33,6 +33,10 @@ use crate::Column;
 /// If `preserve_nulls` is true (the default), nulls from the input
 /// column are carried through to the output.
 ///
+/// If `preserve_empty` is true, empty lists from the input column are
+/// carried through to the output as nulls, like `LATERAL VIEW OUTER` in
+/// Hive and Spark. Defaults to false.
+///
 /// # Examples
 ///
 /// ## `Unnest(c1)`, preserve_nulls: false
@@ -66,6 +70,11 @@ use crate::Column;
 /// `recursions` instruct how a column should be unnested (e.g unnesting a column multiple
 /// time, with depth = 1 and depth = 2). Any unnested column not being mentioned inside this
 /// options is inferred to be unnested with depth = 1
+///
+/// If `ordinality` is true, a column with the 1-based position of every output row
+/// among the rows unnested from the same input row is added to the output, like
+/// `UNNEST ... WITH ORDINALITY` in PostgreSQL. If multiple columns are unnested,
+/// the position is the one of the zipped row.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq)]
 pub struct UnnestOptions {
     /// Should nulls in the input be preserved? Defaults to true
@@ -74,6 +83,11 @@ pub struct UnnestOptions {
     /// declare them here. Any unnested columns not being mentioned inside this option
     /// will be unnested with depth = 1
     pub recursions: Vec<RecursionUnnestOption>,
+    /// Should empty lists in the input be preserved as nulls? Defaults to false
+    pub preserve_empty: bool,
+    /// Should a column with the position of every unnested row be added?
+    /// Defaults to false
+    pub ordinality: bool,
 }
 
 /// Instruction on how to unnest a column (mostly with a list type)
@@ -91,6 +105,8 @@ impl Default for UnnestOptions {
             // default to true to maintain backwards compatible behavior
             preserve_nulls: true,
             recursions: vec![],
+            preserve_empty: false,
+            ordinality: false,
         }
     }
 }
@@ -108,9 +124,23 @@ impl UnnestOptions {
         self
     }
 
+    /// Set the behavior with empty lists in the input as described on
+    /// [`Self`]
+    pub fn with_preserve_empty(mut self, preserve_empty: bool) -> Self {
+        self.preserve_empty = preserve_empty;
+        self
+    }
+
     /// Set the recursions for the unnest operation
     pub fn with_recursions(mut self, recursion: RecursionUnnestOption) -> Self {
         self.recursions.push(recursion);
         self
     }
+
+    /// Set whether the position of every unnested row is added as a column, as
+    /// described on [`Self`]
+    pub fn with_ordinality(mut self, ordinality: bool) -> Self {
+        self.ordinality = ordinality;
+        self
+    }
 }
@@ -56,6 +56,7 @@ use datafusion_common::{
     TableReference, UnnestOptions, exec_err, internal_datafusion_err, not_impl_err,
     plan_datafusion_err, plan_err, unqualified_field_not_found,
 };
+use datafusion_execution::cache::cache_manager::{CachedQueryResult, QueryResultCache};
 use datafusion_expr::select_expr::SelectExpr;
 use datafusion_expr::{
     ExplainOption, SortExpr, TableProviderFilterPushDown, UNNAMED_TABLE, case,
@@ -1412,6 +1413,14 @@ impl DataFrame {
     ///
     /// See [`Self::execute_stream`] to execute a DataFrame without buffering.
     ///
+    /// If the query result cache is enabled (see
+    /// [`RuntimeEnvBuilder::with_query_result_cache_limit`]), the result of a
+    /// deterministic query is returned from the cache if the same query was
+    /// collected before, and none of the tables it reads was modified since.
+    /// Queries over tables that do not track a
+    /// [data version](crate::catalog::TableProvider::data_version), such as
+    /// external tables, are always executed.
+    ///
     /// # Example
     /// ```
     /// # use datafusion::prelude::*;
@@ -1426,12 +1435,64 @@ impl DataFrame {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// [`RuntimeEnvBuilder::with_query_result_cache_limit`]: crate::execution::runtime_env::RuntimeEnvBuilder::with_query_result_cache_limit
     pub async fn collect(self) -> Result<Vec<RecordBatch>> {
+        if let Some(cache) = self
+            .session_state
+            .runtime_env()
+            .cache_manager
+            .get_query_result_cache()
+        {
+            return self.collect_with_result_cache(cache.as_ref()).await;
+        }
+
         let task_ctx = Arc::new(self.task_ctx());
         let plan = self.create_physical_plan().await?;
         collect(plan, task_ctx).await
     }
 
+    /// Collects the result of this `DataFrame` from the query result `cache`,
+    /// or executes it and stores its result in the cache.
+    async fn collect_with_result_cache(
+        self,
+        cache: &dyn QueryResultCache,
+    ) -> Result<Vec<RecordBatch>> {
+        let task_ctx = Arc::new(self.task_ctx());
+        let state = self.session_state;
+        let plan = state.optimize(&self.plan)?;
+
+        let Some(key) = state.query_result_cache_key(&plan)? else {
+            let physical_plan = state
+                .query_planner()
+                .create_physical_plan(&plan, &state)
+                .await?;
+            return collect(physical_plan, task_ctx).await;
+        };
+
+        if let Some(cached) = cache.get_with_extra(&key, &plan) {
+            return Ok(cached.batches.clone());
+        }
+
+        let physical_plan = state
+            .query_planner()
+            .create_physical_plan(&plan, &state)
+            .await?;
+        let schema = physical_plan.schema();
+        let batches = collect(physical_plan, task_ctx).await?;
+        // If a table was modified in the meantime, the result is stored with an
+        // outdated table version in its key, and is never returned again
+        cache.put(
+            &key,
+            Arc::new(CachedQueryResult {
+                plan: Arc::new(plan),
+                schema,
+                batches: batches.clone(),
+            }),
+        );
+        Ok(batches)
+    }
+
     /// Execute the `DataFrame` and print the results to the console.
     ///
     /// # Example
@@ -43,8 +43,8 @@ mod tests {
     use arrow::json::ReaderBuilder;
     use arrow::util::pretty;
     use datafusion_common::cast::as_int64_array;
-    use datafusion_common::internal_err;
     use datafusion_common::stats::Precision;
+    use datafusion_common::{assert_contains, internal_err};
 
     use datafusion_common::Result;
     use futures::StreamExt;
@@ -391,4 +391,117 @@ mod tests {
         assert_eq!(metadata.len(), 0);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn it_can_read_json_array() -> Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = tmp_dir.path().join("array.json");
+        std::fs::write(
+            &path,
+            r#"[
+  {"a": 1, "b": "x, [y]", "c": [1, 2]},
+  {"a": 2, "b": "\"]\"", "c": []}
+]"#,
+        )?;
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS JSON LOCATION '{}' \
+             OPTIONS ('format.format' 'array')",
+            path.to_string_lossy()
+        ))
+        .await?;
+        let result = ctx.sql("SELECT a, b, c FROM t").await?.collect().await?;
+
+        assert_snapshot!(batches_to_string(&result),@r#"
+        +---+--------+--------+
+        | a | b      | c      |
+        +---+--------+--------+
+        | 1 | x, [y] | [1, 2] |
+        | 2 | "]"    | []     |
+        +---+--------+--------+
+        "#);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_malformed_json_array() -> Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = tmp_dir.path().join("array.json");
+        std::fs::write(&path, r#"[{"a": 1} {"a": 2}]"#)?;
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t (a BIGINT) STORED AS JSON LOCATION '{}' \
+             OPTIONS ('format.format' 'array')",
+            path.to_string_lossy()
+        ))
+        .await?;
+        let err = ctx
+            .sql("SELECT * FROM t")
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "Expected ',' or ']' in JSON array");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_json_array() -> Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = tmp_dir.path().join("out.json");
+        let path = path.to_string_lossy();
+
+        ctx.sql(&format!(
+            "COPY (VALUES (1, 'a'), (2, 'b')) TO '{path}' \
+             STORED AS JSON OPTIONS ('format.format' 'array')"
+        ))
+        .await?
+        .collect()
+        .await?;
+        assert_eq!(
+            std::fs::read_to_string(path.as_ref())?,
+            "[\n{\"column1\":1,\"column2\":\"a\"},\n{\"column1\":2,\"column2\":\"b\"}\n]\n"
+        );
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS JSON LOCATION '{path}' \
+             OPTIONS ('format.format' 'array')"
+        ))
+        .await?;
+        let result = ctx.sql("SELECT * FROM t").await?.collect().await?;
+        assert_snapshot!(batches_to_string(&result),@r"
+        +---------+---------+
+        | column1 | column2 |
+        +---------+---------+
+        | 1       | a       |
+        | 2       | b       |
+        +---------+---------+
+        ");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_empty_json_array() -> Result<()> {
+        let ctx = SessionContext::new();
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = tmp_dir.path().join("empty.json");
+        let path = path.to_string_lossy();
+
+        ctx.sql(&format!(
+            "COPY (SELECT CAST(1 AS BIGINT) AS id LIMIT 0) TO '{path}' \
+             STORED AS JSON OPTIONS ('format.format' 'array')"
+        ))
+        .await?
+        .collect()
+        .await?;
+        assert_eq!(std::fs::read_to_string(path.as_ref())?, "[]\n");
+
+        Ok(())
+    }
 }
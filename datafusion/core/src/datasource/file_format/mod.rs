@@ -25,6 +25,9 @@ pub mod json;
 #[cfg(feature = "avro")]
 pub mod avro;
 
+#[cfg(feature = "orc")]
+pub mod orc;
+
 #[cfg(feature = "parquet")]
 pub mod parquet;
 
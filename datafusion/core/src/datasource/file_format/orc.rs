@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Re-exports the [`datafusion_datasource_orc::file_format`] module, and contains tests for it.
+
+pub use datafusion_datasource_orc::file_format::*;
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use crate::prelude::SessionContext;
+    use arrow::array::{Float64Array, Int64Array, RecordBatch, StringArray};
+    use datafusion_common::Result;
+    use datafusion_common::test_util::batches_to_string;
+    use datafusion_datasource_orc::orc_rust::ArrowWriterBuilder;
+    use insta::assert_snapshot;
+    use tempfile::TempDir;
+
+    /// Writes `batches` to the ORC file `path`
+    fn write_orc_file(path: &Path, batches: &[RecordBatch]) {
+        let file = File::create(path).unwrap();
+        let mut writer = ArrowWriterBuilder::new(file, batches[0].schema())
+            .try_build()
+            .unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn read_orc_stored_as() -> Result<()> {
+        let dir = TempDir::new()?;
+        let batch = RecordBatch::try_from_iter(vec![
+            ("id", Arc::new(Int64Array::from(vec![1, 2, 3, 4])) as _),
+            (
+                "name",
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    Some("b"),
+                    None,
+                    Some("d"),
+                ])) as _,
+            ),
+        ])?;
+        write_orc_file(&dir.path().join("data.orc"), &[batch]);
+
+        let ctx = SessionContext::new();
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS ORC LOCATION '{}/'",
+            dir.path().display()
+        ))
+        .await?
+        .collect()
+        .await?;
+
+        let batches = ctx
+            .sql("SELECT name, id FROM t WHERE id > 1 ORDER BY id")
+            .await?
+            .collect()
+            .await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +------+----+
+        | name | id |
+        +------+----+
+        | b    | 2  |
+        |      | 3  |
+        | d    | 4  |
+        +------+----+
+        ");
+
+        let batches = ctx.sql("SELECT count(*) FROM t").await?.collect().await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----------+
+        | count(*) |
+        +----------+
+        | 4        |
+        +----------+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_orc_merged_schema() -> Result<()> {
+        let dir = TempDir::new()?;
+        let batch = RecordBatch::try_from_iter(vec![
+            ("id", Arc::new(Int64Array::from(vec![1])) as _),
+            ("name", Arc::new(StringArray::from(vec!["a"])) as _),
+        ])?;
+        write_orc_file(&dir.path().join("1.orc"), &[batch]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("id", Arc::new(Int64Array::from(vec![2])) as _),
+            ("extra", Arc::new(Float64Array::from(vec![0.5])) as _),
+        ])?;
+        write_orc_file(&dir.path().join("2.orc"), &[batch]);
+
+        let ctx = SessionContext::new();
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS ORC LOCATION '{}/'",
+            dir.path().display()
+        ))
+        .await?
+        .collect()
+        .await?;
+
+        let batches = ctx
+            .sql("SELECT id, name, extra FROM t ORDER BY id")
+            .await?
+            .collect()
+            .await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+------+-------+
+        | id | name | extra |
+        +----+------+-------+
+        | 1  | a    |       |
+        | 2  |      | 0.5   |
+        +----+------+-------+
+        ");
+
+        let batches = ctx
+            .sql("SELECT id FROM t WHERE extra > 0 ORDER BY id")
+            .await?
+            .collect()
+            .await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+
+        | id |
+        +----+
+        | 2  |
+        +----+
+        ");
+        Ok(())
+    }
+}
@@ -30,6 +30,12 @@ pub mod avro;
 #[cfg(feature = "avro")]
 pub use avro::AvroSource;
 
+#[cfg(feature = "orc")]
+pub mod orc;
+
+#[cfg(feature = "orc")]
+pub use orc::OrcSource;
+
 #[cfg(feature = "parquet")]
 pub use datafusion_datasource_parquet::source::ParquetSource;
 #[cfg(feature = "parquet")]
@@ -28,13 +28,14 @@ use crate::execution::session_state::SessionStateBuilder;
 use crate::{
     catalog::listing_schema::ListingSchemaProvider,
     catalog::{
-        CatalogProvider, CatalogProviderList, TableProvider, TableProviderFactory,
+        CatalogProvider, CatalogProviderList, SchemaProvider, TableProvider,
+        TableProviderFactory,
     },
     dataframe::DataFrame,
     datasource::listing::{
         ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
     },
-    datasource::{MemTable, ViewTable, provider_as_source},
+    datasource::{MaterializedViewTable, MemTable, ViewTable, provider_as_source},
     error::Result,
     execution::{
         FunctionRegistry,
@@ -44,10 +45,11 @@ use crate::{
     logical_expr::AggregateUDF,
     logical_expr::ScalarUDF,
     logical_expr::{
-        CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateFunction,
-        CreateMemoryTable, CreateView, DropCatalogSchema, DropFunction, DropTable,
-        DropView, Execute, LogicalPlan, LogicalPlanBuilder, Prepare, ResetVariable,
-        SetVariable, TableType, UNNAMED_TABLE,
+        AlterTable, AlterTableOperation, CreateCatalog, CreateCatalogSchema,
+        CreateExternalTable, CreateFunction, CreateMaterializedView, CreateMemoryTable,
+        CreateView, DropCatalogSchema, DropFunction, DropTable, DropView, Execute,
+        LogicalPlan, LogicalPlanBuilder, Prepare, RefreshMaterializedView, ResetVariable,
+        SetVariable, TableType, TruncateTable, UNNAMED_TABLE,
     },
     physical_expr::PhysicalExpr,
     physical_plan::ExecutionPlan,
@@ -56,6 +58,7 @@ use crate::{
 
 // backwards compatibility
 pub use crate::execution::session_state::SessionState;
+use crate::execution::session_state::TEMPORARY_SCHEMA;
 
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
@@ -689,10 +692,12 @@ impl SessionContext {
     pub async fn execute_logical_plan(&self, plan: LogicalPlan) -> Result<DataFrame> {
         match plan {
             LogicalPlan::Ddl(ddl) => {
+                // Free the cached results of the queries that read the modified tables
+                let modified_tables = modified_tables(&ddl);
                 // Box::pin avoids allocating the stack space within this function's frame
                 // for every one of these individual async functions, decreasing the risk of
                 // stack overflows.
-                match ddl {
+                let result = match ddl {
                     DdlStatement::CreateExternalTable(cmd) => {
                         (Box::pin(async move { self.create_external_table(&cmd).await })
                             as std::pin::Pin<Box<dyn Future<Output = _> + Send>>)
@@ -704,6 +709,12 @@ impl SessionContext {
                     DdlStatement::CreateView(cmd) => {
                         Box::pin(self.create_view(cmd)).await
                     }
+                    DdlStatement::CreateMaterializedView(cmd) => {
+                        Box::pin(self.create_materialized_view(cmd)).await
+                    }
+                    DdlStatement::RefreshMaterializedView(cmd) => {
+                        Box::pin(self.refresh_materialized_view(cmd)).await
+                    }
                     DdlStatement::CreateCatalogSchema(cmd) => {
                         Box::pin(self.create_catalog_schema(cmd)).await
                     }
@@ -721,8 +732,16 @@ impl SessionContext {
                     DdlStatement::DropFunction(cmd) => {
                         Box::pin(self.drop_function(cmd)).await
                     }
+                    DdlStatement::AlterTable(cmd) => {
+                        Box::pin(self.alter_table(cmd)).await
+                    }
+                    DdlStatement::TruncateTable(cmd) => {
+                        Box::pin(self.truncate_table(cmd)).await
+                    }
                     ddl => Ok(DataFrame::new(self.state(), LogicalPlan::Ddl(ddl))),
-                }
+                };
+                self.invalidate_query_results(modified_tables);
+                result
             }
             // TODO what about the other statements (like TransactionStart and TransactionEnd)
             LogicalPlan::Statement(Statement::SetVariable(stmt)) => {
@@ -805,6 +824,26 @@ impl SessionContext {
         self.state.read().create_physical_expr(expr, df_schema)
     }
 
+    /// Invalidates the cached results of the queries that read any of `tables`,
+    /// or of all queries if `tables` is `None`
+    fn invalidate_query_results(&self, tables: Option<Vec<TableReference>>) {
+        let state = self.state.read();
+        match tables {
+            Some(tables) => {
+                for table in tables {
+                    state.invalidate_query_results(table);
+                }
+            }
+            None => {
+                if let Some(cache) =
+                    state.runtime_env().cache_manager.get_query_result_cache()
+                {
+                    cache.clear();
+                }
+            }
+        }
+    }
+
     // return an empty dataframe
     fn return_empty_dataframe(&self) -> Result<DataFrame> {
         let plan = LogicalPlanBuilder::empty(false).build()?;
@@ -882,9 +921,19 @@ impl SessionContext {
         let input = Arc::unwrap_or_clone(input);
         let input = self.state().optimize(&input)?;
 
-        if temporary {
-            return not_impl_err!("Temporary tables not supported");
-        }
+        // Temporary tables are registered in the temporary schema of the session
+        let name = if temporary {
+            if let Some(schema) = name.schema()
+                && schema != TEMPORARY_SCHEMA
+            {
+                return plan_err!(
+                    "Cannot create temporary table '{name}' in schema '{schema}'"
+                );
+            }
+            TableReference::partial(TEMPORARY_SCHEMA, name.table())
+        } else {
+            name
+        };
 
         let table = self.table(name.clone()).await;
         match (if_not_exists, or_replace, table) {
@@ -931,7 +980,7 @@ impl SessionContext {
         }
     }
 
-    fn calculate_row_count(batches: &Vec<Vec<RecordBatch>>) -> usize {
+    fn calculate_row_count(batches: &[Vec<RecordBatch>]) -> usize {
         let row_count: usize = batches
             .iter()
             .flatten()
@@ -983,6 +1032,106 @@ impl SessionContext {
         }
     }
 
+    async fn create_materialized_view(
+        &self,
+        cmd: CreateMaterializedView,
+    ) -> Result<DataFrame> {
+        let CreateMaterializedView {
+            name,
+            input,
+            location,
+            if_not_exists,
+            or_replace,
+            definition,
+        } = cmd;
+
+        let view = self.table_provider(name.clone()).await;
+        match (if_not_exists, or_replace, &view) {
+            (true, false, Ok(_)) => return self.return_empty_dataframe(),
+            (true, true, Ok(_)) => {
+                return exec_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'");
+            }
+            (false, false, Ok(_)) => return exec_err!("Table '{name}' already exists"),
+            (_, true, Ok(_)) | (_, _, Err(_)) => {}
+        }
+
+        let input = Self::apply_type_coercion(Arc::unwrap_or_clone(input))?;
+        // The result is stored as the files of a directory
+        let location = location.map(|location| {
+            if location.ends_with('/') {
+                location
+            } else {
+                format!("{location}/")
+            }
+        });
+        // Only the files of the replaced view may be overwritten, the files of
+        // any other table at the location are kept
+        let overwrite = view.ok().is_some_and(|view| {
+            view.as_any()
+                .downcast_ref::<MaterializedViewTable>()
+                .is_some_and(|view| view.location() == location.as_deref())
+        });
+        let data = self
+            .materialize(&input, location.as_deref(), overwrite)
+            .await?;
+        let table = Arc::new(MaterializedViewTable::new(
+            input, definition, location, data,
+        ));
+
+        if or_replace {
+            self.deregister_table(name.clone())?;
+        }
+        self.register_table(name, table)?;
+        self.return_empty_dataframe()
+    }
+
+    async fn refresh_materialized_view(
+        &self,
+        cmd: RefreshMaterializedView,
+    ) -> Result<DataFrame> {
+        let RefreshMaterializedView { name, .. } = cmd;
+        let table = self.table_provider(name.clone()).await?;
+        let Some(view) = table.as_any().downcast_ref::<MaterializedViewTable>() else {
+            return exec_err!("'{name}' is not a materialized view");
+        };
+        let data = self
+            .materialize(view.logical_plan(), view.location(), true)
+            .await?;
+        view.set_data(data);
+        self.return_empty_dataframe()
+    }
+
+    /// Computes the result of `plan` and returns the table storing it, either
+    /// in memory or as Parquet files at `location`
+    ///
+    /// If `overwrite` is false, `location` must be empty.
+    async fn materialize(
+        &self,
+        plan: &LogicalPlan,
+        location: Option<&str>,
+        overwrite: bool,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let dataframe = DataFrame::new(self.state(), plan.clone());
+        match location {
+            None => {
+                let schema = Arc::clone(plan.schema().inner());
+                let batches = dataframe.collect_partitioned().await?;
+                Ok(Arc::new(MemTable::try_new(schema, batches)?))
+            }
+            #[cfg(feature = "parquet")]
+            Some(location) => {
+                self.materialize_as_parquet(dataframe, location, overwrite)
+                    .await
+            }
+            #[cfg(not(feature = "parquet"))]
+            Some(_) => {
+                not_impl_err!(
+                    "Storing materialized views at a location requires the parquet feature"
+                )
+            }
+        }
+    }
+
     async fn create_catalog_schema(&self, cmd: CreateCatalogSchema) -> Result<DataFrame> {
         let CreateCatalogSchema {
             schema_name,
@@ -993,11 +1142,17 @@ impl SessionContext {
         // sqlparser doesn't accept database / catalog as parameter to CREATE SCHEMA
         // so for now, we default to default catalog
         let tokens: Vec<&str> = schema_name.split('.').collect();
+        let default_catalog = self
+            .state
+            .read()
+            .config_options()
+            .catalog
+            .default_catalog
+            .clone();
         let (catalog, schema_name) = match tokens.len() {
             1 => {
-                let state = self.state.read();
-                let name = &state.config().options().catalog.default_catalog;
-                let catalog = state.catalog_list().catalog(name).ok_or_else(|| {
+                let name = &default_catalog;
+                let catalog = self.catalog(name).ok_or_else(|| {
                     exec_datafusion_err!("Missing default catalog '{name}'")
                 })?;
                 (catalog, tokens[0])
@@ -1011,6 +1166,15 @@ impl SessionContext {
             }
             _ => return exec_err!("Unable to parse catalog from {schema_name}"),
         };
+        // The temporary schema of the session takes the place of a schema of the
+        // same name in the default catalog, which could therefore not be used
+        if schema_name == TEMPORARY_SCHEMA
+            && (tokens.len() == 1 || tokens[0] == default_catalog)
+        {
+            return plan_err!(
+                "Schema name '{TEMPORARY_SCHEMA}' is reserved for temporary tables"
+            );
+        }
         let schema = catalog.schema(schema_name);
 
         match (if_not_exists, schema) {
@@ -1074,6 +1238,91 @@ impl SessionContext {
         }
     }
 
+    async fn alter_table(&self, cmd: AlterTable) -> Result<DataFrame> {
+        let AlterTable {
+            name,
+            if_exists,
+            operations,
+            ..
+        } = cmd;
+        let Some((schema, table)) = self.find_base_table(name.clone()).await? else {
+            return if if_exists {
+                self.return_empty_dataframe()
+            } else {
+                exec_err!("Table '{name}' doesn't exist.")
+            };
+        };
+
+        let state = self.state();
+        let table_name = name.table().to_string();
+        let mut new_table_name = None;
+        let mut altered = Arc::clone(&table);
+        for operation in operations {
+            match operation {
+                AlterTableOperation::AddColumn {
+                    field,
+                    default,
+                    if_not_exists,
+                } => {
+                    if if_not_exists
+                        && altered.schema().field_with_name(field.name()).is_ok()
+                    {
+                        continue;
+                    }
+                    altered = altered.add_column(&state, field, default).await?;
+                }
+                AlterTableOperation::DropColumn {
+                    name: column,
+                    if_exists,
+                } => {
+                    if if_exists && altered.schema().field_with_name(&column).is_err() {
+                        continue;
+                    }
+                    altered = altered.drop_column(&state, &column).await?;
+                }
+                AlterTableOperation::RenameColumn { old_name, new_name } => {
+                    altered = altered.rename_column(&state, &old_name, &new_name).await?;
+                }
+                AlterTableOperation::RenameTable { new_name } => {
+                    let resolved = state.resolve_table_ref(name.clone());
+                    let new_resolved = state.resolve_table_ref(new_name);
+                    if resolved.catalog != new_resolved.catalog
+                        || resolved.schema != new_resolved.schema
+                    {
+                        return not_impl_err!(
+                            "Moving table '{name}' to another schema is not supported"
+                        );
+                    }
+                    new_table_name = Some(new_resolved.table.to_string());
+                }
+            }
+        }
+
+        // The original table is only renamed and replaced by the altered one once
+        // all operations succeeded
+        let table_name = match new_table_name {
+            Some(new_table_name) if new_table_name != table_name => {
+                schema.rename_table(&table_name, new_table_name.clone())?;
+                new_table_name
+            }
+            _ => table_name,
+        };
+        if !Arc::ptr_eq(&altered, &table) {
+            schema.deregister_table(&table_name)?;
+            schema.register_table(table_name, altered)?;
+        }
+        self.return_empty_dataframe()
+    }
+
+    async fn truncate_table(&self, cmd: TruncateTable) -> Result<DataFrame> {
+        let TruncateTable { name, .. } = cmd;
+        let Some((_, table)) = self.find_base_table(name.clone()).await? else {
+            return exec_err!("Table '{name}' doesn't exist.");
+        };
+        table.truncate(&self.state()).await?;
+        self.return_empty_dataframe()
+    }
+
     async fn drop_schema(&self, cmd: DropCatalogSchema) -> Result<DataFrame> {
         let DropCatalogSchema {
             name,
@@ -1326,6 +1575,23 @@ impl SessionContext {
         Ok(table)
     }
 
+    /// Returns the base table `table_ref` refers to and the schema containing it
+    async fn find_base_table(
+        &self,
+        table_ref: impl Into<TableReference>,
+    ) -> Result<Option<(Arc<dyn SchemaProvider>, Arc<dyn TableProvider>)>> {
+        let table_ref = table_ref.into();
+        let maybe_schema = self.state.read().schema_for_ref(table_ref.clone()).ok();
+
+        if let Some(schema) = maybe_schema
+            && let Some(table_provider) = schema.table(table_ref.table()).await?
+            && table_provider.table_type() == TableType::Base
+        {
+            return Ok(Some((schema, table_provider)));
+        }
+        Ok(None)
+    }
+
     async fn find_and_deregister(
         &self,
         table_ref: impl Into<TableReference>,
@@ -1333,14 +1599,7 @@ impl SessionContext {
     ) -> Result<bool> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_owned();
-        let maybe_schema = {
-            let state = self.state.read();
-            let resolved = state.resolve_table_ref(table_ref.clone());
-            state
-                .catalog_list()
-                .catalog(&resolved.catalog)
-                .and_then(|c| c.schema(&resolved.schema))
-        };
+        let maybe_schema = self.state.read().schema_for_ref(table_ref.clone()).ok();
 
         if let Some(schema) = maybe_schema
             && let Some(table_provider) = schema.table(&table).await?
@@ -1767,8 +2026,9 @@ impl SessionContext {
     ) -> Result<Option<Arc<dyn TableProvider>>> {
         let table_ref: TableReference = table_ref.into();
         let table = table_ref.table().to_owned();
-        self.state
-            .read()
+        let state = self.state.read();
+        state.invalidate_query_results(table_ref.clone());
+        state
             .schema_for_ref(table_ref)?
             .register_table(table, provider)
     }
@@ -1782,10 +2042,9 @@ impl SessionContext {
     ) -> Result<Option<Arc<dyn TableProvider>>> {
         let table_ref = table_ref.into();
         let table = table_ref.table().to_owned();
-        self.state
-            .read()
-            .schema_for_ref(table_ref)?
-            .deregister_table(&table)
+        let state = self.state.read();
+        state.invalidate_query_results(table_ref.clone());
+        state.schema_for_ref(table_ref)?.deregister_table(&table)
     }
 
     /// Return `true` if the specified table exists in the schema provider.
@@ -1978,6 +2237,39 @@ impl From<SessionContext> for SessionStateBuilder {
     }
 }
 
+/// Returns the tables modified by `ddl`, or `None` if it can change the result
+/// of queries over any table (e.g. by replacing a function)
+fn modified_tables(ddl: &DdlStatement) -> Option<Vec<TableReference>> {
+    let table = match ddl {
+        DdlStatement::CreateExternalTable(CreateExternalTable { name, .. })
+        | DdlStatement::CreateMemoryTable(CreateMemoryTable { name, .. })
+        | DdlStatement::CreateView(CreateView { name, .. })
+        | DdlStatement::CreateMaterializedView(CreateMaterializedView { name, .. })
+        | DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+            name, ..
+        })
+        | DdlStatement::DropTable(DropTable { name, .. })
+        | DdlStatement::DropView(DropView { name, .. })
+        | DdlStatement::TruncateTable(TruncateTable { name, .. }) => name,
+        DdlStatement::AlterTable(AlterTable {
+            name, operations, ..
+        }) => {
+            let renamed = operations.iter().filter_map(|operation| match operation {
+                AlterTableOperation::RenameTable { new_name } => Some(new_name.clone()),
+                _ => None,
+            });
+            return Some(std::iter::once(name.clone()).chain(renamed).collect());
+        }
+        DdlStatement::CreateCatalogSchema(_)
+        | DdlStatement::CreateCatalog(_)
+        | DdlStatement::CreateIndex(_) => return Some(vec![]),
+        DdlStatement::DropCatalogSchema(_)
+        | DdlStatement::CreateFunction(_)
+        | DdlStatement::DropFunction(_) => return None,
+    };
+    Some(vec![table.clone()])
+}
+
 /// A planner used to add extensions to DataFusion logical and physical plans.
 #[async_trait]
 pub trait QueryPlanner: Debug {
@@ -2169,7 +2461,7 @@ mod tests {
     use crate::test;
     use crate::test_util::{plan_and_collect, populate_csv_partitions};
     use arrow::datatypes::{DataType, TimeUnit};
-    use datafusion_common::DataFusionError;
+    use datafusion_common::{DataFusionError, assert_contains};
     use std::error::Error;
     use std::path::PathBuf;
 
@@ -2589,6 +2881,130 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn sql_create_temporary_table() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql("CREATE TEMPORARY TABLE t AS VALUES (2)")
+            .await?
+            .collect()
+            .await?;
+
+        // The temporary table shadows the permanent one
+        let results = ctx.sql("SELECT * FROM t").await?.collect().await?;
+        assert_snapshot!(batches_to_string(&results), @r"
+        +---------+
+        | column1 |
+        +---------+
+        | 2       |
+        +---------+
+        ");
+        assert!(ctx.state().temporary_schema().table_exist("t"));
+
+        // Another session sharing the catalog only sees the permanent table
+        let state = SessionStateBuilder::new()
+            .with_default_features()
+            .with_config(
+                SessionConfig::new().with_create_default_catalog_and_schema(false),
+            )
+            .with_catalog_list(Arc::clone(ctx.state().catalog_list()))
+            .build();
+        let other = SessionContext::new_with_state(state);
+        let results = other.sql("SELECT * FROM t").await?.collect().await?;
+        assert_snapshot!(batches_to_string(&results), @r"
+        +---------+
+        | column1 |
+        +---------+
+        | 1       |
+        +---------+
+        ");
+        assert!(other.sql("SELECT * FROM temp.t").await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sql_create_temporary_schema_is_rejected() -> Result<()> {
+        let ctx = SessionContext::new();
+        for sql in ["CREATE SCHEMA temp", "CREATE SCHEMA datafusion.temp"] {
+            let err = ctx.sql(sql).await.unwrap_err();
+            assert_contains!(
+                err.to_string(),
+                "Schema name 'temp' is reserved for temporary tables"
+            );
+        }
+        let catalog = ctx.catalog("datafusion").unwrap();
+        assert!(catalog.schema(TEMPORARY_SCHEMA).is_none());
+
+        // Other catalogs may have a schema of this name
+        ctx.register_catalog("other", Arc::new(MemoryCatalogProvider::new()));
+        ctx.sql("CREATE SCHEMA other.temp").await?.collect().await?;
+        ctx.sql("CREATE TABLE other.temp.t AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+        assert!(!ctx.state().temporary_schema().table_exist("t"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn alter_table_rename_is_atomic() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t (a INT) AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+
+        // The SQL planner does not combine RENAME TO with other operations, but
+        // other frontends can
+        let alter = |operations| {
+            LogicalPlan::Ddl(DdlStatement::AlterTable(AlterTable {
+                name: TableReference::bare("t"),
+                if_exists: false,
+                operations,
+                schema: Arc::new(DFSchema::empty()),
+            }))
+        };
+        let err = ctx
+            .execute_logical_plan(alter(vec![
+                AlterTableOperation::RenameTable {
+                    new_name: TableReference::bare("t2"),
+                },
+                AlterTableOperation::DropColumn {
+                    name: "missing".to_string(),
+                    if_exists: false,
+                },
+            ]))
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "Column 'missing' does not exist");
+        assert!(ctx.table_exist("t")?);
+        assert!(!ctx.table_exist("t2")?);
+
+        ctx.execute_logical_plan(alter(vec![
+            AlterTableOperation::RenameTable {
+                new_name: TableReference::bare("t2"),
+            },
+            AlterTableOperation::RenameColumn {
+                old_name: "a".to_string(),
+                new_name: "b".to_string(),
+            },
+        ]))
+        .await?;
+        assert!(!ctx.table_exist("t")?);
+        let results = ctx.sql("SELECT b FROM t2").await?.collect().await?;
+        assert_snapshot!(batches_to_string(&results), @r"
+        +---+
+        | b |
+        +---+
+        | 1 |
+        +---+
+        ");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn custom_type_planner() -> Result<()> {
         let state = SessionStateBuilder::new()
@@ -15,13 +15,22 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use super::super::options::{ParquetReadOptions, ReadOptions};
-use super::{DataFilePaths, DataFrame, ExecutionPlan, Result, SessionContext};
+use super::{
+    DataFilePaths, DataFrame, ExecutionPlan, ListingOptions, ListingTable,
+    ListingTableConfig, ListingTableUrl, Result, SessionContext, TableProvider,
+};
+use crate::datasource::file_format::parquet::ParquetFormat;
+use crate::physical_plan::collect;
 use datafusion_datasource_parquet::plan_to_parquet;
 
-use datafusion_common::TableReference;
+use datafusion_common::{TableReference, plan_err};
+use datafusion_execution::cache::TableScopedPath;
+use datafusion_expr::dml::InsertOp;
+use futures::TryStreamExt;
 use parquet::file::properties::WriterProperties;
 
 impl SessionContext {
@@ -94,6 +103,80 @@ impl SessionContext {
     ) -> Result<()> {
         plan_to_parquet(self.task_ctx(), plan, path, writer_properties).await
     }
+
+    /// Writes the result of `dataframe` as Parquet files to the directory at
+    /// `location`, replacing the Parquet files already there, and returns a
+    /// [`ListingTable`] reading them.
+    ///
+    /// If `overwrite` is false, an error is returned if the directory is not
+    /// empty, so that files of other tables are not deleted.
+    ///
+    /// The previous files are only removed once the new result was written, so
+    /// that they are kept if computing or writing the result fails. Scans of
+    /// the directory running while the files are replaced may read both the
+    /// previous and the new files.
+    pub(super) async fn materialize_as_parquet(
+        &self,
+        dataframe: DataFrame,
+        location: &str,
+        overwrite: bool,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let state = self.state();
+        let table_path = ListingTableUrl::parse(location)?;
+        let store = state.runtime_env().object_store(&table_path)?;
+        let options = ListingOptions::new(Arc::new(ParquetFormat::default()))
+            .with_session_config_options(state.config());
+        let config = ListingTableConfig::new(table_path.clone())
+            .with_listing_options(options)
+            .with_schema(Arc::clone(dataframe.schema().inner()));
+        let table = Arc::new(ListingTable::try_new(config)?);
+
+        let list_parquet_files = || {
+            store
+                .list(Some(table_path.prefix()))
+                .try_filter(|meta| {
+                    let is_parquet_file = table_path.contains(&meta.location, true)
+                        && meta.location.as_ref().ends_with(".parquet");
+                    futures::future::ready(is_parquet_file)
+                })
+                .map_ok(|meta| meta.location)
+                .try_collect::<HashSet<_>>()
+        };
+        if !overwrite {
+            let mut objects = store.list(Some(table_path.prefix()));
+            if objects.try_next().await?.is_some() {
+                return plan_err!(
+                    "Location '{location}' of the materialized view is not empty"
+                );
+            }
+        }
+        let previous = list_parquet_files().await?;
+
+        // The new files are written next to the previous ones, with unique names
+        let plan = dataframe.create_physical_plan().await?;
+        let insert = table.insert_into(&state, plan, InsertOp::Append).await?;
+        if let Err(e) = collect(insert, state.task_ctx()).await {
+            // Remove the files of the partially written result. This is best
+            // effort, the error of the refresh is more relevant than cleanup errors
+            let written = list_parquet_files().await.unwrap_or_default();
+            for location in written.difference(&previous) {
+                let _ = store.delete(location).await;
+            }
+            return Err(e);
+        }
+
+        for location in &previous {
+            store.delete(location).await?;
+        }
+        if let Some(lfc) = state.runtime_env().cache_manager.get_list_files_cache() {
+            let key = TableScopedPath {
+                table: table_path.get_table_ref().clone(),
+                path: table_path.prefix().clone(),
+            };
+            let _ = lfc.remove(&key);
+        }
+        Ok(table)
+    }
 }
 
 #[cfg(test)]
@@ -524,4 +607,99 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn refresh_materialized_view_failure_keeps_files() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = tempdir()?;
+        let location = format!("{}/", dir.path().join("view").to_str().unwrap());
+        ctx.sql("CREATE TABLE t (a INT) AS VALUES (1), (2)")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql(&format!(
+            "CREATE MATERIALIZED VIEW v WITH (location = '{location}') \
+             AS SELECT 10 / a AS q FROM t"
+        ))
+        .await?
+        .collect()
+        .await?;
+        let files = std::fs::read_dir(dir.path().join("view"))?.count();
+
+        // The refresh fails while computing the result
+        ctx.sql("INSERT INTO t VALUES (0)").await?.collect().await?;
+        let err = ctx.sql("REFRESH MATERIALIZED VIEW v").await.unwrap_err();
+        assert_contains!(err.to_string(), "Divide by zero");
+
+        let expected = ["+----+", "| q  |", "+----+", "| 10 |", "| 5  |", "+----+"];
+        let results = ctx.sql("SELECT * FROM v").await?.collect().await?;
+        assert_batches_sorted_eq!(expected, &results);
+        assert_eq!(std::fs::read_dir(dir.path().join("view"))?.count(), files);
+        let results = ctx
+            .read_parquet(&location, ParquetReadOptions::default())
+            .await?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(expected, &results);
+
+        // A successful refresh replaces the files
+        ctx.sql("DELETE FROM t WHERE a = 0")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql("DELETE FROM t WHERE a = 2")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql("REFRESH MATERIALIZED VIEW v")
+            .await?
+            .collect()
+            .await?;
+        let expected = ["+----+", "| q  |", "+----+", "| 10 |", "+----+"];
+        let results = ctx
+            .read_parquet(&location, ParquetReadOptions::default())
+            .await?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(expected, &results);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_materialized_view_non_empty_location() -> Result<()> {
+        let ctx = SessionContext::new();
+        let dir = tempdir()?;
+        let location = format!("{}/", dir.path().to_str().unwrap());
+        ctx.sql(&format!("COPY (SELECT 1 AS a) TO '{location}t.parquet'"))
+            .await?
+            .collect()
+            .await?;
+
+        // The files at the location are not deleted
+        let err = ctx
+            .sql(&format!(
+                "CREATE MATERIALIZED VIEW v WITH (location = '{location}') AS SELECT 2 AS a"
+            ))
+            .await
+            .unwrap_err();
+        assert_contains!(err.to_string(), "of the materialized view is not empty");
+        assert!(dir.path().join("t.parquet").exists());
+
+        // Replacing a view may overwrite its own files
+        let location = format!("{}/", dir.path().join("view").to_str().unwrap());
+        let sql = format!(
+            "CREATE OR REPLACE MATERIALIZED VIEW v WITH (location = '{location}') \
+             AS SELECT 3 AS a"
+        );
+        ctx.sql(&sql).await?.collect().await?;
+        ctx.sql(&sql).await?.collect().await?;
+        let expected = ["+---+", "| a |", "+---+", "| 3 |", "+---+"];
+        let results = ctx
+            .read_parquet(&location, ParquetReadOptions::default())
+            .await?
+            .collect()
+            .await?;
+        assert_batches_sorted_eq!(expected, &results);
+        Ok(())
+    }
 }
@@ -26,27 +26,30 @@ use std::sync::Arc;
 use crate::catalog::{CatalogProviderList, SchemaProvider, TableProviderFactory};
 use crate::datasource::file_format::FileFormatFactory;
 #[cfg(feature = "sql")]
-use crate::datasource::provider_as_source;
+use crate::datasource::{provider_as_source, source_as_provider};
 use crate::execution::SessionStateDefaults;
 use crate::execution::context::{EmptySerializerRegistry, FunctionFactory, QueryPlanner};
 use crate::physical_planner::{DefaultPhysicalPlanner, PhysicalPlanner};
 use arrow_schema::{DataType, FieldRef};
-use datafusion_catalog::MemoryCatalogProviderList;
 use datafusion_catalog::information_schema::{
     INFORMATION_SCHEMA, InformationSchemaProvider,
 };
+use datafusion_catalog::{MemoryCatalogProviderList, MemorySchemaProvider};
 use datafusion_catalog::{TableFunction, TableFunctionImpl};
 use datafusion_common::alias::AliasGenerator;
 #[cfg(feature = "sql")]
 use datafusion_common::config::Dialect;
 use datafusion_common::config::{ConfigExtension, ConfigOptions, TableOptions};
 use datafusion_common::display::{PlanType, StringifiedPlan, ToStringifiedPlan};
-use datafusion_common::tree_node::TreeNode;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{
     DFSchema, DataFusionError, ResolvedTableReference, TableReference, config_err,
     exec_err, plan_datafusion_err,
 };
 use datafusion_execution::TaskContext;
+use datafusion_execution::cache::cache_manager::{
+    QueryResultCacheKey, QueryResultCacheTable,
+};
 use datafusion_execution::config::SessionConfig;
 use datafusion_execution::runtime_env::RuntimeEnv;
 #[cfg(feature = "sql")]
@@ -59,11 +62,13 @@ use datafusion_expr::planner::{RelationPlanner, TypePlanner};
 use datafusion_expr::registry::{FunctionRegistry, SerializerRegistry};
 use datafusion_expr::simplify::SimplifyInfo;
 use datafusion_expr::{
-    AggregateUDF, Explain, Expr, ExprSchemable, LogicalPlan, ScalarUDF, WindowUDF,
+    AggregateUDF, Explain, Expr, ExprSchemable, LogicalPlan, ScalarUDF, TableType,
+    WindowUDF,
 };
 use datafusion_optimizer::simplify_expressions::ExprSimplifier;
 use datafusion_optimizer::{
-    Analyzer, AnalyzerRule, Optimizer, OptimizerConfig, OptimizerRule,
+    Analyzer, AnalyzerRule, LogicalPlanSignature, Optimizer, OptimizerConfig,
+    OptimizerRule,
 };
 use datafusion_physical_expr::create_physical_expr;
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
@@ -90,6 +95,15 @@ use sqlparser::{
 use url::Url;
 use uuid::Uuid;
 
+/// The name of the schema holding the temporary tables of a session.
+///
+/// The schema belongs to the default catalog and is consulted first when
+/// resolving unqualified table names, so temporary tables shadow permanent
+/// tables of the same name. The name is reserved: `CREATE SCHEMA temp` is
+/// rejected for the default catalog, and a schema of this name registered in
+/// the default catalog through the API is hidden by the temporary schema.
+pub const TEMPORARY_SCHEMA: &str = "temp";
+
 /// `SessionState` contains all the necessary state to plan and execute queries,
 /// such as configuration, functions, and runtime environment. Please see the
 /// documentation on [`SessionContext`] for more information.
@@ -152,6 +166,8 @@ pub struct SessionState {
     query_planner: Arc<dyn QueryPlanner + Send + Sync>,
     /// Collection of catalogs containing schemas and ultimately TableProviders
     catalog_list: Arc<dyn CatalogProviderList>,
+    /// Schema holding the temporary tables of this session, see [`TEMPORARY_SCHEMA`]
+    temporary_schema: Arc<dyn SchemaProvider>,
     /// Table Functions
     table_functions: HashMap<String, Arc<TableFunction>>,
     /// Scalar functions that are registered with the context
@@ -203,6 +219,7 @@ impl Debug for SessionState {
             .field("config", &self.config)
             .field("runtime_env", &self.runtime_env)
             .field("catalog_list", &self.catalog_list)
+            .field("temporary_schema", &self.temporary_schema)
             .field("serializer_registry", &self.serializer_registry)
             .field("file_formats", &self.file_formats)
             .field("execution_props", &self.execution_props)
@@ -299,9 +316,15 @@ impl SessionState {
         table_ref: impl Into<TableReference>,
     ) -> ResolvedTableReference {
         let catalog = &self.config_options().catalog;
-        table_ref
-            .into()
-            .resolve(&catalog.default_catalog, &catalog.default_schema)
+        let table_ref = table_ref.into();
+        // Temporary tables shadow permanent tables of the same name
+        if let TableReference::Bare { table } = &table_ref
+            && self.temporary_schema.table_exist(table)
+        {
+            return TableReference::partial(TEMPORARY_SCHEMA, Arc::clone(table))
+                .resolve(&catalog.default_catalog, &catalog.default_schema);
+        }
+        table_ref.resolve(&catalog.default_catalog, &catalog.default_schema)
     }
 
     /// Retrieve the [`SchemaProvider`] for a specific [`TableReference`], if it
@@ -317,6 +340,11 @@ impl SessionState {
                 &self.catalog_list,
             ))));
         }
+        if *resolved_ref.schema == *TEMPORARY_SCHEMA
+            && *resolved_ref.catalog == self.config_options().catalog.default_catalog
+        {
+            return Ok(Arc::clone(&self.temporary_schema));
+        }
 
         self.catalog_list
             .catalog(&resolved_ref.catalog)
@@ -725,6 +753,80 @@ impl SessionState {
             .await
     }
 
+    /// Returns the key of the optimized `plan` in the query result cache, or
+    /// `None` if its result must not be cached.
+    ///
+    /// Only the results of queries over base and temporary tables that report a
+    /// [data version] are cached, so that results computed before a table was
+    /// modified are not returned. Plans that modify data, contain volatile
+    /// functions such as `random()`, or read from user defined nodes, external
+    /// tables or views that are not inlined (e.g. `information_schema`) bypass
+    /// the cache.
+    ///
+    /// [data version]: crate::catalog::TableProvider::data_version
+    pub(crate) fn query_result_cache_key(
+        &self,
+        plan: &LogicalPlan,
+    ) -> datafusion_common::Result<Option<QueryResultCacheKey>> {
+        let mut cacheable = true;
+        let mut tables = vec![];
+        plan.apply_with_subqueries(|node| {
+            match node {
+                LogicalPlan::TableScan(scan)
+                    if matches!(
+                        scan.source.table_type(),
+                        TableType::Base | TableType::Temporary
+                    ) =>
+                {
+                    let provider = source_as_provider(&scan.source).ok();
+                    let version = provider.as_ref().and_then(|p| p.data_version());
+                    match (provider, version) {
+                        (Some(provider), Some(version)) => {
+                            tables.push(QueryResultCacheTable {
+                                table: self
+                                    .resolve_table_ref(scan.table_name.clone())
+                                    .into(),
+                                provider_id: Arc::as_ptr(&provider).cast::<()>() as usize,
+                                version,
+                            });
+                        }
+                        _ => cacheable = false,
+                    }
+                }
+                LogicalPlan::TableScan(_)
+                | LogicalPlan::Dml(_)
+                | LogicalPlan::Ddl(_)
+                | LogicalPlan::Statement(_)
+                | LogicalPlan::Copy(_)
+                | LogicalPlan::Explain(_)
+                | LogicalPlan::Analyze(_)
+                | LogicalPlan::DescribeTable(_)
+                | LogicalPlan::Extension(_) => cacheable = false,
+                _ => {}
+            }
+            if node.expressions().iter().any(Expr::is_volatile) {
+                cacheable = false;
+            }
+            Ok(if cacheable {
+                TreeNodeRecursion::Continue
+            } else {
+                TreeNodeRecursion::Stop
+            })
+        })?;
+
+        // Queries that do not read any table are cheap to recompute
+        Ok((cacheable && !tables.is_empty())
+            .then(|| QueryResultCacheKey::new(&LogicalPlanSignature::new(plan), tables)))
+    }
+
+    /// Invalidates the cached results of the queries that read `table`, if the
+    /// query result cache is enabled.
+    pub(crate) fn invalidate_query_results(&self, table: impl Into<TableReference>) {
+        if let Some(cache) = self.runtime_env.cache_manager.get_query_result_cache() {
+            cache.invalidate_table(&self.resolve_table_ref(table).into());
+        }
+    }
+
     /// Create a [`PhysicalExpr`] from an [`Expr`] after applying type
     /// coercion, and function rewrites.
     ///
@@ -875,6 +977,12 @@ impl SessionState {
         &self.catalog_list
     }
 
+    /// Returns the schema holding the temporary tables of this session, see
+    /// [`TEMPORARY_SCHEMA`]
+    pub fn temporary_schema(&self) -> &Arc<dyn SchemaProvider> {
+        &self.temporary_schema
+    }
+
     /// set the catalog list
     pub(crate) fn register_catalog_list(
         &mut self,
@@ -981,6 +1089,7 @@ pub struct SessionStateBuilder {
     physical_optimizers: Option<PhysicalOptimizer>,
     query_planner: Option<Arc<dyn QueryPlanner + Send + Sync>>,
     catalog_list: Option<Arc<dyn CatalogProviderList>>,
+    temporary_schema: Option<Arc<dyn SchemaProvider>>,
     table_functions: Option<HashMap<String, Arc<TableFunction>>>,
     scalar_functions: Option<Vec<Arc<ScalarUDF>>>,
     aggregate_functions: Option<Vec<Arc<AggregateUDF>>>,
@@ -1021,6 +1130,7 @@ impl SessionStateBuilder {
             physical_optimizers: None,
             query_planner: None,
             catalog_list: None,
+            temporary_schema: None,
             table_functions: None,
             scalar_functions: None,
             aggregate_functions: None,
@@ -1074,6 +1184,7 @@ impl SessionStateBuilder {
             physical_optimizers: Some(existing.physical_optimizers),
             query_planner: Some(existing.query_planner),
             catalog_list: Some(existing.catalog_list),
+            temporary_schema: Some(existing.temporary_schema),
             table_functions: Some(existing.table_functions),
             scalar_functions: Some(existing.scalar_functions.into_values().collect_vec()),
             aggregate_functions: Some(
@@ -1449,6 +1560,7 @@ impl SessionStateBuilder {
             physical_optimizers,
             query_planner,
             catalog_list,
+            temporary_schema,
             table_functions,
             scalar_functions,
             aggregate_functions,
@@ -1485,6 +1597,8 @@ impl SessionStateBuilder {
             catalog_list: catalog_list.unwrap_or_else(|| {
                 Arc::new(MemoryCatalogProviderList::new()) as Arc<dyn CatalogProviderList>
             }),
+            temporary_schema: temporary_schema
+                .unwrap_or_else(|| Arc::new(MemorySchemaProvider::new())),
             table_functions: table_functions.unwrap_or_default(),
             scalar_functions: HashMap::new(),
             aggregate_functions: HashMap::new(),
@@ -1747,6 +1861,7 @@ impl Debug for SessionStateBuilder {
             .field("config", &self.config)
             .field("runtime_env", &self.runtime_env)
             .field("catalog_list", &self.catalog_list)
+            .field("temporary_schema", &self.temporary_schema)
             .field("serializer_registry", &self.serializer_registry)
             .field("file_formats", &self.file_formats)
             .field("execution_props", &self.execution_props)
@@ -23,6 +23,8 @@ use crate::datasource::file_format::arrow::ArrowFormatFactory;
 use crate::datasource::file_format::avro::AvroFormatFactory;
 use crate::datasource::file_format::csv::CsvFormatFactory;
 use crate::datasource::file_format::json::JsonFormatFactory;
+#[cfg(feature = "orc")]
+use crate::datasource::file_format::orc::OrcFormatFactory;
 #[cfg(feature = "parquet")]
 use crate::datasource::file_format::parquet::ParquetFormatFactory;
 use crate::datasource::provider::DefaultTableFactory;
@@ -57,6 +59,7 @@ impl SessionStateDefaults {
         table_factories.insert("NDJSON".into(), Arc::new(DefaultTableFactory::new()));
         table_factories.insert("AVRO".into(), Arc::new(DefaultTableFactory::new()));
         table_factories.insert("ARROW".into(), Arc::new(DefaultTableFactory::new()));
+        table_factories.insert("ORC".into(), Arc::new(DefaultTableFactory::new()));
 
         table_factories
     }
@@ -137,6 +140,8 @@ impl SessionStateDefaults {
             Arc::new(ArrowFormatFactory::new()),
             #[cfg(feature = "avro")]
             Arc::new(AvroFormatFactory::new()),
+            #[cfg(feature = "orc")]
+            Arc::new(OrcFormatFactory::new()),
         ];
 
         file_formats
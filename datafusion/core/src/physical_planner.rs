@@ -29,7 +29,8 @@ use crate::error::{DataFusionError, Result};
 use crate::execution::context::{ExecutionProps, SessionState};
 use crate::logical_expr::utils::generate_sort_key;
 use crate::logical_expr::{
-    Aggregate, EmptyRelation, Join, Projection, Sort, TableScan, Unnest, Values, Window,
+    Aggregate, AsOfJoin, EmptyRelation, Join, MatchRecognize, Projection, Sample, Sort,
+    TableScan, Unnest, Values, Window,
 };
 use crate::logical_expr::{
     Expr, LogicalPlan, Partitioning as LogicalPartitioning, PlanType, Repartition,
@@ -42,11 +43,13 @@ use crate::physical_plan::explain::ExplainExec;
 use crate::physical_plan::filter::FilterExec;
 use crate::physical_plan::joins::utils as join_utils;
 use crate::physical_plan::joins::{
-    CrossJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode, SortMergeJoinExec,
+    AsOfJoinExec, CrossJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode,
+    SortMergeJoinExec,
 };
 use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use crate::physical_plan::projection::{ProjectionExec, ProjectionExpr};
 use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::sample::SampleExec;
 use crate::physical_plan::sorts::sort::SortExec;
 use crate::physical_plan::union::UnionExec;
 use crate::physical_plan::unnest::UnnestExec;
@@ -99,6 +102,7 @@ use datafusion_physical_optimizer::PhysicalOptimizerRule;
 use datafusion_physical_plan::empty::EmptyExec;
 use datafusion_physical_plan::execution_plan::InvariantLevel;
 use datafusion_physical_plan::joins::PiecewiseMergeJoinExec;
+use datafusion_physical_plan::match_recognize::{MatchMeasureExpr, MatchRecognizeExec};
 use datafusion_physical_plan::metrics::MetricType;
 use datafusion_physical_plan::placeholder_row::PlaceholderRowExec;
 use datafusion_physical_plan::recursive_query::RecursiveQueryExec;
@@ -655,6 +659,30 @@ impl DefaultPhysicalPlanner {
                     );
                 }
             }
+            LogicalPlan::Dml(DmlStatement {
+                table_name,
+                target,
+                op: WriteOp::Merge,
+                ..
+            }) => {
+                if let Some(provider) =
+                    target.as_any().downcast_ref::<DefaultTableSource>()
+                {
+                    // For MERGE, the action of each row is computed by the input plan
+                    let input_exec = children.one()?;
+                    provider
+                        .table_provider
+                        .merge_into(session_state, input_exec)
+                        .await
+                        .map_err(|e| {
+                            e.context(format!("MERGE operation on table '{table_name}'"))
+                        })?
+                } else {
+                    return exec_err!(
+                        "Table source can't be downcasted to DefaultTableSource"
+                    );
+                }
+            }
             LogicalPlan::Window(Window { window_expr, .. }) => {
                 assert_or_internal_err!(
                     !window_expr.is_empty(),
@@ -1058,6 +1086,15 @@ impl DefaultPhysicalPlanner {
 
                 Arc::new(GlobalLimitExec::new(input, skip, fetch))
             }
+            LogicalPlan::Sample(Sample { method, seed, .. }) => {
+                let input = children.one()?;
+                // Let the input, such as a Parquet scan, skip reading the rows
+                // that are not sampled if it can
+                match input.try_pushdown_sample(method, *seed)? {
+                    Some(sampled) => sampled,
+                    None => Arc::new(SampleExec::try_new(input, *method, *seed)?),
+                }
+            }
             LogicalPlan::Unnest(Unnest {
                 list_type_columns,
                 struct_type_columns,
@@ -1347,15 +1384,6 @@ impl DefaultPhysicalPlanner {
                         Arc::new(CrossJoinExec::new(physical_left, physical_right))
                     } else if num_range_filters == 1
                         && total_filters == 1
-                        && !matches!(
-                            join_type,
-                            JoinType::LeftSemi
-                                | JoinType::RightSemi
-                                | JoinType::LeftAnti
-                                | JoinType::RightAnti
-                                | JoinType::LeftMark
-                                | JoinType::RightMark
-                        )
                         && session_state
                             .config_options()
                             .optimizer
@@ -1519,6 +1547,97 @@ impl DefaultPhysicalPlanner {
                     join
                 }
             }
+            LogicalPlan::AsOfJoin(AsOfJoin {
+                left,
+                right,
+                on,
+                match_on,
+                match_op,
+                join_type,
+                ..
+            }) => {
+                let [physical_left, physical_right] = children.two()?;
+                let left_df_schema = left.schema();
+                let right_df_schema = right.schema();
+                let execution_props = session_state.execution_props();
+                let join_on = on
+                    .iter()
+                    .map(|(l, r)| {
+                        let l = create_physical_expr(l, left_df_schema, execution_props)?;
+                        let r =
+                            create_physical_expr(r, right_df_schema, execution_props)?;
+                        Ok((l, r))
+                    })
+                    .collect::<Result<join_utils::JoinOn>>()?;
+                let match_left =
+                    create_physical_expr(&match_on.0, left_df_schema, execution_props)?;
+                let match_right =
+                    create_physical_expr(&match_on.1, right_df_schema, execution_props)?;
+
+                Arc::new(AsOfJoinExec::try_new(
+                    physical_left,
+                    physical_right,
+                    join_on,
+                    (match_left, match_right),
+                    *match_op,
+                    *join_type,
+                )?)
+            }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                partition_by,
+                order_by,
+                symbols,
+                definitions,
+                pattern,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                schema,
+            }) => {
+                let physical_input = children.one()?;
+                let input_dfschema = input.schema();
+                let execution_props = session_state.execution_props();
+                let partition_by =
+                    create_physical_exprs(partition_by, input_dfschema, execution_props)?;
+                let order_by = create_physical_sort_exprs(
+                    order_by,
+                    input_dfschema,
+                    execution_props,
+                )?;
+                let definitions =
+                    create_physical_exprs(definitions, input_dfschema, execution_props)?;
+                let measures = measures
+                    .iter()
+                    .map(|measure| {
+                        let arg = measure
+                            .arg
+                            .as_ref()
+                            .map(|arg| {
+                                create_physical_expr(arg, input_dfschema, execution_props)
+                            })
+                            .transpose()?;
+                        Ok(MatchMeasureExpr {
+                            function: measure.function,
+                            symbol: measure.symbol.clone(),
+                            arg,
+                            name: measure.name.clone(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Arc::new(MatchRecognizeExec::try_new(
+                    physical_input,
+                    partition_by,
+                    order_by,
+                    symbols.clone(),
+                    definitions,
+                    pattern.clone(),
+                    measures,
+                    *rows_per_match,
+                    after_match_skip.clone(),
+                    Arc::clone(schema.inner()),
+                )?)
+            }
             LogicalPlan::RecursiveQuery(RecursiveQuery {
                 name, is_distinct, ..
             }) => {
@@ -18,4 +18,5 @@
 mod coop;
 mod datasource_split;
 mod logical_plan;
+mod query_result_cache;
 mod register_arrow;
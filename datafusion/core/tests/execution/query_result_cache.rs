@@ -0,0 +1,218 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Integration tests for the query result cache
+
+use std::sync::Arc;
+
+use arrow::array::{Int64Array, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema};
+use datafusion::datasource::MemTable;
+use datafusion::execution::runtime_env::{RuntimeEnv, RuntimeEnvBuilder};
+use datafusion::prelude::*;
+use datafusion_common::Result;
+use datafusion_common::cast::as_int64_array;
+use futures::TryStreamExt;
+
+fn runtime_with_result_cache() -> Result<Arc<RuntimeEnv>> {
+    RuntimeEnvBuilder::new()
+        .with_query_result_cache_limit(1024 * 1024)
+        .build_arc()
+}
+
+fn context_with_result_cache() -> Result<SessionContext> {
+    Ok(SessionContext::new_with_config_rt(
+        SessionConfig::new().with_information_schema(true),
+        runtime_with_result_cache()?,
+    ))
+}
+
+fn cached_results(ctx: &SessionContext) -> usize {
+    ctx.runtime_env()
+        .cache_manager
+        .get_query_result_cache()
+        .map(|cache| cache.len())
+        .unwrap_or_default()
+}
+
+async fn query_sum(ctx: &SessionContext, sql: &str) -> Result<i64> {
+    let batches = ctx.sql(sql).await?.collect().await?;
+    Ok(as_int64_array(batches[0].column(0))?.value(0))
+}
+
+fn mem_table(values: Vec<i64>) -> Result<Arc<MemTable>> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    let batch = RecordBatch::try_new(
+        Arc::clone(&schema),
+        vec![Arc::new(Int64Array::from(values))],
+    )?;
+    Ok(Arc::new(MemTable::try_new(schema, vec![vec![batch]])?))
+}
+
+#[tokio::test]
+async fn test_query_result_cache_disabled_by_default() -> Result<()> {
+    let ctx = SessionContext::new();
+    assert!(
+        ctx.runtime_env()
+            .cache_manager
+            .get_query_result_cache()
+            .is_none()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_result_cache_hit() -> Result<()> {
+    let ctx = context_with_result_cache()?;
+    ctx.register_table("t", mem_table(vec![1, 2])?)?;
+
+    let sql = "SELECT sum(a) FROM t";
+    assert_eq!(query_sum(&ctx, sql).await?, 3);
+    assert_eq!(cached_results(&ctx), 1);
+    assert_eq!(query_sum(&ctx, sql).await?, 3);
+    assert_eq!(cached_results(&ctx), 1);
+
+    // Replacing the table behind the back of the session context changes the
+    // table provider, so the cached result is not returned
+    let schema = ctx.catalog("datafusion").unwrap().schema("public").unwrap();
+    schema.deregister_table("t")?;
+    schema.register_table("t".to_string(), mem_table(vec![10, 20])?)?;
+    assert_eq!(query_sum(&ctx, sql).await?, 30);
+    assert_eq!(cached_results(&ctx), 2);
+
+    // Registering the table through the session context evicts its results
+    ctx.deregister_table("t")?;
+    ctx.register_table("t", mem_table(vec![100, 200])?)?;
+    assert_eq!(cached_results(&ctx), 0);
+    assert_eq!(query_sum(&ctx, sql).await?, 300);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_result_cache_shared_by_sessions() -> Result<()> {
+    let runtime = runtime_with_result_cache()?;
+    let ctx1 =
+        SessionContext::new_with_config_rt(SessionConfig::new(), Arc::clone(&runtime));
+    let ctx2 =
+        SessionContext::new_with_config_rt(SessionConfig::new(), Arc::clone(&runtime));
+    let ctx3 = SessionContext::new_with_config_rt(SessionConfig::new(), runtime);
+    let table = mem_table(vec![1, 2])?;
+    ctx1.register_table("t", Arc::clone(&table) as _)?;
+    ctx2.register_table("t", mem_table(vec![10, 20])?)?;
+    ctx3.register_table("t", table)?;
+
+    // Tables with the same name in different sessions do not share results
+    let sql = "SELECT sum(a) FROM t";
+    assert_eq!(query_sum(&ctx1, sql).await?, 3);
+    assert_eq!(query_sum(&ctx2, sql).await?, 30);
+    assert_eq!(cached_results(&ctx1), 2);
+    assert_eq!(query_sum(&ctx1, sql).await?, 3);
+    assert_eq!(query_sum(&ctx2, sql).await?, 30);
+
+    // Sessions reading the same table share its results
+    assert_eq!(query_sum(&ctx3, sql).await?, 3);
+    assert_eq!(cached_results(&ctx3), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_result_cache_invalidated_by_dml_and_ddl() -> Result<()> {
+    let ctx = context_with_result_cache()?;
+    ctx.sql("CREATE TABLE t (a BIGINT) AS VALUES (1), (2)")
+        .await?
+        .collect()
+        .await?;
+    ctx.sql("CREATE TABLE u (a BIGINT) AS VALUES (5)")
+        .await?
+        .collect()
+        .await?;
+
+    let sql_t = "SELECT sum(a) FROM t";
+    let sql_u = "SELECT sum(a) FROM u";
+    assert_eq!(query_sum(&ctx, sql_t).await?, 3);
+    assert_eq!(query_sum(&ctx, sql_u).await?, 5);
+    assert_eq!(cached_results(&ctx), 2);
+
+    ctx.sql("INSERT INTO t VALUES (3)").await?.collect().await?;
+    assert_eq!(query_sum(&ctx, sql_t).await?, 6);
+    assert_eq!(query_sum(&ctx, sql_u).await?, 5);
+
+    // Statements executed without `collect` are taken into account as well
+    let df = ctx.sql("INSERT INTO t VALUES (4)").await?;
+    df.execute_stream().await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(query_sum(&ctx, sql_t).await?, 10);
+    let df = ctx.sql("DELETE FROM t WHERE a = 1").await?;
+    df.execute_stream().await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(query_sum(&ctx, sql_t).await?, 9);
+    let df = ctx.sql("UPDATE t SET a = a * 2").await?;
+    df.execute_stream().await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(query_sum(&ctx, sql_t).await?, 18);
+
+    ctx.sql("DROP TABLE t").await?.collect().await?;
+    ctx.sql("CREATE TABLE t (a BIGINT) AS VALUES (10)")
+        .await?
+        .collect()
+        .await?;
+    assert_eq!(query_sum(&ctx, sql_t).await?, 10);
+    assert_eq!(query_sum(&ctx, sql_u).await?, 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_result_cache_bypassed() -> Result<()> {
+    let ctx = context_with_result_cache()?;
+    ctx.register_table("t", mem_table(vec![1, 2])?)?;
+
+    // Volatile functions
+    ctx.sql("SELECT a, random() FROM t")
+        .await?
+        .collect()
+        .await?;
+    // Views that are not inlined into the plan
+    ctx.sql("SELECT * FROM information_schema.tables")
+        .await?
+        .collect()
+        .await?;
+    // Statements
+    ctx.sql("EXPLAIN SELECT a FROM t").await?.collect().await?;
+    assert_eq!(cached_results(&ctx), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_result_cache_bypassed_for_external_tables() -> Result<()> {
+    let ctx = context_with_result_cache()?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("t.csv");
+    std::fs::write(&path, "a\n1\n2\n")?;
+    ctx.sql(&format!(
+        "CREATE EXTERNAL TABLE t (a BIGINT) STORED AS CSV LOCATION '{}' \
+         OPTIONS ('format.has_header' 'true')",
+        path.display()
+    ))
+    .await?
+    .collect()
+    .await?;
+
+    // The files of external tables can change at any time
+    let sql = "SELECT sum(a) FROM t";
+    assert_eq!(query_sum(&ctx, sql).await?, 3);
+    assert_eq!(cached_results(&ctx), 0);
+    std::fs::write(&path, "a\n10\n20\n")?;
+    assert_eq!(query_sum(&ctx, sql).await?, 30);
+    Ok(())
+}
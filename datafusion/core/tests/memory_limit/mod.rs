@@ -359,6 +359,26 @@ async fn oom_recursive_cte() {
         .await
 }
 
+#[tokio::test]
+async fn recursive_cte_spills_work_table() {
+    TestCase::new()
+        .with_query(
+            "WITH RECURSIVE nodes AS (
+            SELECT 1 as id
+            UNION ALL
+            SELECT UNNEST(RANGE(id+1, id+1000)) as id
+            FROM nodes
+            WHERE id < 10
+        )
+        SELECT * FROM nodes;",
+        )
+        .with_memory_limit(2_000)
+        .with_disk_manager_builder(DiskManagerBuilder::default())
+        .with_expected_success()
+        .run()
+        .await
+}
+
 #[tokio::test]
 async fn oom_parquet_sink() {
     let dir = tempfile::tempdir().unwrap();
@@ -17,8 +17,10 @@
 
 use std::sync::Arc;
 
-use arrow::array::{RecordBatch, record_batch};
-use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow::array::{
+    ArrayRef, Int32Array, RecordBatch, StringArray, StructArray, record_batch,
+};
+use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
 use bytes::{BufMut, BytesMut};
 use datafusion::assert_batches_eq;
 use datafusion::common::Result;
@@ -38,7 +40,7 @@ use datafusion_physical_expr_adapter::{
     PhysicalExprAdapterFactory,
 };
 use object_store::{ObjectStore, memory::InMemory, path::Path};
-use parquet::arrow::ArrowWriter;
+use parquet::arrow::{ArrowWriter, PARQUET_FIELD_ID_META_KEY};
 
 async fn write_parquet(batch: RecordBatch, store: Arc<dyn ObjectStore>, path: &str) {
     let mut out = BytesMut::new().writer();
@@ -464,3 +466,202 @@ async fn test_physical_expr_adapter_factory_reuse_across_tables() {
     ];
     assert_batches_eq!(expected, &batches);
 }
+
+fn field_with_id(name: &str, data_type: DataType, id: i32) -> Field {
+    Field::new(name, data_type, true).with_metadata(
+        [(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string())]
+            .into_iter()
+            .collect(),
+    )
+}
+
+/// Test resolving columns by their Parquet field IDs when
+/// `datafusion.execution.parquet.match_by_field_id` is enabled
+#[tokio::test]
+async fn test_parquet_match_by_field_id() {
+    // The file was written before columns and struct fields were renamed,
+    // reordered, and `extra` was dropped and re-added
+    let file_info_fields = Fields::from(vec![
+        field_with_id("postal", DataType::Int32, 7),
+        field_with_id("town", DataType::Utf8, 6),
+    ]);
+    let file_schema = Arc::new(Schema::new(vec![
+        field_with_id("label", DataType::Utf8, 2),
+        field_with_id("user_id", DataType::Int32, 1),
+        field_with_id("extra", DataType::Utf8, 4),
+        field_with_id("details", DataType::Struct(file_info_fields.clone()), 5),
+    ]));
+    let info = StructArray::new(
+        file_info_fields,
+        vec![
+            Arc::new(Int32Array::from(vec![75001, 10115, 1010])) as ArrayRef,
+            Arc::new(StringArray::from(vec!["Paris", "Berlin", "Vienna"])),
+        ],
+        None,
+    );
+    let batch = RecordBatch::try_new(
+        Arc::clone(&file_schema),
+        vec![
+            Arc::new(StringArray::from(vec!["a", "b", "c"])),
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            Arc::new(StringArray::from(vec!["x", "y", "z"])),
+            Arc::new(info),
+        ],
+    )
+    .unwrap();
+
+    let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+    let store_url = ObjectStoreUrl::parse("memory://").unwrap();
+    write_parquet(batch, store.clone(), "field_ids.parquet").await;
+
+    let table_schema = Arc::new(Schema::new(vec![
+        field_with_id("id", DataType::Int64, 1),
+        field_with_id("name", DataType::Utf8, 2),
+        field_with_id("extra", DataType::Utf8, 3),
+        field_with_id(
+            "info",
+            DataType::Struct(Fields::from(vec![
+                field_with_id("city", DataType::Utf8, 6),
+                field_with_id("zip", DataType::Int64, 7),
+            ])),
+            5,
+        ),
+    ]));
+
+    let mut cfg = SessionConfig::new()
+        .with_collect_statistics(false)
+        .with_parquet_pruning(false);
+    cfg.options_mut().execution.parquet.pushdown_filters = true;
+    cfg.options_mut().execution.parquet.match_by_field_id = true;
+    let ctx = SessionContext::new_with_config(cfg);
+    ctx.register_object_store(store_url.as_ref(), Arc::clone(&store));
+
+    let listing_table_config =
+        ListingTableConfig::new(ListingTableUrl::parse("memory:///").unwrap())
+            .infer_options(&ctx.state())
+            .await
+            .unwrap()
+            .with_schema(table_schema);
+    let table = ListingTable::try_new(listing_table_config).unwrap();
+    ctx.register_table("t", Arc::new(table)).unwrap();
+
+    let batches = ctx
+        .sql("SELECT id, name, extra, info FROM t WHERE id > 1 ORDER BY id")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+    let expected = [
+        "+----+------+-------+----------------------------+",
+        "| id | name | extra | info                       |",
+        "+----+------+-------+----------------------------+",
+        "| 2  | b    |       | {city: Berlin, zip: 10115} |",
+        "| 3  | c    |       | {city: Vienna, zip: 1010}  |",
+        "+----+------+-------+----------------------------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+
+    let batches = ctx
+        .sql("SELECT name FROM t WHERE info['city'] = 'Paris'")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+    #[rustfmt::skip]
+    let expected = [
+        "+------+",
+        "| name |",
+        "+------+",
+        "| a    |",
+        "+------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+}
+
+/// Test that file statistics are resolved by field ID when
+/// `datafusion.execution.parquet.match_by_field_id` is enabled, so that a
+/// column dropped and re-added with the same name is not pruned using the
+/// statistics of the dropped column
+#[tokio::test]
+async fn test_parquet_match_by_field_id_statistics() {
+    // The file was written before `a` was dropped and re-added with a new ID
+    let file_schema = Arc::new(Schema::new(vec![
+        field_with_id("id", DataType::Int32, 1),
+        field_with_id("a", DataType::Int32, 2),
+    ]));
+    let batch = RecordBatch::try_new(
+        Arc::clone(&file_schema),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2, 3])),
+            Arc::new(Int32Array::from(vec![10, 20, 30])),
+        ],
+    )
+    .unwrap();
+
+    let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+    let store_url = ObjectStoreUrl::parse("memory://").unwrap();
+    write_parquet(batch, store.clone(), "dropped.parquet").await;
+
+    let table_schema = Arc::new(Schema::new(vec![
+        field_with_id("id", DataType::Int32, 1),
+        field_with_id("a", DataType::Int32, 3),
+    ]));
+
+    let mut cfg = SessionConfig::new()
+        .with_collect_statistics(true)
+        .with_parquet_pruning(true);
+    cfg.options_mut().execution.parquet.match_by_field_id = true;
+    let ctx = SessionContext::new_with_config(cfg);
+    ctx.register_object_store(store_url.as_ref(), Arc::clone(&store));
+
+    let listing_table_config =
+        ListingTableConfig::new(ListingTableUrl::parse("memory:///").unwrap())
+            .infer_options(&ctx.state())
+            .await
+            .unwrap()
+            .with_schema(table_schema);
+    let table = ListingTable::try_new(listing_table_config).unwrap();
+    ctx.register_table("t", Arc::new(table)).unwrap();
+
+    let batches = ctx
+        .sql("SELECT id, a FROM t WHERE a IS NULL ORDER BY id")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+    #[rustfmt::skip]
+    let expected = [
+        "+----+---+",
+        "| id | a |",
+        "+----+---+",
+        "| 1  |   |",
+        "| 2  |   |",
+        "| 3  |   |",
+        "+----+---+",
+    ];
+    assert_batches_eq!(expected, &batches);
+
+    let batches = ctx
+        .sql("SELECT count(*) FROM t WHERE a > 0")
+        .await
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+    #[rustfmt::skip]
+    let expected = [
+        "+----------+",
+        "| count(*) |",
+        "+----------+",
+        "| 0        |",
+        "+----------+",
+    ];
+    assert_batches_eq!(expected, &batches);
+}
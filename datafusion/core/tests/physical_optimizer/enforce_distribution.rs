@@ -52,7 +52,9 @@ use datafusion_physical_expr_common::sort_expr::{
 use datafusion_physical_optimizer::PhysicalOptimizerRule;
 use datafusion_physical_optimizer::enforce_distribution::*;
 use datafusion_physical_optimizer::enforce_sorting::EnforceSorting;
-use datafusion_physical_optimizer::output_requirements::OutputRequirements;
+use datafusion_physical_optimizer::output_requirements::{
+    OutputRequirementExec, OutputRequirements,
+};
 use datafusion_physical_plan::aggregates::{
     AggregateExec, AggregateMode, PhysicalGroupBy,
 };
@@ -67,8 +69,8 @@ use datafusion_physical_plan::projection::{ProjectionExec, ProjectionExpr};
 use datafusion_physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
 use datafusion_physical_plan::union::UnionExec;
 use datafusion_physical_plan::{
-    DisplayAs, DisplayFormatType, ExecutionPlanProperties, PlanProperties, Statistics,
-    displayable,
+    DisplayAs, DisplayFormatType, Distribution, ExecutionPlanProperties, PlanProperties,
+    Statistics, displayable,
 };
 use insta::Settings;
 
@@ -3675,3 +3677,40 @@ fn test_replace_order_preserving_variants_with_fetch() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn range_partitioned_requirement() -> Result<()> {
+    let schema = schema();
+    let ordering: LexOrdering = [PhysicalSortExpr {
+        expr: col("a", &schema)?,
+        options: SortOptions::default(),
+    }]
+    .into();
+    let physical_plan = Arc::new(OutputRequirementExec::new(
+        parquet_exec_multiple(),
+        None,
+        Distribution::RangePartitioned(ordering),
+        None,
+    ));
+
+    let mut config = ConfigOptions::new();
+    config.execution.target_partitions = 10;
+    config.optimizer.repartition_file_scans = false;
+    let dist_plan = EnforceDistribution::new().optimize(physical_plan, &config)?;
+    // The requirement is satisfied by a range repartitioning
+    assert_plan!(dist_plan, @r"
+    OutputRequirementExec: order_by=[], dist_by=RangePartitioned[a@0 ASC]
+      RepartitionExec: partitioning=Range([a@0 ASC], 10), input_partitions=2
+        DataSourceExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], file_type=parquet
+    ");
+
+    // An existing range partitioning on the same ordering is kept
+    let dist_plan = EnforceDistribution::new().optimize(dist_plan, &config)?;
+    assert_plan!(dist_plan, @r"
+    OutputRequirementExec: order_by=[], dist_by=RangePartitioned[a@0 ASC]
+      RepartitionExec: partitioning=Range([a@0 ASC], 10), input_partitions=2
+        DataSourceExec: file_groups={2 groups: [[x], [y]]}, projection=[a, b, c, d, e], file_type=parquet
+    ");
+
+    Ok(())
+}
@@ -24,7 +24,8 @@ use crate::physical_optimizer::test_utils::{
     coalesce_partitions_exec, create_test_schema, create_test_schema2,
     create_test_schema3, filter_exec, global_limit_exec, hash_join_exec,
     local_limit_exec, memory_exec, parquet_exec, parquet_exec_with_sort, projection_exec,
-    repartition_exec, sort_exec, sort_exec_with_fetch, sort_expr, sort_expr_options,
+    repartition_exec, sort_exec, sort_exec_with_fetch,
+    sort_exec_with_preserve_partitioning, sort_expr, sort_expr_options,
     sort_merge_join_exec, sort_preserving_merge_exec,
     sort_preserving_merge_exec_with_fetch, spr_repartition_exec, stream_exec_ordered,
     union_exec,
@@ -97,6 +98,7 @@ fn csv_exec_sorted(
 pub(crate) struct EnforceSortingTest {
     plan: Arc<dyn ExecutionPlan>,
     repartition_sorts: bool,
+    range_repartition_sorts: bool,
 }
 
 impl EnforceSortingTest {
@@ -104,6 +106,7 @@ impl EnforceSortingTest {
         Self {
             plan,
             repartition_sorts: false,
+            range_repartition_sorts: false,
         }
     }
 
@@ -113,11 +116,22 @@ impl EnforceSortingTest {
         self
     }
 
+    /// Set whether to range partition the inputs of repartitioned sorts
+    pub(crate) fn with_range_repartition_sorts(
+        mut self,
+        range_repartition_sorts: bool,
+    ) -> Self {
+        self.range_repartition_sorts = range_repartition_sorts;
+        self
+    }
+
     /// Runs the enforce sorting test and returns a string with the input and
     /// optimized plan as strings for snapshot comparison using insta
     pub(crate) fn run(&self) -> String {
         let mut config = ConfigOptions::new();
         config.optimizer.repartition_sorts = self.repartition_sorts;
+        config.optimizer.range_repartition_sorts = self.range_repartition_sorts;
+        config.execution.target_partitions = 10;
 
         // This file has 4 rules that use tree node, apply these rules as in the
         // EnforceSorting::optimize implementation
@@ -269,6 +283,61 @@ async fn test_do_not_remove_sort_with_limit() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_range_repartition_sorts() -> Result<()> {
+    let schema = create_test_schema()?;
+    let source = parquet_exec(schema.clone());
+    let ordering: LexOrdering = [sort_expr("nullable_col", &schema)].into();
+    let repartition = repartition_exec(source);
+    let sort = sort_exec_with_preserve_partitioning(ordering.clone(), repartition);
+    let physical_plan = sort_preserving_merge_exec(ordering, sort);
+
+    let test = EnforceSortingTest::new(physical_plan)
+        .with_repartition_sorts(true)
+        .with_range_repartition_sorts(true);
+    assert_snapshot!(test.run(), @r"
+    Input Plan:
+    SortPreservingMergeExec: [nullable_col@0 ASC]
+      SortExec: expr=[nullable_col@0 ASC], preserve_partitioning=[true]
+        RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1
+          DataSourceExec: file_groups={1 group: [[x]]}, projection=[nullable_col, non_nullable_col], file_type=parquet
+
+    Optimized Plan:
+    SortPreservingMergeExec: [nullable_col@0 ASC]
+      SortExec: expr=[nullable_col@0 ASC], preserve_partitioning=[true]
+        RepartitionExec: partitioning=Range([nullable_col@0 ASC], 10), input_partitions=1
+          DataSourceExec: file_groups={1 group: [[x]]}, projection=[nullable_col, non_nullable_col], file_type=parquet
+    ");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_range_repartition_sorts_with_fetch() -> Result<()> {
+    let schema = create_test_schema()?;
+    let source = parquet_exec(schema.clone());
+    let ordering: LexOrdering = [sort_expr("nullable_col", &schema)].into();
+    let repartition = repartition_exec(source);
+    let sort = Arc::new(
+        SortExec::new(ordering.clone(), repartition)
+            .with_preserve_partitioning(true)
+            .with_fetch(Some(10)),
+    );
+    let physical_plan = sort_preserving_merge_exec(ordering, sort);
+
+    // The top-k of every partition is merged instead
+    let test = EnforceSortingTest::new(physical_plan)
+        .with_repartition_sorts(true)
+        .with_range_repartition_sorts(true);
+    assert_snapshot!(test.run(), @r"
+    Input / Optimized Plan:
+    SortPreservingMergeExec: [nullable_col@0 ASC]
+      SortExec: TopK(fetch=10), expr=[nullable_col@0 ASC], preserve_partitioning=[true]
+        RepartitionExec: partitioning=RoundRobinBatch(10), input_partitions=1
+          DataSourceExec: file_groups={1 group: [[x]]}, projection=[nullable_col, non_nullable_col], file_type=parquet
+    ");
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_union_inputs_sorted() -> Result<()> {
     let schema = create_test_schema()?;
@@ -0,0 +1,263 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::physical_optimizer::join_selection::StatisticsExec;
+
+use arrow::datatypes::{DataType, Field, Schema};
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::stats::Precision;
+use datafusion_common::{ColumnStatistics, JoinType, NullEquality, Result, Statistics};
+use datafusion_physical_expr::expressions::col;
+use datafusion_physical_optimizer::PhysicalOptimizerRule;
+use datafusion_physical_optimizer::join_reorder::JoinReorder;
+use datafusion_physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion_physical_plan::projection::{ProjectionExec, ProjectionExpr};
+use datafusion_physical_plan::{ExecutionPlan, displayable};
+
+use insta::assert_snapshot;
+
+/// Creates an input with the given row count, and the given columns along
+/// with their distinct counts
+fn input(
+    num_rows: Option<usize>,
+    columns: &[(&str, Option<usize>)],
+) -> Arc<StatisticsExec> {
+    let schema = Schema::new(
+        columns
+            .iter()
+            .map(|(name, _)| Field::new(*name, DataType::Int32, false))
+            .collect::<Vec<_>>(),
+    );
+    let column_statistics = columns
+        .iter()
+        .map(|(_, distinct_count)| ColumnStatistics {
+            distinct_count: distinct_count.map_or(Precision::Absent, Precision::Inexact),
+            ..ColumnStatistics::new_unknown()
+        })
+        .collect();
+    let statistics = Statistics {
+        num_rows: num_rows.map_or(Precision::Absent, Precision::Inexact),
+        total_byte_size: Precision::Absent,
+        column_statistics,
+    };
+    Arc::new(StatisticsExec::new(statistics, schema))
+}
+
+fn inner_join(
+    left: Arc<dyn ExecutionPlan>,
+    right: Arc<dyn ExecutionPlan>,
+    on: &[(&str, &str)],
+) -> Result<Arc<dyn ExecutionPlan>> {
+    let on = on
+        .iter()
+        .map(|(l, r)| Ok((col(l, &left.schema())?, col(r, &right.schema())?)))
+        .collect::<Result<_>>()?;
+    Ok(Arc::new(HashJoinExec::try_new(
+        left,
+        right,
+        on,
+        None,
+        &JoinType::Inner,
+        None,
+        PartitionMode::Auto,
+        NullEquality::NullEqualsNothing,
+    )?))
+}
+
+fn config(dp_threshold: usize) -> ConfigOptions {
+    let mut config = ConfigOptions::new();
+    config.optimizer.enable_join_reordering = true;
+    config.optimizer.join_reordering_dp_threshold = dp_threshold;
+    config
+}
+
+fn optimize(plan: &Arc<dyn ExecutionPlan>, config: &ConfigOptions) -> Result<String> {
+    let optimized = JoinReorder::new().optimize(Arc::clone(plan), config)?;
+    assert_eq!(optimized.schema(), plan.schema());
+    Ok(displayable(optimized.as_ref())
+        .indent(true)
+        .to_string()
+        .trim()
+        .to_string())
+}
+
+/// `(fact JOIN dim1) JOIN dim2`, where joining `dim2` first filters out most
+/// of the rows of `fact`
+fn star_join() -> Result<Arc<dyn ExecutionPlan>> {
+    let fact = input(
+        Some(1_000_000),
+        &[("fk1", Some(1_000)), ("fk2", Some(10_000))],
+    );
+    let dim1 = input(Some(100), &[("pk1", Some(100))]);
+    let dim2 = input(Some(10), &[("pk2", Some(10))]);
+    let join = inner_join(fact, dim1, &[("fk1", "pk1")])?;
+    inner_join(join, dim2, &[("fk2", "pk2")])
+}
+
+#[test]
+fn reorder_star_join() -> Result<()> {
+    let plan = star_join()?;
+    assert_snapshot!(optimize(&plan, &config(10))?, @r"
+    ProjectionExec: expr=[fk1@2 as fk1, fk2@3 as fk2, pk1@0 as pk1, pk2@1 as pk2]
+      HashJoinExec: mode=Auto, join_type=Inner, on=[(pk1@0, fk1@1)]
+        StatisticsExec: col_count=1, row_count=Inexact(100)
+        HashJoinExec: mode=Auto, join_type=Inner, on=[(pk2@0, fk2@1)]
+          StatisticsExec: col_count=1, row_count=Inexact(10)
+          StatisticsExec: col_count=2, row_count=Inexact(1000000)
+    ");
+    Ok(())
+}
+
+#[test]
+fn reorder_star_join_greedy() -> Result<()> {
+    let plan = star_join()?;
+    assert_snapshot!(optimize(&plan, &config(2))?, @r"
+    ProjectionExec: expr=[fk1@2 as fk1, fk2@3 as fk2, pk1@0 as pk1, pk2@1 as pk2]
+      HashJoinExec: mode=Auto, join_type=Inner, on=[(pk1@0, fk1@1)]
+        StatisticsExec: col_count=1, row_count=Inexact(100)
+        HashJoinExec: mode=Auto, join_type=Inner, on=[(pk2@0, fk2@1)]
+          StatisticsExec: col_count=1, row_count=Inexact(10)
+          StatisticsExec: col_count=2, row_count=Inexact(1000000)
+    ");
+    Ok(())
+}
+
+/// `(a JOIN b) JOIN c` where `a JOIN b` explodes, but `b JOIN c` is small
+#[test]
+fn reorder_chain_join() -> Result<()> {
+    let a = input(Some(1_000), &[("a_x", Some(10))]);
+    let b = input(Some(1_000), &[("b_x", Some(10)), ("b_y", Some(1_000))]);
+    let c = input(Some(10), &[("c_y", Some(10))]);
+    let join = inner_join(a, b, &[("a_x", "b_x")])?;
+    let plan = inner_join(join, c, &[("b_y", "c_y")])?;
+
+    assert_snapshot!(optimize(&plan, &config(10))?, @r"
+    ProjectionExec: expr=[a_x@3 as a_x, b_x@1 as b_x, b_y@2 as b_y, c_y@0 as c_y]
+      HashJoinExec: mode=Auto, join_type=Inner, on=[(b_x@1, a_x@0)]
+        HashJoinExec: mode=Auto, join_type=Inner, on=[(c_y@0, b_y@1)]
+          StatisticsExec: col_count=1, row_count=Inexact(10)
+          StatisticsExec: col_count=2, row_count=Inexact(1000)
+        StatisticsExec: col_count=1, row_count=Inexact(1000)
+    ");
+    Ok(())
+}
+
+/// Projections of columns between the joins do not stop the reordering
+#[test]
+fn reorder_through_projection() -> Result<()> {
+    let fact = input(
+        Some(1_000_000),
+        &[("fk1", Some(1_000)), ("fk2", Some(10_000))],
+    );
+    let dim1 = input(Some(100), &[("pk1", Some(100))]);
+    let dim2 = input(Some(10), &[("pk2", Some(10))]);
+    let join = inner_join(fact, dim1, &[("fk1", "pk1")])?;
+    let exprs = vec![
+        ProjectionExpr::new(col("pk1", &join.schema())?, "id".to_string()),
+        ProjectionExpr::new(col("fk2", &join.schema())?, "fk2".to_string()),
+    ];
+    let projection = Arc::new(ProjectionExec::try_new(exprs, join)?);
+    let plan = inner_join(projection, dim2, &[("fk2", "pk2")])?;
+
+    assert_snapshot!(optimize(&plan, &config(10))?, @r"
+    ProjectionExec: expr=[pk1@0 as id, fk2@3 as fk2, pk2@1 as pk2]
+      HashJoinExec: mode=Auto, join_type=Inner, on=[(pk1@0, fk1@1)]
+        StatisticsExec: col_count=1, row_count=Inexact(100)
+        HashJoinExec: mode=Auto, join_type=Inner, on=[(pk2@0, fk2@1)]
+          StatisticsExec: col_count=1, row_count=Inexact(10)
+          StatisticsExec: col_count=2, row_count=Inexact(1000000)
+    ");
+    Ok(())
+}
+
+#[test]
+fn keep_order_without_row_counts() -> Result<()> {
+    let fact = input(None, &[("fk1", None), ("fk2", None)]);
+    let dim1 = input(Some(100), &[("pk1", Some(100))]);
+    let dim2 = input(Some(10), &[("pk2", Some(10))]);
+    let join = inner_join(fact, dim1, &[("fk1", "pk1")])?;
+    let plan = inner_join(join, dim2, &[("fk2", "pk2")])?;
+
+    let optimized = JoinReorder::new().optimize(Arc::clone(&plan), &config(10))?;
+    assert!(Arc::ptr_eq(&plan, &optimized));
+    Ok(())
+}
+
+#[test]
+fn keep_order_when_disabled() -> Result<()> {
+    let plan = star_join()?;
+    let optimized =
+        JoinReorder::new().optimize(Arc::clone(&plan), &ConfigOptions::new())?;
+    assert!(Arc::ptr_eq(&plan, &optimized));
+    Ok(())
+}
+
+#[test]
+fn keep_order_of_outer_joins() -> Result<()> {
+    let fact = input(
+        Some(1_000_000),
+        &[("fk1", Some(1_000)), ("fk2", Some(10_000))],
+    );
+    let dim1 = input(Some(100), &[("pk1", Some(100))]);
+    let dim2 = input(Some(10), &[("pk2", Some(10))]);
+    let join = Arc::new(HashJoinExec::try_new(
+        Arc::clone(&fact) as _,
+        Arc::clone(&dim1) as _,
+        vec![(col("fk1", &fact.schema())?, col("pk1", &dim1.schema())?)],
+        None,
+        &JoinType::Left,
+        None,
+        PartitionMode::Auto,
+        NullEquality::NullEqualsNothing,
+    )?);
+    let plan = inner_join(join, dim2, &[("fk2", "pk2")])?;
+
+    let optimized = JoinReorder::new().optimize(Arc::clone(&plan), &config(10))?;
+    assert!(Arc::ptr_eq(&plan, &optimized));
+    Ok(())
+}
+
+/// Join chains below other operators are reordered as well
+#[test]
+fn reorder_nested_join_graph() -> Result<()> {
+    let plan = star_join()?;
+    let other = input(Some(5), &[("id", Some(5))]);
+    let semi_join = Arc::new(HashJoinExec::try_new(
+        Arc::clone(&plan),
+        Arc::clone(&other) as _,
+        vec![(col("pk2", &plan.schema())?, col("id", &other.schema())?)],
+        None,
+        &JoinType::LeftSemi,
+        None,
+        PartitionMode::Auto,
+        NullEquality::NullEqualsNothing,
+    )?) as Arc<dyn ExecutionPlan>;
+
+    assert_snapshot!(optimize(&semi_join, &config(10))?, @r"
+    HashJoinExec: mode=Auto, join_type=LeftSemi, on=[(pk2@3, id@0)]
+      ProjectionExec: expr=[fk1@2 as fk1, fk2@3 as fk2, pk1@0 as pk1, pk2@1 as pk2]
+        HashJoinExec: mode=Auto, join_type=Inner, on=[(pk1@0, fk1@1)]
+          StatisticsExec: col_count=1, row_count=Inexact(100)
+          HashJoinExec: mode=Auto, join_type=Inner, on=[(pk2@0, fk2@1)]
+            StatisticsExec: col_count=1, row_count=Inexact(10)
+            StatisticsExec: col_count=2, row_count=Inexact(1000000)
+      StatisticsExec: col_count=1, row_count=Inexact(5)
+    ");
+    Ok(())
+}
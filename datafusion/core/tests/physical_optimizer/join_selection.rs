@@ -42,7 +42,9 @@ use datafusion_physical_plan::ExecutionPlanProperties;
 use datafusion_physical_plan::displayable;
 use datafusion_physical_plan::joins::utils::ColumnIndex;
 use datafusion_physical_plan::joins::utils::JoinFilter;
-use datafusion_physical_plan::joins::{HashJoinExec, NestedLoopJoinExec, PartitionMode};
+use datafusion_physical_plan::joins::{
+    AdaptiveHashJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode,
+};
 use datafusion_physical_plan::projection::ProjectionExec;
 use datafusion_physical_plan::{
     DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties,
@@ -889,6 +891,73 @@ async fn test_join_selection_partitioned() {
     check_join_partition_mode(big, empty, join_on, false, PartitionMode::Partitioned);
 }
 
+#[tokio::test]
+async fn test_join_selection_adaptive() {
+    let big = Arc::new(StatisticsExec::new(
+        big_statistics(),
+        Schema::new(vec![Field::new("big_col", DataType::Int32, false)]),
+    ));
+
+    let small = Arc::new(StatisticsExec::new(
+        small_statistics(),
+        Schema::new(vec![Field::new("small_col", DataType::Int32, false)]),
+    ));
+
+    let empty = Arc::new(StatisticsExec::new(
+        empty_statistics(),
+        Schema::new(vec![Field::new("empty_col", DataType::Int32, false)]),
+    ));
+
+    let mut config = ConfigOptions::new();
+    config.optimizer.adaptive_join_selection = true;
+    config.execution.target_partitions = 4;
+
+    let join = |left: Arc<StatisticsExec>, right: Arc<StatisticsExec>| {
+        let on = vec![(
+            col(left.schema().field(0).name(), &left.schema()).unwrap(),
+            col(right.schema().field(0).name(), &right.schema()).unwrap(),
+        )];
+        Arc::new(
+            HashJoinExec::try_new(
+                left,
+                right,
+                on,
+                None,
+                &JoinType::Inner,
+                None,
+                PartitionMode::Auto,
+                NullEquality::NullEqualsNothing,
+            )
+            .unwrap(),
+        )
+    };
+
+    // Without statistics for an input, the join is chosen at execution time
+    let optimized_join = JoinSelection::new()
+        .optimize(join(Arc::clone(&empty), Arc::clone(&small)), &config)
+        .unwrap();
+    let adaptive_join = optimized_join
+        .as_any()
+        .downcast_ref::<AdaptiveHashJoinExec>()
+        .expect("The join should be adaptive");
+    assert_eq!(adaptive_join.partitions(), 4);
+    assert_eq!(*adaptive_join.join().partition_mode(), PartitionMode::Auto);
+    assert_eq!(
+        optimized_join.schema(),
+        join(empty, Arc::clone(&small)).schema()
+    );
+
+    // With statistics for both inputs, the join is chosen from them
+    let optimized_join = JoinSelection::new()
+        .optimize(join(small, big), &config)
+        .unwrap();
+    let hash_join = optimized_join
+        .as_any()
+        .downcast_ref::<HashJoinExec>()
+        .expect("The join should not be adaptive");
+    assert_eq!(*hash_join.partition_mode(), PartitionMode::CollectLeft);
+}
+
 fn check_join_partition_mode(
     left: Arc<StatisticsExec>,
     right: Arc<StatisticsExec>,
@@ -26,6 +26,7 @@ mod enforce_sorting;
 mod enforce_sorting_monotonicity;
 #[expect(clippy::needless_pass_by_value)]
 mod filter_pushdown;
+mod join_reorder;
 mod join_selection;
 #[expect(clippy::needless_pass_by_value)]
 mod limit_pushdown;
@@ -17,7 +17,8 @@
 
 //! [`ArrowFormat`]: Apache Arrow [`FileFormat`] abstractions
 //!
-//! Works with files following the [Arrow IPC format](https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format)
+//! Works with files following the [Arrow IPC file format](https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format)
+//! or the [Arrow IPC streaming format](https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format)
 
 use std::any::Any;
 use std::collections::HashMap;
@@ -25,17 +26,21 @@ use std::fmt::{self, Debug};
 use std::io::{Seek, SeekFrom};
 use std::sync::Arc;
 
+use arrow::array::RecordBatch;
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::error::ArrowError;
 use arrow::ipc::convert::fb_to_schema;
 use arrow::ipc::reader::{FileReader, StreamReader};
-use arrow::ipc::writer::IpcWriteOptions;
+use arrow::ipc::writer::{FileWriter, IpcWriteOptions, StreamWriter};
 use arrow::ipc::{CompressionType, root_as_message};
+use datafusion_common::config::{
+    ArrowIpcFormat, ArrowOptions, ConfigField, ConfigFileType,
+};
 use datafusion_common::error::Result;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{
-    DEFAULT_ARROW_EXTENSION, DataFusionError, GetExt, Statistics,
-    internal_datafusion_err, not_impl_err,
+    DEFAULT_ARROW_EXTENSION, DEFAULT_ARROW_STREAM_EXTENSION, DataFusionError, GetExt,
+    Statistics, internal_datafusion_err, not_impl_err,
 };
 use datafusion_common_runtime::{JoinSet, SpawnedTask};
 use datafusion_datasource::TableSchema;
@@ -76,26 +81,52 @@ const BUFFER_FLUSH_BYTES: usize = 1024000;
 
 /// Factory struct used to create [`ArrowFormat`]
 #[derive(Default, Debug)]
-pub struct ArrowFormatFactory;
+pub struct ArrowFormatFactory {
+    /// the options carried by format factory
+    pub options: Option<ArrowOptions>,
+}
 
 impl ArrowFormatFactory {
     /// Creates an instance of [ArrowFormatFactory]
     pub fn new() -> Self {
-        Self {}
+        Self { options: None }
+    }
+
+    /// Creates an instance of [ArrowFormatFactory] with customized default options
+    pub fn new_with_options(options: ArrowOptions) -> Self {
+        Self {
+            options: Some(options),
+        }
     }
 }
 
 impl FileFormatFactory for ArrowFormatFactory {
     fn create(
         &self,
-        _state: &dyn Session,
-        _format_options: &HashMap<String, String>,
+        state: &dyn Session,
+        format_options: &HashMap<String, String>,
     ) -> Result<Arc<dyn FileFormat>> {
-        Ok(Arc::new(ArrowFormat))
+        let arrow_options = match &self.options {
+            None => {
+                let mut table_options = state.default_table_options();
+                table_options.set_config_format(ConfigFileType::ARROW);
+                table_options.alter_with_string_hash_map(format_options)?;
+                table_options.arrow
+            }
+            Some(arrow_options) => {
+                let mut arrow_options = arrow_options.clone();
+                for (k, v) in format_options {
+                    arrow_options.set(k, v)?;
+                }
+                arrow_options
+            }
+        };
+
+        Ok(Arc::new(ArrowFormat::default().with_options(arrow_options)))
     }
 
     fn default(&self) -> Arc<dyn FileFormat> {
-        Arc::new(ArrowFormat)
+        Arc::new(ArrowFormat::default())
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -111,8 +142,36 @@ impl GetExt for ArrowFormatFactory {
 }
 
 /// Arrow [`FileFormat`] implementation.
+///
+/// Files in both the IPC file and streaming formats are read. The format is
+/// detected from the leading bytes of the first file of a scan, so all the
+/// files of a scan must use the same format. [`ArrowOptions::format`]
+/// selects the format of written files, and their extension: `.arrow` for the
+/// file format and `.arrows` for the streaming format.
 #[derive(Default, Debug)]
-pub struct ArrowFormat;
+pub struct ArrowFormat {
+    options: ArrowOptions,
+}
+
+impl ArrowFormat {
+    /// Set Arrow options
+    pub fn with_options(mut self, options: ArrowOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Retrieve Arrow options
+    pub fn options(&self) -> &ArrowOptions {
+        &self.options
+    }
+
+    /// Set the IPC format of written files
+    /// - defaults to [`ArrowIpcFormat::File`]
+    pub fn with_ipc_format(mut self, format: ArrowIpcFormat) -> Self {
+        self.options.format = format;
+        self
+    }
+}
 
 #[async_trait]
 impl FileFormat for ArrowFormat {
@@ -121,7 +180,11 @@ impl FileFormat for ArrowFormat {
     }
 
     fn get_ext(&self) -> String {
-        ArrowFormatFactory::new().get_ext()
+        match self.options.format {
+            ArrowIpcFormat::File => ArrowFormatFactory::new().get_ext(),
+            // Removes the dot, i.e. ".arrows" -> "arrows"
+            ArrowIpcFormat::Stream => DEFAULT_ARROW_STREAM_EXTENSION[1..].to_string(),
+        }
     }
 
     fn get_ext_with_compression(
@@ -138,7 +201,10 @@ impl FileFormat for ArrowFormat {
     }
 
     fn compression_type(&self) -> Option<FileCompressionType> {
-        None
+        // Arrow files are not compressed as a whole. Reporting them as
+        // uncompressed lets listing tables glob on the extension of the
+        // configured IPC format
+        Some(FileCompressionType::UNCOMPRESSED)
     }
 
     async fn infer_schema(
@@ -239,28 +305,77 @@ impl FileFormat for ArrowFormat {
         conf: FileSinkConfig,
         order_requirements: Option<LexRequirement>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        if conf.insert_op != InsertOp::Append {
-            return not_impl_err!("Overwrites are not implemented yet for Arrow format");
+        if conf.insert_op == InsertOp::Replace {
+            return not_impl_err!(
+                "{} is not implemented yet for Arrow format",
+                conf.insert_op
+            );
         }
 
-        let sink = Arc::new(ArrowFileSink::new(conf));
+        let sink = Arc::new(ArrowFileSink::new(conf, self.options.format));
 
         Ok(Arc::new(DataSinkExec::new(input, sink, order_requirements)) as _)
     }
 
     fn file_source(&self, table_schema: TableSchema) -> Arc<dyn FileSource> {
-        Arc::new(ArrowSource::new_file_source(table_schema))
+        match self.options.format {
+            ArrowIpcFormat::File => Arc::new(ArrowSource::new_file_source(table_schema)),
+            ArrowIpcFormat::Stream => {
+                Arc::new(ArrowSource::new_stream_file_source(table_schema))
+            }
+        }
+    }
+}
+
+/// Writes record batches in either of the Arrow IPC formats
+enum IpcWriter {
+    File(FileWriter<SharedBuffer>),
+    Stream(StreamWriter<SharedBuffer>),
+}
+
+impl IpcWriter {
+    fn try_new(
+        format: ArrowIpcFormat,
+        buffer: SharedBuffer,
+        schema: &Schema,
+        options: IpcWriteOptions,
+    ) -> Result<Self> {
+        Ok(match format {
+            ArrowIpcFormat::File => {
+                Self::File(FileWriter::try_new_with_options(buffer, schema, options)?)
+            }
+            ArrowIpcFormat::Stream => {
+                Self::Stream(StreamWriter::try_new_with_options(buffer, schema, options)?)
+            }
+        })
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        match self {
+            Self::File(writer) => writer.write(batch)?,
+            Self::Stream(writer) => writer.write(batch)?,
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        match self {
+            Self::File(writer) => writer.finish()?,
+            Self::Stream(writer) => writer.finish()?,
+        }
+        Ok(())
     }
 }
 
 /// Implements [`FileSink`] for Arrow IPC files
 struct ArrowFileSink {
     config: FileSinkConfig,
+    format: ArrowIpcFormat,
 }
 
 impl ArrowFileSink {
-    fn new(config: FileSinkConfig) -> Self {
-        Self { config }
+    fn new(config: FileSinkConfig, format: ArrowIpcFormat) -> Self {
+        Self { config, format }
     }
 }
 
@@ -285,7 +400,8 @@ impl FileSink for ArrowFileSink {
                 .try_with_compression(Some(CompressionType::LZ4_FRAME))?;
         while let Some((path, mut rx)) = file_stream_rx.recv().await {
             let shared_buffer = SharedBuffer::new(INITIAL_BUFFER_BYTES);
-            let mut arrow_writer = arrow_ipc::writer::FileWriter::try_new_with_options(
+            let mut arrow_writer = IpcWriter::try_new(
+                self.format,
                 shared_buffer.clone(),
                 &get_writer_schema(&self.config),
                 ipc_options.clone(),
@@ -345,13 +461,23 @@ impl FileSink for ArrowFileSink {
             .join_unwind()
             .await
             .map_err(|e| DataFusionError::ExecutionJoin(Box::new(e)))??;
+
+        // The written files have fresh names, so the files that made up the
+        // table before the write can be removed once all of them are complete
+        if self.config.insert_op == InsertOp::Overwrite {
+            for file in self.config.file_group.iter() {
+                object_store.delete(&file.object_meta.location).await?;
+            }
+        }
         Ok(row_count as u64)
     }
 }
 
 impl Debug for ArrowFileSink {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("ArrowFileSink").finish()
+        f.debug_struct("ArrowFileSink")
+            .field("format", &self.format)
+            .finish()
     }
 }
 
@@ -548,6 +674,8 @@ async fn is_object_in_arrow_ipc_file_format(
 mod tests {
     use super::*;
 
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
     use chrono::DateTime;
     use datafusion_common::DFSchema;
     use datafusion_common::config::TableOptions;
@@ -652,7 +780,7 @@ mod tests {
                 version: None,
             };
 
-            let arrow_format = ArrowFormat {};
+            let arrow_format = ArrowFormat::default();
             let expected = vec!["f0: Int64", "f1: Utf8", "f2: Boolean"];
 
             // Test chunk sizes where too small so we keep having to read more bytes
@@ -696,7 +824,7 @@ mod tests {
                 version: None,
             };
 
-            let arrow_format = ArrowFormat {};
+            let arrow_format = ArrowFormat::default();
 
             let store = Arc::new(ChunkedStore::new(in_memory_store.clone(), 7));
             let err = arrow_format
@@ -778,4 +906,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_ext() {
+        assert_eq!(ArrowFormat::default().get_ext(), "arrow");
+        let format = ArrowFormat::default().with_ipc_format(ArrowIpcFormat::Stream);
+        assert_eq!(format.get_ext(), "arrows");
+        assert_eq!(
+            format
+                .get_ext_with_compression(&FileCompressionType::UNCOMPRESSED)
+                .unwrap(),
+            "arrows"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ipc_writer_stream_format() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])),
+            ],
+        )?;
+
+        let shared_buffer = SharedBuffer::new(INITIAL_BUFFER_BYTES);
+        let ipc_options = IpcWriteOptions::default()
+            .try_with_compression(Some(CompressionType::LZ4_FRAME))?;
+        let mut writer = IpcWriter::try_new(
+            ArrowIpcFormat::Stream,
+            shared_buffer.clone(),
+            &schema,
+            ipc_options,
+        )?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        let bytes = shared_buffer.buffer.try_lock().unwrap().clone();
+
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("test.arrows");
+        store.put(&path, bytes.clone().into()).await?;
+        let is_file = is_object_in_arrow_ipc_file_format(store.clone(), &path).await?;
+        assert!(!is_file, "Should write stream format (not file)");
+
+        let reader = StreamReader::try_new(bytes.as_slice(), None)?;
+        assert_eq!(reader.schema(), schema);
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+        assert_eq!(batches, vec![batch]);
+        Ok(())
+    }
 }
@@ -0,0 +1,424 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::Decimal;
+use apache_avro::types::Value;
+use arrow::array::{Array, ArrayRef, AsArray, OffsetSizeTrait, RecordBatch};
+use arrow::compute::cast;
+use arrow::datatypes::{
+    ArrowPrimitiveType, DataType, Date32Type, Date64Type, Decimal32Type, Decimal64Type,
+    Decimal128Type, Decimal256Type, Field, Float16Type, Float32Type, Float64Type,
+    Int8Type, Int16Type, Int32Type, Int64Type, Time32MillisecondType, Time32SecondType,
+    Time64MicrosecondType, Time64NanosecondType, TimeUnit, TimestampMicrosecondType,
+    TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt8Type,
+    UInt16Type, UInt32Type, UInt64Type,
+};
+use datafusion_common::{Result, exec_datafusion_err, not_impl_err};
+
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+/// Converts the rows of a record batch to avro records matching the schema
+/// returned by [`to_avro_schema`](super::to_avro_schema) for the batch schema
+pub fn to_avro_records(batch: &RecordBatch) -> Result<Vec<Value>> {
+    let schema = batch.schema();
+    let mut columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, array)| Ok(field_to_values(field, array)?.into_iter()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((0..batch.num_rows())
+        .map(|_| {
+            let fields = schema
+                .fields()
+                .iter()
+                .zip(columns.iter_mut())
+                .map(|(field, values)| {
+                    (field.name().clone(), values.next().unwrap_or(Value::Null))
+                })
+                .collect();
+            Value::Record(fields)
+        })
+        .collect())
+}
+
+/// Converts the values of an array, wrapping them in the `null` union of
+/// nullable fields.
+///
+/// Null values of non-nullable fields are only valid below null parents (e.g.
+/// in the children of a null struct), where they are discarded.
+fn field_to_values(field: &Field, array: &ArrayRef) -> Result<Vec<Value>> {
+    let array = match array.data_type() {
+        DataType::Dictionary(_, value_type) => cast(array, value_type)?,
+        _ => Arc::clone(array),
+    };
+    let values = array_to_values(&array)?;
+    if !field.is_nullable() || matches!(field.data_type(), DataType::Null) {
+        return Ok(values);
+    }
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            if array.is_null(i) {
+                Value::Union(0, Box::new(Value::Null))
+            } else {
+                Value::Union(1, Box::new(value))
+            }
+        })
+        .collect())
+}
+
+/// Converts the values of an array, with nulls converted to [`Value::Null`]
+fn array_to_values(array: &ArrayRef) -> Result<Vec<Value>> {
+    match array.data_type() {
+        DataType::Null => Ok(vec![Value::Null; array.len()]),
+        DataType::Boolean => Ok(array
+            .as_boolean()
+            .iter()
+            .map(|v| v.map(Value::Boolean).unwrap_or(Value::Null))
+            .collect()),
+        DataType::Int8 => {
+            primitive_values::<Int8Type>(array, |v| Ok(Value::Int(v.into())))
+        }
+        DataType::Int16 => {
+            primitive_values::<Int16Type>(array, |v| Ok(Value::Int(v.into())))
+        }
+        DataType::Int32 => primitive_values::<Int32Type>(array, |v| Ok(Value::Int(v))),
+        DataType::Int64 => primitive_values::<Int64Type>(array, |v| Ok(Value::Long(v))),
+        DataType::UInt8 => {
+            primitive_values::<UInt8Type>(array, |v| Ok(Value::Int(v.into())))
+        }
+        DataType::UInt16 => {
+            primitive_values::<UInt16Type>(array, |v| Ok(Value::Int(v.into())))
+        }
+        DataType::UInt32 => {
+            primitive_values::<UInt32Type>(array, |v| Ok(Value::Long(v.into())))
+        }
+        DataType::UInt64 => primitive_values::<UInt64Type>(array, |v| {
+            let v = i64::try_from(v).map_err(|_| {
+                exec_datafusion_err!("Value {v} is out of range of an Avro long")
+            })?;
+            Ok(Value::Long(v))
+        }),
+        DataType::Float16 => {
+            primitive_values::<Float16Type>(array, |v| Ok(Value::Float(v.to_f32())))
+        }
+        DataType::Float32 => {
+            primitive_values::<Float32Type>(array, |v| Ok(Value::Float(v)))
+        }
+        DataType::Float64 => {
+            primitive_values::<Float64Type>(array, |v| Ok(Value::Double(v)))
+        }
+        DataType::Utf8 => Ok(string_values(array.as_string::<i32>().iter())),
+        DataType::LargeUtf8 => Ok(string_values(array.as_string::<i64>().iter())),
+        DataType::Utf8View => Ok(string_values(array.as_string_view().iter())),
+        DataType::Binary => Ok(binary_values(array.as_binary::<i32>().iter())),
+        DataType::LargeBinary => Ok(binary_values(array.as_binary::<i64>().iter())),
+        DataType::BinaryView => Ok(binary_values(array.as_binary_view().iter())),
+        DataType::FixedSizeBinary(size) => Ok(array
+            .as_fixed_size_binary()
+            .iter()
+            .map(|v| {
+                v.map(|v| Value::Fixed(*size as usize, v.to_vec()))
+                    .unwrap_or(Value::Null)
+            })
+            .collect()),
+        DataType::Decimal32(_, _) => primitive_values::<Decimal32Type>(array, |v| {
+            Ok(decimal_value(&i128::from(v).to_be_bytes()))
+        }),
+        DataType::Decimal64(_, _) => primitive_values::<Decimal64Type>(array, |v| {
+            Ok(decimal_value(&i128::from(v).to_be_bytes()))
+        }),
+        DataType::Decimal128(_, _) => primitive_values::<Decimal128Type>(array, |v| {
+            Ok(decimal_value(&v.to_be_bytes()))
+        }),
+        DataType::Decimal256(_, _) => primitive_values::<Decimal256Type>(array, |v| {
+            Ok(decimal_value(&v.to_be_bytes()))
+        }),
+        DataType::Date32 => primitive_values::<Date32Type>(array, |v| Ok(Value::Date(v))),
+        DataType::Date64 => primitive_values::<Date64Type>(array, |v| {
+            let days =
+                i32::try_from(v.div_euclid(MILLISECONDS_IN_DAY)).map_err(|_| {
+                    exec_datafusion_err!("Date {v} is out of range of an Avro date")
+                })?;
+            Ok(Value::Date(days))
+        }),
+        DataType::Time32(TimeUnit::Second) => {
+            primitive_values::<Time32SecondType>(array, |v| {
+                Ok(Value::TimeMillis(v * 1000))
+            })
+        }
+        DataType::Time32(_) => {
+            primitive_values::<Time32MillisecondType>(array, |v| Ok(Value::TimeMillis(v)))
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            primitive_values::<Time64NanosecondType>(array, |v| {
+                Ok(Value::TimeMicros(v / 1000))
+            })
+        }
+        DataType::Time64(_) => {
+            primitive_values::<Time64MicrosecondType>(array, |v| Ok(Value::TimeMicros(v)))
+        }
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            primitive_values::<TimestampSecondType>(array, |v| {
+                let v = v.checked_mul(1000).ok_or_else(|| {
+                    exec_datafusion_err!("Timestamp {v} is out of range of an Avro long")
+                })?;
+                Ok(Value::TimestampMillis(v))
+            })
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            primitive_values::<TimestampMillisecondType>(array, |v| {
+                Ok(Value::TimestampMillis(v))
+            })
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            primitive_values::<TimestampMicrosecondType>(array, |v| {
+                Ok(Value::TimestampMicros(v))
+            })
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            primitive_values::<TimestampNanosecondType>(array, |v| {
+                Ok(Value::TimestampNanos(v))
+            })
+        }
+        DataType::List(field) => list_values::<i32>(array, field),
+        DataType::LargeList(field) => list_values::<i64>(array, field),
+        DataType::FixedSizeList(field, size) => {
+            let list = array.as_fixed_size_list();
+            let size = *size as usize;
+            let mut items = field_to_values(field, list.values())?.into_iter();
+            Ok((0..list.len())
+                .map(|i| {
+                    let row = items.by_ref().take(size).collect();
+                    if list.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Array(row)
+                    }
+                })
+                .collect())
+        }
+        DataType::Struct(fields) => {
+            let struct_array = array.as_struct();
+            let mut columns = fields
+                .iter()
+                .zip(struct_array.columns())
+                .map(|(field, array)| Ok(field_to_values(field, array)?.into_iter()))
+                .collect::<Result<Vec<_>>>()?;
+            Ok((0..struct_array.len())
+                .map(|i| {
+                    let record = fields
+                        .iter()
+                        .zip(columns.iter_mut())
+                        .map(|(field, values)| {
+                            (field.name().clone(), values.next().unwrap_or(Value::Null))
+                        })
+                        .collect();
+                    if struct_array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Record(record)
+                    }
+                })
+                .collect())
+        }
+        DataType::Map(entries, _) => {
+            let DataType::Struct(fields) = entries.data_type() else {
+                return not_impl_err!(
+                    "Writing {} to Avro is not supported",
+                    array.data_type()
+                );
+            };
+            let map = array.as_map();
+            let offsets = map.value_offsets();
+            // Only convert the entries of the maps of sliced arrays
+            let start = offsets[0] as usize;
+            let len = offsets[offsets.len() - 1] as usize - start;
+            let keys = cast(&map.keys().slice(start, len), &DataType::Utf8)?;
+            let values = field_to_values(&fields[1], &map.values().slice(start, len))?;
+            let mut entries = keys.as_string::<i32>().iter().zip(values);
+            Ok(offsets
+                .windows(2)
+                .enumerate()
+                .map(|(i, window)| {
+                    let len = (window[1] - window[0]) as usize;
+                    let row = entries
+                        .by_ref()
+                        .take(len)
+                        .map(|(key, value)| (key.unwrap_or_default().to_string(), value))
+                        .collect::<HashMap<_, _>>();
+                    if map.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Map(row)
+                    }
+                })
+                .collect())
+        }
+        data_type => not_impl_err!("Writing {data_type} to Avro is not supported"),
+    }
+}
+
+fn primitive_values<T: ArrowPrimitiveType>(
+    array: &ArrayRef,
+    f: impl Fn(T::Native) -> Result<Value>,
+) -> Result<Vec<Value>> {
+    array
+        .as_primitive::<T>()
+        .iter()
+        .map(|v| v.map(&f).unwrap_or(Ok(Value::Null)))
+        .collect()
+}
+
+fn string_values<'a>(values: impl Iterator<Item = Option<&'a str>>) -> Vec<Value> {
+    values
+        .map(|v| {
+            v.map(|v| Value::String(v.to_string()))
+                .unwrap_or(Value::Null)
+        })
+        .collect()
+}
+
+fn binary_values<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> Vec<Value> {
+    values
+        .map(|v| v.map(|v| Value::Bytes(v.to_vec())).unwrap_or(Value::Null))
+        .collect()
+}
+
+/// Creates a decimal from the big-endian two's-complement bytes of its
+/// unscaled value
+fn decimal_value(bytes: &[u8]) -> Value {
+    Value::Decimal(Decimal::from(bytes.to_vec()))
+}
+
+fn list_values<O: OffsetSizeTrait>(
+    array: &ArrayRef,
+    field: &Field,
+) -> Result<Vec<Value>> {
+    let list = array.as_list::<O>();
+    let offsets = list.value_offsets();
+    // Only convert the items of the lists of sliced arrays
+    let start = offsets[0].as_usize();
+    let len = offsets[offsets.len() - 1].as_usize() - start;
+    let mut items = field_to_values(field, &list.values().slice(start, len))?.into_iter();
+    Ok(offsets
+        .windows(2)
+        .enumerate()
+        .map(|(i, window)| {
+            let row = items
+                .by_ref()
+                .take((window[1] - window[0]).as_usize())
+                .collect();
+            if list.is_null(i) {
+                Value::Null
+            } else {
+                Value::Array(row)
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{
+        Decimal128Array, Int32Array, ListArray, StringArray, TimestampMillisecondArray,
+    };
+    use arrow::datatypes::Schema;
+
+    #[test]
+    fn test_to_avro_records() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("price", DataType::Decimal128(5, 2), false),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                false,
+            ),
+            Field::new_list(
+                "items",
+                Arc::new(Field::new_list_field(DataType::Int32, true)),
+                true,
+            ),
+        ]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+                Arc::new(
+                    Decimal128Array::from(vec![12345, -1])
+                        .with_precision_and_scale(5, 2)?,
+                ),
+                Arc::new(TimestampMillisecondArray::from(vec![1000, 2000])),
+                Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                    Some(vec![Some(1), None]),
+                    None,
+                ])),
+            ],
+        )?;
+
+        let records = to_avro_records(&batch)?;
+        let expected = vec![
+            Value::Record(vec![
+                ("id".to_string(), Value::Int(1)),
+                (
+                    "name".to_string(),
+                    Value::Union(1, Box::new(Value::String("a".to_string()))),
+                ),
+                (
+                    "price".to_string(),
+                    decimal_value(&12345_i128.to_be_bytes()),
+                ),
+                ("ts".to_string(), Value::TimestampMillis(1000)),
+                (
+                    "items".to_string(),
+                    Value::Union(
+                        1,
+                        Box::new(Value::Array(vec![
+                            Value::Union(1, Box::new(Value::Int(1))),
+                            Value::Union(0, Box::new(Value::Null)),
+                        ])),
+                    ),
+                ),
+            ]),
+            Value::Record(vec![
+                ("id".to_string(), Value::Int(2)),
+                ("name".to_string(), Value::Union(0, Box::new(Value::Null))),
+                ("price".to_string(), decimal_value(&(-1_i128).to_be_bytes())),
+                ("ts".to_string(), Value::TimestampMillis(2000)),
+                ("items".to_string(), Value::Union(0, Box::new(Value::Null))),
+            ]),
+        ];
+        assert_eq!(records, expected);
+
+        // The records are valid for the converted schema
+        let (avro_schema, _) = super::super::to_avro_schema(&schema)?;
+        for record in records {
+            apache_avro::to_avro_datum(&avro_schema, record)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use apache_avro::Schema as AvroSchema;
+use arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
+use datafusion_common::{Result, not_impl_err, plan_err};
+use serde_json::{Value as JsonValue, json};
+
+/// Name of the top-level record of written Avro files
+const TOP_LEVEL_RECORD_NAME: &str = "topLevelRecord";
+
+/// Converts an arrow schema to an avro record schema, returned along with its
+/// JSON representation.
+///
+/// Nullable fields become unions of `null` and the field type. Decimals are
+/// written as `bytes` with the `decimal` logical type, and dates, times and
+/// timestamps with the `date`, `time-*` and `timestamp-*` logical types.
+/// Second precision times and timestamps are widened to milliseconds and
+/// nanosecond precision times are truncated to microseconds.
+///
+/// Named types (records and fixed) are named after the path of their field,
+/// e.g. `topLevelRecord.a.b` for the struct field `b` of the struct column `a`.
+///
+/// Fails if a column or struct field name is not a valid Avro name, e.g.
+/// `count(*)`: such columns must be aliased before being written.
+pub fn to_avro_schema(schema: &Schema) -> Result<(AvroSchema, JsonValue)> {
+    let json = record_to_json(TOP_LEVEL_RECORD_NAME, schema.fields())?;
+    let avro_schema = AvroSchema::parse(&json)?;
+    Ok((avro_schema, json))
+}
+
+fn record_to_json(name: &str, fields: &Fields) -> Result<JsonValue> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            check_name(field.name())?;
+            let field_type = field_to_json(field, &format!("{name}.{}", field.name()))?;
+            Ok(json!({ "name": field.name(), "type": field_type }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({ "type": "record", "name": name, "fields": fields }))
+}
+
+/// Checks that `name` matches `[A-Za-z_][A-Za-z0-9_]*`, as required by the
+/// Avro specification for field and type names
+fn check_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return plan_err!(
+            "Column name \"{name}\" is not a valid Avro name: names must start with a letter or '_' and contain only letters, digits and '_'. Use an alias to rename the column"
+        );
+    }
+    Ok(())
+}
+
+fn field_to_json(field: &Field, name: &str) -> Result<JsonValue> {
+    let data_type = data_type_to_json(field.data_type(), name)?;
+    if field.is_nullable() && !matches!(field.data_type(), DataType::Null) {
+        Ok(json!(["null", data_type]))
+    } else {
+        Ok(data_type)
+    }
+}
+
+fn data_type_to_json(data_type: &DataType, name: &str) -> Result<JsonValue> {
+    Ok(match data_type {
+        DataType::Null => json!("null"),
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16 => json!("int"),
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => json!("long"),
+        DataType::Float16 | DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => json!("string"),
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+            json!("bytes")
+        }
+        DataType::FixedSizeBinary(size) => {
+            json!({ "type": "fixed", "name": name, "size": size })
+        }
+        DataType::Decimal32(precision, scale)
+        | DataType::Decimal64(precision, scale)
+        | DataType::Decimal128(precision, scale)
+        | DataType::Decimal256(precision, scale) => {
+            if *scale < 0 {
+                return not_impl_err!(
+                    "Writing decimals with a negative scale to Avro is not supported"
+                );
+            }
+            json!({
+                "type": "bytes",
+                "logicalType": "decimal",
+                "precision": precision,
+                "scale": scale,
+            })
+        }
+        DataType::Date32 | DataType::Date64 => {
+            json!({ "type": "int", "logicalType": "date" })
+        }
+        DataType::Time32(_) => json!({ "type": "int", "logicalType": "time-millis" }),
+        DataType::Time64(_) => json!({ "type": "long", "logicalType": "time-micros" }),
+        DataType::Timestamp(unit, _) => {
+            let logical_type = match unit {
+                TimeUnit::Second | TimeUnit::Millisecond => "timestamp-millis",
+                TimeUnit::Microsecond => "timestamp-micros",
+                TimeUnit::Nanosecond => "timestamp-nanos",
+            };
+            json!({ "type": "long", "logicalType": logical_type })
+        }
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) => {
+            let items = field_to_json(field, &format!("{name}.{}", field.name()))?;
+            json!({ "type": "array", "items": items })
+        }
+        DataType::Struct(fields) => record_to_json(name, fields)?,
+        DataType::Map(entries, _) => {
+            let DataType::Struct(fields) = entries.data_type() else {
+                return not_impl_err!("Writing {data_type} to Avro is not supported");
+            };
+            if !matches!(
+                fields[0].data_type(),
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+            ) {
+                return not_impl_err!(
+                    "Writing maps with {} keys to Avro is not supported",
+                    fields[0].data_type()
+                );
+            }
+            let values =
+                field_to_json(&fields[1], &format!("{name}.{}", fields[1].name()))?;
+            json!({ "type": "map", "values": values })
+        }
+        DataType::Dictionary(_, value_type) => data_type_to_json(value_type, name)?,
+        _ => return not_impl_err!("Writing {data_type} to Avro is not supported"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_to_avro_schema() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("price", DataType::Decimal128(10, 2), true),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                false,
+            ),
+            Field::new(
+                "point",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("x", DataType::Float64, false),
+                    Field::new("tag", DataType::FixedSizeBinary(4), false),
+                ])),
+                false,
+            ),
+            Field::new_list(
+                "tags",
+                Arc::new(Field::new_list_field(DataType::Utf8, false)),
+                false,
+            ),
+        ]);
+
+        let (_, json) = to_avro_schema(&schema)?;
+        assert_eq!(
+            json,
+            json!({
+                "type": "record",
+                "name": "topLevelRecord",
+                "fields": [
+                    { "name": "id", "type": "long" },
+                    { "name": "name", "type": ["null", "string"] },
+                    {
+                        "name": "price",
+                        "type": ["null", {
+                            "type": "bytes",
+                            "logicalType": "decimal",
+                            "precision": 10,
+                            "scale": 2,
+                        }],
+                    },
+                    {
+                        "name": "ts",
+                        "type": { "type": "long", "logicalType": "timestamp-micros" },
+                    },
+                    {
+                        "name": "point",
+                        "type": {
+                            "type": "record",
+                            "name": "topLevelRecord.point",
+                            "fields": [
+                                { "name": "x", "type": "double" },
+                                {
+                                    "name": "tag",
+                                    "type": {
+                                        "type": "fixed",
+                                        "name": "topLevelRecord.point.tag",
+                                        "size": 4,
+                                    },
+                                },
+                            ],
+                        },
+                    },
+                    {
+                        "name": "tags",
+                        "type": { "type": "array", "items": "string" },
+                    },
+                ],
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_avro_schema_unsupported() {
+        let schema = Schema::new(vec![Field::new(
+            "d",
+            DataType::Duration(TimeUnit::Second),
+            false,
+        )]);
+        let err = to_avro_schema(&schema).unwrap_err();
+        assert!(
+            err.to_string().contains("to Avro is not supported"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_to_avro_schema_invalid_name() {
+        for name in ["count(*)", "1a", "a.b", ""] {
+            let schema = Schema::new(vec![Field::new(name, DataType::Int64, false)]);
+            let err = to_avro_schema(&schema).unwrap_err();
+            assert!(
+                err.to_string().contains(&format!(
+                    "Column name \"{name}\" is not a valid Avro name"
+                )),
+                "{err}"
+            );
+        }
+
+        let schema = Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(Fields::from(vec![Field::new(
+                "max(x)",
+                DataType::Int64,
+                false,
+            )])),
+            false,
+        )]);
+        let err = to_avro_schema(&schema).unwrap_err();
+        assert!(err.to_string().contains("\"max(x)\""), "{err}");
+    }
+}
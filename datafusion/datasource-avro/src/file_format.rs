@@ -20,51 +20,97 @@
 use std::any::Any;
 use std::collections::HashMap;
 use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 
+use crate::arrow_to_avro::{to_avro_records, to_avro_schema};
 use crate::avro_to_arrow::read_avro_schema_from_reader;
 use crate::source::AvroSource;
 
+use apache_avro::Codec;
+use arrow::array::RecordBatch;
 use arrow::datatypes::Schema;
 use arrow::datatypes::SchemaRef;
 use datafusion_common::DEFAULT_AVRO_EXTENSION;
 use datafusion_common::GetExt;
-use datafusion_common::internal_err;
+use datafusion_common::config::{AvroCodec, AvroOptions, ConfigField, ConfigFileType};
+use datafusion_common::file_options::avro_writer::AvroWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{Result, Statistics};
+use datafusion_common::{internal_datafusion_err, internal_err, not_impl_err};
+use datafusion_common_runtime::SpawnedTask;
+use datafusion_datasource::display::FileGroupDisplay;
 use datafusion_datasource::file::FileSource;
 use datafusion_datasource::file_compression_type::FileCompressionType;
 use datafusion_datasource::file_format::{FileFormat, FileFormatFactory};
 use datafusion_datasource::file_scan_config::FileScanConfig;
+use datafusion_datasource::file_sink_config::{FileSink, FileSinkConfig};
+use datafusion_datasource::sink::{DataSink, DataSinkExec};
 use datafusion_datasource::source::DataSourceExec;
-use datafusion_physical_plan::ExecutionPlan;
+use datafusion_datasource::write::BatchSerializer;
+use datafusion_datasource::write::demux::DemuxedStreamReceiver;
+use datafusion_datasource::write::orchestration::spawn_writer_tasks_and_join;
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_expr::dml::InsertOp;
+use datafusion_physical_expr_common::sort_expr::LexRequirement;
+use datafusion_physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan};
 use datafusion_session::Session;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use object_store::{GetResultPayload, ObjectMeta, ObjectStore};
 
+/// The magic bytes at the start of Avro object container files
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+
 #[derive(Default)]
 /// Factory struct used to create [`AvroFormat`]
-pub struct AvroFormatFactory;
+pub struct AvroFormatFactory {
+    /// the options carried by format factory
+    pub options: Option<AvroOptions>,
+}
 
 impl AvroFormatFactory {
     /// Creates an instance of [`AvroFormatFactory`]
     pub fn new() -> Self {
-        Self {}
+        Self { options: None }
+    }
+
+    /// Creates an instance of [`AvroFormatFactory`] with customized default options
+    pub fn new_with_options(options: AvroOptions) -> Self {
+        Self {
+            options: Some(options),
+        }
     }
 }
 
 impl FileFormatFactory for AvroFormatFactory {
     fn create(
         &self,
-        _state: &dyn Session,
-        _format_options: &HashMap<String, String>,
+        state: &dyn Session,
+        format_options: &HashMap<String, String>,
     ) -> Result<Arc<dyn FileFormat>> {
-        Ok(Arc::new(AvroFormat))
+        let avro_options = match &self.options {
+            None => {
+                let mut table_options = state.default_table_options();
+                table_options.set_config_format(ConfigFileType::AVRO);
+                table_options.alter_with_string_hash_map(format_options)?;
+                table_options.avro
+            }
+            Some(avro_options) => {
+                let mut avro_options = avro_options.clone();
+                for (k, v) in format_options {
+                    avro_options.set(k, v)?;
+                }
+                avro_options
+            }
+        };
+
+        Ok(Arc::new(AvroFormat::default().with_options(avro_options)))
     }
 
     fn default(&self) -> Arc<dyn FileFormat> {
-        Arc::new(AvroFormat)
+        Arc::new(AvroFormat::default())
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -74,7 +120,9 @@ impl FileFormatFactory for AvroFormatFactory {
 
 impl fmt::Debug for AvroFormatFactory {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("AvroFormatFactory").finish()
+        f.debug_struct("AvroFormatFactory")
+            .field("options", &self.options)
+            .finish()
     }
 }
 
@@ -87,7 +135,29 @@ impl GetExt for AvroFormatFactory {
 
 /// Avro [`FileFormat`] implementation.
 #[derive(Default, Debug)]
-pub struct AvroFormat;
+pub struct AvroFormat {
+    options: AvroOptions,
+}
+
+impl AvroFormat {
+    /// Set Avro options
+    pub fn with_options(mut self, options: AvroOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Retrieve Avro options
+    pub fn options(&self) -> &AvroOptions {
+        &self.options
+    }
+
+    /// Set the codec used to compress the blocks of written files
+    /// - defaults to [`AvroCodec::Null`]
+    pub fn with_codec(mut self, codec: AvroCodec) -> Self {
+        self.options.codec = codec;
+        self
+    }
+}
 
 #[async_trait]
 impl FileFormat for AvroFormat {
@@ -157,6 +227,24 @@ impl FileFormat for AvroFormat {
         Ok(DataSourceExec::from_data_source(conf))
     }
 
+    async fn create_writer_physical_plan(
+        &self,
+        input: Arc<dyn ExecutionPlan>,
+        _state: &dyn Session,
+        conf: FileSinkConfig,
+        order_requirements: Option<LexRequirement>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if conf.insert_op != InsertOp::Append {
+            return not_impl_err!("Overwrites are not implemented yet for Avro");
+        }
+
+        let writer_options = AvroWriterOptions::try_from(&self.options)?;
+
+        let sink = Arc::new(AvroSink::new(conf, writer_options));
+
+        Ok(Arc::new(DataSinkExec::new(input, sink, order_requirements)) as _)
+    }
+
     fn file_source(
         &self,
         table_schema: datafusion_datasource::TableSchema,
@@ -164,3 +252,238 @@ impl FileFormat for AvroFormat {
         Arc::new(AvroSource::new(table_schema))
     }
 }
+
+/// Serializes record batches to the blocks of an Avro object container file.
+///
+/// The initial batch is preceded by the file header, which holds the schema of
+/// the batch, and every non-empty batch is written as a single block.
+pub struct AvroSerializer {
+    codec: AvroCodec,
+    /// Marker written after the header and every block, which is shared by all
+    /// files written by this serializer
+    sync_marker: [u8; 16],
+}
+
+impl AvroSerializer {
+    /// Constructor for the AvroSerializer object
+    pub fn new(codec: AvroCodec) -> Self {
+        Self {
+            codec,
+            sync_marker: rand::random(),
+        }
+    }
+
+    fn write_header(&self, schema: &serde_json::Value, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(AVRO_MAGIC);
+        // The file metadata is a map of two entries
+        encode_long(2, buffer);
+        encode_bytes(b"avro.schema", buffer);
+        encode_bytes(schema.to_string().as_bytes(), buffer);
+        encode_bytes(b"avro.codec", buffer);
+        encode_bytes(self.codec.to_string().as_bytes(), buffer);
+        encode_long(0, buffer);
+        buffer.extend_from_slice(&self.sync_marker);
+    }
+}
+
+impl BatchSerializer for AvroSerializer {
+    fn serialize(&self, batch: RecordBatch, initial: bool) -> Result<Bytes> {
+        let (schema, schema_json) = to_avro_schema(batch.schema_ref())?;
+        let mut buffer = Vec::with_capacity(4096);
+        if initial {
+            self.write_header(&schema_json, &mut buffer);
+        }
+        if batch.num_rows() == 0 {
+            return Ok(Bytes::from(buffer));
+        }
+
+        let mut block = Vec::with_capacity(4096);
+        for record in to_avro_records(&batch)? {
+            block.extend(apache_avro::to_avro_datum(&schema, record)?);
+        }
+        Codec::from_str(&self.codec.to_string())
+            .map_err(|e| internal_datafusion_err!("Unsupported Avro codec: {e:?}"))?
+            .compress(&mut block)?;
+
+        encode_long(batch.num_rows() as i64, &mut buffer);
+        encode_bytes(&block, &mut buffer);
+        buffer.extend_from_slice(&self.sync_marker);
+        Ok(Bytes::from(buffer))
+    }
+}
+
+/// Writes a zig-zag encoded variable length long
+fn encode_long(value: i64, buffer: &mut Vec<u8>) {
+    let mut n = ((value << 1) ^ (value >> 63)) as u64;
+    while n >= 0x80 {
+        buffer.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    buffer.push(n as u8);
+}
+
+/// Writes bytes prefixed by their length
+fn encode_bytes(bytes: &[u8], buffer: &mut Vec<u8>) {
+    encode_long(bytes.len() as i64, buffer);
+    buffer.extend_from_slice(bytes);
+}
+
+/// Implements [`DataSink`] for writing to an Avro file.
+pub struct AvroSink {
+    /// Config options for writing data
+    config: FileSinkConfig,
+    /// Writer options for underlying Avro writer
+    writer_options: AvroWriterOptions,
+}
+
+impl fmt::Debug for AvroSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AvroSink").finish()
+    }
+}
+
+impl DisplayAs for AvroSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "AvroSink(file_groups=",)?;
+                FileGroupDisplay(&self.config.file_group).fmt_as(t, f)?;
+                write!(f, ")")
+            }
+            DisplayFormatType::TreeRender => {
+                writeln!(f, "format: avro")?;
+                write!(f, "file={}", &self.config.original_url)
+            }
+        }
+    }
+}
+
+impl AvroSink {
+    /// Create from config.
+    pub fn new(config: FileSinkConfig, writer_options: AvroWriterOptions) -> Self {
+        Self {
+            config,
+            writer_options,
+        }
+    }
+
+    /// Retrieve the writer options
+    pub fn writer_options(&self) -> &AvroWriterOptions {
+        &self.writer_options
+    }
+}
+
+#[async_trait]
+impl FileSink for AvroSink {
+    fn config(&self) -> &FileSinkConfig {
+        &self.config
+    }
+
+    async fn spawn_writer_tasks_and_join(
+        &self,
+        context: &Arc<TaskContext>,
+        demux_task: SpawnedTask<Result<()>>,
+        file_stream_rx: DemuxedStreamReceiver,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> Result<u64> {
+        let serializer = Arc::new(AvroSerializer::new(self.writer_options.codec)) as _;
+        // Blocks are compressed by the serializer, not the whole file
+        spawn_writer_tasks_and_join(
+            context,
+            serializer,
+            FileCompressionType::UNCOMPRESSED,
+            None,
+            object_store,
+            demux_task,
+            file_stream_rx,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl DataSink for AvroSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        self.config.output_schema()
+    }
+
+    async fn write_all(
+        &self,
+        data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        FileSink::write_all(self, data, context).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avro_to_arrow::ReaderBuilder;
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_encode_long() {
+        for (value, expected) in [
+            (0, vec![0x00]),
+            (-1, vec![0x01]),
+            (1, vec![0x02]),
+            (-64, vec![0x7f]),
+            (64, vec![0x80, 0x01]),
+        ] {
+            let mut buffer = vec![];
+            encode_long(value, &mut buffer);
+            assert_eq!(buffer, expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_serializer_roundtrip() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch1 = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ],
+        )?;
+        let batch2 = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int64Array::from(vec![3])),
+                Arc::new(StringArray::from(vec![Some("c")])),
+            ],
+        )?;
+
+        for codec in [AvroCodec::Null, AvroCodec::Deflate, AvroCodec::Snappy] {
+            let serializer = AvroSerializer::new(codec);
+            let mut file = serializer.serialize(batch1.clone(), true)?.to_vec();
+            file.extend(serializer.serialize(batch2.clone(), false)?);
+            file.extend(serializer.finish(false)?);
+
+            let reader = ReaderBuilder::new()
+                .read_schema()
+                .with_batch_size(10)
+                .build(Cursor::new(file))?;
+            let batches = reader.collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(batches.len(), 1);
+            let ids = batches[0].column(0).as_any().downcast_ref::<Int64Array>();
+            assert_eq!(ids, Some(&Int64Array::from(vec![1, 2, 3])));
+            let names = batches[0].column(1).as_any().downcast_ref::<StringArray>();
+            assert_eq!(
+                names,
+                Some(&StringArray::from(vec![Some("a"), None, Some("c")]))
+            );
+        }
+        Ok(())
+    }
+}
@@ -28,6 +28,7 @@
 
 //! An [Avro](https://avro.apache.org/) based [`FileSource`](datafusion_datasource::file::FileSource) implementation and related functionality.
 
+pub mod arrow_to_avro;
 pub mod avro_to_arrow;
 pub mod file_format;
 pub mod source;
@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Streaming support for JSON files holding a single top-level array of
+//! records (`[{...}, {...}]`)
+
+use std::io::{self, Read};
+
+use arrow::error::ArrowError;
+use bytes::Bytes;
+use datafusion_common::Result;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+
+/// Rewrites a top-level JSON array of objects into a sequence of whitespace
+/// separated objects, which the arrow JSON decoder reads like newline
+/// delimited JSON.
+///
+/// The brackets and separating commas of the array are replaced by whitespace
+/// in place, so the input can be rewritten chunk by chunk without buffering
+/// the whole file. The content of the objects is left to the decoder.
+#[derive(Debug, Default)]
+pub(crate) struct JsonArrayRewriter {
+    state: ArrayState,
+    /// Nesting depth of brackets within the current object
+    depth: usize,
+    /// Whether the current object is within a string literal
+    in_string: bool,
+    /// Whether the previous byte of the string literal was a backslash
+    escaped: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ArrayState {
+    /// Before the opening bracket
+    #[default]
+    Start,
+    /// After the opening bracket
+    FirstValue,
+    /// After a separating comma
+    Value,
+    /// Within an object
+    InValue,
+    /// After an object
+    Separator,
+    /// After the closing bracket
+    End,
+}
+
+impl JsonArrayRewriter {
+    /// Rewrites the next chunk of the input in place
+    pub(crate) fn rewrite(&mut self, buf: &mut [u8]) -> Result<(), ArrowError> {
+        for b in buf.iter_mut() {
+            match self.state {
+                ArrayState::InValue => {
+                    if self.in_string {
+                        if self.escaped {
+                            self.escaped = false;
+                        } else if *b == b'\\' {
+                            self.escaped = true;
+                        } else if *b == b'"' {
+                            self.in_string = false;
+                        }
+                        continue;
+                    }
+                    match *b {
+                        b'"' => self.in_string = true,
+                        b'{' | b'[' => self.depth += 1,
+                        b'}' | b']' => {
+                            self.depth -= 1;
+                            if self.depth == 0 {
+                                self.state = ArrayState::Separator;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                _ if b.is_ascii_whitespace() => {}
+                ArrayState::Start => match *b {
+                    b'[' => {
+                        *b = b' ';
+                        self.state = ArrayState::FirstValue;
+                    }
+                    _ => return Err(unexpected(*b, "'['")),
+                },
+                ArrayState::FirstValue | ArrayState::Value => match *b {
+                    b'{' => {
+                        self.depth = 1;
+                        self.state = ArrayState::InValue;
+                    }
+                    b']' if self.state == ArrayState::FirstValue => {
+                        *b = b' ';
+                        self.state = ArrayState::End;
+                    }
+                    _ => return Err(unexpected(*b, "a JSON object")),
+                },
+                ArrayState::Separator => match *b {
+                    b',' => {
+                        *b = b'\n';
+                        self.state = ArrayState::Value;
+                    }
+                    b']' => {
+                        *b = b' ';
+                        self.state = ArrayState::End;
+                    }
+                    _ => return Err(unexpected(*b, "',' or ']'")),
+                },
+                ArrayState::End => return Err(unexpected(*b, "end of file")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the input ended after a complete array. Empty input is
+    /// treated like an empty array.
+    pub(crate) fn finish(&self) -> Result<(), ArrowError> {
+        match self.state {
+            ArrayState::Start | ArrayState::End => Ok(()),
+            _ => Err(ArrowError::JsonError(
+                "Unexpected end of file in JSON array".to_string(),
+            )),
+        }
+    }
+}
+
+fn unexpected(b: u8, expected: &str) -> ArrowError {
+    ArrowError::JsonError(format!(
+        "Expected {expected} in JSON array, found '{}'",
+        b.escape_ascii()
+    ))
+}
+
+/// A [`Read`] adapter which rewrites a JSON array using [`JsonArrayRewriter`]
+pub(crate) struct JsonArrayReader<R> {
+    inner: R,
+    rewriter: JsonArrayRewriter,
+}
+
+impl<R> JsonArrayReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            rewriter: JsonArrayRewriter::default(),
+        }
+    }
+}
+
+impl<R: Read> Read for JsonArrayReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        let result = if n == 0 {
+            self.rewriter.finish()
+        } else {
+            self.rewriter.rewrite(&mut buf[..n])
+        };
+        result.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(n)
+    }
+}
+
+/// Rewrites a stream of bytes holding a JSON array using [`JsonArrayRewriter`]
+pub(crate) fn rewrite_json_array_stream(
+    input: BoxStream<'static, Result<Bytes>>,
+) -> BoxStream<'static, Result<Bytes>> {
+    futures::stream::unfold(
+        Some((input, JsonArrayRewriter::default())),
+        |state| async move {
+            let (mut input, mut rewriter) = state?;
+            match input.next().await {
+                Some(Ok(bytes)) => {
+                    let mut buf = Vec::from(bytes);
+                    let result = rewriter
+                        .rewrite(&mut buf)
+                        .map(|_| Bytes::from(buf))
+                        .map_err(Into::into);
+                    Some((result, Some((input, rewriter))))
+                }
+                Some(Err(e)) => Some((Err(e), None)),
+                None => rewriter.finish().err().map(|e| (Err(e.into()), None)),
+            }
+        },
+    )
+    .boxed()
+}
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`JsonFormat`]: Line delimited JSON [`FileFormat`] abstractions
+//! [`JsonFormat`]: JSON [`FileFormat`] abstractions
 
 use std::any::Any;
 use std::collections::HashMap;
@@ -24,6 +24,7 @@ use std::fmt::Debug;
 use std::io::BufReader;
 use std::sync::Arc;
 
+use crate::array::JsonArrayReader;
 use crate::source::JsonSource;
 
 use arrow::array::RecordBatch;
@@ -31,7 +32,9 @@ use arrow::datatypes::{Schema, SchemaRef};
 use arrow::error::ArrowError;
 use arrow::json;
 use arrow::json::reader::{ValueIter, infer_json_schema_from_iterator};
-use datafusion_common::config::{ConfigField, ConfigFileType, JsonOptions};
+use datafusion_common::config::{
+    ConfigField, ConfigFileType, JsonFormatType, JsonOptions,
+};
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::{
     DEFAULT_JSON_EXTENSION, GetExt, Result, Statistics, not_impl_err,
@@ -132,7 +135,11 @@ impl Debug for JsonFormatFactory {
     }
 }
 
-/// New line delimited JSON `FileFormat` implementation.
+/// JSON `FileFormat` implementation.
+///
+/// By default files hold one JSON object per line (NDJSON). With
+/// [`JsonFormatType::Array`] they hold a single top-level array of objects,
+/// which is still read and written in a streaming fashion.
 #[derive(Debug, Default)]
 pub struct JsonFormat {
     options: JsonOptions,
@@ -166,6 +173,13 @@ impl JsonFormat {
         self.options.compression = file_compression_type.into();
         self
     }
+
+    /// Set the layout of the records in the files
+    /// - defaults to [`JsonFormatType::NdJson`]
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.options.format = format;
+        self
+    }
 }
 
 #[async_trait]
@@ -202,6 +216,11 @@ impl FileFormat for JsonFormat {
             .schema_infer_max_rec
             .unwrap_or(DEFAULT_SCHEMA_INFER_MAX_RECORD);
         let file_compression_type = FileCompressionType::from(self.options.compression);
+        let format = self.options.format;
+        let read = |reader: Box<dyn std::io::Read + Send>| match format {
+            JsonFormatType::NdJson => reader,
+            JsonFormatType::Array => Box::new(JsonArrayReader::new(reader)),
+        };
         for object in objects {
             let mut take_while = || {
                 let should_take = records_to_read > 0;
@@ -216,14 +235,14 @@ impl FileFormat for JsonFormat {
                 #[cfg(not(target_arch = "wasm32"))]
                 GetResultPayload::File(file, _) => {
                     let decoder = file_compression_type.convert_read(file)?;
-                    let mut reader = BufReader::new(decoder);
+                    let mut reader = BufReader::new(read(decoder));
                     let iter = ValueIter::new(&mut reader, None);
                     infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
                 }
                 GetResultPayload::Stream(_) => {
                     let data = r.bytes().await?;
                     let decoder = file_compression_type.convert_read(data.reader())?;
-                    let mut reader = BufReader::new(decoder);
+                    let mut reader = BufReader::new(read(decoder));
                     let iter = ValueIter::new(&mut reader, None);
                     infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
                 }
@@ -281,7 +300,7 @@ impl FileFormat for JsonFormat {
     }
 
     fn file_source(&self, table_schema: TableSchema) -> Arc<dyn FileSource> {
-        Arc::new(JsonSource::new(table_schema))
+        Arc::new(JsonSource::new(table_schema).with_format(self.options.format))
     }
 }
 
@@ -292,22 +311,74 @@ impl Default for JsonSerializer {
 }
 
 /// Define a struct for serializing Json records to a stream
-pub struct JsonSerializer {}
+pub struct JsonSerializer {
+    format: JsonFormatType,
+}
 
 impl JsonSerializer {
     /// Constructor for the JsonSerializer object
     pub fn new() -> Self {
-        Self {}
+        Self {
+            format: JsonFormatType::default(),
+        }
+    }
+
+    /// Set the layout of the serialized records
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.format = format;
+        self
     }
 }
 
 impl BatchSerializer for JsonSerializer {
-    fn serialize(&self, batch: RecordBatch, _initial: bool) -> Result<Bytes> {
+    fn serialize(&self, batch: RecordBatch, initial: bool) -> Result<Bytes> {
         let mut buffer = Vec::with_capacity(4096);
-        let mut writer = json::LineDelimitedWriter::new(&mut buffer);
-        writer.write(&batch)?;
+        match self.format {
+            JsonFormatType::NdJson => {
+                let mut writer = json::LineDelimitedWriter::new(&mut buffer);
+                writer.write(&batch)?;
+            }
+            JsonFormatType::Array => {
+                // The rows of all batches are elements of a single array, which
+                // is opened by the initial batch and closed by `finish`
+                if initial {
+                    buffer.push(b'[');
+                }
+                if batch.num_rows() > 0 {
+                    buffer.extend_from_slice(if initial { b"\n" } else { b",\n" });
+                }
+                let mut writer = json::Writer::<_, JsonArrayElements>::new(&mut buffer);
+                writer.write(&batch)?;
+            }
+        }
         Ok(Bytes::from(buffer))
     }
+
+    fn finish(&self, empty: bool) -> Result<Bytes> {
+        Ok(match (self.format, empty) {
+            (JsonFormatType::NdJson, _) => Bytes::new(),
+            (JsonFormatType::Array, true) => Bytes::from_static(b"]\n"),
+            (JsonFormatType::Array, false) => Bytes::from_static(b"\n]\n"),
+        })
+    }
+}
+
+/// Writes the rows of a batch as comma separated elements of a JSON array,
+/// without the enclosing brackets
+#[derive(Debug, Default)]
+struct JsonArrayElements {}
+
+impl json::writer::JsonFormat for JsonArrayElements {
+    fn start_row<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        is_first_row: bool,
+    ) -> Result<(), ArrowError> {
+        if !is_first_row {
+            writer.write_all(b",\n")?;
+        }
+        Ok(())
+    }
 }
 
 /// Implements [`DataSink`] for writing to a Json file.
@@ -368,7 +439,8 @@ impl FileSink for JsonSink {
         file_stream_rx: DemuxedStreamReceiver,
         object_store: Arc<dyn ObjectStore>,
     ) -> Result<u64> {
-        let serializer = Arc::new(JsonSerializer::new()) as _;
+        let serializer =
+            Arc::new(JsonSerializer::new().with_format(self.writer_options.format)) as _;
         spawn_writer_tasks_and_join(
             context,
             serializer,
@@ -15,16 +15,20 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Execution plan for reading line-delimited JSON files
+//! Execution plan for reading JSON files
 
 use std::any::Any;
+use std::fmt;
 use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::sync::Arc;
 use std::task::Poll;
 
+use crate::array::{JsonArrayReader, rewrite_json_array_stream};
 use crate::file_format::JsonDecoder;
 
+use datafusion_common::config::JsonFormatType;
 use datafusion_common::error::{DataFusionError, Result};
+use datafusion_common::exec_err;
 use datafusion_common_runtime::JoinSet;
 use datafusion_datasource::decoder::{DecoderDeserializer, deserialize_stream};
 use datafusion_datasource::file_compression_type::FileCompressionType;
@@ -34,7 +38,9 @@ use datafusion_datasource::{
     ListingTableUrl, PartitionedFile, RangeCalculation, as_file_source, calculate_range,
 };
 use datafusion_physical_plan::projection::ProjectionExprs;
-use datafusion_physical_plan::{ExecutionPlan, ExecutionPlanProperties};
+use datafusion_physical_plan::{
+    DisplayFormatType, ExecutionPlan, ExecutionPlanProperties,
+};
 
 use arrow::json::ReaderBuilder;
 use arrow::{datatypes::SchemaRef, json};
@@ -54,6 +60,7 @@ pub struct JsonOpener {
     projected_schema: SchemaRef,
     file_compression_type: FileCompressionType,
     object_store: Arc<dyn ObjectStore>,
+    format: JsonFormatType,
 }
 
 impl JsonOpener {
@@ -69,8 +76,15 @@ impl JsonOpener {
             projected_schema,
             file_compression_type,
             object_store,
+            format: JsonFormatType::default(),
         }
     }
+
+    /// Set the layout of the records in the opened files
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 /// JsonSource holds the extra configuration that is necessary for [`JsonOpener`]
@@ -80,6 +94,7 @@ pub struct JsonSource {
     batch_size: Option<usize>,
     metrics: ExecutionPlanMetricsSet,
     projection: SplitProjection,
+    format: JsonFormatType,
 }
 
 impl JsonSource {
@@ -91,8 +106,20 @@ impl JsonSource {
             table_schema,
             batch_size: None,
             metrics: ExecutionPlanMetricsSet::new(),
+            format: JsonFormatType::default(),
         }
     }
+
+    /// Set the layout of the records in the scanned files
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// The layout of the records in the scanned files
+    pub fn format(&self) -> JsonFormatType {
+        self.format
+    }
 }
 
 impl From<JsonSource> for Arc<dyn FileSource> {
@@ -120,6 +147,7 @@ impl FileSource for JsonSource {
             projected_schema,
             file_compression_type: base_config.file_compression_type,
             object_store,
+            format: self.format,
         }) as Arc<dyn FileOpener>;
 
         // Wrap with ProjectionOpener
@@ -169,12 +197,30 @@ impl FileSource for JsonSource {
     fn file_type(&self) -> &str {
         "json"
     }
+
+    fn fmt_extra(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                if self.format != JsonFormatType::NdJson {
+                    write!(f, ", format={}", self.format)?;
+                }
+                Ok(())
+            }
+            DisplayFormatType::TreeRender => Ok(()),
+        }
+    }
+
+    /// A JSON array can only be read from its start
+    fn supports_repartitioning(&self) -> bool {
+        self.format == JsonFormatType::NdJson
+    }
 }
 
 impl FileOpener for JsonOpener {
-    /// Open a partitioned NDJSON file.
+    /// Open a partitioned JSON file.
     ///
-    /// If `file_meta.range` is `None`, the entire file is opened.
+    /// If `file_meta.range` is `None`, the entire file is opened. Files
+    /// holding a JSON array must be opened entirely.
     /// Else `file_meta.range` is `Some(FileRange{start, end})`, which corresponds to the byte range [start, end) within the file.
     ///
     /// Note: `start` or `end` might be in the middle of some lines. In such cases, the following rules
@@ -186,6 +232,14 @@ impl FileOpener for JsonOpener {
         let schema = Arc::clone(&self.projected_schema);
         let batch_size = self.batch_size;
         let file_compression_type = self.file_compression_type.to_owned();
+        let format = self.format;
+
+        if format == JsonFormatType::Array && partitioned_file.range.is_some() {
+            return exec_err!(
+                "Can not read a byte range of JSON array file {}",
+                partitioned_file.object_meta.location
+            );
+        }
 
         Ok(Box::pin(async move {
             let calculated_range =
@@ -221,6 +275,10 @@ impl FileOpener for JsonOpener {
                             file_compression_type.convert_read(file.take(limit as u64))?
                         }
                     };
+                    let bytes = match format {
+                        JsonFormatType::NdJson => bytes,
+                        JsonFormatType::Array => Box::new(JsonArrayReader::new(bytes)),
+                    };
 
                     let reader = ReaderBuilder::new(schema)
                         .with_batch_size(batch_size)
@@ -236,7 +294,12 @@ impl FileOpener for JsonOpener {
                     let decoder = ReaderBuilder::new(schema)
                         .with_batch_size(batch_size)
                         .build_decoder()?;
-                    let input = file_compression_type.convert_stream(s.boxed())?.fuse();
+                    let input = file_compression_type.convert_stream(s.boxed())?;
+                    let input = match format {
+                        JsonFormatType::NdJson => input,
+                        JsonFormatType::Array => rewrite_json_array_stream(input),
+                    }
+                    .fuse();
 
                     let stream = deserialize_stream(
                         input,
@@ -0,0 +1,163 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Apache ORC [`FileFormat`] abstractions
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::ObjectStoreReader;
+use crate::source::OrcSource;
+
+use arrow::datatypes::{Schema, SchemaRef};
+use datafusion_common::parsers::CompressionTypeVariant;
+use datafusion_common::{
+    DEFAULT_ORC_EXTENSION, DataFusionError, GetExt, Result, Statistics, config_err,
+    internal_err,
+};
+use datafusion_datasource::TableSchema;
+use datafusion_datasource::file::FileSource;
+use datafusion_datasource::file_compression_type::FileCompressionType;
+use datafusion_datasource::file_format::{FileFormat, FileFormatFactory};
+use datafusion_datasource::file_scan_config::FileScanConfig;
+use datafusion_datasource::source::DataSourceExec;
+use datafusion_physical_plan::ExecutionPlan;
+use datafusion_session::Session;
+
+use async_trait::async_trait;
+use object_store::{ObjectMeta, ObjectStore};
+use orc_rust::ArrowReaderBuilder;
+
+#[derive(Default)]
+/// Factory struct used to create [`OrcFormat`]
+pub struct OrcFormatFactory;
+
+impl OrcFormatFactory {
+    /// Creates an instance of [`OrcFormatFactory`]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl FileFormatFactory for OrcFormatFactory {
+    fn create(
+        &self,
+        _state: &dyn Session,
+        format_options: &HashMap<String, String>,
+    ) -> Result<Arc<dyn FileFormat>> {
+        if let Some(option) = format_options.keys().next() {
+            return config_err!("Unsupported ORC format option: {option}");
+        }
+        Ok(Arc::new(OrcFormat))
+    }
+
+    fn default(&self) -> Arc<dyn FileFormat> {
+        Arc::new(OrcFormat)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl fmt::Debug for OrcFormatFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrcFormatFactory").finish()
+    }
+}
+
+impl GetExt for OrcFormatFactory {
+    fn get_ext(&self) -> String {
+        // Removes the dot, i.e. ".orc" -> "orc"
+        DEFAULT_ORC_EXTENSION[1..].to_string()
+    }
+}
+
+/// Apache ORC [`FileFormat`] implementation.
+///
+/// The schema of ORC files is read from their footer. Stripes are pruned
+/// using the column statistics stored in the files, see [`OrcSource`].
+#[derive(Default, Debug)]
+pub struct OrcFormat;
+
+#[async_trait]
+impl FileFormat for OrcFormat {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_ext(&self) -> String {
+        OrcFormatFactory::new().get_ext()
+    }
+
+    fn get_ext_with_compression(
+        &self,
+        file_compression_type: &FileCompressionType,
+    ) -> Result<String> {
+        let ext = self.get_ext();
+        match file_compression_type.get_variant() {
+            CompressionTypeVariant::UNCOMPRESSED => Ok(ext),
+            _ => internal_err!("Orc FileFormat does not support compression."),
+        }
+    }
+
+    fn compression_type(&self) -> Option<FileCompressionType> {
+        None
+    }
+
+    async fn infer_schema(
+        &self,
+        _state: &dyn Session,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> Result<SchemaRef> {
+        let mut schemas = vec![];
+        for object in objects {
+            let reader = ObjectStoreReader::new(Arc::clone(store), object.clone());
+            let builder = ArrowReaderBuilder::try_new_async(reader)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            schemas.push(builder.schema().as_ref().clone());
+        }
+        let merged_schema = Schema::try_merge(schemas)?;
+        Ok(Arc::new(merged_schema))
+    }
+
+    async fn infer_stats(
+        &self,
+        _state: &dyn Session,
+        _store: &Arc<dyn ObjectStore>,
+        table_schema: SchemaRef,
+        _object: &ObjectMeta,
+    ) -> Result<Statistics> {
+        Ok(Statistics::new_unknown(&table_schema))
+    }
+
+    async fn create_physical_plan(
+        &self,
+        _state: &dyn Session,
+        conf: FileScanConfig,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(DataSourceExec::from_data_source(conf))
+    }
+
+    fn file_source(&self, table_schema: TableSchema) -> Arc<dyn FileSource> {
+        Arc::new(OrcSource::new(table_schema))
+    }
+}
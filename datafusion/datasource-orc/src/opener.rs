@@ -0,0 +1,619 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`OrcOpener`] for opening ORC files
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::stripe_filter::{prune_stripes_by_statistics, read_writer_version};
+use crate::{ObjectStoreReader, OrcFileMetrics};
+
+use datafusion_common::{DataFusionError, Result, ScalarValue};
+use datafusion_datasource::file_stream::{FileOpenFuture, FileOpener};
+use datafusion_datasource::{PartitionedFile, TableSchema};
+use datafusion_physical_expr::simplifier::PhysicalExprSimplifier;
+use datafusion_physical_expr_adapter::{
+    BatchAdapterFactory, DefaultPhysicalExprAdapterFactory, PhysicalExprAdapterFactory,
+    replace_columns_with_literals,
+};
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use datafusion_physical_plan::metrics::{ExecutionPlanMetricsSet, MetricBuilder};
+use datafusion_pruning::build_pruning_predicate;
+use futures::{StreamExt, TryStreamExt};
+use object_store::ObjectStore;
+use orc_rust::array_decoder::NaiveStripeDecoder;
+use orc_rust::projection::ProjectionMask;
+use orc_rust::reader::metadata::read_metadata_async;
+use orc_rust::stripe::Stripe;
+
+/// Implements [`FileOpener`] for an ORC file
+pub(super) struct OrcOpener {
+    /// Execution partition index
+    pub partition_index: usize,
+    pub object_store: Arc<dyn ObjectStore>,
+    /// Target number of rows in each output RecordBatch
+    pub batch_size: usize,
+    /// The indices of the columns to read in the logical file schema
+    pub file_indices: Vec<usize>,
+    /// Optional predicate to prune stripes with
+    pub predicate: Option<Arc<dyn PhysicalExpr>>,
+    /// Schema of the table, including partition columns
+    pub table_schema: TableSchema,
+    pub metrics: ExecutionPlanMetricsSet,
+}
+
+impl FileOpener for OrcOpener {
+    fn open(&self, partitioned_file: PartitionedFile) -> Result<FileOpenFuture> {
+        let file_metrics = OrcFileMetrics::new(
+            self.partition_index,
+            partitioned_file.object_meta.location.as_ref(),
+            &self.metrics,
+        );
+        let predicate_creation_errors = MetricBuilder::new(&self.metrics)
+            .global_counter("num_predicate_creation_errors");
+
+        // Replace the partition columns of the predicate with the partition
+        // values of the file, so that it only references columns of the file
+        let literal_columns: HashMap<String, ScalarValue> = self
+            .table_schema
+            .table_partition_cols()
+            .iter()
+            .zip(partitioned_file.partition_values.iter())
+            .map(|(field, value)| (field.name().clone(), value.clone()))
+            .collect();
+        let predicate = self
+            .predicate
+            .clone()
+            .map(|predicate| replace_columns_with_literals(predicate, &literal_columns))
+            .transpose()?;
+
+        let logical_file_schema = Arc::clone(self.table_schema.file_schema());
+        let projected_schema = Arc::new(logical_file_schema.project(&self.file_indices)?);
+        let object_store = Arc::clone(&self.object_store);
+        let batch_size = self.batch_size;
+
+        Ok(Box::pin(async move {
+            let mut reader =
+                ObjectStoreReader::new(object_store, partitioned_file.object_meta);
+            let mut metadata_timer = file_metrics.metadata_load_time.timer();
+            let metadata = read_metadata_async(&mut reader)
+                .await
+                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+            metadata_timer.stop();
+
+            let user_metadata = metadata
+                .user_custom_metadata()
+                .iter()
+                .map(|(key, value)| {
+                    (key.clone(), String::from_utf8_lossy(value).to_string())
+                })
+                .collect::<HashMap<_, _>>();
+            let physical_file_schema = Arc::new(
+                metadata
+                    .root_data_type()
+                    .create_arrow_schema(&user_metadata),
+            );
+
+            // Adapt the predicate to the physical file schema, so that it is
+            // evaluated against the statistics of the columns of this file
+            let rewriter = DefaultPhysicalExprAdapterFactory.create(
+                Arc::clone(&logical_file_schema),
+                Arc::clone(&physical_file_schema),
+            );
+            let simplifier = PhysicalExprSimplifier::new(&physical_file_schema);
+            let pruning_predicate = predicate
+                .map(|predicate| simplifier.simplify(rewriter.rewrite(predicate)?))
+                .transpose()?
+                .and_then(|predicate| {
+                    build_pruning_predicate(
+                        predicate,
+                        &physical_file_schema,
+                        &predicate_creation_errors,
+                    )
+                });
+
+            // Only read the stripes that start within the range to scan, so
+            // that each stripe is read by exactly one partition
+            let mut stripes = metadata
+                .stripe_metadatas()
+                .iter()
+                .enumerate()
+                .filter(|(_, stripe)| {
+                    partitioned_file
+                        .range
+                        .as_ref()
+                        .is_none_or(|range| range.contains(stripe.offset() as i64))
+                })
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
+            if let Some(pruning_predicate) = &pruning_predicate {
+                let writer_version = read_writer_version(&mut reader).await?;
+                stripes = prune_stripes_by_statistics(
+                    &metadata,
+                    writer_version,
+                    stripes,
+                    pruning_predicate,
+                    &file_metrics,
+                );
+            }
+
+            // Columns that are missing from the file are filled with nulls
+            // when adapting the batches to the projected schema
+            let column_names = projected_schema
+                .fields()
+                .iter()
+                .map(|field| field.name().as_str())
+                .filter(|&name| physical_file_schema.field_with_name(name).is_ok())
+                .collect::<Vec<_>>();
+            let projection =
+                ProjectionMask::named_roots(metadata.root_data_type(), &column_names);
+            let projected_data_type = metadata.root_data_type().project(&projection);
+            let read_schema =
+                Arc::new(projected_data_type.create_arrow_schema(&user_metadata));
+            let adapter = BatchAdapterFactory::new(projected_schema)
+                .make_adapter(Arc::clone(&read_schema))?;
+
+            // The stripes are read one after the other, reusing the metadata
+            // read from the footer above
+            let metadata = Arc::new(metadata);
+            let stream = futures::stream::iter(stripes)
+                .then(move |stripe| {
+                    let mut reader = reader.clone();
+                    let metadata = Arc::clone(&metadata);
+                    let projected_data_type = projected_data_type.clone();
+                    let read_schema = Arc::clone(&read_schema);
+                    async move {
+                        let info = &metadata.stripe_metadatas()[stripe];
+                        let stripe = Stripe::new_async(
+                            &mut reader,
+                            &metadata,
+                            &projected_data_type,
+                            info,
+                        )
+                        .await
+                        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                        let decoder =
+                            NaiveStripeDecoder::new(stripe, read_schema, batch_size)
+                                .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                        Ok::<_, DataFusionError>(
+                            futures::stream::iter(decoder)
+                                .map_err(|e| DataFusionError::External(Box::new(e))),
+                        )
+                    }
+                })
+                .try_flatten()
+                .map(move |batch| adapter.adapt_batch(&batch?));
+            Ok(stream.boxed())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stripe_filter::HIVE_8732_WRITER_VERSION;
+
+    use arrow::array::{AsArray, Int32Array, Int64Array, RecordBatch, StringArray};
+    use arrow::compute::{concat_batches, max, max_string, min, min_string};
+    use arrow::datatypes::{DataType, Field, Int32Type, Schema, SchemaRef};
+    use datafusion_expr::{Expr, col, lit};
+    use datafusion_physical_expr::planner::logical2physical;
+    use datafusion_physical_plan::metrics::MetricValue;
+    use object_store::memory::InMemory;
+    use object_store::path::Path;
+    use orc_rust::ArrowWriterBuilder;
+    use prost::Message;
+
+    /// Writes `batches` to `path` in `store` as an ORC file with one stripe
+    /// per batch, and returns the size of the file
+    async fn write_orc(
+        store: &Arc<dyn ObjectStore>,
+        path: &str,
+        schema: SchemaRef,
+        batches: &[RecordBatch],
+    ) -> u64 {
+        let mut data = vec![];
+        let mut writer = ArrowWriterBuilder::new(&mut data, schema)
+            .try_build()
+            .unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+            writer.flush_stripe().unwrap();
+        }
+        writer.close().unwrap();
+
+        let size = data.len() as u64;
+        store.put(&Path::from(path), data.into()).await.unwrap();
+        size
+    }
+
+    /// Reads `file` with a projection of `file_indices` of `file_schema`
+    async fn read_orc(
+        store: &Arc<dyn ObjectStore>,
+        file_schema: SchemaRef,
+        file_indices: Vec<usize>,
+        file: PartitionedFile,
+    ) -> RecordBatch {
+        read_orc_with_predicate(store, file_schema, file_indices, None, file)
+            .await
+            .0
+    }
+
+    /// Reads `file` with a projection of `file_indices` of `file_schema`,
+    /// pruning stripes with `predicate`. Returns the rows read and the
+    /// numbers of stripes pruned and matched by their statistics.
+    async fn read_orc_with_predicate(
+        store: &Arc<dyn ObjectStore>,
+        file_schema: SchemaRef,
+        file_indices: Vec<usize>,
+        predicate: Option<Expr>,
+        file: PartitionedFile,
+    ) -> (RecordBatch, (usize, usize)) {
+        let predicate =
+            predicate.map(|predicate| logical2physical(&predicate, &file_schema));
+        let opener = OrcOpener {
+            partition_index: 0,
+            object_store: Arc::clone(store),
+            batch_size: 1024,
+            file_indices,
+            predicate,
+            table_schema: TableSchema::new(file_schema, vec![]),
+            metrics: ExecutionPlanMetricsSet::new(),
+        };
+        let projected_schema = opener
+            .table_schema
+            .file_schema()
+            .project(&opener.file_indices)
+            .unwrap();
+        let batches = opener
+            .open(file)
+            .unwrap()
+            .await
+            .unwrap()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let batch = concat_batches(&Arc::new(projected_schema), &batches).unwrap();
+
+        let pruning = match opener
+            .metrics
+            .clone_inner()
+            .sum_by_name("stripes_pruned_statistics")
+        {
+            Some(MetricValue::PruningMetrics {
+                pruning_metrics, ..
+            }) => (pruning_metrics.pruned(), pruning_metrics.matched()),
+            _ => (0, 0),
+        };
+        (batch, pruning)
+    }
+
+    /// The postscript of an ORC file
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct PostScript {
+        #[prost(uint64, optional, tag = "1")]
+        footer_length: Option<u64>,
+        #[prost(int32, optional, tag = "2")]
+        compression: Option<i32>,
+        #[prost(uint64, optional, tag = "3")]
+        compression_block_size: Option<u64>,
+        #[prost(uint32, repeated, tag = "4")]
+        version: Vec<u32>,
+        #[prost(uint64, optional, tag = "5")]
+        metadata_length: Option<u64>,
+        #[prost(uint32, optional, tag = "6")]
+        writer_version: Option<u32>,
+        #[prost(string, optional, tag = "8000")]
+        magic: Option<String>,
+    }
+
+    /// The metadata section of an ORC file, of which only the integer and
+    /// string statistics of the stripes are used here
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Metadata {
+        #[prost(message, repeated, tag = "1")]
+        stripe_stats: Vec<StripeStatistics>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct StripeStatistics {
+        #[prost(message, repeated, tag = "1")]
+        col_stats: Vec<ColumnStatistics>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct ColumnStatistics {
+        #[prost(uint64, optional, tag = "1")]
+        number_of_values: Option<u64>,
+        #[prost(message, optional, tag = "2")]
+        int_statistics: Option<IntegerStatistics>,
+        #[prost(message, optional, tag = "4")]
+        string_statistics: Option<StringStatistics>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct IntegerStatistics {
+        #[prost(sint64, optional, tag = "1")]
+        minimum: Option<i64>,
+        #[prost(sint64, optional, tag = "2")]
+        maximum: Option<i64>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct StringStatistics {
+        #[prost(string, optional, tag = "1")]
+        minimum: Option<String>,
+        #[prost(string, optional, tag = "2")]
+        maximum: Option<String>,
+    }
+
+    /// The statistics of the columns of `batch`, with the root struct first
+    fn stripe_statistics(batch: &RecordBatch) -> StripeStatistics {
+        let root = ColumnStatistics {
+            number_of_values: Some(batch.num_rows() as u64),
+            ..Default::default()
+        };
+        let columns = batch.columns().iter().map(|column| {
+            let mut statistics = ColumnStatistics {
+                number_of_values: Some((column.len() - column.null_count()) as u64),
+                ..Default::default()
+            };
+            if let Some(column) = column.as_primitive_opt::<Int32Type>() {
+                statistics.int_statistics = Some(IntegerStatistics {
+                    minimum: min(column).map(i64::from),
+                    maximum: max(column).map(i64::from),
+                });
+            } else {
+                let column = column.as_string::<i32>();
+                statistics.string_statistics = Some(StringStatistics {
+                    minimum: min_string(column).map(String::from),
+                    maximum: max_string(column).map(String::from),
+                });
+            }
+            statistics
+        });
+        StripeStatistics {
+            col_stats: std::iter::once(root).chain(columns).collect(),
+        }
+    }
+
+    /// Writes `batches` like [`write_orc`], with the stripe statistics that
+    /// orc-rust does not write and with `writer_version` in the postscript
+    async fn write_orc_with_statistics(
+        store: &Arc<dyn ObjectStore>,
+        path: &str,
+        schema: SchemaRef,
+        batches: &[RecordBatch],
+        writer_version: u32,
+    ) -> u64 {
+        write_orc(store, path, schema, batches).await;
+        let data = store
+            .get(&Path::from(path))
+            .await
+            .unwrap()
+            .bytes()
+            .await
+            .unwrap();
+
+        // The tail of the file is the metadata, the footer and the postscript,
+        // followed by the length of the postscript
+        let (&postscript_len, rest) = data.split_last().unwrap();
+        let (rest, postscript) = rest.split_at(rest.len() - postscript_len as usize);
+        let mut postscript = PostScript::decode(postscript).unwrap();
+        let footer_len = postscript.footer_length.unwrap() as usize;
+        let (rest, footer) = rest.split_at(rest.len() - footer_len);
+        let metadata_len = postscript.metadata_length.unwrap_or(0) as usize;
+        let body = &rest[..rest.len() - metadata_len];
+
+        let metadata = Metadata {
+            stripe_stats: batches.iter().map(stripe_statistics).collect(),
+        }
+        .encode_to_vec();
+        postscript.metadata_length = Some(metadata.len() as u64);
+        postscript.writer_version = Some(writer_version);
+        let postscript = postscript.encode_to_vec();
+
+        let data = [
+            body,
+            &metadata,
+            footer,
+            &postscript,
+            &[postscript.len() as u8],
+        ]
+        .concat();
+        let size = data.len() as u64;
+        store.put(&Path::from(path), data.into()).await.unwrap();
+        size
+    }
+
+    fn int_batch(schema: &SchemaRef, values: Vec<i32>) -> RecordBatch {
+        let names = values.iter().map(|v| format!("v{v}")).collect::<Vec<_>>();
+        RecordBatch::try_new(
+            Arc::clone(schema),
+            vec![
+                Arc::new(Int32Array::from(values)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn file_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]))
+    }
+
+    #[tokio::test]
+    async fn test_read_projection() {
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+        let schema = file_schema();
+        let batches = [int_batch(&schema, vec![1, 2, 3])];
+        let size = write_orc(&store, "test.orc", Arc::clone(&schema), &batches).await;
+        let file = PartitionedFile::new("test.orc", size);
+
+        // The column "c" of the table is missing from the file
+        let table_schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Int64, true),
+        ]));
+        let batch = read_orc(&store, table_schema, vec![2, 1], file).await;
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &Int64Array::from(vec![None, None, None])
+        );
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &StringArray::from(vec!["v1", "v2", "v3"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_stripes_split_across_ranges() {
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+        let schema = file_schema();
+        let batches = [
+            int_batch(&schema, vec![1, 2]),
+            int_batch(&schema, vec![3, 4]),
+            int_batch(&schema, vec![5, 6]),
+        ];
+        let size = write_orc(&store, "test.orc", Arc::clone(&schema), &batches).await;
+
+        let meta = store.head(&Path::from("test.orc")).await.unwrap();
+        let mut reader = ObjectStoreReader::new(Arc::clone(&store), meta);
+        let metadata = read_metadata_async(&mut reader).await.unwrap();
+        let offsets = metadata
+            .stripe_metadatas()
+            .iter()
+            .map(|stripe| stripe.offset() as i64)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets.len(), 3);
+
+        // Every stripe is read by the range it starts in, even if the range
+        // ends in the middle of the stripe
+        let values = async |start: i64, end: i64| {
+            let file = PartitionedFile::new("test.orc", size).with_range(start, end);
+            let batch = read_orc(&store, Arc::clone(&schema), vec![0], file).await;
+            batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .values()
+                .to_vec()
+        };
+        assert_eq!(values(0, offsets[1]).await, vec![1, 2]);
+        assert_eq!(values(0, offsets[1] + 1).await, vec![1, 2, 3, 4]);
+        assert_eq!(values(offsets[1] + 1, size as i64).await, vec![5, 6]);
+        assert_eq!(values(offsets[2] + 1, size as i64).await, Vec::<i32>::new());
+    }
+
+    #[tokio::test]
+    async fn test_prune_stripes_by_statistics() {
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+        let schema = file_schema();
+        let batches = [
+            int_batch(&schema, vec![1, 2]),
+            int_batch(&schema, vec![3, 4]),
+            int_batch(&schema, vec![5, 6]),
+        ];
+        let size = write_orc_with_statistics(
+            &store,
+            "test.orc",
+            Arc::clone(&schema),
+            &batches,
+            HIVE_8732_WRITER_VERSION,
+        )
+        .await;
+
+        let read = async |predicate: Expr| {
+            let file = PartitionedFile::new("test.orc", size);
+            let (batch, pruning) = read_orc_with_predicate(
+                &store,
+                Arc::clone(&schema),
+                vec![0],
+                Some(predicate),
+                file,
+            )
+            .await;
+            let values = batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .values()
+                .to_vec();
+            (values, pruning)
+        };
+
+        assert_eq!(read(col("a").gt(lit(4))).await, (vec![5, 6], (2, 1)));
+        assert_eq!(read(col("b").eq(lit("v3"))).await, (vec![3, 4], (2, 1)));
+        assert_eq!(read(col("a").is_null()).await, (vec![], (3, 0)));
+        assert_eq!(
+            read(col("a").is_not_null()).await,
+            (vec![1, 2, 3, 4, 5, 6], (0, 3))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_string_statistics_ignored_before_hive_8732() {
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+        let schema = file_schema();
+        let batches = [
+            int_batch(&schema, vec![1, 2]),
+            int_batch(&schema, vec![3, 4]),
+        ];
+        // Written before the writer version was recorded
+        let size = write_orc_with_statistics(
+            &store,
+            "old.orc",
+            Arc::clone(&schema),
+            &batches,
+            0,
+        )
+        .await;
+
+        let read = async |predicate: Expr| {
+            let file = PartitionedFile::new("old.orc", size);
+            let (batch, pruning) = read_orc_with_predicate(
+                &store,
+                Arc::clone(&schema),
+                vec![0, 1],
+                Some(predicate),
+                file,
+            )
+            .await;
+            (batch.num_rows(), pruning)
+        };
+
+        // Only the statistics of strings are ignored
+        assert_eq!(read(col("b").eq(lit("v3"))).await, (4, (0, 2)));
+        assert_eq!(read(col("a").gt(lit(2))).await, (2, (1, 1)));
+    }
+
+    #[tokio::test]
+    async fn test_read_empty_file() {
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+        let schema = file_schema();
+        let size = write_orc(&store, "empty.orc", Arc::clone(&schema), &[]).await;
+        let file = PartitionedFile::new("empty.orc", size);
+
+        let batch = read_orc(&store, schema, vec![0, 1], file).await;
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.num_columns(), 2);
+    }
+}
@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`AsyncChunkReader`] implementation over an [`ObjectStore`]
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use object_store::{ObjectMeta, ObjectStore};
+use orc_rust::reader::AsyncChunkReader;
+
+/// Reads the bytes of an ORC file from an [`ObjectStore`]
+#[derive(Debug, Clone)]
+pub struct ObjectStoreReader {
+    store: Arc<dyn ObjectStore>,
+    file: ObjectMeta,
+}
+
+impl ObjectStoreReader {
+    /// Creates a reader of `file` in `store`
+    pub fn new(store: Arc<dyn ObjectStore>, file: ObjectMeta) -> Self {
+        Self { store, file }
+    }
+}
+
+impl AsyncChunkReader for ObjectStoreReader {
+    fn len(&mut self) -> BoxFuture<'_, std::io::Result<u64>> {
+        async move { Ok(self.file.size) }.boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        offset_from_start: u64,
+        length: u64,
+    ) -> BoxFuture<'_, std::io::Result<Bytes>> {
+        let range = offset_from_start..offset_from_start + length;
+        async move {
+            self.store
+                .get_range(&self.file.location, range)
+                .await
+                .map_err(std::io::Error::other)
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution plan for reading ORC files
+
+use std::any::Any;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::opener::OrcOpener;
+
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::{Result, internal_err};
+use datafusion_datasource::TableSchema;
+use datafusion_datasource::file::FileSource;
+use datafusion_datasource::file_scan_config::FileScanConfig;
+use datafusion_datasource::file_stream::FileOpener;
+use datafusion_datasource::projection::{ProjectionOpener, SplitProjection};
+use datafusion_physical_expr::conjunction;
+use datafusion_physical_expr_common::physical_expr::{PhysicalExpr, fmt_sql};
+use datafusion_physical_plan::DisplayFormatType;
+use datafusion_physical_plan::filter_pushdown::{FilterPushdownPropagation, PushedDown};
+use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
+use datafusion_physical_plan::projection::ProjectionExprs;
+
+use object_store::ObjectStore;
+
+/// Execution plan source for reading one or more ORC files.
+///
+/// Supports:
+/// * Projection of the top level columns of the files
+/// * Pruning of stripes using the column statistics stored in the files
+/// * Reading the stripes of a file in parallel, by splitting it into byte ranges
+///
+/// Filters pushed down to an [`OrcSource`] are only used to prune stripes: the
+/// rows of the stripes that are read are not filtered, so the filters are
+/// still applied above the scan.
+#[derive(Clone)]
+pub struct OrcSource {
+    table_schema: TableSchema,
+    batch_size: Option<usize>,
+    projection: SplitProjection,
+    /// Optional predicate for pruning stripes
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl OrcSource {
+    /// Initialize an OrcSource with the provided schema
+    pub fn new(table_schema: impl Into<TableSchema>) -> Self {
+        let table_schema = table_schema.into();
+        Self {
+            projection: SplitProjection::unprojected(&table_schema),
+            table_schema,
+            batch_size: None,
+            predicate: None,
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
+    }
+
+    /// Set predicate information, used to prune stripes
+    pub fn with_predicate(&self, predicate: Arc<dyn PhysicalExpr>) -> Self {
+        let mut conf = self.clone();
+        conf.predicate = Some(predicate);
+        conf
+    }
+
+    /// Optional predicate used to prune stripes
+    pub fn predicate(&self) -> Option<&Arc<dyn PhysicalExpr>> {
+        self.predicate.as_ref()
+    }
+}
+
+impl FileSource for OrcSource {
+    fn create_file_opener(
+        &self,
+        object_store: Arc<dyn ObjectStore>,
+        _base_config: &FileScanConfig,
+        partition: usize,
+    ) -> Result<Arc<dyn FileOpener>> {
+        let Some(batch_size) = self.batch_size else {
+            return internal_err!("Batch size must be set before creating OrcOpener");
+        };
+        let opener = Arc::new(OrcOpener {
+            partition_index: partition,
+            object_store,
+            batch_size,
+            file_indices: self.projection.file_indices.clone(),
+            predicate: self.predicate.clone(),
+            table_schema: self.table_schema.clone(),
+            metrics: self.metrics.clone(),
+        });
+        ProjectionOpener::try_new(
+            self.projection.clone(),
+            opener,
+            self.table_schema.file_schema(),
+        )
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_schema(&self) -> &TableSchema {
+        &self.table_schema
+    }
+
+    fn filter(&self) -> Option<Arc<dyn PhysicalExpr>> {
+        self.predicate.clone()
+    }
+
+    fn with_batch_size(&self, batch_size: usize) -> Arc<dyn FileSource> {
+        let mut conf = self.clone();
+        conf.batch_size = Some(batch_size);
+        Arc::new(conf)
+    }
+
+    fn try_pushdown_projection(
+        &self,
+        projection: &ProjectionExprs,
+    ) -> Result<Option<Arc<dyn FileSource>>> {
+        let mut source = self.clone();
+        let new_projection = self.projection.source.try_merge(projection)?;
+        let split_projection =
+            SplitProjection::new(self.table_schema.file_schema(), &new_projection);
+        source.projection = split_projection;
+        Ok(Some(Arc::new(source)))
+    }
+
+    fn projection(&self) -> Option<&ProjectionExprs> {
+        Some(&self.projection.source)
+    }
+
+    fn metrics(&self) -> &ExecutionPlanMetricsSet {
+        &self.metrics
+    }
+
+    fn file_type(&self) -> &str {
+        "orc"
+    }
+
+    fn fmt_extra(&self, t: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                if let Some(predicate) = self.filter() {
+                    write!(f, ", predicate={predicate}")?;
+                }
+                Ok(())
+            }
+            DisplayFormatType::TreeRender => {
+                if let Some(predicate) = self.filter() {
+                    writeln!(f, "predicate={}", fmt_sql(predicate.as_ref()))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn try_pushdown_filters(
+        &self,
+        filters: Vec<Arc<dyn PhysicalExpr>>,
+        _config: &ConfigOptions,
+    ) -> Result<FilterPushdownPropagation<Arc<dyn FileSource>>> {
+        if filters.is_empty() {
+            return Ok(FilterPushdownPropagation::with_parent_pushdown_result(
+                vec![],
+            ));
+        }
+        // The filters are only used to prune stripes, so our parents still
+        // have to apply them to the rows that are read
+        let pushdown_result = vec![PushedDown::No; filters.len()];
+        let predicate = match &self.predicate {
+            Some(predicate) => {
+                conjunction(std::iter::once(Arc::clone(predicate)).chain(filters))
+            }
+            None => conjunction(filters),
+        };
+        let source = Arc::new(self.with_predicate(predicate));
+        Ok(
+            FilterPushdownPropagation::with_parent_pushdown_result(pushdown_result)
+                .with_updated_node(source),
+        )
+    }
+}
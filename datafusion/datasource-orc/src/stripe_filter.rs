@@ -0,0 +1,449 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Stripe pruning using the column statistics of ORC stripes
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::OrcFileMetrics;
+
+use arrow::array::{ArrayRef, BooleanArray, UInt64Array};
+use arrow::datatypes::{DataType, Schema};
+use datafusion_common::pruning::PruningStatistics;
+use datafusion_common::{Column, DataFusionError, Result, ScalarValue};
+use datafusion_pruning::PruningPredicate;
+use orc_rust::reader::AsyncChunkReader;
+use orc_rust::reader::metadata::FileMetadata;
+use orc_rust::statistics::{ColumnStatistics, TypeStatistics};
+use prost::Message;
+
+/// The first writer version whose string statistics can be used, see
+/// [HIVE-8732](https://issues.apache.org/jira/browse/HIVE-8732)
+pub(crate) const HIVE_8732_WRITER_VERSION: u32 = 1;
+
+/// The postscript of an ORC file, of which only the writer version is decoded
+#[derive(Clone, PartialEq, Message)]
+struct PostScript {
+    #[prost(uint32, optional, tag = "6")]
+    writer_version: Option<u32>,
+}
+
+/// Reads the writer version from the postscript at the end of an ORC file,
+/// `0` for files written before it was recorded.
+pub(crate) async fn read_writer_version<R: AsyncChunkReader>(
+    reader: &mut R,
+) -> Result<u32> {
+    // The postscript is at most 255 bytes long, and followed by its length
+    let file_len = reader.len().await?;
+    let tail_len = file_len.min(256);
+    let tail = reader.get_bytes(file_len - tail_len, tail_len).await?;
+    let Some((&postscript_len, tail)) = tail.split_last() else {
+        return Ok(0);
+    };
+    let postscript = tail
+        .len()
+        .checked_sub(postscript_len as usize)
+        .map(|start| &tail[start..])
+        .ok_or_else(|| {
+            DataFusionError::Execution("Invalid ORC postscript length".to_string())
+        })?;
+    let postscript = PostScript::decode(postscript)
+        .map_err(|e| DataFusionError::External(Box::new(e)))?;
+    Ok(postscript.writer_version.unwrap_or(0))
+}
+
+/// Returns the stripes of `stripes` that may contain rows matching `predicate`,
+/// according to the stripe-level column statistics of the file.
+///
+/// All of `stripes` are returned if the predicate can not be evaluated.
+pub(crate) fn prune_stripes_by_statistics(
+    metadata: &FileMetadata,
+    writer_version: u32,
+    stripes: Vec<usize>,
+    predicate: &PruningPredicate,
+    metrics: &OrcFileMetrics,
+) -> Vec<usize> {
+    let _timer_guard = metrics.statistics_eval_time.timer();
+
+    let statistics = StripePruningStatistics::from_metadata(
+        metadata,
+        writer_version,
+        &stripes,
+        predicate.schema(),
+    );
+    match predicate.prune(&statistics) {
+        Ok(values) => {
+            let (kept, pruned): (Vec<_>, Vec<_>) =
+                stripes.into_iter().zip(values).partition(|(_, keep)| *keep);
+            metrics.stripes_pruned_statistics.add_pruned(pruned.len());
+            metrics.stripes_pruned_statistics.add_matched(kept.len());
+            kept.into_iter().map(|(stripe, _)| stripe).collect()
+        }
+        // stripes can still be read, even if the predicate can not be evaluated
+        Err(e) => {
+            log::debug!("Error evaluating stripe predicate values {e}");
+            metrics.predicate_evaluation_errors.add(1);
+            stripes
+        }
+    }
+}
+
+/// The statistics of a column in a stripe, as used for pruning
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct StripeColumnStatistics {
+    /// The minimum value, if known
+    min: Option<ScalarValue>,
+    /// The maximum value, if known
+    max: Option<ScalarValue>,
+    /// The number of nulls, if known
+    null_count: Option<u64>,
+}
+
+impl StripeColumnStatistics {
+    /// Converts the ORC statistics of a column in a stripe of `num_rows` rows.
+    ///
+    /// Only the statistics of integer, floating point, string and date
+    /// columns are used: the minimum and maximum of other types are unknown.
+    /// As in the Java ORC reader, the minimum and maximum of strings are not
+    /// used for files written before HIVE-8732 fixed them, as `writer_version`
+    /// tells.
+    fn from_orc(
+        statistics: &ColumnStatistics,
+        num_rows: u64,
+        writer_version: u32,
+    ) -> Self {
+        let (min, max) = match statistics.type_statistics() {
+            Some(TypeStatistics::Integer { min, max, .. }) => (
+                Some(ScalarValue::Int64(Some(*min))),
+                Some(ScalarValue::Int64(Some(*max))),
+            ),
+            // NaN is not ordered relative to other values
+            Some(TypeStatistics::Double { min, max, .. })
+                if !min.is_nan() && !max.is_nan() =>
+            {
+                (
+                    Some(ScalarValue::Float64(Some(*min))),
+                    Some(ScalarValue::Float64(Some(*max))),
+                )
+            }
+            Some(TypeStatistics::String { min, max, .. })
+                if writer_version >= HIVE_8732_WRITER_VERSION =>
+            {
+                (
+                    Some(ScalarValue::Utf8(Some(min.clone()))),
+                    Some(ScalarValue::Utf8(Some(max.clone()))),
+                )
+            }
+            Some(TypeStatistics::Date { min, max }) => (
+                Some(ScalarValue::Date32(Some(*min))),
+                Some(ScalarValue::Date32(Some(*max))),
+            ),
+            _ => (None, None),
+        };
+        // `has_null` is not written by all writers, so it is not relied on
+        Self {
+            min,
+            max,
+            null_count: num_rows.checked_sub(statistics.number_of_values()),
+        }
+    }
+}
+
+/// Wraps the column statistics of a set of stripes of an ORC file, one
+/// container per stripe, and implements [`PruningStatistics`].
+///
+/// Columns are resolved by name against `schema`, the schema the
+/// [`PruningPredicate`] was built against. The statistics of a column are only
+/// used if they can be represented exactly in the type of its field.
+pub(crate) struct StripePruningStatistics<'a> {
+    schema: &'a Schema,
+    /// The number of rows of each stripe
+    row_counts: Vec<u64>,
+    /// The statistics of each stripe, by column name
+    columns: HashMap<String, Vec<StripeColumnStatistics>>,
+}
+
+impl<'a> StripePruningStatistics<'a> {
+    fn new(
+        schema: &'a Schema,
+        row_counts: Vec<u64>,
+        columns: HashMap<String, Vec<StripeColumnStatistics>>,
+    ) -> Self {
+        Self {
+            schema,
+            row_counts,
+            columns,
+        }
+    }
+
+    /// Collects the statistics of the top level columns of `stripes` in `metadata`
+    pub(crate) fn from_metadata(
+        metadata: &FileMetadata,
+        writer_version: u32,
+        stripes: &[usize],
+        schema: &'a Schema,
+    ) -> Self {
+        let stripe_metadatas = metadata.stripe_metadatas();
+        let row_counts = stripes
+            .iter()
+            .map(|&stripe| stripe_metadatas[stripe].number_of_rows())
+            .collect();
+        let columns = metadata
+            .root_data_type()
+            .children()
+            .iter()
+            .filter(|column| schema.field_with_name(column.name()).is_ok())
+            .map(|column| {
+                let column_index = column.data_type().column_index();
+                let statistics = stripes
+                    .iter()
+                    .map(|&stripe| {
+                        let stripe = &stripe_metadatas[stripe];
+                        stripe
+                            .column_statistics()
+                            .get(column_index)
+                            .map(|statistics| {
+                                StripeColumnStatistics::from_orc(
+                                    statistics,
+                                    stripe.number_of_rows(),
+                                    writer_version,
+                                )
+                            })
+                            .unwrap_or_default()
+                    })
+                    .collect();
+                (column.name().to_string(), statistics)
+            })
+            .collect();
+        Self::new(schema, row_counts, columns)
+    }
+
+    /// Returns the values selected by `value` from the statistics of `column`,
+    /// cast to the type of its field
+    fn values(
+        &self,
+        column: &Column,
+        value: impl Fn(&StripeColumnStatistics) -> Option<&ScalarValue>,
+    ) -> Option<ArrayRef> {
+        let data_type = self.schema.field_with_name(&column.name).ok()?.data_type();
+        let statistics = self.columns.get(&column.name)?;
+        let values = statistics
+            .iter()
+            .map(|statistics| {
+                match value(statistics).and_then(|value| cast_exact(value, data_type)) {
+                    Some(value) => Ok(value),
+                    None => ScalarValue::try_from(data_type),
+                }
+            })
+            .collect::<Result<Vec<_>>>()
+            .ok()?;
+        ScalarValue::iter_to_array(values).ok()
+    }
+}
+
+/// Casts a statistics value to `data_type` if it represents the same value
+/// in `data_type`, and orders the same way.
+fn cast_exact(value: &ScalarValue, data_type: &DataType) -> Option<ScalarValue> {
+    let compatible = match value.data_type() {
+        DataType::Int64 => data_type.is_integer(),
+        DataType::Float64 => data_type.is_floating(),
+        DataType::Utf8 => matches!(
+            data_type,
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+        ),
+        value_type => &value_type == data_type,
+    };
+    if !compatible {
+        return None;
+    }
+    let cast = value.cast_to(data_type).ok()?;
+    (cast.cast_to(&value.data_type()).ok()? == *value).then_some(cast)
+}
+
+impl PruningStatistics for StripePruningStatistics<'_> {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.values(column, |statistics| statistics.min.as_ref())
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.values(column, |statistics| statistics.max.as_ref())
+    }
+
+    fn num_containers(&self) -> usize {
+        self.row_counts.len()
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        let statistics = self.columns.get(&column.name)?;
+        let null_counts = statistics
+            .iter()
+            .map(|statistics| statistics.null_count)
+            .collect::<UInt64Array>();
+        Some(Arc::new(null_counts))
+    }
+
+    fn row_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        Some(Arc::new(UInt64Array::from(self.row_counts.clone())))
+    }
+
+    fn contained(
+        &self,
+        _column: &Column,
+        _values: &HashSet<ScalarValue>,
+    ) -> Option<BooleanArray> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::datatypes::Field;
+    use datafusion_expr::{Expr, col, lit};
+    use datafusion_physical_expr::planner::logical2physical;
+
+    fn int_statistics(min: i64, max: i64, null_count: u64) -> StripeColumnStatistics {
+        StripeColumnStatistics {
+            min: Some(ScalarValue::Int64(Some(min))),
+            max: Some(ScalarValue::Int64(Some(max))),
+            null_count: Some(null_count),
+        }
+    }
+
+    fn prune(
+        schema: &Schema,
+        columns: Vec<(&str, Vec<StripeColumnStatistics>)>,
+        expr: &Expr,
+    ) -> Vec<bool> {
+        let num_stripes = columns[0].1.len();
+        let columns = columns
+            .into_iter()
+            .map(|(name, statistics)| (name.to_string(), statistics))
+            .collect();
+        let statistics =
+            StripePruningStatistics::new(schema, vec![10; num_stripes], columns);
+        let predicate = PruningPredicate::try_new(
+            logical2physical(expr, schema),
+            Arc::new(schema.clone()),
+        )
+        .unwrap();
+        predicate.prune(&statistics).unwrap()
+    }
+
+    #[test]
+    fn test_prune_integer_statistics() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let statistics = vec![
+            int_statistics(0, 3, 0),
+            int_statistics(4, 9, 0),
+            int_statistics(10, 20, 0),
+        ];
+
+        let kept = prune(
+            &schema,
+            vec![("a", statistics.clone())],
+            &col("a").gt(lit(5)),
+        );
+        assert_eq!(kept, vec![false, true, true]);
+
+        let kept = prune(&schema, vec![("a", statistics)], &col("a").eq(lit(2)));
+        assert_eq!(kept, vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_prune_string_statistics() {
+        let schema = Schema::new(vec![Field::new("s", DataType::Utf8View, true)]);
+        let string_statistics = |min: &str, max: &str| StripeColumnStatistics {
+            min: Some(ScalarValue::Utf8(Some(min.to_string()))),
+            max: Some(ScalarValue::Utf8(Some(max.to_string()))),
+            null_count: Some(0),
+        };
+        let statistics = vec![
+            string_statistics("apple", "banana"),
+            string_statistics("cherry", "grape"),
+        ];
+
+        let date = lit(ScalarValue::Utf8View(Some("date".to_string())));
+        let kept = prune(&schema, vec![("s", statistics)], &col("s").eq(date));
+        assert_eq!(kept, vec![false, true]);
+    }
+
+    #[test]
+    fn test_prune_null_counts() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let all_null = StripeColumnStatistics {
+            min: None,
+            max: None,
+            null_count: Some(10),
+        };
+        let statistics = vec![int_statistics(0, 3, 0), all_null];
+
+        let kept = prune(
+            &schema,
+            vec![("a", statistics.clone())],
+            &col("a").is_not_null(),
+        );
+        assert_eq!(kept, vec![true, false]);
+
+        let kept = prune(&schema, vec![("a", statistics)], &col("a").is_null());
+        assert_eq!(kept, vec![false, true]);
+    }
+
+    #[test]
+    fn test_unknown_statistics_are_kept() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int8, true),
+            Field::new("b", DataType::Int64, true),
+        ]);
+        // 1000 does not fit in the Int8 field, and "b" has no statistics
+        let statistics = vec![int_statistics(0, 1000, 0), int_statistics(0, 3, 0)];
+
+        let kept = prune(&schema, vec![("a", statistics)], &col("a").gt(lit(5i8)));
+        assert_eq!(kept, vec![true, false]);
+
+        let kept = prune(
+            &schema,
+            vec![("a", vec![int_statistics(0, 3, 0)])],
+            &col("b").gt(lit(5i64)),
+        );
+        assert_eq!(kept, vec![true]);
+    }
+
+    #[test]
+    fn test_cast_exact() {
+        let value = ScalarValue::Int64(Some(300));
+        assert_eq!(
+            cast_exact(&value, &DataType::Int16),
+            Some(ScalarValue::Int16(Some(300)))
+        );
+        assert_eq!(cast_exact(&value, &DataType::Int8), None);
+        assert_eq!(cast_exact(&value, &DataType::Utf8), None);
+
+        let value = ScalarValue::Float64(Some(0.1));
+        assert_eq!(cast_exact(&value, &DataType::Float32), None);
+        assert_eq!(cast_exact(&value, &DataType::Int64), None);
+
+        let value = ScalarValue::Utf8(Some("10".to_string()));
+        assert_eq!(cast_exact(&value, &DataType::Int64), None);
+        assert_eq!(
+            cast_exact(&value, &DataType::LargeUtf8),
+            Some(ScalarValue::LargeUtf8(Some("10".to_string())))
+        );
+    }
+}
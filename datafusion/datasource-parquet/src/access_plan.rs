@@ -18,6 +18,8 @@
 use datafusion_common::{Result, assert_eq_or_internal_err};
 use parquet::arrow::arrow_reader::{RowSelection, RowSelector};
 use parquet::file::metadata::RowGroupMetaData;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// A selection of rows and row groups within a ParquetFile to decode.
 ///
@@ -149,6 +151,22 @@ impl ParquetAccessPlan {
         self.row_groups[idx].should_scan()
     }
 
+    /// Skip a random sample of the row groups, keeping every row group
+    /// independently with probability `fraction` (`TABLESAMPLE SYSTEM`).
+    ///
+    /// Row groups that are already skipped remain skipped. The same `seed`
+    /// always keeps the same row groups.
+    pub fn sample_row_groups(&mut self, fraction: f64, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        for access in self.row_groups.iter_mut() {
+            // Draw for every row group, so that the choice of a row group
+            // does not depend on which other row groups are skipped
+            if !rng.random_bool(fraction) {
+                *access = RowGroupAccess::Skip;
+            }
+        }
+    }
+
     /// Set to scan only the [`RowSelection`] in the specified row group.
     ///
     /// Behavior is different depending on the existing access
@@ -461,6 +479,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_sample_row_groups() {
+        let mut access_plan = ParquetAccessPlan::new_all(100);
+        access_plan.skip(0);
+        access_plan.sample_row_groups(0.5, 42);
+        assert!(!access_plan.should_scan(0));
+        let scanned = access_plan.row_group_indexes();
+        assert!(!scanned.is_empty() && scanned.len() < 99);
+
+        // The same seed keeps the same row groups
+        let mut repeated = ParquetAccessPlan::new_all(100);
+        repeated.sample_row_groups(0.5, 42);
+        repeated.skip(0);
+        assert_eq!(access_plan, repeated);
+
+        let mut all = ParquetAccessPlan::new_all(10);
+        all.sample_row_groups(1.0, 7);
+        assert_eq!(all, ParquetAccessPlan::new_all(10));
+        let mut none = ParquetAccessPlan::new_all(10);
+        none.sample_row_groups(0.0, 7);
+        assert_eq!(none, ParquetAccessPlan::new_none(10));
+    }
+
     #[test]
     fn test_invalid_too_few() {
         let access_plan = ParquetAccessPlan::new(vec![
@@ -445,6 +445,7 @@ impl FileFormat for ParquetFormat {
             .with_metadata_size_hint(self.metadata_size_hint())
             .with_decryption_properties(file_decryption_properties)
             .with_file_metadata_cache(Some(file_metadata_cache))
+            .with_match_by_field_id(self.options.global.match_by_field_id)
             .fetch_statistics(&table_schema)
             .await
     }
@@ -33,16 +33,18 @@ use datafusion_common::{
 };
 use datafusion_execution::cache::cache_manager::{FileMetadata, FileMetadataCache};
 use datafusion_functions_aggregate_common::min_max::{MaxAccumulator, MinAccumulator};
+use datafusion_physical_expr_adapter::find_field;
 use datafusion_physical_plan::Accumulator;
 use log::debug;
 use object_store::path::Path;
 use object_store::{ObjectMeta, ObjectStore};
 use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
-use parquet::arrow::{parquet_column, parquet_to_arrow_schema};
+use parquet::arrow::{
+    PARQUET_FIELD_ID_META_KEY, parquet_column, parquet_to_arrow_schema,
+};
 use parquet::file::metadata::{
     PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData,
 };
-use parquet::schema::types::SchemaDescriptor;
 use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -63,6 +65,8 @@ pub struct DFParquetMetadata<'a> {
     file_metadata_cache: Option<Arc<dyn FileMetadataCache>>,
     /// timeunit to coerce INT96 timestamps to
     pub coerce_int96: Option<TimeUnit>,
+    /// resolve the statistics of columns by their field ID
+    match_by_field_id: bool,
 }
 
 impl<'a> DFParquetMetadata<'a> {
@@ -74,6 +78,7 @@ impl<'a> DFParquetMetadata<'a> {
             decryption_properties: None,
             file_metadata_cache: None,
             coerce_int96: None,
+            match_by_field_id: false,
         }
     }
 
@@ -107,6 +112,13 @@ impl<'a> DFParquetMetadata<'a> {
         self
     }
 
+    /// Resolve the columns of the table schema to those of the file by their
+    /// [`PARQUET_FIELD_ID_META_KEY`] metadata in [`Self::fetch_statistics`]
+    pub fn with_match_by_field_id(mut self, match_by_field_id: bool) -> Self {
+        self.match_by_field_id = match_by_field_id;
+        self
+    }
+
     /// Fetch parquet metadata from the remote object store
     pub async fn fetch_metadata(&self) -> Result<Arc<ParquetMetaData>> {
         let Self {
@@ -116,6 +128,7 @@ impl<'a> DFParquetMetadata<'a> {
             decryption_properties,
             file_metadata_cache,
             coerce_int96: _,
+            match_by_field_id: _,
         } = self;
 
         let fetch = ObjectStoreFetch::new(*store, object_meta);
@@ -205,7 +218,12 @@ impl<'a> DFParquetMetadata<'a> {
     /// the statistics in the metadata using [`Self::statistics_from_parquet_metadata`]
     pub async fn fetch_statistics(&self, table_schema: &SchemaRef) -> Result<Statistics> {
         let metadata = self.fetch_metadata().await?;
-        Self::statistics_from_parquet_metadata(&metadata, table_schema)
+        let field_id_key = self.match_by_field_id.then_some(PARQUET_FIELD_ID_META_KEY);
+        Self::statistics_from_parquet_metadata_with_field_id_key(
+            &metadata,
+            table_schema,
+            field_id_key,
+        )
     }
 
     /// Convert statistics in [`ParquetMetaData`] into [`Statistics`] using [`StatisticsConverter`]
@@ -248,6 +266,26 @@ impl<'a> DFParquetMetadata<'a> {
     pub fn statistics_from_parquet_metadata(
         metadata: &ParquetMetaData,
         logical_file_schema: &SchemaRef,
+    ) -> Result<Statistics> {
+        Self::statistics_from_parquet_metadata_with_field_id_key(
+            metadata,
+            logical_file_schema,
+            None,
+        )
+    }
+
+    /// Like [`Self::statistics_from_parquet_metadata`], but if `field_id_key`
+    /// is set, the columns of `logical_file_schema` are resolved to those of
+    /// the file by the field IDs stored in their metadata under this key, as
+    /// [`find_field`] does.
+    ///
+    /// Columns that do not resolve to any column of the file, such as a column
+    /// that was dropped and then re-added with the same name but a new field
+    /// ID, are treated as missing from the file.
+    pub fn statistics_from_parquet_metadata_with_field_id_key(
+        metadata: &ParquetMetaData,
+        logical_file_schema: &SchemaRef,
+        field_id_key: Option<&str>,
     ) -> Result<Statistics> {
         let row_groups_metadata = metadata.row_groups();
 
@@ -281,21 +319,36 @@ impl<'a> DFParquetMetadata<'a> {
             physical_file_schema = merged;
         }
 
-        statistics.column_statistics =
-            if has_statistics {
-                let (mut max_accs, mut min_accs) =
-                    create_max_min_accs(logical_file_schema);
-                let mut null_counts_array =
-                    vec![Precision::Absent; logical_file_schema.fields().len()];
-                let mut column_byte_sizes =
-                    vec![Precision::Absent; logical_file_schema.fields().len()];
-                let mut is_max_value_exact =
-                    vec![Some(true); logical_file_schema.fields().len()];
-                let mut is_min_value_exact =
-                    vec![Some(true); logical_file_schema.fields().len()];
-                logical_file_schema.fields().iter().enumerate().for_each(
-                    |(idx, field)| match StatisticsConverter::try_new(
-                        field.name(),
+        // The name of the column of the file each column resolves to, if any
+        let physical_names = logical_file_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                find_field(physical_file_schema.fields(), field, field_id_key)
+                    .map(|idx| physical_file_schema.field(idx).name().as_str())
+            })
+            .collect::<Vec<_>>();
+
+        statistics.column_statistics = if has_statistics {
+            let (mut max_accs, mut min_accs) = create_max_min_accs(logical_file_schema);
+            let mut null_counts_array =
+                vec![Precision::Absent; logical_file_schema.fields().len()];
+            let mut column_byte_sizes =
+                vec![Precision::Absent; logical_file_schema.fields().len()];
+            let mut is_max_value_exact =
+                vec![Some(true); logical_file_schema.fields().len()];
+            let mut is_min_value_exact =
+                vec![Some(true); logical_file_schema.fields().len()];
+            physical_names
+                .iter()
+                .enumerate()
+                .for_each(|(idx, physical_name)| {
+                    let Some(physical_name) = physical_name else {
+                        null_counts_array[idx] = Precision::Exact(num_rows);
+                        return;
+                    };
+                    match StatisticsConverter::try_new(
+                        physical_name,
                         &physical_file_schema,
                         file_metadata.schema_descr(),
                     ) {
@@ -309,9 +362,7 @@ impl<'a> DFParquetMetadata<'a> {
                                 column_byte_sizes: &mut column_byte_sizes,
                             };
                             summarize_min_max_null_counts(
-                                file_metadata.schema_descr(),
                                 logical_file_schema,
-                                &physical_file_schema,
                                 &mut accumulators,
                                 idx,
                                 &stats_converter,
@@ -323,43 +374,44 @@ impl<'a> DFParquetMetadata<'a> {
                             debug!("Failed to create statistics converter: {e}");
                             null_counts_array[idx] = Precision::Exact(num_rows);
                         }
-                    },
-                );
-
-                get_col_stats(
-                    logical_file_schema,
-                    &null_counts_array,
-                    &mut max_accs,
-                    &mut min_accs,
-                    &mut is_max_value_exact,
-                    &mut is_min_value_exact,
-                    &column_byte_sizes,
-                )
-            } else {
-                // Record column sizes
-                logical_file_schema
-                    .fields()
-                    .iter()
-                    .enumerate()
-                    .map(|(logical_file_schema_index, field)| {
-                        let arrow_field =
-                            logical_file_schema.field(logical_file_schema_index);
-                        let parquet_idx = parquet_column(
-                            file_metadata.schema_descr(),
-                            &physical_file_schema,
-                            arrow_field.name(),
-                        )
+                    }
+                });
+
+            get_col_stats(
+                logical_file_schema,
+                &null_counts_array,
+                &mut max_accs,
+                &mut min_accs,
+                &mut is_max_value_exact,
+                &mut is_min_value_exact,
+                &column_byte_sizes,
+            )
+        } else {
+            // Record column sizes
+            logical_file_schema
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(logical_file_schema_index, field)| {
+                    let parquet_idx = physical_names[logical_file_schema_index]
+                        .and_then(|physical_name| {
+                            parquet_column(
+                                file_metadata.schema_descr(),
+                                &physical_file_schema,
+                                physical_name,
+                            )
+                        })
                         .map(|(idx, _)| idx);
-                        let byte_size = compute_arrow_column_size(
-                            field.data_type(),
-                            row_groups_metadata,
-                            parquet_idx,
-                            num_rows,
-                        );
-                        ColumnStatistics::new_unknown().with_byte_size(byte_size)
-                    })
-                    .collect()
-            };
+                    let byte_size = compute_arrow_column_size(
+                        field.data_type(),
+                        row_groups_metadata,
+                        parquet_idx,
+                        num_rows,
+                    );
+                    ColumnStatistics::new_unknown().with_byte_size(byte_size)
+                })
+                .collect()
+        };
 
         #[cfg(debug_assertions)]
         {
@@ -465,9 +517,7 @@ struct StatisticsAccumulators<'a> {
 }
 
 fn summarize_min_max_null_counts(
-    parquet_schema: &SchemaDescriptor,
     logical_file_schema: &Schema,
-    physical_file_schema: &Schema,
     accumulators: &mut StatisticsAccumulators,
     logical_schema_index: usize,
     stats_converter: &StatisticsConverter,
@@ -510,20 +560,11 @@ fn summarize_min_max_null_counts(
         },
     };
 
-    // This is the same logic as parquet_column but we start from arrow schema index
-    // instead of looking up by name.
-    let parquet_index = parquet_column(
-        parquet_schema,
-        physical_file_schema,
-        logical_file_schema.field(logical_schema_index).name(),
-    )
-    .map(|(idx, _)| idx);
-
     let arrow_field = logical_file_schema.field(logical_schema_index);
     accumulators.column_byte_sizes[logical_schema_index] = compute_arrow_column_size(
         arrow_field.data_type(),
         row_groups_metadata,
-        parquet_index,
+        stats_converter.parquet_column_index(),
         row_groups_metadata
             .iter()
             .map(|rg| rg.num_rows() as usize)
@@ -49,6 +49,8 @@ pub struct ParquetFileMetrics {
     pub row_groups_pruned_bloom_filter: PruningMetrics,
     /// Number of row groups whose statistics were checked, tracked with matched/pruned counts
     pub row_groups_pruned_statistics: PruningMetrics,
+    /// Number of row groups whose dictionary pages were checked, tracked with matched/pruned counts
+    pub row_groups_pruned_dictionary: PruningMetrics,
     /// Total number of bytes scanned
     pub bytes_scanned: Count,
     /// Total rows filtered out by predicates pushed into parquet scan
@@ -61,6 +63,8 @@ pub struct ParquetFileMetrics {
     pub statistics_eval_time: Time,
     /// Total time spent evaluating row group Bloom Filters
     pub bloom_filter_eval_time: Time,
+    /// Total time spent fetching and evaluating row group dictionary pages
+    pub dictionary_eval_time: Time,
     /// Total rows filtered or matched by parquet page index
     pub page_index_rows_pruned: PruningMetrics,
     /// Total time spent evaluating parquet page index filters
@@ -98,6 +102,11 @@ impl ParquetFileMetrics {
             .with_type(MetricType::SUMMARY)
             .pruning_metrics("row_groups_pruned_statistics", partition);
 
+        let row_groups_pruned_dictionary = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .with_type(MetricType::SUMMARY)
+            .pruning_metrics("row_groups_pruned_dictionary", partition);
+
         let page_index_rows_pruned = MetricBuilder::new(metrics)
             .with_new_label("filename", filename.to_string())
             .with_type(MetricType::SUMMARY)
@@ -149,6 +158,9 @@ impl ParquetFileMetrics {
         let bloom_filter_eval_time = MetricBuilder::new(metrics)
             .with_new_label("filename", filename.to_string())
             .subset_time("bloom_filter_eval_time", partition);
+        let dictionary_eval_time = MetricBuilder::new(metrics)
+            .with_new_label("filename", filename.to_string())
+            .subset_time("dictionary_eval_time", partition);
 
         let page_index_eval_time = MetricBuilder::new(metrics)
             .with_new_label("filename", filename.to_string())
@@ -167,6 +179,7 @@ impl ParquetFileMetrics {
             predicate_evaluation_errors,
             row_groups_pruned_bloom_filter,
             row_groups_pruned_statistics,
+            row_groups_pruned_dictionary,
             bytes_scanned,
             pushdown_rows_pruned,
             pushdown_rows_matched,
@@ -174,6 +187,7 @@ impl ParquetFileMetrics {
             page_index_rows_pruned,
             statistics_eval_time,
             bloom_filter_eval_time,
+            dictionary_eval_time,
             page_index_eval_time,
             metadata_load_time,
             scan_efficiency_ratio,
@@ -65,6 +65,7 @@ use parquet::arrow::arrow_reader::{
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::{ParquetRecordBatchStreamBuilder, ProjectionMask};
 use parquet::file::metadata::{PageIndexPolicy, ParquetMetaDataReader, RowGroupMetaData};
+use twox_hash::XxHash64;
 
 /// Implements [`FileOpener`] for a parquet file
 pub(super) struct ParquetOpener {
@@ -100,6 +101,9 @@ pub(super) struct ParquetOpener {
     /// Should the bloom filter be read from parquet, if present, to skip row
     /// groups
     pub enable_bloom_filter: bool,
+    /// Should the dictionary pages of the row groups be read from parquet, if
+    /// all their data pages are dictionary encoded, to skip row groups
+    pub enable_dictionary_pruning: bool,
     /// Should row group pruning be applied
     pub enable_row_group_stats_pruning: bool,
     /// Coerce INT96 timestamps to specific TimeUnit
@@ -118,6 +122,9 @@ pub(super) struct ParquetOpener {
     pub max_predicate_cache_size: Option<usize>,
     /// Whether to read row groups in reverse order
     pub reverse_row_groups: bool,
+    /// Fraction of the row groups to read (`TABLESAMPLE SYSTEM`) and the seed
+    /// of the random number generator that chooses them
+    pub sample: Option<(f64, u64)>,
 }
 
 /// Represents a prepared access plan with optional row selection
@@ -199,6 +206,20 @@ impl FileOpener for ParquetOpener {
                 &self.metrics,
             )?;
 
+        // Dictionary pages are read with a separate reader, as the one above is
+        // owned by the record batch stream builder
+        let dictionary_file_reader =
+            if self.enable_dictionary_pruning && self.predicate.is_some() {
+                Some(self.parquet_file_reader_factory.create_reader(
+                    self.partition_index,
+                    partitioned_file.clone(),
+                    metadata_size_hint,
+                    &self.metrics,
+                )?)
+            } else {
+                None
+            };
+
         let batch_size = self.batch_size;
 
         // Calculate the output schema from the original projection (before literal replacement)
@@ -274,11 +295,16 @@ impl FileOpener for ParquetOpener {
         let max_predicate_cache_size = self.max_predicate_cache_size;
 
         let reverse_row_groups = self.reverse_row_groups;
+        let sample = self.sample;
         Ok(Box::pin(async move {
             #[cfg(feature = "parquet_encryption")]
             let file_decryption_properties = encryption_context
                 .get_file_decryption_properties(&file_location)
                 .await?;
+            // Encrypted dictionary pages can not be decoded on their own
+            #[cfg(feature = "parquet_encryption")]
+            let dictionary_file_reader =
+                dictionary_file_reader.filter(|_| file_decryption_properties.is_none());
 
             // Prune this file using the file level statistics and partition values.
             // Since dynamic filters may have been updated since planning it is possible that we are able
@@ -470,8 +496,14 @@ impl FileOpener for ParquetOpener {
             let predicate = pruning_predicate.as_ref().map(|p| p.as_ref());
             let rg_metadata = file_metadata.row_groups();
             // track which row groups to actually read
-            let access_plan =
+            let mut access_plan =
                 create_initial_plan(&file_name, extensions, rg_metadata.len())?;
+            // Sample the row groups before any pruning, so that the sample
+            // does not depend on the predicate or on how the file is split
+            if let Some((fraction, seed)) = sample {
+                access_plan
+                    .sample_row_groups(fraction, file_sample_seed(seed, &file_name));
+            }
             let mut row_groups = RowGroupAccessPlanFilter::new(access_plan);
             // if there is a range restricting what parts of the file to read
             if let Some(range) = file_range.as_ref() {
@@ -512,6 +544,27 @@ impl FileOpener for ParquetOpener {
                         .row_groups_pruned_bloom_filter
                         .add_matched(row_groups.remaining_row_group_count());
                 }
+
+                if let Some(mut dictionary_file_reader) = dictionary_file_reader
+                    && !row_groups.is_empty()
+                {
+                    row_groups
+                        .prune_by_dictionaries(
+                            &physical_file_schema,
+                            builder.parquet_schema(),
+                            rg_metadata,
+                            dictionary_file_reader.as_mut(),
+                            predicate,
+                            &file_metrics,
+                        )
+                        .await;
+                } else {
+                    // Update metrics: dictionary pruning disabled, so all row
+                    // groups are matched (not pruned)
+                    file_metrics
+                        .row_groups_pruned_dictionary
+                        .add_matched(row_groups.remaining_row_group_count());
+                }
             } else {
                 // Update metrics: no predicate, so all row groups are matched (not pruned)
                 let n_remaining_row_groups = row_groups.remaining_row_group_count();
@@ -521,6 +574,9 @@ impl FileOpener for ParquetOpener {
                 file_metrics
                     .row_groups_pruned_bloom_filter
                     .add_matched(n_remaining_row_groups);
+                file_metrics
+                    .row_groups_pruned_dictionary
+                    .add_matched(n_remaining_row_groups);
             }
 
             let mut access_plan = row_groups.build();
@@ -889,6 +945,15 @@ fn create_initial_plan(
     Ok(ParquetAccessPlan::new_all(row_group_count))
 }
 
+/// Returns the seed for sampling the row groups of the file `file_name`, so
+/// that different files with the same row group count keep different row groups.
+///
+/// Uses a hash function with a stable output, so that `REPEATABLE` samples are
+/// the same across releases and platforms.
+fn file_sample_seed(seed: u64, file_name: &str) -> u64 {
+    XxHash64::oneshot(seed, file_name.as_bytes())
+}
+
 /// Build a page pruning predicate from an optional predicate expression.
 /// If the predicate is None or the predicate cannot be converted to a page pruning
 /// predicate, return None.
@@ -969,7 +1034,7 @@ fn should_enable_page_index(
 mod test {
     use std::sync::Arc;
 
-    use super::{ConstantColumns, constant_columns_from_stats};
+    use super::{ConstantColumns, constant_columns_from_stats, file_sample_seed};
     use crate::{DefaultParquetFileReaderFactory, RowGroupAccess, opener::ParquetOpener};
     use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
     use bytes::{BufMut, BytesMut};
@@ -1012,10 +1077,12 @@ mod test {
         force_filter_selections: bool,
         enable_page_index: bool,
         enable_bloom_filter: bool,
+        enable_dictionary_pruning: bool,
         enable_row_group_stats_pruning: bool,
         coerce_int96: Option<arrow::datatypes::TimeUnit>,
         max_predicate_cache_size: Option<usize>,
         reverse_row_groups: bool,
+        sample: Option<(f64, u64)>,
     }
 
     impl ParquetOpenerBuilder {
@@ -1037,10 +1104,12 @@ mod test {
                 force_filter_selections: false,
                 enable_page_index: false,
                 enable_bloom_filter: false,
+                enable_dictionary_pruning: false,
                 enable_row_group_stats_pruning: false,
                 coerce_int96: None,
                 max_predicate_cache_size: None,
                 reverse_row_groups: false,
+                sample: None,
             }
         }
 
@@ -1098,6 +1167,12 @@ mod test {
             self
         }
 
+        /// Sample the row groups with the given fraction and seed.
+        fn with_sample(mut self, fraction: f64, seed: u64) -> Self {
+            self.sample = Some((fraction, seed));
+            self
+        }
+
         /// Build the ParquetOpener instance.
         ///
         /// # Panics
@@ -1139,6 +1214,7 @@ mod test {
                 force_filter_selections: self.force_filter_selections,
                 enable_page_index: self.enable_page_index,
                 enable_bloom_filter: self.enable_bloom_filter,
+                enable_dictionary_pruning: self.enable_dictionary_pruning,
                 enable_row_group_stats_pruning: self.enable_row_group_stats_pruning,
                 coerce_int96: self.coerce_int96,
                 #[cfg(feature = "parquet_encryption")]
@@ -1148,6 +1224,7 @@ mod test {
                 encryption_factory: None,
                 max_predicate_cache_size: self.max_predicate_cache_size,
                 reverse_row_groups: self.reverse_row_groups,
+                sample: self.sample,
             }
         }
     }
@@ -1728,6 +1805,76 @@ mod test {
         assert_eq!(reverse_values, vec![7, 8, 9, 4, 5, 6, 1, 2, 3]);
     }
 
+    #[tokio::test]
+    async fn test_sample_row_groups() {
+        use parquet::file::properties::WriterProperties;
+
+        let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
+
+        let batches = (0..10)
+            .map(|i| record_batch!(("a", Int32, vec![Some(i), Some(i), Some(i)])))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let schema = batches[0].schema();
+
+        // Every batch is written to its own row group
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(3)
+            .build();
+        let data_len = write_parquet_batches(
+            Arc::clone(&store),
+            "test.parquet",
+            batches,
+            Some(props),
+        )
+        .await;
+        let file = PartitionedFile::new(
+            "test.parquet".to_string(),
+            u64::try_from(data_len).unwrap(),
+        );
+
+        let make_opener = |fraction: f64, seed: u64| {
+            ParquetOpenerBuilder::new()
+                .with_store(Arc::clone(&store))
+                .with_schema(Arc::clone(&schema))
+                .with_projection_indices(&[0])
+                .with_sample(fraction, seed)
+                .build()
+        };
+
+        let opener = make_opener(1.0, 0);
+        let stream = opener.open(file.clone()).unwrap().await.unwrap();
+        assert_eq!(collect_int32_values(stream).await.len(), 30);
+
+        let opener = make_opener(0.0, 0);
+        let stream = opener.open(file.clone()).unwrap().await.unwrap();
+        assert!(collect_int32_values(stream).await.is_empty());
+
+        // Whole row groups are read, and the same seed reads the same row groups
+        let opener = make_opener(0.5, 42);
+        let stream = opener.open(file.clone()).unwrap().await.unwrap();
+        let values = collect_int32_values(stream).await;
+        assert_eq!(values.len() % 3, 0);
+        assert!(values.chunks(3).all(|chunk| chunk[0] == chunk[1]));
+        let opener = make_opener(0.5, 42);
+        let stream = opener.open(file.clone()).unwrap().await.unwrap();
+        assert_eq!(collect_int32_values(stream).await, values);
+    }
+
+    #[test]
+    fn test_file_sample_seed_is_stable() {
+        // `REPEATABLE` samples must not change across releases and platforms
+        assert_eq!(file_sample_seed(42, "test.parquet"), 5225155843445494741);
+        assert_ne!(
+            file_sample_seed(42, "test.parquet"),
+            file_sample_seed(42, "other.parquet")
+        );
+        assert_ne!(
+            file_sample_seed(42, "test.parquet"),
+            file_sample_seed(43, "test.parquet")
+        );
+    }
+
     #[tokio::test]
     async fn test_reverse_scan_single_row_group() {
         let store = Arc::new(InMemory::new()) as Arc<dyn ObjectStore>;
@@ -21,15 +21,21 @@ use std::sync::Arc;
 use super::{ParquetAccessPlan, ParquetFileMetrics};
 use arrow::array::{ArrayRef, BooleanArray};
 use arrow::datatypes::Schema;
+use bytes::{Buf, Bytes};
 use datafusion_common::pruning::PruningStatistics;
-use datafusion_common::{Column, Result, ScalarValue};
+use datafusion_common::{Column, Result, ScalarValue, exec_err};
 use datafusion_datasource::FileRange;
 use datafusion_pruning::PruningPredicate;
 use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
 use parquet::arrow::parquet_column;
-use parquet::basic::Type;
+use parquet::basic::{Encoding, PageType, Type};
+use parquet::column::page::{Page, PageReader};
 use parquet::data_type::Decimal;
-use parquet::schema::types::SchemaDescriptor;
+use parquet::errors::ParquetError;
+use parquet::file::metadata::ColumnChunkMetaData;
+use parquet::file::reader::{ChunkReader, Length};
+use parquet::file::serialized_reader::SerializedPageReader;
+use parquet::schema::types::{ColumnDescriptor, SchemaDescriptor};
 use parquet::{
     arrow::{ParquetRecordBatchStreamBuilder, async_reader::AsyncFileReader},
     bloom_filter::Sbbf,
@@ -227,6 +233,368 @@ impl RowGroupAccessPlanFilter {
             }
         }
     }
+
+    /// Prune remaining row groups using the dictionary pages of the columns
+    /// that the [`PruningPredicate`] compares to literals (`=` and `IN`).
+    ///
+    /// When all the data pages of a column chunk are dictionary encoded, its
+    /// dictionary page holds every value of the chunk, so the row group can be
+    /// skipped if none of the literals is in the dictionary. Column chunks
+    /// without page encoding statistics are not pruned, as they may contain
+    /// plain encoded pages.
+    ///
+    /// Updates this set with row groups that should not be scanned
+    ///
+    /// # Panics
+    /// if `groups.len() != self.len()`
+    pub async fn prune_by_dictionaries<R: AsyncFileReader + ?Sized>(
+        &mut self,
+        arrow_schema: &Schema,
+        parquet_schema: &SchemaDescriptor,
+        groups: &[RowGroupMetaData],
+        reader: &mut R,
+        predicate: &PruningPredicate,
+        metrics: &ParquetFileMetrics,
+    ) {
+        // scoped timer updates on drop
+        let _timer_guard = metrics.dictionary_eval_time.timer();
+
+        assert_eq!(groups.len(), self.access_plan.len());
+        let literal_columns = predicate.literal_columns();
+        for (idx, group) in groups.iter().enumerate() {
+            if !self.access_plan.should_scan(idx) {
+                continue;
+            }
+
+            // Attempt to read the dictionaries of this row group
+            let mut column_dictionaries = HashMap::with_capacity(literal_columns.len());
+            for column_name in &literal_columns {
+                let Some((column_idx, _field)) =
+                    parquet_column(parquet_schema, arrow_schema, column_name)
+                else {
+                    continue;
+                };
+
+                let column = group.column(column_idx);
+                if !is_fully_dictionary_encoded(column) {
+                    continue;
+                }
+
+                match read_dictionary(reader, column, group.num_rows()).await {
+                    Ok(dictionary) => {
+                        column_dictionaries.insert(column_name.to_string(), dictionary);
+                    }
+                    Err(e) => {
+                        log::debug!("Ignoring error reading dictionary page: {e}");
+                        metrics.predicate_evaluation_errors.add(1);
+                    }
+                }
+            }
+
+            let stats = DictionaryStatistics {
+                column_dictionaries,
+            };
+
+            // Can this group be pruned?
+            let prune_group = match predicate.prune(&stats) {
+                Ok(values) => !values[0],
+                Err(e) => {
+                    log::debug!(
+                        "Error evaluating row group predicate on dictionary pages: {e}"
+                    );
+                    metrics.predicate_evaluation_errors.add(1);
+                    false
+                }
+            };
+
+            if prune_group {
+                metrics.row_groups_pruned_dictionary.add_pruned(1);
+                self.access_plan.skip(idx)
+            } else {
+                metrics.row_groups_pruned_dictionary.add_matched(1);
+            }
+        }
+    }
+}
+
+/// Returns true if all the data pages of `column` are dictionary encoded, in
+/// which case its dictionary page holds all of its values
+fn is_fully_dictionary_encoded(column: &ColumnChunkMetaData) -> bool {
+    if column.dictionary_page_offset().is_none() {
+        return false;
+    }
+    let Some(encoding_stats) = column.page_encoding_stats() else {
+        return false;
+    };
+    let is_data_page = |page_type: PageType| {
+        matches!(page_type, PageType::DATA_PAGE | PageType::DATA_PAGE_V2)
+    };
+    encoding_stats
+        .iter()
+        .any(|stats| is_data_page(stats.page_type))
+        && encoding_stats.iter().all(|stats| {
+            !is_data_page(stats.page_type)
+                || matches!(
+                    stats.encoding,
+                    Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY
+                )
+        })
+}
+
+/// Reads and decodes the dictionary page of `column`
+async fn read_dictionary<R: AsyncFileReader + ?Sized>(
+    reader: &mut R,
+    column: &ColumnChunkMetaData,
+    num_rows: i64,
+) -> Result<ColumnDictionary> {
+    let Some(offset) = column.dictionary_page_offset() else {
+        return exec_err!(
+            "Column chunk {} has no dictionary page",
+            column.column_path()
+        );
+    };
+    // The dictionary page is the first page of the column chunk, directly
+    // followed by the first data page
+    let (offset, end) = (offset as u64, column.data_page_offset() as u64);
+    if end <= offset {
+        return exec_err!(
+            "Invalid dictionary page range {offset}..{end} for column chunk {}",
+            column.column_path()
+        );
+    }
+    let data = reader.get_bytes(offset..end).await?;
+
+    let chunk = Arc::new(DictionaryPageChunk { offset, data });
+    let mut page_reader =
+        SerializedPageReader::new(chunk, column, num_rows as usize, None)?;
+    match page_reader.get_next_page()? {
+        Some(Page::DictionaryPage {
+            buf,
+            num_values,
+            encoding: Encoding::PLAIN | Encoding::PLAIN_DICTIONARY,
+            ..
+        }) => ColumnDictionary::try_new(&buf, num_values as usize, column.column_descr()),
+        _ => exec_err!(
+            "Expected a plain encoded dictionary page at offset {offset} for column chunk {}",
+            column.column_path()
+        ),
+    }
+}
+
+/// The bytes of a dictionary page, read from `offset` in the file, exposed as
+/// a [`ChunkReader`] addressed by file offsets
+struct DictionaryPageChunk {
+    offset: u64,
+    data: Bytes,
+}
+
+impl DictionaryPageChunk {
+    /// Returns the position of the file offset `start` in `data`
+    fn position(&self, start: u64, length: usize) -> parquet::errors::Result<usize> {
+        start
+            .checked_sub(self.offset)
+            .map(|position| position as usize)
+            .filter(|position| position + length <= self.data.len())
+            .ok_or_else(|| {
+                ParquetError::EOF(format!(
+                    "Range {start}..{} is outside of the dictionary page",
+                    start + length as u64
+                ))
+            })
+    }
+}
+
+impl Length for DictionaryPageChunk {
+    fn len(&self) -> u64 {
+        self.offset + self.data.len() as u64
+    }
+}
+
+impl ChunkReader for DictionaryPageChunk {
+    type T = bytes::buf::Reader<Bytes>;
+
+    fn get_read(&self, start: u64) -> parquet::errors::Result<Self::T> {
+        let position = self.position(start, 0)?;
+        Ok(self.data.slice(position..).reader())
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> parquet::errors::Result<Bytes> {
+        let position = self.position(start, length)?;
+        Ok(self.data.slice(position..position + length))
+    }
+}
+
+/// The distinct values of a dictionary encoded column chunk
+struct ColumnDictionary {
+    /// The plain encoded values, without the length prefix of `BYTE_ARRAY`s
+    values: HashSet<Bytes>,
+    physical_type: Type,
+    type_length: i32,
+}
+
+impl ColumnDictionary {
+    /// Splits the plain encoded values of a dictionary page
+    fn try_new(buf: &Bytes, num_values: usize, descr: &ColumnDescriptor) -> Result<Self> {
+        let physical_type = descr.physical_type();
+        let type_length = descr.type_length();
+        let width = match physical_type {
+            Type::INT32 | Type::FLOAT => Some(4),
+            Type::INT64 | Type::DOUBLE => Some(8),
+            Type::INT96 => Some(12),
+            Type::FIXED_LEN_BYTE_ARRAY => Some(type_length.max(0) as usize),
+            Type::BYTE_ARRAY => None,
+            Type::BOOLEAN => {
+                return exec_err!("Unexpected dictionary page for a BOOLEAN column");
+            }
+        };
+
+        let mut values = HashSet::with_capacity(num_values);
+        let mut position = 0;
+        for _ in 0..num_values {
+            let len = match width {
+                Some(width) => width,
+                None => {
+                    if position + 4 > buf.len() {
+                        return exec_err!("Truncated dictionary page");
+                    }
+                    let mut len = [0; 4];
+                    len.copy_from_slice(&buf[position..position + 4]);
+                    position += 4;
+                    u32::from_le_bytes(len) as usize
+                }
+            };
+            if position + len > buf.len() {
+                return exec_err!("Truncated dictionary page");
+            }
+            values.insert(buf.slice(position..position + len));
+            position += len;
+        }
+
+        Ok(Self {
+            values,
+            physical_type,
+            type_length,
+        })
+    }
+
+    /// Returns the plain encoding of `value` in this column, or `None` if it is
+    /// not known, in which case the value may be present.
+    ///
+    /// Floating point values are not supported as `0.0` and `-0.0` compare
+    /// equal but are encoded differently.
+    fn encode_scalar(&self, value: &ScalarValue) -> Option<Vec<u8>> {
+        match (value, self.physical_type) {
+            (
+                ScalarValue::Utf8(Some(v))
+                | ScalarValue::Utf8View(Some(v))
+                | ScalarValue::LargeUtf8(Some(v)),
+                Type::BYTE_ARRAY,
+            ) => Some(v.as_bytes().to_vec()),
+            (
+                ScalarValue::Binary(Some(v))
+                | ScalarValue::BinaryView(Some(v))
+                | ScalarValue::LargeBinary(Some(v)),
+                Type::BYTE_ARRAY,
+            ) => Some(v.clone()),
+            (ScalarValue::FixedSizeBinary(_, Some(v)), Type::FIXED_LEN_BYTE_ARRAY) => {
+                Some(v.clone())
+            }
+            (ScalarValue::Int8(Some(v)), _) => self.encode_integer(i64::from(*v)),
+            (ScalarValue::Int16(Some(v)), _) => self.encode_integer(i64::from(*v)),
+            (ScalarValue::Int32(Some(v)), _) => self.encode_integer(i64::from(*v)),
+            (ScalarValue::Int64(Some(v)), _) => self.encode_integer(*v),
+            (ScalarValue::UInt8(Some(v)), _) => self.encode_integer(i64::from(*v)),
+            (ScalarValue::UInt16(Some(v)), _) => self.encode_integer(i64::from(*v)),
+            (ScalarValue::Date32(Some(v)), _) => self.encode_integer(i64::from(*v)),
+            // Unsigned integers are stored with the bit pattern of the signed
+            // integer of the same width
+            (ScalarValue::UInt32(Some(v)), Type::INT32) => {
+                Some((*v as i32).to_le_bytes().to_vec())
+            }
+            (ScalarValue::UInt64(Some(v)), Type::INT64) => {
+                Some((*v as i64).to_le_bytes().to_vec())
+            }
+            (ScalarValue::Decimal128(Some(v), _, _), Type::INT32) => {
+                i32::try_from(*v).ok().map(|v| v.to_le_bytes().to_vec())
+            }
+            (ScalarValue::Decimal128(Some(v), _, _), Type::INT64) => {
+                i64::try_from(*v).ok().map(|v| v.to_le_bytes().to_vec())
+            }
+            (ScalarValue::Decimal128(Some(v), _, _), Type::FIXED_LEN_BYTE_ARRAY) => {
+                // Big endian two's complement, truncated to the type length
+                let bytes = v.to_be_bytes();
+                let len = usize::try_from(self.type_length).ok()?;
+                if len == 0 || len > bytes.len() {
+                    return None;
+                }
+                let (extension, value) = bytes.split_at(bytes.len() - len);
+                let sign = if value[0] & 0x80 == 0 { 0 } else { 0xff };
+                extension.iter().all(|b| *b == sign).then(|| value.to_vec())
+            }
+            (ScalarValue::Dictionary(_, inner), _) => self.encode_scalar(inner),
+            _ => None,
+        }
+    }
+
+    /// Returns the plain encoding of an integer in an `INT32` or `INT64` column
+    fn encode_integer(&self, value: i64) -> Option<Vec<u8>> {
+        match self.physical_type {
+            Type::INT32 => i32::try_from(value).ok().map(|v| v.to_le_bytes().to_vec()),
+            Type::INT64 => Some(value.to_le_bytes().to_vec()),
+            _ => None,
+        }
+    }
+}
+
+/// Implements [`PruningStatistics`] for the dictionary pages of a row group
+struct DictionaryStatistics {
+    /// Maps column name to the values of its dictionary page
+    column_dictionaries: HashMap<String, ColumnDictionary>,
+}
+
+impl PruningStatistics for DictionaryStatistics {
+    fn min_values(&self, _column: &Column) -> Option<ArrayRef> {
+        None
+    }
+
+    fn max_values(&self, _column: &Column) -> Option<ArrayRef> {
+        None
+    }
+
+    fn num_containers(&self) -> usize {
+        1
+    }
+
+    fn null_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        None
+    }
+
+    fn row_counts(&self, _column: &Column) -> Option<ArrayRef> {
+        None
+    }
+
+    /// Use the dictionary to determine if the column can not possibly contain
+    /// `values`
+    ///
+    /// As the dictionary holds all the values of the column chunk, it is known
+    /// that *NONE* of `values` are present if they are all missing from it.
+    fn contained(
+        &self,
+        column: &Column,
+        values: &HashSet<ScalarValue>,
+    ) -> Option<BooleanArray> {
+        let dictionary = self.column_dictionaries.get(column.name.as_str())?;
+
+        let known_not_present = values.iter().all(|value| {
+            dictionary
+                .encode_scalar(value)
+                .is_some_and(|encoded| !dictionary.values.contains(encoded.as_slice()))
+        });
+
+        let contains = if known_not_present { Some(false) } else { None };
+
+        Some(BooleanArray::from(vec![contains]))
+    }
 }
 /// Implements [`PruningStatistics`] for Parquet Split Block Bloom Filters (SBBF)
 struct BloomFilterStatistics {
@@ -1280,7 +1648,7 @@ mod tests {
         let testdata = datafusion_common::test_util::parquet_test_data();
         let file_name = "data_index_bloom_encoding_stats.parquet";
         let path = format!("{testdata}/{file_name}");
-        let data = bytes::Bytes::from(std::fs::read(path).unwrap());
+        let data = Bytes::from(std::fs::read(path).unwrap());
 
         // generate pruning predicate
         let schema = Schema::new(vec![Field::new("String", DataType::Utf8, false)]);
@@ -1512,7 +1880,7 @@ mod tests {
 
             let testdata = datafusion_common::test_util::parquet_test_data();
             let path = format!("{testdata}/{file_name}");
-            let data = bytes::Bytes::from(std::fs::read(path).unwrap());
+            let data = Bytes::from(std::fs::read(path).unwrap());
 
             let expr = logical2physical(&expr, &schema);
             let pruning_predicate =
@@ -1533,7 +1901,7 @@ mod tests {
     /// Evaluates the pruning predicate on the specified row groups and returns the row groups that are left
     async fn test_row_group_bloom_filter_pruning_predicate(
         file_name: &str,
-        data: bytes::Bytes,
+        data: Bytes,
         pruning_predicate: &PruningPredicate,
     ) -> Result<RowGroupAccessPlanFilter> {
         use datafusion_datasource::PartitionedFile;
@@ -1588,4 +1956,184 @@ mod tests {
 
         Ok(pruned_row_groups)
     }
+
+    #[tokio::test]
+    async fn test_row_group_dictionary_pruning_eq() {
+        // row group 0 has `a` and `z`, so statistics can not prune it
+        DictionaryTest::new()
+            .with_expect_all_pruned()
+            .run(col("status").eq(lit("b")))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_row_group_dictionary_pruning_eq_exists() {
+        DictionaryTest::new()
+            .with_expected_row_groups(vec![1])
+            .run(col("status").eq(lit("n")))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_row_group_dictionary_pruning_in_list() {
+        DictionaryTest::new()
+            .with_expect_all_pruned()
+            .run(col("status").in_list(vec![lit("b"), lit("y")], false))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_row_group_dictionary_pruning_in_list_exists() {
+        DictionaryTest::new()
+            .run(col("status").in_list(vec![lit("a"), lit("m")], false))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_row_group_dictionary_pruning_int() {
+        DictionaryTest::new()
+            .with_expected_row_groups(vec![0])
+            .run(col("code").in_list(vec![lit(1), lit(5)], false))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_row_group_dictionary_pruning_or_not_eq() {
+        // `!=` can not be evaluated using the dictionary
+        DictionaryTest::new()
+            .run(
+                col("status")
+                    .eq(lit("b"))
+                    .or(col("status").not_eq(lit("c"))),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_row_group_dictionary_pruning_without_dictionary() {
+        DictionaryTest::new()
+            .with_dictionary_enabled(false)
+            .run(col("status").eq(lit("b")))
+            .await
+    }
+
+    struct DictionaryTest {
+        dictionary_enabled: bool,
+        // which row groups are expected to be left after pruning
+        post_pruning_row_groups: ExpectedPruning,
+    }
+
+    impl DictionaryTest {
+        /// Return a test for a file with two row groups:
+        ///
+        /// ```text
+        /// +-----------+--------+------+
+        /// | row group | status | code |
+        /// +-----------+--------+------+
+        /// | 0         | a      | 1    |
+        /// | 0         | z      | 9    |
+        /// | 1         | m      | 4    |
+        /// | 1         | n      | 6    |
+        /// +-----------+--------+------+
+        /// ```
+        fn new() -> Self {
+            Self {
+                dictionary_enabled: true,
+                post_pruning_row_groups: ExpectedPruning::None,
+            }
+        }
+
+        /// Write the columns with or without dictionary encoding
+        fn with_dictionary_enabled(mut self, dictionary_enabled: bool) -> Self {
+            self.dictionary_enabled = dictionary_enabled;
+            self
+        }
+
+        /// Expect all row groups to be pruned
+        fn with_expect_all_pruned(mut self) -> Self {
+            self.post_pruning_row_groups = ExpectedPruning::All;
+            self
+        }
+
+        /// Expect only the specified row groups to remain
+        fn with_expected_row_groups(mut self, row_groups: Vec<usize>) -> Self {
+            self.post_pruning_row_groups = ExpectedPruning::Some(row_groups);
+            self
+        }
+
+        fn schema() -> Schema {
+            Schema::new(vec![
+                Field::new("status", DataType::Utf8, false),
+                Field::new("code", DataType::Int32, false),
+            ])
+        }
+
+        fn write_file(&self) -> Bytes {
+            use arrow::array::{Int32Array, RecordBatch, StringArray};
+            use parquet::arrow::ArrowWriter;
+            use parquet::file::properties::WriterProperties;
+
+            let schema = Arc::new(Self::schema());
+            let batch = RecordBatch::try_new(
+                Arc::clone(&schema),
+                vec![
+                    Arc::new(StringArray::from(vec!["a", "z", "m", "n"])),
+                    Arc::new(Int32Array::from(vec![1, 9, 4, 6])),
+                ],
+            )
+            .unwrap();
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(2)
+                .set_dictionary_enabled(self.dictionary_enabled)
+                .build();
+
+            let mut buf = vec![];
+            let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+            Bytes::from(buf)
+        }
+
+        /// Prune the file using the specified expression and check that the expected row groups are left
+        async fn run(self, expr: Expr) {
+            use object_store::ObjectStore;
+            use object_store::path::Path;
+
+            let schema = Self::schema();
+            let expr = logical2physical(&expr, &schema);
+            let pruning_predicate =
+                PruningPredicate::try_new(expr, Arc::new(schema)).unwrap();
+
+            let data = self.write_file();
+            let size = data.len() as u64;
+            let location = Path::from("dictionary.parquet");
+            let in_memory = Arc::new(object_store::memory::InMemory::new());
+            in_memory
+                .put(&location, data.into())
+                .await
+                .expect("put parquet file into in memory object store");
+            let mut reader =
+                ParquetObjectReader::new(in_memory, location).with_file_size(size);
+
+            let metadata = reader.get_metadata(None).await.unwrap();
+            let groups = metadata.row_groups();
+
+            let metrics = parquet_file_metrics();
+            let access_plan = ParquetAccessPlan::new_all(groups.len());
+            let mut pruned_row_groups = RowGroupAccessPlanFilter::new(access_plan);
+            pruned_row_groups
+                .prune_by_dictionaries(
+                    pruning_predicate.schema(),
+                    metadata.file_metadata().schema_descr(),
+                    groups,
+                    &mut reader,
+                    &pruning_predicate,
+                    &metrics,
+                )
+                .await;
+
+            self.post_pruning_row_groups.assert(&pruned_row_groups);
+            assert_eq!(metrics.predicate_evaluation_errors.value(), 0);
+        }
+    }
 }
@@ -33,14 +33,16 @@ use datafusion_datasource::as_file_source;
 use datafusion_datasource::file_stream::FileOpener;
 
 use arrow::datatypes::TimeUnit;
-use datafusion_common::DataFusionError;
 use datafusion_common::config::TableParquetOptions;
+use datafusion_common::{DataFusionError, SampleMethod};
 use datafusion_datasource::TableSchema;
 use datafusion_datasource::file::FileSource;
 use datafusion_datasource::file_scan_config::FileScanConfig;
 use datafusion_physical_expr::projection::ProjectionExprs;
 use datafusion_physical_expr::{EquivalenceProperties, conjunction};
-use datafusion_physical_expr_adapter::DefaultPhysicalExprAdapterFactory;
+use datafusion_physical_expr_adapter::{
+    DefaultPhysicalExprAdapterFactory, FieldIdPhysicalExprAdapterFactory,
+};
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
 use datafusion_physical_expr_common::physical_expr::fmt_sql;
 use datafusion_physical_plan::DisplayFormatType;
@@ -57,6 +59,7 @@ use datafusion_execution::parquet_encryption::EncryptionFactory;
 use datafusion_physical_expr_common::sort_expr::PhysicalSortExpr;
 use itertools::Itertools;
 use object_store::ObjectStore;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
 #[cfg(feature = "parquet_encryption")]
 use parquet::encryption::decrypt::FileDecryptionProperties;
 
@@ -293,6 +296,10 @@ pub struct ParquetSource {
     /// so we still need to sort them after reading, so the reverse scan is inexact.
     /// Used to optimize ORDER BY ... DESC on sorted data.
     reverse_row_groups: bool,
+    /// Fraction of the row groups to read (`TABLESAMPLE SYSTEM`) and the seed
+    /// of the random number generator that chooses them. If the seed is
+    /// `None`, a random seed is used.
+    sample: Option<(f64, Option<u64>)>,
 }
 
 impl ParquetSource {
@@ -318,6 +325,7 @@ impl ParquetSource {
             #[cfg(feature = "parquet_encryption")]
             encryption_factory: None,
             reverse_row_groups: false,
+            sample: None,
         }
     }
 
@@ -454,6 +462,41 @@ impl ParquetSource {
         self.table_parquet_options.global.bloom_filter_on_read
     }
 
+    /// If enabled, the reader will read the dictionary pages of the remaining
+    /// row groups to prune those without any of the values compared to by `=`
+    /// or `IN` predicates
+    pub fn with_dictionary_pruning_on_read(
+        mut self,
+        dictionary_pruning_on_read: bool,
+    ) -> Self {
+        self.table_parquet_options.global.dictionary_pruning_on_read =
+            dictionary_pruning_on_read;
+        self
+    }
+
+    /// Return the value described in [`Self::with_dictionary_pruning_on_read`]
+    fn dictionary_pruning_on_read(&self) -> bool {
+        self.table_parquet_options.global.dictionary_pruning_on_read
+    }
+
+    /// If enabled, columns and struct fields of the file schema are resolved to
+    /// those of each file by the field IDs stored in their metadata under
+    /// [`PARQUET_FIELD_ID_META_KEY`], rather than by name.
+    ///
+    /// This is ignored if a custom [`PhysicalExprAdapterFactory`] is set on the
+    /// [`FileScanConfig`]. See [`FieldIdPhysicalExprAdapterFactory`] for details.
+    ///
+    /// [`PhysicalExprAdapterFactory`]: datafusion_physical_expr_adapter::PhysicalExprAdapterFactory
+    pub fn with_match_by_field_id(mut self, match_by_field_id: bool) -> Self {
+        self.table_parquet_options.global.match_by_field_id = match_by_field_id;
+        self
+    }
+
+    /// Return the value described in [`Self::with_match_by_field_id`]
+    fn match_by_field_id(&self) -> bool {
+        self.table_parquet_options.global.match_by_field_id
+    }
+
     /// Return the maximum predicate cache size, in bytes, used when
     /// `pushdown_filters`
     pub fn max_predicate_cache_size(&self) -> Option<usize> {
@@ -481,6 +524,12 @@ impl ParquetSource {
     pub(crate) fn reverse_row_groups(&self) -> bool {
         self.reverse_row_groups
     }
+
+    /// Fraction of the row groups to read and the seed that chooses them, if
+    /// a `TABLESAMPLE SYSTEM` was pushed down into this source
+    pub fn sample(&self) -> Option<(f64, Option<u64>)> {
+        self.sample
+    }
 }
 
 /// Parses datafusion.common.config.ParquetOptions.coerce_int96 String to a arrow_schema.datatype.TimeUnit
@@ -515,10 +564,16 @@ impl FileSource for ParquetSource {
         base_config: &FileScanConfig,
         partition: usize,
     ) -> datafusion_common::Result<Arc<dyn FileOpener>> {
-        let expr_adapter_factory = base_config
-            .expr_adapter_factory
-            .clone()
-            .unwrap_or_else(|| Arc::new(DefaultPhysicalExprAdapterFactory) as _);
+        let expr_adapter_factory =
+            base_config.expr_adapter_factory.clone().unwrap_or_else(|| {
+                if self.match_by_field_id() {
+                    Arc::new(FieldIdPhysicalExprAdapterFactory::new(
+                        PARQUET_FIELD_ID_META_KEY,
+                    )) as _
+                } else {
+                    Arc::new(DefaultPhysicalExprAdapterFactory) as _
+                }
+            });
 
         let parquet_file_reader_factory =
             self.parquet_file_reader_factory.clone().unwrap_or_else(|| {
@@ -558,6 +613,7 @@ impl FileSource for ParquetSource {
             force_filter_selections: self.force_filter_selections(),
             enable_page_index: self.enable_page_index(),
             enable_bloom_filter: self.bloom_filter_on_read(),
+            enable_dictionary_pruning: self.dictionary_pruning_on_read(),
             enable_row_group_stats_pruning: self.table_parquet_options.global.pruning,
             coerce_int96,
             #[cfg(feature = "parquet_encryption")]
@@ -567,6 +623,9 @@ impl FileSource for ParquetSource {
             encryption_factory: self.get_encryption_factory_with_config(),
             max_predicate_cache_size: self.max_predicate_cache_size(),
             reverse_row_groups: self.reverse_row_groups,
+            sample: self
+                .sample
+                .map(|(fraction, seed)| (fraction, seed.unwrap_or_else(rand::random))),
         });
         Ok(opener)
     }
@@ -625,6 +684,13 @@ impl FileSource for ParquetSource {
                     write!(f, ", reverse_row_groups=true")?;
                 }
 
+                if let Some((fraction, seed)) = self.sample {
+                    write!(f, ", sample={}", SampleMethod::System(fraction))?;
+                    if let Some(seed) = seed {
+                        write!(f, ", sample_seed={seed}")?;
+                    }
+                }
+
                 // Try to build a the pruning predicates.
                 // These are only generated here because it's useful to have *some*
                 // idea of what pushdown is happening when viewing plans.
@@ -815,6 +881,26 @@ impl FileSource for ParquetSource {
         // - Detection of exact ordering (return Exact to remove Sort operator)
         // - Partial sort pushdown for prefix matches
     }
+
+    /// Push `TABLESAMPLE SYSTEM` down into the scan, which then only reads a
+    /// random sample of the row groups of every file.
+    ///
+    /// Other sampling methods choose individual rows, and are left to
+    /// `SampleExec`.
+    fn try_pushdown_sample(
+        &self,
+        method: &SampleMethod,
+        seed: Option<u64>,
+    ) -> datafusion_common::Result<Option<Arc<dyn FileSource>>> {
+        match method {
+            SampleMethod::System(fraction) if self.sample.is_none() => {
+                let mut source = self.clone();
+                source.sample = Some((*fraction, seed));
+                Ok(Some(Arc::new(source)))
+            }
+            _ => Ok(None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -916,4 +1002,31 @@ mod tests {
         assert!(source.reverse_row_groups());
         assert!(source.filter().is_some());
     }
+
+    #[test]
+    fn test_pushdown_sample() {
+        let schema = Arc::new(Schema::empty());
+        let source = ParquetSource::new(schema);
+
+        let sampled = source
+            .try_pushdown_sample(&SampleMethod::System(0.1), Some(42))
+            .unwrap()
+            .unwrap();
+        let sampled = sampled.as_any().downcast_ref::<ParquetSource>().unwrap();
+        assert_eq!(sampled.sample(), Some((0.1, Some(42))));
+
+        // Only whole row groups can be skipped
+        assert!(
+            source
+                .try_pushdown_sample(&SampleMethod::Bernoulli(0.1), None)
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            source
+                .try_pushdown_sample(&SampleMethod::Rows(10), None)
+                .unwrap()
+                .is_none()
+        );
+    }
 }
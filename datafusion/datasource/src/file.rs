@@ -28,7 +28,7 @@ use crate::file_stream::FileOpener;
 #[expect(deprecated)]
 use crate::schema_adapter::SchemaAdapterFactory;
 use datafusion_common::config::ConfigOptions;
-use datafusion_common::{Result, not_impl_err};
+use datafusion_common::{Result, SampleMethod, not_impl_err};
 use datafusion_physical_expr::projection::ProjectionExprs;
 use datafusion_physical_expr::{EquivalenceProperties, LexOrdering, PhysicalExpr};
 use datafusion_physical_plan::DisplayFormatType;
@@ -227,6 +227,24 @@ pub trait FileSource: Send + Sync {
         Ok(None)
     }
 
+    /// Try to push a random sample of the rows (`TABLESAMPLE`) down into this
+    /// FileSource.
+    ///
+    /// `FileSource` implementations that can skip whole blocks of rows without
+    /// reading them, such as the row groups of a Parquet file, may accept
+    /// [`SampleMethod::System`] and return a new `FileSource` that only reads
+    /// the sampled blocks. `seed` is the seed of the random number generator,
+    /// or `None` to use a random seed.
+    ///
+    /// Default implementation returns `None`.
+    fn try_pushdown_sample(
+        &self,
+        _method: &SampleMethod,
+        _seed: Option<u64>,
+    ) -> Result<Option<Arc<dyn FileSource>>> {
+        Ok(None)
+    }
+
     /// Deprecated: Set optional schema adapter factory.
     ///
     /// `SchemaAdapterFactory` has been removed. Use `PhysicalExprAdapterFactory` instead.
@@ -28,7 +28,8 @@ use arrow::datatypes::FieldRef;
 use arrow::datatypes::{DataType, Schema, SchemaRef};
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::{
-    Constraints, Result, ScalarValue, Statistics, internal_datafusion_err, internal_err,
+    Constraints, Result, SampleMethod, ScalarValue, Statistics, internal_datafusion_err,
+    internal_err,
 };
 use datafusion_execution::{
     SendableRecordBatchStream, TaskContext, object_store::ObjectStoreUrl,
@@ -850,6 +851,28 @@ impl DataSource for FileScanConfig {
             }
         }
     }
+
+    fn try_pushdown_sample(
+        &self,
+        method: &SampleMethod,
+        seed: Option<u64>,
+    ) -> Result<Option<Arc<dyn DataSource>>> {
+        let Some(new_file_source) = self.file_source.try_pushdown_sample(method, seed)?
+        else {
+            return Ok(None);
+        };
+
+        let mut new_config = self.clone();
+        new_config.file_source = new_file_source;
+        // Only a sample of the rows is read, so the statistics are estimates
+        new_config.statistics = new_config.statistics.with_sample(method);
+        for group in new_config.file_groups.iter_mut() {
+            if let Some(statistics) = group.statistics_mut() {
+                *statistics = std::mem::take(statistics).with_sample(method);
+            }
+        }
+        Ok(Some(Arc::new(new_config)))
+    }
 }
 
 impl FileScanConfig {
@@ -37,7 +37,7 @@ use itertools::Itertools;
 
 use crate::file_scan_config::FileScanConfig;
 use datafusion_common::config::ConfigOptions;
-use datafusion_common::{Constraints, Result, Statistics};
+use datafusion_common::{Constraints, Result, SampleMethod, Statistics};
 use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning, PhysicalExpr};
 use datafusion_physical_expr_common::sort_expr::{LexOrdering, PhysicalSortExpr};
@@ -210,6 +210,18 @@ pub trait DataSource: Send + Sync + Debug {
     ) -> Result<SortOrderPushdownResult<Arc<dyn DataSource>>> {
         Ok(SortOrderPushdownResult::Unsupported)
     }
+
+    /// Try to create a new DataSource that only produces a random sample of
+    /// the rows. See [`ExecutionPlan::try_pushdown_sample`] for more details.
+    ///
+    /// Default implementation returns `None`.
+    fn try_pushdown_sample(
+        &self,
+        _method: &SampleMethod,
+        _seed: Option<u64>,
+    ) -> Result<Option<Arc<dyn DataSource>>> {
+        Ok(None)
+    }
 }
 
 /// [`ExecutionPlan`] that reads one or more files
@@ -393,6 +405,20 @@ impl ExecutionPlan for DataSourceExec {
                 Ok(Arc::new(new_exec) as Arc<dyn ExecutionPlan>)
             })
     }
+
+    fn try_pushdown_sample(
+        &self,
+        method: &SampleMethod,
+        seed: Option<u64>,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        Ok(self
+            .data_source
+            .try_pushdown_sample(method, seed)?
+            .map(|new_data_source| {
+                let new_exec = self.clone().with_data_source(new_data_source);
+                Arc::new(new_exec) as Arc<dyn ExecutionPlan>
+            }))
+    }
 }
 
 impl DataSourceExec {
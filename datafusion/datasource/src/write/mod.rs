@@ -73,6 +73,13 @@ pub trait BatchSerializer: Sync + Send {
     /// Parameter `initial` signals whether the given batch is the first batch.
     /// This distinction is important for certain serializers (like CSV).
     fn serialize(&self, batch: RecordBatch, initial: bool) -> Result<Bytes>;
+
+    /// Returns the bytes to write after the last batch, such as the closing
+    /// bracket of a JSON array. Parameter `empty` signals whether no rows were
+    /// serialized.
+    fn finish(&self, _empty: bool) -> Result<Bytes> {
+        Ok(Bytes::new())
+    }
 }
 
 /// Returns an [`AsyncWrite`] which writes to the given object store location
@@ -89,17 +89,27 @@ pub(crate) async fn serialize_rb_stream_to_object_store(
 ) -> SerializedRecordBatchResult {
     let (tx, mut rx) =
         mpsc::channel::<SpawnedTask<Result<(usize, Bytes), DataFusionError>>>(100);
+    let task_serializer = Arc::clone(&serializer);
     let serialize_task = SpawnedTask::spawn(async move {
-        // Some serializers (like CSV) handle the first batch differently than
-        // subsequent batches, so we track that here.
-        let mut initial = true;
-        while let Some(batch) = data_rx.recv().await {
-            let serializer_clone = Arc::clone(&serializer);
-            let task = SpawnedTask::spawn(async move {
+        let spawn_serialize = |batch: RecordBatch, initial: bool| {
+            let serializer_clone = Arc::clone(&task_serializer);
+            SpawnedTask::spawn(async move {
                 let num_rows = batch.num_rows();
                 let bytes = serializer_clone.serialize(batch, initial)?;
                 Ok((num_rows, bytes))
-            });
+            })
+        };
+        // Some serializers (like CSV) handle the first batch differently than
+        // subsequent batches, so we track that here. Leading empty batches are
+        // held back so that the initial batch contains rows whenever possible.
+        let mut initial = true;
+        let mut empty_batch = None;
+        while let Some(batch) = data_rx.recv().await {
+            if initial && batch.num_rows() == 0 {
+                empty_batch = Some(batch);
+                continue;
+            }
+            let task = spawn_serialize(batch, initial);
             if initial {
                 initial = false;
             }
@@ -107,6 +117,11 @@ pub(crate) async fn serialize_rb_stream_to_object_store(
                 internal_datafusion_err!("Unknown error writing to object store")
             })?;
         }
+        if initial && let Some(batch) = empty_batch {
+            tx.send(spawn_serialize(batch, true)).await.map_err(|_| {
+                internal_datafusion_err!("Unknown error writing to object store")
+            })?;
+        }
         Ok(())
     });
 
@@ -151,6 +166,16 @@ pub(crate) async fn serialize_rb_stream_to_object_store(
             );
         }
     }
+    let bytes = match serializer.finish(row_count == 0) {
+        Ok(bytes) => bytes,
+        Err(e) => return SerializedRecordBatchResult::failure(Some(writer), e),
+    };
+    if let Err(e) = writer.write_all(&bytes).await {
+        return SerializedRecordBatchResult::failure(
+            None,
+            exec_datafusion_err!("Error writing to object store: {e}"),
+        );
+    }
     SerializedRecordBatchResult::success(writer, row_count)
 }
 
@@ -18,15 +18,20 @@
 use crate::cache::cache_unit::DefaultFilesMetadataCache;
 use crate::cache::list_files_cache::ListFilesEntry;
 use crate::cache::list_files_cache::TableScopedPath;
-use crate::cache::{CacheAccessor, DefaultListFilesCache};
+use crate::cache::{CacheAccessor, DefaultListFilesCache, DefaultQueryResultCache};
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
 use datafusion_common::TableReference;
 use datafusion_common::stats::Precision;
 use datafusion_common::{Result, Statistics};
+use datafusion_expr::LogicalPlan;
 use object_store::ObjectMeta;
 use object_store::path::Path;
 use std::any::Any;
 use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -161,6 +166,118 @@ pub struct FileMetadataCacheEntry {
     pub extra: HashMap<String, String>,
 }
 
+/// Key of the [`QueryResultCache`].
+///
+/// The key combines the signature of the optimized [`LogicalPlan`] with the
+/// identity and data version of all tables the plan reads (see
+/// [`QueryResultCacheTable`]), so that results computed before a table was
+/// modified or replaced are no longer found.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryResultCacheKey {
+    /// Hash of the plan signature
+    plan_signature: u64,
+    /// Tables read by the plan, sorted
+    tables: Vec<QueryResultCacheTable>,
+}
+
+impl QueryResultCacheKey {
+    /// Creates a new key from the signature of the optimized plan (typically its
+    /// `LogicalPlanSignature`), and the tables it reads.
+    ///
+    /// Different plans can have the same signature, so the cache also compares
+    /// the plan of a cached result before returning it.
+    pub fn new(
+        plan_signature: &impl Hash,
+        mut tables: Vec<QueryResultCacheTable>,
+    ) -> Self {
+        let mut hasher = DefaultHasher::new();
+        plan_signature.hash(&mut hasher);
+        tables.sort_unstable();
+        tables.dedup();
+        Self {
+            plan_signature: hasher.finish(),
+            tables,
+        }
+    }
+
+    /// Returns the tables read by the plan.
+    pub fn tables(&self) -> &[QueryResultCacheTable] {
+        &self.tables
+    }
+
+    /// Returns true if the plan reads `table`.
+    pub fn references_table(&self, table: &TableReference) -> bool {
+        self.tables.iter().any(|t| &t.table == table)
+    }
+}
+
+/// A table read by a query, as part of a [`QueryResultCacheKey`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QueryResultCacheTable {
+    /// Resolved name of the table
+    pub table: TableReference,
+    /// Identity of the table provider, typically the address of its `Arc`.
+    ///
+    /// Sessions sharing the cache can register different tables under the same
+    /// name, and a table can be replaced, so the name alone does not identify
+    /// the data. The cached plan keeps the provider alive, so its address is
+    /// not reused while the result is cached.
+    pub provider_id: usize,
+    /// Version of the data of the table, see `TableProvider::data_version`
+    pub version: u64,
+}
+
+/// Result of a query, stored in the [`QueryResultCache`].
+#[derive(Debug)]
+pub struct CachedQueryResult {
+    /// Optimized plan the result was computed for
+    pub plan: Arc<LogicalPlan>,
+    /// Schema of the result
+    pub schema: SchemaRef,
+    /// Batches of the result
+    pub batches: Vec<RecordBatch>,
+}
+
+impl CachedQueryResult {
+    /// Returns the size of the result batches, in bytes.
+    pub fn memory_size(&self) -> usize {
+        self.batches
+            .iter()
+            .map(RecordBatch::get_array_memory_size)
+            .sum()
+    }
+}
+
+/// Cache for the results of queries.
+///
+/// If enabled via [`CacheManagerConfig::with_query_result_cache_limit`], this
+/// cache avoids executing the same query repeatedly, e.g. for dashboards. The
+/// cache is keyed by [`QueryResultCacheKey`], and `get_with_extra` only
+/// returns a result if its plan is equal to the given plan.
+///
+/// Only deterministic queries should be cached: plans that contain volatile
+/// functions such as `random()` or `now()` must bypass the cache, as well as
+/// plans reading tables that do not report a data version (e.g. external
+/// tables, whose files can change at any time).
+///
+/// See [`crate::runtime_env::RuntimeEnv`] for more details.
+pub trait QueryResultCache:
+    CacheAccessor<QueryResultCacheKey, Arc<CachedQueryResult>, Extra = LogicalPlan>
+{
+    /// Returns the cache's memory limit in bytes.
+    fn cache_limit(&self) -> usize;
+
+    /// Updates the cache with a new memory limit in bytes.
+    fn update_cache_limit(&self, limit: usize);
+
+    /// Removes all cached results that read a table named `table`, as it is
+    /// replaced or dropped (e.g. by `CREATE OR REPLACE TABLE` or `DROP TABLE`).
+    ///
+    /// This only frees memory: results of a replaced table are never returned,
+    /// as the key contains the identity of the table provider.
+    fn invalidate_table(&self, table: &TableReference);
+}
+
 impl Debug for dyn FileStatisticsCache {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Cache name: {} with length: {}", self.name(), self.len())
@@ -179,6 +296,12 @@ impl Debug for dyn FileMetadataCache {
     }
 }
 
+impl Debug for dyn QueryResultCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cache name: {} with length: {}", self.name(), self.len())
+    }
+}
+
 /// Manages various caches used in DataFusion.
 ///
 /// Following DataFusion design principles, DataFusion provides default cache
@@ -191,6 +314,7 @@ pub struct CacheManager {
     file_statistic_cache: Option<Arc<dyn FileStatisticsCache>>,
     list_files_cache: Option<Arc<dyn ListFilesCache>>,
     file_metadata_cache: Arc<dyn FileMetadataCache>,
+    query_result_cache: Option<Arc<dyn QueryResultCache>>,
 }
 
 impl CacheManager {
@@ -229,10 +353,26 @@ impl CacheManager {
         // the cache memory limit might have changed, ensure the limit is updated
         file_metadata_cache.update_cache_limit(config.metadata_cache_limit);
 
+        let query_result_cache = match &config.query_result_cache {
+            Some(qrc) if config.query_result_cache_limit > 0 => {
+                // the cache memory limit might have changed, ensure it is updated
+                qrc.update_cache_limit(config.query_result_cache_limit);
+                Some(Arc::clone(qrc))
+            }
+            None if config.query_result_cache_limit > 0 => {
+                let qrc: Arc<dyn QueryResultCache> = Arc::new(
+                    DefaultQueryResultCache::new(config.query_result_cache_limit),
+                );
+                Some(qrc)
+            }
+            _ => None,
+        };
+
         Ok(Arc::new(CacheManager {
             file_statistic_cache,
             list_files_cache,
             file_metadata_cache,
+            query_result_cache,
         }))
     }
 
@@ -267,10 +407,25 @@ impl CacheManager {
     pub fn get_metadata_cache_limit(&self) -> usize {
         self.file_metadata_cache.cache_limit()
     }
+
+    /// Get the cache of query results.
+    pub fn get_query_result_cache(&self) -> Option<Arc<dyn QueryResultCache>> {
+        self.query_result_cache.clone()
+    }
+
+    /// Get the memory limit of the query result cache.
+    pub fn get_query_result_cache_limit(&self) -> usize {
+        self.query_result_cache
+            .as_ref()
+            .map_or(0, |c| c.cache_limit())
+    }
 }
 
 pub const DEFAULT_METADATA_CACHE_LIMIT: usize = 50 * 1024 * 1024; // 50M
 
+/// Default limit of the query result cache: disabled.
+pub const DEFAULT_QUERY_RESULT_CACHE_LIMIT: usize = 0;
+
 #[derive(Clone)]
 pub struct CacheManagerConfig {
     /// Enable caching of file statistics when listing files.
@@ -297,6 +452,14 @@ pub struct CacheManagerConfig {
     pub file_metadata_cache: Option<Arc<dyn FileMetadataCache>>,
     /// Limit of the file-embedded metadata cache, in bytes.
     pub metadata_cache_limit: usize,
+    /// Cache of query results, used to avoid executing the same query repeatedly.
+    /// Only queries over tables that report a data version (such as
+    /// `MemTable`) are cached, queries over external tables always bypass it.
+    /// If not provided and `query_result_cache_limit` is non-zero, the
+    /// [`CacheManager`] will create a [`DefaultQueryResultCache`].
+    pub query_result_cache: Option<Arc<dyn QueryResultCache>>,
+    /// Limit of the query result cache, in bytes. Default: 0 (disabled).
+    pub query_result_cache_limit: usize,
 }
 
 impl Default for CacheManagerConfig {
@@ -308,6 +471,8 @@ impl Default for CacheManagerConfig {
             list_files_cache_ttl: DEFAULT_LIST_FILES_CACHE_TTL,
             file_metadata_cache: Default::default(),
             metadata_cache_limit: DEFAULT_METADATA_CACHE_LIMIT,
+            query_result_cache: Default::default(),
+            query_result_cache_limit: DEFAULT_QUERY_RESULT_CACHE_LIMIT,
         }
     }
 }
@@ -367,6 +532,26 @@ impl CacheManagerConfig {
         self.metadata_cache_limit = limit;
         self
     }
+
+    /// Sets the cache for query results.
+    ///
+    /// Default is `None`. The cache is only used if the
+    /// [query result cache limit](Self::with_query_result_cache_limit) is non-zero.
+    pub fn with_query_result_cache(
+        mut self,
+        cache: Option<Arc<dyn QueryResultCache>>,
+    ) -> Self {
+        self.query_result_cache = cache;
+        self
+    }
+
+    /// Sets the limit of the query result cache, in bytes.
+    ///
+    /// Default: 0 (disabled).
+    pub fn with_query_result_cache_limit(mut self, limit: usize) -> Self {
+        self.query_result_cache_limit = limit;
+        self
+    }
 }
 
 #[cfg(test)]
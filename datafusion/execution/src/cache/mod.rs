@@ -21,11 +21,13 @@ pub mod lru_queue;
 
 mod file_metadata_cache;
 mod list_files_cache;
+mod query_result_cache;
 
 pub use file_metadata_cache::DefaultFilesMetadataCache;
 pub use list_files_cache::DefaultListFilesCache;
 pub use list_files_cache::ListFilesEntry;
 pub use list_files_cache::TableScopedPath;
+pub use query_result_cache::DefaultQueryResultCache;
 
 /// A trait that can be implemented to provide custom cache behavior for the caches managed by
 /// [`cache_manager::CacheManager`].
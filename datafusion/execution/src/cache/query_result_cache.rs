@@ -0,0 +1,358 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::{Arc, Mutex};
+
+use datafusion_common::TableReference;
+use datafusion_expr::LogicalPlan;
+
+use crate::cache::{
+    CacheAccessor,
+    cache_manager::{CachedQueryResult, QueryResultCache, QueryResultCacheKey},
+    lru_queue::LruQueue,
+};
+
+/// Handles the inner state of the [`DefaultQueryResultCache`] struct.
+struct DefaultQueryResultCacheState {
+    lru_queue: LruQueue<QueryResultCacheKey, Arc<CachedQueryResult>>,
+    memory_limit: usize,
+    memory_used: usize,
+}
+
+impl DefaultQueryResultCacheState {
+    fn new(memory_limit: usize) -> Self {
+        Self {
+            lru_queue: LruQueue::new(),
+            memory_limit,
+            memory_used: 0,
+        }
+    }
+
+    /// Returns the respective entry from the cache, if it exists and its plan
+    /// is equal to `plan` (if provided).
+    /// If the entry exists, it becomes the most recently used.
+    fn get(
+        &mut self,
+        k: &QueryResultCacheKey,
+        plan: Option<&LogicalPlan>,
+    ) -> Option<Arc<CachedQueryResult>> {
+        self.lru_queue
+            .get(k)
+            .filter(|result| plan.is_none_or(|plan| result.plan.as_ref() == plan))
+            .map(Arc::clone)
+    }
+
+    /// Adds a new key-value pair to cache, meaning LRU entries might be evicted if required.
+    /// If the key is already in the cache, the previous result is returned.
+    /// If the size of the result is greater than the `memory_limit`, the value is not
+    /// inserted.
+    fn put(
+        &mut self,
+        key: QueryResultCacheKey,
+        value: Arc<CachedQueryResult>,
+    ) -> Option<Arc<CachedQueryResult>> {
+        let value_size = value.memory_size();
+
+        // no point in trying to add this value to the cache if it cannot fit entirely
+        if value_size > self.memory_limit {
+            return None;
+        }
+
+        // if the key is already in the cache, the old value is removed
+        let old_value = self.lru_queue.put(key, value);
+        self.memory_used += value_size;
+        if let Some(ref old_result) = old_value {
+            self.memory_used -= old_result.memory_size();
+        }
+
+        self.evict_entries();
+
+        old_value
+    }
+
+    /// Evicts entries from the LRU cache until `memory_used` is lower than `memory_limit`.
+    fn evict_entries(&mut self) {
+        while self.memory_used > self.memory_limit {
+            if let Some((_, removed)) = self.lru_queue.pop() {
+                self.memory_used -= removed.memory_size();
+            } else {
+                // cache is empty while memory_used > memory_limit, cannot happen
+                debug_assert!(
+                    false,
+                    "cache is empty while memory_used > memory_limit, cannot happen"
+                );
+                return;
+            }
+        }
+    }
+
+    /// Removes an entry from the cache and returns it, if it exists.
+    fn remove(&mut self, k: &QueryResultCacheKey) -> Option<Arc<CachedQueryResult>> {
+        let old_result = self.lru_queue.remove(k)?;
+        self.memory_used -= old_result.memory_size();
+        Some(old_result)
+    }
+
+    /// Removes all entries that read `table`.
+    fn invalidate_table(&mut self, table: &TableReference) {
+        let keys = self
+            .lru_queue
+            .list_entries()
+            .into_keys()
+            .filter(|key| key.references_table(table))
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+
+    /// Removes all entries from the cache.
+    fn clear(&mut self) {
+        self.lru_queue.clear();
+        self.memory_used = 0;
+    }
+}
+
+/// Default implementation of [`QueryResultCache`]
+///
+/// Results computed before a table they read was modified are not returned, as
+/// the key contains the version of the table. Such results are evicted like any
+/// other unused entry, or by [`QueryResultCache::invalidate_table`].
+///
+/// # Internal details
+///
+/// The `memory_limit` controls the maximum size of the cache, which uses a
+/// Least Recently Used eviction algorithm. When adding a new entry, if the total
+/// size of the cached entries exceeds `memory_limit`, the least recently used entries
+/// are evicted until the total size is lower than `memory_limit`.
+///
+/// # `Extra` Handling
+///
+/// [`Self::get_with_extra`] only returns a result if it was computed for the
+/// given plan, which guards against different plans with the same signature.
+/// [`Self::get`] does not check the plan. [`Self::put_with_extra`] simply calls
+/// `put`.
+pub struct DefaultQueryResultCache {
+    // the state is wrapped in a Mutex to ensure the operations are atomic
+    state: Mutex<DefaultQueryResultCacheState>,
+}
+
+impl DefaultQueryResultCache {
+    /// Create a new instance of [`DefaultQueryResultCache`].
+    ///
+    /// # Arguments
+    /// `memory_limit`:  the maximum size of the cache, in bytes
+    pub fn new(memory_limit: usize) -> Self {
+        Self {
+            state: Mutex::new(DefaultQueryResultCacheState::new(memory_limit)),
+        }
+    }
+
+    /// Returns the size of the cached memory, in bytes.
+    pub fn memory_used(&self) -> usize {
+        let state = self.state.lock().unwrap();
+        state.memory_used
+    }
+}
+
+impl QueryResultCache for DefaultQueryResultCache {
+    fn cache_limit(&self) -> usize {
+        let state = self.state.lock().unwrap();
+        state.memory_limit
+    }
+
+    fn update_cache_limit(&self, limit: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.memory_limit = limit;
+        state.evict_entries();
+    }
+
+    fn invalidate_table(&self, table: &TableReference) {
+        let mut state = self.state.lock().unwrap();
+        state.invalidate_table(table);
+    }
+}
+
+impl CacheAccessor<QueryResultCacheKey, Arc<CachedQueryResult>>
+    for DefaultQueryResultCache
+{
+    type Extra = LogicalPlan;
+
+    fn get(&self, k: &QueryResultCacheKey) -> Option<Arc<CachedQueryResult>> {
+        let mut state = self.state.lock().unwrap();
+        state.get(k, None)
+    }
+
+    fn get_with_extra(
+        &self,
+        k: &QueryResultCacheKey,
+        e: &Self::Extra,
+    ) -> Option<Arc<CachedQueryResult>> {
+        let mut state = self.state.lock().unwrap();
+        state.get(k, Some(e))
+    }
+
+    fn put(
+        &self,
+        key: &QueryResultCacheKey,
+        value: Arc<CachedQueryResult>,
+    ) -> Option<Arc<CachedQueryResult>> {
+        let mut state = self.state.lock().unwrap();
+        state.put(key.clone(), value)
+    }
+
+    fn put_with_extra(
+        &self,
+        key: &QueryResultCacheKey,
+        value: Arc<CachedQueryResult>,
+        _e: &Self::Extra,
+    ) -> Option<Arc<CachedQueryResult>> {
+        self.put(key, value)
+    }
+
+    fn remove(&self, k: &QueryResultCacheKey) -> Option<Arc<CachedQueryResult>> {
+        let mut state = self.state.lock().unwrap();
+        state.remove(k)
+    }
+
+    fn contains_key(&self, k: &QueryResultCacheKey) -> bool {
+        let state = self.state.lock().unwrap();
+        state.lru_queue.contains_key(k)
+    }
+
+    fn len(&self) -> usize {
+        let state = self.state.lock().unwrap();
+        state.lru_queue.len()
+    }
+
+    fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.clear();
+    }
+
+    fn name(&self) -> String {
+        "DefaultQueryResultCache".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Int32Array, RecordBatch};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_common::{DFSchema, TableReference};
+    use datafusion_expr::{EmptyRelation, LogicalPlan};
+
+    use crate::cache::CacheAccessor;
+    use crate::cache::DefaultQueryResultCache;
+    use crate::cache::cache_manager::{
+        CachedQueryResult, QueryResultCache, QueryResultCacheKey, QueryResultCacheTable,
+    };
+
+    fn plan(produce_one_row: bool) -> LogicalPlan {
+        LogicalPlan::EmptyRelation(EmptyRelation {
+            produce_one_row,
+            schema: Arc::new(DFSchema::empty()),
+        })
+    }
+
+    fn result(plan: LogicalPlan, num_rows: usize) -> Arc<CachedQueryResult> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(Int32Array::from(vec![0; num_rows]))],
+        )
+        .unwrap();
+        Arc::new(CachedQueryResult {
+            plan: Arc::new(plan),
+            schema,
+            batches: vec![batch],
+        })
+    }
+
+    fn key(signature: &str, tables: &[&str]) -> QueryResultCacheKey {
+        let tables = tables
+            .iter()
+            .map(|table| QueryResultCacheTable {
+                table: TableReference::bare(*table),
+                provider_id: 0,
+                version: 0,
+            })
+            .collect();
+        QueryResultCacheKey::new(&signature, tables)
+    }
+
+    #[test]
+    fn test_query_result_cache_plan_check() {
+        let cache = DefaultQueryResultCache::new(1024 * 1024);
+        let key = key("q1", &["t1"]);
+        cache.put(&key, result(plan(true), 1));
+
+        assert!(cache.get_with_extra(&key, &plan(true)).is_some());
+        // a different plan with the same signature is not returned
+        assert!(cache.get_with_extra(&key, &plan(false)).is_none());
+        assert!(cache.get(&key).is_some());
+    }
+
+    #[test]
+    fn test_query_result_cache_invalidate_table() {
+        let cache = DefaultQueryResultCache::new(1024 * 1024);
+        let key1 = key("q1", &["t1"]);
+        let key2 = key("q2", &["t1", "t2"]);
+        let key3 = key("q3", &["t3"]);
+        for key in [&key1, &key2, &key3] {
+            cache.put(key, result(plan(true), 1));
+        }
+        assert_eq!(cache.len(), 3);
+
+        cache.invalidate_table(&TableReference::bare("t1"));
+
+        assert!(!cache.contains_key(&key1));
+        assert!(!cache.contains_key(&key2));
+        assert!(cache.contains_key(&key3));
+        assert_eq!(cache.memory_used(), result(plan(true), 1).memory_size());
+    }
+
+    #[test]
+    fn test_query_result_cache_eviction() {
+        let entry_size = result(plan(true), 100).memory_size();
+        let cache = DefaultQueryResultCache::new(2 * entry_size);
+        let key1 = key("q1", &[]);
+        let key2 = key("q2", &[]);
+        let key3 = key("q3", &[]);
+        cache.put(&key1, result(plan(true), 100));
+        cache.put(&key2, result(plan(true), 100));
+        // key1 becomes the most recently used entry
+        assert!(cache.get(&key1).is_some());
+        cache.put(&key3, result(plan(true), 100));
+
+        assert!(cache.contains_key(&key1));
+        assert!(!cache.contains_key(&key2));
+        assert!(cache.contains_key(&key3));
+        assert_eq!(cache.memory_used(), 2 * entry_size);
+
+        // results larger than the limit are not cached
+        cache.put(&key2, result(plan(true), 1000));
+        assert!(!cache.contains_key(&key2));
+
+        cache.update_cache_limit(entry_size);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.memory_used(), entry_size);
+    }
+}
@@ -433,6 +433,13 @@ impl RuntimeEnvBuilder {
         self
     }
 
+    /// Specifies the memory limit for the query result cache, in bytes. The
+    /// cache is disabled if the limit is 0, which is the default.
+    pub fn with_query_result_cache_limit(mut self, limit: usize) -> Self {
+        self.cache_manager = self.cache_manager.with_query_result_cache_limit(limit);
+        self
+    }
+
     /// Build a RuntimeEnv
     pub fn build(self) -> Result<RuntimeEnv> {
         let Self {
@@ -482,6 +489,10 @@ impl RuntimeEnvBuilder {
                 runtime_env.cache_manager.get_file_metadata_cache(),
             ),
             metadata_cache_limit: runtime_env.cache_manager.get_metadata_cache_limit(),
+            query_result_cache: runtime_env.cache_manager.get_query_result_cache(),
+            query_result_cache_limit: runtime_env
+                .cache_manager
+                .get_query_result_cache_limit(),
         };
 
         Self {
@@ -25,23 +25,23 @@ use std::iter::once;
 use std::sync::Arc;
 
 use crate::dml::CopyTo;
-use crate::expr::{Alias, PlannedReplaceSelectItem, Sort as SortExpr};
+use crate::expr::{Alias, BinaryExpr, PlannedReplaceSelectItem, Sort as SortExpr};
 use crate::expr_rewriter::{
     coerce_plan_expr_for_schema, normalize_col,
     normalize_col_with_schemas_and_ambiguity_check, normalize_cols, normalize_sorts,
     rewrite_sort_cols_by_aggs,
 };
 use crate::logical_plan::{
-    Aggregate, Analyze, Distinct, DistinctOn, EmptyRelation, Explain, Filter, Join,
-    JoinConstraint, JoinType, Limit, LogicalPlan, Partitioning, PlanType, Prepare,
-    Projection, Repartition, Sort, SubqueryAlias, TableScan, Union, Unnest, Values,
-    Window,
+    Aggregate, Analyze, AsOfJoin, Distinct, DistinctOn, EmptyRelation, Explain, Filter,
+    Join, JoinConstraint, JoinType, Limit, LogicalPlan, Partitioning, PlanType, Prepare,
+    Projection, Repartition, Sample, Sort, SubqueryAlias, TableScan, Union, Unnest,
+    Values, Window,
 };
 use crate::select_expr::SelectExpr;
 use crate::utils::{
     can_hash, columnize_expr, compare_sort_expr, expand_qualified_wildcard,
     expand_wildcard, expr_to_columns, find_valid_equijoin_key_pair,
-    group_window_expr_by_sort_keys,
+    group_window_expr_by_sort_keys, split_conjunction_owned,
 };
 use crate::{
     DmlStatement, ExplainOption, Expr, ExprSchemable, Operator, RecursiveQuery,
@@ -55,12 +55,14 @@ use datafusion_common::display::ToStringifiedPlan;
 use datafusion_common::file_options::file_type::FileType;
 use datafusion_common::metadata::FieldMetadata;
 use datafusion_common::{
-    Column, Constraints, DFSchema, DFSchemaRef, NullEquality, Result, ScalarValue,
-    TableReference, ToDFSchema, UnnestOptions, exec_err,
+    Column, Constraints, DFSchema, DFSchemaRef, NullEquality, Result, SampleMethod,
+    ScalarValue, TableReference, ToDFSchema, UnnestOptions, exec_err,
     get_target_functional_dependencies, internal_datafusion_err, plan_datafusion_err,
     plan_err,
 };
-use datafusion_expr_common::type_coercion::binary::type_union_resolution;
+use datafusion_expr_common::type_coercion::binary::{
+    comparison_coercion, type_union_resolution,
+};
 
 use indexmap::IndexSet;
 
@@ -659,6 +661,23 @@ impl LogicalPlanBuilder {
         })))
     }
 
+    /// Return a random sample of the rows, see [`SampleMethod`]
+    ///
+    /// `seed` - Seed of the random number generator, to make the sample
+    ///          repeatable. If `None`, a random seed is used.
+    pub fn sample(self, method: SampleMethod, seed: Option<u64>) -> Result<Self> {
+        if let Some(fraction) = method.fraction()
+            && !(0.0..=1.0).contains(&fraction)
+        {
+            return plan_err!("Sample fraction must be between 0 and 1, got {fraction}");
+        }
+        Ok(Self::new(LogicalPlan::Sample(Sample {
+            method,
+            seed,
+            input: self.plan,
+        })))
+    }
+
     /// Apply an alias
     pub fn alias(self, alias: impl Into<TableReference>) -> Result<Self> {
         subquery_alias(Arc::unwrap_or_clone(self.plan), alias).map(Self::new)
@@ -1222,6 +1241,140 @@ impl LogicalPlanBuilder {
         Ok(Self::new(LogicalPlan::Join(join)))
     }
 
+    /// Apply an ASOF join, see [`AsOfJoin`].
+    ///
+    /// `match_condition` is a comparison with `>=`, `>`, `<=` or `<` between
+    /// an expression of each input, for example `left.ts >= right.ts`.
+    /// `on_exprs` are equality predicates between the inputs, which are
+    /// combined with `AND`.
+    pub fn asof_join(
+        self,
+        right: LogicalPlan,
+        join_type: JoinType,
+        match_condition: Expr,
+        on_exprs: impl IntoIterator<Item = Expr>,
+    ) -> Result<Self> {
+        let on = on_exprs
+            .into_iter()
+            .flat_map(split_conjunction_owned)
+            .map(|expr| {
+                let expr = normalize_col_with_schemas_and_ambiguity_check(
+                    expr,
+                    &[&[self.schema(), right.schema()]],
+                    &[],
+                )?;
+                match expr {
+                    Expr::BinaryExpr(BinaryExpr {
+                        left,
+                        op: Operator::Eq,
+                        right: r,
+                    }) => find_valid_equijoin_key_pair(
+                        &left,
+                        &r,
+                        self.schema(),
+                        right.schema(),
+                    )?
+                    .ok_or_else(|| {
+                        plan_datafusion_err!(
+                            "ASOF JOIN equality must compare an expression of each input, got {left} = {r}"
+                        )
+                    }),
+                    expr => plan_err!(
+                        "ASOF JOIN ON clause must only contain equalities, got {expr}"
+                    ),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.asof_join_detailed(right, join_type, match_condition, on, JoinConstraint::On)
+    }
+
+    /// Apply an ASOF join with using constraint, which duplicates all join
+    /// columns in output schema. See [`Self::asof_join`].
+    pub fn asof_join_using(
+        self,
+        right: LogicalPlan,
+        join_type: JoinType,
+        match_condition: Expr,
+        using_keys: Vec<Column>,
+    ) -> Result<Self> {
+        let on = using_keys
+            .into_iter()
+            .map(|c| {
+                Ok((
+                    Expr::Column(Self::normalize(&self.plan, c.clone())?),
+                    Expr::Column(Self::normalize(&right, c)?),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.asof_join_detailed(
+            right,
+            join_type,
+            match_condition,
+            on,
+            JoinConstraint::Using,
+        )
+    }
+
+    fn asof_join_detailed(
+        self,
+        right: LogicalPlan,
+        join_type: JoinType,
+        match_condition: Expr,
+        on: Vec<(Expr, Expr)>,
+        join_constraint: JoinConstraint,
+    ) -> Result<Self> {
+        let match_condition = normalize_col_with_schemas_and_ambiguity_check(
+            match_condition,
+            &[&[self.schema(), right.schema()]],
+            &[],
+        )?;
+        let Expr::BinaryExpr(BinaryExpr { left, op, right: r }) = &match_condition else {
+            return plan_err!(
+                "ASOF JOIN match condition must be a comparison, got {match_condition}"
+            );
+        };
+        let Some((match_left, match_right)) =
+            find_valid_equijoin_key_pair(left, r, self.schema(), right.schema())?
+        else {
+            return plan_err!(
+                "ASOF JOIN match condition must compare an expression of each input, got {match_condition}"
+            );
+        };
+        // Keep the operator consistent if the sides were swapped
+        let match_op = if match_left == **left {
+            *op
+        } else {
+            op.swap().ok_or_else(|| {
+                plan_datafusion_err!("Cannot swap the operator of {match_condition}")
+            })?
+        };
+
+        let on = on
+            .into_iter()
+            .map(|(l, r)| coerce_asof_join_keys(l, r, self.schema(), right.schema()))
+            .collect::<Result<Vec<_>>>()?;
+        let match_on = coerce_asof_join_keys(
+            match_left,
+            match_right,
+            self.schema(),
+            right.schema(),
+        )?;
+
+        let join = AsOfJoin::try_new(
+            self.plan,
+            Arc::new(right),
+            on,
+            match_on,
+            match_op,
+            join_type,
+            join_constraint,
+        )?;
+
+        Ok(Self::new(LogicalPlan::AsOfJoin(join)))
+    }
+
     /// Repartition
     pub fn repartition(self, partitioning_scheme: Partitioning) -> Result<Self> {
         Ok(Self::new(LogicalPlan::Repartition(Repartition {
@@ -1619,6 +1772,30 @@ fn mark_field(schema: &DFSchema) -> (Option<TableReference>, Arc<Field>) {
     )
 }
 
+/// Casts a pair of ASOF join keys to a common type, so that they can be
+/// compared by the physical operator.
+fn coerce_asof_join_keys(
+    left: Expr,
+    right: Expr,
+    left_schema: &DFSchema,
+    right_schema: &DFSchema,
+) -> Result<(Expr, Expr)> {
+    let left_type = left.get_type(left_schema)?;
+    let right_type = right.get_type(right_schema)?;
+    if left_type == right_type {
+        return Ok((left, right));
+    }
+    let Some(coerced_type) = comparison_coercion(&left_type, &right_type) else {
+        return plan_err!(
+            "Cannot compare ASOF JOIN keys {left} ({left_type}) and {right} ({right_type})"
+        );
+    };
+    Ok((
+        left.cast_to(&coerced_type, left_schema)?,
+        right.cast_to(&coerced_type, right_schema)?,
+    ))
+}
+
 /// Creates a schema for a join operation.
 /// The fields from the left side are first
 pub fn build_join_schema(
@@ -27,7 +27,7 @@ use std::{
 #[cfg(not(feature = "sql"))]
 use crate::expr::Ident;
 use crate::expr::Sort;
-use arrow::datatypes::DataType;
+use arrow::datatypes::{DataType, FieldRef};
 use datafusion_common::tree_node::{Transformed, TreeNodeContainer, TreeNodeRecursion};
 use datafusion_common::{
     Constraints, DFSchemaRef, Result, SchemaReference, TableReference,
@@ -44,6 +44,10 @@ pub enum DdlStatement {
     CreateMemoryTable(CreateMemoryTable),
     /// Creates a new view.
     CreateView(CreateView),
+    /// Creates a new materialized view.
+    CreateMaterializedView(CreateMaterializedView),
+    /// Recomputes the stored result of a materialized view.
+    RefreshMaterializedView(RefreshMaterializedView),
     /// Creates a new catalog schema.
     CreateCatalogSchema(CreateCatalogSchema),
     /// Creates a new catalog (aka "Database").
@@ -60,6 +64,10 @@ pub enum DdlStatement {
     CreateFunction(CreateFunction),
     /// Drop function statement
     DropFunction(DropFunction),
+    /// Alters a table.
+    AlterTable(AlterTable),
+    /// Removes all rows from a table.
+    TruncateTable(TruncateTable),
 }
 
 impl DdlStatement {
@@ -70,7 +78,15 @@ impl DdlStatement {
                 schema
             }
             DdlStatement::CreateMemoryTable(CreateMemoryTable { input, .. })
-            | DdlStatement::CreateView(CreateView { input, .. }) => input.schema(),
+            | DdlStatement::CreateView(CreateView { input, .. })
+            | DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => input.schema(),
+            DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                schema,
+                ..
+            }) => schema,
             DdlStatement::CreateCatalogSchema(CreateCatalogSchema { schema, .. }) => {
                 schema
             }
@@ -81,6 +97,8 @@ impl DdlStatement {
             DdlStatement::DropCatalogSchema(DropCatalogSchema { schema, .. }) => schema,
             DdlStatement::CreateFunction(CreateFunction { schema, .. }) => schema,
             DdlStatement::DropFunction(DropFunction { schema, .. }) => schema,
+            DdlStatement::AlterTable(AlterTable { schema, .. }) => schema,
+            DdlStatement::TruncateTable(TruncateTable { schema, .. }) => schema,
         }
     }
 
@@ -91,6 +109,8 @@ impl DdlStatement {
             DdlStatement::CreateExternalTable(_) => "CreateExternalTable",
             DdlStatement::CreateMemoryTable(_) => "CreateMemoryTable",
             DdlStatement::CreateView(_) => "CreateView",
+            DdlStatement::CreateMaterializedView(_) => "CreateMaterializedView",
+            DdlStatement::RefreshMaterializedView(_) => "RefreshMaterializedView",
             DdlStatement::CreateCatalogSchema(_) => "CreateCatalogSchema",
             DdlStatement::CreateCatalog(_) => "CreateCatalog",
             DdlStatement::CreateIndex(_) => "CreateIndex",
@@ -99,6 +119,8 @@ impl DdlStatement {
             DdlStatement::DropCatalogSchema(_) => "DropCatalogSchema",
             DdlStatement::CreateFunction(_) => "CreateFunction",
             DdlStatement::DropFunction(_) => "DropFunction",
+            DdlStatement::AlterTable(_) => "AlterTable",
+            DdlStatement::TruncateTable(_) => "TruncateTable",
         }
     }
 
@@ -112,12 +134,19 @@ impl DdlStatement {
                 vec![input]
             }
             DdlStatement::CreateView(CreateView { input, .. }) => vec![input],
+            DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => vec![input],
+            DdlStatement::RefreshMaterializedView(_) => vec![],
             DdlStatement::CreateIndex(_) => vec![],
             DdlStatement::DropTable(_) => vec![],
             DdlStatement::DropView(_) => vec![],
             DdlStatement::DropCatalogSchema(_) => vec![],
             DdlStatement::CreateFunction(_) => vec![],
             DdlStatement::DropFunction(_) => vec![],
+            DdlStatement::AlterTable(_) => vec![],
+            DdlStatement::TruncateTable(_) => vec![],
         }
     }
 
@@ -156,6 +185,23 @@ impl DdlStatement {
                     DdlStatement::CreateView(CreateView { name, .. }) => {
                         write!(f, "CreateView: {name:?}")
                     }
+                    DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                        name,
+                        location,
+                        ..
+                    }) => match location {
+                        Some(location) => write!(
+                            f,
+                            "CreateMaterializedView: {name:?} location={location}"
+                        ),
+                        None => write!(f, "CreateMaterializedView: {name:?}"),
+                    },
+                    DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                        name,
+                        ..
+                    }) => {
+                        write!(f, "RefreshMaterializedView: {name:?}")
+                    }
                     DdlStatement::CreateCatalogSchema(CreateCatalogSchema {
                         schema_name,
                         ..
@@ -197,6 +243,25 @@ impl DdlStatement {
                     DdlStatement::DropFunction(DropFunction { name, .. }) => {
                         write!(f, "DropFunction: name {name:?}")
                     }
+                    DdlStatement::AlterTable(AlterTable {
+                        name,
+                        if_exists,
+                        operations,
+                        ..
+                    }) => {
+                        let operations = operations
+                            .iter()
+                            .map(|op| op.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        write!(
+                            f,
+                            "AlterTable: {name:?} if exists:={if_exists} operations=[{operations}]"
+                        )
+                    }
+                    DdlStatement::TruncateTable(TruncateTable { name, .. }) => {
+                        write!(f, "TruncateTable: {name:?}")
+                    }
                 }
             }
         }
@@ -493,6 +558,47 @@ pub struct CreateView {
     pub temporary: bool,
 }
 
+/// Creates a materialized view.
+///
+/// The result of `input` is computed when the view is created and stored,
+/// either in memory or as files at `location`. It is only recomputed by
+/// [`RefreshMaterializedView`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash)]
+pub struct CreateMaterializedView {
+    /// The view name
+    pub name: TableReference,
+    /// The logical plan defining the view
+    pub input: Arc<LogicalPlan>,
+    /// The location to store the result of the view at, or `None` to store
+    /// it in memory
+    pub location: Option<String>,
+    /// Option to not error if the view already exists
+    pub if_not_exists: bool,
+    /// Option to replace the view if it already exists
+    pub or_replace: bool,
+    /// SQL used to create the view, if available
+    pub definition: Option<String>,
+}
+
+/// Recomputes the stored result of a materialized view.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RefreshMaterializedView {
+    /// The view name
+    pub name: TableReference,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for RefreshMaterializedView {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.name
+            .partial_cmp(&other.name)
+            // TODO (https://github.com/apache/datafusion/issues/17477) avoid recomparing all fields
+            .filter(|cmp| *cmp != Ordering::Equal || self == other)
+    }
+}
+
 /// Creates a catalog (aka "Database").
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateCatalog {
@@ -613,6 +719,127 @@ impl PartialOrd for DropCatalogSchema {
     }
 }
 
+/// Alters the definition of a table.
+///
+/// The operations are applied in order and the altered table only replaces
+/// the original one once all of them succeeded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTable {
+    /// The table name
+    pub name: TableReference,
+    /// If the table exists
+    pub if_exists: bool,
+    /// The operations to apply to the table
+    pub operations: Vec<AlterTableOperation>,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for AlterTable {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.name.partial_cmp(&other.name) {
+            Some(Ordering::Equal) => match self.if_exists.partial_cmp(&other.if_exists) {
+                Some(Ordering::Equal) => self.operations.partial_cmp(&other.operations),
+                cmp => cmp,
+            },
+            cmp => cmp,
+        }
+        // TODO (https://github.com/apache/datafusion/issues/17477) avoid recomparing all fields
+        .filter(|cmp| *cmp != Ordering::Equal || self == other)
+    }
+}
+
+/// A single operation of an [`AlterTable`] statement.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum AlterTableOperation {
+    /// `ADD COLUMN [IF NOT EXISTS] <column_def>`
+    AddColumn {
+        /// The new column
+        field: FieldRef,
+        /// The default value of the column, used for the existing rows
+        default: Option<Expr>,
+        /// Do nothing if the column already exists
+        if_not_exists: bool,
+    },
+    /// `DROP COLUMN [IF EXISTS] <name>`
+    DropColumn {
+        /// The column name
+        name: String,
+        /// Do nothing if the column doesn't exist
+        if_exists: bool,
+    },
+    /// `RENAME COLUMN <old_name> TO <new_name>`
+    RenameColumn {
+        /// The current column name
+        old_name: String,
+        /// The new column name
+        new_name: String,
+    },
+    /// `RENAME TO <new_name>`
+    RenameTable {
+        /// The new table name
+        new_name: TableReference,
+    },
+}
+
+impl Display for AlterTableOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlterTableOperation::AddColumn {
+                field,
+                default,
+                if_not_exists,
+            } => {
+                write!(f, "AddColumn: ")?;
+                if *if_not_exists {
+                    write!(f, "if not exists ")?;
+                }
+                write!(f, "{} {}", field.name(), field.data_type())?;
+                if !field.is_nullable() {
+                    write!(f, " NOT NULL")?;
+                }
+                if let Some(default) = default {
+                    write!(f, " DEFAULT {default}")?;
+                }
+                Ok(())
+            }
+            AlterTableOperation::DropColumn { name, if_exists } => {
+                write!(f, "DropColumn: ")?;
+                if *if_exists {
+                    write!(f, "if exists ")?;
+                }
+                write!(f, "{name}")
+            }
+            AlterTableOperation::RenameColumn { old_name, new_name } => {
+                write!(f, "RenameColumn: {old_name} to {new_name}")
+            }
+            AlterTableOperation::RenameTable { new_name } => {
+                write!(f, "RenameTable: {new_name}")
+            }
+        }
+    }
+}
+
+/// Removes all rows from a table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TruncateTable {
+    /// The table name
+    pub name: TableReference,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for TruncateTable {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.name
+            .partial_cmp(&other.name)
+            // TODO (https://github.com/apache/datafusion/issues/17477) avoid recomparing all fields
+            .filter(|cmp| *cmp != Ordering::Equal || self == other)
+    }
+}
+
 /// Arguments passed to the `CREATE FUNCTION` statement
 ///
 /// These statements are turned into executable functions using [`FunctionFactory`]
@@ -21,10 +21,10 @@ use std::collections::HashMap;
 use std::fmt;
 
 use crate::{
-    Aggregate, DescribeTable, Distinct, DistinctOn, DmlStatement, Expr, Filter, Join,
-    Limit, LogicalPlan, Partitioning, Projection, RecursiveQuery, Repartition, Sort,
-    Subquery, SubqueryAlias, TableProviderFilterPushDown, TableScan, Unnest, Values,
-    Window, expr_vec_fmt,
+    Aggregate, AsOfJoin, DescribeTable, Distinct, DistinctOn, DmlStatement, Expr, Filter,
+    Join, Limit, LogicalPlan, MatchRecognize, Partitioning, Projection, RecursiveQuery,
+    Repartition, Sample, Sort, Subquery, SubqueryAlias, TableProviderFilterPushDown,
+    TableScan, Unnest, Values, Window, expr_vec_fmt,
 };
 
 use crate::dml::CopyTo;
@@ -499,6 +499,23 @@ impl<'a, 'b> PgJsonVisitor<'a, 'b> {
                     "Filter": format!("{}", filter_expr)
                 })
             }
+            LogicalPlan::AsOfJoin(AsOfJoin {
+                on: keys,
+                match_on: (match_left, match_right),
+                match_op,
+                join_constraint,
+                join_type,
+                ..
+            }) => {
+                let join_expr: Vec<String> =
+                    keys.iter().map(|(l, r)| format!("{l} = {r}")).collect();
+                json!({
+                    "Node Type": format!("{join_type} AsOf Join"),
+                    "Join Constraint": format!("{join_constraint:?}"),
+                    "Join Keys": join_expr.join(", "),
+                    "Match Condition": format!("{match_left} {match_op} {match_right}"),
+                })
+            }
             LogicalPlan::Repartition(Repartition {
                 partitioning_scheme,
                 ..
@@ -545,6 +562,16 @@ impl<'a, 'b> PgJsonVisitor<'a, 'b> {
                 };
                 object
             }
+            LogicalPlan::Sample(Sample { method, seed, .. }) => {
+                let mut object = json!({
+                    "Node Type": "Sample",
+                    "Method": method.to_string(),
+                });
+                if let Some(seed) = seed {
+                    object["Seed"] = seed.to_string().into()
+                };
+                object
+            }
             LogicalPlan::Subquery(Subquery { .. }) => {
                 json!({
                     "Node Type": "Subquery"
@@ -640,6 +667,25 @@ impl<'a, 'b> PgJsonVisitor<'a, 'b> {
                     "StructColumn": expr_vec_fmt!(struct_type_columns),
                 })
             }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                partition_by,
+                order_by,
+                pattern,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                ..
+            }) => {
+                json!({
+                    "Node Type": "MatchRecognize",
+                    "Partition By": expr_vec_fmt!(partition_by),
+                    "Order By": expr_vec_fmt!(order_by),
+                    "Pattern": pattern.to_string(),
+                    "Measures": expr_vec_fmt!(measures),
+                    "Rows Per Match": rows_per_match.to_string(),
+                    "After Match Skip": after_match_skip.to_string(),
+                })
+            }
         }
     }
 }
@@ -117,7 +117,7 @@ impl CopyTo {
 /// Modifies the content of a database
 ///
 /// This operator is used to perform DML operations such as INSERT, DELETE,
-/// UPDATE, and CTAS (CREATE TABLE AS SELECT).
+/// UPDATE, MERGE, and CTAS (CREATE TABLE AS SELECT).
 ///
 /// * `INSERT` - Appends new rows to the existing table. Calls
 ///   [`TableProvider::insert_into`]
@@ -126,6 +126,9 @@ impl CopyTo {
 ///
 /// * `UPDATE` - Modifies existing rows in the table. Calls [`TableProvider::update`]
 ///
+/// * `MERGE` - Inserts, updates and deletes rows of the table based on a
+///   join with a source relation. Calls [`TableProvider::merge_into`]
+///
 /// * `CREATE TABLE AS SELECT` - Creates a new table and populates it with data
 ///   from a query. This is similar to the `INSERT` operation, but it creates a new
 ///   table instead of modifying an existing one.
@@ -136,6 +139,7 @@ impl CopyTo {
 /// [`TableProvider::insert_into`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html#method.insert_into
 /// [`TableProvider::delete_from`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html#method.delete_from
 /// [`TableProvider::update`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html#method.update
+/// [`TableProvider::merge_into`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html#method.merge_into
 #[derive(Clone)]
 pub struct DmlStatement {
     /// The table name
@@ -237,6 +241,15 @@ pub enum WriteOp {
     Update,
     /// `CREATE TABLE AS SELECT` operation
     Ctas,
+    /// `MERGE INTO` operation
+    ///
+    /// The input of the statement is the target table joined with the source
+    /// of the `MERGE`. Its first column, named [`MERGE_ACTION_COLUMN`], holds
+    /// the [`MergeAction`] to apply to each row, encoded as a `UInt8`. The
+    /// second column, named [`MERGE_TARGET_ROW_COLUMN`], identifies the target
+    /// row of each row, and the remaining columns match the schema of the
+    /// target table.
+    Merge,
 }
 
 impl WriteOp {
@@ -247,6 +260,7 @@ impl WriteOp {
             WriteOp::Delete => "Delete",
             WriteOp::Update => "Update",
             WriteOp::Ctas => "Ctas",
+            WriteOp::Merge => "Merge",
         }
     }
 }
@@ -257,6 +271,77 @@ impl Display for WriteOp {
     }
 }
 
+/// Name of the column holding the [`MergeAction`] of each row in the input of
+/// a [`WriteOp::Merge`] statement
+pub const MERGE_ACTION_COLUMN: &str = "__merge_action";
+
+/// Name of the column identifying the target row of each row in the input of
+/// a [`WriteOp::Merge`] statement.
+///
+/// It holds a distinct `UInt64` for every row of the target table, and is
+/// `NULL` for inserted source rows. A target row matched by several source
+/// rows appears several times with the same value. Updating or deleting it
+/// more than once is a cardinality violation that must fail the statement,
+/// while it must only be kept once if none of its rows modify it.
+pub const MERGE_TARGET_ROW_COLUMN: &str = "__merge_target_row";
+
+/// The change a `MERGE INTO` statement applies to a row of its input.
+///
+/// Every row of the target table appears in the input of a [`WriteOp::Merge`]
+/// statement, either unchanged with [`MergeAction::Keep`], or with the action
+/// of the first `WHEN` clause that applies to it. Source rows that are not
+/// matched by any target row only appear if they are inserted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum MergeAction {
+    /// The target row is left unchanged
+    Keep,
+    /// The row is inserted into the target table
+    Insert,
+    /// The target row is replaced by the values of the row
+    Update,
+    /// The target row is removed from the target table
+    Delete,
+}
+
+impl MergeAction {
+    /// Return the value of this action in the [`MERGE_ACTION_COLUMN`]
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            MergeAction::Keep => 0,
+            MergeAction::Insert => 1,
+            MergeAction::Update => 2,
+            MergeAction::Delete => 3,
+        }
+    }
+
+    /// Return the action encoded as `value` in the [`MERGE_ACTION_COLUMN`]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(MergeAction::Keep),
+            1 => Some(MergeAction::Insert),
+            2 => Some(MergeAction::Update),
+            3 => Some(MergeAction::Delete),
+            _ => None,
+        }
+    }
+
+    /// Return a descriptive name of this [`MergeAction`]
+    pub fn name(&self) -> &str {
+        match self {
+            MergeAction::Keep => "Keep",
+            MergeAction::Insert => "Insert",
+            MergeAction::Update => "Update",
+            MergeAction::Delete => "Delete",
+        }
+    }
+}
+
+impl Display for MergeAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
 pub enum InsertOp {
     /// Appends new rows to the existing table without modifying any
@@ -280,6 +280,7 @@ fn check_inner_plan(inner_plan: &LogicalPlan) -> Result<()> {
         | LogicalPlan::TableScan(_)
         | LogicalPlan::EmptyRelation(_)
         | LogicalPlan::Limit(_)
+        | LogicalPlan::Sample(_)
         | LogicalPlan::Values(_)
         | LogicalPlan::Subquery(_)
         | LogicalPlan::SubqueryAlias(_)
@@ -58,8 +58,9 @@ use datafusion_common::tree_node::{
     Transformed, TreeNode, TreeNodeContainer, TreeNodeRecursion,
 };
 use datafusion_common::{
-    Column, Constraints, DFSchema, DFSchemaRef, DataFusionError, Dependency,
-    FunctionalDependence, FunctionalDependencies, NullEquality, ParamValues, Result,
+    AfterMatchSkip, Column, Constraints, DFSchema, DFSchemaRef, DataFusionError,
+    Dependency, FunctionalDependence, FunctionalDependencies, MatchFunction,
+    NullEquality, ParamValues, Result, RowPattern, RowsPerMatch, SampleMethod,
     ScalarValue, Spans, TableReference, UnnestOptions, aggregate_functional_dependencies,
     assert_eq_or_internal_err, assert_or_internal_err, internal_err, plan_err,
 };
@@ -234,6 +235,12 @@ pub enum LogicalPlan {
     /// Join two logical plans on one or more join columns.
     /// This is used to implement SQL `JOIN`
     Join(Join),
+    /// Join each row of the left input with at most one row of the right
+    /// input: the nearest row that satisfies an inequality match condition.
+    /// This is used to implement SQL `ASOF JOIN`.
+    ///
+    /// See [`AsOfJoin`] for more details
+    AsOfJoin(AsOfJoin),
     /// Repartitions the input based on a partitioning scheme. This is
     /// used to add parallelism and is sometimes referred to as an
     /// "exchange" operator in other systems
@@ -256,6 +263,9 @@ pub enum LogicalPlan {
     SubqueryAlias(SubqueryAlias),
     /// Skip some number of rows, and then fetch some number of rows.
     Limit(Limit),
+    /// Returns a random sample of the rows of its input. This is used to
+    /// implement SQL `TABLESAMPLE`.
+    Sample(Sample),
     /// A DataFusion [`Statement`] such as `SET VARIABLE` or `START TRANSACTION`
     Statement(Statement),
     /// Values expression. See
@@ -290,6 +300,11 @@ pub enum LogicalPlan {
     Unnest(Unnest),
     /// A variadic query (e.g. "Recursive CTEs")
     RecursiveQuery(RecursiveQuery),
+    /// Find the matches of a row pattern in the ordered partitions of the
+    /// input. This is used to implement SQL `MATCH_RECOGNIZE`.
+    ///
+    /// See [`MatchRecognize`] for more details
+    MatchRecognize(MatchRecognize),
 }
 
 impl Default for LogicalPlan {
@@ -334,8 +349,10 @@ impl LogicalPlan {
             LogicalPlan::Aggregate(Aggregate { schema, .. }) => schema,
             LogicalPlan::Sort(Sort { input, .. }) => input.schema(),
             LogicalPlan::Join(Join { schema, .. }) => schema,
+            LogicalPlan::AsOfJoin(AsOfJoin { schema, .. }) => schema,
             LogicalPlan::Repartition(Repartition { input, .. }) => input.schema(),
             LogicalPlan::Limit(Limit { input, .. }) => input.schema(),
+            LogicalPlan::Sample(Sample { input, .. }) => input.schema(),
             LogicalPlan::Statement(statement) => statement.schema(),
             LogicalPlan::Subquery(Subquery { subquery, .. }) => subquery.schema(),
             LogicalPlan::SubqueryAlias(SubqueryAlias { schema, .. }) => schema,
@@ -350,6 +367,7 @@ impl LogicalPlan {
             LogicalPlan::Copy(CopyTo { output_schema, .. }) => output_schema,
             LogicalPlan::Ddl(ddl) => ddl.schema(),
             LogicalPlan::Unnest(Unnest { schema, .. }) => schema,
+            LogicalPlan::MatchRecognize(MatchRecognize { schema, .. }) => schema,
             LogicalPlan::RecursiveQuery(RecursiveQuery { static_term, .. }) => {
                 // we take the schema of the static term as the schema of the entire recursive query
                 static_term.schema()
@@ -365,7 +383,9 @@ impl LogicalPlan {
             | LogicalPlan::Projection(_)
             | LogicalPlan::Aggregate(_)
             | LogicalPlan::Unnest(_)
-            | LogicalPlan::Join(_) => self
+            | LogicalPlan::Join(_)
+            | LogicalPlan::AsOfJoin(_)
+            | LogicalPlan::MatchRecognize(_) => self
                 .inputs()
                 .iter()
                 .map(|input| input.schema().as_ref())
@@ -455,7 +475,9 @@ impl LogicalPlan {
             LogicalPlan::Aggregate(Aggregate { input, .. }) => vec![input],
             LogicalPlan::Sort(Sort { input, .. }) => vec![input],
             LogicalPlan::Join(Join { left, right, .. }) => vec![left, right],
+            LogicalPlan::AsOfJoin(AsOfJoin { left, right, .. }) => vec![left, right],
             LogicalPlan::Limit(Limit { input, .. }) => vec![input],
+            LogicalPlan::Sample(Sample { input, .. }) => vec![input],
             LogicalPlan::Subquery(Subquery { subquery, .. }) => vec![subquery],
             LogicalPlan::SubqueryAlias(SubqueryAlias { input, .. }) => vec![input],
             LogicalPlan::Extension(extension) => extension.node.inputs(),
@@ -471,6 +493,7 @@ impl LogicalPlan {
             LogicalPlan::Copy(copy) => vec![&copy.input],
             LogicalPlan::Ddl(ddl) => ddl.inputs(),
             LogicalPlan::Unnest(Unnest { input, .. }) => vec![input],
+            LogicalPlan::MatchRecognize(MatchRecognize { input, .. }) => vec![input],
             LogicalPlan::RecursiveQuery(RecursiveQuery {
                 static_term,
                 recursive_term,
@@ -490,12 +513,20 @@ impl LogicalPlan {
         let mut using_columns: Vec<HashSet<Column>> = vec![];
 
         self.apply_with_subqueries(|plan| {
-            if let LogicalPlan::Join(Join {
-                join_constraint: JoinConstraint::Using,
-                on,
-                ..
-            }) = plan
-            {
+            let on = match plan {
+                LogicalPlan::Join(Join {
+                    join_constraint: JoinConstraint::Using,
+                    on,
+                    ..
+                })
+                | LogicalPlan::AsOfJoin(AsOfJoin {
+                    join_constraint: JoinConstraint::Using,
+                    on,
+                    ..
+                }) => Some(on),
+                _ => None,
+            };
+            if let Some(on) = on {
                 // The join keys in using-join must be columns.
                 let columns =
                     on.iter().try_fold(HashSet::new(), |mut accumu, (l, r)| {
@@ -541,6 +572,7 @@ impl LogicalPlan {
             | LogicalPlan::Distinct(Distinct::All(input))
             | LogicalPlan::Sort(Sort { input, .. })
             | LogicalPlan::Limit(Limit { input, .. })
+            | LogicalPlan::Sample(Sample { input, .. })
             | LogicalPlan::Repartition(Repartition { input, .. })
             | LogicalPlan::Window(Window { input, .. }) => input.head_output_expr(),
             LogicalPlan::Join(Join {
@@ -563,6 +595,13 @@ impl LogicalPlan {
                     right.head_output_expr()
                 }
             },
+            LogicalPlan::AsOfJoin(AsOfJoin { left, right, .. }) => {
+                if left.schema().fields().is_empty() {
+                    right.head_output_expr()
+                } else {
+                    left.head_output_expr()
+                }
+            }
             LogicalPlan::RecursiveQuery(RecursiveQuery { static_term, .. }) => {
                 static_term.head_output_expr()
             }
@@ -572,6 +611,9 @@ impl LogicalPlan {
             LogicalPlan::TableScan(table) => Ok(Some(Expr::Column(Column::from(
                 table.projected_schema.qualified_field(0),
             )))),
+            LogicalPlan::MatchRecognize(match_recognize) => Ok(Some(Expr::Column(
+                Column::from(match_recognize.schema.qualified_field(0)),
+            ))),
             LogicalPlan::SubqueryAlias(subquery_alias) => {
                 let expr_opt = subquery_alias.input.head_output_expr()?;
                 expr_opt
@@ -684,6 +726,25 @@ impl LogicalPlan {
                     null_equality,
                 }))
             }
+            LogicalPlan::AsOfJoin(AsOfJoin {
+                left,
+                right,
+                on,
+                match_on,
+                match_op,
+                join_type,
+                join_constraint,
+                schema: _,
+            }) => AsOfJoin::try_new(
+                left,
+                right,
+                on,
+                match_on,
+                match_op,
+                join_type,
+                join_constraint,
+            )
+            .map(LogicalPlan::AsOfJoin),
             LogicalPlan::Subquery(_) => Ok(self),
             LogicalPlan::SubqueryAlias(SubqueryAlias {
                 input,
@@ -691,6 +752,7 @@ impl LogicalPlan {
                 schema: _,
             }) => SubqueryAlias::try_new(input, alias).map(LogicalPlan::SubqueryAlias),
             LogicalPlan::Limit(_) => Ok(self),
+            LogicalPlan::Sample(_) => Ok(self),
             LogicalPlan::Ddl(_) => Ok(self),
             LogicalPlan::Extension(Extension { node }) => {
                 // todo make an API that does not require cloning
@@ -735,6 +797,29 @@ impl LogicalPlan {
                 };
                 Ok(LogicalPlan::Distinct(distinct))
             }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                partition_by,
+                order_by,
+                symbols,
+                definitions,
+                pattern,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                schema: _,
+            }) => MatchRecognize::try_new(
+                input,
+                partition_by,
+                order_by,
+                symbols,
+                definitions,
+                pattern,
+                measures,
+                rows_per_match,
+                after_match_skip,
+            )
+            .map(LogicalPlan::MatchRecognize),
             LogicalPlan::RecursiveQuery(_) => Ok(self),
             LogicalPlan::Analyze(_) => Ok(self),
             LogicalPlan::Explain(_) => Ok(self),
@@ -872,6 +957,59 @@ impl LogicalPlan {
                 let input = self.only_input(inputs)?;
                 Window::try_new(expr, Arc::new(input)).map(LogicalPlan::Window)
             }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                partition_by,
+                order_by,
+                symbols,
+                definitions,
+                pattern,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                ..
+            }) => {
+                let input = self.only_input(inputs)?;
+                // The partition, order and definition expressions come first,
+                // followed by the arguments of the measures
+                let num_exprs = partition_by.len()
+                    + order_by.len()
+                    + definitions.len()
+                    + measures.iter().filter(|m| m.arg.is_some()).count();
+                assert_eq_or_internal_err!(
+                    expr.len(),
+                    num_exprs,
+                    "Invalid number of new MatchRecognize expressions: expected {}, got {}",
+                    num_exprs,
+                    expr.len()
+                );
+                let mut expr = expr.into_iter();
+                let new_partition_by = expr.by_ref().take(partition_by.len()).collect();
+                let new_order_by = order_by
+                    .iter()
+                    .zip(expr.by_ref())
+                    .map(|(sort, expr)| sort.with_expr(expr))
+                    .collect();
+                let new_definitions = expr.by_ref().take(definitions.len()).collect();
+                let new_measures = measures
+                    .iter()
+                    .map(|measure| MatchMeasure {
+                        arg: measure.arg.as_ref().and_then(|_| expr.next()),
+                        ..measure.clone()
+                    })
+                    .collect();
+                MatchRecognize::try_new(
+                    Arc::new(input),
+                    new_partition_by,
+                    new_order_by,
+                    symbols.clone(),
+                    new_definitions,
+                    pattern.clone(),
+                    new_measures,
+                    *rows_per_match,
+                    after_match_skip.clone(),
+                )
+                .map(LogicalPlan::MatchRecognize)
+            }
             LogicalPlan::Aggregate(Aggregate { group_expr, .. }) => {
                 let input = self.only_input(inputs)?;
                 // group exprs are the first expressions
@@ -944,6 +1082,40 @@ impl LogicalPlan {
                     null_equality: *null_equality,
                 }))
             }
+            LogicalPlan::AsOfJoin(AsOfJoin {
+                on,
+                match_op,
+                join_type,
+                join_constraint,
+                ..
+            }) => {
+                let (left, right) = self.only_two_inputs(inputs)?;
+                // The equijoin pairs come first, followed by the match condition
+                assert_eq_or_internal_err!(
+                    expr.len(),
+                    on.len() * 2 + 2,
+                    "Invalid number of new AsOfJoin expressions: expected {}, got {}",
+                    on.len() * 2 + 2,
+                    expr.len()
+                );
+                let match_right = expr.pop().unwrap().unalias();
+                let match_left = expr.pop().unwrap().unalias();
+                let mut iter = expr.into_iter();
+                let mut new_on = Vec::with_capacity(on.len());
+                while let (Some(l), Some(r)) = (iter.next(), iter.next()) {
+                    new_on.push((l.unalias(), r.unalias()));
+                }
+                AsOfJoin::try_new(
+                    Arc::new(left),
+                    Arc::new(right),
+                    new_on,
+                    (match_left, match_right),
+                    *match_op,
+                    *join_type,
+                    *join_constraint,
+                )
+                .map(LogicalPlan::AsOfJoin)
+            }
             LogicalPlan::Subquery(Subquery {
                 outer_ref_columns,
                 spans,
@@ -983,6 +1155,15 @@ impl LogicalPlan {
                     input: Arc::new(input),
                 }))
             }
+            LogicalPlan::Sample(Sample { method, seed, .. }) => {
+                self.assert_no_expressions(expr)?;
+                let input = self.only_input(inputs)?;
+                Ok(LogicalPlan::Sample(Sample {
+                    method: *method,
+                    seed: *seed,
+                    input: Arc::new(input),
+                }))
+            }
             LogicalPlan::Ddl(DdlStatement::CreateMemoryTable(CreateMemoryTable {
                 name,
                 if_not_exists,
@@ -1356,6 +1537,7 @@ impl LogicalPlan {
                     right.max_rows()
                 }
             },
+            LogicalPlan::AsOfJoin(AsOfJoin { left, .. }) => left.max_rows(),
             LogicalPlan::Repartition(Repartition { input, .. }) => input.max_rows(),
             LogicalPlan::Union(Union { inputs, .. }) => {
                 inputs.iter().try_fold(0usize, |mut acc, plan| {
@@ -1372,11 +1554,26 @@ impl LogicalPlan {
                 Ok(FetchType::Literal(s)) => s,
                 _ => None,
             },
+            LogicalPlan::Sample(Sample { method, input, .. }) => match method {
+                SampleMethod::Rows(rows) => {
+                    Some(input.max_rows().map_or(*rows, |max| max.min(*rows)))
+                }
+                _ => input.max_rows(),
+            },
             LogicalPlan::Distinct(
                 Distinct::All(input) | Distinct::On(DistinctOn { input, .. }),
             ) => input.max_rows(),
             LogicalPlan::Values(v) => Some(v.values.len()),
             LogicalPlan::Unnest(_) => None,
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                rows_per_match,
+                ..
+            }) => match rows_per_match {
+                // Every match starts at a different row
+                RowsPerMatch::OneRow => input.max_rows(),
+                RowsPerMatch::AllRows(_) => None,
+            },
             LogicalPlan::Ddl(_)
             | LogicalPlan::Explain(_)
             | LogicalPlan::Analyze(_)
@@ -1988,6 +2185,25 @@ impl LogicalPlan {
                             }
                         }
                     }
+                    LogicalPlan::AsOfJoin(AsOfJoin {
+                        on: keys,
+                        match_on: (match_left, match_right),
+                        match_op,
+                        join_type,
+                        join_constraint,
+                        ..
+                    }) => {
+                        let join_expr: Vec<String> =
+                            keys.iter().map(|(l, r)| format!("{l} = {r}")).collect();
+                        write!(f, "{join_type} AsOf Join: ")?;
+                        if !join_expr.is_empty() {
+                            if *join_constraint == JoinConstraint::Using {
+                                write!(f, "Using ")?;
+                            }
+                            write!(f, "{}, ", join_expr.join(", "))?;
+                        }
+                        write!(f, "Match: {match_left} {match_op} {match_right}")
+                    }
                     LogicalPlan::Repartition(Repartition {
                         partitioning_scheme,
                         ..
@@ -2034,6 +2250,13 @@ impl LogicalPlan {
                         };
                         write!(f, "Limit: skip={skip_str}, fetch={fetch_str}",)
                     }
+                    LogicalPlan::Sample(Sample { method, seed, .. }) => {
+                        write!(f, "Sample: method={method}")?;
+                        if let Some(seed) = seed {
+                            write!(f, ", seed={seed}")?;
+                        }
+                        Ok(())
+                    }
                     LogicalPlan::Subquery(Subquery { .. }) => {
                         write!(f, "Subquery:")
                     }
@@ -2073,6 +2296,7 @@ impl LogicalPlan {
                         input: plan,
                         list_type_columns: list_col_indices,
                         struct_type_columns: struct_col_indices,
+                        options,
                         ..
                     }) => {
                         let input_columns = plan.schema().columns();
@@ -2096,6 +2320,39 @@ impl LogicalPlan {
                             "Unnest: lists[{}] structs[{}]",
                             expr_vec_fmt!(list_type_columns),
                             expr_vec_fmt!(struct_type_columns)
+                        )?;
+                        if options.ordinality {
+                            write!(f, " with ordinality")?;
+                        }
+                        Ok(())
+                    }
+                    LogicalPlan::MatchRecognize(MatchRecognize {
+                        partition_by,
+                        order_by,
+                        symbols,
+                        definitions,
+                        pattern,
+                        measures,
+                        rows_per_match,
+                        after_match_skip,
+                        ..
+                    }) => {
+                        let definitions = symbols
+                            .iter()
+                            .zip(definitions)
+                            .map(|(symbol, definition)| {
+                                format!("{symbol} AS {definition}")
+                            })
+                            .collect::<Vec<_>>();
+                        write!(
+                            f,
+                            "MatchRecognize: partition_by=[{}], order_by=[{}], \
+                            pattern=[{pattern}], define=[{}], measures=[{}], \
+                            {rows_per_match}, {after_match_skip}",
+                            expr_vec_fmt!(partition_by),
+                            expr_vec_fmt!(order_by),
+                            definitions.join(", "),
+                            expr_vec_fmt!(measures),
                         )
                     }
                 }
@@ -3270,6 +3527,20 @@ pub struct Limit {
     pub input: Arc<LogicalPlan>,
 }
 
+/// Returns a random sample of the rows of its input, see [`SampleMethod`].
+///
+/// Used to implement SQL `TABLESAMPLE`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct Sample {
+    /// How the rows are sampled
+    pub method: SampleMethod,
+    /// The seed of the random number generator (`REPEATABLE (seed)`). If
+    /// `None`, a random seed is chosen when the plan is executed.
+    pub seed: Option<u64>,
+    /// The incoming logical plan
+    pub input: Arc<LogicalPlan>,
+}
+
 /// Different types of skip expression in Limit plan.
 pub enum SkipType {
     /// The skip expression is a literal value.
@@ -3928,6 +4199,334 @@ impl PartialOrd for Join {
     }
 }
 
+/// Join each row of the left input with the nearest row of the right input
+/// that satisfies the match condition, used to implement SQL `ASOF JOIN`.
+///
+/// For example, `left.ts >= right.ts` matches every left row with the right
+/// row with the latest `right.ts` at or before `left.ts`, among the right
+/// rows with equal equijoin keys. Each left row is matched with at most one
+/// right row.
+///
+/// ```text
+/// SELECT * FROM trades t
+/// ASOF JOIN quotes q MATCH_CONDITION (t.ts >= q.ts) ON t.sym = q.sym
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsOfJoin {
+    /// Left input, every row of which is matched at most once
+    pub left: Arc<LogicalPlan>,
+    /// Right input, searched for the nearest match
+    pub right: Arc<LogicalPlan>,
+    /// Equijoin clause expressed as pairs of (left, right) join expressions
+    pub on: Vec<(Expr, Expr)>,
+    /// The (left, right) expressions compared by the match condition
+    pub match_on: (Expr, Expr),
+    /// The match condition operator, one of `>=`, `>`, `<=` or `<`
+    pub match_op: Operator,
+    /// Join type, [`JoinType::Left`] keeps left rows without a match
+    pub join_type: JoinType,
+    /// Join constraint of the equijoin keys
+    pub join_constraint: JoinConstraint,
+    /// The output schema, containing fields from the left and right inputs
+    pub schema: DFSchemaRef,
+}
+
+impl AsOfJoin {
+    /// Creates a new AsOfJoin operator with automatically computed schema.
+    pub fn try_new(
+        left: Arc<LogicalPlan>,
+        right: Arc<LogicalPlan>,
+        on: Vec<(Expr, Expr)>,
+        match_on: (Expr, Expr),
+        match_op: Operator,
+        join_type: JoinType,
+        join_constraint: JoinConstraint,
+    ) -> Result<Self> {
+        if !matches!(join_type, JoinType::Inner | JoinType::Left) {
+            return plan_err!("Unsupported join type for ASOF JOIN: {join_type}");
+        }
+        if !matches!(
+            match_op,
+            Operator::Gt | Operator::GtEq | Operator::Lt | Operator::LtEq
+        ) {
+            return plan_err!(
+                "ASOF JOIN match condition must use one of >=, >, <= or <, got {match_op}"
+            );
+        }
+        let join_schema = build_join_schema(left.schema(), right.schema(), &join_type)?;
+
+        Ok(AsOfJoin {
+            left,
+            right,
+            on,
+            match_on,
+            match_op,
+            join_type,
+            join_constraint,
+            schema: Arc::new(join_schema),
+        })
+    }
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for AsOfJoin {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        #[derive(PartialEq, PartialOrd)]
+        struct ComparableAsOfJoin<'a> {
+            pub left: &'a Arc<LogicalPlan>,
+            pub right: &'a Arc<LogicalPlan>,
+            pub on: &'a Vec<(Expr, Expr)>,
+            pub match_on: &'a (Expr, Expr),
+            pub match_op: &'a Operator,
+            pub join_type: &'a JoinType,
+            pub join_constraint: &'a JoinConstraint,
+        }
+        let comparable_self = ComparableAsOfJoin {
+            left: &self.left,
+            right: &self.right,
+            on: &self.on,
+            match_on: &self.match_on,
+            match_op: &self.match_op,
+            join_type: &self.join_type,
+            join_constraint: &self.join_constraint,
+        };
+        let comparable_other = ComparableAsOfJoin {
+            left: &other.left,
+            right: &other.right,
+            on: &other.on,
+            match_on: &other.match_on,
+            match_op: &other.match_op,
+            join_type: &other.join_type,
+            join_constraint: &other.join_constraint,
+        };
+        comparable_self
+            .partial_cmp(&comparable_other)
+            .filter(|cmp| *cmp != Ordering::Equal || self == other)
+    }
+}
+
+/// Find the matches of a row pattern in the ordered partitions of the input,
+/// used to implement SQL `MATCH_RECOGNIZE`.
+///
+/// Every partition is searched for matches of the [`RowPattern`], in the order
+/// of `order_by`. A row matches a pattern variable (symbol) if it satisfies the
+/// variable's definition. After a match, the search resumes at the row given
+/// by `after_match_skip`.
+///
+/// With [`RowsPerMatch::OneRow`], the output contains the `partition_by`
+/// expressions followed by the `measures` of every match. With
+/// [`RowsPerMatch::AllRows`], it contains the input columns followed by the
+/// `measures` of every row of every match.
+///
+/// ```text
+/// SELECT * FROM ticks MATCH_RECOGNIZE (
+///   PARTITION BY symbol ORDER BY ts
+///   MEASURES FIRST(DOWN.price) AS top, LAST(UP.price) AS bottom
+///   PATTERN (DOWN+ UP+)
+///   DEFINE DOWN AS price < PREV(price), UP AS price > PREV(price)
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchRecognize {
+    /// The input plan
+    pub input: Arc<LogicalPlan>,
+    /// The expressions partitioning the input
+    pub partition_by: Vec<Expr>,
+    /// The order of the rows in a partition
+    pub order_by: Vec<SortExpr>,
+    /// The pattern variables
+    pub symbols: Vec<String>,
+    /// The condition of every pattern variable, which a row must satisfy to
+    /// be mapped to it
+    pub definitions: Vec<Expr>,
+    /// The row pattern to match
+    pub pattern: RowPattern,
+    /// The values computed for every match
+    pub measures: Vec<MatchMeasure>,
+    /// Whether to return a row per match or per matched row
+    pub rows_per_match: RowsPerMatch,
+    /// Where to resume looking for matches after a match
+    pub after_match_skip: AfterMatchSkip,
+    /// The output schema
+    pub schema: DFSchemaRef,
+}
+
+impl MatchRecognize {
+    /// Creates a new MatchRecognize operator with automatically computed
+    /// schema.
+    #[expect(clippy::too_many_arguments)]
+    pub fn try_new(
+        input: Arc<LogicalPlan>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<SortExpr>,
+        symbols: Vec<String>,
+        definitions: Vec<Expr>,
+        pattern: RowPattern,
+        measures: Vec<MatchMeasure>,
+        rows_per_match: RowsPerMatch,
+        after_match_skip: AfterMatchSkip,
+    ) -> Result<Self> {
+        assert_eq_or_internal_err!(
+            symbols.len(),
+            definitions.len(),
+            "MatchRecognize requires a definition for every pattern variable"
+        );
+        let is_symbol = |symbol: &str| symbols.iter().any(|s| s == symbol);
+        if let Some(symbol) = pattern.symbols().into_iter().find(|s| !is_symbol(s)) {
+            return plan_err!("Pattern variable {symbol} is not defined");
+        }
+        if let AfterMatchSkip::ToFirst(symbol) | AfterMatchSkip::ToLast(symbol) =
+            &after_match_skip
+            && !is_symbol(symbol)
+        {
+            return plan_err!(
+                "AFTER MATCH SKIP refers to {symbol}, which is not a pattern variable"
+            );
+        }
+
+        let input_schema = input.schema();
+        let mut fields = match rows_per_match {
+            RowsPerMatch::OneRow => exprlist_to_fields(&partition_by, &input)?,
+            RowsPerMatch::AllRows(_) => input_schema
+                .iter()
+                .map(|(qualifier, field)| (qualifier.cloned(), Arc::clone(field)))
+                .collect(),
+        };
+        for measure in &measures {
+            if let Some(symbol) = &measure.symbol
+                && !is_symbol(symbol)
+            {
+                return plan_err!(
+                    "Measure {measure} refers to {symbol}, which is not a pattern variable"
+                );
+            }
+            let data_type = match (measure.function, &measure.arg) {
+                (MatchFunction::First | MatchFunction::Last, Some(arg)) => {
+                    arg.get_type(input_schema)?
+                }
+                (MatchFunction::Count, None) => DataType::Int64,
+                (MatchFunction::MatchNumber, None) => DataType::UInt64,
+                (MatchFunction::Classifier, None) => DataType::Utf8,
+                _ => return internal_err!("Invalid MatchRecognize measure {measure}"),
+            };
+            // Measures are null for rows that are not part of a match
+            fields.push((None, Arc::new(Field::new(&measure.name, data_type, true))));
+        }
+        let schema =
+            DFSchema::new_with_metadata(fields, input_schema.metadata().clone())?;
+
+        Ok(MatchRecognize {
+            input,
+            partition_by,
+            order_by,
+            symbols,
+            definitions,
+            pattern,
+            measures,
+            rows_per_match,
+            after_match_skip,
+            schema: Arc::new(schema),
+        })
+    }
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for MatchRecognize {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        #[derive(PartialEq, PartialOrd)]
+        struct ComparableMatchRecognize<'a> {
+            pub input: &'a Arc<LogicalPlan>,
+            pub partition_by: &'a Vec<Expr>,
+            pub order_by: &'a Vec<SortExpr>,
+            pub symbols: &'a Vec<String>,
+            pub definitions: &'a Vec<Expr>,
+            pub pattern: &'a RowPattern,
+            pub measures: &'a Vec<MatchMeasure>,
+            pub rows_per_match: &'a RowsPerMatch,
+            pub after_match_skip: &'a AfterMatchSkip,
+        }
+        let comparable_self = ComparableMatchRecognize {
+            input: &self.input,
+            partition_by: &self.partition_by,
+            order_by: &self.order_by,
+            symbols: &self.symbols,
+            definitions: &self.definitions,
+            pattern: &self.pattern,
+            measures: &self.measures,
+            rows_per_match: &self.rows_per_match,
+            after_match_skip: &self.after_match_skip,
+        };
+        let comparable_other = ComparableMatchRecognize {
+            input: &other.input,
+            partition_by: &other.partition_by,
+            order_by: &other.order_by,
+            symbols: &other.symbols,
+            definitions: &other.definitions,
+            pattern: &other.pattern,
+            measures: &other.measures,
+            rows_per_match: &other.rows_per_match,
+            after_match_skip: &other.after_match_skip,
+        };
+        comparable_self
+            .partial_cmp(&comparable_other)
+            .filter(|cmp| *cmp != Ordering::Equal || self == other)
+    }
+}
+
+/// A value computed for every match of [`MatchRecognize`], such as the value
+/// of `arg` at the first row of the match mapped to `symbol`. See
+/// [`MatchFunction`] for the supported functions.
+///
+/// The `MEASURES` of SQL `MATCH_RECOGNIZE` are expressions of these values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct MatchMeasure {
+    /// How the value is computed from the rows of the match
+    pub function: MatchFunction,
+    /// If set, only the rows mapped to this pattern variable are considered
+    pub symbol: Option<String>,
+    /// The argument of [`MatchFunction::First`] and [`MatchFunction::Last`],
+    /// evaluated on the input rows
+    pub arg: Option<Expr>,
+    /// The name of the output column
+    pub name: String,
+}
+
+impl Display for MatchMeasure {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let prefix = match &self.symbol {
+            Some(symbol) => format!("{symbol}."),
+            None => String::new(),
+        };
+        match (self.function, &self.arg) {
+            (_, Some(arg @ Expr::Column(_))) => {
+                write!(f, "{}({prefix}{arg})", self.function)?
+            }
+            (_, Some(arg)) => write!(f, "{}({prefix}({arg}))", self.function)?,
+            (MatchFunction::Count, None) => write!(f, "COUNT({prefix}*)")?,
+            (_, None) => write!(f, "{}()", self.function)?,
+        }
+        write!(f, " AS {}", self.name)
+    }
+}
+
+impl<'a> TreeNodeContainer<'a, Expr> for MatchMeasure {
+    fn apply_elements<F: FnMut(&'a Expr) -> Result<TreeNodeRecursion>>(
+        &'a self,
+        f: F,
+    ) -> Result<TreeNodeRecursion> {
+        self.arg.apply_elements(f)
+    }
+
+    fn map_elements<F: FnMut(Expr) -> Result<Transformed<Expr>>>(
+        self,
+        f: F,
+    ) -> Result<Transformed<Self>> {
+        self.arg
+            .map_elements(f)?
+            .map_data(|arg| Ok(Self { arg, ..self }))
+    }
+}
+
 /// Subquery
 #[derive(Clone, PartialEq, Eq, PartialOrd, Hash)]
 pub struct Subquery {
@@ -4131,7 +4730,7 @@ impl Unnest {
         // 4.unnest_col2_depth_1: int
         // Meaning the placeholder column will be replaced by its unnested variation(s), note
         // the plural.
-        let fields = input_schema
+        let mut fields = input_schema
             .iter()
             .enumerate()
             .map(|(index, (original_qualifier, original_field))| {
@@ -4214,6 +4813,14 @@ impl Unnest {
             .into_iter()
             .flatten()
             .collect::<Vec<_>>();
+        if options.ordinality {
+            // Named like in PostgreSQL. The ordinality column does not depend on
+            // any input column, and is therefore not part of `dependency_indices`
+            fields.push((
+                None,
+                Arc::new(Field::new("ordinality", DataType::Int64, false)),
+            ));
+        }
 
         let metadata = input_schema.metadata().clone();
         let df_schema = DFSchema::new_with_metadata(fields, metadata)?;
@@ -4231,6 +4838,14 @@ impl Unnest {
             options,
         })
     }
+
+    /// Returns the column holding the position of every unnested row, if
+    /// [`UnnestOptions::ordinality`] is set
+    pub fn ordinality_column(&self) -> Option<Column> {
+        self.options.ordinality.then(|| {
+            Column::from(self.schema.qualified_field(self.schema.fields().len() - 1))
+        })
+    }
 }
 
 // Based on data type, either struct or a variant of list
@@ -38,11 +38,11 @@
 //! * [`LogicalPlan::expressions`]: Return a copy of the plan's expressions
 
 use crate::{
-    Aggregate, Analyze, CreateMemoryTable, CreateView, DdlStatement, Distinct,
-    DistinctOn, DmlStatement, Execute, Explain, Expr, Extension, Filter, Join, Limit,
-    LogicalPlan, Partitioning, Prepare, Projection, RecursiveQuery, Repartition, Sort,
-    Statement, Subquery, SubqueryAlias, TableScan, Union, Unnest, UserDefinedLogicalNode,
-    Values, Window, dml::CopyTo,
+    Aggregate, Analyze, AsOfJoin, CreateMaterializedView, CreateMemoryTable, CreateView,
+    DdlStatement, Distinct, DistinctOn, DmlStatement, Execute, Explain, Expr, Extension,
+    Filter, Join, Limit, LogicalPlan, MatchRecognize, Partitioning, Prepare, Projection,
+    RecursiveQuery, Repartition, Sample, Sort, Statement, Subquery, SubqueryAlias,
+    TableScan, Union, Unnest, UserDefinedLogicalNode, Values, Window, dml::CopyTo,
 };
 use datafusion_common::tree_node::TreeNodeRefContainer;
 
@@ -145,9 +145,41 @@ impl TreeNode for LogicalPlan {
                     null_equality,
                 })
             }),
+            LogicalPlan::AsOfJoin(AsOfJoin {
+                left,
+                right,
+                on,
+                match_on,
+                match_op,
+                join_type,
+                join_constraint,
+                schema,
+            }) => (left, right).map_elements(f)?.update_data(|(left, right)| {
+                LogicalPlan::AsOfJoin(AsOfJoin {
+                    left,
+                    right,
+                    on,
+                    match_on,
+                    match_op,
+                    join_type,
+                    join_constraint,
+                    schema,
+                })
+            }),
             LogicalPlan::Limit(Limit { skip, fetch, input }) => input
                 .map_elements(f)?
                 .update_data(|input| LogicalPlan::Limit(Limit { skip, fetch, input })),
+            LogicalPlan::Sample(Sample {
+                method,
+                seed,
+                input,
+            }) => input.map_elements(f)?.update_data(|input| {
+                LogicalPlan::Sample(Sample {
+                    method,
+                    seed,
+                    input,
+                })
+            }),
             LogicalPlan::Subquery(Subquery {
                 subquery,
                 outer_ref_columns,
@@ -290,6 +322,23 @@ impl TreeNode for LogicalPlan {
                             temporary,
                         })
                     }),
+                    DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                        name,
+                        input,
+                        location,
+                        if_not_exists,
+                        or_replace,
+                        definition,
+                    }) => input.map_elements(f)?.update_data(|input| {
+                        DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                            name,
+                            input,
+                            location,
+                            if_not_exists,
+                            or_replace,
+                            definition,
+                        })
+                    }),
                     // no inputs in these statements
                     DdlStatement::CreateExternalTable(_)
                     | DdlStatement::CreateCatalogSchema(_)
@@ -299,7 +348,10 @@ impl TreeNode for LogicalPlan {
                     | DdlStatement::DropView(_)
                     | DdlStatement::DropCatalogSchema(_)
                     | DdlStatement::CreateFunction(_)
-                    | DdlStatement::DropFunction(_) => Transformed::no(ddl),
+                    | DdlStatement::DropFunction(_)
+                    | DdlStatement::AlterTable(_)
+                    | DdlStatement::TruncateTable(_)
+                    | DdlStatement::RefreshMaterializedView(_) => Transformed::no(ddl),
                 }
                 .update_data(LogicalPlan::Ddl)
             }
@@ -322,6 +374,31 @@ impl TreeNode for LogicalPlan {
                     options,
                 })
             }),
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                partition_by,
+                order_by,
+                symbols,
+                definitions,
+                pattern,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                schema,
+            }) => input.map_elements(f)?.update_data(|input| {
+                LogicalPlan::MatchRecognize(MatchRecognize {
+                    input,
+                    partition_by,
+                    order_by,
+                    symbols,
+                    definitions,
+                    pattern,
+                    measures,
+                    rows_per_match,
+                    after_match_skip,
+                    schema,
+                })
+            }),
             LogicalPlan::RecursiveQuery(RecursiveQuery {
                 name,
                 static_term,
@@ -428,6 +505,10 @@ impl LogicalPlan {
             LogicalPlan::Join(Join { on, filter, .. }) => {
                 (on, filter).apply_ref_elements(f)
             }
+            // The equijoin expressions are followed by the match condition
+            LogicalPlan::AsOfJoin(AsOfJoin { on, match_on, .. }) => {
+                (on, match_on).apply_ref_elements(f)
+            }
             LogicalPlan::Sort(Sort { expr, .. }) => expr.apply_elements(f),
             LogicalPlan::Extension(extension) => {
                 // would be nice to avoid this copy -- maybe can
@@ -452,6 +533,13 @@ impl LogicalPlan {
                 sort_expr,
                 ..
             })) => (on_expr, select_expr, sort_expr).apply_ref_elements(f),
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                partition_by,
+                order_by,
+                definitions,
+                measures,
+                ..
+            }) => (partition_by, order_by, definitions, measures).apply_ref_elements(f),
             LogicalPlan::Limit(Limit { skip, fetch, .. }) => {
                 (skip, fetch).apply_ref_elements(f)
             }
@@ -470,6 +558,7 @@ impl LogicalPlan {
             | LogicalPlan::Explain(_)
             | LogicalPlan::Union(_)
             | LogicalPlan::Distinct(Distinct::All(_))
+            | LogicalPlan::Sample(_)
             | LogicalPlan::Dml(_)
             | LogicalPlan::Ddl(_)
             | LogicalPlan::Copy(_)
@@ -576,6 +665,29 @@ impl LogicalPlan {
                     null_equality,
                 })
             }),
+            LogicalPlan::AsOfJoin(AsOfJoin {
+                left,
+                right,
+                on,
+                match_on,
+                match_op,
+                join_type,
+                join_constraint,
+                schema,
+            }) => (on, match_on)
+                .map_elements(f)?
+                .update_data(|(on, match_on)| {
+                    LogicalPlan::AsOfJoin(AsOfJoin {
+                        left,
+                        right,
+                        on,
+                        match_on,
+                        match_op,
+                        join_type,
+                        join_constraint,
+                        schema,
+                    })
+                }),
             LogicalPlan::Sort(Sort { expr, input, fetch }) => expr
                 .map_elements(f)?
                 .update_data(|expr| LogicalPlan::Sort(Sort { expr, input, fetch })),
@@ -631,6 +743,33 @@ impl LogicalPlan {
                     LogicalPlan::Limit(Limit { skip, fetch, input })
                 })
             }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                partition_by,
+                order_by,
+                symbols,
+                definitions,
+                pattern,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                schema,
+            }) => (partition_by, order_by, definitions, measures)
+                .map_elements(f)?
+                .update_data(|(partition_by, order_by, definitions, measures)| {
+                    LogicalPlan::MatchRecognize(MatchRecognize {
+                        input,
+                        partition_by,
+                        order_by,
+                        symbols,
+                        definitions,
+                        pattern,
+                        measures,
+                        rows_per_match,
+                        after_match_skip,
+                        schema,
+                    })
+                }),
             LogicalPlan::Statement(stmt) => match stmt {
                 Statement::Execute(e) => {
                     e.parameters.map_elements(f)?.update_data(|parameters| {
@@ -650,6 +789,7 @@ impl LogicalPlan {
             | LogicalPlan::Explain(_)
             | LogicalPlan::Union(_)
             | LogicalPlan::Distinct(Distinct::All(_))
+            | LogicalPlan::Sample(_)
             | LogicalPlan::Dml(_)
             | LogicalPlan::Ddl(_)
             | LogicalPlan::Copy(_)
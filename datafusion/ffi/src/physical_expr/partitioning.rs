@@ -19,10 +19,11 @@ use std::sync::Arc;
 
 use abi_stable::StableAbi;
 use abi_stable::std_types::RVec;
-use datafusion_physical_expr::Partitioning;
+use datafusion_physical_expr::{LexOrdering, Partitioning, PhysicalSortExpr};
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
 
 use crate::physical_expr::FFI_PhysicalExpr;
+use crate::physical_expr::sort::FFI_PhysicalSortExpr;
 
 /// A stable struct for sharing [`Partitioning`] across FFI boundaries.
 /// See ['Partitioning'] for the meaning of each variant.
@@ -31,6 +32,7 @@ use crate::physical_expr::FFI_PhysicalExpr;
 pub enum FFI_Partitioning {
     RoundRobinBatch(usize),
     Hash(RVec<FFI_PhysicalExpr>, usize),
+    Range(RVec<FFI_PhysicalSortExpr>, usize),
     UnknownPartitioning(usize),
 }
 
@@ -46,6 +48,10 @@ impl From<&Partitioning> for FFI_Partitioning {
                     .collect();
                 Self::Hash(exprs, *size)
             }
+            Partitioning::Range(ordering, size) => {
+                let ordering = ordering.iter().map(FFI_PhysicalSortExpr::from).collect();
+                Self::Range(ordering, *size)
+            }
             Partitioning::UnknownPartitioning(size) => Self::UnknownPartitioning(*size),
         }
     }
@@ -61,6 +67,13 @@ impl From<&FFI_Partitioning> for Partitioning {
                 let exprs = exprs.iter().map(<Arc<dyn PhysicalExpr>>::from).collect();
                 Self::Hash(exprs, *size)
             }
+            FFI_Partitioning::Range(ordering, size) => {
+                let ordering = ordering.iter().map(PhysicalSortExpr::from);
+                match LexOrdering::new(ordering) {
+                    Some(ordering) => Self::Range(ordering, *size),
+                    None => Self::UnknownPartitioning(*size),
+                }
+            }
             FFI_Partitioning::UnknownPartitioning(size) => {
                 Self::UnknownPartitioning(*size)
             }
@@ -70,8 +83,9 @@ impl From<&FFI_Partitioning> for Partitioning {
 
 #[cfg(test)]
 mod tests {
-    use datafusion_physical_expr::Partitioning;
-    use datafusion_physical_expr::expressions::lit;
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion_physical_expr::expressions::{col, lit};
+    use datafusion_physical_expr::{LexOrdering, Partitioning, PhysicalSortExpr};
 
     use crate::physical_expr::partitioning::FFI_Partitioning;
 
@@ -80,6 +94,16 @@ mod tests {
         for partitioning in [
             Partitioning::RoundRobinBatch(10),
             Partitioning::Hash(vec![lit(1)], 10),
+            Partitioning::Range(
+                LexOrdering::from([PhysicalSortExpr::new_default(
+                    col(
+                        "a",
+                        &Schema::new(vec![Field::new("a", DataType::Int32, false)]),
+                    )
+                    .unwrap(),
+                )]),
+                10,
+            ),
             Partitioning::UnknownPartitioning(10),
         ] {
             let ffi_partitioning: FFI_Partitioning = (&partitioning).into();
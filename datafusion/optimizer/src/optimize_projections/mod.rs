@@ -30,8 +30,8 @@ use datafusion_common::{
 };
 use datafusion_expr::expr::Alias;
 use datafusion_expr::{
-    Aggregate, Distinct, EmptyRelation, Expr, Projection, TableScan, Unnest, Window,
-    logical_plan::LogicalPlan,
+    Aggregate, AsOfJoin, Distinct, EmptyRelation, Expr, Join, Projection, TableScan,
+    Unnest, Window, logical_plan::LogicalPlan,
 };
 
 use crate::optimize_projections::required_indices::RequiredIndices;
@@ -305,7 +305,7 @@ fn optimize_projections(
                 })
                 .collect::<Result<_>>()?
         }
-        LogicalPlan::Limit(_) => {
+        LogicalPlan::Limit(_) | LogicalPlan::Sample(_) => {
             // Pass index requirements from the parent as well as column indices
             // that appear in this plan's expressions to its child. These operators
             // do not benefit from "small" inputs, so the projection_beneficial
@@ -322,6 +322,7 @@ fn optimize_projections(
         | LogicalPlan::Analyze(_)
         | LogicalPlan::Subquery(_)
         | LogicalPlan::Statement(_)
+        | LogicalPlan::MatchRecognize(_)
         | LogicalPlan::Distinct(Distinct::All(_)) => {
             // These plans require all their fields, and their children should
             // be treated as final plans -- otherwise, we may have schema a
@@ -388,14 +389,24 @@ fn optimize_projections(
                 })
                 .collect::<Result<Vec<_>>>()?
         }
-        LogicalPlan::Join(join) => {
-            let left_len = join.left.schema().fields().len();
+        LogicalPlan::Join(Join {
+            left,
+            right,
+            join_type,
+            ..
+        })
+        | LogicalPlan::AsOfJoin(AsOfJoin {
+            left,
+            right,
+            join_type,
+            ..
+        }) => {
+            let left_len = left.schema().fields().len();
             let (left_req_indices, right_req_indices) =
-                split_join_requirements(left_len, indices, &join.join_type);
-            let left_indices =
-                left_req_indices.with_plan_exprs(&plan, join.left.schema())?;
+                split_join_requirements(left_len, indices, join_type);
+            let left_indices = left_req_indices.with_plan_exprs(&plan, left.schema())?;
             let right_indices =
-                right_req_indices.with_plan_exprs(&plan, join.right.schema())?;
+                right_req_indices.with_plan_exprs(&plan, right.schema())?;
             // Joins benefit from "small" input tables (lower memory usage).
             // Therefore, each child benefits from projection:
             vec![
@@ -65,7 +65,9 @@ impl OptimizerRule for PropagateEmptyRelation {
             | LogicalPlan::Sort(_)
             | LogicalPlan::SubqueryAlias(_)
             | LogicalPlan::Repartition(_)
-            | LogicalPlan::Limit(_) => {
+            | LogicalPlan::Limit(_)
+            | LogicalPlan::Sample(_)
+            | LogicalPlan::MatchRecognize(_) => {
                 let empty = empty_child(&plan)?;
                 if let Some(empty_plan) = empty {
                     return Ok(Transformed::yes(empty_plan));
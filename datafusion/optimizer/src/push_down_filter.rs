@@ -891,6 +891,8 @@ impl OptimizerRule for PushDownFilter {
                     }
                 }
 
+                let ordinality_column = unnest.ordinality_column();
+
                 for predicate in predicates {
                     // collect all the Expr::Column in predicate recursively
                     let mut accum: HashSet<Column> = HashSet::new();
@@ -902,8 +904,14 @@ impl OptimizerRule for PushDownFilter {
                         });
                     let contains_struct_columns =
                         unnest_struct_columns.iter().any(|c| accum.contains(c));
-
-                    if contains_list_columns || contains_struct_columns {
+                    let contains_ordinality_column = ordinality_column
+                        .as_ref()
+                        .is_some_and(|c| accum.contains(c));
+
+                    if contains_list_columns
+                        || contains_struct_columns
+                        || contains_ordinality_column
+                    {
                         unnest_predicates.push(predicate);
                     } else {
                         non_unnest_predicates.push(predicate);
@@ -0,0 +1,277 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Resolution of fields by the field IDs stored in their metadata, used by
+//! [`FieldIdPhysicalExprAdapterFactory`](crate::FieldIdPhysicalExprAdapterFactory).
+
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields, Schema};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{Result, ScalarValue, exec_err, internal_err};
+use datafusion_expr::ColumnarValue;
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+
+/// Returns the field ID stored under `field_id_key` in the metadata of `field`
+pub(crate) fn field_id(field: &Field, field_id_key: &str) -> Option<i64> {
+    field.metadata().get(field_id_key)?.trim().parse().ok()
+}
+
+/// Returns the index of the field of `fields` that `field` resolves to.
+///
+/// Fields are matched by field ID if `field_id_key` is set, `field` has a
+/// field ID and at least one of `fields` has a field ID. Otherwise, for
+/// example for files written without field IDs, they are matched by name.
+///
+/// This is the lookup used by [`FieldIdPhysicalExprAdapterFactory`], exposed
+/// so that file statistics can be resolved the same way.
+///
+/// [`FieldIdPhysicalExprAdapterFactory`]: crate::FieldIdPhysicalExprAdapterFactory
+pub fn find_field(
+    fields: &Fields,
+    field: &Field,
+    field_id_key: Option<&str>,
+) -> Option<usize> {
+    if let Some(key) = field_id_key
+        && let Some(id) = field_id(field, key)
+        && fields.iter().any(|f| field_id(f, key).is_some())
+    {
+        return fields.iter().position(|f| field_id(f, key) == Some(id));
+    }
+    fields.iter().position(|f| f.name() == field.name())
+}
+
+/// How the fields of a struct are read from the fields of a physical struct
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct StructMapping {
+    /// For each output field, the index of the physical field it is read
+    /// from, and how to read its own fields if it is a struct
+    children: Vec<(usize, Option<StructMapping>)>,
+}
+
+/// Resolves the (possibly nested) struct fields of `physical_field` to the
+/// fields of `logical_field` by field ID.
+///
+/// Returns `physical_field` with its struct fields renamed to, and ordered as,
+/// the logical fields they resolve to, along with the mapping to apply to the
+/// physical arrays, or `None` if `physical_field` can be used as is.
+///
+/// Physical struct fields that do not resolve to any logical field are
+/// removed, so that a dropped field is not mistaken for a later added field
+/// of the same name.
+///
+/// Only structs nested directly in structs are resolved: the fields of structs
+/// nested in lists or maps are left to be matched by name.
+pub(crate) fn resolve_struct_fields(
+    physical_field: &Field,
+    logical_field: &Field,
+    field_id_key: &str,
+) -> Option<(Field, StructMapping)> {
+    let (DataType::Struct(physical_fields), DataType::Struct(logical_fields)) =
+        (physical_field.data_type(), logical_field.data_type())
+    else {
+        return None;
+    };
+
+    let mut fields = Vec::with_capacity(logical_fields.len());
+    let mut children = Vec::with_capacity(logical_fields.len());
+    for logical_child in logical_fields {
+        let Some(idx) = find_field(physical_fields, logical_child, Some(field_id_key))
+        else {
+            continue;
+        };
+        let physical_child = &physical_fields[idx];
+        let (child, mapping) =
+            match resolve_struct_fields(physical_child, logical_child, field_id_key) {
+                Some((child, mapping)) => (child, Some(mapping)),
+                None => (physical_child.as_ref().clone(), None),
+            };
+        fields.push(Arc::new(child.with_name(logical_child.name())));
+        children.push((idx, mapping));
+    }
+
+    let fields = Fields::from(fields);
+    if &fields == physical_fields {
+        return None;
+    }
+    let field = physical_field
+        .clone()
+        .with_data_type(DataType::Struct(fields));
+    Some((field, StructMapping { children }))
+}
+
+/// Applies `mapping` to `array`, producing an array of type `target_type`
+fn map_struct_array(
+    array: &ArrayRef,
+    target_type: &DataType,
+    mapping: &StructMapping,
+) -> Result<ArrayRef> {
+    let (Some(array), DataType::Struct(target_fields)) =
+        (array.as_any().downcast_ref::<StructArray>(), target_type)
+    else {
+        return exec_err!(
+            "Cannot resolve struct fields of {} as {target_type}",
+            array.data_type()
+        );
+    };
+
+    let columns = mapping
+        .children
+        .iter()
+        .zip(target_fields.iter())
+        .map(|((idx, child_mapping), target_field)| {
+            let column = array.column(*idx);
+            match child_mapping {
+                Some(child_mapping) => {
+                    map_struct_array(column, target_field.data_type(), child_mapping)
+                }
+                None => Ok(Arc::clone(column)),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Arc::new(StructArray::try_new_with_length(
+        target_fields.clone(),
+        columns,
+        array.nulls().cloned(),
+        array.len(),
+    )?))
+}
+
+/// A physical expression that renames, reorders and removes the fields of a
+/// struct column as resolved by [`resolve_struct_fields`].
+///
+/// The child arrays are not copied or cast: the output can then be cast to the
+/// logical type, matching fields by name.
+#[derive(Debug, Clone, Eq)]
+pub(crate) struct ResolveStructFieldsExpr {
+    /// The physical expression producing the physical struct
+    expr: Arc<dyn PhysicalExpr>,
+    /// The physical field with its struct fields resolved
+    target_field: FieldRef,
+    mapping: StructMapping,
+}
+
+// Manually derive PartialEq and Hash to work around https://github.com/rust-lang/rust/issues/78808
+impl PartialEq for ResolveStructFieldsExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.expr.eq(&other.expr)
+            && self.target_field.eq(&other.target_field)
+            && self.mapping.eq(&other.mapping)
+    }
+}
+
+impl Hash for ResolveStructFieldsExpr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.expr.hash(state);
+        self.target_field.hash(state);
+        self.mapping.hash(state);
+    }
+}
+
+impl ResolveStructFieldsExpr {
+    pub(crate) fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        target_field: FieldRef,
+        mapping: StructMapping,
+    ) -> Self {
+        Self {
+            expr,
+            target_field,
+            mapping,
+        }
+    }
+}
+
+impl Display for ResolveStructFieldsExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RESOLVE_STRUCT_FIELDS({} AS {:?})",
+            self.expr,
+            self.target_field.data_type()
+        )
+    }
+}
+
+impl PhysicalExpr for ResolveStructFieldsExpr {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+        Ok(self.target_field.data_type().clone())
+    }
+
+    fn nullable(&self, _input_schema: &Schema) -> Result<bool> {
+        Ok(self.target_field.is_nullable())
+    }
+
+    fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
+        let target_type = self.target_field.data_type();
+        match self.expr.evaluate(batch)? {
+            ColumnarValue::Array(array) => Ok(ColumnarValue::Array(map_struct_array(
+                &array,
+                target_type,
+                &self.mapping,
+            )?)),
+            ColumnarValue::Scalar(scalar) => {
+                let array = map_struct_array(
+                    &scalar.to_array_of_size(1)?,
+                    target_type,
+                    &self.mapping,
+                )?;
+                Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                    array.as_ref(),
+                    0,
+                )?))
+            }
+        }
+    }
+
+    fn return_field(&self, _input_schema: &Schema) -> Result<FieldRef> {
+        Ok(Arc::clone(&self.target_field))
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn PhysicalExpr>> {
+        vec![&self.expr]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        let Ok([child]) = <[_; 1]>::try_from(children) else {
+            return internal_err!(
+                "ResolveStructFieldsExpr should have exactly one child"
+            );
+        };
+        Ok(Arc::new(Self::new(
+            child,
+            Arc::clone(&self.target_field),
+            self.mapping.clone(),
+        )))
+    }
+
+    fn fmt_sql(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
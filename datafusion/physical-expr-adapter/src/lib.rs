@@ -26,10 +26,12 @@
 
 //! Physical expression schema adaptation utilities for DataFusion
 
+mod field_id;
 pub mod schema_rewriter;
 
+pub use field_id::find_field;
 pub use schema_rewriter::{
     BatchAdapter, BatchAdapterFactory, DefaultPhysicalExprAdapter,
-    DefaultPhysicalExprAdapterFactory, PhysicalExprAdapter, PhysicalExprAdapterFactory,
-    replace_columns_with_literals,
+    DefaultPhysicalExprAdapterFactory, FieldIdPhysicalExprAdapterFactory,
+    PhysicalExprAdapter, PhysicalExprAdapterFactory, replace_columns_with_literals,
 };
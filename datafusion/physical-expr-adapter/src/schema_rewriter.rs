@@ -43,6 +43,8 @@ use datafusion_physical_expr::{
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
 use itertools::Itertools;
 
+use crate::field_id::{ResolveStructFieldsExpr, find_field, resolve_struct_fields};
+
 /// Replace column references in the given physical expression with literal values.
 ///
 /// Some use cases for this include:
@@ -193,10 +195,54 @@ impl PhysicalExprAdapterFactory for DefaultPhysicalExprAdapterFactory {
         Arc::new(DefaultPhysicalExprAdapter {
             logical_file_schema,
             physical_file_schema,
+            field_id_key: None,
         })
     }
 }
 
+/// Creates [`DefaultPhysicalExprAdapter`]s that match the fields of the
+/// logical and physical file schemas by field ID.
+///
+/// Writers that support schema evolution, such as Iceberg, store a stable
+/// integer ID in the metadata of every field (for Parquet files, under the
+/// `PARQUET:field_id` key). Matching by field ID rather than by name resolves
+/// renamed and reordered columns and struct fields to their data, and does not
+/// resolve a dropped then re-added column to the data of the dropped one.
+///
+/// See [`DefaultPhysicalExprAdapter::with_field_id_key`] for details.
+#[derive(Debug, Clone)]
+pub struct FieldIdPhysicalExprAdapterFactory {
+    field_id_key: String,
+}
+
+impl FieldIdPhysicalExprAdapterFactory {
+    /// Create a new factory reading field IDs from the field metadata entries
+    /// with key `field_id_key`.
+    pub fn new(field_id_key: impl Into<String>) -> Self {
+        Self {
+            field_id_key: field_id_key.into(),
+        }
+    }
+
+    /// The key of the field metadata entries holding field IDs
+    pub fn field_id_key(&self) -> &str {
+        &self.field_id_key
+    }
+}
+
+impl PhysicalExprAdapterFactory for FieldIdPhysicalExprAdapterFactory {
+    fn create(
+        &self,
+        logical_file_schema: SchemaRef,
+        physical_file_schema: SchemaRef,
+    ) -> Arc<dyn PhysicalExprAdapter> {
+        Arc::new(
+            DefaultPhysicalExprAdapter::new(logical_file_schema, physical_file_schema)
+                .with_field_id_key(&self.field_id_key),
+        )
+    }
+}
+
 /// Default implementation of [`PhysicalExprAdapter`] for rewriting physical
 /// expressions to match different schemas.
 ///
@@ -219,6 +265,9 @@ impl PhysicalExprAdapterFactory for DefaultPhysicalExprAdapterFactory {
 /// - **Default column values**: Partition column references can be replaced with their literal values
 ///   when scanning specific partitions. See [`replace_columns_with_literals`] for more details.
 ///
+/// - **Field IDs**: Columns and struct fields are matched by name, or by the field IDs stored in
+///   their metadata when enabled with [`Self::with_field_id_key`].
+///
 /// # Example
 ///
 /// ```rust
@@ -241,6 +290,7 @@ impl PhysicalExprAdapterFactory for DefaultPhysicalExprAdapterFactory {
 pub struct DefaultPhysicalExprAdapter {
     logical_file_schema: SchemaRef,
     physical_file_schema: SchemaRef,
+    field_id_key: Option<String>,
 }
 
 impl DefaultPhysicalExprAdapter {
@@ -252,8 +302,23 @@ impl DefaultPhysicalExprAdapter {
         Self {
             logical_file_schema,
             physical_file_schema,
+            field_id_key: None,
         }
     }
+
+    /// Match fields by the field IDs stored in the field metadata entries with
+    /// key `field_id_key`, rather than by name.
+    ///
+    /// A logical field is resolved to the physical field with the same field
+    /// ID, and is treated as missing if there is none, even if a physical field
+    /// has the same name. Fields without a field ID, and fields whose physical
+    /// siblings have no field IDs (for example in files written without them),
+    /// are matched by name. Nested struct fields are resolved the same way,
+    /// but the fields of structs nested in lists or maps are matched by name.
+    pub fn with_field_id_key(mut self, field_id_key: impl Into<String>) -> Self {
+        self.field_id_key = Some(field_id_key.into());
+        self
+    }
 }
 
 impl PhysicalExprAdapter for DefaultPhysicalExprAdapter {
@@ -261,6 +326,7 @@ impl PhysicalExprAdapter for DefaultPhysicalExprAdapter {
         let rewriter = DefaultPhysicalExprAdapterRewriter {
             logical_file_schema: &self.logical_file_schema,
             physical_file_schema: &self.physical_file_schema,
+            field_id_key: self.field_id_key.as_deref(),
         };
         expr.transform(|expr| rewriter.rewrite_expr(Arc::clone(&expr)))
             .data()
@@ -270,6 +336,7 @@ impl PhysicalExprAdapter for DefaultPhysicalExprAdapter {
 struct DefaultPhysicalExprAdapterRewriter<'a> {
     logical_file_schema: &'a Schema,
     physical_file_schema: &'a Schema,
+    field_id_key: Option<&'a str>,
 }
 
 impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
@@ -291,10 +358,17 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
     /// Attempt to rewrite struct field access expressions to return null if the field does not exist in the physical schema.
     /// Note that this does *not* handle nested struct fields, only top-level struct field access.
     /// See <https://github.com/apache/datafusion/issues/17114> for more details.
+    ///
+    /// When matching by field ID, struct fields are resolved by [`Self::rewrite_column`] instead,
+    /// as a field missing by name may be present under another name.
     fn try_rewrite_struct_field_access(
         &self,
         expr: &Arc<dyn PhysicalExpr>,
     ) -> Result<Option<Arc<dyn PhysicalExpr>>> {
+        if self.field_id_key.is_some() {
+            return Ok(None);
+        }
+
         let get_field_expr =
             match ScalarFunctionExpr::try_downcast_func::<GetFieldFunc>(expr.as_ref()) {
                 Some(expr) => expr,
@@ -400,12 +474,13 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
         };
 
         // Check if the column exists in the physical schema
-        let physical_column_index = match self
-            .physical_file_schema
-            .index_of(column.name())
-        {
-            Ok(index) => index,
-            Err(_) => {
+        let physical_column_index = match find_field(
+            self.physical_file_schema.fields(),
+            logical_field,
+            self.field_id_key,
+        ) {
+            Some(index) => index,
+            None => {
                 if !logical_field.is_nullable() {
                     return exec_err!(
                         "Non-nullable column '{}' is missing from the physical schema",
@@ -421,16 +496,15 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
         let physical_field = self.physical_file_schema.field(physical_column_index);
 
         let column = match (
-            column.index() == physical_column_index,
+            column.index() == physical_column_index
+                && column.name() == physical_field.name(),
             logical_field.data_type() == physical_field.data_type(),
         ) {
             // If the column index matches and the data types match, we can use the column as is
             (true, true) => return Ok(Transformed::no(expr)),
             // If the indexes or data types do not match, we need to create a new column expression
             (true, _) => column.clone(),
-            (false, _) => {
-                Column::new_with_schema(logical_field.name(), self.physical_file_schema)?
-            }
+            (false, _) => Column::new(physical_field.name(), physical_column_index),
         };
 
         if logical_field.data_type() == physical_field.data_type() {
@@ -438,6 +512,28 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
             return Ok(Transformed::yes(Arc::new(column)));
         }
 
+        // When matching by field ID, struct fields may have been renamed, reordered or
+        // dropped, so resolve them before casting (which matches struct fields by name)
+        let (column, physical_field): (Arc<dyn PhysicalExpr>, _) = match self
+            .field_id_key
+            .and_then(|key| resolve_struct_fields(physical_field, logical_field, key))
+        {
+            Some((resolved_field, mapping)) => {
+                let resolved_field = Arc::new(resolved_field);
+                let resolved: Arc<dyn PhysicalExpr> =
+                    Arc::new(ResolveStructFieldsExpr::new(
+                        Arc::new(column),
+                        Arc::clone(&resolved_field),
+                        mapping,
+                    ));
+                if logical_field.data_type() == resolved_field.data_type() {
+                    return Ok(Transformed::yes(resolved));
+                }
+                (resolved, resolved_field)
+            }
+            None => (Arc::new(column), Arc::new(physical_field.clone())),
+        };
+
         // We need to cast the column to the logical data type
         // TODO: add optimization to move the cast from the column to literal expressions in the case of `col = 123`
         // since that's much cheaper to evalaute.
@@ -458,7 +554,7 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
                 if !is_compatible {
                     return exec_err!(
                         "Cannot cast column '{}' from '{}' (physical data type) to '{}' (logical data type)",
-                        column.name(),
+                        physical_field.name(),
                         physical_field.data_type(),
                         logical_field.data_type()
                     );
@@ -467,8 +563,8 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
         }
 
         let cast_expr = Arc::new(CastColumnExpr::new(
-            Arc::new(column),
-            Arc::new(physical_field.clone()),
+            column,
+            physical_field,
             Arc::new(logical_field.clone()),
             None,
         ));
@@ -1174,6 +1270,7 @@ mod tests {
         let rewriter = DefaultPhysicalExprAdapterRewriter {
             logical_file_schema: &logical_schema,
             physical_file_schema: &physical_schema,
+            field_id_key: None,
         };
 
         // Test that when a field exists in physical schema, it returns None
@@ -1394,4 +1491,158 @@ mod tests {
         assert!(format!("{:?}", adapter1).contains("BatchAdapter"));
         assert!(format!("{:?}", adapter2).contains("BatchAdapter"));
     }
+
+    const FIELD_ID_KEY: &str = "PARQUET:field_id";
+
+    fn field_with_id(name: &str, data_type: DataType, id: i32) -> Field {
+        Field::new(name, data_type, true).with_metadata(
+            [(FIELD_ID_KEY.to_string(), id.to_string())]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_rewrite_column_by_field_id() -> Result<()> {
+        // `a` was renamed to `x` and `b` to `y`, and the columns were reordered
+        let logical_schema = Schema::new(vec![
+            field_with_id("a", DataType::Int64, 1),
+            field_with_id("b", DataType::Utf8, 2),
+        ]);
+        let physical_schema = Schema::new(vec![
+            field_with_id("y", DataType::Utf8, 2),
+            field_with_id("x", DataType::Int32, 1),
+        ]);
+
+        let factory = FieldIdPhysicalExprAdapterFactory::new(FIELD_ID_KEY);
+        let adapter = factory.create(Arc::new(logical_schema), Arc::new(physical_schema));
+
+        let result = adapter.rewrite(Arc::new(Column::new("b", 1)))?;
+        let column = result.as_any().downcast_ref::<Column>().unwrap();
+        assert_eq!(column, &Column::new("y", 0));
+
+        let result = adapter.rewrite(Arc::new(Column::new("a", 0)))?;
+        let cast = result.as_any().downcast_ref::<CastColumnExpr>().unwrap();
+        let column = cast.expr().as_any().downcast_ref::<Column>().unwrap();
+        assert_eq!(column, &Column::new("x", 1));
+        assert_eq!(cast.target_field().data_type(), &DataType::Int64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rewrite_column_by_field_id_readded() -> Result<()> {
+        // `a` was dropped and a new column `a` was added
+        let logical_schema = Schema::new(vec![field_with_id("a", DataType::Int32, 3)]);
+        let physical_schema = Schema::new(vec![field_with_id("a", DataType::Int32, 1)]);
+
+        let factory = FieldIdPhysicalExprAdapterFactory::new(FIELD_ID_KEY);
+        let adapter = factory.create(Arc::new(logical_schema), Arc::new(physical_schema));
+        let result = adapter.rewrite(Arc::new(Column::new("a", 0)))?;
+
+        let literal = result.as_any().downcast_ref::<Literal>().unwrap();
+        assert_eq!(*literal.value(), ScalarValue::Int32(None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rewrite_column_by_field_id_without_physical_ids() -> Result<()> {
+        // Files written without field IDs are matched by name
+        let logical_schema = Schema::new(vec![
+            field_with_id("a", DataType::Int32, 1),
+            field_with_id("b", DataType::Int32, 2),
+        ]);
+        let physical_schema = Schema::new(vec![
+            Field::new("b", DataType::Int32, true),
+            Field::new("a", DataType::Int32, true),
+        ]);
+
+        let factory = FieldIdPhysicalExprAdapterFactory::new(FIELD_ID_KEY);
+        let adapter = factory.create(Arc::new(logical_schema), Arc::new(physical_schema));
+        let result = adapter.rewrite(Arc::new(Column::new("a", 0)))?;
+
+        let column = result.as_any().downcast_ref::<Column>().unwrap();
+        assert_eq!(column, &Column::new("a", 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_adapter_factory_with_struct_field_ids() {
+        // Physical struct: {renamed_id (1), dropped (2), name (3)}
+        // Logical struct: {name (3), id (1), dropped (4)}, where `dropped` was re-added
+        let source_struct_fields: Fields = vec![
+            field_with_id("renamed_id", DataType::Int32, 1),
+            field_with_id("dropped", DataType::Utf8, 2),
+            field_with_id("name", DataType::Utf8, 3),
+        ]
+        .into();
+        let source_schema = Arc::new(Schema::new(vec![field_with_id(
+            "old_data",
+            DataType::Struct(source_struct_fields.clone()),
+            10,
+        )]));
+        let target_struct_fields: Fields = vec![
+            field_with_id("name", DataType::Utf8, 3),
+            field_with_id("id", DataType::Int64, 1),
+            field_with_id("dropped", DataType::Utf8, 4),
+        ]
+        .into();
+        let target_schema = Arc::new(Schema::new(vec![field_with_id(
+            "data",
+            DataType::Struct(target_struct_fields),
+            10,
+        )]));
+
+        let struct_array = StructArray::new(
+            source_struct_fields,
+            vec![
+                Arc::new(Int32Array::from(vec![10, 20])) as _,
+                Arc::new(StringArray::from(vec!["x", "y"])) as _,
+                Arc::new(StringArray::from(vec!["a", "b"])) as _,
+            ],
+            None,
+        );
+        let source_batch = RecordBatch::try_new(
+            Arc::clone(&source_schema),
+            vec![Arc::new(struct_array)],
+        )
+        .unwrap();
+
+        let factory = BatchAdapterFactory::new(Arc::clone(&target_schema))
+            .with_adapter_factory(Arc::new(FieldIdPhysicalExprAdapterFactory::new(
+                FIELD_ID_KEY,
+            )));
+        let adapter = factory.make_adapter(source_schema).unwrap();
+        let adapted = adapter.adapt_batch(&source_batch).unwrap();
+
+        let result_struct = adapted
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(
+            result_struct
+                .fields()
+                .iter()
+                .map(|f| f.name())
+                .collect_vec(),
+            vec!["name", "id", "dropped"]
+        );
+
+        let name_values = result_struct
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(name_values.iter().collect_vec(), vec![Some("a"), Some("b")]);
+        let id_values = result_struct
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(id_values.iter().collect_vec(), vec![Some(10), Some(20)]);
+        assert_eq!(result_struct.column(2).null_count(), 2);
+    }
 }
@@ -18,8 +18,8 @@
 //! [`Partitioning`] and [`Distribution`] for `ExecutionPlans`
 
 use crate::{
-    EquivalenceProperties, PhysicalExpr, equivalence::ProjectionMapping,
-    expressions::UnKnownColumn, physical_exprs_equal,
+    EquivalenceProperties, LexOrdering, PhysicalExpr, PhysicalSortExpr,
+    equivalence::ProjectionMapping, expressions::UnKnownColumn, physical_exprs_equal,
 };
 use datafusion_physical_expr_common::physical_expr::format_physical_expr_list;
 use std::fmt;
@@ -117,6 +117,13 @@ pub enum Partitioning {
     /// Allocate rows based on a hash of one of more expressions and the specified number of
     /// partitions
     Hash(Vec<Arc<dyn PhysicalExpr>>, usize),
+    /// Allocate rows based on ranges of the sort key described by the ordering, and the
+    /// specified number of partitions.
+    ///
+    /// The partitions do not overlap, and are ordered: every row of a partition
+    /// sorts before all rows of the following partitions. The split points of the
+    /// ranges are sampled from the input when the partitioning is executed.
+    Range(LexOrdering, usize),
     /// Unknown partitioning scheme with a known number of partitions
     UnknownPartitioning(usize),
 }
@@ -133,6 +140,9 @@ impl Display for Partitioning {
                     .join(", ");
                 write!(f, "Hash([{phy_exprs_str}], {size})")
             }
+            Partitioning::Range(ordering, size) => {
+                write!(f, "Range([{ordering}], {size})")
+            }
             Partitioning::UnknownPartitioning(size) => {
                 write!(f, "UnknownPartitioning({size})")
             }
@@ -166,7 +176,7 @@ impl Partitioning {
     pub fn partition_count(&self) -> usize {
         use Partitioning::*;
         match self {
-            RoundRobinBatch(n) | Hash(_, n) | UnknownPartitioning(n) => *n,
+            RoundRobinBatch(n) | Hash(_, n) | Range(_, n) | UnknownPartitioning(n) => *n,
         }
     }
 
@@ -265,6 +275,67 @@ impl Partitioning {
 
                     PartitioningSatisfaction::NotSatisfied
                 }
+                // Rows with the same values of the range keys end up in the same
+                // partition, but not in the partition the hash function would
+                // choose. Therefore, this is only a subset satisfaction, which
+                // is not used where inputs have to be partitioned alike.
+                Partitioning::Range(ordering, _) if allow_subset => {
+                    let partition_exprs = ordering
+                        .iter()
+                        .map(|sort_expr| Arc::clone(&sort_expr.expr))
+                        .collect::<Vec<_>>();
+                    let eq_groups = eq_properties.eq_group();
+                    let normalize = |exprs: &[Arc<dyn PhysicalExpr>]| {
+                        exprs
+                            .iter()
+                            .map(|e| eq_groups.normalize_expr(Arc::clone(e)))
+                            .collect::<Vec<_>>()
+                    };
+                    let partition_exprs = normalize(&partition_exprs);
+                    let required_exprs = normalize(required_exprs);
+                    if !required_exprs.is_empty()
+                        && partition_exprs.iter().all(|partition_expr| {
+                            required_exprs
+                                .iter()
+                                .any(|required_expr| partition_expr.eq(required_expr))
+                        })
+                    {
+                        PartitioningSatisfaction::Subset
+                    } else {
+                        PartitioningSatisfaction::NotSatisfied
+                    }
+                }
+                _ => PartitioningSatisfaction::NotSatisfied,
+            },
+            // When partition count is 1, range requirement is satisfied.
+            Distribution::RangePartitioned(_) if self.partition_count() == 1 => {
+                PartitioningSatisfaction::Exact
+            }
+            Distribution::RangePartitioned(required_ordering) => match self {
+                Partitioning::Range(ordering, _) => {
+                    if ordering == required_ordering {
+                        return PartitioningSatisfaction::Exact;
+                    }
+
+                    // Normalization path using equivalence groups
+                    let eq_groups = eq_properties.eq_group();
+                    let normalize = |ordering: &LexOrdering| {
+                        ordering
+                            .iter()
+                            .map(|sort_expr| {
+                                PhysicalSortExpr::new(
+                                    eq_groups.normalize_expr(Arc::clone(&sort_expr.expr)),
+                                    sort_expr.options,
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                    };
+                    if normalize(ordering) == normalize(required_ordering) {
+                        PartitioningSatisfaction::Exact
+                    } else {
+                        PartitioningSatisfaction::NotSatisfied
+                    }
+                }
                 _ => PartitioningSatisfaction::NotSatisfied,
             },
             _ => PartitioningSatisfaction::NotSatisfied,
@@ -277,19 +348,39 @@ impl Partitioning {
         mapping: &ProjectionMapping,
         input_eq_properties: &EquivalenceProperties,
     ) -> Self {
-        if let Partitioning::Hash(exprs, part) = self {
-            let normalized_exprs = input_eq_properties
-                .project_expressions(exprs, mapping)
-                .zip(exprs)
-                .map(|(proj_expr, expr)| {
-                    proj_expr.unwrap_or_else(|| {
-                        Arc::new(UnKnownColumn::new(&expr.to_string()))
+        match self {
+            Partitioning::Hash(exprs, part) => {
+                let normalized_exprs = input_eq_properties
+                    .project_expressions(exprs, mapping)
+                    .zip(exprs)
+                    .map(|(proj_expr, expr)| {
+                        proj_expr.unwrap_or_else(|| {
+                            Arc::new(UnKnownColumn::new(&expr.to_string()))
+                        })
                     })
-                })
-                .collect();
-            Partitioning::Hash(normalized_exprs, *part)
-        } else {
-            self.clone()
+                    .collect();
+                Partitioning::Hash(normalized_exprs, *part)
+            }
+            Partitioning::Range(ordering, part) => {
+                let exprs = ordering
+                    .iter()
+                    .map(|sort_expr| Arc::clone(&sort_expr.expr))
+                    .collect::<Vec<_>>();
+                let normalized_ordering = input_eq_properties
+                    .project_expressions(&exprs, mapping)
+                    .zip(ordering.iter())
+                    .map(|(proj_expr, sort_expr)| {
+                        let expr = proj_expr.unwrap_or_else(|| {
+                            Arc::new(UnKnownColumn::new(&sort_expr.expr.to_string()))
+                        });
+                        PhysicalSortExpr::new(expr, sort_expr.options)
+                    });
+                match LexOrdering::new(normalized_ordering) {
+                    Some(ordering) => Partitioning::Range(ordering, *part),
+                    None => Partitioning::UnknownPartitioning(*part),
+                }
+            }
+            _ => self.clone(),
         }
     }
 }
@@ -306,6 +397,10 @@ impl PartialEq for Partitioning {
             {
                 true
             }
+            (
+                Partitioning::Range(ordering1, count1),
+                Partitioning::Range(ordering2, count2),
+            ) if ordering1 == ordering2 && count1 == count2 => true,
             _ => false,
         }
     }
@@ -322,6 +417,10 @@ pub enum Distribution {
     /// Requires children to be distributed in such a way that the same
     /// values of the keys end up in the same partition
     HashPartitioned(Vec<Arc<dyn PhysicalExpr>>),
+    /// Requires children to be distributed in non-overlapping ranges of the
+    /// ordering, such that every row of a partition sorts before all rows of
+    /// the following partitions
+    RangePartitioned(LexOrdering),
 }
 
 impl Distribution {
@@ -335,6 +434,9 @@ impl Distribution {
             Distribution::HashPartitioned(expr) => {
                 Partitioning::Hash(expr, partition_count)
             }
+            Distribution::RangePartitioned(ordering) => {
+                Partitioning::Range(ordering, partition_count)
+            }
         }
     }
 }
@@ -347,6 +449,9 @@ impl Display for Distribution {
             Distribution::HashPartitioned(exprs) => {
                 write!(f, "HashPartitioned[{}])", format_physical_expr_list(exprs))
             }
+            Distribution::RangePartitioned(ordering) => {
+                write!(f, "RangePartitioned[{ordering}]")
+            }
         }
     }
 }
@@ -377,10 +482,18 @@ mod tests {
             Arc::new(Column::new_with_schema("column_1", &schema).unwrap()),
         ];
 
+        let ordering = LexOrdering::new(
+            partition_exprs1
+                .iter()
+                .map(|e| PhysicalSortExpr::new_default(Arc::clone(e))),
+        )
+        .unwrap();
+
         let distribution_types = vec![
             Distribution::UnspecifiedDistribution,
             Distribution::SinglePartition,
             Distribution::HashPartitioned(partition_exprs1.clone()),
+            Distribution::RangePartitioned(ordering),
         ];
 
         let single_partition = Partitioning::UnknownPartitioning(1);
@@ -419,6 +532,9 @@ mod tests {
                 Distribution::HashPartitioned(_) => {
                     assert_eq!(result, (true, false, false, true, false))
                 }
+                Distribution::RangePartitioned(_) => {
+                    assert_eq!(result, (true, false, false, false, false))
+                }
             }
         }
 
@@ -845,4 +961,96 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_partitioning_range() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+
+        let col_a: Arc<dyn PhysicalExpr> =
+            Arc::new(Column::new_with_schema("a", &schema)?);
+        let col_b: Arc<dyn PhysicalExpr> =
+            Arc::new(Column::new_with_schema("b", &schema)?);
+        let a_asc = PhysicalSortExpr::new_default(Arc::clone(&col_a));
+        let b_asc = PhysicalSortExpr::new_default(Arc::clone(&col_b));
+        let range_a = LexOrdering::from([a_asc.clone()]);
+        let range_a_desc = LexOrdering::from([a_asc.clone().desc()]);
+        let range_ab = LexOrdering::from([a_asc, b_asc]);
+        let eq_properties = EquivalenceProperties::new(Arc::clone(&schema));
+
+        let test_cases = vec![
+            (
+                "Range([a]) vs Range([a])",
+                Partitioning::Range(range_a.clone(), 4),
+                Distribution::RangePartitioned(range_a.clone()),
+                PartitioningSatisfaction::Exact,
+                PartitioningSatisfaction::Exact,
+            ),
+            (
+                "Range([a]) vs Range([a DESC])",
+                Partitioning::Range(range_a.clone(), 4),
+                Distribution::RangePartitioned(range_a_desc),
+                PartitioningSatisfaction::NotSatisfied,
+                PartitioningSatisfaction::NotSatisfied,
+            ),
+            (
+                "Range([a]) vs Range([a, b])",
+                Partitioning::Range(range_a.clone(), 4),
+                Distribution::RangePartitioned(range_ab.clone()),
+                PartitioningSatisfaction::NotSatisfied,
+                PartitioningSatisfaction::NotSatisfied,
+            ),
+            (
+                "Range([a]) vs Range([a]) in a single partition",
+                Partitioning::UnknownPartitioning(1),
+                Distribution::RangePartitioned(range_a.clone()),
+                PartitioningSatisfaction::Exact,
+                PartitioningSatisfaction::Exact,
+            ),
+            (
+                "Hash([a]) vs Range([a])",
+                Partitioning::Hash(vec![Arc::clone(&col_a)], 4),
+                Distribution::RangePartitioned(range_a.clone()),
+                PartitioningSatisfaction::NotSatisfied,
+                PartitioningSatisfaction::NotSatisfied,
+            ),
+            (
+                "Range([a]) vs Hash([a, b])",
+                Partitioning::Range(range_a.clone(), 4),
+                Distribution::HashPartitioned(vec![
+                    Arc::clone(&col_a),
+                    Arc::clone(&col_b),
+                ]),
+                PartitioningSatisfaction::Subset,
+                PartitioningSatisfaction::NotSatisfied,
+            ),
+            (
+                "Range([a, b]) vs Hash([a])",
+                Partitioning::Range(range_ab, 4),
+                Distribution::HashPartitioned(vec![Arc::clone(&col_a)]),
+                PartitioningSatisfaction::NotSatisfied,
+                PartitioningSatisfaction::NotSatisfied,
+            ),
+        ];
+
+        for (desc, partition, required, expected_with_subset, expected_without_subset) in
+            test_cases
+        {
+            let result = partition.satisfaction(&required, &eq_properties, true);
+            assert_eq!(
+                result, expected_with_subset,
+                "Failed for {desc} with subset enabled"
+            );
+
+            let result = partition.satisfaction(&required, &eq_properties, false);
+            assert_eq!(
+                result, expected_without_subset,
+                "Failed for {desc} with subset disabled"
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -40,7 +40,8 @@ use datafusion_expr::logical_plan::{Aggregate, JoinType};
 use datafusion_physical_expr::expressions::{Column, NoOp};
 use datafusion_physical_expr::utils::map_columns_before_projection;
 use datafusion_physical_expr::{
-    EquivalenceProperties, PhysicalExpr, PhysicalExprRef, physical_exprs_equal,
+    EquivalenceProperties, LexOrdering, PhysicalExpr, PhysicalExprRef,
+    physical_exprs_equal,
 };
 use datafusion_physical_plan::ExecutionPlanProperties;
 use datafusion_physical_plan::aggregates::{
@@ -937,6 +938,51 @@ fn add_hash_on_top(
     Ok(input)
 }
 
+/// Adds a range [`RepartitionExec`] operator on top of the given plan node to
+/// satisfy a range partitioning requirement on `ordering`, unless the output
+/// partitioning of the plan already satisfies it.
+///
+/// # Parameters
+///
+/// * `input`: Current node.
+/// * `ordering`: Sort key of the range partitioning requirement.
+/// * `n_target`: Desired target partition number, if partition number of the
+///   current executor is less than this value, partition number will be increased.
+///
+/// # Returns
+///
+/// A [`Result`] object that contains new execution plan where the desired
+/// distribution is satisfied by adding a range repartition.
+fn add_range_on_top(
+    input: DistributionContext,
+    ordering: LexOrdering,
+    n_target: usize,
+) -> Result<DistributionContext> {
+    // A single partition is trivially range partitioned
+    if n_target == 1 && input.plan.output_partitioning().partition_count() == 1 {
+        return Ok(input);
+    }
+
+    let dist = Distribution::RangePartitioned(ordering);
+    let satisfaction = input.plan.output_partitioning().satisfaction(
+        &dist,
+        input.plan.equivalence_properties(),
+        false,
+    );
+    if satisfaction.is_satisfied() {
+        return Ok(input);
+    }
+
+    // As for hash repartitioning, the ordering is preserved if possible, and
+    // this is rolled back later if it does not help satisfy ordering requirements.
+    let partitioning = dist.create_partitioning(n_target);
+    let repartition = RepartitionExec::try_new(Arc::clone(&input.plan), partitioning)?
+        .with_preserve_order();
+    let plan = Arc::new(repartition) as _;
+
+    Ok(DistributionContext::new(plan, true, vec![input]))
+}
+
 /// Adds a [`SortPreservingMergeExec`] or a [`CoalescePartitionsExec`] operator
 /// on top of the given plan node to satisfy a single partition requirement
 /// while preserving ordering constraints.
@@ -1348,6 +1394,9 @@ pub fn ensure_distribution(
                         )?;
                     }
                 }
+                Distribution::RangePartitioned(ordering) => {
+                    child = add_range_on_top(child, ordering.clone(), target_partitions)?;
+                }
                 Distribution::UnspecifiedDistribution => {
                     if add_roundrobin {
                         // Add round-robin repartitioning on top of the operator
@@ -1392,7 +1441,9 @@ pub fn ensure_distribution(
                 // no ordering requirement
                 match requirement {
                     // Operator requires specific distribution.
-                    Distribution::SinglePartition | Distribution::HashPartitioned(_) => {
+                    Distribution::SinglePartition
+                    | Distribution::HashPartitioned(_)
+                    | Distribution::RangePartitioned(_) => {
                         // Since there is no ordering requirement, preserving ordering is pointless
                         child = replace_order_preserving_variants(child)?;
                     }
@@ -206,6 +206,8 @@ fn update_coalesce_ctx_children(
 ///    or a [`SortExec`] + [`RepartitionExec`] combination into an order preserving [`RepartitionExec`]
 /// 4. [`sort_pushdown`] Works top-down. Responsible to push down sort operators as deep as possible in the plan.
 /// 5. `replace_with_partial_sort` Checks if it's possible to replace [`SortExec`]s with [`PartialSortExec`] operators
+/// 6. [`range_partition_sorts`] (Optional, depends on the `range_repartition_sorts` configuration)
+///    Range partitions the input of sorts that are executed per partition and merged afterwards.
 impl PhysicalOptimizerRule for EnforceSorting {
     fn optimize(
         &self,
@@ -243,10 +245,18 @@ impl PhysicalOptimizerRule for EnforceSorting {
         let mut sort_pushdown = SortPushDown::new_default(updated_plan.plan);
         assign_initial_requirements(&mut sort_pushdown);
         let adjusted = pushdown_sorts(sort_pushdown)?;
-        adjusted
+        let plan = adjusted
             .plan
             .transform_up(|plan| Ok(Transformed::yes(replace_with_partial_sort(plan)?)))
-            .data()
+            .data()?;
+        if config.optimizer.repartition_sorts && config.optimizer.range_repartition_sorts
+        {
+            let target_partitions = config.execution.target_partitions;
+            plan.transform_up(|plan| range_partition_sorts(plan, target_partitions))
+                .data()
+        } else {
+            Ok(plan)
+        }
     }
 
     fn name(&self) -> &str {
@@ -258,6 +268,74 @@ impl PhysicalOptimizerRule for EnforceSorting {
     }
 }
 
+/// Range partitions the input of a [`SortExec`] executed per partition and
+/// merged by a [`SortPreservingMergeExec`], so that the sorted partitions are
+/// non-overlapping ranges of the sort key, which the merge concatenates:
+/// ```text
+///      "SortPreservingMergeExec: [a@0 ASC]",
+///      "  SortExec: expr=[a@0 ASC], preserve_partitioning=[true]",
+///      "    RepartitionExec: partitioning=RoundRobinBatch(8), input_partitions=1",
+/// ```
+/// becomes
+/// ```text
+///      "SortPreservingMergeExec: [a@0 ASC]",
+///      "  SortExec: expr=[a@0 ASC], preserve_partitioning=[true]",
+///      "    RepartitionExec: partitioning=Range([a@0 ASC], 8), input_partitions=1",
+/// ```
+///
+/// Sorts with a fetch and sorts of unbounded inputs are left as they are, as
+/// the split points of the ranges are only known once the whole input of the
+/// [`RepartitionExec`] was read.
+pub fn range_partition_sorts(
+    plan: Arc<dyn ExecutionPlan>,
+    target_partitions: usize,
+) -> Result<Transformed<Arc<dyn ExecutionPlan>>> {
+    let Some(spm) = plan.as_any().downcast_ref::<SortPreservingMergeExec>() else {
+        return Ok(Transformed::no(plan));
+    };
+    let Some(sort) = spm.input().as_any().downcast_ref::<SortExec>() else {
+        return Ok(Transformed::no(plan));
+    };
+    if !sort.preserve_partitioning()
+        || sort.fetch().is_some()
+        || sort.expr() != spm.expr()
+        || sort.input().boundedness().is_unbounded()
+        || target_partitions <= 1
+    {
+        return Ok(Transformed::no(plan));
+    }
+
+    let distribution = Distribution::RangePartitioned(spm.expr().clone());
+    let input = sort.input();
+    if input
+        .output_partitioning()
+        .satisfaction(&distribution, input.equivalence_properties(), false)
+        .is_satisfied()
+    {
+        return Ok(Transformed::no(plan));
+    }
+    // A round robin repartitioning is replaced, the rows are redistributed anyway
+    let input = match input.as_any().downcast_ref::<RepartitionExec>() {
+        Some(repartition)
+            if matches!(repartition.partitioning(), Partitioning::RoundRobinBatch(_))
+                && !repartition.preserve_order() =>
+        {
+            Arc::clone(repartition.input())
+        }
+        _ => Arc::clone(input),
+    };
+    let repartition = Arc::new(RepartitionExec::try_new(
+        input,
+        distribution.create_partitioning(target_partitions),
+    )?);
+    let sort = Arc::new(
+        SortExec::new(sort.expr().clone(), repartition).with_preserve_partitioning(true),
+    );
+    Ok(Transformed::yes(
+        Arc::clone(&plan).with_new_children(vec![sort])?,
+    ))
+}
+
 /// Only interested with [`SortExec`]s and their unbounded children.
 /// If the plan is not a [`SortExec`] or its child is not unbounded, returns the original plan.
 /// Otherwise, by checking the requirement satisfaction searches for a replacement chance.
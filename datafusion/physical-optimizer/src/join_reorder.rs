@@ -0,0 +1,617 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The [`JoinReorder`] rule picks the order of chains of inner equi-joins
+//! using the row count and distinct count statistics of their inputs.
+//!
+//! A chain of inner [`HashJoinExec`]s is flattened into a join graph, whose
+//! vertices are the inputs of the chain and whose edges are the equi-join
+//! conditions. The rule then searches for the join tree with the smallest
+//! estimated total size of the intermediate results:
+//!
+//! - For small graphs all join trees are enumerated with dynamic programming
+//!   over the subsets of the inputs.
+//! - Larger graphs are joined greedily, always joining the two subtrees which
+//!   produce the smallest result.
+//!
+//! Cross products are never introduced. The size of the join of two subtrees
+//! is estimated as `|L| * |R| / max(ndv(l), ndv(r))` for every join condition
+//! `l = r` between them, where `ndv` is the number of distinct values of the
+//! join key. The rule leaves the plan unchanged when the row count of any
+//! input is unknown, or when the estimated cost is not lower than the cost
+//! of the original join order.
+//!
+//! This rule only chooses the join order. The build side and partition mode
+//! of every join are picked by the [`JoinSelection`] rule which runs later.
+//!
+//! [`JoinSelection`]: crate::join_selection::JoinSelection
+
+use std::sync::Arc;
+
+use crate::PhysicalOptimizerRule;
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::error::Result;
+use datafusion_common::tree_node::{Transformed, TreeNode, TreeNodeRecursion};
+use datafusion_common::{JoinType, NullEquality, Statistics};
+use datafusion_physical_expr::PhysicalExpr;
+use datafusion_physical_expr::expressions::Column;
+use datafusion_physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion_physical_plan::projection::{ProjectionExec, ProjectionExpr};
+use datafusion_physical_plan::{ExecutionPlan, ExecutionPlanProperties};
+
+/// Join graphs with more inputs are always ordered greedily, regardless of
+/// the configured threshold, as the number of join trees enumerated by the
+/// dynamic programming grows exponentially with the number of inputs.
+const MAX_DP_INPUTS: usize = 14;
+
+/// The [`JoinReorder`] rule reorders chains of inner equi-joins using the
+/// statistics of their inputs. See the [module level docs](self) for details.
+#[derive(Default, Debug)]
+pub struct JoinReorder {}
+
+impl JoinReorder {
+    #[expect(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for JoinReorder {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let config = &config.optimizer;
+        if !config.enable_join_reordering {
+            return Ok(plan);
+        }
+        let dp_threshold = config.join_reordering_dp_threshold.min(MAX_DP_INPUTS);
+        reorder_joins(plan, dp_threshold).map(|t| t.data)
+    }
+
+    fn name(&self) -> &str {
+        "join_reorder"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+fn reorder_joins(
+    plan: Arc<dyn ExecutionPlan>,
+    dp_threshold: usize,
+) -> Result<Transformed<Arc<dyn ExecutionPlan>>> {
+    plan.transform_down(|plan| reorder_join_graph(plan, dp_threshold))
+}
+
+/// Reorders the join graph rooted at `plan`, if any. The inputs of the join
+/// graph are optimized recursively, so the traversal does not need to visit
+/// the children of a join graph.
+fn reorder_join_graph(
+    plan: Arc<dyn ExecutionPlan>,
+    dp_threshold: usize,
+) -> Result<Transformed<Arc<dyn ExecutionPlan>>> {
+    let Some((mut graph, original)) = JoinGraph::try_new(&plan) else {
+        return Ok(Transformed::no(plan));
+    };
+    if graph.inputs.len() < 3
+        || graph.inputs.iter().any(|i| i.boundedness().is_unbounded())
+    {
+        return Ok(Transformed::no(plan));
+    }
+
+    let mut inputs_transformed = false;
+    for input in graph.inputs.iter_mut() {
+        let transformed = reorder_joins(Arc::clone(input), dp_threshold)?;
+        inputs_transformed |= transformed.transformed;
+        *input = transformed.data;
+    }
+
+    let reordered = match CardinalityEstimator::try_new(&graph)? {
+        Some(estimator) => {
+            let reordered = if graph.inputs.len() <= dp_threshold {
+                estimator.dp_order()
+            } else {
+                estimator.greedy_order()
+            };
+            let reordered_cost = estimator.estimate(&reordered).cost;
+            (reordered_cost < estimator.estimate(&original).cost).then_some(reordered)
+        }
+        None => None,
+    };
+
+    let tree = match reordered {
+        Some(tree) => tree,
+        None if inputs_transformed => original,
+        None => {
+            return Ok(Transformed::new(plan, false, TreeNodeRecursion::Jump));
+        }
+    };
+    let (new_plan, columns) = graph.build(&tree)?;
+    let new_plan = restore_column_order(new_plan, &columns, &graph.columns, &plan)?;
+    Ok(Transformed::new(new_plan, true, TreeNodeRecursion::Jump))
+}
+
+/// Adds a projection on top of the reordered joins which restores the column
+/// order of the original joins
+fn restore_column_order(
+    new_plan: Arc<dyn ExecutionPlan>,
+    columns: &[InputColumn],
+    original_columns: &[InputColumn],
+    original: &Arc<dyn ExecutionPlan>,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    // Projections in the original joins may have renamed columns
+    if columns == original_columns && new_plan.schema() == original.schema() {
+        return Ok(new_plan);
+    }
+    let original_schema = original.schema();
+    let exprs = original_columns
+        .iter()
+        .zip(original_schema.fields())
+        .map(|(input_column, field)| ProjectionExpr {
+            expr: column(&new_plan, position(columns, *input_column)),
+            alias: field.name().to_owned(),
+        })
+        .collect::<Vec<_>>();
+    Ok(Arc::new(ProjectionExec::try_new(exprs, new_plan)?))
+}
+
+/// A column of an input of a [`JoinGraph`], as `(input index, column index)`
+type InputColumn = (usize, usize);
+
+fn position(columns: &[InputColumn], column: InputColumn) -> usize {
+    columns
+        .iter()
+        .position(|c| *c == column)
+        .expect("join graph column should be produced by the join tree")
+}
+
+/// An equi-join condition between two inputs of a [`JoinGraph`]
+#[derive(Debug)]
+struct JoinEdge {
+    left: InputColumn,
+    right: InputColumn,
+}
+
+/// The shape of a join tree over the inputs of a [`JoinGraph`]
+#[derive(Debug, Clone)]
+enum JoinTree {
+    Input(usize),
+    Join(Box<JoinTree>, Box<JoinTree>),
+}
+
+impl JoinTree {
+    fn join(left: JoinTree, right: JoinTree) -> Self {
+        Self::Join(Box::new(left), Box::new(right))
+    }
+
+    /// Calls `f` for every input of this tree
+    fn for_each_input(&self, f: &mut impl FnMut(usize)) {
+        match self {
+            Self::Input(input) => f(*input),
+            Self::Join(left, right) => {
+                left.for_each_input(f);
+                right.for_each_input(f);
+            }
+        }
+    }
+}
+
+/// A chain of inner equi-joins, flattened into its inputs and join conditions
+#[derive(Debug)]
+struct JoinGraph {
+    inputs: Vec<Arc<dyn ExecutionPlan>>,
+    edges: Vec<JoinEdge>,
+    /// The output columns of the original joins
+    columns: Vec<InputColumn>,
+    partition_mode: PartitionMode,
+    null_equality: NullEquality,
+}
+
+impl JoinGraph {
+    /// Flattens the chain of joins rooted at `plan`, and returns it along with
+    /// the tree of the original join order. Returns `None` if `plan` is not a
+    /// join which can be reordered.
+    fn try_new(plan: &Arc<dyn ExecutionPlan>) -> Option<(Self, JoinTree)> {
+        let join = plan.as_any().downcast_ref::<HashJoinExec>()?;
+        let mut graph = Self {
+            inputs: vec![],
+            edges: vec![],
+            columns: vec![],
+            partition_mode: *join.partition_mode(),
+            null_equality: join.null_equality(),
+        };
+        if !graph.is_reorderable(join) {
+            return None;
+        }
+        let (tree, columns) = graph.add(plan);
+        graph.columns = columns;
+        Some((graph, tree))
+    }
+
+    /// Only inner joins whose conditions are all column equalities can be
+    /// reordered freely
+    fn is_reorderable(&self, join: &HashJoinExec) -> bool {
+        join.join_type() == &JoinType::Inner
+            && join.filter().is_none()
+            && !join.contains_projection()
+            && join.null_equality() == self.null_equality
+            && join
+                .on()
+                .iter()
+                .all(|(l, r)| l.as_any().is::<Column>() && r.as_any().is::<Column>())
+    }
+
+    /// Returns true if `plan` is a reorderable join, possibly below
+    /// projections of columns
+    fn is_join(&self, plan: &Arc<dyn ExecutionPlan>) -> bool {
+        if let Some((input, _)) = column_projection(plan) {
+            return self.is_join(input);
+        }
+        plan.as_any()
+            .downcast_ref::<HashJoinExec>()
+            .is_some_and(|join| self.is_reorderable(join))
+    }
+
+    fn add(&mut self, plan: &Arc<dyn ExecutionPlan>) -> (JoinTree, Vec<InputColumn>) {
+        // The projection pushdown of the logical optimizer places projections
+        // of columns between the joins, which are looked through. The columns
+        // they drop are kept until the end of the reordered joins.
+        if let Some((input, indices)) = column_projection(plan)
+            && self.is_join(input)
+        {
+            let (tree, input_columns) = self.add(input);
+            let columns = indices.into_iter().map(|i| input_columns[i]).collect();
+            return (tree, columns);
+        }
+        if let Some(join) = plan.as_any().downcast_ref::<HashJoinExec>()
+            && self.is_reorderable(join)
+        {
+            let (left_tree, left_columns) = self.add(join.left());
+            let (right_tree, right_columns) = self.add(join.right());
+            for (l, r) in join.on() {
+                let column_index = |expr: &Arc<dyn PhysicalExpr>| {
+                    expr.as_any().downcast_ref::<Column>().map(Column::index)
+                };
+                if let (Some(l), Some(r)) = (column_index(l), column_index(r)) {
+                    self.edges.push(JoinEdge {
+                        left: left_columns[l],
+                        right: right_columns[r],
+                    });
+                }
+            }
+            let columns = left_columns.into_iter().chain(right_columns).collect();
+            return (JoinTree::join(left_tree, right_tree), columns);
+        }
+
+        let input = self.inputs.len();
+        self.inputs.push(Arc::clone(plan));
+        let columns = (0..plan.schema().fields().len())
+            .map(|column| (input, column))
+            .collect();
+        (JoinTree::Input(input), columns)
+    }
+
+    /// Creates the joins of the given tree, and returns them along with their
+    /// output columns
+    fn build(
+        &self,
+        tree: &JoinTree,
+    ) -> Result<(Arc<dyn ExecutionPlan>, Vec<InputColumn>)> {
+        match tree {
+            JoinTree::Input(input) => {
+                let plan = Arc::clone(&self.inputs[*input]);
+                let columns = (0..plan.schema().fields().len())
+                    .map(|column| (*input, column))
+                    .collect();
+                Ok((plan, columns))
+            }
+            JoinTree::Join(left, right) => {
+                let (left, left_columns) = self.build(left)?;
+                let (right, right_columns) = self.build(right)?;
+                let contains = |columns: &[InputColumn], column: &InputColumn| {
+                    columns.iter().any(|c| c.0 == column.0)
+                };
+                let on = self
+                    .edges
+                    .iter()
+                    .filter_map(|edge| {
+                        if contains(&left_columns, &edge.left)
+                            && contains(&right_columns, &edge.right)
+                        {
+                            Some((edge.left, edge.right))
+                        } else if contains(&left_columns, &edge.right)
+                            && contains(&right_columns, &edge.left)
+                        {
+                            Some((edge.right, edge.left))
+                        } else {
+                            None
+                        }
+                    })
+                    .map(|(l, r)| {
+                        (
+                            column(&left, position(&left_columns, l)),
+                            column(&right, position(&right_columns, r)),
+                        )
+                    })
+                    .collect();
+                let join = HashJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    None,
+                    &JoinType::Inner,
+                    None,
+                    self.partition_mode,
+                    self.null_equality,
+                )?;
+                let columns = left_columns.into_iter().chain(right_columns).collect();
+                Ok((Arc::new(join), columns))
+            }
+        }
+    }
+}
+
+/// Returns the input of `plan` and the indices of the projected columns, if
+/// `plan` is a projection of columns only
+fn column_projection(
+    plan: &Arc<dyn ExecutionPlan>,
+) -> Option<(&Arc<dyn ExecutionPlan>, Vec<usize>)> {
+    let projection = plan.as_any().downcast_ref::<ProjectionExec>()?;
+    let indices = projection
+        .expr()
+        .iter()
+        .map(|e| e.expr.as_any().downcast_ref::<Column>().map(Column::index))
+        .collect::<Option<_>>()?;
+    Some((projection.input(), indices))
+}
+
+fn column(plan: &Arc<dyn ExecutionPlan>, index: usize) -> Arc<dyn PhysicalExpr> {
+    Arc::new(Column::new(plan.schema().field(index).name(), index))
+}
+
+/// The estimated output rows of a join tree, and its cost as the total
+/// number of rows produced by its joins
+#[derive(Debug, Clone, Copy)]
+struct Estimate {
+    rows: f64,
+    cost: f64,
+}
+
+/// Estimates the size of joins between the inputs of a [`JoinGraph`]
+struct CardinalityEstimator {
+    /// The estimated number of rows of every input
+    rows: Vec<f64>,
+    /// The inputs joined by every join condition
+    edges: Vec<(usize, usize)>,
+    /// The selectivity of every join condition
+    selectivities: Vec<f64>,
+}
+
+impl CardinalityEstimator {
+    /// Returns `None` if the row count of any input is unknown
+    fn try_new(graph: &JoinGraph) -> Result<Option<Self>> {
+        let mut statistics = Vec::with_capacity(graph.inputs.len());
+        for input in &graph.inputs {
+            let input_statistics = input.partition_statistics(None)?;
+            if input_statistics.num_rows.get_value().is_none() {
+                return Ok(None);
+            }
+            statistics.push(input_statistics);
+        }
+        let rows = statistics
+            .iter()
+            .map(|s| s.num_rows.get_value().map_or(1.0, |r| (*r as f64).max(1.0)))
+            .collect::<Vec<_>>();
+
+        let distinct_count = |statistics: &Statistics, (input, column): InputColumn| {
+            statistics
+                .column_statistics
+                .get(column)
+                .and_then(|c| c.distinct_count.get_value())
+                .map(|d| (*d as f64).clamp(1.0, rows[input]))
+        };
+        let selectivities = graph
+            .edges
+            .iter()
+            .map(|edge| {
+                let left = distinct_count(&statistics[edge.left.0], edge.left);
+                let right = distinct_count(&statistics[edge.right.0], edge.right);
+                let distinct = match (left, right) {
+                    (Some(l), Some(r)) => l.max(r),
+                    (Some(d), None) | (None, Some(d)) => d,
+                    // Without distinct counts, assume that the key of the
+                    // larger input is unique
+                    (None, None) => rows[edge.left.0].max(rows[edge.right.0]),
+                };
+                1.0 / distinct
+            })
+            .collect();
+        let edges = graph
+            .edges
+            .iter()
+            .map(|edge| (edge.left.0, edge.right.0))
+            .collect();
+
+        Ok(Some(Self {
+            rows,
+            edges,
+            selectivities,
+        }))
+    }
+
+    /// Estimates the rows of joining two subtrees, where `crosses` tells
+    /// whether a join condition (given by the inputs it joins) connects them.
+    /// Returns `None` if no join condition connects them.
+    fn join_rows(
+        &self,
+        left_rows: f64,
+        right_rows: f64,
+        crosses: impl Fn(usize, usize) -> bool,
+    ) -> Option<f64> {
+        let mut connected = false;
+        let mut rows = left_rows * right_rows;
+        for (&(l, r), selectivity) in self.edges.iter().zip(&self.selectivities) {
+            if crosses(l, r) {
+                connected = true;
+                rows *= selectivity;
+            }
+        }
+        connected.then_some(rows.max(1.0))
+    }
+
+    fn estimate(&self, tree: &JoinTree) -> Estimate {
+        match tree {
+            JoinTree::Input(input) => Estimate {
+                rows: self.rows[*input],
+                cost: 0.0,
+            },
+            JoinTree::Join(left_tree, right_tree) => {
+                let left = self.estimate(left_tree);
+                let right = self.estimate(right_tree);
+                let mut side = vec![0u8; self.rows.len()];
+                left_tree.for_each_input(&mut |input| side[input] = 1);
+                right_tree.for_each_input(&mut |input| side[input] = 2);
+                let rows = self
+                    .join_rows(left.rows, right.rows, |l, r| side[l] * side[r] == 2)
+                    .unwrap_or(left.rows * right.rows);
+                Estimate {
+                    rows,
+                    cost: left.cost + right.cost + rows,
+                }
+            }
+        }
+    }
+
+    /// Creates a join with the input producing fewer rows on the left (build)
+    /// side
+    fn join(left: (JoinTree, f64), right: (JoinTree, f64)) -> JoinTree {
+        if left.1 <= right.1 {
+            JoinTree::join(left.0, right.0)
+        } else {
+            JoinTree::join(right.0, left.0)
+        }
+    }
+
+    /// Finds the cheapest join tree by enumerating the splits of every subset
+    /// of the inputs into two connected subtrees
+    fn dp_order(&self) -> JoinTree {
+        let n = self.rows.len();
+        let full = (1usize << n) - 1;
+        // The cheapest join tree of every subset of the inputs, given as its
+        // estimate and the subset of its left input
+        let mut best: Vec<Option<(Estimate, usize)>> = vec![None; full + 1];
+        for (input, rows) in self.rows.iter().enumerate() {
+            let estimate = Estimate {
+                rows: *rows,
+                cost: 0.0,
+            };
+            best[1 << input] = Some((estimate, 0));
+        }
+
+        for set in 1..=full {
+            if set.count_ones() < 2 {
+                continue;
+            }
+            let mut left = (set - 1) & set;
+            while left > 0 {
+                let right = set & !left;
+                if let (Some((l, _)), Some((r, _))) = (best[left], best[right]) {
+                    let crosses = |a: usize, b: usize| {
+                        let (a, b) = (1 << a, 1 << b);
+                        (left & a != 0 && right & b != 0)
+                            || (left & b != 0 && right & a != 0)
+                    };
+                    if let Some(rows) = self.join_rows(l.rows, r.rows, crosses) {
+                        let cost = l.cost + r.cost + rows;
+                        if best[set].is_none_or(|(b, _)| cost < b.cost) {
+                            best[set] = Some((Estimate { rows, cost }, left));
+                        }
+                    }
+                }
+                left = (left - 1) & set;
+            }
+        }
+
+        self.dp_tree(&best, full)
+    }
+
+    fn dp_tree(&self, best: &[Option<(Estimate, usize)>], set: usize) -> JoinTree {
+        let (estimate, left) = best[set].expect("join graph should be connected");
+        if left == 0 {
+            return JoinTree::Input(set.trailing_zeros() as usize);
+        }
+        let right = set & !left;
+        let rows = |set: usize| best[set].map_or(estimate.rows, |(e, _)| e.rows);
+        Self::join(
+            (self.dp_tree(best, left), rows(left)),
+            (self.dp_tree(best, right), rows(right)),
+        )
+    }
+
+    /// Repeatedly joins the two connected subtrees producing the fewest rows
+    fn greedy_order(&self) -> JoinTree {
+        let n = self.rows.len();
+        // The subtree every input currently belongs to
+        let mut subtree_of = (0..n).collect::<Vec<_>>();
+        let mut subtrees = (0..n)
+            .map(|input| Some((JoinTree::Input(input), self.rows[input])))
+            .collect::<Vec<_>>();
+
+        for _ in 1..n {
+            let mut cheapest: Option<(usize, usize, f64)> = None;
+            for (i, left) in subtrees.iter().enumerate() {
+                let Some((_, left_rows)) = left else {
+                    continue;
+                };
+                for (j, right) in subtrees.iter().enumerate().skip(i + 1) {
+                    let Some((_, right_rows)) = right else {
+                        continue;
+                    };
+                    let crosses = |a: usize, b: usize| {
+                        let (a, b) = (subtree_of[a], subtree_of[b]);
+                        (a == i && b == j) || (a == j && b == i)
+                    };
+                    if let Some(rows) = self.join_rows(*left_rows, *right_rows, crosses)
+                        && cheapest.is_none_or(|(_, _, c)| rows < c)
+                    {
+                        cheapest = Some((i, j, rows));
+                    }
+                }
+            }
+            let Some((i, j, rows)) = cheapest else {
+                break;
+            };
+            let left = subtrees[i].take().expect("subtree should exist");
+            let right = subtrees[j].take().expect("subtree should exist");
+            subtree_of
+                .iter_mut()
+                .filter(|s| **s == j)
+                .for_each(|s| *s = i);
+            subtrees[i] = Some((Self::join(left, right), rows));
+        }
+
+        subtrees
+            .into_iter()
+            .flatten()
+            .map(|(tree, _)| tree)
+            .next()
+            .expect("join graph should have inputs")
+    }
+}
@@ -34,7 +34,7 @@ use datafusion_physical_expr::expressions::Column;
 use datafusion_physical_plan::execution_plan::EmissionType;
 use datafusion_physical_plan::joins::utils::ColumnIndex;
 use datafusion_physical_plan::joins::{
-    CrossJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode,
+    AdaptiveHashJoinExec, CrossJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode,
     StreamJoinPartitionMode, SymmetricHashJoinExec,
 };
 use datafusion_physical_plan::{ExecutionPlan, ExecutionPlanProperties};
@@ -131,6 +131,13 @@ impl PhysicalOptimizerRule for JoinSelection {
         //   do not modify join sides.
         // - We will also swap left and right sides for cross joins so that the left
         //   side is the small side.
+        // - If adaptive join selection is enabled, a hash join with partition mode
+        //   [`PartitionMode::Auto`] and an input without size statistics makes
+        //   these decisions at execution time instead.
+        let adaptive_partitions = config
+            .optimizer
+            .adaptive_join_selection
+            .then_some(config.execution.target_partitions);
         let config = &config.optimizer;
         let collect_threshold_byte_size = config.hash_join_single_partition_threshold;
         let collect_threshold_num_rows = config.hash_join_single_partition_threshold_rows;
@@ -140,6 +147,7 @@ impl PhysicalOptimizerRule for JoinSelection {
                     plan,
                     collect_threshold_byte_size,
                     collect_threshold_num_rows,
+                    adaptive_partitions,
                 )
             })
             .data()
@@ -249,26 +257,76 @@ pub(crate) fn partitioned_hash_join(
     }
 }
 
+/// Returns true if the size of an input of `hash_join` is unknown, and both
+/// inputs are bounded so they can be buffered to find out their sizes.
+fn supports_adaptive_join(hash_join: &HashJoinExec) -> bool {
+    let has_size_statistics = |plan: &Arc<dyn ExecutionPlan>| {
+        plan.partition_statistics(None).is_ok_and(|stats| {
+            stats.total_byte_size.get_value().is_some()
+                || stats.num_rows.get_value().is_some()
+        })
+    };
+    let (left, right) = (hash_join.left(), hash_join.right());
+    let is_bounded =
+        !left.boundedness().is_unbounded() && !right.boundedness().is_unbounded();
+    is_bounded && !(has_size_statistics(left) && has_size_statistics(right))
+}
+
+/// Plans an [`AdaptiveHashJoinExec`], which chooses the build side and the
+/// partition mode of `hash_join` at execution time.
+fn adaptive_hash_join(
+    hash_join: &HashJoinExec,
+    partitions: usize,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    let join = HashJoinExec::try_new(
+        Arc::clone(hash_join.left()),
+        Arc::clone(hash_join.right()),
+        hash_join.on().to_vec(),
+        hash_join.filter().cloned(),
+        hash_join.join_type(),
+        hash_join.projection.clone(),
+        PartitionMode::Auto,
+        hash_join.null_equality(),
+    )?;
+    Ok(Arc::new(AdaptiveHashJoinExec::try_new(
+        Arc::new(join),
+        partitions,
+    )?))
+}
+
 /// This subrule tries to modify a given plan so that it can
 /// optimize hash and cross joins in the plan according to available statistical information.
+///
+/// If `adaptive_partitions` is set, hash joins in [`PartitionMode::Auto`]
+/// without size statistics are planned as [`AdaptiveHashJoinExec`] with that
+/// many partitions.
 fn statistical_join_selection_subrule(
     plan: Arc<dyn ExecutionPlan>,
     collect_threshold_byte_size: usize,
     collect_threshold_num_rows: usize,
+    adaptive_partitions: Option<usize>,
 ) -> Result<Transformed<Arc<dyn ExecutionPlan>>> {
     let transformed =
         if let Some(hash_join) = plan.as_any().downcast_ref::<HashJoinExec>() {
             match hash_join.partition_mode() {
-                PartitionMode::Auto => try_collect_left(
-                    hash_join,
-                    false,
-                    collect_threshold_byte_size,
-                    collect_threshold_num_rows,
-                )?
-                .map_or_else(
-                    || partitioned_hash_join(hash_join).map(Some),
-                    |v| Ok(Some(v)),
-                )?,
+                PartitionMode::Auto => {
+                    if let Some(partitions) = adaptive_partitions
+                        && supports_adaptive_join(hash_join)
+                    {
+                        Some(adaptive_hash_join(hash_join, partitions)?)
+                    } else {
+                        try_collect_left(
+                            hash_join,
+                            false,
+                            collect_threshold_byte_size,
+                            collect_threshold_num_rows,
+                        )?
+                        .map_or_else(
+                            || partitioned_hash_join(hash_join).map(Some),
+                            |v| Ok(Some(v)),
+                        )?
+                    }
+                }
                 PartitionMode::CollectLeft => try_collect_left(hash_join, true, 0, 0)?
                     .map_or_else(
                         || partitioned_hash_join(hash_join).map(Some),
@@ -34,7 +34,8 @@ use datafusion_physical_expr::Distribution;
 use datafusion_physical_expr_common::sort_expr::OrderingRequirements;
 use datafusion_physical_plan::execution_plan::Boundedness;
 use datafusion_physical_plan::projection::{
-    ProjectionExec, make_with_child, update_expr, update_ordering_requirement,
+    ProjectionExec, make_with_child, update_expr, update_ordering,
+    update_ordering_requirement,
 };
 use datafusion_physical_plan::sorts::sort::SortExec;
 use datafusion_physical_plan::sorts::sort_preserving_merge::SortPreservingMergeExec;
@@ -288,6 +289,14 @@ impl ExecutionPlan for OutputRequirementExec {
                 }
                 Distribution::HashPartitioned(updated_exprs)
             }
+            Distribution::RangePartitioned(ordering) => {
+                let Some(updated_ordering) =
+                    update_ordering(ordering.clone(), projection.expr())?
+                else {
+                    return Ok(None);
+                };
+                Distribution::RangePartitioned(updated_ordering)
+            }
             dist => dist.clone(),
         };
 
@@ -49,7 +49,7 @@ use arrow::array::{Array, RecordBatch};
 use arrow::datatypes::SchemaRef;
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::{
-    Constraints, DataFusionError, Result, assert_eq_or_internal_err,
+    Constraints, DataFusionError, Result, SampleMethod, assert_eq_or_internal_err,
     assert_or_internal_err, exec_err,
 };
 use datafusion_common_runtime::JoinSet;
@@ -708,6 +708,24 @@ pub trait ExecutionPlan: Debug + DisplayAs + Send + Sync {
     ) -> Result<SortOrderPushdownResult<Arc<dyn ExecutionPlan>>> {
         Ok(SortOrderPushdownResult::Unsupported)
     }
+
+    /// Try to push a random sample of the rows (`TABLESAMPLE`) down into this
+    /// node.
+    ///
+    /// Nodes that can produce the sample more cheaply than a
+    /// [`SampleExec`](crate::sample::SampleExec) on top of them, such as file
+    /// scans that can skip whole blocks of rows, should return a new node
+    /// that only produces the sampled rows. `seed` is the seed of the random
+    /// number generator, or `None` to use a random seed.
+    ///
+    /// Default implementation returns `None`.
+    fn try_pushdown_sample(
+        &self,
+        _method: &SampleMethod,
+        _seed: Option<u64>,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        Ok(None)
+    }
 }
 
 /// [`ExecutionPlan`] Invariant Level
@@ -0,0 +1,608 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, RecordBatch};
+use arrow::compute::concat_batches;
+use arrow::row::{RowConverter, SortField};
+use arrow_schema::{SchemaRef, SortOptions};
+use datafusion_common::{HashMap, Result, internal_err, not_impl_err};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_expr::{JoinType, Operator};
+use datafusion_physical_expr::equivalence::join_equivalence_properties;
+use datafusion_physical_expr::{
+    Distribution, LexOrdering, OrderingRequirements, PhysicalExprRef, PhysicalSortExpr,
+};
+use datafusion_physical_expr_common::physical_expr::fmt_sql;
+use futures::TryStreamExt;
+
+use crate::execution_plan::{EmissionType, boundedness_from_children};
+use crate::joins::asof_join::stream::{AsOfJoinStream, BufferedSide};
+use crate::joins::utils::{BuildProbeJoinMetrics, OnceAsync, build_join_schema};
+use crate::joins::{JoinOn, JoinOnRef};
+use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use crate::spill::get_record_batch_memory_size;
+use crate::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, ExecutionPlanProperties, PlanProperties,
+};
+
+/// `AsOfJoinExec` joins every row of the left input with at most one row of
+/// the right input: among the right rows with equal equijoin keys (`on`), the
+/// nearest one that satisfies the match condition `left_expr op right_expr`,
+/// where `op` is one of [`Operator::GtEq`], [`Operator::Gt`],
+/// [`Operator::LtEq`] or [`Operator::Lt`].
+///
+/// For example, with `left.ts >= right.ts` every left row is joined with the
+/// right row with the latest `right.ts` at or before `left.ts`, as used to
+/// align trades with the latest quote of the same symbol:
+///
+/// ```text
+/// SELECT * FROM trades t
+/// ASOF JOIN quotes q MATCH_CONDITION (t.ts >= q.ts) ON t.sym = q.sym
+/// ```
+///
+/// For a [`JoinType::Left`] join, left rows without a match are emitted with
+/// nulls for the right columns, while [`JoinType::Inner`] drops them.
+///
+/// # Algorithm
+/// Like [`PiecewiseMergeJoinExec`], the right side is buffered and the left
+/// side is streamed. The buffered side is required to be sorted ascending on
+/// the right match expression, which lets the optimizer reuse an existing
+/// ordering of time series data. While buffering, the row indices are grouped
+/// by their equijoin keys, so the indices of every group are sorted by the
+/// match value.
+///
+/// Every streamed row then binary searches its group for the nearest match,
+/// so the output preserves the order of the streamed side:
+///
+/// ```text
+///   Streamed row (sym, ts)      Buffered group of sym = 'A', sorted by ts
+///   ┌─────────────────┐         ┌──────┬──────┬──────┬──────┐
+///   │    ('A', 17)    │ ──────> │  5   │  12  │  20  │  31  │
+///   └─────────────────┘         └──────┴──────┴──────┴──────┘
+///                                         ▲
+///                      `ts >= 12` is the latest ts at or before 17
+/// ```
+///
+/// Rows with a null equijoin key or match value never match.
+///
+/// # Partitioning Logic
+/// The buffered side is collected once into a single partition which is
+/// shared by all partitions of the streamed side.
+///
+/// [`PiecewiseMergeJoinExec`]: crate::joins::PiecewiseMergeJoinExec
+#[derive(Debug)]
+pub struct AsOfJoinExec {
+    /// Left streamed execution plan, every row of which is matched at most once
+    pub left: Arc<dyn ExecutionPlan>,
+    /// Right buffered execution plan, searched for the nearest match
+    pub right: Arc<dyn ExecutionPlan>,
+    /// Equijoin keys as pairs of (left, right) expressions
+    pub on: JoinOn,
+    /// The (left, right) expressions compared by the match condition
+    pub match_on: (PhysicalExprRef, PhysicalExprRef),
+    /// Comparison operator of the match condition
+    pub match_op: Operator,
+    /// How the join is performed, `Inner` or `Left`
+    pub join_type: JoinType,
+    /// The schema once the join is applied
+    schema: SchemaRef,
+    /// Buffered data
+    buffered_fut: OnceAsync<BufferedSideData>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// The buffered side sort order, ascending on the right match expression
+    right_required_order: LexOrdering,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl AsOfJoinExec {
+    pub fn try_new(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        on: JoinOn,
+        match_on: (PhysicalExprRef, PhysicalExprRef),
+        match_op: Operator,
+        join_type: JoinType,
+    ) -> Result<Self> {
+        if !matches!(join_type, JoinType::Inner | JoinType::Left) {
+            return not_impl_err!("AsOfJoinExec does not support join type {join_type}");
+        }
+        if !matches!(
+            match_op,
+            Operator::Gt | Operator::GtEq | Operator::Lt | Operator::LtEq
+        ) {
+            return internal_err!(
+                "Cannot contain non-range operator {match_op} in AsOfJoinExec"
+            );
+        }
+
+        // Nulls never match, so sorting them first lets the grouping skip them
+        let sort_expr =
+            PhysicalSortExpr::new(Arc::clone(&match_on.1), SortOptions::new(false, true));
+        let Some(right_required_order) = LexOrdering::new(vec![sort_expr]) else {
+            return internal_err!(
+                "AsOfJoinExec requires valid sort expressions for its right side"
+            );
+        };
+
+        let schema =
+            Arc::new(build_join_schema(&left.schema(), &right.schema(), &join_type).0);
+        let cache =
+            Self::compute_properties(&left, &right, Arc::clone(&schema), join_type, &on)?;
+
+        Ok(Self {
+            left,
+            right,
+            on,
+            match_on,
+            match_op,
+            join_type,
+            schema,
+            buffered_fut: Default::default(),
+            metrics: ExecutionPlanMetricsSet::new(),
+            right_required_order,
+            cache,
+        })
+    }
+
+    /// Reference to the left (streamed) side execution plan
+    pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.left
+    }
+
+    /// Reference to the right (buffered) side execution plan
+    pub fn right(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.right
+    }
+
+    /// Equijoin keys
+    pub fn on(&self) -> &[(PhysicalExprRef, PhysicalExprRef)] {
+        &self.on
+    }
+
+    /// Expressions compared by the match condition
+    pub fn match_on(&self) -> &(PhysicalExprRef, PhysicalExprRef) {
+        &self.match_on
+    }
+
+    /// Comparison operator of the match condition
+    pub fn match_op(&self) -> Operator {
+        self.match_op
+    }
+
+    /// Join type
+    pub fn join_type(&self) -> JoinType {
+        self.join_type
+    }
+
+    fn compute_properties(
+        left: &Arc<dyn ExecutionPlan>,
+        right: &Arc<dyn ExecutionPlan>,
+        schema: SchemaRef,
+        join_type: JoinType,
+        on: JoinOnRef,
+    ) -> Result<PlanProperties> {
+        // The streamed rows are emitted in their input order, but the right
+        // ordering does not carry over, as every streamed row has its own match
+        let eq_properties = join_equivalence_properties(
+            left.equivalence_properties().clone(),
+            right.equivalence_properties().clone(),
+            &join_type,
+            schema,
+            &[true, false],
+            None,
+            on,
+        )?;
+
+        // The left columns keep their position in the output
+        let output_partitioning = left.output_partitioning().clone();
+
+        Ok(PlanProperties::new(
+            eq_properties,
+            output_partitioning,
+            EmissionType::Incremental,
+            boundedness_from_children([left, right]),
+        ))
+    }
+}
+
+impl ExecutionPlan for AsOfJoinExec {
+    fn name(&self) -> &str {
+        "AsOfJoinExec"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.left, &self.right]
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        vec![
+            Distribution::UnspecifiedDistribution,
+            Distribution::SinglePartition,
+        ]
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<OrderingRequirements>> {
+        vec![
+            None,
+            Some(OrderingRequirements::from(
+                self.right_required_order.clone(),
+            )),
+        ]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true, false]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        match &children[..] {
+            [left, right] => Ok(Arc::new(AsOfJoinExec::try_new(
+                Arc::clone(left),
+                Arc::clone(right),
+                self.on.clone(),
+                self.match_on.clone(),
+                self.match_op,
+                self.join_type,
+            )?)),
+            _ => internal_err!(
+                "AsOfJoinExec should have 2 children, found {}",
+                children.len()
+            ),
+        }
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
+        let buffered_fut = self.buffered_fut.try_once(|| {
+            let reservation =
+                MemoryConsumer::new("AsOfJoinInput").register(context.memory_pool());
+
+            let buffered_stream = self.right.execute(0, Arc::clone(&context))?;
+            Ok(build_buffered_data(
+                buffered_stream,
+                self.on.iter().map(|(_, r)| Arc::clone(r)).collect(),
+                Arc::clone(&self.match_on.1),
+                metrics.clone(),
+                reservation,
+            ))
+        })?;
+
+        let streamed = self.left.execute(partition, Arc::clone(&context))?;
+
+        Ok(Box::pin(AsOfJoinStream::new(
+            Arc::clone(&self.schema),
+            self.on.iter().map(|(l, _)| Arc::clone(l)).collect(),
+            Arc::clone(&self.match_on.0),
+            self.match_op,
+            self.join_type,
+            streamed,
+            BufferedSide::Initial(buffered_fut),
+            metrics,
+        )))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+impl DisplayAs for AsOfJoinExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
+        let match_on = format!(
+            "({} {} {})",
+            fmt_sql(self.match_on.0.as_ref()),
+            self.match_op,
+            fmt_sql(self.match_on.1.as_ref())
+        );
+
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let on = self
+                    .on
+                    .iter()
+                    .map(|(c1, c2)| format!("({c1}, {c2})"))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "AsOfJoinExec: join_type={:?}, on=[{}], match_on={}",
+                    self.join_type, on, match_on
+                )
+            }
+            DisplayFormatType::TreeRender => {
+                let on = self
+                    .on
+                    .iter()
+                    .map(|(c1, c2)| {
+                        format!("({} = {})", fmt_sql(c1.as_ref()), fmt_sql(c2.as_ref()))
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                if self.join_type != JoinType::Inner {
+                    writeln!(f, "join_type={:?}", self.join_type)?;
+                }
+                if !on.is_empty() {
+                    writeln!(f, "on={on}")?;
+                }
+                writeln!(f, "match_on={match_on}")
+            }
+        }
+    }
+}
+
+async fn build_buffered_data(
+    buffered: SendableRecordBatchStream,
+    on_buffered: Vec<PhysicalExprRef>,
+    match_on_buffered: PhysicalExprRef,
+    metrics: BuildProbeJoinMetrics,
+    reservation: MemoryReservation,
+) -> Result<BufferedSideData> {
+    let schema = buffered.schema();
+
+    // Combine batches and record number of rows
+    let initial = (Vec::new(), 0, metrics, reservation);
+    let (batches, num_rows, metrics, mut reservation) = buffered
+        .try_fold(initial, |mut acc, batch| async {
+            let batch_size = get_record_batch_memory_size(&batch);
+            acc.3.try_grow(batch_size)?;
+            acc.2.build_mem_used.add(batch_size);
+            acc.2.build_input_batches.add(1);
+            acc.2.build_input_rows.add(batch.num_rows());
+            // Update row count
+            acc.1 += batch.num_rows();
+            // Push batch to output
+            acc.0.push(batch);
+            Ok(acc)
+        })
+        .await?;
+
+    let single_batch = concat_batches(&schema, batches.iter())?;
+
+    let match_values = match_on_buffered
+        .evaluate(&single_batch)?
+        .into_array(num_rows)?;
+    let keys = on_buffered
+        .iter()
+        .map(|expr| expr.evaluate(&single_batch)?.into_array(num_rows))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Without equijoin keys, all rows form a single group
+    let row_converter = if keys.is_empty() {
+        None
+    } else {
+        let sort_fields = keys
+            .iter()
+            .map(|key| SortField::new(key.data_type().clone()))
+            .collect();
+        Some(RowConverter::new(sort_fields)?)
+    };
+    let rows = row_converter
+        .as_ref()
+        .map(|converter| converter.convert_columns(&keys))
+        .transpose()?;
+
+    // The buffered side is sorted by the match value, so the indices of every
+    // group are sorted as well
+    let mut groups: HashMap<Box<[u8]>, Vec<u32>> = HashMap::new();
+    for idx in 0..num_rows {
+        if match_values.is_null(idx) || keys.iter().any(|key| key.is_null(idx)) {
+            continue;
+        }
+        let group_key = match &rows {
+            Some(rows) => rows.row(idx).as_ref().into(),
+            None => Box::default(),
+        };
+        groups.entry(group_key).or_default().push(idx as u32);
+    }
+
+    // We add the single batch size + the memory of the match values, the
+    // encoded keys and the grouped indices
+    let size_estimation = get_record_batch_memory_size(&single_batch)
+        + match_values.get_array_memory_size()
+        + rows.as_ref().map_or(0, |rows| rows.size())
+        + num_rows * size_of::<u32>();
+    reservation.try_grow(size_estimation)?;
+    metrics.build_mem_used.add(size_estimation);
+
+    Ok(BufferedSideData {
+        batch: single_batch,
+        match_values,
+        row_converter,
+        groups,
+        _reservation: reservation,
+    })
+}
+
+/// The collected buffered side of an [`AsOfJoinExec`]
+pub(super) struct BufferedSideData {
+    /// All buffered rows, sorted by the match value
+    pub(super) batch: RecordBatch,
+    /// The match values of `batch`
+    pub(super) match_values: ArrayRef,
+    /// Converts equijoin keys to the row format used as keys of `groups`, or
+    /// `None` if the join has no equijoin keys
+    pub(super) row_converter: Option<RowConverter>,
+    /// Indices of the rows of `batch` with non-null keys and match values,
+    /// grouped by the equijoin keys and sorted by the match value
+    pub(super) groups: HashMap<Box<[u8]>, Vec<u32>>,
+    _reservation: MemoryReservation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common;
+    use crate::test::{TestMemoryExec, build_table_i32};
+    use datafusion_common::assert_contains;
+    use datafusion_common::test_util::batches_to_string;
+    use datafusion_physical_expr::expressions::Column;
+    use insta::assert_snapshot;
+
+    fn build_table(
+        a: (&str, &Vec<i32>),
+        b: (&str, &Vec<i32>),
+        c: (&str, &Vec<i32>),
+    ) -> Arc<dyn ExecutionPlan> {
+        let batch = build_table_i32(a, b, c);
+        let schema = batch.schema();
+        TestMemoryExec::try_new_exec(&[vec![batch]], schema, None).unwrap()
+    }
+
+    fn trades() -> Arc<dyn ExecutionPlan> {
+        build_table(
+            ("a1", &vec![1, 1, 1, 2, 2]),
+            ("b1", &vec![10, 20, 30, 5, 15]),
+            ("c1", &vec![1, 2, 3, 4, 5]),
+        )
+    }
+
+    // The buffered side is sorted by `b2`
+    fn quotes() -> Arc<dyn ExecutionPlan> {
+        build_table(
+            ("a2", &vec![1, 2, 1, 1, 2]),
+            ("b2", &vec![5, 6, 15, 20, 30]),
+            ("c2", &vec![100, 200, 300, 400, 500]),
+        )
+    }
+
+    async fn join_collect(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        on: JoinOn,
+        match_op: Operator,
+        join_type: JoinType,
+    ) -> Result<Vec<RecordBatch>> {
+        let match_on = (
+            Arc::new(Column::new_with_schema("b1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("b2", &right.schema())?) as _,
+        );
+        let join = AsOfJoinExec::try_new(left, right, on, match_on, match_op, join_type)?;
+        let stream = join.execute(0, Arc::new(TaskContext::default()))?;
+        common::collect(stream).await
+    }
+
+    fn equijoin_keys(
+        left: &Arc<dyn ExecutionPlan>,
+        right: &Arc<dyn ExecutionPlan>,
+    ) -> Result<JoinOn> {
+        Ok(vec![(
+            Arc::new(Column::new_with_schema("a1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("a2", &right.schema())?) as _,
+        )])
+    }
+
+    #[tokio::test]
+    async fn join_left_greater_than_or_equal() -> Result<()> {
+        let (left, right) = (trades(), quotes());
+        let on = equijoin_keys(&left, &right)?;
+        let batches =
+            join_collect(left, right, on, Operator::GtEq, JoinType::Left).await?;
+
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+----+----+----+----+-----+
+        | a1 | b1 | c1 | a2 | b2 | c2  |
+        +----+----+----+----+----+-----+
+        | 1  | 10 | 1  | 1  | 5  | 100 |
+        | 1  | 20 | 2  | 1  | 20 | 400 |
+        | 1  | 30 | 3  | 1  | 20 | 400 |
+        | 2  | 5  | 4  |    |    |     |
+        | 2  | 15 | 5  | 2  | 6  | 200 |
+        +----+----+----+----+----+-----+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_left_greater_than() -> Result<()> {
+        let (left, right) = (trades(), quotes());
+        let on = equijoin_keys(&left, &right)?;
+        let batches = join_collect(left, right, on, Operator::Gt, JoinType::Left).await?;
+
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+----+----+----+----+-----+
+        | a1 | b1 | c1 | a2 | b2 | c2  |
+        +----+----+----+----+----+-----+
+        | 1  | 10 | 1  | 1  | 5  | 100 |
+        | 1  | 20 | 2  | 1  | 15 | 300 |
+        | 1  | 30 | 3  | 1  | 20 | 400 |
+        | 2  | 5  | 4  |    |    |     |
+        | 2  | 15 | 5  | 2  | 6  | 200 |
+        +----+----+----+----+----+-----+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_inner_less_than_without_equijoin_keys() -> Result<()> {
+        let batches =
+            join_collect(trades(), quotes(), vec![], Operator::Lt, JoinType::Inner)
+                .await?;
+
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+----+----+----+----+-----+
+        | a1 | b1 | c1 | a2 | b2 | c2  |
+        +----+----+----+----+----+-----+
+        | 1  | 10 | 1  | 1  | 15 | 300 |
+        | 1  | 20 | 2  | 2  | 30 | 500 |
+        | 2  | 5  | 4  | 2  | 6  | 200 |
+        | 2  | 15 | 5  | 1  | 20 | 400 |
+        +----+----+----+----+----+-----+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_unsupported_join_type() -> Result<()> {
+        let (left, right) = (trades(), quotes());
+        let match_on = (
+            Arc::new(Column::new_with_schema("b1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("b2", &right.schema())?) as _,
+        );
+        let err = AsOfJoinExec::try_new(
+            left,
+            right,
+            vec![],
+            match_on,
+            Operator::GtEq,
+            JoinType::Full,
+        )
+        .unwrap_err();
+        assert_contains!(err.to_string(), "does not support join type");
+        Ok(())
+    }
+}
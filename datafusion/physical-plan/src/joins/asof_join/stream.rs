@@ -0,0 +1,270 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Stream implementation for [`AsOfJoinExec`](super::AsOfJoinExec)
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+use std::task::{Context, Poll, ready};
+
+use arrow::array::{Array, RecordBatch, UInt32Array, make_comparator};
+use arrow::compute::{SortOptions, take, take_record_batch};
+use arrow_schema::SchemaRef;
+use datafusion_common::{Result, internal_err};
+use datafusion_execution::{RecordBatchStream, SendableRecordBatchStream};
+use datafusion_expr::{JoinType, Operator};
+use datafusion_physical_expr::PhysicalExprRef;
+use futures::{Stream, StreamExt};
+
+use crate::handle_state;
+use crate::joins::asof_join::exec::BufferedSideData;
+use crate::joins::utils::{BuildProbeJoinMetrics, OnceFut, StatefulStreamResult};
+
+pub(super) enum BufferedSide {
+    /// Indicates that the buffered side is not collected yet
+    Initial(OnceFut<BufferedSideData>),
+    /// Indicates that the buffered side has been collected
+    Ready(Arc<BufferedSideData>),
+}
+
+enum AsOfJoinStreamState {
+    WaitBufferedSide,
+    FetchStreamBatch,
+    Completed,
+}
+
+// `AsOfJoinStreamState` is separated into `WaitBufferedSide`, `FetchStreamBatch`
+// and `Completed`.
+//  1. `WaitBufferedSide` - Load the buffered side data into memory.
+//  2. `FetchStreamBatch` - Fetch incoming stream batches and join each of them
+//     with the buffered side. Switches to `Completed` once the stream is exhausted.
+pub(super) struct AsOfJoinStream {
+    // Output schema of the `AsOfJoinExec`
+    schema: SchemaRef,
+    // Equijoin key expressions evaluated on the streamed side
+    on_streamed: Vec<PhysicalExprRef>,
+    // Match expression evaluated on the streamed side
+    match_on_streamed: PhysicalExprRef,
+    // Comparison operator of the match condition
+    match_op: Operator,
+    // Type of join
+    join_type: JoinType,
+    // Streamed batches
+    streamed: SendableRecordBatchStream,
+    // Buffered side data
+    buffered_side: BufferedSide,
+    // Tracks the state of the `AsOfJoinStream`
+    state: AsOfJoinStreamState,
+    // Metrics for build + probe joins
+    join_metrics: BuildProbeJoinMetrics,
+}
+
+impl AsOfJoinStream {
+    #[expect(clippy::too_many_arguments)]
+    pub(super) fn new(
+        schema: SchemaRef,
+        on_streamed: Vec<PhysicalExprRef>,
+        match_on_streamed: PhysicalExprRef,
+        match_op: Operator,
+        join_type: JoinType,
+        streamed: SendableRecordBatchStream,
+        buffered_side: BufferedSide,
+        join_metrics: BuildProbeJoinMetrics,
+    ) -> Self {
+        Self {
+            schema,
+            on_streamed,
+            match_on_streamed,
+            match_op,
+            join_type,
+            streamed,
+            buffered_side,
+            state: AsOfJoinStreamState::WaitBufferedSide,
+            join_metrics,
+        }
+    }
+
+    fn poll_next_impl(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        loop {
+            return match self.state {
+                AsOfJoinStreamState::WaitBufferedSide => {
+                    handle_state!(ready!(self.collect_buffered_side(cx)))
+                }
+                AsOfJoinStreamState::FetchStreamBatch => {
+                    handle_state!(ready!(self.fetch_stream_batch(cx)))
+                }
+                AsOfJoinStreamState::Completed => Poll::Ready(None),
+            };
+        }
+    }
+
+    // Collects buffered side data
+    fn collect_buffered_side(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<StatefulStreamResult<Option<RecordBatch>>>> {
+        let BufferedSide::Initial(buffered_fut) = &mut self.buffered_side else {
+            return Poll::Ready(internal_err!("Expected buffered side in initial state"));
+        };
+        let build_timer = self.join_metrics.build_time.timer();
+        let buffered_data = ready!(buffered_fut.get_shared(cx))?;
+        build_timer.done();
+
+        self.buffered_side = BufferedSide::Ready(buffered_data);
+        self.state = AsOfJoinStreamState::FetchStreamBatch;
+        Poll::Ready(Ok(StatefulStreamResult::Continue))
+    }
+
+    // Fetches incoming stream batches and joins them with the buffered side
+    fn fetch_stream_batch(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<StatefulStreamResult<Option<RecordBatch>>>> {
+        match ready!(self.streamed.poll_next_unpin(cx)) {
+            None => {
+                self.state = AsOfJoinStreamState::Completed;
+                Poll::Ready(Ok(StatefulStreamResult::Continue))
+            }
+            Some(Ok(batch)) => {
+                self.join_metrics.input_batches.add(1);
+                self.join_metrics.input_rows.add(batch.num_rows());
+
+                let timer = self.join_metrics.join_time.timer();
+                let result = self.join_stream_batch(&batch)?;
+                timer.done();
+
+                if result.num_rows() == 0 {
+                    Poll::Ready(Ok(StatefulStreamResult::Continue))
+                } else {
+                    Poll::Ready(Ok(StatefulStreamResult::Ready(Some(result))))
+                }
+            }
+            Some(Err(err)) => Poll::Ready(Err(err)),
+        }
+    }
+
+    // Finds the match of every row of the stream batch and builds the output
+    fn join_stream_batch(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let BufferedSide::Ready(buffered_data) = &self.buffered_side else {
+            return internal_err!("Expected buffered side in ready state");
+        };
+        let num_rows = batch.num_rows();
+
+        let match_values = self
+            .match_on_streamed
+            .evaluate(batch)?
+            .into_array(num_rows)?;
+        let keys = self
+            .on_streamed
+            .iter()
+            .map(|expr| expr.evaluate(batch)?.into_array(num_rows))
+            .collect::<Result<Vec<_>>>()?;
+        let rows = buffered_data
+            .row_converter
+            .as_ref()
+            .map(|converter| converter.convert_columns(&keys))
+            .transpose()?;
+
+        let compare = make_comparator(
+            match_values.as_ref(),
+            buffered_data.match_values.as_ref(),
+            SortOptions::default(),
+        )?;
+
+        let mut streamed_indices = Vec::with_capacity(num_rows);
+        let mut buffered_indices = Vec::with_capacity(num_rows);
+        for idx in 0..num_rows {
+            let group =
+                if match_values.is_null(idx) || keys.iter().any(|key| key.is_null(idx)) {
+                    None
+                } else {
+                    match &rows {
+                        Some(rows) => buffered_data.groups.get(rows.row(idx).as_ref()),
+                        None => buffered_data.groups.get(&[] as &[u8]),
+                    }
+                };
+
+            let matched = group.and_then(|group| {
+                // Compares the streamed match value with a buffered one
+                let cmp = |buffered_idx: &u32| compare(idx, *buffered_idx as usize);
+                match self.match_op {
+                    // The last buffered value at or below the streamed value
+                    Operator::GtEq => {
+                        let end = group.partition_point(|i| cmp(i) != Ordering::Less);
+                        end.checked_sub(1).map(|pos| group[pos])
+                    }
+                    // The last buffered value below the streamed value
+                    Operator::Gt => {
+                        let end = group.partition_point(|i| cmp(i) == Ordering::Greater);
+                        end.checked_sub(1).map(|pos| group[pos])
+                    }
+                    // The first buffered value at or above the streamed value
+                    Operator::LtEq => {
+                        let start =
+                            group.partition_point(|i| cmp(i) == Ordering::Greater);
+                        group.get(start).copied()
+                    }
+                    // The first buffered value above the streamed value, as the
+                    // operator is `Operator::Lt`
+                    _ => {
+                        let start = group.partition_point(|i| cmp(i) != Ordering::Less);
+                        group.get(start).copied()
+                    }
+                }
+            });
+
+            if matched.is_some() || self.join_type == JoinType::Left {
+                streamed_indices.push(idx as u32);
+                buffered_indices.push(matched);
+            }
+        }
+
+        let streamed_batch = if streamed_indices.len() == num_rows {
+            batch.clone()
+        } else {
+            take_record_batch(batch, &UInt32Array::from(streamed_indices))?
+        };
+        let buffered_indices = UInt32Array::from(buffered_indices);
+        let mut columns = streamed_batch.columns().to_vec();
+        for column in buffered_data.batch.columns() {
+            columns.push(take(column.as_ref(), &buffered_indices, None)?);
+        }
+
+        Ok(RecordBatch::try_new(Arc::clone(&self.schema), columns)?)
+    }
+}
+
+impl RecordBatchStream for AsOfJoinStream {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+impl Stream for AsOfJoinStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.poll_next_impl(cx);
+        self.join_metrics.baseline.record_poll(poll)
+    }
+}
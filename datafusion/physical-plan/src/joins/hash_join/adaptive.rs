@@ -0,0 +1,795 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`AdaptiveHashJoinExec`], which chooses the build side and the partition
+//! mode of a hash join from the size of its inputs at execution time
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
+use datafusion_common::{JoinType, NullEquality, Result, internal_err};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_physical_expr::{EquivalenceProperties, Partitioning};
+use datafusion_physical_expr_common::physical_expr::fmt_sql;
+use futures::future::{poll_fn, try_join, try_join_all};
+use futures::{Stream, StreamExt, TryStreamExt};
+use parking_lot::Mutex;
+
+use crate::coalesce_partitions::CoalescePartitionsExec;
+use crate::joins::utils::OnceAsync;
+use crate::joins::{HashJoinExec, PartitionMode};
+use crate::metrics::{Count, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
+use crate::repartition::RepartitionExec;
+use crate::spill::get_record_batch_memory_size;
+use crate::stream::RecordBatchStreamAdapter;
+use crate::streaming::{PartitionStream, StreamingTableExec};
+use crate::{
+    DisplayAs, DisplayFormatType, ExecutionPlan, ExecutionPlanProperties, PlanProperties,
+};
+
+/// `AdaptiveHashJoinExec` executes a [`HashJoinExec`] planned in
+/// [`PartitionMode::Auto`] whose build side and partition mode are chosen
+/// once the sizes of its inputs are known, rather than from statistics.
+///
+/// The `JoinSelection` rule plans it when the statistics of a join input are
+/// [`Precision::Absent`] and `datafusion.optimizer.adaptive_join_selection`
+/// is enabled.
+///
+/// # Algorithm
+/// Before the join starts, both inputs are read concurrently and buffered
+/// until they end or until more than
+/// `datafusion.optimizer.hash_join_single_partition_threshold` bytes of them
+/// are buffered, so at most about twice that size is held in memory:
+///
+/// * If the right input ends and is smaller than the left input, or the left
+///   input does not end, the inputs are swapped, as
+///   [`HashJoinExec::swap_inputs`] does, so it becomes the build side.
+/// * Otherwise the left input stays the build side.
+///
+/// The join runs in [`PartitionMode::CollectLeft`] when the build side ended
+/// and is below `datafusion.optimizer.hash_join_single_partition_threshold`
+/// and `datafusion.optimizer.hash_join_single_partition_threshold_rows`, and
+/// in [`PartitionMode::Partitioned`] otherwise, with the inputs
+/// repartitioned as the `EnforceDistribution` rule would. In particular, if
+/// neither input ends, the join runs as if it had been planned in
+/// [`PartitionMode::Partitioned`]. The rest of the inputs is streamed after
+/// the buffered batches.
+///
+/// # Partitioning Logic
+/// The output has a fixed number of partitions, usually the target
+/// partitions of the session, whichever plan is chosen.
+///
+/// [`Precision::Absent`]: datafusion_common::stats::Precision::Absent
+#[derive(Debug)]
+pub struct AdaptiveHashJoinExec {
+    /// The join as planned, in [`PartitionMode::Auto`]
+    join: Arc<HashJoinExec>,
+    /// The number of output partitions
+    partitions: usize,
+    /// The join chosen once the inputs are buffered
+    join_fut: OnceAsync<ChosenJoin>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl AdaptiveHashJoinExec {
+    /// Creates an adaptive join executing `join` with `partitions` output
+    /// partitions
+    pub fn try_new(join: Arc<HashJoinExec>, partitions: usize) -> Result<Self> {
+        if *join.partition_mode() != PartitionMode::Auto {
+            return internal_err!(
+                "AdaptiveHashJoinExec requires a HashJoinExec in PartitionMode::Auto, got {:?}",
+                join.partition_mode()
+            );
+        }
+        if partitions == 0 {
+            return internal_err!(
+                "AdaptiveHashJoinExec requires at least one output partition"
+            );
+        }
+        let cache = Self::compute_properties(&join, partitions);
+        Ok(Self {
+            join,
+            partitions,
+            join_fut: Default::default(),
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// The join as planned, in [`PartitionMode::Auto`]
+    pub fn join(&self) -> &Arc<HashJoinExec> {
+        &self.join
+    }
+
+    /// The number of output partitions
+    pub fn partitions(&self) -> usize {
+        self.partitions
+    }
+
+    fn compute_properties(join: &HashJoinExec, partitions: usize) -> PlanProperties {
+        // Swapping the inputs changes which orderings and equivalences hold,
+        // so none are kept
+        PlanProperties::new(
+            EquivalenceProperties::new(join.schema()),
+            Partitioning::UnknownPartitioning(partitions),
+            join.properties().emission_type,
+            join.properties().boundedness,
+        )
+    }
+}
+
+impl DisplayAs for AdaptiveHashJoinExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        let join = &self.join;
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let on = join
+                    .on()
+                    .iter()
+                    .map(|(c1, c2)| format!("({c1}, {c2})"))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let display_filter = join.filter().map_or_else(
+                    || "".to_string(),
+                    |f| format!(", filter={}", f.expression()),
+                );
+                let display_projections = match &join.projection {
+                    Some(projection) => format!(
+                        ", projection=[{}]",
+                        projection
+                            .iter()
+                            .map(|index| format!(
+                                "{}@{}",
+                                join.join_schema().field(*index).name(),
+                                index
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    None => "".to_string(),
+                };
+                let display_null_equality =
+                    if matches!(join.null_equality(), NullEquality::NullEqualsNull) {
+                        ", NullsEqual: true"
+                    } else {
+                        ""
+                    };
+                write!(
+                    f,
+                    "AdaptiveHashJoinExec: partitions={}, join_type={:?}, on=[{}]{}{}{}",
+                    self.partitions,
+                    join.join_type(),
+                    on,
+                    display_filter,
+                    display_projections,
+                    display_null_equality,
+                )
+            }
+            DisplayFormatType::TreeRender => {
+                let on = join
+                    .on()
+                    .iter()
+                    .map(|(c1, c2)| {
+                        format!("({} = {})", fmt_sql(c1.as_ref()), fmt_sql(c2.as_ref()))
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                if *join.join_type() != JoinType::Inner {
+                    writeln!(f, "join_type={:?}", join.join_type())?;
+                }
+
+                writeln!(f, "on={on}")?;
+
+                if let Some(filter) = join.filter() {
+                    writeln!(f, "filter={filter}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for AdaptiveHashJoinExec {
+    fn name(&self) -> &'static str {
+        "AdaptiveHashJoinExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![self.join.left(), self.join.right()]
+    }
+
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        // The inputs are repartitioned once the join is chosen
+        vec![false, false]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let [left, right] = &children[..] else {
+            return internal_err!(
+                "AdaptiveHashJoinExec should have 2 children, found {}",
+                children.len()
+            );
+        };
+        let join = HashJoinExec::try_new(
+            Arc::clone(left),
+            Arc::clone(right),
+            self.join.on().to_vec(),
+            self.join.filter().cloned(),
+            self.join.join_type(),
+            self.join.projection.clone(),
+            PartitionMode::Auto,
+            self.join.null_equality(),
+        )?;
+        Ok(Arc::new(Self::try_new(Arc::new(join), self.partitions)?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        if partition >= self.partitions {
+            return internal_err!(
+                "Invalid partition {partition} for AdaptiveHashJoinExec with {} partitions",
+                self.partitions
+            );
+        }
+
+        let mut join_fut = self.join_fut.try_once(|| {
+            let left = execute_all(self.join.left(), &context)?;
+            let right = execute_all(self.join.right(), &context)?;
+            let reservation = MemoryConsumer::new("AdaptiveHashJoinInput")
+                .register(context.memory_pool());
+            Ok(choose_join(
+                Arc::clone(&self.join),
+                left,
+                right,
+                self.partitions,
+                AdaptiveJoinMetrics::new(&self.metrics),
+                reservation,
+                Arc::clone(&context),
+            ))
+        })?;
+
+        let stream = futures::stream::once(async move {
+            let chosen = poll_fn(|cx| join_fut.get_shared(cx)).await?;
+            chosen.plan.execute(partition, context)
+        })
+        .try_flatten();
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            self.schema(),
+            stream,
+        )))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+/// Metrics for [`AdaptiveHashJoinExec`]
+#[derive(Clone, Debug)]
+struct AdaptiveJoinMetrics {
+    /// Number of left rows buffered before the join was chosen
+    left_buffered_rows: Count,
+    /// Number of right rows buffered before the join was chosen
+    right_buffered_rows: Count,
+    /// 1 if the inputs were swapped, 0 otherwise
+    swapped_inputs: Count,
+    /// 1 if the join was run in [`PartitionMode::CollectLeft`], 0 otherwise
+    collect_left: Count,
+}
+
+impl AdaptiveJoinMetrics {
+    fn new(metrics: &ExecutionPlanMetricsSet) -> Self {
+        Self {
+            left_buffered_rows: MetricBuilder::new(metrics)
+                .global_counter("left_buffered_rows"),
+            right_buffered_rows: MetricBuilder::new(metrics)
+                .global_counter("right_buffered_rows"),
+            swapped_inputs: MetricBuilder::new(metrics).global_counter("swapped_inputs"),
+            collect_left: MetricBuilder::new(metrics).global_counter("collect_left"),
+        }
+    }
+}
+
+/// The join an [`AdaptiveHashJoinExec`] runs, over its buffered inputs
+struct ChosenJoin {
+    plan: Arc<dyn ExecutionPlan>,
+}
+
+fn execute_all(
+    plan: &Arc<dyn ExecutionPlan>,
+    context: &Arc<TaskContext>,
+) -> Result<Vec<SendableRecordBatchStream>> {
+    (0..plan.output_partitioning().partition_count())
+        .map(|partition| plan.execute(partition, Arc::clone(context)))
+        .collect()
+}
+
+/// Buffers the inputs of `join` and plans the join to run over them
+async fn choose_join(
+    join: Arc<HashJoinExec>,
+    left: Vec<SendableRecordBatchStream>,
+    right: Vec<SendableRecordBatchStream>,
+    partitions: usize,
+    metrics: AdaptiveJoinMetrics,
+    reservation: MemoryReservation,
+    context: Arc<TaskContext>,
+) -> Result<ChosenJoin> {
+    let options = &context.session_config().options().optimizer;
+    let limit = options.hash_join_single_partition_threshold;
+    let (left, right) = try_join(
+        BufferedInputs::read(left, limit, &reservation),
+        BufferedInputs::read(right, limit, &reservation),
+    )
+    .await?;
+    metrics.left_buffered_rows.add(left.num_rows);
+    metrics.right_buffered_rows.add(right.num_rows);
+
+    let swap = join.join_type().supports_swap()
+        && right.is_complete()
+        && (!left.is_complete() || right.num_bytes < left.num_bytes);
+    let build = if swap { &right } else { &left };
+    let mode = if build.is_complete()
+        && build.num_bytes < options.hash_join_single_partition_threshold
+        && build.num_rows < options.hash_join_single_partition_threshold_rows
+    {
+        PartitionMode::CollectLeft
+    } else {
+        PartitionMode::Partitioned
+    };
+    if swap {
+        metrics.swapped_inputs.add(1);
+    }
+    if mode == PartitionMode::CollectLeft {
+        metrics.collect_left.add(1);
+    }
+
+    let (left_keys, right_keys): (Vec<_>, Vec<_>) = join
+        .on()
+        .iter()
+        .map(|(l, r)| (Arc::clone(l), Arc::clone(r)))
+        .unzip();
+    let left = left.into_plan(join.left().schema())?;
+    let right = right.into_plan(join.right().schema())?;
+    let (left, right) = match (mode, swap) {
+        (PartitionMode::CollectLeft, false) => (
+            collect_input(left),
+            distribute_input(right, Partitioning::RoundRobinBatch(partitions))?,
+        ),
+        (PartitionMode::CollectLeft, true) => (
+            distribute_input(left, Partitioning::RoundRobinBatch(partitions))?,
+            collect_input(right),
+        ),
+        _ => (
+            distribute_input(left, Partitioning::Hash(left_keys, partitions))?,
+            distribute_input(right, Partitioning::Hash(right_keys, partitions))?,
+        ),
+    };
+
+    let chosen = HashJoinExec::try_new(
+        left,
+        right,
+        join.on().to_vec(),
+        join.filter().cloned(),
+        join.join_type(),
+        join.projection.clone(),
+        mode,
+        join.null_equality(),
+    )?;
+    let plan = if swap {
+        chosen.swap_inputs(mode)?
+    } else {
+        Arc::new(chosen)
+    };
+    Ok(ChosenJoin { plan })
+}
+
+fn collect_input(input: Arc<dyn ExecutionPlan>) -> Arc<dyn ExecutionPlan> {
+    if input.output_partitioning().partition_count() == 1 {
+        input
+    } else {
+        Arc::new(CoalescePartitionsExec::new(input))
+    }
+}
+
+fn distribute_input(
+    input: Arc<dyn ExecutionPlan>,
+    partitioning: Partitioning,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    Ok(Arc::new(RepartitionExec::try_new(input, partitioning)?))
+}
+
+/// All partitions of a join input, as buffered while choosing the join
+struct BufferedInputs {
+    partitions: Vec<BufferedInput>,
+    num_rows: usize,
+    num_bytes: usize,
+}
+
+impl BufferedInputs {
+    /// Reads all `streams` concurrently, until they end or until more than
+    /// `limit` bytes are buffered across all of them
+    async fn read(
+        streams: Vec<SendableRecordBatchStream>,
+        limit: usize,
+        reservation: &MemoryReservation,
+    ) -> Result<Self> {
+        let num_bytes = AtomicUsize::new(0);
+        let partitions = try_join_all(streams.into_iter().map(|stream| {
+            BufferedInput::read(stream, &num_bytes, limit, reservation.new_empty())
+        }))
+        .await?;
+        let num_rows = partitions.iter().map(|p| p.num_rows).sum();
+        Ok(Self {
+            partitions,
+            num_rows,
+            num_bytes: num_bytes.into_inner(),
+        })
+    }
+
+    /// Returns true if every partition was read to the end
+    fn is_complete(&self) -> bool {
+        self.partitions.iter().all(|p| p.rest.is_none())
+    }
+
+    /// Returns a plan replaying the buffered batches of every partition,
+    /// followed by the rest of the partition
+    fn into_plan(self, schema: SchemaRef) -> Result<Arc<dyn ExecutionPlan>> {
+        let partitions = self
+            .partitions
+            .into_iter()
+            .map(|input| {
+                Arc::new(BufferedPartition {
+                    schema: Arc::clone(&schema),
+                    input: Mutex::new(Some(input)),
+                }) as Arc<dyn PartitionStream>
+            })
+            .collect();
+        Ok(Arc::new(StreamingTableExec::try_new(
+            schema,
+            partitions,
+            None,
+            vec![],
+            false,
+            None,
+        )?))
+    }
+}
+
+/// A partition of a join input, as buffered while choosing the join
+struct BufferedInput {
+    batches: Vec<RecordBatch>,
+    /// The rest of the partition, or `None` if it was read to the end
+    rest: Option<SendableRecordBatchStream>,
+    num_rows: usize,
+    /// Holds the memory of `batches`
+    reservation: MemoryReservation,
+}
+
+impl BufferedInput {
+    /// Reads `stream` until it ends or until more than `limit` bytes are
+    /// buffered, counting the bytes buffered by all partitions in `num_bytes`
+    async fn read(
+        mut stream: SendableRecordBatchStream,
+        num_bytes: &AtomicUsize,
+        limit: usize,
+        mut reservation: MemoryReservation,
+    ) -> Result<Self> {
+        let mut batches = vec![];
+        let mut num_rows = 0;
+        while num_bytes.load(Ordering::Relaxed) <= limit {
+            let Some(batch) = stream.next().await.transpose()? else {
+                return Ok(Self {
+                    batches,
+                    rest: None,
+                    num_rows,
+                    reservation,
+                });
+            };
+            let batch_size = get_record_batch_memory_size(&batch);
+            reservation.try_grow(batch_size)?;
+            num_bytes.fetch_add(batch_size, Ordering::Relaxed);
+            num_rows += batch.num_rows();
+            batches.push(batch);
+        }
+        Ok(Self {
+            batches,
+            rest: Some(stream),
+            num_rows,
+            reservation,
+        })
+    }
+
+    /// Returns the buffered batches, releasing their memory as they are
+    /// consumed, followed by the rest of the partition
+    fn into_stream(self) -> impl Stream<Item = Result<RecordBatch>> + Send + 'static {
+        let Self {
+            batches,
+            rest,
+            mut reservation,
+            ..
+        } = self;
+        futures::stream::iter(batches)
+            .map(move |batch| {
+                reservation.shrink(get_record_batch_memory_size(&batch));
+                Ok(batch)
+            })
+            .chain(futures::stream::iter(rest).flatten())
+    }
+}
+
+/// Replays a [`BufferedInput`], which can only be executed once
+struct BufferedPartition {
+    schema: SchemaRef,
+    input: Mutex<Option<BufferedInput>>,
+}
+
+impl fmt::Debug for BufferedPartition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferedPartition")
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartitionStream for BufferedPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _ctx: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let schema = Arc::clone(&self.schema);
+        match self.input.lock().take() {
+            Some(input) => {
+                Box::pin(RecordBatchStreamAdapter::new(schema, input.into_stream()))
+            }
+            None => Box::pin(RecordBatchStreamAdapter::new(
+                schema,
+                futures::stream::once(async {
+                    internal_err!("Buffered join input executed more than once")
+                }),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common;
+    use crate::expressions::Column;
+    use crate::test::{TestMemoryExec, build_table_i32};
+    use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::runtime_env::RuntimeEnvBuilder;
+    use datafusion_physical_expr::PhysicalExprRef;
+
+    /// Builds a table of `num_batches` copies of a batch of the given
+    /// columns, split into two partitions
+    fn build_table(
+        a: (&str, &Vec<i32>),
+        b: (&str, &Vec<i32>),
+        c: (&str, &Vec<i32>),
+        num_batches: usize,
+    ) -> Arc<dyn ExecutionPlan> {
+        let batch = build_table_i32(a, b, c);
+        let schema = batch.schema();
+        let batches = vec![batch; num_batches];
+        let (first, second) = batches.split_at(num_batches / 2);
+        TestMemoryExec::try_new_exec(&[first.to_vec(), second.to_vec()], schema, None)
+            .unwrap()
+    }
+
+    fn adaptive_join(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        join_type: JoinType,
+    ) -> Result<AdaptiveHashJoinExec> {
+        let on: Vec<(PhysicalExprRef, PhysicalExprRef)> = vec![(
+            Arc::new(Column::new_with_schema("b1", &left.schema())?),
+            Arc::new(Column::new_with_schema("b2", &right.schema())?),
+        )];
+        let join = HashJoinExec::try_new(
+            left,
+            right,
+            on,
+            None,
+            &join_type,
+            None,
+            PartitionMode::Auto,
+            NullEquality::NullEqualsNothing,
+        )?;
+        AdaptiveHashJoinExec::try_new(Arc::new(join), 3)
+    }
+
+    fn task_ctx(single_partition_threshold_rows: usize) -> Arc<TaskContext> {
+        let mut config = SessionConfig::new();
+        config
+            .options_mut()
+            .optimizer
+            .hash_join_single_partition_threshold_rows = single_partition_threshold_rows;
+        Arc::new(TaskContext::default().with_session_config(config))
+    }
+
+    async fn collect(
+        join: &AdaptiveHashJoinExec,
+        context: Arc<TaskContext>,
+    ) -> Result<Vec<RecordBatch>> {
+        let mut batches = vec![];
+        for partition in 0..join.partitions() {
+            let stream = join.execute(partition, Arc::clone(&context))?;
+            batches.extend(common::collect(stream).await?);
+        }
+        Ok(batches)
+    }
+
+    fn metric(join: &AdaptiveHashJoinExec, name: &str) -> usize {
+        join.metrics()
+            .unwrap()
+            .sum_by_name(name)
+            .map(|m| m.as_usize())
+            .unwrap()
+    }
+
+    fn num_rows(batches: &[RecordBatch]) -> usize {
+        batches.iter().map(|b| b.num_rows()).sum()
+    }
+
+    #[tokio::test]
+    async fn swaps_to_the_smaller_input() -> Result<()> {
+        let big = build_table(
+            ("a1", &vec![1, 2, 3]),
+            ("b1", &vec![4, 5, 6]),
+            ("c1", &vec![7, 8, 9]),
+            8,
+        );
+        let small = build_table(
+            ("a2", &vec![10, 20]),
+            ("b2", &vec![4, 6]),
+            ("c2", &vec![70, 90]),
+            2,
+        );
+        let join = adaptive_join(big, small, JoinType::Inner)?;
+        let batches = collect(&join, task_ctx(1024)).await?;
+
+        // Every big row with b1 = 4 or 6 matches both copies of a small row
+        assert_eq!(num_rows(&batches), 32);
+        assert!(batches.iter().all(|b| b.schema() == join.schema()));
+        assert_eq!(metric(&join, "swapped_inputs"), 1);
+        assert_eq!(metric(&join, "collect_left"), 1);
+        assert_eq!(metric(&join, "left_buffered_rows"), 24);
+        assert_eq!(metric(&join, "right_buffered_rows"), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keeps_the_smaller_left_input() -> Result<()> {
+        let small = build_table(
+            ("a1", &vec![1, 2]),
+            ("b1", &vec![4, 7]),
+            ("c1", &vec![7, 8]),
+            2,
+        );
+        let big = build_table(
+            ("a2", &vec![10, 20, 30]),
+            ("b2", &vec![4, 5, 6]),
+            ("c2", &vec![70, 80, 90]),
+            8,
+        );
+        let join = adaptive_join(small, big, JoinType::Left)?;
+        // The build side has too many rows to be collected into one partition
+        let batches = collect(&join, task_ctx(1)).await?;
+
+        // Both copies of b1 = 4 match 8 rows, both copies of b1 = 7 match none
+        assert_eq!(num_rows(&batches), 18);
+        let unmatched = batches
+            .iter()
+            .map(|b| b.column_by_name("a2").unwrap().null_count())
+            .sum::<usize>();
+        assert_eq!(unmatched, 2);
+        assert_eq!(metric(&join, "swapped_inputs"), 0);
+        assert_eq!(metric(&join, "collect_left"), 0);
+        assert_eq!(metric(&join, "left_buffered_rows"), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_partitioned_join() -> Result<()> {
+        let left = build_table(
+            ("a1", &vec![1, 2]),
+            ("b1", &vec![4, 7]),
+            ("c1", &vec![7, 8]),
+            8,
+        );
+        let right = build_table(
+            ("a2", &vec![10, 20, 30]),
+            ("b2", &vec![4, 5, 6]),
+            ("c2", &vec![70, 80, 90]),
+            8,
+        );
+        let join = adaptive_join(left, right, JoinType::Inner)?;
+        // Both inputs are larger than the threshold, so neither is read to
+        // the end
+        let mut config = SessionConfig::new();
+        config.options_mut().optimizer.hash_join_single_partition_threshold = 1;
+        let context = Arc::new(TaskContext::default().with_session_config(config));
+        let batches = collect(&join, context).await?;
+
+        assert_eq!(num_rows(&batches), 64);
+        assert_eq!(metric(&join, "swapped_inputs"), 0);
+        assert_eq!(metric(&join, "collect_left"), 0);
+        assert!(metric(&join, "left_buffered_rows") < 16);
+        assert!(metric(&join, "right_buffered_rows") < 24);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn buffers_a_bounded_part_of_the_inputs() -> Result<()> {
+        let values = (0..1000).collect::<Vec<_>>();
+        let big = build_table(("a1", &values), ("b1", &values), ("c1", &values), 40);
+        let small = build_table(("a2", &values), ("b2", &values), ("c2", &values), 2);
+        let batch_size = get_record_batch_memory_size(&build_table_i32(
+            ("a1", &values),
+            ("b1", &values),
+            ("c1", &values),
+        ));
+        let join = adaptive_join(big, small, JoinType::Inner)?;
+
+        // The big input does not fit in memory, but only a few of its batches
+        // are buffered before the small input is chosen as the build side
+        let mut config = SessionConfig::new();
+        config.options_mut().optimizer.hash_join_single_partition_threshold =
+            4 * batch_size;
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_limit(20 * batch_size, 1.0)
+            .build_arc()?;
+        let context = TaskContext::default()
+            .with_session_config(config)
+            .with_runtime(runtime);
+        let batches = collect(&join, Arc::new(context)).await?;
+
+        // Every row of the big input matches both copies of a small row
+        assert_eq!(num_rows(&batches), 80_000);
+        assert_eq!(metric(&join, "swapped_inputs"), 1);
+        assert_eq!(metric(&join, "collect_left"), 1);
+        assert_eq!(metric(&join, "right_buffered_rows"), 2000);
+        assert!(metric(&join, "left_buffered_rows") <= 6000);
+        Ok(())
+    }
+}
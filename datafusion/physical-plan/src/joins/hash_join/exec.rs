@@ -31,6 +31,9 @@ use crate::joins::hash_join::inlist_builder::build_struct_inlist_values;
 use crate::joins::hash_join::shared_bounds::{
     ColumnBounds, PartitionBounds, PushdownStrategy, SharedBuildAccumulator,
 };
+use crate::joins::hash_join::spill::{
+    HashJoinSpillConfig, HashPartitionWriter, SpilledBuildSide,
+};
 use crate::joins::hash_join::stream::{
     BuildSide, BuildSideInitialState, HashJoinStream, HashJoinStreamState,
 };
@@ -83,7 +86,7 @@ use datafusion_physical_expr::{PhysicalExpr, PhysicalExprRef};
 use ahash::RandomState;
 use datafusion_physical_expr_common::physical_expr::fmt_sql;
 use datafusion_physical_expr_common::utils::evaluate_expressions_to_arrays;
-use futures::TryStreamExt;
+use futures::StreamExt;
 use parking_lot::Mutex;
 
 use super::partitioned_hash_eval::SeededRandomState;
@@ -118,6 +121,10 @@ pub(super) struct JoinLeftData {
     /// Membership testing strategy for filter pushdown
     /// Contains either InList values for small build sides or hash table reference for large build sides
     pub(super) membership: PushdownStrategy,
+    /// Set when the build side did not fit in memory and was spilled to disk.
+    /// In this case the hash table and batch are empty, and the join is instead
+    /// performed partition by partition, see [`super::spill`]
+    spilled: Option<SpilledBuildSide>,
 }
 
 impl JoinLeftData {
@@ -151,6 +158,11 @@ impl JoinLeftData {
     pub(super) fn report_probe_completed(&self) -> bool {
         self.probe_threads_counter.fetch_sub(1, Ordering::Relaxed) == 1
     }
+
+    /// returns the spilled build side, if the build side did not fit in memory
+    pub(super) fn spilled_build_side(&self) -> Option<&SpilledBuildSide> {
+        self.spilled.as_ref()
+    }
 }
 
 #[expect(rustdoc::private_intra_doc_links)]
@@ -310,6 +322,19 @@ impl JoinLeftData {
 ///                       └───────────────┘     └───────────────┘
 /// ```
 ///
+/// # Spilling
+///
+/// If the memory pool refuses to grow the build side, and a `DiskManager` is
+/// available, the build side and then the probe side are split into hash
+/// partitions on the join keys and written to disk. The matching partitions are
+/// then joined one at a time, so only one build-side partition is held in
+/// memory. The number of partitions is controlled by
+/// `datafusion.execution.hash_join_spill_partitions`.
+///
+/// Spilling does not preserve the order of the probe side, so it is disabled
+/// when the join has an output ordering. It is also disabled in
+/// [`PartitionMode::CollectLeft`] if the probe side has multiple partitions.
+///
 /// # Clone / Shared State
 ///
 /// Note this structure includes a [`OnceAsync`] that is used to coordinate the
@@ -948,13 +973,36 @@ impl ExecutionPlan for HashJoinExec {
                 .map(|df| df.filter.is_used())
                 .unwrap_or(false);
 
+        // Spilling partitions both inputs by hash, which does not preserve the
+        // order of the probe side. It also requires each build side to be probed
+        // by a single output stream, as the spilled partitions are joined by it.
+        let spill_partitions = context
+            .session_config()
+            .options()
+            .execution
+            .hash_join_spill_partitions;
+        let can_spill = spill_partitions > 1
+            && context.runtime_env().disk_manager.tmp_files_enabled()
+            && self.cache.output_ordering().is_none()
+            && (self.mode == PartitionMode::Partitioned || right_partitions == 1);
+        let spill_config = can_spill.then(|| {
+            HashJoinSpillConfig::new(
+                context.runtime_env(),
+                self.metrics.clone(),
+                partition,
+                context.session_config().spill_compression(),
+                spill_partitions,
+            )
+        });
+
         let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
         let left_fut = match self.mode {
             PartitionMode::CollectLeft => self.left_fut.try_once(|| {
                 let left_stream = self.left.execute(0, Arc::clone(&context))?;
 
-                let reservation =
-                    MemoryConsumer::new("HashJoinInput").register(context.memory_pool());
+                let reservation = MemoryConsumer::new("HashJoinInput")
+                    .with_can_spill(spill_config.is_some())
+                    .register(context.memory_pool());
 
                 Ok(collect_left_input(
                     self.random_state.random_state().clone(),
@@ -975,6 +1023,7 @@ impl ExecutionPlan for HashJoinExec {
                         .options()
                         .optimizer
                         .hash_join_inlist_pushdown_max_distinct_values,
+                    spill_config,
                 ))
             })?,
             PartitionMode::Partitioned => {
@@ -982,6 +1031,7 @@ impl ExecutionPlan for HashJoinExec {
 
                 let reservation =
                     MemoryConsumer::new(format!("HashJoinInput[{partition}]"))
+                        .with_can_spill(spill_config.is_some())
                         .register(context.memory_pool());
 
                 OnceFut::new(collect_left_input(
@@ -1003,6 +1053,7 @@ impl ExecutionPlan for HashJoinExec {
                         .options()
                         .optimizer
                         .hash_join_inlist_pushdown_max_distinct_values,
+                    spill_config,
                 ))
             }
             PartitionMode::Auto => {
@@ -1362,6 +1413,69 @@ impl BuildSideState {
                 .transpose()?,
         })
     }
+
+    /// Writes the buffered batches to a new [`HashPartitionWriter`] and releases
+    /// their memory. All further build-side batches must be appended to the
+    /// returned writer.
+    fn spill(
+        &mut self,
+        config: &HashJoinSpillConfig,
+        on_left: &[PhysicalExprRef],
+        schema: &SchemaRef,
+    ) -> Result<HashPartitionWriter> {
+        let mut writer = config.partition_writer(on_left.to_vec(), Arc::clone(schema));
+        for batch in std::mem::take(&mut self.batches) {
+            writer.append(&batch)?;
+        }
+        self.num_rows = 0;
+        self.reservation.free();
+        self.metrics.build_mem_used.set(0);
+        Ok(writer)
+    }
+
+    /// Finishes spilling the build side and creates the [`JoinLeftData`]
+    /// describing the spilled partitions
+    fn into_spilled(
+        self,
+        writer: HashPartitionWriter,
+        config: &HashJoinSpillConfig,
+        on_left: Vec<PhysicalExprRef>,
+        schema: &SchemaRef,
+        with_visited_indices_bitmap: bool,
+        probe_threads_count: usize,
+    ) -> Result<JoinLeftData> {
+        // Bounds are still valid, as every build-side batch updated the accumulators
+        let bounds = self
+            .bounds_accumulators
+            .map(|accumulators| {
+                accumulators
+                    .into_iter()
+                    .map(CollectLeftAccumulator::evaluate)
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?
+            .map(PartitionBounds::new);
+
+        let spilled = SpilledBuildSide {
+            partitions: writer.finish()?,
+            config: config.clone(),
+            on_left,
+            with_visited_indices_bitmap,
+            reservation: self.reservation.new_empty(),
+        };
+
+        Ok(JoinLeftData {
+            hash_map: Arc::new(JoinHashMapU32::with_capacity(0)),
+            batch: RecordBatch::new_empty(Arc::clone(schema)),
+            values: vec![],
+            visited_indices_bitmap: Mutex::new(BooleanBufferBuilder::new(0)),
+            probe_threads_counter: AtomicUsize::new(probe_threads_count),
+            _reservation: self.reservation,
+            bounds,
+            membership: PushdownStrategy::Spilled,
+            spilled: Some(spilled),
+        })
+    }
 }
 
 /// Collects all batches from the left (build) side stream and creates a hash map for joining.
@@ -1381,6 +1495,7 @@ impl BuildSideState {
 /// * `with_visited_indices_bitmap` - Whether to track visited indices (for outer joins)
 /// * `probe_threads_count` - Number of threads that will probe this hash table
 /// * `should_compute_dynamic_filters` - Whether to compute min/max bounds for dynamic filtering
+/// * `spill_config` - Configuration for spilling the build side, `None` if spilling is disabled
 ///
 /// # Dynamic Filter Coordination
 /// When `should_compute_dynamic_filters` is true, this function computes the min/max bounds
@@ -1389,13 +1504,19 @@ impl BuildSideState {
 /// `SharedBuildAccumulator` to ensure all partitions contribute their bounds
 /// before updating the filter exactly once.
 ///
+/// # Spilling
+/// When `spill_config` is set and the memory pool refuses to grow `reservation`,
+/// the buffered batches and the remainder of the stream are split into hash
+/// partitions and written to disk. The returned `JoinLeftData` then holds the
+/// [`SpilledBuildSide`] instead of a hash table.
+///
 /// # Returns
 /// `JoinLeftData` containing the hash map, consolidated batch, join key values,
 /// visited indices bitmap, and computed bounds (if requested).
 #[expect(clippy::too_many_arguments)]
-async fn collect_left_input(
+pub(super) async fn collect_left_input(
     random_state: RandomState,
-    left_stream: SendableRecordBatchStream,
+    mut left_stream: SendableRecordBatchStream,
     on_left: Vec<PhysicalExprRef>,
     metrics: BuildProbeJoinMetrics,
     reservation: MemoryReservation,
@@ -1404,13 +1525,14 @@ async fn collect_left_input(
     should_compute_dynamic_filters: bool,
     max_inlist_size: usize,
     max_inlist_distinct_values: usize,
+    spill_config: Option<HashJoinSpillConfig>,
 ) -> Result<JoinLeftData> {
     let schema = left_stream.schema();
 
     // This operation performs 2 steps at once:
     // 1. creates a [JoinHashMap] of all batches from the stream
     // 2. stores the batches in a vector.
-    let initial = BuildSideState::try_new(
+    let mut state = BuildSideState::try_new(
         metrics,
         reservation,
         on_left.clone(),
@@ -1418,30 +1540,81 @@ async fn collect_left_input(
         should_compute_dynamic_filters,
     )?;
 
-    let state = left_stream
-        .try_fold(initial, |mut state, batch| async move {
-            // Update accumulators if computing bounds
-            if let Some(ref mut accumulators) = state.bounds_accumulators {
-                for accumulator in accumulators {
-                    accumulator.update_batch(&batch)?;
-                }
+    // Set once the build side has been spilled, all further batches are written to disk
+    let mut spill_writer: Option<HashPartitionWriter> = None;
+
+    while let Some(batch) = left_stream.next().await {
+        let batch = batch?;
+
+        // Update accumulators if computing bounds
+        if let Some(ref mut accumulators) = state.bounds_accumulators {
+            for accumulator in accumulators {
+                accumulator.update_batch(&batch)?;
             }
+        }
+        state.metrics.build_input_batches.add(1);
+        state.metrics.build_input_rows.add(batch.num_rows());
 
-            // Decide if we spill or not
-            let batch_size = get_record_batch_memory_size(&batch);
-            // Reserve memory for incoming batch
-            state.reservation.try_grow(batch_size)?;
-            // Update metrics
-            state.metrics.build_mem_used.add(batch_size);
-            state.metrics.build_input_batches.add(1);
-            state.metrics.build_input_rows.add(batch.num_rows());
-            // Update row count
-            state.num_rows += batch.num_rows();
-            // Push batch to output
-            state.batches.push(batch);
-            Ok(state)
-        })
-        .await?;
+        if let Some(writer) = spill_writer.as_mut() {
+            writer.append(&batch)?;
+            continue;
+        }
+
+        // Decide if we spill or not
+        let batch_size = get_record_batch_memory_size(&batch);
+        // Reserve memory for incoming batch
+        if let Err(e) = state.reservation.try_grow(batch_size) {
+            let Some(config) = &spill_config else {
+                return Err(e);
+            };
+            let mut writer = state.spill(config, &on_left, &schema)?;
+            writer.append(&batch)?;
+            spill_writer = Some(writer);
+            continue;
+        }
+        // Update metrics
+        state.metrics.build_mem_used.add(batch_size);
+        // Update row count
+        state.num_rows += batch.num_rows();
+        // Push batch to output
+        state.batches.push(batch);
+    }
+
+    if let (Some(writer), Some(config)) = (spill_writer, &spill_config) {
+        return state.into_spilled(
+            writer,
+            config,
+            on_left,
+            &schema,
+            with_visited_indices_bitmap,
+            probe_threads_count,
+        );
+    }
+
+    // Estimation of memory size, required for hashtable, prior to allocation.
+    // Final result can be verified using `RawTable.allocation_info()`
+    let fixed_size_u32 = size_of::<JoinHashMapU32>();
+    let fixed_size_u64 = size_of::<JoinHashMapU64>();
+    let estimated_hashtable_size = if state.num_rows > u32::MAX as usize {
+        estimate_memory_size::<(u64, u64)>(state.num_rows, fixed_size_u64)?
+    } else {
+        estimate_memory_size::<(u32, u64)>(state.num_rows, fixed_size_u32)?
+    };
+    if let Err(e) = state.reservation.try_grow(estimated_hashtable_size) {
+        let Some(config) = &spill_config else {
+            return Err(e);
+        };
+        let writer = state.spill(config, &on_left, &schema)?;
+        return state.into_spilled(
+            writer,
+            config,
+            on_left,
+            &schema,
+            with_visited_indices_bitmap,
+            probe_threads_count,
+        );
+    }
+    state.metrics.build_mem_used.add(estimated_hashtable_size);
 
     // Extract fields from state
     let BuildSideState {
@@ -1452,25 +1625,12 @@ async fn collect_left_input(
         bounds_accumulators,
     } = state;
 
-    // Estimation of memory size, required for hashtable, prior to allocation.
-    // Final result can be verified using `RawTable.allocation_info()`
-    let fixed_size_u32 = size_of::<JoinHashMapU32>();
-    let fixed_size_u64 = size_of::<JoinHashMapU64>();
-
     // Use `u32` indices for the JoinHashMap when num_rows ≤ u32::MAX, otherwise use the
     // `u64` indice variant
     // Arc is used instead of Box to allow sharing with SharedBuildAccumulator for hash map pushdown
     let mut hashmap: Box<dyn JoinHashMapType> = if num_rows > u32::MAX as usize {
-        let estimated_hashtable_size =
-            estimate_memory_size::<(u64, u64)>(num_rows, fixed_size_u64)?;
-        reservation.try_grow(estimated_hashtable_size)?;
-        metrics.build_mem_used.add(estimated_hashtable_size);
         Box::new(JoinHashMapU64::with_capacity(num_rows))
     } else {
-        let estimated_hashtable_size =
-            estimate_memory_size::<(u32, u64)>(num_rows, fixed_size_u32)?;
-        reservation.try_grow(estimated_hashtable_size)?;
-        metrics.build_mem_used.add(estimated_hashtable_size);
         Box::new(JoinHashMapU32::with_capacity(num_rows))
     };
 
@@ -1559,6 +1719,7 @@ async fn collect_left_input(
         _reservation: reservation,
         bounds,
         membership,
+        spilled: None,
     };
 
     Ok(data)
@@ -1586,6 +1747,7 @@ mod tests {
         exec_err, internal_err,
     };
     use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::memory_pool::FairSpillPool;
     use datafusion_execution::runtime_env::RuntimeEnvBuilder;
     use datafusion_expr::Operator;
     use datafusion_physical_expr::PhysicalExpr;
@@ -4370,7 +4532,11 @@ mod tests {
             let runtime = RuntimeEnvBuilder::new()
                 .with_memory_limit(100, 1.0)
                 .build_arc()?;
-            let task_ctx = TaskContext::default().with_runtime(runtime);
+            let session_config = SessionConfig::default()
+                .set_usize("datafusion.execution.hash_join_spill_partitions", 0);
+            let task_ctx = TaskContext::default()
+                .with_session_config(session_config)
+                .with_runtime(runtime);
             let task_ctx = Arc::new(task_ctx);
 
             let join = join(
@@ -4445,7 +4611,9 @@ mod tests {
             let runtime = RuntimeEnvBuilder::new()
                 .with_memory_limit(100, 1.0)
                 .build_arc()?;
-            let session_config = SessionConfig::default().with_batch_size(50);
+            let session_config = SessionConfig::default()
+                .with_batch_size(50)
+                .set_usize("datafusion.execution.hash_join_spill_partitions", 0);
             let task_ctx = TaskContext::default()
                 .with_session_config(session_config)
                 .with_runtime(runtime);
@@ -4480,6 +4648,170 @@ mod tests {
         Ok(())
     }
 
+    /// Builds a table with `num_rows` rows in batches of 100 rows, where the
+    /// join key `b` cycles through `num_keys` values
+    fn build_spill_table(
+        names: (&str, &str, &str),
+        num_rows: i32,
+        num_keys: i32,
+        partitions: usize,
+    ) -> Arc<dyn ExecutionPlan> {
+        // rows are assigned to partitions by key, so that matching keys are
+        // always located in the same partition
+        let mut partitioned_batches = vec![vec![]; partitions];
+        for start in (0..num_rows).step_by(100) {
+            let rows = start..(start + 100).min(num_rows);
+            for (partition, batches) in partitioned_batches.iter_mut().enumerate() {
+                let a = rows
+                    .clone()
+                    .filter(|row| (row % num_keys) as usize % partitions == partition)
+                    .collect::<Vec<_>>();
+                let b = a.iter().map(|row| row % num_keys).collect::<Vec<_>>();
+                let c = a.iter().map(|row| row * 10).collect::<Vec<_>>();
+                batches.push(build_table_i32(
+                    (names.0, &a),
+                    (names.1, &b),
+                    (names.2, &c),
+                ));
+            }
+        }
+        let schema = partitioned_batches[0][0].schema();
+        TestMemoryExec::try_new_exec(&partitioned_batches, schema, None).unwrap()
+    }
+
+    async fn spill_join_collect(
+        mode: PartitionMode,
+        join_type: JoinType,
+        filter: Option<JoinFilter>,
+        memory_limit: Option<usize>,
+    ) -> Result<(Vec<RecordBatch>, MetricsSet)> {
+        let partitions = match mode {
+            PartitionMode::Partitioned => 2,
+            _ => 1,
+        };
+        let left = build_spill_table(("a1", "b1", "c1"), 2000, 700, partitions);
+        let right = build_spill_table(("a2", "b2", "c2"), 1000, 1000, partitions);
+        let on = vec![(
+            Arc::new(Column::new_with_schema("b1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("b2", &right.schema())?) as _,
+        )];
+
+        let mut runtime = RuntimeEnvBuilder::new();
+        if let Some(memory_limit) = memory_limit {
+            runtime =
+                runtime.with_memory_pool(Arc::new(FairSpillPool::new(memory_limit)));
+        }
+        let task_ctx = Arc::new(
+            TaskContext::default()
+                .with_session_config(SessionConfig::default().with_batch_size(64))
+                .with_runtime(runtime.build_arc()?),
+        );
+
+        let join = HashJoinExec::try_new(
+            left,
+            right,
+            on,
+            filter,
+            &join_type,
+            None,
+            mode,
+            NullEquality::NullEqualsNothing,
+        )?;
+
+        let mut batches = vec![];
+        for partition in 0..partitions {
+            let stream = join.execute(partition, Arc::clone(&task_ctx))?;
+            batches.extend(common::collect(stream).await?);
+        }
+        Ok((batches, join.metrics().unwrap()))
+    }
+
+    #[tokio::test]
+    async fn join_spill_build_side() -> Result<()> {
+        let join_types = vec![
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
+            JoinType::LeftMark,
+            JoinType::RightMark,
+        ];
+
+        for mode in [PartitionMode::CollectLeft, PartitionMode::Partitioned] {
+            for join_type in &join_types {
+                let (expected, expected_metrics) =
+                    spill_join_collect(mode, *join_type, None, None).await?;
+                assert_eq!(expected_metrics.spill_count(), None);
+
+                let (batches, metrics) =
+                    spill_join_collect(mode, *join_type, None, Some(8 * 1024)).await?;
+                assert!(
+                    metrics.spill_count().unwrap() > 0,
+                    "expected {join_type} join to spill in {mode:?} mode"
+                );
+                assert_eq!(
+                    batches_to_sort_string(&batches),
+                    batches_to_sort_string(&expected),
+                    "unexpected result for {join_type} join in {mode:?} mode"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_spill_build_side_with_filter() -> Result<()> {
+        for join_type in [JoinType::Inner, JoinType::Left, JoinType::Full] {
+            let (expected, _) = spill_join_collect(
+                PartitionMode::Partitioned,
+                join_type,
+                Some(prepare_join_filter()),
+                None,
+            )
+            .await?;
+            let (batches, metrics) = spill_join_collect(
+                PartitionMode::Partitioned,
+                join_type,
+                Some(prepare_join_filter()),
+                Some(8 * 1024),
+            )
+            .await?;
+            assert!(metrics.spill_count().unwrap() > 0);
+            assert_eq!(
+                batches_to_sort_string(&batches),
+                batches_to_sort_string(&expected)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_spill_build_side_exhausted() -> Result<()> {
+        // Partitions can not be made small enough to fit into the memory limit
+        let err = spill_join_collect(
+            PartitionMode::CollectLeft,
+            JoinType::Inner,
+            None,
+            Some(100),
+        )
+        .await
+        .unwrap_err();
+
+        assert_contains!(
+            err.to_string(),
+            "Resources exhausted: Failed to allocate additional"
+        );
+        assert_contains!(err.to_string(), "for HashJoinInput");
+
+        Ok(())
+    }
+
     fn build_table_struct(
         struct_name: &str,
         field_name_and_values: (&str, &Vec<Option<i32>>),
@@ -17,11 +17,14 @@
 
 //! [`HashJoinExec`] Partitioned Hash Join Operator
 
+pub use adaptive::AdaptiveHashJoinExec;
 pub use exec::HashJoinExec;
 pub use partitioned_hash_eval::{HashExpr, HashTableLookupExpr, SeededRandomState};
 
+mod adaptive;
 mod exec;
 mod inlist_builder;
 mod partitioned_hash_eval;
 mod shared_bounds;
+mod spill;
 mod stream;
@@ -142,7 +142,7 @@ fn create_membership_predicate(
             )) as Arc<dyn PhysicalExpr>))
         }
         // Empty partition - should not create a filter for this
-        PushdownStrategy::Empty => Ok(None),
+        PushdownStrategy::Empty | PushdownStrategy::Spilled => Ok(None),
     }
 }
 
@@ -244,6 +244,9 @@ pub(crate) enum PushdownStrategy {
     HashTable(Arc<dyn JoinHashMapType>),
     /// There was no data in this partition, do not build a dynamic filter for it
     Empty,
+    /// The build side was spilled to disk, so no membership check is available.
+    /// Only the bounds are used for this partition
+    Spilled,
 }
 
 /// Build-side data reported by a single partition
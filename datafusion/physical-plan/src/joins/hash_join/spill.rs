@@ -0,0 +1,257 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Spilling support for [`super::HashJoinExec`]
+//!
+//! When the build side of a hash join does not fit in memory, the join falls
+//! back to a "grace" hash join:
+//!
+//! 1. The build side is split into hash partitions on the join keys, and each
+//!    partition is written to its own spill file.
+//! 2. The probe side is split with the same hash function and written to disk.
+//! 3. Each pair of matching build / probe partitions is joined with a regular
+//!    in-memory hash join, one pair at a time.
+//!
+//! A build partition which still does not fit in memory is partitioned again
+//! with a different hash seed, up to [`MAX_SPILL_LEVEL`] times.
+
+use std::sync::Arc;
+
+use crate::hash_utils::create_hashes;
+use crate::metrics::{ExecutionPlanMetricsSet, SpillMetrics};
+use crate::spill::in_progress_spill_file::InProgressSpillFile;
+use crate::{EmptyRecordBatchStream, SendableRecordBatchStream, SpillManager};
+
+use arrow::array::UInt32Array;
+use arrow::compute::take_record_batch;
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use datafusion_common::Result;
+use datafusion_common::config::SpillCompression;
+use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::memory_pool::MemoryReservation;
+use datafusion_execution::runtime_env::RuntimeEnv;
+use datafusion_physical_expr::PhysicalExprRef;
+use datafusion_physical_expr_common::utils::evaluate_expressions_to_arrays;
+
+use ahash::RandomState;
+use parking_lot::Mutex;
+
+/// Maximum number of times the rows of a single build-side partition are
+/// re-partitioned before giving up and reporting the memory error.
+///
+/// Re-partitioning can not help when a single join key is too large to fit in
+/// memory, so the recursion needs to stop at some point.
+pub(super) const MAX_SPILL_LEVEL: usize = 3;
+
+/// Configuration for spilling the inputs of a hash join
+#[derive(Debug, Clone)]
+pub(super) struct HashJoinSpillConfig {
+    /// Runtime environment providing the disk manager
+    runtime: Arc<RuntimeEnv>,
+    /// Metrics of the join, spill metrics are only registered once spilling happens
+    metrics: ExecutionPlanMetricsSet,
+    /// Output partition the metrics are reported for
+    partition: usize,
+    /// Compression codec for the spill files
+    compression: SpillCompression,
+    /// Number of hash partitions each input is split into
+    num_partitions: usize,
+    /// Number of times the rows have been re-partitioned already
+    level: usize,
+}
+
+impl HashJoinSpillConfig {
+    pub(super) fn new(
+        runtime: Arc<RuntimeEnv>,
+        metrics: ExecutionPlanMetricsSet,
+        partition: usize,
+        compression: SpillCompression,
+        num_partitions: usize,
+    ) -> Self {
+        Self {
+            runtime,
+            metrics,
+            partition,
+            compression,
+            num_partitions,
+            level: 0,
+        }
+    }
+
+    /// Returns the configuration used to re-partition a partition produced
+    /// with this configuration, or `None` if the maximum depth is reached
+    pub(super) fn next_level(&self) -> Option<Self> {
+        (self.level + 1 < MAX_SPILL_LEVEL).then(|| Self {
+            level: self.level + 1,
+            ..self.clone()
+        })
+    }
+
+    /// Random state used to assign rows to partitions.
+    ///
+    /// The seeds differ from the ones used by the hash table and by
+    /// `RepartitionExec`, and from level to level, so that the rows of a single
+    /// partition are spread across all partitions of the next level.
+    fn random_state(&self) -> RandomState {
+        let level = self.level as u64;
+        RandomState::with_seeds('S' as u64, 'P' as u64, 'I' as u64 + level, 'L' as u64)
+    }
+
+    /// Creates a writer splitting batches of `schema` into hash partitions on `on`
+    pub(super) fn partition_writer(
+        &self,
+        on: Vec<PhysicalExprRef>,
+        schema: SchemaRef,
+    ) -> HashPartitionWriter {
+        let spill_manager = SpillManager::new(
+            Arc::clone(&self.runtime),
+            SpillMetrics::new(&self.metrics, self.partition),
+            schema,
+        )
+        .with_compression_type(self.compression);
+
+        HashPartitionWriter {
+            on,
+            random_state: self.random_state(),
+            spill_manager,
+            files: (0..self.num_partitions).map(|_| None).collect(),
+            hashes_buffer: vec![],
+        }
+    }
+}
+
+/// Splits record batches into hash partitions, appending the rows of each
+/// partition to a dedicated spill file
+pub(super) struct HashPartitionWriter {
+    /// Expressions the rows are partitioned on
+    on: Vec<PhysicalExprRef>,
+    /// Random state used to hash the partitioning expressions
+    random_state: RandomState,
+    /// Spill manager creating the spill files
+    spill_manager: SpillManager,
+    /// Lazily created spill file of each partition
+    files: Vec<Option<InProgressSpillFile>>,
+    /// Scratch space for computing hashes
+    hashes_buffer: Vec<u64>,
+}
+
+impl HashPartitionWriter {
+    /// Appends the rows of `batch` to the spill files of their partitions
+    pub(super) fn append(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let values = evaluate_expressions_to_arrays(&self.on, batch)?;
+        self.hashes_buffer.clear();
+        self.hashes_buffer.resize(batch.num_rows(), 0);
+        create_hashes(&values, &self.random_state, &mut self.hashes_buffer)?;
+
+        let num_partitions = self.files.len();
+        let mut indices = vec![vec![]; num_partitions];
+        for (row, hash) in self.hashes_buffer.iter().enumerate() {
+            indices[(*hash % num_partitions as u64) as usize].push(row as u32);
+        }
+
+        for (partition, indices) in indices.into_iter().enumerate() {
+            if indices.is_empty() {
+                continue;
+            }
+            let partition_batch = take_record_batch(batch, &UInt32Array::from(indices))?;
+            let file = match &mut self.files[partition] {
+                Some(file) => file,
+                file => file.insert(
+                    self.spill_manager
+                        .create_in_progress_file("HashJoin partition spill")?,
+                ),
+            };
+            file.append_batch(&partition_batch)?;
+        }
+
+        Ok(())
+    }
+
+    /// Finalizes the spill files of all partitions
+    pub(super) fn finish(self) -> Result<SpilledPartitions> {
+        let files = self
+            .files
+            .into_iter()
+            .map(|file| match file {
+                Some(mut file) => file.finish(),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(SpilledPartitions {
+            spill_manager: self.spill_manager,
+            files: Mutex::new(files),
+        })
+    }
+}
+
+/// Hash partitions of a join input that have been written to disk
+pub(super) struct SpilledPartitions {
+    /// Spill manager used to read the partitions back
+    spill_manager: SpillManager,
+    /// Spill file of each partition, `None` for empty or already consumed partitions
+    files: Mutex<Vec<Option<RefCountedTempFile>>>,
+}
+
+impl SpilledPartitions {
+    /// Returns the number of partitions
+    pub(super) fn num_partitions(&self) -> usize {
+        self.files.lock().len()
+    }
+
+    /// Removes the spill file of `partition`, the file is deleted from disk
+    /// once the returned stream (if any) is dropped
+    pub(super) fn take_partition(&self, partition: usize) -> Option<RefCountedTempFile> {
+        self.files.lock()[partition].take()
+    }
+
+    /// Reads back a partition previously removed with [`Self::take_partition`].
+    ///
+    /// Empty partitions produce an empty stream.
+    pub(super) fn read_partition(
+        &self,
+        file: Option<RefCountedTempFile>,
+    ) -> Result<SendableRecordBatchStream> {
+        match file {
+            Some(file) => self.spill_manager.read_spill_as_stream(file, None),
+            None => Ok(Box::pin(EmptyRecordBatchStream::new(Arc::clone(
+                self.spill_manager.schema(),
+            )))),
+        }
+    }
+}
+
+/// Build side of a hash join that has been spilled to disk instead of being
+/// loaded into a hash table
+pub(super) struct SpilledBuildSide {
+    /// Hash partitions of the build side
+    pub(super) partitions: SpilledPartitions,
+    /// Configuration `partitions` were written with, the probe side must be
+    /// partitioned with the same configuration
+    pub(super) config: HashJoinSpillConfig,
+    /// Build side join key expressions
+    pub(super) on_left: Vec<PhysicalExprRef>,
+    /// Whether visited build rows need to be tracked for the join type
+    pub(super) with_visited_indices_bitmap: bool,
+    /// Reservation the hash tables of the individual partitions are accounted to
+    pub(super) reservation: MemoryReservation,
+}
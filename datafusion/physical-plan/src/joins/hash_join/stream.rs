@@ -24,13 +24,15 @@ use std::sync::Arc;
 use std::task::Poll;
 
 use crate::joins::PartitionMode;
-use crate::joins::hash_join::exec::JoinLeftData;
+use crate::joins::hash_join::exec::{JoinLeftData, collect_left_input};
 use crate::joins::hash_join::shared_bounds::{
     PartitionBounds, PartitionBuildData, SharedBuildAccumulator,
 };
+use crate::joins::hash_join::spill::{HashPartitionWriter, SpilledPartitions};
 use crate::joins::utils::{
     OnceFut, equal_rows_arr, get_final_indices_from_shared_bitmap,
 };
+use crate::metrics::ExecutionPlanMetricsSet;
 use crate::{
     RecordBatchStream, SendableRecordBatchStream, handle_state,
     hash_utils::create_hashes,
@@ -115,10 +117,16 @@ impl BuildSide {
 ///             │
 ///             ▼
 ///  ┌─► FetchProbeBatch ───► ExhaustedProbeSide ───► Completed
-///  │          │
-///  │          ▼
-///  └─ ProcessProbeBatch
+///  │          │                                         ▲
+///  │          ▼                                         │
+///  └─ ProcessProbeBatch                                 │
+///                                                       │
+///  (build side spilled)                                 │
+///  PartitionProbeSide ───► JoinSpilledPartitions ───────┘
 /// ```
+///
+/// When the build side has been spilled to disk, `WaitBuildSide` is followed
+/// by `PartitionProbeSide` instead of `FetchProbeBatch`.
 #[derive(Debug, Clone)]
 pub(super) enum HashJoinStreamState {
     /// Initial state for HashJoinStream indicating that build-side data not collected yet
@@ -131,6 +139,12 @@ pub(super) enum HashJoinStreamState {
     ProcessProbeBatch(ProcessProbeBatchState),
     /// Indicates that probe-side has been fully processed
     ExhaustedProbeSide,
+    /// Indicates that the build side has been spilled, and probe-side batches
+    /// are written to disk using the same hash partitioning
+    PartitionProbeSide,
+    /// Indicates that both sides have been spilled, and matching partitions are
+    /// being joined one at a time
+    JoinSpilledPartitions,
     /// Indicates that HashJoinStream execution is completed
     Completed,
 }
@@ -223,6 +237,20 @@ pub(super) struct HashJoinStream {
     /// Uses `BatchCoalescer` from arrow to efficiently combine batches.
     /// When batches are already close to target size, they bypass coalescing.
     output_buffer: Box<BatchCoalescer>,
+    /// State of the partition-wise join, set if the build side has been spilled
+    spilled_join: Option<SpilledJoinState>,
+}
+
+/// Container for the state of joining a spilled build side
+struct SpilledJoinState {
+    /// Writer partitioning the probe side, until the probe side is exhausted
+    probe_writer: Option<HashPartitionWriter>,
+    /// Spilled partitions of the probe side, once the probe side is exhausted
+    probe_partitions: Option<SpilledPartitions>,
+    /// Index of the next partition to join
+    next_partition: usize,
+    /// Join of the current pair of build and probe partitions
+    current: Option<SendableRecordBatchStream>,
 }
 
 impl RecordBatchStream for HashJoinStream {
@@ -401,6 +429,7 @@ impl HashJoinStream {
             build_waiter: None,
             mode,
             output_buffer,
+            spilled_join: None,
         }
     }
 
@@ -435,6 +464,12 @@ impl HashJoinStream {
                 HashJoinStreamState::ExhaustedProbeSide => {
                     handle_state!(self.process_unmatched_build_batch())
                 }
+                HashJoinStreamState::PartitionProbeSide => {
+                    handle_state!(ready!(self.partition_probe_batch(cx)))
+                }
+                HashJoinStreamState::JoinSpilledPartitions => {
+                    handle_state!(ready!(self.join_spilled_partitions(cx)))
+                }
                 HashJoinStreamState::Completed if !self.output_buffer.is_empty() => {
                     // Flush any remaining buffered data
                     self.output_buffer.finish_buffered_batch()?;
@@ -462,10 +497,19 @@ impl HashJoinStream {
         if let Some(ref mut fut) = self.build_waiter {
             ready!(fut.get_shared(cx))?;
         }
-        self.state = HashJoinStreamState::FetchProbeBatch;
+        self.state = self.probe_start_state();
         Poll::Ready(Ok(StatefulStreamResult::Continue))
     }
 
+    /// Returns the state to continue with once the build side is available
+    fn probe_start_state(&self) -> HashJoinStreamState {
+        if self.spilled_join.is_some() {
+            HashJoinStreamState::PartitionProbeSide
+        } else {
+            HashJoinStreamState::FetchProbeBatch
+        }
+    }
+
     /// Collects build-side data by polling `OnceFut` future from initialized build-side
     ///
     /// Updates build-side to `Ready`, and state to `FetchProbeSide`
@@ -483,6 +527,21 @@ impl HashJoinStream {
         )?;
         build_timer.done();
 
+        // If the build side has been spilled, the probe side needs to be
+        // partitioned the same way before the partitions can be joined
+        if let Some(spilled) = left_data.spilled_build_side() {
+            let on_right = self.on_right.clone();
+            let probe_writer = spilled
+                .config
+                .partition_writer(on_right, self.right.schema());
+            self.spilled_join = Some(SpilledJoinState {
+                probe_writer: Some(probe_writer),
+                probe_partitions: None,
+                next_partition: 0,
+                current: None,
+            });
+        }
+
         // Handle dynamic filter build-side information accumulation
         //
         // Dynamic filter coordination between partitions:
@@ -529,7 +588,7 @@ impl HashJoinStream {
             }));
             self.state = HashJoinStreamState::WaitPartitionBoundsReport;
         } else {
-            self.state = HashJoinStreamState::FetchProbeBatch;
+            self.state = self.probe_start_state();
         }
 
         self.build_side = BuildSide::Ready(BuildSideReadyState { left_data });
@@ -776,6 +835,173 @@ impl HashJoinStream {
 
         Ok(StatefulStreamResult::Continue)
     }
+
+    /// Fetches the next probe-side batch and writes it to the spilled probe
+    /// partitions
+    ///
+    /// Updates state to `JoinSpilledPartitions` once the probe side is exhausted
+    fn partition_probe_batch(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Result<StatefulStreamResult<Option<RecordBatch>>>> {
+        let Some(spilled_join) = self.spilled_join.as_mut() else {
+            return Poll::Ready(internal_err!("Expected spilled hash join state"));
+        };
+        let Some(probe_writer) = spilled_join.probe_writer.as_mut() else {
+            return Poll::Ready(internal_err!("Probe side has already been partitioned"));
+        };
+
+        match ready!(self.right.poll_next_unpin(cx)) {
+            None => {
+                let timer = self.join_metrics.join_time.timer();
+                if let Some(probe_writer) = spilled_join.probe_writer.take() {
+                    spilled_join.probe_partitions = Some(probe_writer.finish()?);
+                }
+                timer.done();
+                self.state = HashJoinStreamState::JoinSpilledPartitions;
+            }
+            Some(Ok(batch)) => {
+                self.join_metrics.input_batches.add(1);
+                self.join_metrics.input_rows.add(batch.num_rows());
+
+                let timer = self.join_metrics.join_time.timer();
+                probe_writer.append(&batch)?;
+                timer.done();
+            }
+            Some(Err(err)) => return Poll::Ready(Err(err)),
+        }
+
+        Poll::Ready(Ok(StatefulStreamResult::Continue))
+    }
+
+    /// Joins the spilled build and probe partitions pair by pair, forwarding
+    /// the output of each partition join
+    ///
+    /// Updates state to `Completed` once all partitions have been joined
+    fn join_spilled_partitions(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Result<StatefulStreamResult<Option<RecordBatch>>>> {
+        loop {
+            let current = match self.spilled_join.as_mut() {
+                Some(SpilledJoinState {
+                    current: Some(current),
+                    ..
+                }) => current,
+                _ => match self.next_spilled_partition_join()? {
+                    Some(stream) => {
+                        let Some(spilled_join) = self.spilled_join.as_mut() else {
+                            return Poll::Ready(internal_err!(
+                                "Expected spilled hash join state"
+                            ));
+                        };
+                        spilled_join.current.insert(stream)
+                    }
+                    None => {
+                        self.state = HashJoinStreamState::Completed;
+                        return Poll::Ready(Ok(StatefulStreamResult::Continue));
+                    }
+                },
+            };
+
+            let timer = self.join_metrics.join_time.timer();
+            let poll = current.poll_next_unpin(cx);
+            timer.done();
+
+            match ready!(poll) {
+                Some(Ok(batch)) => {
+                    self.output_buffer.push_batch(batch)?;
+                    return Poll::Ready(Ok(StatefulStreamResult::Continue));
+                }
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => {
+                    if let Some(spilled_join) = self.spilled_join.as_mut() {
+                        spilled_join.current = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Creates the join of the next pair of spilled partitions, or returns
+    /// `None` if all partitions have been joined.
+    ///
+    /// Each pair is joined by a regular [`HashJoinStream`] whose build side is
+    /// loaded from the spilled build partition. If that partition still does
+    /// not fit in memory, it is spilled again with the next level of
+    /// partitioning.
+    fn next_spilled_partition_join(
+        &mut self,
+    ) -> Result<Option<SendableRecordBatchStream>> {
+        let left_data = Arc::clone(&self.build_side.try_as_ready()?.left_data);
+        let Some(spilled_build) = left_data.spilled_build_side() else {
+            return internal_err!("Expected spilled build side");
+        };
+        let Some(spilled_join) = self.spilled_join.as_mut() else {
+            return internal_err!("Expected spilled hash join state");
+        };
+        let Some(probe_partitions) = spilled_join.probe_partitions.as_ref() else {
+            return internal_err!("Probe side has not been partitioned");
+        };
+
+        while spilled_join.next_partition < spilled_build.partitions.num_partitions() {
+            let partition = spilled_join.next_partition;
+            spilled_join.next_partition += 1;
+
+            let build_file = spilled_build.partitions.take_partition(partition);
+            let probe_file = probe_partitions.take_partition(partition);
+            if build_file.is_none() && probe_file.is_none() {
+                continue;
+            }
+
+            let build_stream = spilled_build.partitions.read_partition(build_file)?;
+            let probe_stream = probe_partitions.read_partition(probe_file)?;
+
+            // Rows were already accounted for in the metrics of this stream
+            // while being spilled, so the partition join reports to its own metrics
+            let partition_metrics = BuildProbeJoinMetrics::new(
+                self.partition,
+                &ExecutionPlanMetricsSet::new(),
+            );
+
+            let left_fut = OnceFut::new(collect_left_input(
+                self.random_state.clone(),
+                build_stream,
+                spilled_build.on_left.clone(),
+                partition_metrics.clone(),
+                spilled_build.reservation.new_empty(),
+                spilled_build.with_visited_indices_bitmap,
+                1,
+                false,
+                0,
+                0,
+                spilled_build.config.next_level(),
+            ));
+
+            let stream = HashJoinStream::new(
+                self.partition,
+                Arc::clone(&self.schema),
+                self.on_right.clone(),
+                self.filter.clone(),
+                self.join_type,
+                probe_stream,
+                self.random_state.clone(),
+                partition_metrics,
+                self.column_indices.clone(),
+                self.null_equality,
+                HashJoinStreamState::WaitBuildSide,
+                BuildSide::Initial(BuildSideInitialState { left_fut }),
+                self.batch_size,
+                vec![],
+                false,
+                None,
+                self.mode,
+            );
+            return Ok(Some(Box::pin(stream)));
+        }
+
+        Ok(None)
+    }
 }
 
 impl Stream for HashJoinStream {
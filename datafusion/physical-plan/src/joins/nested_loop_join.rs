@@ -22,7 +22,7 @@ use std::fmt::Formatter;
 use std::ops::{BitOr, ControlFlow};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::task::Poll;
+use std::task::{Poll, ready};
 
 use super::utils::{
     asymmetric_join_output_partitioning, need_produce_result_in_final,
@@ -38,14 +38,17 @@ use crate::joins::utils::{
 };
 use crate::metrics::{
     Count, ExecutionPlanMetricsSet, MetricBuilder, MetricType, MetricsSet, RatioMetrics,
+    SpillMetrics,
 };
 use crate::projection::{
     EmbeddedProjection, JoinData, ProjectionExec, try_embed_projection,
     try_pushdown_through_join,
 };
+use crate::spill::in_progress_spill_file::InProgressSpillFile;
 use crate::{
-    DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, ExecutionPlanProperties,
-    PlanProperties, RecordBatchStream, SendableRecordBatchStream,
+    DisplayAs, DisplayFormatType, Distribution, EmptyRecordBatchStream, ExecutionPlan,
+    ExecutionPlanProperties, PlanProperties, RecordBatchStream,
+    SendableRecordBatchStream, SpillManager,
 };
 
 use arrow::array::{
@@ -60,18 +63,21 @@ use arrow::datatypes::{Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use arrow_schema::DataType;
 use datafusion_common::cast::as_boolean_array;
+use datafusion_common::config::SpillCompression;
 use datafusion_common::{
     JoinSide, Result, ScalarValue, Statistics, arrow_err, assert_eq_or_internal_err,
     internal_datafusion_err, internal_err, project_schema, unwrap_or_internal_err,
 };
 use datafusion_execution::TaskContext;
+use datafusion_execution::disk_manager::RefCountedTempFile;
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::runtime_env::RuntimeEnv;
 use datafusion_expr::JoinType;
 use datafusion_physical_expr::equivalence::{
     ProjectionMapping, join_equivalence_properties,
 };
 
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{Stream, StreamExt};
 use log::debug;
 use parking_lot::Mutex;
 
@@ -118,11 +124,9 @@ use parking_lot::Mutex;
 ///
 /// ## 1. Buffering Left Input
 /// - The operator eagerly buffers all left-side input batches into memory,
-///   util a memory limit is reached.
-///   Currently, an out-of-memory error will be thrown if all the left-side input batches
-///   cannot fit into memory at once.
-///   In the future, it's possible to make this case finish execution. (see
-///   'Memory-limited Execution' section)
+///   until a memory limit is reached. If all the left-side input batches
+///   cannot fit into memory at once, they are written to disk in chunks
+///   instead. (see 'Memory-limited Execution' section)
 /// - The rationale for buffering the left side is that scanning the right side
 ///   can be expensive (e.g., decoding Parquet files), so buffering more left
 ///   rows reduces the number of right-side scan passes required.
@@ -158,12 +162,37 @@ use parking_lot::Mutex;
 /// - The design try to minimize the intermediate data size to approximately
 ///   1 batch, for better cache locality and memory efficiency.
 ///
-/// # TODO: Memory-limited Execution
-/// If the memory budget is exceeded during left-side buffering, fallback
-/// strategies such as streaming left batches and re-scanning the right side
-/// may be implemented in the future.
+/// # Memory-limited Execution
+/// If the memory budget is exceeded during left-side buffering and the
+/// `DiskManager` allows temporary files, the left side is split into chunks
+/// which are written to disk. Each chunk is limited to the memory the left side
+/// could be buffered in, divided by the number of output partitions, so that
+/// every output stream can hold one chunk at a time.
 ///
-/// Tracking issue: <https://github.com/apache/datafusion/issues/15760>
+/// Every output stream then joins its right input with one chunk after the
+/// other:
+///
+/// ```text
+/// for chunk in left_chunks:
+///     load chunk from disk
+///     for batch in right_side:
+///         for row in chunk:
+///             join(batch, row)
+///     (maybe) emit unmatched rows of chunk
+/// ```
+///
+/// While joining the first chunk, the right input is written to disk as well,
+/// and it's re-scanned from there for the following chunks.
+///
+/// - The bitmap of visited left rows covers the whole left side, and every chunk
+///   keeps its own counter of output streams that have not finished joining it.
+///   The last stream to finish a chunk emits its unmatched rows, as it's done
+///   for the in-memory left side.
+/// - Matched flags of the right rows are kept for all right batches of a
+///   stream, and unmatched right rows are only emitted while joining the last
+///   chunk.
+/// - The output no longer follows the order of the right input, which this
+///   operator does not maintain anyway.
 ///
 /// # Clone / Shared State
 /// Note this structure includes a [`OnceAsync`] that is used to coordinate the
@@ -499,9 +528,23 @@ impl ExecutionPlan for NestedLoopJoinExec {
 
         let metrics = NestedLoopJoinMetrics::new(&self.metrics, partition);
 
+        // The left side is written to disk in chunks if it does not fit in memory
+        let spill_config =
+            context
+                .runtime_env()
+                .disk_manager
+                .tmp_files_enabled()
+                .then(|| NestedLoopJoinSpillConfig {
+                    runtime: context.runtime_env(),
+                    metrics: self.metrics.clone(),
+                    partition,
+                    compression: context.session_config().spill_compression(),
+                });
+
         // Initialization reservation for load of inner table
         let load_reservation =
             MemoryConsumer::new(format!("NestedLoopJoinLoad[{partition}]"))
+                .with_can_spill(spill_config.is_some())
                 .register(context.memory_pool());
 
         let build_side_data = self.build_side_data.try_once(|| {
@@ -513,6 +556,7 @@ impl ExecutionPlan for NestedLoopJoinExec {
                 load_reservation,
                 need_produce_result_in_final(self.join_type),
                 self.right().output_partitioning().partition_count(),
+                spill_config.clone(),
             ))
         })?;
 
@@ -538,6 +582,7 @@ impl ExecutionPlan for NestedLoopJoinExec {
             column_indices_after_projection,
             metrics,
             batch_size,
+            spill_config,
         )))
     }
 
@@ -628,12 +673,15 @@ impl EmbeddedProjection for NestedLoopJoinExec {
 
 /// Left (build-side) data
 pub(crate) struct JoinLeftData {
-    /// Build-side data collected to single batch
+    /// Build-side data collected to single batch, empty if the build side has
+    /// been spilled
     batch: RecordBatch,
     /// Shared bitmap builder for visited left indices
     bitmap: SharedBitmapBuilder,
     /// Counter of running probe-threads, potentially able to update `bitmap`
     probe_threads_counter: AtomicUsize,
+    /// Chunks of the build side written to disk, if it did not fit in memory
+    spilled: Option<SpilledLeftSide>,
     /// Memory reservation for tracking batch and bitmap
     /// Cleared on `JoinLeftData` drop
     /// reservation is cleared on Drop
@@ -646,12 +694,14 @@ impl JoinLeftData {
         batch: RecordBatch,
         bitmap: SharedBitmapBuilder,
         probe_threads_counter: AtomicUsize,
+        spilled: Option<SpilledLeftSide>,
         reservation: MemoryReservation,
     ) -> Self {
         Self {
             batch,
             bitmap,
             probe_threads_counter,
+            spilled,
             reservation,
         }
     }
@@ -664,47 +714,100 @@ impl JoinLeftData {
         &self.bitmap
     }
 
-    /// Decrements counter of running threads, and returns `true`
-    /// if caller is the last running thread
-    pub(crate) fn report_probe_completed(&self) -> bool {
-        self.probe_threads_counter.fetch_sub(1, Ordering::Relaxed) == 1
+    /// Returns the chunks of the build side, if it has been spilled
+    pub(crate) fn spilled(&self) -> Option<&SpilledLeftSide> {
+        self.spilled.as_ref()
+    }
+
+    /// Decrements counter of running threads for the build-side chunk `chunk`,
+    /// and returns `true` if caller is the last running thread.
+    ///
+    /// The build side is a single chunk unless it has been spilled.
+    pub(crate) fn report_probe_completed(&self, chunk: usize) -> Result<bool> {
+        let counter = match &self.spilled {
+            Some(spilled) => {
+                let chunk = spilled.chunks.get(chunk).ok_or_else(|| {
+                    internal_datafusion_err!("Build-side chunk {chunk} does not exist")
+                })?;
+                &chunk.probe_threads_counter
+            }
+            None => &self.probe_threads_counter,
+        };
+        Ok(counter.fetch_sub(1, Ordering::Relaxed) == 1)
     }
 }
 
 /// Asynchronously collect input into a single batch, and creates `JoinLeftData` from it
+///
+/// If `spill_config` is set and the memory pool refuses to grow `reservation`,
+/// the input is written to disk in chunks instead, see [`SpilledLeftSide`].
 async fn collect_left_input(
-    stream: SendableRecordBatchStream,
-    join_metrics: BuildProbeJoinMetrics,
-    reservation: MemoryReservation,
+    mut stream: SendableRecordBatchStream,
+    metrics: BuildProbeJoinMetrics,
+    mut reservation: MemoryReservation,
     with_visited_left_side: bool,
     probe_threads_count: usize,
+    spill_config: Option<NestedLoopJoinSpillConfig>,
 ) -> Result<JoinLeftData> {
     let schema = stream.schema();
 
+    let mut batches = Vec::new();
+    // Set once the build side has been spilled, all further batches are written to disk
+    let mut chunk_writer: Option<LeftChunkWriter> = None;
+
     // Load all batches and count the rows
-    let (batches, metrics, mut reservation) = stream
-        .try_fold(
-            (Vec::new(), join_metrics, reservation),
-            |(mut batches, metrics, mut reservation), batch| async {
-                let batch_size = batch.get_array_memory_size();
-                // Reserve memory for incoming batch
-                reservation.try_grow(batch_size)?;
-                // Update metrics
-                metrics.build_mem_used.add(batch_size);
-                metrics.build_input_batches.add(1);
-                metrics.build_input_rows.add(batch.num_rows());
-                // Push batch to output
-                batches.push(batch);
-                Ok((batches, metrics, reservation))
-            },
-        )
-        .await?;
+    while let Some(batch) = stream.next().await {
+        let batch = batch?;
+        // Update metrics
+        metrics.build_input_batches.add(1);
+        metrics.build_input_rows.add(batch.num_rows());
+
+        if let Some(writer) = chunk_writer.as_mut() {
+            writer.push(batch)?;
+            continue;
+        }
 
-    let merged_batch = concat_batches(&schema, &batches)?;
+        let batch_size = batch.get_array_memory_size();
+        // Reserve memory for incoming batch
+        if let Err(e) = reservation.try_grow(batch_size) {
+            let Some(config) = &spill_config else {
+                return Err(e);
+            };
+            // Every probe thread holds one chunk at a time, so the memory the
+            // build side could be buffered in is split between them
+            let max_chunk_size = (reservation.size() / probe_threads_count.max(1)).max(1);
+            reservation.free();
+            metrics.build_mem_used.set(0);
+
+            let mut writer = LeftChunkWriter::new(
+                config.spill_manager(Arc::clone(&schema)),
+                max_chunk_size,
+                probe_threads_count,
+                reservation.new_empty(),
+            );
+            for batch in std::mem::take(&mut batches) {
+                writer.push(batch)?;
+            }
+            writer.push(batch)?;
+            chunk_writer = Some(writer);
+            continue;
+        }
+        metrics.build_mem_used.add(batch_size);
+        // Push batch to output
+        batches.push(batch);
+    }
+
+    let (merged_batch, spilled) = match chunk_writer {
+        Some(writer) => (RecordBatch::new_empty(schema), Some(writer.finish()?)),
+        None => (concat_batches(&schema, &batches)?, None),
+    };
 
     // Reserve memory for visited_left_side bitmap if required by join type
     let visited_left_side = if with_visited_left_side {
-        let n_rows = merged_batch.num_rows();
+        let n_rows = match &spilled {
+            Some(spilled) => spilled.num_rows,
+            None => merged_batch.num_rows(),
+        };
         let buffer_size = n_rows.div_ceil(8);
         reservation.try_grow(buffer_size)?;
         metrics.build_mem_used.add(buffer_size);
@@ -720,10 +823,157 @@ async fn collect_left_input(
         merged_batch,
         Mutex::new(visited_left_side),
         AtomicUsize::new(probe_threads_count),
+        spilled,
         reservation,
     ))
 }
 
+/// Configuration for spilling a nested loop join
+#[derive(Debug, Clone)]
+pub(crate) struct NestedLoopJoinSpillConfig {
+    /// Runtime environment providing the disk manager and the memory pool
+    runtime: Arc<RuntimeEnv>,
+    /// Metrics of the join, spill metrics are only registered once spilling happens
+    metrics: ExecutionPlanMetricsSet,
+    /// Output partition the metrics are reported for
+    partition: usize,
+    /// Compression codec for the spill files
+    compression: SpillCompression,
+}
+
+impl NestedLoopJoinSpillConfig {
+    /// Creates a spill manager for batches of `schema`
+    fn spill_manager(&self, schema: SchemaRef) -> SpillManager {
+        SpillManager::new(
+            Arc::clone(&self.runtime),
+            SpillMetrics::new(&self.metrics, self.partition),
+            schema,
+        )
+        .with_compression_type(self.compression)
+    }
+}
+
+/// Build side of a nested loop join that did not fit in memory, and has been
+/// written to disk in chunks that are joined with the probe side one at a time
+pub(crate) struct SpilledLeftSide {
+    /// Spill manager used to read the chunks back
+    spill_manager: SpillManager,
+    /// Chunks in the order of the build-side input
+    chunks: Vec<SpilledLeftChunk>,
+    /// Number of rows of all chunks
+    num_rows: usize,
+}
+
+/// A chunk of a spilled build side
+struct SpilledLeftChunk {
+    /// Spill file holding the rows of the chunk
+    file: RefCountedTempFile,
+    /// Index of the first row of the chunk within the whole build side
+    offset: usize,
+    /// Memory size of the chunk once loaded
+    memory_size: usize,
+    /// Counter of probe-threads that have not finished joining this chunk
+    probe_threads_counter: AtomicUsize,
+}
+
+/// Writes build-side batches to disk in chunks of a bounded memory size
+struct LeftChunkWriter {
+    /// Spill manager creating the spill files
+    spill_manager: SpillManager,
+    /// Maximum memory size of a chunk, unless it consists of a single batch
+    max_chunk_size: usize,
+    /// Number of probe-threads which will join every chunk
+    probe_threads_count: usize,
+    /// Batches of the chunk which is being collected
+    batches: Vec<RecordBatch>,
+    /// Memory size of `batches`
+    batches_size: usize,
+    /// Chunks written so far
+    chunks: Vec<SpilledLeftChunk>,
+    /// Number of rows of the chunks written so far
+    num_rows: usize,
+    /// Reservation for `batches`
+    reservation: MemoryReservation,
+}
+
+impl LeftChunkWriter {
+    fn new(
+        spill_manager: SpillManager,
+        max_chunk_size: usize,
+        probe_threads_count: usize,
+        reservation: MemoryReservation,
+    ) -> Self {
+        Self {
+            spill_manager,
+            max_chunk_size,
+            probe_threads_count,
+            batches: vec![],
+            batches_size: 0,
+            chunks: vec![],
+            num_rows: 0,
+            reservation,
+        }
+    }
+
+    /// Adds `batch` to the current chunk, writing the chunk to disk first if
+    /// it would exceed the maximum chunk size
+    fn push(&mut self, batch: RecordBatch) -> Result<()> {
+        let batch_size = batch.get_array_memory_size();
+        if !self.batches.is_empty()
+            && self.batches_size + batch_size > self.max_chunk_size
+        {
+            self.write_chunk()?;
+        }
+
+        if let Err(e) = self.reservation.try_grow(batch_size) {
+            // A chunk can not be smaller than a single batch
+            if self.batches.is_empty() {
+                return Err(e);
+            }
+            self.write_chunk()?;
+            self.reservation.try_grow(batch_size)?;
+        }
+
+        self.batches_size += batch_size;
+        self.batches.push(batch);
+        Ok(())
+    }
+
+    /// Writes the batches of the current chunk to a spill file
+    fn write_chunk(&mut self) -> Result<()> {
+        let num_rows = self.batches.iter().map(|b| b.num_rows()).sum::<usize>();
+        let file = self
+            .spill_manager
+            .spill_record_batch_and_finish(&self.batches, "NestedLoopJoin build side")?;
+        let file = unwrap_or_internal_err!(file);
+
+        self.chunks.push(SpilledLeftChunk {
+            file,
+            offset: self.num_rows,
+            memory_size: self.batches_size,
+            probe_threads_counter: AtomicUsize::new(self.probe_threads_count),
+        });
+        self.num_rows += num_rows;
+        self.batches.clear();
+        self.batches_size = 0;
+        self.reservation.free();
+        Ok(())
+    }
+
+    /// Writes the last chunk, and releases the memory of the build side
+    fn finish(mut self) -> Result<SpilledLeftSide> {
+        if !self.batches.is_empty() {
+            self.write_chunk()?;
+        }
+
+        Ok(SpilledLeftSide {
+            spill_manager: self.spill_manager,
+            chunks: self.chunks,
+            num_rows: self.num_rows,
+        })
+    }
+}
+
 /// States for join processing. See `poll_next()` comment for more details about
 /// state transitions.
 #[derive(Debug, Clone, Copy)]
@@ -777,6 +1027,9 @@ pub(crate) struct NestedLoopJoinStream {
     /// See comments in [`need_produce_right_in_final`] for more detail
     should_track_unmatched_right: bool,
 
+    /// Configuration for spilling, `None` if spilling is disabled
+    spill_config: Option<NestedLoopJoinSpillConfig>,
+
     // ========================================================================
     // STATE FLAGS/BUFFERS:
     // Fields that hold intermediate data/flags during execution
@@ -793,6 +1046,14 @@ pub(crate) struct NestedLoopJoinStream {
     // -----------------
     /// The current buffered left data to join
     buffered_left_data: Option<Arc<JoinLeftData>>,
+    /// The left rows the right side is currently joined with. This is the
+    /// whole left side, or the current chunk if the left side has been spilled
+    current_left_batch: Option<RecordBatch>,
+    /// Index of the current left chunk
+    left_chunk_idx: usize,
+    /// Index of the first row of `current_left_batch` within the whole left
+    /// side, which the left bitmap is indexed by
+    left_chunk_offset: usize,
     /// Index into the left buffered batch. Used in `ProbeRight` state
     left_probe_idx: usize,
     /// Index into the left buffered batch. Used in `EmitLeftUnmatched` state
@@ -801,9 +1062,9 @@ pub(crate) struct NestedLoopJoinStream {
     /// state is over.
     left_exhausted: bool,
     /// If we can buffer all left data in one pass
-    /// TODO(now): this is for the (unimplemented) memory-limited execution
-    #[expect(dead_code)]
     left_buffered_in_one_pass: bool,
+    /// State for joining the chunks of a spilled left side
+    spilled_join: Option<SpilledJoinState>,
 
     // Probe(right) side
     // -----------------
@@ -814,6 +1075,29 @@ pub(crate) struct NestedLoopJoinStream {
     current_right_batch_matched: Option<BooleanArray>,
 }
 
+/// State of a [`NestedLoopJoinStream`] joining a spilled left side chunk by
+/// chunk
+struct SpilledJoinState {
+    /// Stream reading the next left chunk from disk, while it is loaded
+    chunk_stream: Option<SendableRecordBatchStream>,
+    /// Batches of the next left chunk read so far
+    chunk_batches: Vec<RecordBatch>,
+    /// Reservation for the left chunk held by this stream
+    chunk_reservation: MemoryReservation,
+    /// Spill manager for the right side, which is written to disk while
+    /// joining the first chunk, and re-scanned for the following chunks
+    right_spill_manager: SpillManager,
+    /// Spill file the right side is written to while joining the first chunk
+    right_writer: Option<InProgressSpillFile>,
+    /// Spill file of the right side, once it has been fully written
+    right_file: Option<RefCountedTempFile>,
+    /// Matched flags of every right batch, accumulated over all chunks. Only
+    /// tracked if unmatched right rows have to be produced.
+    right_batches_matched: Vec<BooleanBuffer>,
+    /// Index of the current right batch within the right side
+    right_batch_idx: usize,
+}
+
 pub(crate) struct NestedLoopJoinMetrics {
     /// Join execution metrics
     pub(crate) join_metrics: BuildProbeJoinMetrics,
@@ -862,6 +1146,8 @@ impl Stream for NestedLoopJoinStream {
     ///
     /// EmitLeftUnmatched → EmitLeftUnmatched (only process 1 chunk for each
     /// iteration)
+    /// EmitLeftUnmatched → BufferingLeft (if the left side has been spilled,
+    /// and there are more left chunks to join)
     /// EmitLeftUnmatched → Done (if finished)
     /// ----------------------------
     /// Done → (end)
@@ -876,6 +1162,8 @@ impl Stream for NestedLoopJoinStream {
                 // This state will prepare the left side batches, next state
                 // `FetchingRight` is responsible for preparing a single probe
                 // side batch, before start joining.
+                // If the left side has been spilled, this state loads the next
+                // left chunk from disk.
                 NLJState::BufferingLeft => {
                     debug!("[NLJState] Entering: {:?}", self.state);
                     // inside `collect_left_input` (the routine to buffer build
@@ -984,12 +1272,12 @@ impl Stream for NestedLoopJoinStream {
                 //    After processing some unmatched rows, it will re-enter
                 //    the same state, to check if there are any more final
                 //    results to output.
-                // 3. --> Done
+                // 3. --> BufferingLeft
+                //    If the left side has been spilled, load the next left
+                //    chunk and join it with the right side again.
+                // 4. --> Done
                 //    It has processed all data, go to the final state and ready
                 //    to exit.
-                //
-                // TODO: For memory-limited case, go back to `BufferingLeft`
-                // state again.
                 NLJState::EmitLeftUnmatched => {
                     debug!("[NLJState] Entering: {:?}", self.state);
 
@@ -1040,6 +1328,7 @@ impl NestedLoopJoinStream {
         column_indices: Vec<ColumnIndex>,
         metrics: NestedLoopJoinMetrics,
         batch_size: usize,
+        spill_config: Option<NestedLoopJoinSpillConfig>,
     ) -> Self {
         Self {
             output_schema: Arc::clone(&schema),
@@ -1055,12 +1344,17 @@ impl NestedLoopJoinStream {
             current_right_batch: None,
             current_right_batch_matched: None,
             state: NLJState::BufferingLeft,
+            current_left_batch: None,
+            left_chunk_idx: 0,
+            left_chunk_offset: 0,
             left_probe_idx: 0,
             left_emit_idx: 0,
             left_exhausted: false,
             left_buffered_in_one_pass: true,
+            spilled_join: None,
             handled_empty_output: false,
             should_track_unmatched_right: need_produce_right_in_final(join_type),
+            spill_config,
         }
     }
 
@@ -1071,18 +1365,33 @@ impl NestedLoopJoinStream {
         &mut self,
         cx: &mut std::task::Context<'_>,
     ) -> ControlFlow<Poll<Option<Result<RecordBatch>>>> {
-        match self.left_data.get_shared(cx) {
-            Poll::Ready(Ok(left_data)) => {
-                self.buffered_left_data = Some(left_data);
-                // TODO: implement memory-limited case
-                self.left_exhausted = true;
-                self.state = NLJState::FetchingRight;
-                // Continue to next state immediately
-                ControlFlow::Continue(())
+        if self.buffered_left_data.is_none() {
+            match self.left_data.get_shared(cx) {
+                Poll::Ready(Ok(left_data)) => {
+                    if let Err(e) = self.init_left_data(left_data) {
+                        return ControlFlow::Break(Poll::Ready(Some(Err(e))));
+                    }
+                }
+                Poll::Ready(Err(e)) => {
+                    return ControlFlow::Break(Poll::Ready(Some(Err(e))));
+                }
+                Poll::Pending => return ControlFlow::Break(Poll::Pending),
+            }
+        }
+
+        if !self.left_buffered_in_one_pass {
+            match self.load_left_chunk(cx) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => {
+                    return ControlFlow::Break(Poll::Ready(Some(Err(e))));
+                }
+                Poll::Pending => return ControlFlow::Break(Poll::Pending),
             }
-            Poll::Ready(Err(e)) => ControlFlow::Break(Poll::Ready(Some(Err(e)))),
-            Poll::Pending => ControlFlow::Break(Poll::Pending),
         }
+
+        self.state = NLJState::FetchingRight;
+        // Continue to next state immediately
+        ControlFlow::Continue(())
     }
 
     /// Handle FetchingRight state - fetch next right batch and prepare for processing
@@ -1103,15 +1412,26 @@ impl NestedLoopJoinStream {
                         return ControlFlow::Continue(());
                     }
 
-                    self.current_right_batch = Some(right_batch);
-
                     // Prepare right bitmap
                     if self.should_track_unmatched_right {
-                        let zeroed_buf = BooleanBuffer::new_unset(right_batch_size);
+                        let matched_buf = match self.previous_right_matched() {
+                            Ok(Some(buf)) => buf,
+                            Ok(None) => BooleanBuffer::new_unset(right_batch_size),
+                            Err(e) => {
+                                return ControlFlow::Break(Poll::Ready(Some(Err(e))));
+                            }
+                        };
                         self.current_right_batch_matched =
-                            Some(BooleanArray::new(zeroed_buf, None));
+                            Some(BooleanArray::new(matched_buf, None));
                     }
 
+                    // The right side is re-scanned for the following left chunks
+                    if let Err(e) = self.maybe_spill_right_batch(&right_batch) {
+                        return ControlFlow::Break(Poll::Ready(Some(Err(e))));
+                    }
+
+                    self.current_right_batch = Some(right_batch);
+
                     self.left_probe_idx = 0;
                     self.state = NLJState::ProbeRight;
                     ControlFlow::Continue(())
@@ -1119,6 +1439,16 @@ impl NestedLoopJoinStream {
                 Some(Err(e)) => ControlFlow::Break(Poll::Ready(Some(Err(e)))),
                 None => {
                     // Right stream exhausted
+                    if let Some(spilled_join) = self.spilled_join.as_mut()
+                        && let Some(mut writer) = spilled_join.right_writer.take()
+                    {
+                        match writer.finish() {
+                            Ok(file) => spilled_join.right_file = file,
+                            Err(e) => {
+                                return ControlFlow::Break(Poll::Ready(Some(Err(e))));
+                            }
+                        }
+                    }
                     self.state = NLJState::EmitLeftUnmatched;
                     ControlFlow::Continue(())
                 }
@@ -1148,11 +1478,11 @@ impl NestedLoopJoinStream {
                 self.left_probe_idx = 0;
 
                 // Selectivity Metric: Update total possibilities for the batch (left_rows * right_rows)
-                // If memory-limited execution is implemented, this logic must be updated accordingly.
-                if let (Ok(left_data), Some(right_batch)) =
-                    (self.get_left_data(), self.current_right_batch.as_ref())
+                // For a spilled left side, the left rows of every chunk add up.
+                if let (Ok(left_batch), Some(right_batch)) =
+                    (self.get_left_batch(), self.current_right_batch.as_ref())
                 {
-                    let left_rows = left_data.batch().num_rows();
+                    let left_rows = left_batch.num_rows();
                     let right_rows = right_batch.num_rows();
                     self.metrics.selectivity.add_total(left_rows * right_rows);
                 }
@@ -1162,7 +1492,17 @@ impl NestedLoopJoinStream {
                         self.current_right_batch_matched.is_some(),
                         "If it's required to track matched rows in the right input, the right bitmap must be present"
                     );
-                    self.state = NLJState::EmitRightUnmatched;
+                    if self.left_exhausted {
+                        self.state = NLJState::EmitRightUnmatched;
+                    } else {
+                        // Unmatched right rows are only known after joining
+                        // the last left chunk
+                        if let Err(e) = self.store_right_matched() {
+                            return ControlFlow::Break(Poll::Ready(Some(Err(e))));
+                        }
+                        self.current_right_batch = None;
+                        self.state = NLJState::FetchingRight;
+                    }
                 } else {
                     self.current_right_batch = None;
                     self.state = NLJState::FetchingRight;
@@ -1226,15 +1566,24 @@ impl NestedLoopJoinStream {
             // State unchanged (EmitLeftUnmatched)
             // Continue processing until we have processed all unmatched rows
             Ok(true) => ControlFlow::Continue(()),
+            // To BufferingLeft state, if there are more left chunks to join
+            Ok(false) if !self.left_exhausted => {
+                self.left_chunk_idx += 1;
+                self.state = NLJState::BufferingLeft;
+                ControlFlow::Continue(())
+            }
             // To Done state
             // We have finished processing all unmatched rows
-            Ok(false) => match self.output_buffer.finish_buffered_batch() {
-                Ok(()) => {
-                    self.state = NLJState::Done;
-                    ControlFlow::Continue(())
+            Ok(false) => {
+                self.release_left_chunk();
+                match self.output_buffer.finish_buffered_batch() {
+                    Ok(()) => {
+                        self.state = NLJState::Done;
+                        ControlFlow::Continue(())
+                    }
+                    Err(e) => ControlFlow::Break(Poll::Ready(Some(arrow_err!(e)))),
                 }
-                Err(e) => ControlFlow::Break(Poll::Ready(Some(arrow_err!(e)))),
-            },
+            }
             Err(e) => ControlFlow::Break(Poll::Ready(Some(Err(e)))),
         }
     }
@@ -1271,6 +1620,7 @@ impl NestedLoopJoinStream {
     /// next state (ProbeRight)
     fn process_probe_batch(&mut self) -> Result<bool> {
         let left_data = Arc::clone(self.get_left_data()?);
+        let left_batch = self.get_left_batch()?.clone();
         let right_batch = self
             .current_right_batch
             .as_ref()
@@ -1278,7 +1628,7 @@ impl NestedLoopJoinStream {
             .clone();
 
         // stop probing, the caller will go to the next state
-        if self.left_probe_idx >= left_data.batch().num_rows() {
+        if self.left_probe_idx >= left_batch.num_rows() {
             return Ok(false);
         }
 
@@ -1307,7 +1657,7 @@ impl NestedLoopJoinStream {
             // batch.
             let l_row_count = std::cmp::min(
                 l_row_cnt_ratio,
-                left_data.batch().num_rows() - self.left_probe_idx,
+                left_batch.num_rows() - self.left_probe_idx,
             );
 
             debug_assert!(
@@ -1316,6 +1666,7 @@ impl NestedLoopJoinStream {
             );
             let joined_batch = self.process_left_range_join(
                 &left_data,
+                &left_batch,
                 &right_batch,
                 self.left_probe_idx,
                 l_row_count,
@@ -1332,7 +1683,7 @@ impl NestedLoopJoinStream {
 
         let l_idx = self.left_probe_idx;
         let joined_batch =
-            self.process_single_left_row_join(&left_data, &right_batch, l_idx)?;
+            self.process_single_left_row_join(&left_batch, &right_batch, l_idx)?;
 
         if let Some(batch) = joined_batch {
             self.output_buffer.push_batch(batch)?;
@@ -1355,6 +1706,7 @@ impl NestedLoopJoinStream {
     fn process_left_range_join(
         &mut self,
         left_data: &JoinLeftData,
+        left_batch: &RecordBatch,
         right_batch: &RecordBatch,
         l_start_index: usize,
         l_row_count: usize,
@@ -1397,7 +1749,7 @@ impl NestedLoopJoinStream {
                     Vec::with_capacity(filter.column_indices().len());
                 for column_index in filter.column_indices() {
                     let array = if column_index.side == JoinSide::Left {
-                        let col = left_data.batch().column(column_index.index);
+                        let col = left_batch.column(column_index.index);
                         take(col.as_ref(), &left_indices, None)?
                     } else {
                         let col = right_batch.column(column_index.index);
@@ -1456,8 +1808,8 @@ impl NestedLoopJoinStream {
             if let Some(bitmap) = left_bitmap.as_mut()
                 && is_matched
             {
-                // Map local index back to absolute left index within the batch
-                bitmap.set_bit(l_index, true);
+                // Map local index back to absolute left index within the left side
+                bitmap.set_bit(self.left_chunk_offset + l_index, true);
             }
 
             if let Some(bitmap) = local_right_bitmap.as_mut()
@@ -1518,7 +1870,7 @@ impl NestedLoopJoinStream {
             Vec::with_capacity(self.output_schema.fields().len());
         for column_index in &self.column_indices {
             let array = if column_index.side == JoinSide::Left {
-                let col = left_data.batch().column(column_index.index);
+                let col = left_batch.column(column_index.index);
                 take(col.as_ref(), &left_indices, None)?
             } else {
                 let col = right_batch.column(column_index.index);
@@ -1539,7 +1891,7 @@ impl NestedLoopJoinStream {
     /// will be set for matched indices.
     fn process_single_left_row_join(
         &mut self,
-        left_data: &JoinLeftData,
+        left_batch: &RecordBatch,
         right_batch: &RecordBatch,
         l_index: usize,
     ) -> Result<Option<RecordBatch>> {
@@ -1549,12 +1901,7 @@ impl NestedLoopJoinStream {
         }
 
         let cur_right_bitmap = if let Some(filter) = &self.join_filter {
-            apply_filter_to_row_join_batch(
-                left_data.batch(),
-                l_index,
-                right_batch,
-                filter,
-            )?
+            apply_filter_to_row_join_batch(left_batch, l_index, right_batch, filter)?
         } else {
             BooleanArray::from(vec![true; right_row_count])
         };
@@ -1582,7 +1929,7 @@ impl NestedLoopJoinStream {
             // Use the optimized approach similar to build_intermediate_batch_for_single_left_row
             let join_batch = build_row_join_batch(
                 &self.output_schema,
-                left_data.batch(),
+                left_batch,
                 l_index,
                 right_batch,
                 Some(cur_right_bitmap),
@@ -1595,10 +1942,10 @@ impl NestedLoopJoinStream {
 
     /// Returns bool to indicate should it continue processing unmatched rows
     /// true -> continue in the same EmitLeftUnmatched state
-    /// false -> next state (Done)
+    /// false -> next state (BufferingLeft for the next left chunk, or Done)
     fn process_left_unmatched(&mut self) -> Result<bool> {
         let left_data = self.get_left_data()?;
-        let left_batch = left_data.batch();
+        let left_batch = self.get_left_batch()?;
 
         // ========
         // Check early return conditions
@@ -1607,8 +1954,8 @@ impl NestedLoopJoinStream {
         // Early return if join type can't have unmatched rows
         let join_type_no_produce_left = !need_produce_result_in_final(self.join_type);
         // Early return if another thread is already processing unmatched rows
-        let handled_by_other_partition =
-            self.left_emit_idx == 0 && !left_data.report_probe_completed();
+        let handled_by_other_partition = self.left_emit_idx == 0
+            && !left_data.report_probe_completed(self.left_chunk_idx)?;
         // Stop processing unmatched rows, the caller will go to the next state
         let finished = self.left_emit_idx >= left_batch.num_rows();
 
@@ -1640,7 +1987,7 @@ impl NestedLoopJoinStream {
     /// Returns a RecordBatch containing the unmatched rows (None if empty).
     ///
     /// # Arguments
-    /// * `left_data` - The left side data containing the batch and bitmap
+    /// * `left_data` - The left side data containing the bitmap
     /// * `start_idx` - Start index (inclusive) of the range to process
     /// * `end_idx` - End index (exclusive) of the range to process
     ///
@@ -1660,7 +2007,7 @@ impl NestedLoopJoinStream {
 
         // Slice both left batch, and bitmap to range [start_idx, end_idx)
         // The range is bit index (not byte)
-        let left_batch = left_data.batch();
+        let left_batch = self.get_left_batch()?;
         let left_batch_sliced = left_batch.slice(start_idx, end_idx - start_idx);
 
         // Can this be more efficient?
@@ -1672,7 +2019,8 @@ impl NestedLoopJoinStream {
                 i - start_idx < bitmap_sliced.capacity(),
                 "DBG: {start_idx}, {end_idx}"
             );
-            bitmap_sliced.set_bit(i - start_idx, bitmap.get_bit(i));
+            bitmap_sliced
+                .set_bit(i - start_idx, bitmap.get_bit(self.left_chunk_offset + i));
         }
         let bitmap_sliced = BooleanArray::new(bitmap_sliced.finish(), None);
 
@@ -1700,8 +2048,7 @@ impl NestedLoopJoinStream {
         let right_batch = self.current_right_batch.take();
         let cur_right_batch = unwrap_or_internal_err!(right_batch);
 
-        let left_data = self.get_left_data()?;
-        let left_schema = left_data.batch().schema();
+        let left_schema = self.get_left_batch()?.schema();
 
         let res = build_unmatched_batch(
             &self.output_schema,
@@ -1719,6 +2066,175 @@ impl NestedLoopJoinStream {
         res
     }
 
+    // ==== Spilled left side ====
+
+    /// Stores the collected left data, and prepares joining it chunk by chunk
+    /// if it has been spilled
+    fn init_left_data(&mut self, left_data: Arc<JoinLeftData>) -> Result<()> {
+        if let Some(spilled) = left_data.spilled() {
+            let Some(config) = &self.spill_config else {
+                return internal_err!(
+                    "Left side has been spilled with spilling disabled"
+                );
+            };
+            let chunk_reservation =
+                MemoryConsumer::new(format!("NestedLoopJoinChunk[{}]", config.partition))
+                    .register(&config.runtime.memory_pool);
+
+            debug!(
+                "NestedLoopJoin partition {} joins {} spilled left chunks",
+                config.partition,
+                spilled.chunks.len()
+            );
+            self.spilled_join = Some(SpilledJoinState {
+                chunk_stream: None,
+                chunk_batches: vec![],
+                chunk_reservation,
+                right_spill_manager: config.spill_manager(self.right_data.schema()),
+                right_writer: None,
+                right_file: None,
+                right_batches_matched: vec![],
+                right_batch_idx: 0,
+            });
+            self.left_buffered_in_one_pass = false;
+        } else {
+            self.current_left_batch = Some(left_data.batch().clone());
+            self.left_exhausted = true;
+        }
+        self.buffered_left_data = Some(left_data);
+        Ok(())
+    }
+
+    /// Loads the left chunk `left_chunk_idx` from disk, and restarts the right
+    /// side from its spill file unless it's the first chunk
+    fn load_left_chunk(&mut self, cx: &mut std::task::Context<'_>) -> Poll<Result<()>> {
+        let left_data = Arc::clone(self.get_left_data()?);
+        let chunk_idx = self.left_chunk_idx;
+        let (Some(spilled), Some(spilled_join)) =
+            (left_data.spilled(), self.spilled_join.as_mut())
+        else {
+            return Poll::Ready(internal_err!("Left side should be spilled"));
+        };
+        let Some(chunk) = spilled.chunks.get(chunk_idx) else {
+            return Poll::Ready(internal_err!("Left chunk {chunk_idx} does not exist"));
+        };
+
+        if spilled_join.chunk_stream.is_none() {
+            // Release the previous chunk before loading the next one
+            self.current_left_batch = None;
+            spilled_join
+                .chunk_reservation
+                .try_resize(chunk.memory_size)?;
+            spilled_join.chunk_stream = Some(
+                spilled
+                    .spill_manager
+                    .read_spill_as_stream(chunk.file.clone(), None)?,
+            );
+        }
+
+        if let Some(chunk_stream) = spilled_join.chunk_stream.as_mut() {
+            while let Some(batch) =
+                ready!(chunk_stream.poll_next_unpin(cx)).transpose()?
+            {
+                spilled_join.chunk_batches.push(batch);
+            }
+        }
+        spilled_join.chunk_stream = None;
+        let batches = std::mem::take(&mut spilled_join.chunk_batches);
+        let chunk_batch = concat_batches(spilled.spill_manager.schema(), &batches)?;
+
+        // Every chunk is joined with the whole right side
+        if chunk_idx > 0 {
+            self.right_data = match &spilled_join.right_file {
+                Some(file) => spilled_join
+                    .right_spill_manager
+                    .read_spill_as_stream(file.clone(), None)?,
+                None => Box::pin(EmptyRecordBatchStream::new(Arc::clone(
+                    spilled_join.right_spill_manager.schema(),
+                ))),
+            };
+        }
+        spilled_join.right_batch_idx = 0;
+
+        self.current_left_batch = Some(chunk_batch);
+        self.left_chunk_offset = chunk.offset;
+        self.left_emit_idx = 0;
+        self.left_exhausted = chunk_idx + 1 == spilled.chunks.len();
+        Poll::Ready(Ok(()))
+    }
+
+    /// Writes `right_batch` to disk while joining the first of several left
+    /// chunks, so that the right side can be re-scanned for the other chunks
+    fn maybe_spill_right_batch(&mut self, right_batch: &RecordBatch) -> Result<()> {
+        let Some(spilled_join) = self.spilled_join.as_mut() else {
+            return Ok(());
+        };
+        spilled_join.right_batch_idx += 1;
+        if self.left_chunk_idx > 0 || self.left_exhausted {
+            return Ok(());
+        }
+
+        let writer = match &mut spilled_join.right_writer {
+            Some(writer) => writer,
+            writer => writer.insert(
+                spilled_join
+                    .right_spill_manager
+                    .create_in_progress_file("NestedLoopJoin probe side")?,
+            ),
+        };
+        writer.append_batch(right_batch)
+    }
+
+    /// Returns the matched flags of the next right batch from joining the
+    /// previous left chunks, `None` if no left chunk has been joined yet
+    fn previous_right_matched(&self) -> Result<Option<BooleanBuffer>> {
+        let Some(spilled_join) = self.spilled_join.as_ref() else {
+            return Ok(None);
+        };
+        if self.left_chunk_idx == 0 {
+            return Ok(None);
+        }
+        spilled_join
+            .right_batches_matched
+            .get(spilled_join.right_batch_idx)
+            .cloned()
+            .map(Some)
+            .ok_or_else(|| {
+                internal_datafusion_err!("Matched flags of the right batch are missing")
+            })
+    }
+
+    /// Stores the matched flags of the current right batch, to be combined
+    /// with the matches of the following left chunks
+    fn store_right_matched(&mut self) -> Result<()> {
+        let matched =
+            std::mem::take(&mut self.current_right_batch_matched).ok_or_else(|| {
+                internal_datafusion_err!("right bitmap should be available")
+            })?;
+        let spilled_join = self
+            .spilled_join
+            .as_mut()
+            .ok_or_else(|| internal_datafusion_err!("Left side should be spilled"))?;
+
+        // `right_batch_idx` has been advanced when fetching the batch
+        let idx = spilled_join.right_batch_idx - 1;
+        let (matched, _) = matched.into_parts();
+        if self.left_chunk_idx == 0 {
+            spilled_join.right_batches_matched.push(matched);
+        } else {
+            spilled_join.right_batches_matched[idx] = matched;
+        }
+        Ok(())
+    }
+
+    /// Releases the memory of the last left chunk
+    fn release_left_chunk(&mut self) {
+        if let Some(spilled_join) = self.spilled_join.as_mut() {
+            self.current_left_batch = None;
+            spilled_join.chunk_reservation.free();
+        }
+    }
+
     // ==== Utilities ====
 
     /// Get the build-side data of the left input, errors if it's None
@@ -1728,6 +2244,14 @@ impl NestedLoopJoinStream {
             .ok_or_else(|| internal_datafusion_err!("LeftData should be available"))
     }
 
+    /// Get the left rows the right side is currently joined with, errors if
+    /// it's None
+    fn get_left_batch(&self) -> Result<&RecordBatch> {
+        self.current_left_batch
+            .as_ref()
+            .ok_or_else(|| internal_datafusion_err!("Left batch should be available"))
+    }
+
     /// Flush the `output_buffer` if there are batches ready to output
     /// None if no result batch ready.
     fn maybe_flush_ready_batch(&mut self) -> Option<Poll<Option<Result<RecordBatch>>>> {
@@ -1772,7 +2296,7 @@ impl NestedLoopJoinStream {
         // 1. Maybe update the left bitmap
         if need_produce_result_in_final(self.join_type) && (joined_len > 0) {
             let mut bitmap = left_data.bitmap().lock();
-            bitmap.set_bit(l_index, true);
+            bitmap.set_bit(self.left_chunk_offset + l_index, true);
         }
 
         // 2. Maybe updateh the right bitmap
@@ -2809,6 +3333,83 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    /// Builds a table of `num_batches` batches, which do not share buffers, so
+    /// that every batch is accounted for its own size
+    fn build_table_batches(
+        names: (&str, &str, &str),
+        num_batches: usize,
+        batch_rows: usize,
+    ) -> Arc<dyn ExecutionPlan> {
+        let batches = (0..num_batches)
+            .map(|i| {
+                let a = (i * batch_rows..(i + 1) * batch_rows)
+                    .map(|v| v as i32)
+                    .collect::<Vec<_>>();
+                let b = a.iter().map(|v| v % 10).collect::<Vec<_>>();
+                build_table_i32((names.0, &a), (names.1, &b), (names.2, &a))
+            })
+            .collect::<Vec<_>>();
+        let schema = batches[0].schema();
+        TestMemoryExec::try_new_exec(&[batches], schema, None).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_spill_left_side() -> Result<()> {
+        let left = build_table_batches(("a1", "b1", "c1"), 20, 100);
+        let right = build_table_batches(("a2", "b2", "c2"), 4, 5);
+        let filter = prepare_join_filter();
+
+        let join_types = vec![
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+            JoinType::LeftSemi,
+            JoinType::LeftAnti,
+            JoinType::LeftMark,
+            JoinType::RightSemi,
+            JoinType::RightAnti,
+            JoinType::RightMark,
+        ];
+
+        for join_type in join_types {
+            let (_, expected, _) = multi_partitioned_join_collect(
+                Arc::clone(&left),
+                Arc::clone(&right),
+                &join_type,
+                Some(filter.clone()),
+                new_task_ctx(64),
+            )
+            .await?;
+
+            // The left side does not fit in memory, but every chunk of it does
+            let runtime = RuntimeEnvBuilder::new()
+                .with_memory_limit(16 * 1024, 1.0)
+                .build_arc()?;
+            let task_ctx = TaskContext::default().with_runtime(runtime);
+            let cfg = task_ctx.session_config().clone().with_batch_size(64);
+            let task_ctx = Arc::new(task_ctx.with_session_config(cfg));
+            let (_, batches, metrics) = multi_partitioned_join_collect(
+                Arc::clone(&left),
+                Arc::clone(&right),
+                &join_type,
+                Some(filter.clone()),
+                Arc::clone(&task_ctx),
+            )
+            .await?;
+
+            assert_eq!(
+                batches_to_sort_string(&batches),
+                batches_to_sort_string(&expected),
+                "{join_type}"
+            );
+            assert!(metrics.spill_count().unwrap() > 0, "{join_type}");
+            assert_eq!(task_ctx.runtime_env().memory_pool.reserved(), 0);
+        }
+
+        Ok(())
+    }
+
     /// Returns the column names on the schema
     fn columns(schema: &Schema) -> Vec<String> {
         schema.fields().iter().map(|f| f.name().clone()).collect()
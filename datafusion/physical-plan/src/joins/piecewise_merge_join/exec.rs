@@ -22,8 +22,7 @@ use arrow::{
     util::bit_util,
 };
 use arrow_schema::{SchemaRef, SortOptions};
-use datafusion_common::not_impl_err;
-use datafusion_common::{JoinSide, Result, internal_err};
+use datafusion_common::{JoinSide, Result, ScalarValue, internal_err};
 use datafusion_execution::{
     SendableRecordBatchStream,
     memory_pool::{MemoryConsumer, MemoryReservation},
@@ -46,6 +45,7 @@ use crate::execution_plan::{EmissionType, boundedness_from_children};
 use crate::joins::piecewise_merge_join::classic_join::{
     ClassicPWMJStream, PiecewiseMergeJoinStreamState,
 };
+use crate::joins::piecewise_merge_join::existence_join::{ExistencePWMJStream, extremum};
 use crate::joins::piecewise_merge_join::utils::{
     build_visited_indices_map, is_existence_join, is_right_existence_join,
 };
@@ -160,65 +160,63 @@ use crate::{
 /// ```
 ///
 /// ## Existence Joins (Semi, Anti, Mark)
-/// Existence joins are made magnitudes of times faster with a `PiecewiseMergeJoin` as we only need to find
-/// the min/max value of the streamed side to be able to emit all matches on the buffered side. By putting
-/// the side we need to mark onto the sorted buffer side, we can emit all these matches at once.
+/// Existence joins are made magnitudes of times faster with a `PiecewiseMergeJoin` as a row only needs to be
+/// compared against the min/max value of the other side to know whether it has any match. Neither input needs
+/// to be sorted.
 ///
-/// For less than operations (`<`) both inputs are to be sorted in descending order and vice versa for greater
-/// than (`>`) operations. `SortExec` is used to enforce sorting on the buffered side and streamed side does not
-/// need to be sorted due to only needing to find the min/max.
+/// For Right Semi, Anti, and Mark joins the min/max of the buffered side is computed once, and every streamed
+/// batch is filtered (or marked) against it as it arrives.
 ///
-/// For Left Semi, Anti, and Mark joins we swap the inputs so that the marked side is on the buffered side.
+/// For Left Semi, Anti, and Mark joins the min/max of the streamed side is accumulated across all streamed
+/// partitions. Once the last partition is exhausted the buffered side is filtered (or marked) against it.
 ///
 /// The pseudocode for the algorithm looks like this:
 ///
 /// ```text
-/// // Using the example of a less than `<` operation
-/// let max = max_batch(streamed_batch)
+/// // Using the example of a less than `<` operation for a left existence join
+/// let max = max(streamed_side)
 ///
 /// for buffer_row in buffer_batch:
 ///     if buffer_row < max:
-///         output buffer_batch[buffer_row:]
+///         output buffer_row
 /// ```
 ///
 /// Only need to find the min/max value and iterate through the buffered side once.
 ///
 /// Here is an example:
 /// We perform a `JoinType::LeftSemi` with these two batches and the operator being `Operator::Lt`(<). Because
-/// the operator is `Operator::Lt` we can find the minimum value in the streamed side; in this case it is 200.
-/// We can then advance a pointer from the start of the buffer side until we find the first value that satisfies
-/// the predicate. All rows after that first matched value satisfy the condition 200 < x so we can mark all of
-/// those rows as matched.
+/// the operator is `Operator::Lt` we find the maximum value in the streamed side; in this case it is 500.
+/// Every buffered row less than 500 has at least one match, so all rows on the buffered side are emitted.
 ///
 /// ```text
 /// SQL statement:
 /// SELECT *
-/// FROM (VALUES (500), (200), (300)) AS streamed(a)
-/// LEFT SEMI JOIN (VALUES (100), (200), (200), (300), (400)) AS buffered(b)
-///   ON streamed.a < buffered.b;
+/// FROM (VALUES (100), (200), (200), (300), (400)) AS buffered(b)
+/// LEFT SEMI JOIN (VALUES (500), (200), (300)) AS streamed(a)
+///   ON buffered.b < streamed.a;
 ///
-///          Sorted Buffered Side             Unsorted Streamed Side
+///             Buffered Side                    Streamed Side
 ///            ┌──────────────────┐          ┌──────────────────┐
-///          1 │       100        │        1 │       500        │
-///            ├──────────────────┤          ├──────────────────┤
-///          2 │       200        │        2 │       200        │
-///            ├──────────────────┤          ├──────────────────┤    
-///          3 │       200        │        3 │       300        │
-///            ├──────────────────┤          └──────────────────┘
-///          4 │       300        │ ─┐       
-///            ├──────────────────┤  | We emit matches for row 4 - 5
+///          1 │       100        │ ─┐     1 │       500        │
+///            ├──────────────────┤  |       ├──────────────────┤
+///          2 │       200        │  |     2 │       200        │
+///            ├──────────────────┤  |       ├──────────────────┤
+///          3 │       200        │  |     3 │       300        │
+///            ├──────────────────┤  |       └──────────────────┘
+///          4 │       300        │  |        max value: 500
+///            ├──────────────────┤  | We emit rows 1 - 5
 ///          5 │       400        │ ─┘ on the buffered side.
 ///            └──────────────────┘
-///             min value: 200
 /// ```
 ///
-/// For both types of joins, the buffered side must be sorted ascending for `Operator::Lt` (<) or
+/// For classic joins, the buffered side must be sorted ascending for `Operator::Lt` (<) or
 /// `Operator::LtEq` (<=) and descending for `Operator::Gt` (>) or `Operator::GtEq` (>=).
 ///
 /// # Partitioning Logic
 /// Piecewise Merge Join requires one buffered side partition + round robin partitioned stream side. A counter
 /// is used in the buffered side to coordinate when all streamed partitions are finished execution. This allows
-/// for processing the rest of the unmatched rows for Left and Full joins. The last partition that finishes
+/// for processing the rest of the unmatched rows for Left and Full joins, and the buffered rows of Left
+/// Semi, Anti, and Mark joins. The last partition that finishes
 /// execution will be responsible for outputting the unmatched rows.
 ///
 /// # Performance Explanation (cost)
@@ -293,32 +291,11 @@ impl PiecewiseMergeJoinExec {
         join_type: JoinType,
         num_partitions: usize,
     ) -> Result<Self> {
-        // TODO: Implement existence joins for PiecewiseMergeJoin
-        if is_existence_join(join_type) {
-            return not_impl_err!(
-                "Existence Joins are currently not supported for PiecewiseMergeJoin"
-            );
-        }
-
         // Take the operator and enforce a sort order on the streamed + buffered side based on
         // the operator type.
         let sort_options = match operator {
-            Operator::Lt | Operator::LtEq => {
-                // For left existence joins the inputs will be swapped so the sort
-                // options are switched
-                if is_right_existence_join(join_type) {
-                    SortOptions::new(false, true)
-                } else {
-                    SortOptions::new(true, true)
-                }
-            }
-            Operator::Gt | Operator::GtEq => {
-                if is_right_existence_join(join_type) {
-                    SortOptions::new(true, true)
-                } else {
-                    SortOptions::new(false, true)
-                }
-            }
+            Operator::Lt | Operator::LtEq => SortOptions::new(true, true),
+            Operator::Gt | Operator::GtEq => SortOptions::new(false, true),
             _ => {
                 return internal_err!(
                     "Cannot contain non-range operator in PiecewiseMergeJoinExec"
@@ -435,25 +412,32 @@ impl PiecewiseMergeJoinExec {
         let output_partitioning =
             asymmetric_join_output_partitioning(buffered, streamed, &join_type)?;
 
+        // Left existence joins only emit the buffered rows once all streamed
+        // partitions are finished
+        let emission_type =
+            if is_existence_join(join_type) && !is_right_existence_join(join_type) {
+                EmissionType::Final
+            } else {
+                EmissionType::Incremental
+            };
+
         Ok(PlanProperties::new(
             eq_properties,
             output_partitioning,
-            EmissionType::Incremental,
+            emission_type,
             boundedness_from_children([buffered, streamed]),
         ))
     }
 
-    // TODO: Add input order. Now they're all `false` indicating it will not maintain the input order.
-    // However, for certain join types the order is maintained. This can be updated in the future after
-    // more testing.
     fn maintains_input_order(join_type: JoinType) -> Vec<bool> {
         match join_type {
-            // The existence side is expected to come in sorted
+            // Existence joins filter or mark the rows of the existence side in
+            // their input order
             JoinType::LeftSemi | JoinType::LeftAnti | JoinType::LeftMark => {
-                vec![false, false]
+                vec![true, false]
             }
             JoinType::RightSemi | JoinType::RightAnti | JoinType::RightMark => {
-                vec![false, false]
+                vec![false, true]
             }
             // Left, Right, Full, Inner Join is not guaranteed to maintain
             // input order as the streamed side will be sorted during
@@ -493,9 +477,10 @@ impl ExecutionPlan for PiecewiseMergeJoinExec {
     }
 
     fn required_input_ordering(&self) -> Vec<Option<OrderingRequirements>> {
-        // Existence joins don't need to be sorted on one side.
-        if is_right_existence_join(self.join_type) {
-            unimplemented!()
+        // Existence joins only compare against the min/max of the other side, so
+        // neither side needs to be sorted
+        if is_existence_join(self.join_type) {
+            vec![None, None]
         } else {
             // Sort the right side in memory, so we do not need to enforce any sorting
             vec![
@@ -546,7 +531,8 @@ impl ExecutionPlan for PiecewiseMergeJoinExec {
                 Arc::clone(&on_buffered),
                 metrics.clone(),
                 reservation,
-                build_visited_indices_map(self.join_type),
+                self.join_type,
+                self.operator,
                 self.num_partitions,
             ))
         })?;
@@ -555,9 +541,17 @@ impl ExecutionPlan for PiecewiseMergeJoinExec {
 
         let batch_size = context.session_config().batch_size();
 
-        // TODO: Add existence joins + this is guarded at physical planner
         if is_existence_join(self.join_type()) {
-            unreachable!()
+            Ok(Box::pin(ExistencePWMJStream::try_new(
+                Arc::clone(&self.schema),
+                on_streamed,
+                self.join_type,
+                self.operator,
+                streamed,
+                BufferedSide::Initial(BufferedSideInitialState { buffered_fut }),
+                metrics,
+                batch_size,
+            )))
         } else {
             Ok(Box::pin(ClassicPWMJStream::try_new(
                 Arc::clone(&self.schema),
@@ -613,7 +607,8 @@ async fn build_buffered_data(
     on_buffered: PhysicalExprRef,
     metrics: BuildProbeJoinMetrics,
     reservation: MemoryReservation,
-    build_map: bool,
+    join_type: JoinType,
+    operator: Operator,
     remaining_partitions: usize,
 ) -> Result<BufferedSideData> {
     let schema = buffered.schema();
@@ -650,7 +645,7 @@ async fn build_buffered_data(
     metrics.build_mem_used.add(size_estimation);
 
     // Created visited indices bitmap only if the join type requires it
-    let visited_indices_bitmap = if build_map {
+    let visited_indices_bitmap = if build_visited_indices_map(join_type) {
         let bitmap_size = bit_util::ceil(single_batch.num_rows(), 8);
         reservation.try_grow(bitmap_size)?;
         metrics.build_mem_used.add(bitmap_size);
@@ -662,11 +657,23 @@ async fn build_buffered_data(
         BooleanBufferBuilder::new(0)
     };
 
+    // Right existence joins compare every streamed row against the min/max of
+    // the buffered values
+    let bound = if is_right_existence_join(join_type) {
+        extremum(
+            &buffered_values,
+            matches!(operator, Operator::Gt | Operator::GtEq),
+        )?
+    } else {
+        None
+    };
+
     let buffered_data = BufferedSideData::new(
         single_batch,
         buffered_values,
         Mutex::new(visited_indices_bitmap),
         remaining_partitions,
+        bound,
         reservation,
     );
 
@@ -678,6 +685,11 @@ pub(super) struct BufferedSideData {
     values: ArrayRef,
     pub(super) visited_indices_bitmap: SharedBitmapBuilder,
     pub(super) remaining_partitions: AtomicUsize,
+    /// The min/max of the buffered values, for right existence joins
+    pub(super) bound: Option<ScalarValue>,
+    /// The min/max of the streamed values seen so far by all partitions, for
+    /// left existence joins
+    pub(super) streamed_bound: Mutex<Option<ScalarValue>>,
     _reservation: MemoryReservation,
 }
 
@@ -687,6 +699,7 @@ impl BufferedSideData {
         values: ArrayRef,
         visited_indices_bitmap: SharedBitmapBuilder,
         remaining_partitions: usize,
+        bound: Option<ScalarValue>,
         reservation: MemoryReservation,
     ) -> Self {
         Self {
@@ -694,6 +707,8 @@ impl BufferedSideData {
             values,
             visited_indices_bitmap,
             remaining_partitions: AtomicUsize::new(remaining_partitions),
+            bound,
+            streamed_bound: Mutex::new(None),
             _reservation: reservation,
         }
     }
@@ -0,0 +1,533 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Stream Implementation for PiecewiseMergeJoin's Existence Join (Semi, Anti, Mark)
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Datum, RecordBatch, RecordBatchOptions,
+};
+use arrow::compute::kernels::cmp::{gt, gt_eq, lt, lt_eq};
+use arrow::compute::{filter_record_batch, not, prep_null_mask_filter, sort_to_indices};
+use arrow_schema::{SchemaRef, SortOptions};
+use datafusion_common::{Result, ScalarValue, internal_err};
+use datafusion_execution::{RecordBatchStream, SendableRecordBatchStream};
+use datafusion_expr::{JoinType, Operator};
+use datafusion_physical_expr::PhysicalExprRef;
+use futures::{Stream, StreamExt};
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::task::{Context, Poll, ready};
+
+use crate::handle_state;
+use crate::joins::piecewise_merge_join::exec::{BufferedSide, BufferedSideReadyState};
+use crate::joins::piecewise_merge_join::utils::is_right_existence_join;
+use crate::joins::utils::{BuildProbeJoinMetrics, StatefulStreamResult};
+
+pub(super) enum ExistencePWMJStreamState {
+    WaitBufferedSide,
+    FetchStreamBatch,
+    /// Emits the buffered rows of a left existence join in slices of the
+    /// batch size, starting at the given offset
+    EmitBuffered(RecordBatch, usize),
+    Completed,
+}
+
+/// Stream for existence joins, which only need to know whether a row has any
+/// match on the other side.
+///
+/// For the predicate `buffered op streamed`, a row has a match if and only if
+/// it satisfies the predicate against the extremum of the other side: the
+/// maximum for `<` and `<=` and the minimum for `>` and `>=` when the other
+/// side is on the right, and the other way around when it is on the left.
+///
+/// Right existence joins compute the extremum of the buffered side once and
+/// filter or mark every streamed batch as it arrives. Left existence joins
+/// fold the extremum of all streamed partitions into the buffered side data,
+/// and the last partition to finish filters or marks the buffered rows.
+pub(super) struct ExistencePWMJStream {
+    // Output schema of the `PiecewiseMergeJoin`
+    schema: SchemaRef,
+    // Physical expression that is evaluated on the streamed side
+    on_streamed: PhysicalExprRef,
+    // Type of join, one of the existence joins
+    join_type: JoinType,
+    // Comparison operator
+    operator: Operator,
+    // Streamed batch
+    streamed: SendableRecordBatchStream,
+    // Buffered side data
+    buffered_side: BufferedSide,
+    // Tracks the state of the `PiecewiseMergeJoin`
+    state: ExistencePWMJStreamState,
+    // Metrics for build + probe joins
+    join_metrics: BuildProbeJoinMetrics,
+    // Maximum number of rows of the emitted buffered batches
+    batch_size: usize,
+}
+
+impl RecordBatchStream for ExistencePWMJStream {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+impl ExistencePWMJStream {
+    #[expect(clippy::too_many_arguments)]
+    pub fn try_new(
+        schema: SchemaRef,
+        on_streamed: PhysicalExprRef,
+        join_type: JoinType,
+        operator: Operator,
+        streamed: SendableRecordBatchStream,
+        buffered_side: BufferedSide,
+        join_metrics: BuildProbeJoinMetrics,
+        batch_size: usize,
+    ) -> Self {
+        Self {
+            schema,
+            on_streamed,
+            join_type,
+            operator,
+            streamed,
+            buffered_side,
+            state: ExistencePWMJStreamState::WaitBufferedSide,
+            join_metrics,
+            batch_size,
+        }
+    }
+
+    fn poll_next_impl(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        loop {
+            return match self.state {
+                ExistencePWMJStreamState::WaitBufferedSide => {
+                    handle_state!(ready!(self.collect_buffered_side(cx)))
+                }
+                ExistencePWMJStreamState::FetchStreamBatch => {
+                    handle_state!(ready!(self.fetch_stream_batch(cx)))
+                }
+                ExistencePWMJStreamState::EmitBuffered(_, _) => {
+                    handle_state!(self.emit_buffered())
+                }
+                ExistencePWMJStreamState::Completed => Poll::Ready(None),
+            };
+        }
+    }
+
+    // Collects buffered side data
+    fn collect_buffered_side(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<StatefulStreamResult<Option<RecordBatch>>>> {
+        let build_timer = self.join_metrics.build_time.timer();
+        let buffered_data = ready!(
+            self.buffered_side
+                .try_as_initial_mut()?
+                .buffered_fut
+                .get_shared(cx)
+        )?;
+        build_timer.done();
+
+        self.state = ExistencePWMJStreamState::FetchStreamBatch;
+        self.buffered_side =
+            BufferedSide::Ready(BufferedSideReadyState { buffered_data });
+
+        Poll::Ready(Ok(StatefulStreamResult::Continue))
+    }
+
+    // Filters or marks incoming stream batches for right existence joins, and
+    // folds their extremum into the buffered side data for left existence joins
+    fn fetch_stream_batch(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<StatefulStreamResult<Option<RecordBatch>>>> {
+        let buffered_data = Arc::clone(&self.buffered_side.try_as_ready()?.buffered_data);
+        let batch = match ready!(self.streamed.poll_next_unpin(cx)) {
+            None => {
+                let is_last_partition = buffered_data
+                    .remaining_partitions
+                    .fetch_sub(1, Ordering::SeqCst)
+                    == 1;
+                self.state = if is_last_partition
+                    && !is_right_existence_join(self.join_type)
+                {
+                    let timer = self.join_metrics.join_time.timer();
+                    let bound = buffered_data.streamed_bound.lock().clone();
+                    let mask = match bound {
+                        Some(bound) => compare(
+                            self.operator,
+                            buffered_data.values(),
+                            &bound.to_scalar()?,
+                        )?,
+                        None => BooleanArray::from(vec![
+                            false;
+                            buffered_data.batch().num_rows()
+                        ]),
+                    };
+                    let batch = existence_output(
+                        &self.schema,
+                        buffered_data.batch(),
+                        &mask,
+                        self.join_type,
+                    )?;
+                    timer.done();
+                    ExistencePWMJStreamState::EmitBuffered(batch, 0)
+                } else {
+                    ExistencePWMJStreamState::Completed
+                };
+                return Poll::Ready(Ok(StatefulStreamResult::Continue));
+            }
+            Some(batch) => batch?,
+        };
+
+        self.join_metrics.input_batches.add(1);
+        self.join_metrics.input_rows.add(batch.num_rows());
+        let _timer = self.join_metrics.join_time.timer();
+
+        // Evaluate the streamed physical expression on the stream batch
+        let stream_values = self
+            .on_streamed
+            .evaluate(&batch)?
+            .into_array(batch.num_rows())?;
+
+        if is_right_existence_join(self.join_type) {
+            let mask = match &buffered_data.bound {
+                Some(bound) => {
+                    compare(self.operator, &bound.to_scalar()?, &stream_values)?
+                }
+                None => BooleanArray::from(vec![false; batch.num_rows()]),
+            };
+            let output = existence_output(&self.schema, &batch, &mask, self.join_type)?;
+            if output.num_rows() == 0 {
+                return Poll::Ready(Ok(StatefulStreamResult::Continue));
+            }
+            Poll::Ready(Ok(StatefulStreamResult::Ready(Some(output))))
+        } else {
+            let use_max = matches!(self.operator, Operator::Lt | Operator::LtEq);
+            if let Some(extremum) = extremum(&stream_values, use_max)? {
+                let mut bound = buffered_data.streamed_bound.lock();
+                let replace = bound.as_ref().is_none_or(|bound| {
+                    let ordering = extremum.partial_cmp(bound);
+                    if use_max {
+                        ordering.is_some_and(|o| o.is_gt())
+                    } else {
+                        ordering.is_some_and(|o| o.is_lt())
+                    }
+                });
+                if replace {
+                    *bound = Some(extremum);
+                }
+            }
+            Poll::Ready(Ok(StatefulStreamResult::Continue))
+        }
+    }
+
+    // Emits the buffered rows of a left existence join
+    fn emit_buffered(&mut self) -> Result<StatefulStreamResult<Option<RecordBatch>>> {
+        let ExistencePWMJStreamState::EmitBuffered(batch, offset) = &mut self.state
+        else {
+            return internal_err!("Expected buffered batch in EmitBuffered");
+        };
+        if *offset >= batch.num_rows() {
+            self.state = ExistencePWMJStreamState::Completed;
+            return Ok(StatefulStreamResult::Ready(None));
+        }
+        let len = self.batch_size.min(batch.num_rows() - *offset);
+        let output = batch.slice(*offset, len);
+        *offset += len;
+        Ok(StatefulStreamResult::Ready(Some(output)))
+    }
+}
+
+impl Stream for ExistencePWMJStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.poll_next_impl(cx);
+        self.join_metrics.baseline.record_poll(poll)
+    }
+}
+
+/// Returns the maximum (or minimum) non-null value of `values`, or `None` if
+/// there is none
+pub(super) fn extremum(values: &ArrayRef, max: bool) -> Result<Option<ScalarValue>> {
+    let options = SortOptions::new(max, false);
+    let indices = sort_to_indices(values, Some(options), Some(1))?;
+    if indices.is_empty() || values.is_null(indices.value(0) as usize) {
+        return Ok(None);
+    }
+    Ok(Some(ScalarValue::try_from_array(
+        values,
+        indices.value(0) as usize,
+    )?))
+}
+
+/// Evaluates `lhs op rhs`, treating nulls as not matching
+fn compare(op: Operator, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray> {
+    let mask = match op {
+        Operator::Lt => lt(lhs, rhs)?,
+        Operator::LtEq => lt_eq(lhs, rhs)?,
+        Operator::Gt => gt(lhs, rhs)?,
+        Operator::GtEq => gt_eq(lhs, rhs)?,
+        _ => {
+            return internal_err!(
+                "Cannot contain non-range operator in PiecewiseMergeJoinExec"
+            );
+        }
+    };
+    Ok(if mask.null_count() > 0 {
+        prep_null_mask_filter(&mask)
+    } else {
+        mask
+    })
+}
+
+/// Returns the rows of `batch` with a match for semi joins, the rows without
+/// one for anti joins, or all rows with a `mark` column for mark joins
+fn existence_output(
+    schema: &SchemaRef,
+    batch: &RecordBatch,
+    matched: &BooleanArray,
+    join_type: JoinType,
+) -> Result<RecordBatch> {
+    let (columns, num_rows) = match join_type {
+        JoinType::LeftSemi | JoinType::RightSemi => {
+            let filtered = filter_record_batch(batch, matched)?;
+            (filtered.columns().to_vec(), filtered.num_rows())
+        }
+        JoinType::LeftAnti | JoinType::RightAnti => {
+            let filtered = filter_record_batch(batch, &not(matched)?)?;
+            (filtered.columns().to_vec(), filtered.num_rows())
+        }
+        JoinType::LeftMark | JoinType::RightMark => {
+            let mut columns = batch.columns().to_vec();
+            columns.push(Arc::new(matched.clone()));
+            (columns, batch.num_rows())
+        }
+        _ => {
+            return internal_err!(
+                "Expected an existence join for PiecewiseMergeJoin, got {join_type}"
+            );
+        }
+    };
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    Ok(RecordBatch::try_new_with_options(
+        Arc::clone(schema),
+        columns,
+        &options,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ExecutionPlan, common,
+        joins::PiecewiseMergeJoinExec,
+        test::{TestMemoryExec, build_table_i32},
+    };
+    use arrow::array::Int32Array;
+    use datafusion_common::test_util::batches_to_string;
+    use datafusion_execution::TaskContext;
+    use datafusion_physical_expr::expressions::Column;
+    use insta::assert_snapshot;
+
+    fn build_table(
+        a: (&str, &Vec<i32>),
+        b: (&str, &Vec<i32>),
+        c: (&str, &Vec<i32>),
+    ) -> Arc<dyn ExecutionPlan> {
+        let batch = build_table_i32(a, b, c);
+        let schema = batch.schema();
+        TestMemoryExec::try_new_exec(&[vec![batch]], schema, None).unwrap()
+    }
+
+    fn left_table() -> Arc<dyn ExecutionPlan> {
+        build_table(
+            ("a1", &vec![1, 2, 3, 4]),
+            ("b1", &vec![100, 200, 300, 400]),
+            ("c1", &vec![7, 8, 9, 10]),
+        )
+    }
+
+    fn right_table() -> Arc<dyn ExecutionPlan> {
+        build_table(
+            ("a2", &vec![10, 20, 30]),
+            ("b2", &vec![250, 150, 350]),
+            ("c2", &vec![70, 80, 90]),
+        )
+    }
+
+    async fn join_collect(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        operator: Operator,
+        join_type: JoinType,
+    ) -> Result<Vec<RecordBatch>> {
+        let on = (
+            Arc::new(Column::new_with_schema("b1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("b2", &right.schema())?) as _,
+        );
+        let join =
+            PiecewiseMergeJoinExec::try_new(left, right, on, operator, join_type, 1)?;
+        let stream = join.execute(0, Arc::new(TaskContext::default()))?;
+        common::collect(stream).await
+    }
+
+    #[tokio::test]
+    async fn join_left_semi_less_than() -> Result<()> {
+        // Left rows with b1 < max(b2) = 350
+        let batches = join_collect(
+            left_table(),
+            right_table(),
+            Operator::Lt,
+            JoinType::LeftSemi,
+        )
+        .await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+-----+----+
+        | a1 | b1  | c1 |
+        +----+-----+----+
+        | 1  | 100 | 7  |
+        | 2  | 200 | 8  |
+        | 3  | 300 | 9  |
+        +----+-----+----+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_left_anti_greater_than() -> Result<()> {
+        // Left rows without b1 > min(b2) = 150
+        let batches = join_collect(
+            left_table(),
+            right_table(),
+            Operator::Gt,
+            JoinType::LeftAnti,
+        )
+        .await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+-----+----+
+        | a1 | b1  | c1 |
+        +----+-----+----+
+        | 1  | 100 | 7  |
+        +----+-----+----+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_left_mark_greater_than_or_equal() -> Result<()> {
+        // b1 >= min(b2) = 150
+        let batches = join_collect(
+            left_table(),
+            right_table(),
+            Operator::GtEq,
+            JoinType::LeftMark,
+        )
+        .await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+-----+----+-------+
+        | a1 | b1  | c1 | mark  |
+        +----+-----+----+-------+
+        | 1  | 100 | 7  | false |
+        | 2  | 200 | 8  | true  |
+        | 3  | 300 | 9  | true  |
+        | 4  | 400 | 10 | true  |
+        +----+-----+----+-------+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_right_semi_less_than_or_equal() -> Result<()> {
+        // Right rows with min(b1) = 200 <= b2
+        let left = build_table(
+            ("a1", &vec![1, 2]),
+            ("b1", &vec![300, 200]),
+            ("c1", &vec![7, 8]),
+        );
+        let batches =
+            join_collect(left, right_table(), Operator::LtEq, JoinType::RightSemi)
+                .await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+-----+----+
+        | a2 | b2  | c2 |
+        +----+-----+----+
+        | 10 | 250 | 70 |
+        | 30 | 350 | 90 |
+        +----+-----+----+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_right_anti_and_mark_greater_than() -> Result<()> {
+        // Right rows with max(b1) = 400 > b2 match
+        let batches = join_collect(
+            left_table(),
+            right_table(),
+            Operator::Gt,
+            JoinType::RightAnti,
+        )
+        .await?;
+        assert!(batches.is_empty());
+
+        let left = build_table(
+            ("a1", &vec![1, 2]),
+            ("b1", &vec![200, 100]),
+            ("c1", &vec![7, 8]),
+        );
+        let batches =
+            join_collect(left, right_table(), Operator::Gt, JoinType::RightMark).await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+-----+----+-------+
+        | a2 | b2  | c2 | mark  |
+        +----+-----+----+-------+
+        | 10 | 250 | 70 | false |
+        | 20 | 150 | 80 | true  |
+        | 30 | 350 | 90 | false |
+        +----+-----+----+-------+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_existence_empty_side() -> Result<()> {
+        let empty = build_table(("a2", &vec![]), ("b2", &vec![]), ("c2", &vec![]));
+        let batches =
+            join_collect(left_table(), empty, Operator::Lt, JoinType::LeftAnti).await?;
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn extremum_skips_nulls() -> Result<()> {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![None, Some(3), Some(1)]));
+        assert_eq!(extremum(&values, true)?, Some(ScalarValue::Int32(Some(3))));
+        assert_eq!(extremum(&values, false)?, Some(ScalarValue::Int32(Some(1))));
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![None, None]));
+        assert_eq!(extremum(&values, true)?, None);
+        Ok(())
+    }
+}
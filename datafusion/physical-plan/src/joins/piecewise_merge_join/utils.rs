@@ -45,17 +45,8 @@ pub(super) fn need_produce_result_in_final(join_type: JoinType) -> bool {
 }
 
 // Returns boolean for whether or not we need to build the buffered side
-// bitmap for marking matched rows on the buffered side.
+// bitmap for marking matched rows on the buffered side. Existence joins
+// compare against the min/max of the other side instead.
 pub(super) fn build_visited_indices_map(join_type: JoinType) -> bool {
-    matches!(
-        join_type,
-        JoinType::Full
-            | JoinType::Left
-            | JoinType::LeftAnti
-            | JoinType::RightAnti
-            | JoinType::LeftSemi
-            | JoinType::RightSemi
-            | JoinType::LeftMark
-            | JoinType::RightMark
-    )
+    matches!(join_type, JoinType::Full | JoinType::Left)
 }
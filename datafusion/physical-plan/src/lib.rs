@@ -78,12 +78,14 @@ pub mod filter;
 pub mod filter_pushdown;
 pub mod joins;
 pub mod limit;
+pub mod match_recognize;
 pub mod memory;
 pub mod metrics;
 pub mod placeholder_row;
 pub mod projection;
 pub mod recursive_query;
 pub mod repartition;
+pub mod sample;
 pub mod sort_pushdown;
 pub mod sorts;
 pub mod spill;
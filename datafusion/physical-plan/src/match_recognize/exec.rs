@@ -0,0 +1,851 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the MATCH_RECOGNIZE plan
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use super::matcher::PatternMatcher;
+use crate::execution_plan::{EmissionType, boundedness_from_children};
+use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::spill::get_record_batch_memory_size;
+use crate::windows::calc_requirements;
+use crate::{
+    DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, ExecutionPlanProperties,
+    Partitioning, PlanProperties, RecordBatchStream, SendableRecordBatchStream,
+};
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, Int64Array, RecordBatch, RecordBatchOptions, StringArray,
+    UInt64Array,
+};
+use arrow::compute::{SortColumn, concat_batches, take};
+use arrow::datatypes::SchemaRef;
+use datafusion_common::utils::evaluate_partition_ranges;
+use datafusion_common::{
+    AfterMatchSkip, EmptyMatchesMode, MatchFunction, Result, RowPattern, RowsPerMatch,
+    ScalarValue, exec_err, internal_err,
+};
+use datafusion_execution::TaskContext;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_physical_expr::{
+    EquivalenceProperties, OrderingRequirements, PhysicalExprRef, PhysicalSortExpr,
+};
+use datafusion_physical_expr_common::physical_expr::fmt_sql;
+
+use futures::{Stream, StreamExt, ready};
+
+/// A value computed for every match of a [`MatchRecognizeExec`]
+#[derive(Debug, Clone)]
+pub struct MatchMeasureExpr {
+    /// How the value is computed from the rows of the match
+    pub function: MatchFunction,
+    /// If set, only the rows mapped to this pattern variable are considered
+    pub symbol: Option<String>,
+    /// The argument of [`MatchFunction::First`] and [`MatchFunction::Last`]
+    pub arg: Option<PhysicalExprRef>,
+    /// The name of the output column
+    pub name: String,
+}
+
+impl Display for MatchMeasureExpr {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let prefix = match &self.symbol {
+            Some(symbol) => format!("{symbol}."),
+            None => String::new(),
+        };
+        match (&self.arg, self.function) {
+            (Some(arg), _) => write!(
+                f,
+                "{}({prefix}{}) AS {}",
+                self.function,
+                fmt_sql(arg.as_ref()),
+                self.name
+            ),
+            (None, MatchFunction::Count) => {
+                write!(f, "{}({prefix}*) AS {}", self.function, self.name)
+            }
+            (None, _) => write!(f, "{}() AS {}", self.function, self.name),
+        }
+    }
+}
+
+/// `MatchRecognizeExec` finds the matches of a row pattern in every
+/// partition of its input, as `MATCH_RECOGNIZE` does:
+///
+/// ```text
+/// SELECT * FROM ticker MATCH_RECOGNIZE (
+///     PARTITION BY symbol ORDER BY ts
+///     MEASURES FIRST(down.ts) AS start_ts, LAST(up.ts) AS end_ts
+///     PATTERN (down+ up+)
+///     DEFINE down AS price < PREV(price), up AS price > PREV(price)
+/// )
+/// ```
+///
+/// The input is required to be sorted by the partition keys and then by the
+/// order of the rows in a partition, like the input of a window function,
+/// and is read one partition at a time. Once a partition is complete, the
+/// conditions of the pattern variables are evaluated on all of its rows,
+/// and the pattern is matched from its first row on. After every match,
+/// matching resumes at the row given by [`AfterMatchSkip`].
+///
+/// With [`RowsPerMatch::OneRow`], the output has a row with the partition
+/// keys and the measures of every match. With [`RowsPerMatch::AllRows`], it
+/// has the input columns and the running measures of every row of every
+/// match instead.
+#[derive(Debug)]
+pub struct MatchRecognizeExec {
+    /// The input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// The expressions partitioning the input
+    partition_by: Vec<PhysicalExprRef>,
+    /// The order of the rows in a partition
+    order_by: Vec<PhysicalSortExpr>,
+    /// The pattern variables
+    symbols: Vec<String>,
+    /// The condition of every pattern variable
+    definitions: Vec<PhysicalExprRef>,
+    /// The row pattern to match
+    pattern: RowPattern,
+    /// The values computed for every match
+    measures: Vec<MatchMeasureExpr>,
+    /// Whether to return a row per match or per matched row
+    rows_per_match: RowsPerMatch,
+    /// Where to resume looking for matches after a match
+    after_match_skip: AfterMatchSkip,
+    /// The output schema
+    schema: SchemaRef,
+    /// The compiled row pattern
+    matcher: Arc<PatternMatcher>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl MatchRecognizeExec {
+    /// Create a new MatchRecognizeExec
+    #[expect(clippy::too_many_arguments)]
+    pub fn try_new(
+        input: Arc<dyn ExecutionPlan>,
+        partition_by: Vec<PhysicalExprRef>,
+        order_by: Vec<PhysicalSortExpr>,
+        symbols: Vec<String>,
+        definitions: Vec<PhysicalExprRef>,
+        pattern: RowPattern,
+        measures: Vec<MatchMeasureExpr>,
+        rows_per_match: RowsPerMatch,
+        after_match_skip: AfterMatchSkip,
+        schema: SchemaRef,
+    ) -> Result<Self> {
+        if symbols.len() != definitions.len() {
+            return internal_err!(
+                "MatchRecognizeExec requires a definition for every pattern variable, \
+                got {} pattern variables and {} definitions",
+                symbols.len(),
+                definitions.len()
+            );
+        }
+        let expected_fields = match rows_per_match {
+            RowsPerMatch::OneRow => partition_by.len(),
+            RowsPerMatch::AllRows(_) => input.schema().fields().len(),
+        } + measures.len();
+        if schema.fields().len() != expected_fields {
+            return internal_err!(
+                "MatchRecognizeExec expects {expected_fields} output fields, got {}",
+                schema.fields().len()
+            );
+        }
+        let matcher = Arc::new(PatternMatcher::try_new(&pattern, &symbols)?);
+        let cache = Self::compute_properties(
+            &input,
+            Arc::clone(&schema),
+            &partition_by,
+            rows_per_match,
+        );
+
+        Ok(Self {
+            input,
+            partition_by,
+            order_by,
+            symbols,
+            definitions,
+            pattern,
+            measures,
+            rows_per_match,
+            after_match_skip,
+            schema,
+            matcher,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// The expressions partitioning the input
+    pub fn partition_by(&self) -> &[PhysicalExprRef] {
+        &self.partition_by
+    }
+
+    /// The order of the rows in a partition
+    pub fn order_by(&self) -> &[PhysicalSortExpr] {
+        &self.order_by
+    }
+
+    /// The pattern variables
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// The condition of every pattern variable
+    pub fn definitions(&self) -> &[PhysicalExprRef] {
+        &self.definitions
+    }
+
+    /// The row pattern to match
+    pub fn pattern(&self) -> &RowPattern {
+        &self.pattern
+    }
+
+    /// The values computed for every match
+    pub fn measures(&self) -> &[MatchMeasureExpr] {
+        &self.measures
+    }
+
+    /// Whether to return a row per match or per matched row
+    pub fn rows_per_match(&self) -> RowsPerMatch {
+        self.rows_per_match
+    }
+
+    /// Where to resume looking for matches after a match
+    pub fn after_match_skip(&self) -> &AfterMatchSkip {
+        &self.after_match_skip
+    }
+
+    fn compute_properties(
+        input: &Arc<dyn ExecutionPlan>,
+        schema: SchemaRef,
+        partition_by: &[PhysicalExprRef],
+        rows_per_match: RowsPerMatch,
+    ) -> PlanProperties {
+        // Overlapping matches repeat rows, so no ordering is kept
+        let eq_properties = EquivalenceProperties::new(schema);
+
+        // The input columns keep their position with `ALL ROWS PER MATCH`
+        let output_partitioning = match rows_per_match {
+            RowsPerMatch::OneRow => Partitioning::UnknownPartitioning(
+                input.output_partitioning().partition_count(),
+            ),
+            RowsPerMatch::AllRows(_) => input.output_partitioning().clone(),
+        };
+
+        // A partition is only matched once it is complete
+        let emission_type = if partition_by.is_empty() {
+            EmissionType::Final
+        } else {
+            EmissionType::Incremental
+        };
+
+        PlanProperties::new(
+            eq_properties,
+            output_partitioning,
+            emission_type,
+            boundedness_from_children([input]),
+        )
+    }
+}
+
+impl DisplayAs for MatchRecognizeExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
+        let define = self
+            .symbols
+            .iter()
+            .zip(&self.definitions)
+            .map(|(symbol, expr)| format!("{symbol} AS {}", fmt_sql(expr.as_ref())))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let measures = self
+            .measures
+            .iter()
+            .map(|measure| measure.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let partition_by = self
+                    .partition_by
+                    .iter()
+                    .map(|expr| expr.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let order_by = self
+                    .order_by
+                    .iter()
+                    .map(|expr| expr.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(
+                    f,
+                    "MatchRecognizeExec: partition_by=[{partition_by}], \
+                    order_by=[{order_by}], pattern=[{}], define=[{define}], \
+                    measures=[{measures}], {}, {}",
+                    self.pattern, self.rows_per_match, self.after_match_skip
+                )
+            }
+            DisplayFormatType::TreeRender => {
+                writeln!(f, "pattern={}", self.pattern)?;
+                writeln!(f, "define={define}")?;
+                if !measures.is_empty() {
+                    writeln!(f, "measures={measures}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for MatchRecognizeExec {
+    fn name(&self) -> &'static str {
+        "MatchRecognizeExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.input]
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        if self.partition_by.is_empty() {
+            vec![Distribution::SinglePartition]
+        } else {
+            vec![Distribution::HashPartitioned(self.partition_by.clone())]
+        }
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<OrderingRequirements>> {
+        // The rows of a partition must be adjacent, so unlike for window
+        // functions, the alternative without the partition keys does not do
+        let requirements = calc_requirements(&self.partition_by, &self.order_by);
+        vec![requirements.map(|r| OrderingRequirements::new(r.into_single()))]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![false]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(MatchRecognizeExec::try_new(
+            Arc::clone(&children[0]),
+            self.partition_by.clone(),
+            self.order_by.clone(),
+            self.symbols.clone(),
+            self.definitions.clone(),
+            self.pattern.clone(),
+            self.measures.clone(),
+            self.rows_per_match,
+            self.after_match_skip.clone(),
+            Arc::clone(&self.schema),
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let input = self.input.execute(partition, Arc::clone(&context))?;
+        let reservation = MemoryConsumer::new(format!("MatchRecognizeExec[{partition}]"))
+            .register(context.memory_pool());
+
+        // Measures refer to pattern variables by their index
+        let measure_symbols = self
+            .measures
+            .iter()
+            .map(|measure| measure.symbol.as_deref().map(|s| self.symbol_index(s)))
+            .map(Option::transpose)
+            .collect::<Result<Vec<_>>>()?;
+        let skip_to = match &self.after_match_skip {
+            AfterMatchSkip::PastLastRow => SkipTo::PastLastRow,
+            AfterMatchSkip::ToNextRow => SkipTo::NextRow,
+            AfterMatchSkip::ToFirst(symbol) => SkipTo::First(self.symbol_index(symbol)?),
+            AfterMatchSkip::ToLast(symbol) => SkipTo::Last(self.symbol_index(symbol)?),
+        };
+
+        Ok(Box::pin(MatchRecognizeStream {
+            input,
+            schema: Arc::clone(&self.schema),
+            partition_by: self.partition_by.clone(),
+            symbols: self.symbols.clone(),
+            definitions: self.definitions.clone(),
+            matcher: Arc::clone(&self.matcher),
+            measures: self.measures.clone(),
+            measure_symbols,
+            rows_per_match: self.rows_per_match,
+            skip_to,
+            partition_key: None,
+            buffer: vec![],
+            output: VecDeque::new(),
+            finished: false,
+            reservation,
+            baseline_metrics: BaselineMetrics::new(&self.metrics, partition),
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+impl MatchRecognizeExec {
+    fn symbol_index(&self, symbol: &str) -> Result<usize> {
+        match self.symbols.iter().position(|s| s == symbol) {
+            Some(index) => Ok(index),
+            None => internal_err!("Undefined pattern variable {symbol}"),
+        }
+    }
+}
+
+/// Where matching resumes after a non-empty match, see [`AfterMatchSkip`]
+#[derive(Debug, Clone, Copy)]
+enum SkipTo {
+    PastLastRow,
+    NextRow,
+    First(usize),
+    Last(usize),
+}
+
+/// The state of a measure over the rows of a match seen so far
+#[derive(Debug, Clone, Copy, Default)]
+struct MeasureState {
+    /// The offset in the match of the first row considered by the measure
+    first: Option<usize>,
+    /// The offset in the match of the last row considered by the measure
+    last: Option<usize>,
+    /// The number of rows considered by the measure
+    count: i64,
+}
+
+/// The output rows of a partition: the index of the row the output row is
+/// made of, and the values of the measures, which are null for unmatched
+/// rows
+#[derive(Debug, Default)]
+struct PartitionOutput {
+    rows: Vec<u64>,
+    measures: Vec<MeasureValues>,
+}
+
+#[derive(Debug)]
+enum MeasureValues {
+    /// The row at which the argument is taken
+    Row(Vec<Option<u64>>),
+    Count(Vec<Option<i64>>),
+    MatchNumber(Vec<Option<u64>>),
+    /// The index of the pattern variable
+    Classifier(Vec<Option<usize>>),
+}
+
+impl PartitionOutput {
+    fn new(measures: &[MatchMeasureExpr]) -> Self {
+        let measures = measures
+            .iter()
+            .map(|measure| match measure.function {
+                MatchFunction::First | MatchFunction::Last => MeasureValues::Row(vec![]),
+                MatchFunction::Count => MeasureValues::Count(vec![]),
+                MatchFunction::MatchNumber => MeasureValues::MatchNumber(vec![]),
+                MatchFunction::Classifier => MeasureValues::Classifier(vec![]),
+            })
+            .collect();
+        Self {
+            rows: vec![],
+            measures,
+        }
+    }
+
+    /// Adds an output row made of row `row` of the partition, with the
+    /// measures of the given match, which starts at row `start` and whose
+    /// rows are mapped to `classifiers`
+    fn push_matched(
+        &mut self,
+        row: usize,
+        measures: &[MatchMeasureExpr],
+        match_number: u64,
+        start: usize,
+        classifiers: &[usize],
+        states: &[MeasureState],
+    ) {
+        self.rows.push(row as u64);
+        for ((values, measure), state) in
+            self.measures.iter_mut().zip(measures).zip(states)
+        {
+            match values {
+                MeasureValues::Row(rows) => {
+                    let offset = match measure.function {
+                        MatchFunction::First => state.first,
+                        _ => state.last,
+                    };
+                    rows.push(offset.map(|offset| (start + offset) as u64))
+                }
+                MeasureValues::Count(counts) => counts.push(Some(state.count)),
+                MeasureValues::MatchNumber(numbers) => numbers.push(Some(match_number)),
+                MeasureValues::Classifier(symbols) => {
+                    symbols.push(state.last.map(|offset| classifiers[offset]))
+                }
+            }
+        }
+    }
+
+    /// Adds an output row made of row `row` of the partition, which is not
+    /// part of any match
+    fn push_unmatched(&mut self, row: usize) {
+        self.rows.push(row as u64);
+        for values in &mut self.measures {
+            match values {
+                MeasureValues::Row(rows) => rows.push(None),
+                MeasureValues::Count(counts) => counts.push(None),
+                MeasureValues::MatchNumber(numbers) => numbers.push(None),
+                MeasureValues::Classifier(symbols) => symbols.push(None),
+            }
+        }
+    }
+}
+
+/// A stream that finds the matches of a row pattern in every partition of
+/// its sorted input
+struct MatchRecognizeStream {
+    /// Input stream, sorted by the partition keys
+    input: SendableRecordBatchStream,
+    /// Output schema
+    schema: SchemaRef,
+    partition_by: Vec<PhysicalExprRef>,
+    symbols: Vec<String>,
+    definitions: Vec<PhysicalExprRef>,
+    matcher: Arc<PatternMatcher>,
+    measures: Vec<MatchMeasureExpr>,
+    /// The index of the pattern variable of every measure
+    measure_symbols: Vec<Option<usize>>,
+    rows_per_match: RowsPerMatch,
+    skip_to: SkipTo,
+    /// The partition keys of the buffered rows
+    partition_key: Option<Vec<ScalarValue>>,
+    /// The rows of the current partition
+    buffer: Vec<RecordBatch>,
+    /// Output batches not yet returned
+    output: VecDeque<RecordBatch>,
+    /// Whether the input is exhausted
+    finished: bool,
+    /// Tracks the memory of the buffered rows
+    reservation: MemoryReservation,
+    /// Execution metrics
+    baseline_metrics: BaselineMetrics,
+}
+
+impl MatchRecognizeStream {
+    fn poll_next_inner(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        loop {
+            if let Some(batch) = self.output.pop_front() {
+                return Poll::Ready(Some(Ok(batch)));
+            }
+            if self.finished {
+                return Poll::Ready(None);
+            }
+
+            match ready!(self.input.poll_next_unpin(cx)) {
+                Some(Ok(batch)) => {
+                    let elapsed_compute = self.baseline_metrics.elapsed_compute().clone();
+                    let _timer = elapsed_compute.timer();
+                    self.push_batch(&batch)?;
+                }
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => {
+                    let elapsed_compute = self.baseline_metrics.elapsed_compute().clone();
+                    let _timer = elapsed_compute.timer();
+                    self.finished = true;
+                    self.finish_partition()?;
+                }
+            }
+        }
+    }
+
+    /// Splits a batch into its partitions, finishing the buffered partition
+    /// once a row of another partition is seen
+    fn push_batch(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+        let keys = self
+            .partition_by
+            .iter()
+            .map(|expr| expr.evaluate(batch)?.into_array(batch.num_rows()))
+            .collect::<Result<Vec<_>>>()?;
+        let sort_columns = keys
+            .iter()
+            .map(|values| SortColumn {
+                values: Arc::clone(values),
+                options: None,
+            })
+            .collect::<Vec<_>>();
+
+        for range in evaluate_partition_ranges(batch.num_rows(), &sort_columns)? {
+            let key = keys
+                .iter()
+                .map(|values| ScalarValue::try_from_array(values, range.start))
+                .collect::<Result<Vec<_>>>()?;
+            if self.partition_key.as_ref().is_some_and(|k| *k != key) {
+                self.finish_partition()?;
+            }
+            let slice = batch.slice(range.start, range.len());
+            self.reservation
+                .try_grow(get_record_batch_memory_size(&slice))?;
+            self.buffer.push(slice);
+            self.partition_key = Some(key);
+        }
+        Ok(())
+    }
+
+    /// Matches the pattern in the buffered partition
+    fn finish_partition(&mut self) -> Result<()> {
+        let batches = std::mem::take(&mut self.buffer);
+        self.partition_key = None;
+        if batches.is_empty() {
+            return Ok(());
+        }
+        let batch = concat_batches(&self.input.schema(), &batches)?;
+        drop(batches);
+        let output = self.match_partition(&batch)?;
+        self.reservation.free();
+        if output.num_rows() > 0 {
+            self.output.push_back(output);
+        }
+        Ok(())
+    }
+
+    fn match_partition(&self, batch: &RecordBatch) -> Result<RecordBatch> {
+        let num_rows = batch.num_rows();
+        let definitions = self
+            .definitions
+            .iter()
+            .map(|expr| expr.evaluate(batch)?.into_array(num_rows))
+            .collect::<Result<Vec<_>>>()?;
+        let definitions = definitions
+            .iter()
+            .map(|array| array.as_boolean_opt())
+            .collect::<Option<Vec<_>>>();
+        let Some(definitions) = definitions else {
+            return internal_err!("Pattern variable definitions must be boolean");
+        };
+        // A row is not mapped to a pattern variable whose condition is null
+        let is_mapped = |row: usize, symbol: usize| {
+            let values = definitions[symbol];
+            values.is_valid(row) && values.value(row)
+        };
+
+        let (all_rows, empty_matches) = match self.rows_per_match {
+            RowsPerMatch::OneRow => (false, EmptyMatchesMode::Show),
+            RowsPerMatch::AllRows(mode) => (true, mode),
+        };
+        let mut output = PartitionOutput::new(&self.measures);
+        let mut match_number = 0;
+        // The rows before this one are part of a match
+        let mut matched_until = 0;
+        let mut row = 0;
+        while row < num_rows {
+            let Some(classifiers) = self.matcher.match_at(row, num_rows, is_mapped)
+            else {
+                if empty_matches == EmptyMatchesMode::WithUnmatched
+                    && row >= matched_until
+                {
+                    output.push_unmatched(row);
+                }
+                row += 1;
+                continue;
+            };
+
+            match_number += 1;
+            let mut states = vec![MeasureState::default(); self.measures.len()];
+            for (offset, &symbol) in classifiers.iter().enumerate() {
+                for (state, measure_symbol) in
+                    states.iter_mut().zip(&self.measure_symbols)
+                {
+                    if measure_symbol.is_none_or(|s| s == symbol) {
+                        state.first.get_or_insert(offset);
+                        state.last = Some(offset);
+                        state.count += 1;
+                    }
+                }
+                if all_rows {
+                    output.push_matched(
+                        row + offset,
+                        &self.measures,
+                        match_number,
+                        row,
+                        &classifiers,
+                        &states,
+                    );
+                }
+            }
+            // An empty match has a row unless empty matches are omitted
+            if !all_rows
+                || (classifiers.is_empty() && empty_matches != EmptyMatchesMode::Omit)
+            {
+                output.push_matched(
+                    row,
+                    &self.measures,
+                    match_number,
+                    row,
+                    &classifiers,
+                    &states,
+                );
+            }
+
+            if classifiers.is_empty() {
+                row += 1;
+                continue;
+            }
+            matched_until = matched_until.max(row + classifiers.len());
+            let skip_to = match self.skip_to {
+                SkipTo::PastLastRow => row + classifiers.len(),
+                SkipTo::NextRow => row + 1,
+                SkipTo::First(symbol) => self.skip_to_symbol(
+                    row,
+                    classifiers.iter().position(|&s| s == symbol),
+                    symbol,
+                )?,
+                SkipTo::Last(symbol) => self.skip_to_symbol(
+                    row,
+                    classifiers.iter().rposition(|&s| s == symbol),
+                    symbol,
+                )?,
+            };
+            row = skip_to;
+        }
+
+        self.build_output(batch, output)
+    }
+
+    /// Returns the row at which matching resumes after skipping to the row
+    /// at `offset` in the match starting at `row`
+    fn skip_to_symbol(
+        &self,
+        row: usize,
+        offset: Option<usize>,
+        symbol: usize,
+    ) -> Result<usize> {
+        match offset {
+            // Resuming at the first row of the match would find it again
+            Some(offset) if offset > 0 => Ok(row + offset),
+            _ => exec_err!(
+                "AFTER MATCH SKIP cannot resume at pattern variable {}, which must be \
+                mapped to a row of the match other than its first row",
+                self.symbols[symbol]
+            ),
+        }
+    }
+
+    fn build_output(
+        &self,
+        batch: &RecordBatch,
+        output: PartitionOutput,
+    ) -> Result<RecordBatch> {
+        let indices = UInt64Array::from(output.rows);
+        let columns = match self.rows_per_match {
+            RowsPerMatch::OneRow => self
+                .partition_by
+                .iter()
+                .map(|expr| expr.evaluate(batch)?.into_array(batch.num_rows()))
+                .collect::<Result<Vec<_>>>()?,
+            RowsPerMatch::AllRows(_) => batch.columns().to_vec(),
+        };
+        let mut columns = columns
+            .iter()
+            .map(|column| Ok(take(column.as_ref(), &indices, None)?))
+            .collect::<Result<Vec<ArrayRef>>>()?;
+
+        for (values, measure) in output.measures.into_iter().zip(&self.measures) {
+            let column: ArrayRef = match values {
+                MeasureValues::Row(rows) => {
+                    let Some(arg) = &measure.arg else {
+                        return internal_err!(
+                            "Measure {} requires an argument",
+                            measure.function
+                        );
+                    };
+                    let values = arg.evaluate(batch)?.into_array(batch.num_rows())?;
+                    take(values.as_ref(), &UInt64Array::from(rows), None)?
+                }
+                MeasureValues::Count(counts) => Arc::new(Int64Array::from(counts)),
+                MeasureValues::MatchNumber(numbers) => {
+                    Arc::new(UInt64Array::from(numbers))
+                }
+                MeasureValues::Classifier(symbols) => Arc::new(
+                    symbols
+                        .into_iter()
+                        .map(|symbol| symbol.map(|s| self.symbols[s].as_str()))
+                        .collect::<StringArray>(),
+                ),
+            };
+            columns.push(column);
+        }
+
+        let options = RecordBatchOptions::new().with_row_count(Some(indices.len()));
+        Ok(RecordBatch::try_new_with_options(
+            Arc::clone(&self.schema),
+            columns,
+            &options,
+        )?)
+    }
+}
+
+impl Stream for MatchRecognizeStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.poll_next_inner(cx);
+        self.baseline_metrics.record_poll(poll)
+    }
+}
+
+impl RecordBatchStream for MatchRecognizeStream {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
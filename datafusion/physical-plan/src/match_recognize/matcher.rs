@@ -0,0 +1,378 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Matches a [`RowPattern`] against the rows of a partition
+
+use std::rc::Rc;
+
+use datafusion_common::{Result, RowPattern, internal_err, plan_err};
+
+/// The maximum number of instructions of a compiled pattern, which bounds
+/// the expansion of quantifiers such as `A{1000}`
+const MAX_PROGRAM_SIZE: usize = 10_000;
+
+/// An instruction of a compiled [`RowPattern`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    /// Consumes a row mapped to the pattern variable with the given index
+    Symbol(usize),
+    /// Continues at both targets, preferring the first one
+    Split(usize, usize),
+    /// Continues at the target
+    Jump(usize),
+    /// Continues only at the start of the partition
+    Start,
+    /// Continues only at the end of the partition
+    End,
+    /// The pattern is matched
+    Match,
+}
+
+/// The pattern variables of the rows consumed by a thread, from the last
+/// row back to the first one
+#[derive(Debug)]
+struct PathNode {
+    symbol: usize,
+    prev: Path,
+}
+
+type Path = Option<Rc<PathNode>>;
+
+#[derive(Debug)]
+struct Thread {
+    pc: usize,
+    path: Path,
+}
+
+/// Finds the preferred match of a [`RowPattern`] at a row, as a Pike VM:
+/// all the ways of matching the pattern are explored in lockstep, one row at
+/// a time, so a match takes time linear in the number of rows it covers.
+///
+/// The threads are kept in order of preference, which is the order a
+/// backtracking matcher would try them in. Once a thread matches, the less
+/// preferred threads are dropped, while the more preferred ones keep running
+/// and replace the match if they match later on.
+#[derive(Debug)]
+pub(super) struct PatternMatcher {
+    program: Vec<Instruction>,
+}
+
+impl PatternMatcher {
+    /// Compiles a pattern whose variables are resolved to their index in
+    /// `symbols`
+    pub(super) fn try_new(pattern: &RowPattern, symbols: &[String]) -> Result<Self> {
+        let mut program = vec![];
+        compile(pattern, symbols, &mut program)?;
+        program.push(Instruction::Match);
+        Ok(Self { program })
+    }
+
+    /// Returns the pattern variables of the rows of the preferred match
+    /// starting at row `start` of a partition with `num_rows` rows, or `None`
+    /// if there is no match. The match is empty if the pattern matches no
+    /// rows.
+    ///
+    /// `is_mapped(row, symbol)` returns whether a row satisfies the condition
+    /// of the pattern variable with the given index.
+    pub(super) fn match_at(
+        &self,
+        start: usize,
+        num_rows: usize,
+        is_mapped: impl Fn(usize, usize) -> bool,
+    ) -> Option<Vec<usize>> {
+        let mut visited = vec![usize::MAX; self.program.len()];
+        let mut threads = vec![];
+        self.add_thread(&mut threads, &mut visited, 0, &None, start, num_rows);
+
+        let mut matched = None;
+        let mut row = start;
+        while !threads.is_empty() {
+            let mut next = vec![];
+            for thread in threads {
+                match self.program[thread.pc] {
+                    Instruction::Match => {
+                        matched = Some(thread.path);
+                        break;
+                    }
+                    Instruction::Symbol(symbol) => {
+                        if row < num_rows && is_mapped(row, symbol) {
+                            let path = Some(Rc::new(PathNode {
+                                symbol,
+                                prev: thread.path,
+                            }));
+                            self.add_thread(
+                                &mut next,
+                                &mut visited,
+                                thread.pc + 1,
+                                &path,
+                                row + 1,
+                                num_rows,
+                            );
+                        }
+                    }
+                    _ => unreachable!("only leaf instructions are added as threads"),
+                }
+            }
+            threads = next;
+            row += 1;
+        }
+
+        matched.map(|path| {
+            let mut symbols = vec![];
+            let mut node = path;
+            while let Some(n) = node {
+                symbols.push(n.symbol);
+                node = n.prev.clone();
+            }
+            symbols.reverse();
+            symbols
+        })
+    }
+
+    /// Adds the threads reachable from `pc` without consuming a row, in order
+    /// of preference. Every instruction is visited at most once per row, so
+    /// patterns that can match empty inside a loop do not loop forever.
+    fn add_thread(
+        &self,
+        threads: &mut Vec<Thread>,
+        visited: &mut [usize],
+        pc: usize,
+        path: &Path,
+        row: usize,
+        num_rows: usize,
+    ) {
+        let mut stack = vec![pc];
+        while let Some(pc) = stack.pop() {
+            if visited[pc] == row {
+                continue;
+            }
+            visited[pc] = row;
+            match self.program[pc] {
+                Instruction::Symbol(_) | Instruction::Match => threads.push(Thread {
+                    pc,
+                    path: path.clone(),
+                }),
+                Instruction::Split(preferred, other) => {
+                    stack.push(other);
+                    stack.push(preferred);
+                }
+                Instruction::Jump(target) => stack.push(target),
+                Instruction::Start => {
+                    if row == 0 {
+                        stack.push(pc + 1);
+                    }
+                }
+                Instruction::End => {
+                    if row == num_rows {
+                        stack.push(pc + 1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn compile(
+    pattern: &RowPattern,
+    symbols: &[String],
+    program: &mut Vec<Instruction>,
+) -> Result<()> {
+    if program.len() > MAX_PROGRAM_SIZE {
+        return plan_err!(
+            "Row pattern is too large, it must compile to at most {MAX_PROGRAM_SIZE} instructions"
+        );
+    }
+    match pattern {
+        RowPattern::Symbol(name) => {
+            let Some(index) = symbols.iter().position(|s| s == name) else {
+                return internal_err!("Undefined pattern variable {name}");
+            };
+            program.push(Instruction::Symbol(index));
+        }
+        RowPattern::Start => program.push(Instruction::Start),
+        RowPattern::End => program.push(Instruction::End),
+        RowPattern::Concat(patterns) => {
+            for pattern in patterns {
+                compile(pattern, symbols, program)?;
+            }
+        }
+        RowPattern::Alternation(patterns) => {
+            // Every alternative but the last one is preceded by a split to
+            // the next alternative and followed by a jump past the last one
+            let mut jumps = vec![];
+            for (i, pattern) in patterns.iter().enumerate() {
+                if i + 1 == patterns.len() {
+                    compile(pattern, symbols, program)?;
+                } else {
+                    let split = program.len();
+                    program.push(Instruction::Split(split + 1, 0));
+                    compile(pattern, symbols, program)?;
+                    jumps.push(program.len());
+                    program.push(Instruction::Jump(0));
+                    program[split] = Instruction::Split(split + 1, program.len());
+                }
+            }
+            let end = program.len();
+            for jump in jumps {
+                program[jump] = Instruction::Jump(end);
+            }
+        }
+        RowPattern::Repetition { pattern, min, max } => {
+            for _ in 0..*min {
+                compile(pattern, symbols, program)?;
+            }
+            match max {
+                None => {
+                    let split = program.len();
+                    program.push(Instruction::Split(split + 1, 0));
+                    compile(pattern, symbols, program)?;
+                    program.push(Instruction::Jump(split));
+                    program[split] = Instruction::Split(split + 1, program.len());
+                }
+                Some(max) => {
+                    // `p{0,2}` is compiled as `(p (p)?)?`
+                    let mut splits = vec![];
+                    for _ in *min..*max {
+                        splits.push(program.len());
+                        program.push(Instruction::Split(0, 0));
+                        compile(pattern, symbols, program)?;
+                    }
+                    let end = program.len();
+                    for split in splits {
+                        program[split] = Instruction::Split(split + 1, end);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(name: &str) -> RowPattern {
+        RowPattern::Symbol(name.to_string())
+    }
+
+    fn repeat(pattern: RowPattern, min: u32, max: Option<u32>) -> RowPattern {
+        RowPattern::Repetition {
+            pattern: Box::new(pattern),
+            min,
+            max,
+        }
+    }
+
+    /// Matches `pattern` at every row of `rows`, where every row is the set
+    /// of pattern variables it is mapped to, and returns the matches as the
+    /// concatenated pattern variables
+    fn matches(pattern: &RowPattern, rows: &[&str]) -> Vec<Option<String>> {
+        let symbols = pattern
+            .symbols()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let matcher = PatternMatcher::try_new(pattern, &symbols).unwrap();
+        (0..rows.len())
+            .map(|start| {
+                matcher
+                    .match_at(start, rows.len(), |row, s| rows[row].contains(&symbols[s]))
+                    .map(|m| m.into_iter().map(|s| symbols[s].as_str()).collect())
+            })
+            .collect()
+    }
+
+    fn expected(matches: &[Option<&str>]) -> Vec<Option<String>> {
+        matches.iter().map(|m| m.map(String::from)).collect()
+    }
+
+    #[test]
+    fn greedy_quantifiers() {
+        // A B+ C? over rows that can all be mapped to B
+        let pattern = RowPattern::Concat(vec![
+            symbol("A"),
+            repeat(symbol("B"), 1, None),
+            repeat(symbol("C"), 0, Some(1)),
+        ]);
+        let rows = ["A", "AB", "BC", "BC", "C"];
+        assert_eq!(
+            matches(&pattern, &rows),
+            expected(&[Some("ABBBC"), Some("ABBC"), None, None, None])
+        );
+    }
+
+    #[test]
+    fn alternation_prefers_the_first_alternative() {
+        // (A | A B) only ever takes the first alternative
+        let pattern = RowPattern::Concat(vec![RowPattern::Alternation(vec![
+            symbol("A"),
+            RowPattern::Concat(vec![symbol("A"), symbol("B")]),
+        ])]);
+        assert_eq!(matches(&pattern, &["A", "B"]), expected(&[Some("A"), None]));
+
+        // (A B | A) takes the longer alternative when it matches
+        let pattern = RowPattern::Alternation(vec![
+            RowPattern::Concat(vec![symbol("A"), symbol("B")]),
+            symbol("A"),
+        ]);
+        assert_eq!(
+            matches(&pattern, &["A", "AB", "C"]),
+            expected(&[Some("AB"), Some("A"), None])
+        );
+    }
+
+    #[test]
+    fn bounded_quantifiers() {
+        let pattern = repeat(symbol("A"), 2, Some(3));
+        assert_eq!(
+            matches(&pattern, &["A", "A", "A", "A", "B"]),
+            expected(&[Some("AAA"), Some("AAA"), Some("AA"), None, None])
+        );
+    }
+
+    #[test]
+    fn empty_matches() {
+        // A* matches empty where no row is mapped to A, and the empty
+        // alternative of a loop body does not loop forever
+        let pattern = repeat(repeat(symbol("A"), 0, Some(1)), 0, None);
+        assert_eq!(
+            matches(&pattern, &["A", "B", "A"]),
+            expected(&[Some("A"), Some(""), Some("A")])
+        );
+    }
+
+    #[test]
+    fn anchors() {
+        let pattern = RowPattern::Concat(vec![RowPattern::Start, symbol("A")]);
+        assert_eq!(matches(&pattern, &["A", "A"]), expected(&[Some("A"), None]));
+
+        let pattern =
+            RowPattern::Concat(vec![repeat(symbol("A"), 1, None), RowPattern::End]);
+        assert_eq!(
+            matches(&pattern, &["A", "B", "A", "A"]),
+            expected(&[None, None, Some("AA"), Some("A")])
+        );
+    }
+
+    #[test]
+    fn pattern_too_large() {
+        let pattern = repeat(repeat(symbol("A"), 100, None), 200, None);
+        let err = PatternMatcher::try_new(&pattern, &["A".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("Row pattern is too large"));
+    }
+}
@@ -26,8 +26,10 @@ use crate::aggregates::group_values::{GroupValues, new_group_values};
 use crate::aggregates::order::GroupOrdering;
 use crate::execution_plan::{Boundedness, EmissionType};
 use crate::metrics::{
-    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet, RecordOutput,
+    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet, RecordOutput, SpillMetrics,
 };
+use crate::spill::in_progress_spill_file::InProgressSpillFile;
+use crate::spill::spill_manager::SpillManager;
 use crate::{
     DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties, RecordBatchStream,
     SendableRecordBatchStream, Statistics,
@@ -37,7 +39,9 @@ use arrow::compute::filter_record_batch;
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
-use datafusion_common::{Result, internal_datafusion_err, not_impl_err};
+use datafusion_common::{
+    Result, exec_err, internal_datafusion_err, internal_err, not_impl_err,
+};
 use datafusion_execution::TaskContext;
 use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning};
@@ -56,9 +60,14 @@ use futures::{Stream, StreamExt, ready};
 /// iteration will be available in a "working table" (not a real table,
 /// can be only accessed using a continuance operation).
 ///
-/// Note that there won't be any limit or checks applied to detect
-/// an infinite recursion, so it is up to the planner to ensure that
-/// it won't happen.
+/// The number of iterations can be limited with the
+/// `datafusion.execution.recursive_cte_max_iterations` setting, after which
+/// the execution fails. Otherwise, there are no checks applied to detect an
+/// infinite recursion, so it is up to the planner to ensure that it won't
+/// happen (e.g. with the `CYCLE` clause of the CTE).
+///
+/// If the rows of an iteration do not fit in memory, they are spilled to
+/// disk, and read back by the next iteration.
 #[derive(Debug, Clone)]
 pub struct RecursiveQueryExec {
     /// Name of the query handler
@@ -195,12 +204,31 @@ impl ExecutionPlan for RecursiveQueryExec {
 
         let static_stream = self.static_term.execute(partition, Arc::clone(&context))?;
         let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let spill_manager =
+            context
+                .runtime_env()
+                .disk_manager
+                .tmp_files_enabled()
+                .then(|| {
+                    Arc::new(
+                        SpillManager::new(
+                            context.runtime_env(),
+                            SpillMetrics::new(&self.metrics, partition),
+                            static_stream.schema(),
+                        )
+                        .with_compression_type(
+                            context.session_config().spill_compression(),
+                        ),
+                    )
+                });
         Ok(Box::pin(RecursiveQueryStream::new(
             context,
+            self.name.clone(),
             Arc::clone(&self.work_table),
             Arc::clone(&self.recursive_term),
             static_stream,
             self.is_distinct,
+            spill_manager,
             baseline_metrics,
         )?))
     }
@@ -256,6 +284,8 @@ impl DisplayAs for RecursiveQueryExec {
 struct RecursiveQueryStream {
     /// The context to be used for managing handlers & executing new tasks
     task_context: Arc<TaskContext>,
+    /// Name of the query, used in error messages
+    name: String,
     /// The working table state, representing the self referencing cte table
     work_table: Arc<WorkTable>,
     /// The dynamic part (recursive term) as is (without being executed)
@@ -273,6 +303,17 @@ struct RecursiveQueryStream {
     buffer: Vec<RecordBatch>,
     /// Tracks the memory used by the buffer
     reservation: MemoryReservation,
+    /// Spill file holding the current results, once they did not fit in
+    /// memory. All following results of the iteration are appended to it.
+    spill_file: Option<InProgressSpillFile>,
+    /// Spill manager for the current results, if spilling is enabled
+    spill_manager: Option<Arc<SpillManager>>,
+    /// Number of rows of the current results, in memory or spilled
+    num_buffered_rows: usize,
+    /// Number of iterations of the recursive term started so far
+    iteration: usize,
+    /// Maximum number of iterations, if limited
+    max_iterations: Option<usize>,
     /// If the distinct flag is set, then we use this hash table to remove duplicates from result and work tables
     distinct_deduplicator: Option<DistinctDeduplicator>,
     /// Metrics.
@@ -281,15 +322,23 @@ struct RecursiveQueryStream {
 
 impl RecursiveQueryStream {
     /// Create a new recursive query stream
+    #[expect(clippy::too_many_arguments)]
     fn new(
         task_context: Arc<TaskContext>,
+        name: String,
         work_table: Arc<WorkTable>,
         recursive_term: Arc<dyn ExecutionPlan>,
         static_stream: SendableRecordBatchStream,
         is_distinct: bool,
+        spill_manager: Option<Arc<SpillManager>>,
         baseline_metrics: BaselineMetrics,
     ) -> Result<Self> {
         let schema = static_stream.schema();
+        let max_iterations = task_context
+            .session_config()
+            .options()
+            .execution
+            .recursive_cte_max_iterations;
         let reservation =
             MemoryConsumer::new("RecursiveQuery").register(task_context.memory_pool());
         let distinct_deduplicator = is_distinct
@@ -297,6 +346,7 @@ impl RecursiveQueryStream {
             .transpose()?;
         Ok(Self {
             task_context,
+            name,
             work_table,
             recursive_term,
             static_stream: Some(static_stream),
@@ -304,6 +354,11 @@ impl RecursiveQueryStream {
             schema,
             buffer: vec![],
             reservation,
+            spill_file: None,
+            spill_manager,
+            num_buffered_rows: 0,
+            iteration: 0,
+            max_iterations,
             distinct_deduplicator,
             baseline_metrics,
         })
@@ -321,14 +376,46 @@ impl RecursiveQueryStream {
             batch = deduplicator.deduplicate(&batch)?;
         }
 
-        if let Err(e) = self.reservation.try_grow(batch.get_array_memory_size()) {
+        if let Err(e) = self.buffer_batch(&batch) {
             return Poll::Ready(Some(Err(e)));
         }
-        self.buffer.push(batch.clone());
         (&batch).record_output(&baseline_metrics);
         Poll::Ready(Some(Ok(batch)))
     }
 
+    /// Buffers a copy of `batch` for the next iteration, in memory if it fits,
+    /// otherwise in the spill file (if spilling is enabled)
+    fn buffer_batch(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.num_buffered_rows += batch.num_rows();
+        if self.spill_file.is_none() {
+            match self.reservation.try_grow(batch.get_array_memory_size()) {
+                Ok(()) => {
+                    self.buffer.push(batch.clone());
+                    return Ok(());
+                }
+                Err(e) if self.spill_manager.is_none() => return Err(e),
+                Err(_) => {}
+            }
+        }
+
+        let spill_file = match (&mut self.spill_file, &self.spill_manager) {
+            (Some(spill_file), _) => spill_file,
+            (spill_file, Some(spill_manager)) => spill_file.insert(
+                spill_manager.create_in_progress_file("RecursiveQuery work table")?,
+            ),
+            (None, None) => {
+                return internal_err!(
+                    "RecursiveQuery cannot spill without a disk manager"
+                );
+            }
+        };
+        for buffered in self.buffer.drain(..) {
+            spill_file.append_batch(&buffered)?;
+        }
+        self.reservation.free();
+        spill_file.append_batch(batch)
+    }
+
     /// Start polling for the next iteration, will be called either after the static term
     /// is completed or another term is completed. It will follow the algorithm above on
     /// to check whether the recursion has ended.
@@ -336,19 +423,35 @@ impl RecursiveQueryStream {
         mut self: std::pin::Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Result<RecordBatch>>> {
-        let total_length = self
-            .buffer
-            .iter()
-            .fold(0, |acc, batch| acc + batch.num_rows());
-
-        if total_length == 0 {
+        if self.num_buffered_rows == 0 {
             return Poll::Ready(None);
         }
+        self.num_buffered_rows = 0;
+
+        self.iteration += 1;
+        if let Some(max_iterations) = self.max_iterations
+            && self.iteration > max_iterations
+        {
+            return Poll::Ready(Some(exec_err!(
+                "Recursive query {} exceeded the maximum of {max_iterations} iterations, \
+                see datafusion.execution.recursive_cte_max_iterations",
+                self.name
+            )));
+        }
 
         // Update the work table with the current buffer
+        let spill_file = match self.spill_file.take() {
+            Some(mut spill_file) => spill_file.finish()?,
+            None => None,
+        };
         let reserved_batches = ReservedBatches::new(
             std::mem::take(&mut self.buffer),
             self.reservation.take(),
+        )
+        .with_spill_file(
+            spill_file
+                .zip(self.spill_manager.clone())
+                .map(|(file, spill_manager)| (spill_manager, file)),
         );
         self.work_table.update(reserved_batches);
 
@@ -34,7 +34,9 @@ use crate::coalesce::LimitedBatchCoalescer;
 use crate::execution_plan::{CardinalityEffect, EvaluationType, SchedulingType};
 use crate::hash_utils::create_hashes;
 use crate::metrics::{BaselineMetrics, SpillMetrics};
-use crate::projection::{ProjectionExec, all_columns, make_with_child, update_expr};
+use crate::projection::{
+    ProjectionExec, all_columns, make_with_child, update_expr, update_ordering,
+};
 use crate::sorts::streaming_merge::StreamingMergeBuilder;
 use crate::spill::spill_manager::SpillManager;
 use crate::spill::spill_pool::{self, SpillPoolWriter};
@@ -44,6 +46,7 @@ use crate::{DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties, Stat
 use arrow::array::{PrimitiveArray, RecordBatch, RecordBatchOptions};
 use arrow::compute::take_arrays;
 use arrow::datatypes::{SchemaRef, UInt32Type};
+use arrow::row::OwnedRow;
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::stats::Precision;
 use datafusion_common::utils::transpose;
@@ -71,9 +74,11 @@ use log::trace;
 use parking_lot::Mutex;
 
 mod distributor_channels;
+mod range;
 use distributor_channels::{
     DistributionReceiver, DistributionSender, channels, partition_aware_channels,
 };
+use range::{RangeInputBuffer, RangeSplitPoints, range_partition};
 
 /// A batch in the repartition queue - either in memory or spilled to disk.
 ///
@@ -294,6 +299,23 @@ impl RepartitionExecState {
 
         let spill_manager = Arc::new(spill_manager);
 
+        // The split points of a range repartitioning are sampled from all
+        // input partitions, before any row is sent to the output partitions
+        let range_split_points = match partitioning {
+            Partitioning::Range(ordering, num_partitions) => {
+                Some(Arc::new(RangeSplitPoints::try_new(
+                    ordering.clone(),
+                    &input.schema(),
+                    num_input_partitions,
+                    *num_partitions,
+                    Arc::clone(&spill_manager),
+                    Arc::clone(context.memory_pool()),
+                    name.to_string(),
+                )?))
+            }
+            _ => None,
+        };
+
         let (txs, rxs) = if preserve_order {
             // Create partition-aware channels with one channel per (input, output) pair
             // This provides backpressure while maintaining proper ordering
@@ -391,6 +413,9 @@ impl RepartitionExecState {
                 // preserve_order depends on partition index to start from 0
                 if preserve_order { 0 } else { i },
                 num_input_partitions,
+                range_split_points
+                    .as_ref()
+                    .map(|split_points| split_points.input_buffer(i)),
             ));
 
             // In a separate task, wait for each input to be done
@@ -426,6 +451,11 @@ enum BatchPartitionerState {
         num_partitions: usize,
         next_idx: usize,
     },
+    Range {
+        split_points: Arc<RangeSplitPoints>,
+        bounds: Arc<[OwnedRow]>,
+        num_partitions: usize,
+    },
 }
 
 /// Fixed RandomState used for hash repartitioning to ensure consistent behavior across
@@ -483,6 +513,25 @@ impl BatchPartitioner {
             timer,
         }
     }
+
+    /// Create a new [`BatchPartitioner`] for range repartitioning, with the
+    /// split points `bounds` sampled by `split_points`.
+    fn new_range_partitioner(
+        split_points: Arc<RangeSplitPoints>,
+        bounds: Arc<[OwnedRow]>,
+        num_partitions: usize,
+        timer: metrics::Time,
+    ) -> Self {
+        Self {
+            state: BatchPartitionerState::Range {
+                split_points,
+                bounds,
+                num_partitions,
+            },
+            timer,
+        }
+    }
+
     /// Create a new [`BatchPartitioner`] based on the provided [`Partitioning`] scheme.
     ///
     /// This is a convenience constructor that delegates to the specialized
@@ -496,6 +545,8 @@ impl BatchPartitioner {
     ///
     /// # Errors
     /// Returns an error if the provided partitioning scheme is not supported.
+    /// [`Partitioning::Range`] is not supported, as its split points are
+    /// sampled from all input partitions by [`RepartitionExec`].
     pub fn try_new(
         partitioning: Partitioning,
         timer: metrics::Time,
@@ -589,35 +640,30 @@ impl BatchPartitioner {
                     // Finished building index-arrays for output partitions
                     timer.done();
 
-                    // Borrowing partitioner timer to prevent moving `self` to closure
-                    let partitioner_timer = &self.timer;
-                    let it = indices
-                        .into_iter()
-                        .enumerate()
-                        .filter_map(|(partition, indices)| {
-                            let indices: PrimitiveArray<UInt32Type> = indices.into();
-                            (!indices.is_empty()).then_some((partition, indices))
-                        })
-                        .map(move |(partition, indices)| {
-                            // Tracking time required for repartitioned batches construction
-                            let _timer = partitioner_timer.timer();
-
-                            // Produce batches based on indices
-                            let columns = take_arrays(batch.columns(), &indices, None)?;
-
-                            let mut options = RecordBatchOptions::new();
-                            options = options.with_row_count(Some(indices.len()));
-                            let batch = RecordBatch::try_new_with_options(
-                                batch.schema(),
-                                columns,
-                                &options,
-                            )
-                            .unwrap();
-
-                            Ok((partition, batch))
-                        });
-
-                    Box::new(it)
+                    Box::new(take_partitions(batch, indices, &self.timer))
+                }
+                BatchPartitionerState::Range {
+                    split_points,
+                    bounds,
+                    num_partitions,
+                } => {
+                    // Tracking time required for distributing indexes across output partitions
+                    let timer = self.timer.timer();
+
+                    let rows = split_points.convert_batch(&batch)?;
+
+                    let mut indices: Vec<_> = (0..*num_partitions)
+                        .map(|_| Vec::with_capacity(batch.num_rows()))
+                        .collect();
+
+                    for (index, row) in rows.iter().enumerate() {
+                        indices[range_partition(bounds, row)].push(index as u32);
+                    }
+
+                    // Finished building index-arrays for output partitions
+                    timer.done();
+
+                    Box::new(take_partitions(batch, indices, &self.timer))
                 }
             };
 
@@ -629,10 +675,42 @@ impl BatchPartitioner {
         match self.state {
             BatchPartitionerState::RoundRobin { num_partitions, .. } => num_partitions,
             BatchPartitionerState::Hash { num_partitions, .. } => num_partitions,
+            BatchPartitionerState::Range { num_partitions, .. } => num_partitions,
         }
     }
 }
 
+/// Splits `batch` into one batch per output partition, taking the rows at
+/// `indices[partition]`. Partitions without rows are skipped.
+fn take_partitions(
+    batch: RecordBatch,
+    indices: Vec<Vec<u32>>,
+    timer: &metrics::Time,
+) -> impl Iterator<Item = Result<(usize, RecordBatch)>> + Send + '_ {
+    indices
+        .into_iter()
+        .enumerate()
+        .filter_map(|(partition, indices)| {
+            let indices: PrimitiveArray<UInt32Type> = indices.into();
+            (!indices.is_empty()).then_some((partition, indices))
+        })
+        .map(move |(partition, indices)| {
+            // Tracking time required for repartitioned batches construction
+            let _timer = timer.timer();
+
+            // Produce batches based on indices
+            let columns = take_arrays(batch.columns(), &indices, None)?;
+
+            let mut options = RecordBatchOptions::new();
+            options = options.with_row_count(Some(indices.len()));
+            let batch =
+                RecordBatch::try_new_with_options(batch.schema(), columns, &options)
+                    .unwrap();
+
+            Ok((partition, batch))
+        })
+}
+
 /// Maps `N` input partitions to `M` output partitions based on a
 /// [`Partitioning`] scheme.
 ///
@@ -895,7 +973,10 @@ impl ExecutionPlan for RepartitionExec {
     }
 
     fn benefits_from_input_partitioning(&self) -> Vec<bool> {
-        vec![matches!(self.partitioning(), Partitioning::Hash(_, _))]
+        vec![matches!(
+            self.partitioning(),
+            Partitioning::Hash(_, _) | Partitioning::Range(_, _)
+        )]
     }
 
     fn maintains_input_order(&self) -> Vec<bool> {
@@ -1131,6 +1212,14 @@ impl ExecutionPlan for RepartitionExec {
                 }
                 Partitioning::Hash(new_partitions, *size)
             }
+            Partitioning::Range(ordering, size) => {
+                let Some(new_ordering) =
+                    update_ordering(ordering.clone(), projection.expr())?
+                else {
+                    return Ok(None);
+                };
+                Partitioning::Range(new_ordering, *size)
+            }
             others => others.clone(),
         };
 
@@ -1189,6 +1278,7 @@ impl ExecutionPlan for RepartitionExec {
         new_properties.partitioning = match new_properties.partitioning {
             RoundRobinBatch(_) => RoundRobinBatch(target_partitions),
             Hash(hash, _) => Hash(hash, target_partitions),
+            Range(ordering, _) => Range(ordering, target_partitions),
             UnknownPartitioning(_) => UnknownPartitioning(target_partitions),
         };
         Ok(Some(Arc::new(Self {
@@ -1294,6 +1384,9 @@ impl RepartitionExec {
     /// output partitions based on the desired partitioning
     ///
     /// `output_channels` holds the output sending channels for each output partition
+    ///
+    /// `range_buffer` buffers the input of a [`Partitioning::Range`] until its
+    /// split points are known
     async fn pull_from_input(
         mut stream: SendableRecordBatchStream,
         mut output_channels: HashMap<usize, OutputChannel>,
@@ -1301,6 +1394,7 @@ impl RepartitionExec {
         metrics: RepartitionMetrics,
         input_partition: usize,
         num_input_partitions: usize,
+        range_buffer: Option<RangeInputBuffer>,
     ) -> Result<()> {
         let mut partitioner = match &partitioning {
             Partitioning::Hash(exprs, num_partitions) => {
@@ -1318,6 +1412,29 @@ impl RepartitionExec {
                     num_input_partitions,
                 )
             }
+            Partitioning::Range(_, num_partitions) => {
+                let Some(mut buffer) = range_buffer else {
+                    return internal_err!(
+                        "Range repartitioning requires an input buffer"
+                    );
+                };
+                let buffered =
+                    buffer.buffer_stream(&mut stream, &metrics.fetch_time).await;
+                // The sample is added even if buffering failed, as the other
+                // input partitions wait for it
+                buffer.add_sample();
+                buffered?;
+
+                let split_points = Arc::clone(buffer.split_points());
+                let bounds = split_points.wait().await?;
+                stream = buffer.finish()?;
+                BatchPartitioner::new_range_partitioner(
+                    split_points,
+                    bounds,
+                    *num_partitions,
+                    metrics.repartition_time.clone(),
+                )
+            }
             other => {
                 return not_impl_err!("Unsupported repartitioning scheme {other:?}");
             }
@@ -1820,6 +1937,61 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn many_to_many_range_partition() -> Result<()> {
+        // define input partitions holding a permutation of 0..1200
+        let schema = test_schema();
+        let partitions = create_shuffled_partitions(3, 50);
+        let ordering = range_ordering(&schema)?;
+
+        let output_partitions =
+            repartition(&schema, partitions, Partitioning::Range(ordering, 4)).await?;
+
+        assert_eq!(4, output_partitions.len());
+        let values = output_partitions
+            .iter()
+            .map(|batches| partition_values(batches))
+            .collect::<Vec<_>>();
+        // all input rows are sampled, so the partitions are balanced
+        let partition_sizes = values.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(partition_sizes, vec![299, 300, 300, 301]);
+        assert_non_overlapping(&values);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn range_repartition_with_spilling() -> Result<()> {
+        let schema = test_schema();
+        let partitions = create_shuffled_partitions(2, 50);
+        let partitioning = Partitioning::Range(range_ordering(&schema)?, 3);
+
+        // Set up context with very tight memory limit to force spilling
+        let runtime = RuntimeEnvBuilder::default()
+            .with_memory_limit(1, 1.0)
+            .build_arc()?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+
+        // create physical plan
+        let exec = TestMemoryExec::try_new_exec(&partitions, Arc::clone(&schema), None)?;
+        let exec = RepartitionExec::try_new(exec, partitioning)?;
+
+        let mut values = vec![];
+        for i in 0..exec.partitioning().partition_count() {
+            let stream = exec.execute(i, Arc::clone(&task_ctx))?;
+            let batches = crate::common::collect(stream).await?;
+            values.push(partition_values(&batches));
+        }
+
+        assert_eq!(values.iter().map(Vec::len).sum::<usize>(), 2 * 50 * 8);
+        assert_non_overlapping(&values);
+
+        let metrics = exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_repartition_with_coalescing() -> Result<()> {
         let schema = test_schema();
@@ -2481,6 +2653,70 @@ mod tests {
         .unwrap()
     }
 
+    /// Create `num_partitions` partitions of `num_batches` batches each, which
+    /// together hold a permutation of the values `0..num_partitions * num_batches * 8`
+    fn create_shuffled_partitions(
+        num_partitions: usize,
+        num_batches: usize,
+    ) -> Vec<Vec<RecordBatch>> {
+        let schema = test_schema();
+        let num_rows = (num_partitions * num_batches * 8) as u32;
+        (0..num_partitions * num_batches)
+            .map(|batch| {
+                let start = batch as u32 * 8;
+                // 7 is coprime to `num_rows`, so this yields a permutation
+                let values = (start..start + 8).map(|i| i * 7 % num_rows);
+                RecordBatch::try_new(
+                    Arc::clone(&schema),
+                    vec![Arc::new(UInt32Array::from_iter_values(values))],
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>()
+            .chunks(num_batches)
+            .map(<[RecordBatch]>::to_vec)
+            .collect()
+    }
+
+    fn range_ordering(schema: &SchemaRef) -> Result<LexOrdering> {
+        Ok([PhysicalSortExpr::new_default(col("c0", schema)?)].into())
+    }
+
+    /// Returns the sorted values of the column `c0` of `batches`
+    fn partition_values(batches: &[RecordBatch]) -> Vec<u32> {
+        let mut values = batches
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<UInt32Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        values.sort_unstable();
+        values
+    }
+
+    /// Asserts that every partition only holds values greater than or equal
+    /// to the values of the previous partitions
+    fn assert_non_overlapping(partition_values: &[Vec<u32>]) {
+        let non_empty = partition_values
+            .iter()
+            .filter(|values| !values.is_empty())
+            .collect::<Vec<_>>();
+        for pair in non_empty.windows(2) {
+            assert!(
+                pair[0].last() <= pair[1].first(),
+                "partitions overlap: {:?} > {:?}",
+                pair[0].last(),
+                pair[1].first()
+            );
+        }
+    }
+
     /// Create batches with sequential values for ordering tests
     fn create_ordered_batches(num_batches: usize) -> Vec<RecordBatch> {
         let schema = test_schema();
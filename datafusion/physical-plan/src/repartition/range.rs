@@ -0,0 +1,343 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Split points of [`Partitioning::Range`], sampled from the input of a
+//! [`RepartitionExec`]
+//!
+//! The split points have to be known before the first row can be sent to an
+//! output partition, and they have to be the same for all input partitions.
+//! Therefore, every input partition buffers its whole input (spilling it to
+//! disk under memory pressure) while sampling the sort keys. Once all input
+//! partitions have contributed their sample, the split points are chosen so
+//! that every output partition receives roughly the same number of rows.
+//!
+//! ```text
+//!  Input 0 ──▶ buffer + sample ──┐                  ┌──▶ route buffered rows
+//!                                ├──▶ split points ─┤
+//!  Input 1 ──▶ buffer + sample ──┘                  └──▶ route buffered rows
+//! ```
+//!
+//! [`Partitioning::Range`]: crate::Partitioning::Range
+//! [`RepartitionExec`]: super::RepartitionExec
+
+use std::sync::Arc;
+
+use crate::metrics;
+use crate::spill::in_progress_spill_file::InProgressSpillFile;
+use crate::spill::spill_manager::SpillManager;
+use crate::stream::RecordBatchStreamAdapter;
+use crate::{EmptyRecordBatchStream, SendableRecordBatchStream};
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
+use arrow::row::{OwnedRow, Row, RowConverter, Rows, SortField};
+use datafusion_common::{Result, internal_datafusion_err, internal_err};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
+use datafusion_physical_expr_common::sort_expr::LexOrdering;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+/// Number of rows sampled from every input partition per output partition
+const SAMPLE_ROWS_PER_PARTITION: usize = 64;
+
+/// Split points of a range repartitioning, shared by the tasks pulling from
+/// all input partitions of a [`RepartitionExec`](super::RepartitionExec)
+pub(super) struct RangeSplitPoints {
+    /// Sort key the rows are partitioned by
+    ordering: LexOrdering,
+    /// Converts the sort key into comparable rows
+    converter: RowConverter,
+    /// Number of output partitions
+    num_partitions: usize,
+    /// Maximum number of rows sampled from every input partition
+    sample_size: usize,
+    /// Spill manager for the buffered input, which is written to disk if it
+    /// does not fit in memory
+    spill_manager: Arc<SpillManager>,
+    /// Memory pool the buffered input is accounted for in
+    memory_pool: Arc<dyn MemoryPool>,
+    /// Name of the [`RepartitionExec`](super::RepartitionExec), used for the
+    /// memory consumers
+    name: String,
+    /// Samples collected so far
+    state: Mutex<SamplingState>,
+    /// Split points, published once all input partitions have been sampled
+    split_points: watch::Sender<Option<Arc<[OwnedRow]>>>,
+}
+
+/// Samples collected from the input partitions
+struct SamplingState {
+    /// Number of input partitions which have not contributed their sample yet
+    remaining_inputs: usize,
+    /// Sampled rows, with the number of input rows every sampled row stands for
+    sample: Vec<(OwnedRow, usize)>,
+}
+
+impl RangeSplitPoints {
+    pub(super) fn try_new(
+        ordering: LexOrdering,
+        schema: &SchemaRef,
+        num_input_partitions: usize,
+        num_partitions: usize,
+        spill_manager: Arc<SpillManager>,
+        memory_pool: Arc<dyn MemoryPool>,
+        name: String,
+    ) -> Result<Self> {
+        let sort_fields = ordering
+            .iter()
+            .map(|sort_expr| {
+                Ok(SortField::new_with_options(
+                    sort_expr.expr.data_type(schema)?,
+                    sort_expr.options,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let (split_points, _) = watch::channel(None);
+
+        Ok(Self {
+            ordering,
+            converter: RowConverter::new(sort_fields)?,
+            num_partitions,
+            sample_size: SAMPLE_ROWS_PER_PARTITION * num_partitions,
+            spill_manager,
+            memory_pool,
+            name,
+            state: Mutex::new(SamplingState {
+                remaining_inputs: num_input_partitions,
+                sample: vec![],
+            }),
+            split_points,
+        })
+    }
+
+    /// Creates the buffer for the input partition `input_partition`
+    pub(super) fn input_buffer(
+        self: &Arc<Self>,
+        input_partition: usize,
+    ) -> RangeInputBuffer {
+        let reservation =
+            MemoryConsumer::new(format!("{}[Range {input_partition}]", self.name))
+                .with_can_spill(true)
+                .register(&self.memory_pool);
+
+        RangeInputBuffer {
+            split_points: Arc::clone(self),
+            batches: vec![],
+            spill_file: None,
+            reservation,
+            sample: vec![],
+            stride: 1,
+            num_rows: 0,
+        }
+    }
+
+    /// Evaluates the sort key of `batch`, and converts it into comparable rows
+    pub(super) fn convert_batch(&self, batch: &RecordBatch) -> Result<Rows> {
+        let columns = self
+            .ordering
+            .iter()
+            .map(|sort_expr| sort_expr.expr.evaluate(batch)?.into_array(batch.num_rows()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.converter.convert_columns(&columns)?)
+    }
+
+    /// Adds the sample of one input partition, where every sampled row stands
+    /// for `weight` input rows. The split points are chosen once all input
+    /// partitions have contributed their sample.
+    ///
+    /// Every input partition must call this exactly once, even if it fails,
+    /// as the others wait for its sample.
+    pub(super) fn add_sample(&self, sample: Vec<OwnedRow>, weight: usize) {
+        let mut state = self.state.lock();
+        state
+            .sample
+            .extend(sample.into_iter().map(|row| (row, weight)));
+        state.remaining_inputs = state.remaining_inputs.saturating_sub(1);
+        if state.remaining_inputs > 0 {
+            return;
+        }
+
+        let mut sample = std::mem::take(&mut state.sample);
+        sample.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let total_weight = sample.iter().map(|(_, weight)| weight).sum::<usize>();
+
+        // The i-th split point is the first sampled row, up to which the
+        // sample covers i / num_partitions of all input rows
+        let mut split_points = Vec::with_capacity(self.num_partitions.saturating_sub(1));
+        let mut cumulative_weight = 0;
+        for (row, weight) in sample {
+            cumulative_weight += weight;
+            while split_points.len() + 1 < self.num_partitions
+                && cumulative_weight * self.num_partitions
+                    >= (split_points.len() + 1) * total_weight
+            {
+                split_points.push(row.clone());
+            }
+        }
+        self.split_points.send_replace(Some(split_points.into()));
+    }
+
+    /// Waits until the samples of all input partitions have been added, and
+    /// returns the split points
+    pub(super) async fn wait(&self) -> Result<Arc<[OwnedRow]>> {
+        let mut receiver = self.split_points.subscribe();
+        let split_points = receiver
+            .wait_for(Option::is_some)
+            .await
+            .map_err(|e| internal_datafusion_err!("Range split points are lost: {e}"))?;
+        match &*split_points {
+            Some(split_points) => Ok(Arc::clone(split_points)),
+            None => internal_err!("Range split points should be available"),
+        }
+    }
+}
+
+/// Returns the output partition of `row`: the number of split points that are
+/// less than or equal to it
+pub(super) fn range_partition(split_points: &[OwnedRow], row: Row<'_>) -> usize {
+    split_points.partition_point(|split_point| split_point.row() <= row)
+}
+
+/// Buffers the input of one input partition until the split points are known,
+/// and samples its sort key
+pub(super) struct RangeInputBuffer {
+    /// Split points the sample is contributed to
+    split_points: Arc<RangeSplitPoints>,
+    /// Batches buffered in memory
+    batches: Vec<RecordBatch>,
+    /// Spill file holding the buffered batches, once they did not fit in
+    /// memory. All following batches are appended to it, to keep their order.
+    spill_file: Option<InProgressSpillFile>,
+    /// Reservation for `batches`
+    reservation: MemoryReservation,
+    /// Rows sampled so far, every `stride`-th row of the input
+    sample: Vec<OwnedRow>,
+    /// Distance between two sampled rows
+    stride: usize,
+    /// Number of input rows seen so far
+    num_rows: usize,
+}
+
+impl RangeInputBuffer {
+    /// Returns the split points this buffer contributes its sample to
+    pub(super) fn split_points(&self) -> &Arc<RangeSplitPoints> {
+        &self.split_points
+    }
+
+    /// Buffers all batches of `stream`
+    pub(super) async fn buffer_stream(
+        &mut self,
+        stream: &mut SendableRecordBatchStream,
+        fetch_time: &metrics::Time,
+    ) -> Result<()> {
+        loop {
+            let timer = fetch_time.timer();
+            let result = stream.next().await;
+            timer.done();
+
+            match result {
+                Some(batch) => self.push(batch?)?,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Samples and buffers `batch`
+    fn push(&mut self, batch: RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+        self.sample_batch(&batch)?;
+
+        let size = batch.get_array_memory_size();
+        if self.spill_file.is_none() && self.reservation.try_grow(size).is_ok() {
+            self.batches.push(batch);
+            return Ok(());
+        }
+
+        let spill_file = match &mut self.spill_file {
+            Some(spill_file) => spill_file,
+            spill_file => spill_file.insert(
+                self.split_points
+                    .spill_manager
+                    .create_in_progress_file("RepartitionExec range buffer")?,
+            ),
+        };
+        for buffered in self.batches.drain(..) {
+            spill_file.append_batch(&buffered)?;
+        }
+        self.reservation.free();
+        spill_file.append_batch(&batch)
+    }
+
+    /// Samples every `stride`-th row of `batch`. Once the sample holds twice
+    /// the sample size, every other sampled row is dropped and the stride is
+    /// doubled, so that the sample stays evenly spread over the input.
+    fn sample_batch(&mut self, batch: &RecordBatch) -> Result<()> {
+        let rows = self.split_points.convert_batch(batch)?;
+        for row in rows.iter() {
+            if self.num_rows.is_multiple_of(self.stride) {
+                self.sample.push(row.owned());
+            }
+            self.num_rows += 1;
+
+            if self.sample.len() >= 2 * self.split_points.sample_size {
+                self.sample = std::mem::take(&mut self.sample)
+                    .into_iter()
+                    .step_by(2)
+                    .collect();
+                self.stride *= 2;
+            }
+        }
+        Ok(())
+    }
+
+    /// Contributes the sample of this input partition to the split points
+    pub(super) fn add_sample(&mut self) {
+        let sample = std::mem::take(&mut self.sample);
+        self.split_points.add_sample(sample, self.stride);
+    }
+
+    /// Returns a stream of the buffered batches, in the order of the input
+    pub(super) fn finish(mut self) -> Result<SendableRecordBatchStream> {
+        let schema = Arc::clone(self.split_points.spill_manager.schema());
+        let spill_file = match self.spill_file.as_mut() {
+            Some(spill_file) => spill_file.finish()?,
+            None => None,
+        };
+        let spilled = match spill_file {
+            Some(file) => self
+                .split_points
+                .spill_manager
+                .read_spill_as_stream(file, None)?,
+            None => Box::pin(EmptyRecordBatchStream::new(Arc::clone(&schema))),
+        };
+
+        let mut reservation = self.reservation;
+        let buffered = futures::stream::iter(self.batches).map(move |batch| {
+            // The memory of a buffered batch is released once it is passed on
+            reservation.shrink(batch.get_array_memory_size());
+            Ok(batch)
+        });
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            schema,
+            buffered.chain(spilled),
+        )))
+    }
+}
@@ -0,0 +1,433 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the TABLESAMPLE plan
+
+use std::any::Any;
+use std::sync::Arc;
+
+use super::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet, RecordOutput,
+};
+use super::{
+    DisplayAs, ExecutionPlanProperties, PlanProperties, SendableRecordBatchStream,
+    Statistics,
+};
+use crate::execution_plan::{CardinalityEffect, EmissionType};
+use crate::stream::RecordBatchStreamAdapter;
+use crate::{DisplayFormatType, Distribution, ExecutionPlan, Partitioning};
+
+use arrow::array::BooleanArray;
+use arrow::compute::{filter_record_batch, interleave_record_batch};
+use arrow::record_batch::RecordBatch;
+use datafusion_common::{Result, SampleMethod, assert_eq_or_internal_err, plan_err};
+use datafusion_execution::TaskContext;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_physical_expr::EquivalenceProperties;
+
+use futures::{StreamExt, TryStreamExt, stream};
+use log::trace;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Returns a random sample of the rows of its input, see [`SampleMethod`].
+///
+/// [`SampleMethod::Bernoulli`] and [`SampleMethod::System`] sample every
+/// partition independently, row by row or record batch by record batch
+/// respectively. [`SampleMethod::Rows`] requires a single input partition and
+/// keeps a reservoir of the sampled rows, which is emitted once the input is
+/// exhausted.
+#[derive(Debug, Clone)]
+pub struct SampleExec {
+    /// Input execution plan
+    input: Arc<dyn ExecutionPlan>,
+    /// How the rows are sampled
+    method: SampleMethod,
+    /// Seed of the random number generator, `None` means a random seed
+    seed: Option<u64>,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    cache: PlanProperties,
+}
+
+impl SampleExec {
+    /// Create a new SampleExec
+    pub fn try_new(
+        input: Arc<dyn ExecutionPlan>,
+        method: SampleMethod,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        if let Some(fraction) = method.fraction()
+            && !(0.0..=1.0).contains(&fraction)
+        {
+            return plan_err!("Sample fraction must be between 0 and 1, got {fraction}");
+        }
+        let cache = Self::compute_properties(&input, &method);
+        Ok(Self {
+            input,
+            method,
+            seed,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// Input execution plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// How the rows are sampled
+    pub fn method(&self) -> &SampleMethod {
+        &self.method
+    }
+
+    /// Seed of the random number generator
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        input: &Arc<dyn ExecutionPlan>,
+        method: &SampleMethod,
+    ) -> PlanProperties {
+        match method {
+            // The reservoir does not keep the rows in their input order
+            SampleMethod::Rows(_) => PlanProperties::new(
+                EquivalenceProperties::new(input.schema()),
+                Partitioning::UnknownPartitioning(1),
+                EmissionType::Final,
+                input.boundedness(),
+            ),
+            SampleMethod::Bernoulli(_) | SampleMethod::System(_) => PlanProperties::new(
+                input.equivalence_properties().clone(),
+                input.output_partitioning().clone(),
+                input.pipeline_behavior(),
+                input.boundedness(),
+            ),
+        }
+    }
+}
+
+impl DisplayAs for SampleExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "SampleExec: method={}", self.method)?;
+                if let Some(seed) = self.seed {
+                    write!(f, ", seed={seed}")?;
+                }
+                Ok(())
+            }
+            DisplayFormatType::TreeRender => {
+                write!(f, "method={}", self.method)?;
+                if let Some(seed) = self.seed {
+                    write!(f, "\nseed={seed}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for SampleExec {
+    fn name(&self) -> &'static str {
+        "SampleExec"
+    }
+
+    /// Return a reference to Any that can be used for downcasting
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.input]
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        match self.method {
+            SampleMethod::Rows(_) => vec![Distribution::SinglePartition],
+            SampleMethod::Bernoulli(_) | SampleMethod::System(_) => {
+                vec![Distribution::UnspecifiedDistribution]
+            }
+        }
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![!matches!(self.method, SampleMethod::Rows(_))]
+    }
+
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        vec![false]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(SampleExec::try_new(
+            Arc::clone(&children[0]),
+            self.method,
+            self.seed,
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        trace!("Start SampleExec::execute for partition: {partition}");
+        let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        let seed = self.seed.unwrap_or_else(rand::random);
+        // Draw a different random sequence for every partition
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(partition as u64));
+        let schema = self.schema();
+
+        let stream = match self.method {
+            SampleMethod::Bernoulli(fraction) => {
+                let input = self.input.execute(partition, context)?;
+                input
+                    .map(move |batch| {
+                        let batch = batch?;
+                        let _timer = baseline_metrics.elapsed_compute().timer();
+                        let mask: BooleanArray = (0..batch.num_rows())
+                            .map(|_| Some(rng.random_bool(fraction)))
+                            .collect();
+                        let batch = filter_record_batch(&batch, &mask)?;
+                        Ok(batch.record_output(&baseline_metrics))
+                    })
+                    .boxed()
+            }
+            SampleMethod::System(fraction) => {
+                let input = self.input.execute(partition, context)?;
+                input
+                    .try_filter(move |_| std::future::ready(rng.random_bool(fraction)))
+                    .map_ok(move |batch| batch.record_output(&baseline_metrics))
+                    .boxed()
+            }
+            SampleMethod::Rows(rows) => {
+                // SampleExec with a fixed number of rows requires a single input partition
+                assert_eq_or_internal_err!(
+                    self.input.output_partitioning().partition_count(),
+                    1,
+                    "SampleExec requires a single input partition"
+                );
+                let input = self.input.execute(0, Arc::clone(&context))?;
+                let reservation = MemoryConsumer::new(format!("SampleExec[{partition}]"))
+                    .register(context.memory_pool());
+                let reservoir = Reservoir {
+                    rows,
+                    seen: 0,
+                    sample: RecordBatch::new_empty(Arc::clone(&schema)),
+                    rng,
+                    reservation,
+                };
+                stream::once(reservoir.sample(input, baseline_metrics)).boxed()
+            }
+        };
+        Ok(Box::pin(RecordBatchStreamAdapter::new(schema, stream)))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        self.partition_statistics(None)
+    }
+
+    fn partition_statistics(&self, partition: Option<usize>) -> Result<Statistics> {
+        Ok(self
+            .input
+            .partition_statistics(partition)?
+            .with_sample(&self.method))
+    }
+
+    fn cardinality_effect(&self) -> CardinalityEffect {
+        CardinalityEffect::LowerEqual
+    }
+}
+
+/// Uniform random sample of a fixed number of rows, using reservoir sampling
+/// ("Algorithm R")
+struct Reservoir {
+    /// The number of rows to sample
+    rows: usize,
+    /// The number of input rows seen so far
+    seen: usize,
+    /// The rows sampled so far
+    sample: RecordBatch,
+    rng: StdRng,
+    reservation: MemoryReservation,
+}
+
+impl Reservoir {
+    /// Consume `input` and return the sampled rows
+    async fn sample(
+        mut self,
+        mut input: SendableRecordBatchStream,
+        baseline_metrics: BaselineMetrics,
+    ) -> Result<RecordBatch> {
+        while let Some(batch) = input.next().await {
+            let _timer = baseline_metrics.elapsed_compute().timer();
+            self.insert(&batch?)?;
+        }
+        Ok(self.sample.record_output(&baseline_metrics))
+    }
+
+    /// Offer every row of `batch` to the reservoir
+    fn insert(&mut self, batch: &RecordBatch) -> Result<()> {
+        // (batch, row) pairs of the new sample, where batch 0 is the current
+        // sample and batch 1 is `batch`
+        let mut indices: Vec<(usize, usize)> =
+            (0..self.sample.num_rows()).map(|row| (0, row)).collect();
+        let mut changed = false;
+        for row in 0..batch.num_rows() {
+            if indices.len() < self.rows {
+                indices.push((1, row));
+                changed = true;
+            } else {
+                let slot = self.rng.random_range(0..=self.seen);
+                if slot < self.rows {
+                    indices[slot] = (1, row);
+                    changed = true;
+                }
+            }
+            self.seen += 1;
+        }
+
+        if changed {
+            self.sample = interleave_record_batch(&[&self.sample, batch], &indices)?;
+            self.reservation
+                .try_resize(self.sample.get_array_memory_size())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::collect;
+    use crate::test;
+
+    use arrow::array::{Array, Int32Array};
+
+    fn sampled_values(batches: &[RecordBatch]) -> Vec<i32> {
+        batches
+            .iter()
+            .flat_map(|batch| {
+                let array = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap();
+                array.values().to_vec()
+            })
+            .collect()
+    }
+
+    async fn run_sample(
+        input: Arc<dyn ExecutionPlan>,
+        method: SampleMethod,
+        seed: Option<u64>,
+    ) -> Result<Vec<i32>> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let sample = SampleExec::try_new(input, method, seed)?;
+        let mut values = vec![];
+        for partition in 0..sample.properties().partitioning.partition_count() {
+            let stream = sample.execute(partition, Arc::clone(&task_ctx))?;
+            values.extend(sampled_values(&collect(stream).await?));
+        }
+        Ok(values)
+    }
+
+    #[tokio::test]
+    async fn sample_bernoulli() -> Result<()> {
+        let input = test::scan_partitioned(4);
+        let values =
+            run_sample(Arc::clone(&input), SampleMethod::Bernoulli(0.5), Some(42))
+                .await?;
+        assert!(!values.is_empty() && values.len() < 400);
+
+        // The same seed returns the same sample
+        let repeated =
+            run_sample(Arc::clone(&input), SampleMethod::Bernoulli(0.5), Some(42))
+                .await?;
+        assert_eq!(values, repeated);
+
+        let all =
+            run_sample(Arc::clone(&input), SampleMethod::Bernoulli(1.0), None).await?;
+        assert_eq!(all.len(), 400);
+        let none = run_sample(input, SampleMethod::Bernoulli(0.0), None).await?;
+        assert!(none.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sample_system() -> Result<()> {
+        // Every partition is a single batch of 100 rows
+        let input = test::scan_partitioned(8);
+        let values = run_sample(input, SampleMethod::System(0.5), Some(7)).await?;
+        assert_eq!(values.len() % 100, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sample_rows() -> Result<()> {
+        let input = test::scan_partitioned(1);
+        let mut values = run_sample(input, SampleMethod::Rows(10), Some(1)).await?;
+        assert_eq!(values.len(), 10);
+        values.sort_unstable();
+        values.dedup();
+        assert_eq!(values.len(), 10);
+
+        let input = test::scan_partitioned(1);
+        let values = run_sample(input, SampleMethod::Rows(1000), None).await?;
+        assert_eq!(values.len(), 100);
+        Ok(())
+    }
+
+    #[test]
+    fn sample_invalid_fraction() {
+        let input = test::scan_partitioned(1);
+        let err =
+            SampleExec::try_new(input, SampleMethod::Bernoulli(1.5), None).unwrap_err();
+        assert!(err.to_string().contains("between 0 and 1"));
+    }
+
+    #[test]
+    fn sample_rows_requires_single_partition() {
+        let input = test::scan_partitioned(4);
+        let sample = SampleExec::try_new(input, SampleMethod::Rows(10), None).unwrap();
+        assert!(matches!(
+            sample.required_input_distribution().as_slice(),
+            [Distribution::SinglePartition]
+        ));
+        assert_eq!(sample.properties().partitioning.partition_count(), 1);
+    }
+}
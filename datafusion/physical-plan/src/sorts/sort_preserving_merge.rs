@@ -25,6 +25,7 @@ use crate::limit::LimitStream;
 use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
 use crate::projection::{ProjectionExec, make_with_child, update_ordering};
 use crate::sorts::streaming_merge::StreamingMergeBuilder;
+use crate::stream::RecordBatchStreamAdapter;
 use crate::{
     DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, ExecutionPlanProperties,
     Partitioning, PlanProperties, SendableRecordBatchStream, Statistics,
@@ -36,6 +37,7 @@ use datafusion_execution::memory_pool::MemoryConsumer;
 use datafusion_physical_expr_common::sort_expr::{LexOrdering, OrderingRequirements};
 
 use crate::execution_plan::{EvaluationType, SchedulingType};
+use futures::StreamExt;
 use log::{debug, trace};
 
 /// Sort preserving merge execution plan
@@ -78,6 +80,13 @@ use log::{debug, trace};
 ///    (sorted)                                                  (sorted)
 /// ```
 ///
+/// # Range Partitioned Inputs
+///
+/// If the input is [`Partitioning::Range`] partitioned by a prefix of the sort
+/// expressions, every row of an input partition sorts before all rows of the
+/// following partitions, so the partitions are concatenated in order instead
+/// of being merged. They are still executed concurrently.
+///
 /// # Error Handling
 ///
 /// If any of the input partitions return an error, the error is propagated to
@@ -137,6 +146,18 @@ impl SortPreservingMergeExec {
         self
     }
 
+    /// Returns true if the input partitions are non-overlapping ranges of the
+    /// sort expressions, in order, so that they can be concatenated
+    fn concatenates_input_ranges(&self) -> bool {
+        match self.input.output_partitioning() {
+            Partitioning::Range(ordering, _) => {
+                ordering.len() <= self.expr.len()
+                    && ordering.iter().zip(self.expr.iter()).all(|(a, b)| a == b)
+            }
+            _ => false,
+        }
+    }
+
     /// Input schema
     pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
         &self.input
@@ -322,6 +343,22 @@ impl ExecutionPlan for SortPreservingMergeExec {
                     stream
                 }
             },
+            _ if self.concatenates_input_ranges() => {
+                let receivers = (0..input_partitions)
+                    .map(|partition| {
+                        let stream =
+                            self.input.execute(partition, Arc::clone(&context))?;
+                        Ok(spawn_buffered(stream, 1))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let stream = futures::stream::iter(receivers).flatten();
+                Ok(Box::pin(LimitStream::new(
+                    Box::pin(RecordBatchStreamAdapter::new(schema, stream)),
+                    0,
+                    self.fetch,
+                    BaselineMetrics::new(&self.metrics, partition),
+                )))
+            }
             _ => {
                 let receivers = (0..input_partitions)
                     .map(|partition| {
@@ -1069,6 +1106,50 @@ mod tests {
         ");
     }
 
+    #[tokio::test]
+    async fn test_merge_range_partitioned_input() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![5, 1, 8, 3, 9, 2, 7]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5, 6, 7]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)])?;
+        let schema = batch.schema();
+        let (first, second) = (batch.slice(0, 4), batch.slice(4, 3));
+        let source =
+            TestMemoryExec::try_new_exec(&[vec![first], vec![second]], schema, None)?;
+
+        let a_asc = PhysicalSortExpr::new_default(col("a", &source.schema())?);
+        let b_asc = PhysicalSortExpr::new_default(col("b", &source.schema())?);
+        let range = LexOrdering::from([a_asc.clone()]);
+        let repartition = Arc::new(RepartitionExec::try_new(
+            source,
+            Partitioning::Range(range, 3),
+        )?);
+        let sort_exprs = LexOrdering::from([a_asc, b_asc]);
+        let sort = Arc::new(
+            SortExec::new(sort_exprs.clone(), repartition)
+                .with_preserve_partitioning(true),
+        );
+
+        // The sorted ranges are concatenated, as a prefix of the sort
+        // expressions is range partitioned
+        let merge = SortPreservingMergeExec::new(sort_exprs, sort);
+        assert!(merge.concatenates_input_ranges());
+        let merge = Arc::new(merge.with_fetch(Some(5)));
+        let collected = collect(merge, task_ctx).await?;
+        assert_snapshot!(batches_to_string(&collected), @r"
+        +---+---+
+        | a | b |
+        +---+---+
+        | 1 | 2 |
+        | 2 | 6 |
+        | 3 | 4 |
+        | 5 | 1 |
+        | 7 | 7 |
+        +---+---+
+        ");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_async() -> Result<()> {
         let task_ctx = Arc::new(TaskContext::default());
@@ -1475,6 +1556,7 @@ mod tests {
         let &partition_count = match properties.output_partitioning() {
             Partitioning::RoundRobinBatch(partitions) => partitions,
             Partitioning::Hash(_, partitions) => partitions,
+            Partitioning::Range(_, partitions) => partitions,
             Partitioning::UnknownPartitioning(partitions) => partitions,
         };
         let source = CongestedExec {
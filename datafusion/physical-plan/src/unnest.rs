@@ -44,7 +44,7 @@ use arrow_ord::cmp::lt;
 use async_trait::async_trait;
 use datafusion_common::{
     Constraints, HashMap, HashSet, Result, UnnestOptions, exec_datafusion_err, exec_err,
-    internal_err,
+    internal_datafusion_err, internal_err,
 };
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::PhysicalExpr;
@@ -616,7 +616,14 @@ fn build_batch(
     options: &UnnestOptions,
 ) -> Result<Option<RecordBatch>> {
     let transformed = match list_type_columns.len() {
-        0 => flatten_struct_cols(batch.columns(), schema, struct_column_indices),
+        0 => {
+            let mut columns = batch.columns().to_vec();
+            if options.ordinality {
+                // Every input row is unnested into exactly one row
+                columns.push(Arc::new(Int64Array::from_value(1, batch.num_rows())));
+            }
+            flatten_struct_cols(&columns, schema, struct_column_indices)
+        }
         _ => {
             let mut temp_unnested_result = HashMap::new();
             let max_recursion = list_type_columns
@@ -625,6 +632,15 @@ fn build_batch(
                     cmp::max(highest_depth, *depth)
                 });
 
+            // The index of every input row is appended as an extra column, which
+            // is repeated along with the other columns, to compute the ordinality
+            let mut input_columns = batch.columns().to_vec();
+            if options.ordinality {
+                input_columns.push(Arc::new(Int64Array::from_iter_values(
+                    0..batch.num_rows() as i64,
+                )));
+            }
+
             // This arr always has the same column count with the input batch
             let mut flatten_arrs = vec![];
 
@@ -632,7 +648,7 @@ fn build_batch(
             // All unnesting results are written to temp_batch
             for depth in (1..=max_recursion).rev() {
                 let input = match depth == max_recursion {
-                    true => batch.columns(),
+                    true => &input_columns,
                     false => &flatten_arrs,
                 };
                 let Some(temp_result) = list_unnest_at_level(
@@ -647,6 +663,15 @@ fn build_batch(
                 };
                 flatten_arrs = temp_result;
             }
+            let ordinality = match options.ordinality {
+                true => {
+                    let row_indices = flatten_arrs.pop().ok_or_else(|| {
+                        internal_datafusion_err!("Missing input row indices of unnest")
+                    })?;
+                    Some(compute_ordinality(row_indices.as_primitive::<Int64Type>()))
+                }
+                false => None,
+            };
             let unnested_array_map: HashMap<usize, Vec<UnnestingResult>> =
                 temp_unnested_result.into_iter().fold(
                     HashMap::new(),
@@ -724,6 +749,7 @@ fn build_batch(
                         None => vec![arr],
                     }
                 })
+                .chain(ordinality)
                 .collect::<Vec<_>>();
 
             flatten_struct_cols(&ret, schema, struct_column_indices)
@@ -732,6 +758,26 @@ fn build_batch(
     Ok(Some(transformed))
 }
 
+/// Returns the 1-based position of every unnested row among the rows unnested
+/// from the same input row, given the (non-decreasing) input row indices.
+///
+/// For example, the row indices `[0, 0, 0, 2, 3, 3]` result in the ordinality
+/// `[1, 2, 3, 1, 1, 2]`.
+fn compute_ordinality(row_indices: &PrimitiveArray<Int64Type>) -> ArrayRef {
+    let mut previous_row = None;
+    let mut position = 0;
+    Arc::new(Int64Array::from_iter_values(
+        row_indices.values().iter().map(|row| {
+            position = match previous_row == Some(*row) {
+                true => position + 1,
+                false => 1,
+            };
+            previous_row = Some(*row);
+            position
+        }),
+    ))
+}
+
 /// Find the longest list length among the given list arrays for each row.
 ///
 /// For example if we have the following two list arrays:
@@ -753,6 +799,8 @@ fn build_batch(
 /// ```ignore
 /// longest_length: [3, 1, 1, 2]
 /// ```
+///
+/// If `preserve_empty` is true, empty lists have a length of 1 as well.
 fn find_longest_length(
     list_arrays: &[ArrayRef],
     options: &UnnestOptions,
@@ -763,12 +811,16 @@ fn find_longest_length(
     } else {
         Scalar::new(Int64Array::from_value(0, 1))
     };
+    let one = Scalar::new(Int64Array::from_value(1, 1));
     let list_lengths: Vec<ArrayRef> = list_arrays
         .iter()
         .map(|list_array| {
             let mut length_array = length(list_array)?;
             // Make sure length arrays have the same type. Int64 is the most general one.
             length_array = cast(&length_array, &DataType::Int64)?;
+            if options.preserve_empty {
+                length_array = zip(&lt(&length_array, &one)?, &one, &length_array)?;
+            }
             length_array =
                 zip(&is_not_null(&length_array)?, &length_array, &null_length)?;
             Ok(length_array)
@@ -1194,6 +1246,8 @@ mod tests {
             &UnnestOptions {
                 preserve_nulls: true,
                 recursions: vec![],
+                preserve_empty: false,
+                ordinality: false,
             },
         )?
         .unwrap();
@@ -1228,6 +1282,118 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_build_batch_with_ordinality() -> Result<()> {
+        // col1      | col2
+        // [1, 2, 3] | [10, 20]
+        // null      | [30]
+        // [4]       | null
+        let col1 = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![Some(4)]),
+        ]);
+        let col2 = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(10), Some(20)]),
+            Some(vec![Some(30)]),
+            None,
+        ]);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("col1", col1.data_type().clone(), true),
+            Field::new("col2", col2.data_type().clone(), true),
+        ]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(col1), Arc::new(col2)])?;
+        let out_schema = Arc::new(Schema::new(vec![
+            Field::new("col1", DataType::Int32, true),
+            Field::new("col2", DataType::Int32, true),
+            Field::new("ordinality", DataType::Int64, false),
+        ]));
+        let list_type_columns = [
+            ListUnnest {
+                index_in_input_schema: 0,
+                depth: 1,
+            },
+            ListUnnest {
+                index_in_input_schema: 1,
+                depth: 1,
+            },
+        ];
+        let options = UnnestOptions::new()
+            .with_preserve_nulls(false)
+            .with_ordinality(true);
+        let ret = build_batch(
+            &batch,
+            &out_schema,
+            &list_type_columns,
+            &HashSet::default(),
+            &options,
+        )?
+        .unwrap();
+
+        assert_snapshot!(batches_to_string(&[ret]), @r"
+        +------+------+------------+
+        | col1 | col2 | ordinality |
+        +------+------+------------+
+        | 1    | 10   | 1          |
+        | 2    | 20   | 2          |
+        | 3    |      | 3          |
+        |      | 30   | 1          |
+        | 4    |      | 1          |
+        +------+------+------------+
+        ");
+
+        // col1
+        // [[1, 2], [3]]
+        // [[4]]
+        let inner = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+            Some(vec![Some(4)]),
+        ]);
+        let inner_field =
+            Arc::new(Field::new_list_field(inner.data_type().clone(), true));
+        let col1 = ListArray::new(
+            Arc::clone(&inner_field),
+            OffsetBuffer::from_lengths([2, 1]),
+            Arc::new(inner),
+            None,
+        );
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "col1",
+            col1.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(col1)])?;
+        let out_schema = Arc::new(Schema::new(vec![
+            Field::new("col1", DataType::Int32, true),
+            Field::new("ordinality", DataType::Int64, false),
+        ]));
+        let list_type_columns = [ListUnnest {
+            index_in_input_schema: 0,
+            depth: 2,
+        }];
+        let ret = build_batch(
+            &batch,
+            &out_schema,
+            &list_type_columns,
+            &HashSet::default(),
+            &options,
+        )?
+        .unwrap();
+
+        assert_snapshot!(batches_to_string(&[ret]), @r"
+        +------+------------+
+        | col1 | ordinality |
+        +------+------------+
+        | 1    | 1          |
+        | 2    | 2          |
+        | 3    | 3          |
+        | 4    | 1          |
+        +------+------------+
+        ");
+        Ok(())
+    }
+
     #[test]
     fn test_unnest_list_array() -> Result<()> {
         // [A, B, C], [], NULL, [D], NULL, [NULL, F]
@@ -1281,6 +1447,8 @@ mod tests {
         let options = UnnestOptions {
             preserve_nulls,
             recursions: vec![],
+            preserve_empty: false,
+            ordinality: false,
         };
         let longest_length = find_longest_length(list_arrays, &options)?;
         let expected_array = Int64Array::from(expected);
@@ -1323,6 +1491,22 @@ mod tests {
         verify_longest_length(&list_arrays, false, vec![3, 0, 2, 1, 2, 2])?;
         verify_longest_length(&list_arrays, true, vec![3, 1, 2, 1, 2, 2])?;
 
+        // Test with preserved empty lists
+        //  [A, B, C], [], NULL, [D], NULL, [NULL, F]
+        for (preserve_nulls, expected) in [
+            (false, vec![3, 1, 0, 1, 0, 2]),
+            (true, vec![3, 1, 1, 1, 1, 2]),
+        ] {
+            let options = UnnestOptions::new()
+                .with_preserve_nulls(preserve_nulls)
+                .with_preserve_empty(true);
+            let longest_length = find_longest_length(&[Arc::clone(&list1)], &options)?;
+            assert_eq!(
+                longest_length.as_primitive::<Int64Type>(),
+                &Int64Array::from(expected)
+            );
+        }
+
         Ok(())
     }
 
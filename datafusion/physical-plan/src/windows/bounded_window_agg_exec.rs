@@ -28,7 +28,11 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use super::utils::create_schema;
-use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet, SpillMetrics,
+};
+use crate::spill::get_record_batch_memory_size;
+use crate::spill::spill_manager::SpillManager;
 use crate::windows::{
     calc_requirements, get_ordered_partition_by_indices, get_partition_by_sort_exprs,
     window_equivalence_properties,
@@ -52,9 +56,11 @@ use datafusion_common::utils::{
     evaluate_partition_ranges, get_at_indices, get_row_at_idx,
 };
 use datafusion_common::{
-    HashMap, Result, arrow_datafusion_err, exec_datafusion_err, exec_err,
+    HashMap, Result, arrow_datafusion_err, exec_datafusion_err, exec_err, internal_err,
 };
 use datafusion_execution::TaskContext;
+use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_expr::ColumnarValue;
 use datafusion_expr::window_state::{PartitionBatchState, WindowAggState};
 use datafusion_physical_expr::window::{
@@ -352,14 +358,31 @@ impl ExecutionPlan for BoundedWindowAggExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
-        let input = self.input.execute(partition, context)?;
+        let input = self.input.execute(partition, Arc::clone(&context))?;
         let search_mode = self.get_search_algo()?;
+        // Rows waiting for their window results can only be spilled if the
+        // search algorithm does not need them to find the rows to emit
+        let can_spill = context.runtime_env().disk_manager.tmp_files_enabled()
+            && !search_mode.reads_input_buffer();
+        let reservation =
+            MemoryConsumer::new(format!("BoundedWindowAggStream[{partition}]"))
+                .with_can_spill(can_spill)
+                .register(context.memory_pool());
+        let spill_manager = SpillManager::new(
+            context.runtime_env(),
+            SpillMetrics::new(&self.metrics, partition),
+            self.input.schema(),
+        )
+        .with_compression_type(context.session_config().spill_compression());
         let stream = Box::pin(BoundedWindowAggStream::new(
             Arc::clone(&self.schema),
             self.window_expr.clone(),
             input,
             BaselineMetrics::new(&self.metrics, partition),
             search_mode,
+            reservation,
+            SpilledRows::new(spill_manager),
+            can_spill,
         )?);
         Ok(stream)
     }
@@ -403,6 +426,13 @@ trait PartitionSearcher: Send {
         false
     }
 
+    /// Whether [`Self::calculate_out_columns`] reads the rows of `input_buffer`.
+    /// If it does not, the rows of `input_buffer` can be spilled to disk while
+    /// they wait for their window results.
+    fn reads_input_buffer(&self) -> bool {
+        true
+    }
+
     // Constructs corresponding batches for each partition for the record_batch.
     fn evaluate_partition_batches(
         &mut self,
@@ -841,6 +871,10 @@ impl PartitionSearcher for SortedSearch {
         }
     }
 
+    fn reads_input_buffer(&self) -> bool {
+        false
+    }
+
     fn input_schema(&self) -> &SchemaRef {
         &self.input_schema
     }
@@ -936,6 +970,18 @@ pub struct BoundedWindowAggStream {
     /// Search mode for partition columns. This determines the algorithm with
     /// which we group each partition.
     search_mode: Box<dyn PartitionSearcher>,
+    /// Memory used by `input_buffer`, `partition_buffers` and the window
+    /// results in `window_agg_states`
+    reservation: MemoryReservation,
+    /// The oldest rows waiting for their window results, which were spilled
+    /// to disk. These rows come before the rows of `input_buffer`.
+    spilled_rows: SpilledRows,
+    /// Whether `input_buffer` can be spilled to disk when it does not fit in
+    /// memory
+    can_spill: bool,
+    /// Window results whose input rows have to be read back from disk
+    /// before they are emitted
+    pending_output: Option<Vec<ArrayRef>>,
 }
 
 impl BoundedWindowAggStream {
@@ -957,6 +1003,143 @@ impl BoundedWindowAggStream {
         self.search_mode.prune(n_out);
         Ok(())
     }
+
+    /// Size of the buffered input rows and window results
+    fn buffered_memory_size(&self) -> usize {
+        let partition_buffers_size = self
+            .partition_buffers
+            .values()
+            .map(|state| get_record_batch_memory_size(&state.record_batch))
+            .sum::<usize>();
+        let window_results_size = self
+            .window_agg_states
+            .iter()
+            .flat_map(|states| states.values())
+            .map(|WindowState { state, .. }| state.out_col.get_array_memory_size())
+            .sum::<usize>();
+        get_record_batch_memory_size(&self.input_buffer)
+            + partition_buffers_size
+            + window_results_size
+    }
+
+    /// Resizes the memory reservation to the buffered state. If it does not
+    /// fit in memory, the rows of `input_buffer` waiting for their window
+    /// results are spilled to disk.
+    fn update_reservation(&mut self) -> Result<()> {
+        if let Err(e) = self.reservation.try_resize(self.buffered_memory_size()) {
+            if !self.can_spill || self.input_buffer.num_rows() == 0 {
+                return Err(e);
+            }
+            self.spilled_rows.spill(&self.input_buffer)?;
+            self.input_buffer =
+                RecordBatch::new_empty(Arc::clone(self.search_mode.input_schema()));
+            self.reservation.try_resize(self.buffered_memory_size())?;
+        }
+        Ok(())
+    }
+
+    /// Emits the window results of `pending_output` together with their input
+    /// rows, the oldest of which are read back from disk.
+    fn poll_pending_output(&mut self, cx: &mut Context<'_>) -> Poll<Result<RecordBatch>> {
+        let Some(window_expr_out) = &self.pending_output else {
+            return Poll::Ready(internal_err!("No pending window results to emit"));
+        };
+        let n_out = window_expr_out[0].len();
+        let n_spilled = min(n_out, self.spilled_rows.num_rows);
+        let spilled = ready!(self.spilled_rows.poll_take(cx, n_spilled))?;
+
+        let n_in_memory = n_out - n_spilled;
+        let input_rows = if n_in_memory == 0 {
+            spilled
+        } else {
+            concat_batches(
+                self.search_mode.input_schema(),
+                [&spilled, &self.input_buffer.slice(0, n_in_memory)],
+            )?
+        };
+        self.prune_input_batch(n_in_memory)?;
+        self.update_reservation()?;
+
+        let columns = input_rows
+            .columns()
+            .iter()
+            .cloned()
+            .chain(self.pending_output.take().into_iter().flatten())
+            .collect::<Vec<_>>();
+        Poll::Ready(Ok(RecordBatch::try_new(Arc::clone(&self.schema), columns)?))
+    }
+}
+
+/// Rows of the input buffer of [`BoundedWindowAggStream`] that were spilled to
+/// disk while they were waiting for their window results. They are read back
+/// in input order when their window results are emitted.
+struct SpilledRows {
+    spill_manager: SpillManager,
+    /// Spill files that were not read yet, oldest first
+    spill_files: VecDeque<RefCountedTempFile>,
+    /// Reads the oldest spill file
+    reader: Option<SendableRecordBatchStream>,
+    /// Rows that were read back from disk but not emitted yet
+    unspilled: Vec<RecordBatch>,
+    /// Number of spilled rows that were not emitted yet
+    num_rows: usize,
+}
+
+impl SpilledRows {
+    fn new(spill_manager: SpillManager) -> Self {
+        Self {
+            spill_manager,
+            spill_files: VecDeque::new(),
+            reader: None,
+            unspilled: vec![],
+            num_rows: 0,
+        }
+    }
+
+    /// Spills `batch` after the rows spilled so far
+    fn spill(&mut self, batch: &RecordBatch) -> Result<()> {
+        if let Some(spill_file) = self.spill_manager.spill_record_batch_and_finish(
+            std::slice::from_ref(batch),
+            "BoundedWindowAggExec spill",
+        )? {
+            self.spill_files.push_back(spill_file);
+            self.num_rows += batch.num_rows();
+        }
+        Ok(())
+    }
+
+    /// Reads the oldest `n` spilled rows back from disk
+    fn poll_take(&mut self, cx: &mut Context<'_>, n: usize) -> Poll<Result<RecordBatch>> {
+        while self
+            .unspilled
+            .iter()
+            .map(|batch| batch.num_rows())
+            .sum::<usize>()
+            < n
+        {
+            let Some(reader) = self.reader.as_mut() else {
+                let Some(spill_file) = self.spill_files.pop_front() else {
+                    return Poll::Ready(internal_err!(
+                        "Expected {n} spilled rows, but the spill files are exhausted"
+                    ));
+                };
+                self.reader =
+                    Some(self.spill_manager.read_spill_as_stream(spill_file, None)?);
+                continue;
+            };
+            match ready!(reader.poll_next_unpin(cx)) {
+                Some(Ok(batch)) => self.unspilled.push(batch),
+                Some(Err(e)) => return Poll::Ready(Err(e)),
+                None => self.reader = None,
+            }
+        }
+
+        let unspilled = concat_batches(self.spill_manager.schema(), &self.unspilled)?;
+        let n_remaining = unspilled.num_rows() - n;
+        self.unspilled = vec![unspilled.slice(n, n_remaining)];
+        self.num_rows -= n;
+        Poll::Ready(Ok(unspilled.slice(0, n)))
+    }
 }
 
 impl Stream for BoundedWindowAggStream {
@@ -973,12 +1156,16 @@ impl Stream for BoundedWindowAggStream {
 
 impl BoundedWindowAggStream {
     /// Create a new BoundedWindowAggStream
+    #[expect(clippy::too_many_arguments)]
     fn new(
         schema: SchemaRef,
         window_expr: Vec<Arc<dyn WindowExpr>>,
         input: SendableRecordBatchStream,
         baseline_metrics: BaselineMetrics,
         search_mode: Box<dyn PartitionSearcher>,
+        reservation: MemoryReservation,
+        spilled_rows: SpilledRows,
+        can_spill: bool,
     ) -> Result<Self> {
         let state = window_expr.iter().map(|_| IndexMap::new()).collect();
         let empty_batch = RecordBatch::new_empty(Arc::clone(&schema));
@@ -992,6 +1179,10 @@ impl BoundedWindowAggStream {
             window_expr,
             baseline_metrics,
             search_mode,
+            reservation,
+            spilled_rows,
+            can_spill,
+            pending_output: None,
         })
     }
 
@@ -1012,6 +1203,17 @@ impl BoundedWindowAggStream {
         )?;
         if let Some(window_expr_out) = window_expr_out {
             let n_out = window_expr_out[0].len();
+            if self.spilled_rows.num_rows > 0 {
+                // The oldest rows of the input buffer are on disk. Prune the
+                // window state now, and emit the results once these rows are
+                // read back.
+                self.prune_out_columns();
+                self.prune_partition_batches();
+                self.search_mode.prune(n_out);
+                self.pending_output = Some(window_expr_out);
+                self.update_reservation()?;
+                return Ok(None);
+            }
             // right append new columns to corresponding section in the original input buffer.
             let columns_to_show = self
                 .input_buffer
@@ -1022,8 +1224,10 @@ impl BoundedWindowAggStream {
                 .collect::<Vec<_>>();
             let n_generated = columns_to_show[0].len();
             self.prune_state(n_generated)?;
+            self.update_reservation()?;
             Ok(Some(RecordBatch::try_new(schema, columns_to_show)?))
         } else {
+            self.update_reservation()?;
             Ok(None)
         }
     }
@@ -1033,6 +1237,9 @@ impl BoundedWindowAggStream {
         &mut self,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Result<RecordBatch>>> {
+        if self.pending_output.is_some() {
+            return self.poll_pending_output(cx).map(Some);
+        }
         if self.finished {
             return Poll::Ready(None);
         }
@@ -1066,6 +1273,9 @@ impl BoundedWindowAggStream {
                 if let Some(batch) = self.compute_aggregates()? {
                     return Poll::Ready(Some(Ok(batch)));
                 }
+                if self.pending_output.is_some() {
+                    return self.poll_pending_output(cx).map(Some);
+                }
                 Poll::Ready(None)
             }
         }
@@ -1254,14 +1464,15 @@ mod tests {
     use crate::{ExecutionPlan, displayable, execute_stream};
 
     use arrow::array::{
-        RecordBatch,
+        AsArray, Int64Array, RecordBatch,
         builder::{Int64Builder, UInt64Builder},
     };
     use arrow::compute::SortOptions;
-    use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+    use arrow::datatypes::{DataType, Field, Int64Type, Schema, SchemaRef};
     use datafusion_common::test_util::batches_to_string;
     use datafusion_common::{Result, ScalarValue, exec_datafusion_err};
     use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::runtime_env::RuntimeEnvBuilder;
     use datafusion_execution::{
         RecordBatchStream, SendableRecordBatchStream, TaskContext,
     };
@@ -1828,4 +2039,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_bounded_window_spill() -> Result<()> {
+        // The window result of every row is known only 5000 rows later, so
+        // the rows waiting for their results do not fit in memory
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batches = (0..10)
+            .map(|i| {
+                let a = Int64Array::from_iter_values(i * 1000..(i + 1) * 1000);
+                RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(a)])
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let input = TestMemoryExec::try_new_exec(&[batches], Arc::clone(&schema), None)?;
+        let window_frame = WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::CurrentRow,
+            WindowFrameBound::Following(ScalarValue::UInt64(Some(5000))),
+        );
+        let window_agg_exec = Arc::new(BoundedWindowAggExec::try_new(
+            vec![create_window_expr(
+                &WindowFunctionDefinition::AggregateUDF(count_udaf()),
+                "count".to_owned(),
+                &[col("a", &schema)?],
+                &[],
+                &[],
+                Arc::new(window_frame),
+                Arc::clone(&schema),
+                false,
+                false,
+                None,
+            )?],
+            input,
+            InputOrderMode::Sorted,
+            false,
+        )?);
+
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_limit(80_000, 1.0)
+            .build_arc()?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let stream = window_agg_exec.execute(0, Arc::clone(&task_ctx))?;
+        let result = collect(stream).await?;
+
+        let values = result
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, (0..10000).collect::<Vec<_>>());
+        let counts = result
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(1)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        let expected = (0..10000)
+            .map(|i| (10000 - i).min(5001))
+            .collect::<Vec<_>>();
+        assert_eq!(counts, expected);
+
+        let metrics = window_agg_exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+
+        Ok(())
+    }
 }
@@ -739,13 +739,16 @@ mod tests {
     use crate::collect;
     use crate::expressions::col;
     use crate::streaming::StreamingTableExec;
-    use crate::test::assert_is_pending;
     use crate::test::exec::{BlockingExec, assert_strong_count_converges_to_zero};
+    use crate::test::{TestMemoryExec, assert_is_pending};
 
     use InputOrderMode::{Linear, PartiallySorted, Sorted};
+    use arrow::array::{AsArray, Int64Array, RecordBatch};
     use arrow::compute::SortOptions;
+    use arrow::datatypes::Int64Type;
     use arrow_schema::{DataType, Field};
     use datafusion_execution::TaskContext;
+    use datafusion_execution::runtime_env::RuntimeEnvBuilder;
     use datafusion_functions_aggregate::count::count_udaf;
 
     use futures::FutureExt;
@@ -1286,4 +1289,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_window_agg_spill() -> Result<()> {
+        // 10 batches of 1000 rows, sorted by `a`, which has 4 distinct values
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let batches = (0..10)
+            .map(|i| {
+                let a = Int64Array::from_iter_values(
+                    (i * 1000..(i + 1) * 1000).map(|v| v / 2500),
+                );
+                RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(a)])
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let ordering = LexOrdering::new([sort_expr("a", &schema)]).unwrap();
+        let input = TestMemoryExec::try_new(&[batches], Arc::clone(&schema), None)?
+            .try_with_sort_information(vec![ordering])?;
+        let input = Arc::new(TestMemoryExec::update_cache(&Arc::new(input)));
+
+        let window_agg_exec = Arc::new(WindowAggExec::try_new(
+            vec![create_window_expr(
+                &WindowFunctionDefinition::AggregateUDF(count_udaf()),
+                "count".to_owned(),
+                &[col("a", &schema)?],
+                &[col("a", &schema)?],
+                &[],
+                Arc::new(WindowFrame::new(None)),
+                Arc::clone(&schema),
+                false,
+                false,
+                None,
+            )?],
+            input,
+            false,
+        )?);
+
+        // The input does not fit in memory, but every window partition does
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_limit(50_000, 1.0)
+            .build_arc()?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let result = collect(
+            Arc::clone(&window_agg_exec) as Arc<dyn ExecutionPlan>,
+            Arc::clone(&task_ctx),
+        )
+        .await?;
+
+        let counts = result
+            .iter()
+            .flat_map(|batch| {
+                batch
+                    .column(1)
+                    .as_primitive::<Int64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![2500; 10000]);
+
+        let metrics = window_agg_exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+        assert_eq!(metrics.output_rows().unwrap(), 10000);
+        assert_eq!(task_ctx.runtime_env().memory_pool.reserved(), 0);
+
+        Ok(())
+    }
 }
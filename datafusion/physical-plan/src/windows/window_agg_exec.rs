@@ -24,7 +24,11 @@ use std::task::{Context, Poll};
 
 use super::utils::create_schema;
 use crate::execution_plan::EmissionType;
-use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet, SpillMetrics,
+};
+use crate::spill::get_record_batch_memory_size;
+use crate::spill::spill_manager::SpillManager;
 use crate::windows::{
     calc_requirements, get_ordered_partition_by_indices, get_partition_by_sort_exprs,
     window_equivalence_properties,
@@ -36,19 +40,24 @@ use crate::{
 };
 
 use arrow::array::ArrayRef;
-use arrow::compute::{concat, concat_batches};
+use arrow::compute::{SortColumn, concat, concat_batches};
 use arrow::datatypes::SchemaRef;
 use arrow::error::ArrowError;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::stats::Precision;
-use datafusion_common::utils::{evaluate_partition_ranges, transpose};
-use datafusion_common::{Result, assert_eq_or_internal_err};
+use datafusion_common::utils::{evaluate_partition_ranges, get_row_at_idx, transpose};
+use datafusion_common::{
+    DataFusionError, Result, ScalarValue, assert_eq_or_internal_err,
+};
 use datafusion_execution::TaskContext;
+use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_physical_expr_common::sort_expr::{
     OrderingRequirements, PhysicalSortExpr,
 };
 
-use futures::{Stream, StreamExt, ready};
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, TryStreamExt, ready};
 
 /// Window execution plan
 #[derive(Debug, Clone)]
@@ -256,7 +265,17 @@ impl ExecutionPlan for WindowAggExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
-        let input = self.input.execute(partition, context)?;
+        let input = self.input.execute(partition, Arc::clone(&context))?;
+        let can_spill = context.runtime_env().disk_manager.tmp_files_enabled();
+        let reservation = MemoryConsumer::new(format!("WindowAggStream[{partition}]"))
+            .with_can_spill(can_spill)
+            .register(context.memory_pool());
+        let spill_manager = SpillManager::new(
+            context.runtime_env(),
+            SpillMetrics::new(&self.metrics, partition),
+            self.input.schema(),
+        )
+        .with_compression_type(context.session_config().spill_compression());
         let stream = Box::pin(WindowAggStream::new(
             Arc::clone(&self.schema),
             self.window_expr.clone(),
@@ -264,6 +283,9 @@ impl ExecutionPlan for WindowAggExec {
             BaselineMetrics::new(&self.metrics, partition),
             self.partition_by_sort_keys()?,
             self.ordered_partition_by_indices.clone(),
+            reservation,
+            spill_manager,
+            can_spill,
         )?);
         Ok(stream)
     }
@@ -307,19 +329,38 @@ fn compute_window_aggregates(
 }
 
 /// stream for window aggregation plan
+///
+/// The input batches are buffered until the input is exhausted. If they do
+/// not fit in memory, the buffered batches are spilled to disk. After the
+/// input is exhausted, the spilled batches are read back in input order and
+/// the window functions are evaluated one window partition at a time, so
+/// only the largest window partition has to fit in memory.
 pub struct WindowAggStream {
     schema: SchemaRef,
     input: SendableRecordBatchStream,
+    /// Buffered input batches. While reading spilled batches back, the
+    /// batches of the window partition that has not ended yet.
     batches: Vec<RecordBatch>,
     finished: bool,
     window_expr: Vec<Arc<dyn WindowExpr>>,
     partition_by_sort_keys: Vec<PhysicalSortExpr>,
     baseline_metrics: BaselineMetrics,
     ordered_partition_by_indices: Vec<usize>,
+    /// Memory used by `batches`
+    reservation: MemoryReservation,
+    /// Spills `batches` to disk when they do not fit in memory
+    spill_manager: SpillManager,
+    /// Whether `batches` can be spilled to disk
+    can_spill: bool,
+    /// Spill files holding the input batches, in input order
+    spills: Vec<RefCountedTempFile>,
+    /// Reads `spills` back after the input is exhausted
+    spilled_input: Option<BoxStream<'static, Result<RecordBatch>>>,
 }
 
 impl WindowAggStream {
     /// Create a new WindowAggStream
+    #[expect(clippy::too_many_arguments)]
     pub fn new(
         schema: SchemaRef,
         window_expr: Vec<Arc<dyn WindowExpr>>,
@@ -327,6 +368,9 @@ impl WindowAggStream {
         baseline_metrics: BaselineMetrics,
         partition_by_sort_keys: Vec<PhysicalSortExpr>,
         ordered_partition_by_indices: Vec<usize>,
+        reservation: MemoryReservation,
+        spill_manager: SpillManager,
+        can_spill: bool,
     ) -> Result<Self> {
         // In WindowAggExec all partition by columns should be ordered.
         assert_eq_or_internal_err!(
@@ -343,23 +387,42 @@ impl WindowAggStream {
             baseline_metrics,
             partition_by_sort_keys,
             ordered_partition_by_indices,
+            reservation,
+            spill_manager,
+            can_spill,
+            spills: vec![],
+            spilled_input: None,
         })
     }
 
-    fn compute_aggregates(&self) -> Result<Option<RecordBatch>> {
+    /// Evaluates the PARTITION BY columns on `batch`, in input order
+    fn partition_by_columns(&self, batch: &RecordBatch) -> Result<Vec<SortColumn>> {
+        self.ordered_partition_by_indices
+            .iter()
+            .map(|idx| self.partition_by_sort_keys[*idx].evaluate_to_sort_column(batch))
+            .collect()
+    }
+
+    /// Returns the PARTITION BY values of the row at `idx` of `batch`
+    fn partition_key(&self, batch: &RecordBatch, idx: usize) -> Result<Vec<ScalarValue>> {
+        let columns = self
+            .partition_by_columns(&batch.slice(idx, 1))?
+            .into_iter()
+            .map(|column| column.values)
+            .collect::<Vec<_>>();
+        get_row_at_idx(&columns, 0)
+    }
+
+    fn compute_aggregates(&self, batches: &[RecordBatch]) -> Result<Option<RecordBatch>> {
         // record compute time on drop
         let _timer = self.baseline_metrics.elapsed_compute().timer();
 
-        let batch = concat_batches(&self.input.schema(), &self.batches)?;
+        let batch = concat_batches(&self.input.schema(), batches)?;
         if batch.num_rows() == 0 {
             return Ok(None);
         }
 
-        let partition_by_sort_keys = self
-            .ordered_partition_by_indices
-            .iter()
-            .map(|idx| self.partition_by_sort_keys[*idx].evaluate_to_sort_column(&batch))
-            .collect::<Result<Vec<_>>>()?;
+        let partition_by_sort_keys = self.partition_by_columns(&batch)?;
         let partition_points =
             evaluate_partition_ranges(batch.num_rows(), &partition_by_sort_keys)?;
 
@@ -390,6 +453,103 @@ impl WindowAggStream {
             batch_columns,
         )?))
     }
+
+    /// Buffers an input batch, spilling the buffered batches to disk first if
+    /// the batch does not fit in memory
+    fn buffer_batch(&mut self, batch: RecordBatch) -> Result<()> {
+        let size = get_record_batch_memory_size(&batch);
+        if let Err(e) = self.reservation.try_grow(size) {
+            if !self.can_spill || self.batches.is_empty() {
+                return Err(Self::err_with_oom_context(e));
+            }
+            self.spill()?;
+            self.reservation
+                .try_grow(size)
+                .map_err(Self::err_with_oom_context)?;
+        }
+        self.batches.push(batch);
+        Ok(())
+    }
+
+    /// Spills the buffered batches to disk and frees their memory
+    fn spill(&mut self) -> Result<()> {
+        let batches = std::mem::take(&mut self.batches);
+        if let Some(spill_file) = self
+            .spill_manager
+            .spill_record_batch_and_finish(&batches, "WindowAggExec spill")?
+        {
+            self.spills.push(spill_file);
+        }
+        self.reservation.free();
+        Ok(())
+    }
+
+    /// Spills the remaining buffered batches and starts reading all spill
+    /// files back in input order
+    fn read_spills(&mut self) -> Result<()> {
+        self.spill()?;
+        let spill_manager = self.spill_manager.clone();
+        let spilled_input =
+            futures::stream::iter(std::mem::take(&mut self.spills).into_iter().map(
+                move |spill_file| spill_manager.read_spill_as_stream(spill_file, None),
+            ))
+            .try_flatten()
+            .boxed();
+        self.spilled_input = Some(spilled_input);
+        Ok(())
+    }
+
+    /// Buffers a batch read back from disk, and evaluates the window
+    /// partitions that end before its last window partition starts
+    fn update_partition_batches(
+        &mut self,
+        batch: &RecordBatch,
+    ) -> Result<Option<RecordBatch>> {
+        if batch.num_rows() == 0 {
+            return Ok(None);
+        }
+        let partition_by = self.partition_by_columns(batch)?;
+        let partition_points =
+            evaluate_partition_ranges(batch.num_rows(), &partition_by)?;
+        // The last window partition of the batch may continue in the next batch
+        let split = partition_points.last().map_or(0, |range| range.start);
+        let partition_ended = match self.batches.last() {
+            Some(last) if split == 0 => {
+                self.partition_key(last, last.num_rows() - 1)?
+                    != self.partition_key(batch, 0)?
+            }
+            _ => split > 0,
+        };
+
+        let mut result = None;
+        if partition_ended {
+            let mut batches = std::mem::take(&mut self.batches);
+            batches.push(batch.slice(0, split));
+            result = self.compute_aggregates(&batches)?;
+            self.reservation.free();
+        }
+
+        let batch = batch.slice(split, batch.num_rows() - split);
+        self.reservation
+            .try_grow(get_record_batch_memory_size(&batch))
+            .map_err(Self::err_with_oom_context)?;
+        self.batches.push(batch);
+        Ok(result)
+    }
+
+    /// Wraps the error with a context message explaining what has to fit in
+    /// memory. This is meant to be used with DataFusionError::ResourcesExhausted only.
+    fn err_with_oom_context(e: DataFusionError) -> DataFusionError {
+        match e {
+            DataFusionError::ResourcesExhausted(_) => e.context(
+                "Not enough memory to buffer the input of WindowAggExec. \
+                    Every window partition has to fit in memory, and spilling \
+                    to disk requires a DiskManager that can create temporary files",
+            ),
+            // This is not an OOM error, so just return it as is.
+            _ => e,
+        }
+    }
 }
 
 impl Stream for WindowAggStream {
@@ -413,16 +573,23 @@ impl WindowAggStream {
         if self.finished {
             return Poll::Ready(None);
         }
+        if self.spilled_input.is_some() {
+            return self.poll_spilled_input(cx);
+        }
 
         loop {
             return Poll::Ready(Some(match ready!(self.input.poll_next_unpin(cx)) {
                 Some(Ok(batch)) => {
-                    self.batches.push(batch);
+                    self.buffer_batch(batch)?;
                     continue;
                 }
                 Some(Err(e)) => Err(e),
+                None if !self.spills.is_empty() => {
+                    self.read_spills()?;
+                    return self.poll_spilled_input(cx);
+                }
                 None => {
-                    let Some(result) = self.compute_aggregates()? else {
+                    let Some(result) = self.compute_aggregates(&self.batches)? else {
                         return Poll::Ready(None);
                     };
                     self.finished = true;
@@ -434,6 +601,35 @@ impl WindowAggStream {
             }));
         }
     }
+
+    /// Evaluates the window partitions of the input batches read back from
+    /// disk, one window partition at a time
+    fn poll_spilled_input(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        loop {
+            let Some(spilled_input) = self.spilled_input.as_mut() else {
+                return Poll::Ready(None);
+            };
+            match ready!(spilled_input.poll_next_unpin(cx)) {
+                Some(Ok(batch)) => {
+                    if let Some(result) = self.update_partition_batches(&batch)? {
+                        return Poll::Ready(Some(Ok(result)));
+                    }
+                }
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => {
+                    self.finished = true;
+                    self.spilled_input = None;
+                    let batches = std::mem::take(&mut self.batches);
+                    let result = self.compute_aggregates(&batches).transpose();
+                    self.reservation.free();
+                    return Poll::Ready(result);
+                }
+            }
+        }
+    }
 }
 
 impl RecordBatchStream for WindowAggStream {
@@ -24,6 +24,8 @@ use crate::coop::cooperative;
 use crate::execution_plan::{Boundedness, EmissionType, SchedulingType};
 use crate::memory::MemoryStream;
 use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use crate::spill::spill_manager::SpillManager;
+use crate::stream::RecordBatchStreamAdapter;
 use crate::{
     DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties,
     SendableRecordBatchStream, Statistics,
@@ -33,14 +35,18 @@ use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::{Result, assert_eq_or_internal_err, internal_datafusion_err};
 use datafusion_execution::TaskContext;
+use datafusion_execution::disk_manager::RefCountedTempFile;
 use datafusion_execution::memory_pool::MemoryReservation;
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning};
+use futures::StreamExt;
 
-/// A vector of record batches with a memory reservation.
+/// A vector of record batches with a memory reservation, followed by the
+/// batches which did not fit in memory and were spilled to disk.
 #[derive(Debug)]
 pub(super) struct ReservedBatches {
     batches: Vec<RecordBatch>,
     reservation: MemoryReservation,
+    spill_file: Option<(Arc<SpillManager>, RefCountedTempFile)>,
 }
 
 impl ReservedBatches {
@@ -48,8 +54,18 @@ impl ReservedBatches {
         ReservedBatches {
             batches,
             reservation,
+            spill_file: None,
         }
     }
+
+    /// Sets the spill file holding the batches following the in-memory ones
+    pub(super) fn with_spill_file(
+        mut self,
+        spill_file: Option<(Arc<SpillManager>, RefCountedTempFile)>,
+    ) -> Self {
+        self.spill_file = spill_file;
+        self
+    }
 }
 
 /// The name is from PostgreSQL's terminology.
@@ -211,6 +227,7 @@ impl ExecutionPlan for WorkTableExec {
         let ReservedBatches {
             mut batches,
             reservation,
+            spill_file,
         } = self.work_table.take()?;
         if let Some(projection) = &self.projection {
             // We apply the projection
@@ -224,7 +241,23 @@ impl ExecutionPlan for WorkTableExec {
 
         let stream = MemoryStream::try_new(batches, Arc::clone(&self.schema), None)?
             .with_reservation(reservation);
-        Ok(Box::pin(cooperative(stream)))
+        let Some((spill_manager, spill_file)) = spill_file else {
+            return Ok(Box::pin(cooperative(stream)));
+        };
+
+        // The spilled batches follow the ones in memory
+        let projection = self.projection.clone();
+        let spilled =
+            spill_manager
+                .read_spill_as_stream(spill_file, None)?
+                .map(move |batch| match &projection {
+                    Some(projection) => Ok(batch?.project(projection)?),
+                    None => batch,
+                });
+        Ok(Box::pin(cooperative(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            stream.chain(spilled),
+        ))))
     }
 
     fn metrics(&self) -> Option<MetricsSet> {
@@ -35,8 +35,8 @@ use datafusion_common::{
     DataFusionError, JoinSide, ScalarValue, Statistics, TableReference,
     arrow_datafusion_err,
     config::{
-        CsvOptions, JsonOptions, ParquetColumnOptions, ParquetOptions,
-        TableParquetOptions,
+        ArrowIpcFormat, ArrowOptions, AvroCodec, AvroOptions, CsvOptions, JsonFormatType,
+        JsonOptions, ParquetColumnOptions, ParquetOptions, TableParquetOptions,
     },
     file_options::{csv_writer::CsvWriterOptions, json_writer::JsonWriterOptions},
     parsers::CompressionTypeVariant,
@@ -870,6 +870,37 @@ impl From<CompressionTypeVariant> for protobuf::CompressionTypeVariant {
     }
 }
 
+impl From<protobuf::JsonFormatType> for JsonFormatType {
+    fn from(value: protobuf::JsonFormatType) -> Self {
+        match value {
+            protobuf::JsonFormatType::Ndjson => Self::NdJson,
+            protobuf::JsonFormatType::Array => Self::Array,
+        }
+    }
+}
+
+impl From<protobuf::ArrowIpcFormat> for ArrowIpcFormat {
+    fn from(value: protobuf::ArrowIpcFormat) -> Self {
+        match value {
+            protobuf::ArrowIpcFormat::IpcFile => Self::File,
+            protobuf::ArrowIpcFormat::IpcStream => Self::Stream,
+        }
+    }
+}
+
+impl From<protobuf::AvroCodec> for AvroCodec {
+    fn from(value: protobuf::AvroCodec) -> Self {
+        match value {
+            protobuf::AvroCodec::Null => Self::Null,
+            protobuf::AvroCodec::Deflate => Self::Deflate,
+            protobuf::AvroCodec::Snappy => Self::Snappy,
+            protobuf::AvroCodec::Zstandard => Self::Zstandard,
+            protobuf::AvroCodec::Bzip2 => Self::Bzip2,
+            protobuf::AvroCodec::Xz => Self::Xz,
+        }
+    }
+}
+
 impl TryFrom<&protobuf::CsvWriterOptions> for CsvWriterOptions {
     type Error = DataFusionError;
 
@@ -889,7 +920,7 @@ impl TryFrom<&protobuf::JsonWriterOptions> for JsonWriterOptions {
         opts: &protobuf::JsonWriterOptions,
     ) -> datafusion_common::Result<Self, Self::Error> {
         let compression: CompressionTypeVariant = opts.compression().into();
-        Ok(JsonWriterOptions::new(compression))
+        Ok(JsonWriterOptions::new(compression).with_format(opts.format().into()))
     }
 }
 
@@ -988,6 +1019,8 @@ impl TryFrom<&protobuf::ParquetOptions> for ParquetOptions {
                 })
                 .unwrap_or(None),
             bloom_filter_on_read: value.bloom_filter_on_read,
+            dictionary_pruning_on_read: value.dictionary_pruning_on_read,
+            match_by_field_id: value.match_by_field_id,
             bloom_filter_on_write: value.bloom_filter_on_write,
             bloom_filter_fpp: value.clone()
                 .bloom_filter_fpp_opt
@@ -1100,6 +1133,31 @@ impl TryFrom<&protobuf::JsonOptions> for JsonOptions {
             compression: compression.into(),
             compression_level: proto_opts.compression_level,
             schema_infer_max_rec: proto_opts.schema_infer_max_rec.map(|h| h as usize),
+            format: proto_opts.format().into(),
+        })
+    }
+}
+
+impl TryFrom<&protobuf::ArrowOptions> for ArrowOptions {
+    type Error = DataFusionError;
+
+    fn try_from(
+        proto_opts: &protobuf::ArrowOptions,
+    ) -> datafusion_common::Result<Self, Self::Error> {
+        Ok(ArrowOptions {
+            format: proto_opts.format().into(),
+        })
+    }
+}
+
+impl TryFrom<&protobuf::AvroOptions> for AvroOptions {
+    type Error = DataFusionError;
+
+    fn try_from(
+        proto_opts: &protobuf::AvroOptions,
+    ) -> datafusion_common::Result<Self, Self::Error> {
+        Ok(AvroOptions {
+            codec: proto_opts.codec().into(),
         })
     }
 }
@@ -39,14 +39,20 @@ pub struct ParquetFormat {
     pub options: ::core::option::Option<TableParquetOptions>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct AvroFormat {}
+pub struct AvroFormat {
+    #[prost(message, optional, tag = "1")]
+    pub options: ::core::option::Option<AvroOptions>,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct NdJsonFormat {
     #[prost(message, optional, tag = "1")]
     pub options: ::core::option::Option<JsonOptions>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct ArrowFormat {}
+pub struct ArrowFormat {
+    #[prost(message, optional, tag = "1")]
+    pub options: ::core::option::Option<ArrowOptions>,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PrimaryKeyConstraint {
     #[prost(uint64, repeated, tag = "1")]
@@ -78,9 +84,17 @@ pub struct Constraints {
     pub constraints: ::prost::alloc::vec::Vec<Constraint>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct AvroOptions {}
+pub struct AvroOptions {
+    /// Compression codec of written files
+    #[prost(enumeration = "AvroCodec", tag = "1")]
+    pub codec: i32,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct ArrowOptions {}
+pub struct ArrowOptions {
+    /// IPC file or streaming format
+    #[prost(enumeration = "ArrowIpcFormat", tag = "1")]
+    pub format: i32,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Schema {
     #[prost(message, repeated, tag = "1")]
@@ -555,6 +569,8 @@ pub struct EmptyMessage {}
 pub struct JsonWriterOptions {
     #[prost(enumeration = "CompressionTypeVariant", tag = "1")]
     pub compression: i32,
+    #[prost(enumeration = "JsonFormatType", tag = "2")]
+    pub format: i32,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CsvWriterOptions {
@@ -665,6 +681,9 @@ pub struct JsonOptions {
     /// Optional compression level
     #[prost(uint32, optional, tag = "3")]
     pub compression_level: ::core::option::Option<u32>,
+    /// Newline delimited or array layout
+    #[prost(enumeration = "JsonFormatType", tag = "4")]
+    pub format: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TableParquetOptions {
@@ -796,6 +815,12 @@ pub struct ParquetOptions {
     #[prost(bool, tag = "26")]
     pub bloom_filter_on_read: bool,
     /// default = false
+    #[prost(bool, tag = "35")]
+    pub dictionary_pruning_on_read: bool,
+    /// default = false
+    #[prost(bool, tag = "36")]
+    pub match_by_field_id: bool,
+    /// default = false
     #[prost(bool, tag = "27")]
     pub bloom_filter_on_write: bool,
     /// default = false
@@ -1165,6 +1190,96 @@ impl CompressionTypeVariant {
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum JsonFormatType {
+    Ndjson = 0,
+    Array = 1,
+}
+impl JsonFormatType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Ndjson => "NDJSON",
+            Self::Array => "ARRAY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NDJSON" => Some(Self::Ndjson),
+            "ARRAY" => Some(Self::Array),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ArrowIpcFormat {
+    IpcFile = 0,
+    IpcStream = 1,
+}
+impl ArrowIpcFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::IpcFile => "IPC_FILE",
+            Self::IpcStream => "IPC_STREAM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "IPC_FILE" => Some(Self::IpcFile),
+            "IPC_STREAM" => Some(Self::IpcStream),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AvroCodec {
+    Null = 0,
+    Deflate = 1,
+    Snappy = 2,
+    Zstandard = 3,
+    Bzip2 = 4,
+    Xz = 5,
+}
+impl AvroCodec {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Null => "AVRO_CODEC_NULL",
+            Self::Deflate => "AVRO_CODEC_DEFLATE",
+            Self::Snappy => "AVRO_CODEC_SNAPPY",
+            Self::Zstandard => "AVRO_CODEC_ZSTANDARD",
+            Self::Bzip2 => "AVRO_CODEC_BZIP2",
+            Self::Xz => "AVRO_CODEC_XZ",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AVRO_CODEC_NULL" => Some(Self::Null),
+            "AVRO_CODEC_DEFLATE" => Some(Self::Deflate),
+            "AVRO_CODEC_SNAPPY" => Some(Self::Snappy),
+            "AVRO_CODEC_ZSTANDARD" => Some(Self::Zstandard),
+            "AVRO_CODEC_BZIP2" => Some(Self::Bzip2),
+            "AVRO_CODEC_XZ" => Some(Self::Xz),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum JoinSide {
     LeftSide = 0,
     RightSide = 1,
@@ -35,8 +35,8 @@ use datafusion_common::{
     Column, ColumnStatistics, Constraint, Constraints, DFSchema, DFSchemaRef,
     DataFusionError, JoinSide, ScalarValue, Statistics,
     config::{
-        CsvOptions, JsonOptions, ParquetColumnOptions, ParquetOptions,
-        TableParquetOptions,
+        ArrowIpcFormat, ArrowOptions, AvroCodec, AvroOptions, CsvOptions, JsonFormatType,
+        JsonOptions, ParquetColumnOptions, ParquetOptions, TableParquetOptions,
     },
     file_options::{csv_writer::CsvWriterOptions, json_writer::JsonWriterOptions},
     parsers::CompressionTypeVariant,
@@ -822,6 +822,37 @@ impl From<&CompressionTypeVariant> for protobuf::CompressionTypeVariant {
     }
 }
 
+impl From<&JsonFormatType> for protobuf::JsonFormatType {
+    fn from(value: &JsonFormatType) -> Self {
+        match value {
+            JsonFormatType::NdJson => Self::Ndjson,
+            JsonFormatType::Array => Self::Array,
+        }
+    }
+}
+
+impl From<&ArrowIpcFormat> for protobuf::ArrowIpcFormat {
+    fn from(value: &ArrowIpcFormat) -> Self {
+        match value {
+            ArrowIpcFormat::File => Self::IpcFile,
+            ArrowIpcFormat::Stream => Self::IpcStream,
+        }
+    }
+}
+
+impl From<&AvroCodec> for protobuf::AvroCodec {
+    fn from(value: &AvroCodec) -> Self {
+        match value {
+            AvroCodec::Null => Self::Null,
+            AvroCodec::Deflate => Self::Deflate,
+            AvroCodec::Snappy => Self::Snappy,
+            AvroCodec::Zstandard => Self::Zstandard,
+            AvroCodec::Bzip2 => Self::Bzip2,
+            AvroCodec::Xz => Self::Xz,
+        }
+    }
+}
+
 impl TryFrom<&CsvWriterOptions> for protobuf::CsvWriterOptions {
     type Error = DataFusionError;
 
@@ -840,8 +871,10 @@ impl TryFrom<&JsonWriterOptions> for protobuf::JsonWriterOptions {
         opts: &JsonWriterOptions,
     ) -> datafusion_common::Result<Self, Self::Error> {
         let compression: protobuf::CompressionTypeVariant = opts.compression.into();
+        let format: protobuf::JsonFormatType = (&opts.format).into();
         Ok(protobuf::JsonWriterOptions {
             compression: compression.into(),
+            format: format.into(),
         })
     }
 }
@@ -872,6 +905,8 @@ impl TryFrom<&ParquetOptions> for protobuf::ParquetOptions {
             data_page_row_count_limit: value.data_page_row_count_limit as u64,
             encoding_opt: value.encoding.clone().map(protobuf::parquet_options::EncodingOpt::Encoding),
             bloom_filter_on_read: value.bloom_filter_on_read,
+            dictionary_pruning_on_read: value.dictionary_pruning_on_read,
+            match_by_field_id: value.match_by_field_id,
             bloom_filter_on_write: value.bloom_filter_on_write,
             bloom_filter_fpp_opt: value.bloom_filter_fpp.map(protobuf::parquet_options::BloomFilterFppOpt::BloomFilterFpp),
             bloom_filter_ndv_opt: value.bloom_filter_ndv.map(protobuf::parquet_options::BloomFilterNdvOpt::BloomFilterNdv),
@@ -986,10 +1021,34 @@ impl TryFrom<&JsonOptions> for protobuf::JsonOptions {
 
     fn try_from(opts: &JsonOptions) -> datafusion_common::Result<Self, Self::Error> {
         let compression: protobuf::CompressionTypeVariant = opts.compression.into();
+        let format: protobuf::JsonFormatType = (&opts.format).into();
         Ok(protobuf::JsonOptions {
             compression: compression.into(),
             schema_infer_max_rec: opts.schema_infer_max_rec.map(|h| h as u64),
             compression_level: opts.compression_level,
+            format: format.into(),
+        })
+    }
+}
+
+impl TryFrom<&ArrowOptions> for protobuf::ArrowOptions {
+    type Error = DataFusionError;
+
+    fn try_from(opts: &ArrowOptions) -> datafusion_common::Result<Self, Self::Error> {
+        let format: protobuf::ArrowIpcFormat = (&opts.format).into();
+        Ok(protobuf::ArrowOptions {
+            format: format.into(),
+        })
+    }
+}
+
+impl TryFrom<&AvroOptions> for protobuf::AvroOptions {
+    type Error = DataFusionError;
+
+    fn try_from(opts: &AvroOptions) -> datafusion_common::Result<Self, Self::Error> {
+        let codec: protobuf::AvroCodec = (&opts.codec).into();
+        Ok(protobuf::AvroOptions {
+            codec: codec.into(),
         })
     }
 }
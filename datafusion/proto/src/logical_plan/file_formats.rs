@@ -17,14 +17,23 @@
 
 use std::sync::Arc;
 
-use crate::protobuf::{CsvOptions as CsvOptionsProto, JsonOptions as JsonOptionsProto};
-use datafusion_common::config::{CsvOptions, JsonOptions};
+#[cfg(feature = "avro")]
+use crate::protobuf::AvroOptions as AvroOptionsProto;
+use crate::protobuf::{
+    ArrowOptions as ArrowOptionsProto, CsvOptions as CsvOptionsProto,
+    JsonOptions as JsonOptionsProto,
+};
+#[cfg(feature = "avro")]
+use datafusion_common::config::AvroOptions;
+use datafusion_common::config::{ArrowOptions, CsvOptions, JsonFormatType, JsonOptions};
 use datafusion_common::{
     TableReference, exec_datafusion_err, exec_err, not_impl_err,
     parsers::CompressionTypeVariant,
 };
 use datafusion_datasource::file_format::FileFormatFactory;
 use datafusion_datasource_arrow::file_format::ArrowFormatFactory;
+#[cfg(feature = "avro")]
+use datafusion_datasource_avro::file_format::AvroFormatFactory;
 use datafusion_datasource_csv::file_format::CsvFormatFactory;
 use datafusion_datasource_json::file_format::JsonFormatFactory;
 use datafusion_execution::TaskContext;
@@ -241,6 +250,7 @@ impl JsonOptionsProto {
                 compression: options.compression as i32,
                 schema_infer_max_rec: options.schema_infer_max_rec.map(|v| v as u64),
                 compression_level: options.compression_level,
+                format: options.format as i32,
             }
         } else {
             JsonOptionsProto::default()
@@ -260,6 +270,10 @@ impl From<&JsonOptionsProto> for JsonOptions {
             },
             schema_infer_max_rec: proto.schema_infer_max_rec.map(|v| v as usize),
             compression_level: proto.compression_level,
+            format: match proto.format {
+                1 => JsonFormatType::Array,
+                _ => JsonFormatType::NdJson,
+            },
         }
     }
 }
@@ -405,6 +419,8 @@ mod parquet {
                     parquet_options::EncodingOpt::Encoding(encoding)
                 }),
                 bloom_filter_on_read: global_options.global.bloom_filter_on_read,
+                dictionary_pruning_on_read: global_options.global.dictionary_pruning_on_read,
+                match_by_field_id: global_options.global.match_by_field_id,
                 bloom_filter_on_write: global_options.global.bloom_filter_on_write,
                 bloom_filter_fpp_opt: global_options.global.bloom_filter_fpp.map(|fpp| {
                     parquet_options::BloomFilterFppOpt::BloomFilterFpp(fpp)
@@ -504,6 +520,8 @@ mod parquet {
                 parquet_options::EncodingOpt::Encoding(encoding) => encoding.clone(),
             }),
             bloom_filter_on_read: proto.bloom_filter_on_read,
+            dictionary_pruning_on_read: proto.dictionary_pruning_on_read,
+            match_by_field_id: proto.match_by_field_id,
             bloom_filter_on_write: proto.bloom_filter_on_write,
             bloom_filter_fpp: proto.bloom_filter_fpp_opt.as_ref().map(|opt| match opt {
                 parquet_options::BloomFilterFppOpt::BloomFilterFpp(fpp) => *fpp,
@@ -719,17 +737,33 @@ impl LogicalExtensionCodec for ArrowLogicalExtensionCodec {
 
     fn try_decode_file_format(
         &self,
-        __buf: &[u8],
-        __ctx: &TaskContext,
+        buf: &[u8],
+        _ctx: &TaskContext,
     ) -> datafusion_common::Result<Arc<dyn FileFormatFactory>> {
-        Ok(Arc::new(ArrowFormatFactory::new()))
+        let proto = ArrowOptionsProto::decode(buf).map_err(|e| {
+            exec_datafusion_err!("Failed to decode ArrowOptionsProto: {e:?}")
+        })?;
+        let options: ArrowOptions = (&proto).try_into()?;
+        Ok(Arc::new(ArrowFormatFactory::new_with_options(options)))
     }
 
     fn try_encode_file_format(
         &self,
-        __buf: &mut Vec<u8>,
-        __node: Arc<dyn FileFormatFactory>,
+        buf: &mut Vec<u8>,
+        node: Arc<dyn FileFormatFactory>,
     ) -> datafusion_common::Result<()> {
+        let options = if let Some(arrow_factory) =
+            node.as_any().downcast_ref::<ArrowFormatFactory>()
+        {
+            arrow_factory.options.clone().unwrap_or_default()
+        } else {
+            return exec_err!("Unsupported FileFormatFactory type");
+        };
+
+        ArrowOptionsProto::try_from(&options)?
+            .encode(buf)
+            .map_err(|e| exec_datafusion_err!("Failed to encode ArrowOptions: {e:?}"))?;
+
         Ok(())
     }
 }
@@ -775,19 +809,37 @@ impl LogicalExtensionCodec for AvroLogicalExtensionCodec {
         not_impl_err!("Method not implemented")
     }
 
+    #[cfg(feature = "avro")]
     fn try_decode_file_format(
         &self,
-        __buf: &[u8],
-        __ctx: &TaskContext,
+        buf: &[u8],
+        _ctx: &TaskContext,
     ) -> datafusion_common::Result<Arc<dyn FileFormatFactory>> {
-        Ok(Arc::new(ArrowFormatFactory::new()))
+        let proto = AvroOptionsProto::decode(buf).map_err(|e| {
+            exec_datafusion_err!("Failed to decode AvroOptionsProto: {e:?}")
+        })?;
+        let options: AvroOptions = (&proto).try_into()?;
+        Ok(Arc::new(AvroFormatFactory::new_with_options(options)))
     }
 
+    #[cfg(feature = "avro")]
     fn try_encode_file_format(
         &self,
-        __buf: &mut Vec<u8>,
-        __node: Arc<dyn FileFormatFactory>,
+        buf: &mut Vec<u8>,
+        node: Arc<dyn FileFormatFactory>,
     ) -> datafusion_common::Result<()> {
+        let options = if let Some(avro_factory) =
+            node.as_any().downcast_ref::<AvroFormatFactory>()
+        {
+            avro_factory.options.clone().unwrap_or_default()
+        } else {
+            return exec_err!("Unsupported FileFormatFactory type");
+        };
+
+        AvroOptionsProto::try_from(&options)?
+            .encode(buf)
+            .map_err(|e| exec_datafusion_err!("Failed to encode AvroOptions: {e:?}"))?;
+
         Ok(())
     }
 }
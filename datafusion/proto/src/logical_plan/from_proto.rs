@@ -66,6 +66,8 @@ impl From<&protobuf::UnnestOptions> for UnnestOptions {
                     depth: r.depth as usize,
                 })
                 .collect::<Vec<_>>(),
+            preserve_empty: opts.preserve_empty,
+            ordinality: opts.ordinality,
         }
     }
 }
@@ -239,6 +241,7 @@ impl From<protobuf::dml_node::Type> for WriteOp {
             }
             protobuf::dml_node::Type::InsertReplace => WriteOp::Insert(InsertOp::Replace),
             protobuf::dml_node::Type::Ctas => WriteOp::Ctas,
+            protobuf::dml_node::Type::Merge => WriteOp::Merge,
         }
     }
 }
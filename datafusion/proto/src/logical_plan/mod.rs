@@ -422,10 +422,15 @@ impl AsLogicalPlan for LogicalPlanNode {
                             }
                             Arc::new(json)
                         }
-                        FileFormatType::Avro(..) => {
+                        #[cfg_attr(not(feature = "avro"), expect(unused_variables))]
+                        FileFormatType::Avro(protobuf::AvroFormat { options }) => {
                             #[cfg(feature = "avro")]
                             {
-                                Arc::new(AvroFormat)
+                                let mut avro = AvroFormat::default();
+                                if let Some(options) = options {
+                                    avro = avro.with_options(options.try_into()?)
+                                }
+                                Arc::new(avro)
                             }
                             #[cfg(not(feature = "avro"))]
                             {
@@ -434,8 +439,14 @@ impl AsLogicalPlan for LogicalPlanNode {
                                 );
                             }
                         }
-                        FileFormatType::Arrow(..) => {
-                            Arc::new(ArrowFormat)
+                        FileFormatType::Arrow(protobuf::ArrowFormat {
+                            options
+                        }) => {
+                            let mut arrow = ArrowFormat::default();
+                            if let Some(options) = options {
+                                arrow = arrow.with_options(options.try_into()?)
+                            }
+                            Arc::new(arrow)
                         }
                     };
 
@@ -1068,14 +1079,20 @@ impl AsLogicalPlan for LogicalPlanNode {
                         }
 
                         #[cfg(feature = "avro")]
-                        if any.is::<AvroFormat>() {
+                        if let Some(avro) = any.downcast_ref::<AvroFormat>() {
+                            let options = avro.options();
                             maybe_some_type =
-                                Some(FileFormatType::Avro(protobuf::AvroFormat {}))
+                                Some(FileFormatType::Avro(protobuf::AvroFormat {
+                                    options: Some(options.try_into()?),
+                                }))
                         }
 
-                        if any.is::<ArrowFormat>() {
+                        if let Some(arrow) = any.downcast_ref::<ArrowFormat>() {
+                            let options = arrow.options();
                             maybe_some_type =
-                                Some(FileFormatType::Arrow(protobuf::ArrowFormat {}))
+                                Some(FileFormatType::Arrow(protobuf::ArrowFormat {
+                                    options: Some(options.try_into()?),
+                                }))
                         }
 
                         if let Some(file_format_type) = maybe_some_type {
@@ -1733,6 +1750,22 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlan::Ddl(DdlStatement::DropFunction(_)) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for DropFunction",
             )),
+            LogicalPlan::Ddl(DdlStatement::AlterTable(_)) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for AlterTable",
+            )),
+            LogicalPlan::Ddl(DdlStatement::TruncateTable(_)) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for TruncateTable",
+            )),
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(_)) => {
+                Err(proto_error(
+                    "LogicalPlan serde is not yet implemented for CreateMaterializedView",
+                ))
+            }
+            LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(_)) => {
+                Err(proto_error(
+                    "LogicalPlan serde is not yet implemented for RefreshMaterializedView",
+                ))
+            }
             LogicalPlan::Statement(_) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for Statement",
             )),
@@ -1786,6 +1819,15 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlan::DescribeTable(_) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for DescribeTable",
             )),
+            LogicalPlan::Sample(_) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for Sample",
+            )),
+            LogicalPlan::AsOfJoin(_) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for AsOfJoin",
+            )),
+            LogicalPlan::MatchRecognize(_) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for MatchRecognize",
+            )),
             LogicalPlan::RecursiveQuery(recursive) => {
                 let static_term = LogicalPlanNode::try_from_logical_plan(
                     recursive.static_term.as_ref(),
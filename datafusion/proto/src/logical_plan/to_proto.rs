@@ -63,6 +63,8 @@ impl From<&UnnestOptions> for protobuf::UnnestOptions {
                     depth: r.depth as u32,
                 })
                 .collect(),
+            ordinality: opts.ordinality,
+            preserve_empty: opts.preserve_empty,
         }
     }
 }
@@ -728,6 +730,7 @@ impl From<&WriteOp> for protobuf::dml_node::Type {
             WriteOp::Delete => protobuf::dml_node::Type::Delete,
             WriteOp::Update => protobuf::dml_node::Type::Update,
             WriteOp::Ctas => protobuf::dml_node::Type::Ctas,
+            WriteOp::Merge => protobuf::dml_node::Type::Merge,
         }
     }
 }
@@ -459,6 +459,33 @@ pub fn parse_protobuf_hash_partitioning(
     }
 }
 
+pub fn parse_protobuf_range_partitioning(
+    partitioning: Option<&protobuf::PhysicalRangeRepartition>,
+    ctx: &TaskContext,
+    input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<Option<Partitioning>> {
+    match partitioning {
+        Some(range_part) => {
+            let sort_exprs = parse_physical_sort_exprs(
+                &range_part.sort_expr,
+                ctx,
+                input_schema,
+                codec,
+            )?;
+            let ordering = LexOrdering::new(sort_exprs).ok_or_else(|| {
+                internal_datafusion_err!("Range partitioning requires sort expressions")
+            })?;
+
+            Ok(Some(Partitioning::Range(
+                ordering,
+                range_part.partition_count.try_into().unwrap(),
+            )))
+        }
+        None => Ok(None),
+    }
+}
+
 pub fn parse_protobuf_partitioning(
     partitioning: Option<&protobuf::Partitioning>,
     ctx: &TaskContext,
@@ -485,6 +512,14 @@ pub fn parse_protobuf_partitioning(
                     *partition_count as usize,
                 )))
             }
+            Some(protobuf::partitioning::PartitionMethod::Range(range_repartition)) => {
+                parse_protobuf_range_partitioning(
+                    Some(range_repartition),
+                    ctx,
+                    input_schema,
+                    codec,
+                )
+            }
             None => Ok(None),
         },
         None => Ok(None),
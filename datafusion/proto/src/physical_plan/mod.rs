@@ -665,7 +665,7 @@ impl protobuf::PhysicalPlanNode {
             base_conf,
             ctx,
             extension_codec,
-            Arc::new(JsonSource::new(table_schema)),
+            Arc::new(JsonSource::new(table_schema).with_format(scan.format().into())),
         )?;
         Ok(DataSourceExec::from_data_source(scan_conf))
     }
@@ -2641,7 +2641,9 @@ impl protobuf::PhysicalPlanNode {
 
         if let Some(scan_conf) = data_source.as_any().downcast_ref::<FileScanConfig>() {
             let source = scan_conf.file_source();
-            if let Some(_json_source) = source.as_any().downcast_ref::<JsonSource>() {
+            if let Some(json_source) = source.as_any().downcast_ref::<JsonSource>() {
+                let format: datafusion_proto_common::JsonFormatType =
+                    (&json_source.format()).into();
                 return Ok(Some(protobuf::PhysicalPlanNode {
                     physical_plan_type: Some(PhysicalPlanType::JsonScan(
                         protobuf::JsonScanExecNode {
@@ -2649,6 +2651,7 @@ impl protobuf::PhysicalPlanNode {
                                 scan_conf,
                                 extension_codec,
                             )?),
+                            format: format.into(),
                         },
                     )),
                 }));
@@ -43,11 +43,11 @@ use std::sync::Arc;
 use std::vec;
 
 use datafusion::catalog::{TableProvider, TableProviderFactory};
-use datafusion::datasource::DefaultTableSource;
-use datafusion::datasource::file_format::arrow::ArrowFormatFactory;
+use datafusion::datasource::file_format::arrow::{ArrowFormat, ArrowFormatFactory};
 use datafusion::datasource::file_format::csv::CsvFormatFactory;
 use datafusion::datasource::file_format::parquet::ParquetFormatFactory;
 use datafusion::datasource::file_format::{DefaultFileType, format_as_file_type};
+use datafusion::datasource::{DefaultTableSource, source_as_provider};
 use datafusion::execution::FunctionRegistry;
 use datafusion::execution::session_state::SessionStateBuilder;
 use datafusion::functions_aggregate::count::count_udaf;
@@ -66,7 +66,9 @@ use datafusion::functions_window::rank::rank_udwf;
 use datafusion::physical_expr::PhysicalExpr;
 use datafusion::prelude::*;
 use datafusion::test_util::{TestTableFactory, TestTableProvider};
-use datafusion_common::config::TableOptions;
+use datafusion_common::config::{
+    ArrowIpcFormat, ArrowOptions, JsonFormatType, TableOptions,
+};
 use datafusion_common::scalar::ScalarStructBuilder;
 use datafusion_common::{
     DFSchema, DFSchemaRef, DataFusionError, Result, ScalarValue, TableReference,
@@ -506,7 +508,12 @@ async fn roundtrip_logical_plan_copy_to_arrow() -> Result<()> {
 
     let input = create_csv_scan(&ctx).await?;
 
-    let file_type = format_as_file_type(Arc::new(ArrowFormatFactory::new()));
+    let arrow_options = ArrowOptions {
+        format: ArrowIpcFormat::Stream,
+    };
+    let file_type = format_as_file_type(Arc::new(ArrowFormatFactory::new_with_options(
+        arrow_options.clone(),
+    )));
 
     let plan = LogicalPlan::Copy(CopyTo::new(
         Arc::new(input),
@@ -527,6 +534,21 @@ async fn roundtrip_logical_plan_copy_to_arrow() -> Result<()> {
             assert_eq!("test.arrow", copy_to.output_url);
             assert_eq!("arrow".to_string(), copy_to.file_type.get_ext());
             assert_eq!(vec!["a", "b", "c"], copy_to.partition_by);
+
+            let file_type = copy_to
+                .file_type
+                .as_ref()
+                .as_any()
+                .downcast_ref::<DefaultFileType>()
+                .unwrap();
+
+            let format_factory = file_type.as_format_factory();
+            let arrow_factory = format_factory
+                .as_ref()
+                .as_any()
+                .downcast_ref::<ArrowFormatFactory>()
+                .unwrap();
+            assert_eq!(arrow_factory.options.as_ref(), Some(&arrow_options));
         }
         _ => panic!(),
     }
@@ -620,6 +642,7 @@ async fn roundtrip_logical_plan_copy_to_json() -> Result<()> {
     // Set specific JSON format options
     json_format.compression = CompressionTypeVariant::GZIP;
     json_format.schema_infer_max_rec = Some(1000);
+    json_format.format = JsonFormatType::Array;
 
     let file_type = format_as_file_type(Arc::new(JsonFormatFactory::new_with_options(
         json_format.clone(),
@@ -665,6 +688,7 @@ async fn roundtrip_logical_plan_copy_to_json() -> Result<()> {
                 json_format.schema_infer_max_rec,
                 json_config.schema_infer_max_rec
             );
+            assert_eq!(json_format.format, json_config.format);
         }
         _ => panic!(),
     }
@@ -865,6 +889,17 @@ async fn roundtrip_logical_plan_unnest() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_logical_plan_unnest_with_ordinality() -> Result<()> {
+    let ctx = SessionContext::new();
+    let query = "SELECT * FROM unnest([1, 2, 3]) WITH ORDINALITY";
+    let plan = ctx.sql(query).await?.into_optimized_plan()?;
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx.task_ctx())?;
+    assert_eq!(format!("{plan}"), format!("{logical_round_trip}"));
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_expr_api() -> Result<()> {
     let ctx = SessionContext::new();
@@ -2845,6 +2880,129 @@ async fn roundtrip_arrow_scan() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_arrow_listing_table_options() -> Result<()> {
+    let ctx = SessionContext::new();
+    let file_format = ArrowFormat::default().with_options(ArrowOptions {
+        format: ArrowIpcFormat::Stream,
+    });
+    let listing_options = ListingOptions::new(Arc::new(file_format));
+    let config = ListingTableConfig::new(ListingTableUrl::parse("tests/testdata/")?)
+        .with_listing_options(listing_options)
+        .with_schema(Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Int64,
+            true,
+        )])));
+    ctx.register_table("t", Arc::new(ListingTable::try_new(config)?))?;
+
+    let plan = ctx.table("t").await?.into_optimized_plan()?;
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx.task_ctx())?;
+
+    let LogicalPlan::TableScan(scan) = logical_round_trip else {
+        panic!("Expected a table scan, got {logical_round_trip}");
+    };
+    let provider = source_as_provider(&scan.source)?;
+    let listing_table = provider.as_any().downcast_ref::<ListingTable>().unwrap();
+    let arrow_format = listing_table
+        .options()
+        .format
+        .as_any()
+        .downcast_ref::<ArrowFormat>()
+        .unwrap();
+    assert_eq!(arrow_format.options().format, ArrowIpcFormat::Stream);
+    Ok(())
+}
+
+#[cfg(feature = "avro")]
+#[tokio::test]
+async fn roundtrip_logical_plan_copy_to_avro() -> Result<()> {
+    use datafusion_common::config::{AvroCodec, AvroOptions};
+    use datafusion_datasource_avro::file_format::AvroFormatFactory;
+    use datafusion_proto::logical_plan::file_formats::AvroLogicalExtensionCodec;
+
+    let ctx = SessionContext::new();
+
+    let input = create_csv_scan(&ctx).await?;
+
+    let avro_options = AvroOptions {
+        codec: AvroCodec::Snappy,
+    };
+    let file_type = format_as_file_type(Arc::new(AvroFormatFactory::new_with_options(
+        avro_options.clone(),
+    )));
+
+    let plan = LogicalPlan::Copy(CopyTo::new(
+        Arc::new(input),
+        "test.avro".to_string(),
+        vec![],
+        file_type,
+        Default::default(),
+    ));
+
+    let codec = AvroLogicalExtensionCodec {};
+    let bytes = logical_plan_to_bytes_with_extension_codec(&plan, &codec)?;
+    let logical_round_trip =
+        logical_plan_from_bytes_with_extension_codec(&bytes, &ctx.task_ctx(), &codec)?;
+    assert_eq!(format!("{plan}"), format!("{logical_round_trip}"));
+
+    let LogicalPlan::Copy(copy_to) = logical_round_trip else {
+        panic!("Expected a copy, got {logical_round_trip}");
+    };
+    let file_type = copy_to
+        .file_type
+        .as_ref()
+        .as_any()
+        .downcast_ref::<DefaultFileType>()
+        .unwrap();
+    let format_factory = file_type.as_format_factory();
+    let avro_factory = format_factory
+        .as_ref()
+        .as_any()
+        .downcast_ref::<AvroFormatFactory>()
+        .unwrap();
+    assert_eq!(avro_factory.options.as_ref(), Some(&avro_options));
+    Ok(())
+}
+
+#[cfg(feature = "avro")]
+#[tokio::test]
+async fn roundtrip_avro_listing_table_options() -> Result<()> {
+    use datafusion_common::config::AvroCodec;
+    use datafusion_datasource_avro::file_format::AvroFormat;
+
+    let ctx = SessionContext::new();
+    let file_format = AvroFormat::default().with_codec(AvroCodec::Deflate);
+    let listing_options = ListingOptions::new(Arc::new(file_format));
+    let config = ListingTableConfig::new(ListingTableUrl::parse("tests/testdata/")?)
+        .with_listing_options(listing_options)
+        .with_schema(Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Int64,
+            true,
+        )])));
+    ctx.register_table("t", Arc::new(ListingTable::try_new(config)?))?;
+
+    let plan = ctx.table("t").await?.into_optimized_plan()?;
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx.task_ctx())?;
+
+    let LogicalPlan::TableScan(scan) = logical_round_trip else {
+        panic!("Expected a table scan, got {logical_round_trip}");
+    };
+    let provider = source_as_provider(&scan.source)?;
+    let listing_table = provider.as_any().downcast_ref::<ListingTable>().unwrap();
+    let avro_format = listing_table
+        .options()
+        .format
+        .as_any()
+        .downcast_ref::<AvroFormat>()
+        .unwrap();
+    assert_eq!(avro_format.options().codec, AvroCodec::Deflate);
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_mixed_case_table_reference() -> Result<()> {
     // Prepare "client" database
@@ -52,7 +52,7 @@ use datafusion::datasource::listing::{
 };
 use datafusion::datasource::object_store::ObjectStoreUrl;
 use datafusion::datasource::physical_plan::{
-    FileGroup, FileScanConfigBuilder, FileSinkConfig, ParquetSource,
+    FileGroup, FileScanConfigBuilder, FileSinkConfig, JsonSource, ParquetSource,
     wrap_partition_type_in_dict, wrap_partition_value_in_dict,
 };
 use datafusion::datasource::sink::DataSinkExec;
@@ -98,7 +98,7 @@ use datafusion::physical_plan::{
 };
 use datafusion::prelude::{ParquetReadOptions, SessionContext};
 use datafusion::scalar::ScalarValue;
-use datafusion_common::config::{ConfigOptions, TableParquetOptions};
+use datafusion_common::config::{ConfigOptions, JsonFormatType, TableParquetOptions};
 use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
@@ -1455,6 +1455,22 @@ async fn roundtrip_json_source() -> Result<()> {
     roundtrip_test(plan)
 }
 
+#[test]
+fn roundtrip_json_array_source() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    let file_source =
+        Arc::new(JsonSource::new(schema).with_format(JsonFormatType::Array));
+    let scan_config =
+        FileScanConfigBuilder::new(ObjectStoreUrl::local_filesystem(), file_source)
+            .with_file_group(FileGroup::new(vec![PartitionedFile::new(
+                "/path/to/file.json".to_string(),
+                1024,
+            )]))
+            .build();
+
+    roundtrip_test(DataSourceExec::from_data_source(scan_config))
+}
+
 #[test]
 fn roundtrip_json_sink() -> Result<()> {
     let field_a = Field::new("plan_type", DataType::Utf8, false);
@@ -1677,6 +1693,23 @@ fn roundtrip_interleave() -> Result<()> {
     roundtrip_test(Arc::new(interleave))
 }
 
+#[test]
+fn roundtrip_range_repartition() -> Result<()> {
+    let field_a = Field::new("a", DataType::Int64, false);
+    let field_b = Field::new("b", DataType::Utf8, true);
+    let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+    let ordering: LexOrdering = [
+        PhysicalSortExpr::new(col("a", &schema)?, SortOptions::new(true, false)),
+        PhysicalSortExpr::new_default(col("b", &schema)?),
+    ]
+    .into();
+    let repartition = RepartitionExec::try_new(
+        Arc::new(EmptyExec::new(schema)),
+        Partitioning::Range(ordering, 4),
+    )?;
+    roundtrip_test(Arc::new(repartition))
+}
+
 #[test]
 fn roundtrip_unnest() -> Result<()> {
     let fa = Field::new("a", DataType::Int64, true);
@@ -0,0 +1,187 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use crate::function::generator::utils::{generate, generator_return_type};
+use arrow::datatypes::DataType;
+use datafusion_common::Result;
+use datafusion_common::utils::take_function_args;
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `explode` and `explode_outer` expressions
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#explode>
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#explode_outer>
+///
+/// Returns the generated rows as a list of structs, with a `col` field for
+/// an array argument and `key` and `value` fields for a map argument.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SparkExplode {
+    signature: Signature,
+    outer: bool,
+}
+
+impl Default for SparkExplode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkExplode {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+            outer: false,
+        }
+    }
+
+    /// Creates `explode_outer`, which generates a row of nulls for a null or
+    /// empty argument
+    pub fn new_outer() -> Self {
+        Self {
+            outer: true,
+            ..Self::new()
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkExplode {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        if self.outer {
+            "explode_outer"
+        } else {
+            "explode"
+        }
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [arg_type] = take_function_args(self.name(), arg_types)?;
+        generator_return_type(self.name(), arg_type, false, self.outer)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(
+            |args| generate(self.name(), args, false, self.outer),
+            vec![],
+        )(&args.args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{
+        Array, ArrayRef, AsArray, Int32Array, Int32Builder, ListArray, MapBuilder,
+        StringBuilder,
+    };
+    use arrow::datatypes::Int32Type;
+    use std::sync::Arc;
+
+    fn list_array() -> ArrayRef {
+        Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(3), None]),
+        ]))
+    }
+
+    fn exploded(result: &ArrayRef) -> Vec<Option<Vec<Option<i32>>>> {
+        let list = result.as_list::<i32>();
+        (0..list.len())
+            .map(|row| {
+                list.is_valid(row).then(|| {
+                    let rows = list.value(row);
+                    let col = rows.as_struct().column(0).as_primitive::<Int32Type>();
+                    col.iter().collect()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_explode_list() -> Result<()> {
+        let result = generate("explode", &[list_array()], false, false)?;
+        assert_eq!(
+            exploded(&result),
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![]),
+                None,
+                Some(vec![Some(3), None]),
+            ]
+        );
+        assert_eq!(
+            result.data_type(),
+            &SparkExplode::new().return_type(&[list_array().data_type().clone()])?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_explode_outer_list() -> Result<()> {
+        let result = generate("explode_outer", &[list_array()], false, true)?;
+        assert_eq!(
+            exploded(&result),
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![None]),
+                Some(vec![None]),
+                Some(vec![Some(3), None]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_explode_map() -> Result<()> {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true)?;
+        builder.append(false)?;
+        let map: ArrayRef = Arc::new(builder.finish());
+
+        let result = generate("explode_outer", &[map], false, true)?;
+        let list = result.as_list::<i32>();
+        assert_eq!(list.value_offsets(), &[0, 2, 3]);
+        let rows = list.values().as_struct();
+        assert_eq!(rows.column_names(), vec!["key", "value"]);
+        let keys = rows.column(0).as_string::<i32>();
+        assert_eq!(
+            keys.iter().collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), None]
+        );
+        let values = rows.column(1).as_primitive::<Int32Type>();
+        assert_eq!(values, &Int32Array::from(vec![Some(1), Some(2), None]));
+        Ok(())
+    }
+}
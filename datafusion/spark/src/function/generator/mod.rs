@@ -15,11 +15,55 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod explode;
+pub mod posexplode;
+mod utils;
+
 use datafusion_expr::ScalarUDF;
+use datafusion_functions::make_udf_function;
 use std::sync::Arc;
 
-pub mod expr_fn {}
+make_udf_function!(explode::SparkExplode, explode);
+make_udf_function!(
+    explode::SparkExplode,
+    explode_outer,
+    explode::SparkExplode::new_outer
+);
+make_udf_function!(posexplode::SparkPosExplode, posexplode);
+make_udf_function!(
+    posexplode::SparkPosExplode,
+    posexplode_outer,
+    posexplode::SparkPosExplode::new_outer
+);
+
+pub mod expr_fn {
+    use datafusion_functions::export_functions;
+
+    export_functions!((
+        explode,
+        "Returns the elements of an array, or the entries of a map, as a list of rows.",
+        arg1
+    ));
+
+    export_functions!((
+        explode_outer,
+        "Like explode, but returns a row of nulls for a null or empty argument.",
+        arg1
+    ));
+
+    export_functions!((
+        posexplode,
+        "Like explode, with the position of every element in an additional column.",
+        arg1
+    ));
+
+    export_functions!((
+        posexplode_outer,
+        "Like posexplode, but returns a row of nulls for a null or empty argument.",
+        arg1
+    ));
+}
 
 pub fn functions() -> Vec<Arc<ScalarUDF>> {
-    vec![]
+    vec![explode(), explode_outer(), posexplode(), posexplode_outer()]
 }
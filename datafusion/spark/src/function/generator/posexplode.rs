@@ -0,0 +1,130 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+
+use crate::function::generator::utils::{generate, generator_return_type};
+use arrow::datatypes::DataType;
+use datafusion_common::Result;
+use datafusion_common::utils::take_function_args;
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `posexplode` and `posexplode_outer` expressions
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#posexplode>
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#posexplode_outer>
+///
+/// Like [`SparkExplode`](super::explode::SparkExplode), with the 0-based
+/// position of every element in an additional `pos` field.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SparkPosExplode {
+    signature: Signature,
+    outer: bool,
+}
+
+impl Default for SparkPosExplode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkPosExplode {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+            outer: false,
+        }
+    }
+
+    /// Creates `posexplode_outer`, which generates a row of nulls for a null
+    /// or empty argument
+    pub fn new_outer() -> Self {
+        Self {
+            outer: true,
+            ..Self::new()
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkPosExplode {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        if self.outer {
+            "posexplode_outer"
+        } else {
+            "posexplode"
+        }
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [arg_type] = take_function_args(self.name(), arg_types)?;
+        generator_return_type(self.name(), arg_type, true, self.outer)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(|args| generate(self.name(), args, true, self.outer), vec![])(
+            &args.args,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, ArrayRef, AsArray, Int32Array, ListArray};
+    use arrow::datatypes::Int32Type;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_posexplode_outer() -> Result<()> {
+        let list: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(10), Some(20)]),
+                None,
+                Some(vec![Some(30)]),
+            ]));
+
+        let result = generate("posexplode_outer", &[Arc::clone(&list)], true, true)?;
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value_offsets(), &[0, 2, 3, 4]);
+        let rows = result.values().as_struct();
+        assert_eq!(rows.column_names(), vec!["pos", "col"]);
+        assert_eq!(
+            rows.column(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(0), Some(1), None, Some(0)])
+        );
+        assert_eq!(
+            rows.column(1).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(10), Some(20), None, Some(30)])
+        );
+
+        let result = generate("posexplode", &[list], true, false)?;
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value_offsets(), &[0, 2, 2, 3]);
+        assert!(result.is_null(1));
+        Ok(())
+    }
+}
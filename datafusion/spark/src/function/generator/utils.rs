@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Shared implementation of the generator functions.
+//!
+//! DataFusion has no table generating functions, so a generator returns, for
+//! every input row, a list of the rows it generates. Every generated row is a
+//! struct whose fields are the generated columns, e.g. `explode` returns a
+//! `List<Struct<col>>` for an array argument and a `List<Struct<key, value>>`
+//! for a map argument. `LATERAL VIEW` unnests the list and the struct.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayBuilder, ArrayRef, AsArray, Int32Builder, ListArray, StructArray,
+    UInt64Builder, new_empty_array,
+};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::take;
+use arrow::datatypes::{ArrowNativeType, DataType, Field, Fields};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{Result, exec_datafusion_err, exec_err, internal_err};
+
+/// Returns the fields of the rows generated from an argument of type
+/// `arg_type`. The fields are nullable for the `_outer` variants, which
+/// generate a row of nulls for a null or empty argument.
+fn generated_fields(
+    name: &str,
+    arg_type: &DataType,
+    with_position: bool,
+    outer: bool,
+) -> Result<Fields> {
+    let mut fields = vec![];
+    if with_position {
+        fields.push(Field::new("pos", DataType::Int32, outer));
+    }
+    match arg_type {
+        DataType::Null => fields.push(Field::new("col", DataType::Null, true)),
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) => fields.push(Field::new(
+            "col",
+            field.data_type().clone(),
+            field.is_nullable() || outer,
+        )),
+        DataType::Map(entries, _) => {
+            let DataType::Struct(key_value) = entries.data_type() else {
+                return internal_err!("{name}: map entries should be a struct");
+            };
+            let [key, value] = key_value.iter().collect::<Vec<_>>()[..] else {
+                return internal_err!("{name}: map entries should have 2 fields");
+            };
+            fields.push(Field::new(
+                "key",
+                key.data_type().clone(),
+                key.is_nullable() || outer,
+            ));
+            fields.push(Field::new(
+                "value",
+                value.data_type().clone(),
+                value.is_nullable() || outer,
+            ));
+        }
+        other => {
+            return exec_err!("{name} expects an array or map argument, got {other}");
+        }
+    }
+    Ok(fields.into())
+}
+
+/// Returns the return type of a generator called with an argument of type
+/// `arg_type`
+pub(super) fn generator_return_type(
+    name: &str,
+    arg_type: &DataType,
+    with_position: bool,
+    outer: bool,
+) -> Result<DataType> {
+    let fields = generated_fields(name, arg_type, with_position, outer)?;
+    Ok(DataType::List(Arc::new(Field::new_list_field(
+        DataType::Struct(fields),
+        false,
+    ))))
+}
+
+/// Returns the range of elements of every row of `array` (`None` for null
+/// rows), and the elements the ranges refer to. The elements of a map are its
+/// key-value entries.
+fn element_ranges(
+    name: &str,
+    array: &ArrayRef,
+) -> Result<(Vec<Option<Range<usize>>>, ArrayRef)> {
+    let (offsets, values): (Vec<usize>, ArrayRef) = match array.data_type() {
+        DataType::Null => {
+            return Ok((vec![None; array.len()], new_empty_array(&DataType::Null)));
+        }
+        DataType::List(_) => {
+            let list = array.as_list::<i32>();
+            let offsets = list.offsets().iter().map(|o| o.as_usize()).collect();
+            (offsets, Arc::clone(list.values()))
+        }
+        DataType::LargeList(_) => {
+            let list = array.as_list::<i64>();
+            let offsets = list.offsets().iter().map(|o| o.as_usize()).collect();
+            (offsets, Arc::clone(list.values()))
+        }
+        DataType::FixedSizeList(_, size) => {
+            let list = array.as_fixed_size_list();
+            let size = *size as usize;
+            let offsets = (0..=list.len()).map(|row| row * size).collect();
+            (offsets, Arc::clone(list.values()))
+        }
+        DataType::Map(_, _) => {
+            let map = array.as_map();
+            let offsets = map.offsets().iter().map(|o| o.as_usize()).collect();
+            (offsets, Arc::new(map.entries().clone()))
+        }
+        other => {
+            return exec_err!("{name} expects an array or map argument, got {other}");
+        }
+    };
+
+    let ranges = offsets
+        .windows(2)
+        .enumerate()
+        .map(|(row, offsets)| array.is_valid(row).then(|| offsets[0]..offsets[1]))
+        .collect();
+    Ok((ranges, values))
+}
+
+/// Generates the rows of every row of the single argument in `args`.
+///
+/// Every element of an array becomes one generated row, as does every entry of
+/// a map. `with_position` adds the 0-based position of the element as first
+/// column. If `outer` is set, a null or empty argument generates a single row
+/// of nulls, instead of no rows at all.
+pub(super) fn generate(
+    name: &str,
+    args: &[ArrayRef],
+    with_position: bool,
+    outer: bool,
+) -> Result<ArrayRef> {
+    let [array] = take_function_args(name, args)?;
+    let fields = generated_fields(name, array.data_type(), with_position, outer)?;
+    let (ranges, values) = element_ranges(name, array)?;
+
+    let mut indices = UInt64Builder::new();
+    let mut positions = Int32Builder::new();
+    let mut offsets = Vec::with_capacity(ranges.len() + 1);
+    offsets.push(0);
+    for range in ranges {
+        let range = range.unwrap_or_default();
+        if range.is_empty() && outer {
+            indices.append_null();
+            positions.append_null();
+        }
+        for (position, index) in range.enumerate() {
+            indices.append_value(index as u64);
+            positions.append_value(position as i32);
+        }
+        offsets.push(i32::try_from(indices.len()).map_err(|_| {
+            exec_datafusion_err!("{name} generated too many rows for a single batch")
+        })?);
+    }
+
+    let values = take(&values, &indices.finish(), None)?;
+    let mut columns: Vec<ArrayRef> = vec![];
+    if with_position {
+        columns.push(Arc::new(positions.finish()));
+    }
+    if matches!(array.data_type(), DataType::Map(_, _)) {
+        columns.extend(values.as_struct().columns().iter().cloned());
+    } else {
+        columns.push(values);
+    }
+    let rows = StructArray::try_new(fields.clone(), columns, None)?;
+
+    // The `_outer` variants generate a row for null arguments too
+    let nulls = if outer { None } else { array.logical_nulls() };
+    Ok(Arc::new(ListArray::try_new(
+        Arc::new(Field::new_list_field(DataType::Struct(fields), false)),
+        OffsetBuffer::new(offsets.into()),
+        Arc::new(rows),
+        nulls,
+    )?))
+}
@@ -17,14 +17,23 @@
 
 use std::sync::Arc;
 
+use crate::parser::RecursiveCteClauses;
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 
 use datafusion_common::{
-    Result, not_impl_err, plan_err,
-    tree_node::{TreeNode, TreeNodeRecursion},
+    Column, DFSchema, DataFusionError, Result, not_impl_err, plan_err,
+    tree_node::{Transformed, TreeNode, TreeNodeRecursion},
 };
-use datafusion_expr::{LogicalPlan, LogicalPlanBuilder, TableSource};
-use sqlparser::ast::{Query, SetExpr, SetOperator, With};
+use datafusion_expr::{
+    Expr, Filter, LogicalPlan, LogicalPlanBuilder, Projection, TableSource, lit, when,
+};
+use sqlparser::ast::{
+    Ident, Query, SetExpr, SetOperator, TableAlias, TableAliasColumnDef, With,
+};
+
+/// Returns the expression of the named `SEARCH` or `CYCLE` column of the row
+/// of the previous iteration of a recursive CTE
+type PreviousColumn<'a> = &'a dyn Fn(&str) -> Result<Expr>;
 
 impl<S: ContextProvider> SqlToRel<'_, S> {
     pub(super) fn plan_with_clause(
@@ -34,7 +43,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
     ) -> Result<()> {
         let is_recursive = with.recursive;
         // Process CTEs from top to bottom
-        for cte in with.cte_tables {
+        for mut cte in with.cte_tables {
             // A `WITH` block can't use the same name more than once
             let cte_name = self.ident_normalizer.normalize(cte.alias.name.clone());
             if planner_context.contains_cte(&cte_name) {
@@ -43,9 +52,26 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 );
             }
 
+            // `SEARCH` and `CYCLE` clauses are attached to the CTE by the
+            // location of the parenthesis closing its query
+            let clauses = planner_context
+                .recursive_cte_clauses(cte.closing_paren_token.0.span.start)
+                .cloned();
+
             // Create a logical plan for the CTE
             let cte_plan = if is_recursive {
-                self.recursive_cte(&cte_name, *cte.query, planner_context)?
+                self.recursive_cte(
+                    &cte_name,
+                    *cte.query,
+                    &mut cte.alias,
+                    clauses,
+                    planner_context,
+                )?
+            } else if clauses.is_some() {
+                return plan_err!(
+                    "SEARCH and CYCLE clauses are only supported for recursive CTEs, \
+                    but {cte_name} is not recursive"
+                );
             } else {
                 self.non_recursive_cte(*cte.query, planner_context)?
             };
@@ -71,6 +97,8 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         &self,
         cte_name: &str,
         mut cte_query: Query,
+        alias: &mut TableAlias,
+        clauses: Option<RecursiveCteClauses>,
         planner_context: &mut PlannerContext,
     ) -> Result<LogicalPlan> {
         if !self
@@ -91,6 +119,12 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             } => (left, right, set_quantifier),
             other => {
                 // If the query is not a UNION, then it is not a recursive CTE
+                if clauses.is_some() {
+                    return plan_err!(
+                        "SEARCH and CYCLE clauses are only supported for recursive CTEs, \
+                        but {cte_name} is not recursive"
+                    );
+                }
                 *cte_query.body = other;
                 return self.non_recursive_cte(cte_query, planner_context);
             }
@@ -110,6 +144,28 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         // ---------- Step 1: Compile the static term ------------------
         let static_plan = self.set_expr_to_plan(*left_expr, planner_context)?;
 
+        // The `SEARCH` and `CYCLE` clauses add columns to both terms, which
+        // are computed from the columns of the previous iteration in the
+        // recursive term
+        let clause_columns = clauses
+            .map(|clauses| {
+                self.resolve_cte_clauses(clauses, &static_plan, alias, planner_context)
+            })
+            .transpose()?;
+        let static_plan = match &clause_columns {
+            Some(clause_columns) => {
+                let schema = static_plan.schema();
+                let values = (0..schema.fields().len())
+                    .map(|i| Expr::Column(Column::from(schema.qualified_field(i))))
+                    .collect::<Vec<_>>();
+                let extra = self.cte_clause_exprs(clause_columns, &values, None)?;
+                LogicalPlanBuilder::from(static_plan)
+                    .project(values.into_iter().chain(extra))?
+                    .build()?
+            }
+            None => static_plan,
+        };
+
         // Since the recursive CTEs include a component that references a
         // table with its name, like the example below:
         //
@@ -166,6 +222,12 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         if !has_work_table_reference(&recursive_plan, &work_table_source) {
             // Remove the work table plan from the context
             planner_context.remove_cte(cte_name);
+            if clause_columns.is_some() {
+                return plan_err!(
+                    "SEARCH and CYCLE clauses are only supported for recursive CTEs, \
+                    but {cte_name} is not recursive"
+                );
+            }
             // Compile it as a non-recursive CTE
             return self.set_operation_to_plan(
                 SetOperator::Union,
@@ -175,6 +237,15 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             );
         }
 
+        let recursive_plan = match &clause_columns {
+            Some(clause_columns) => self.extend_recursive_term(
+                recursive_plan,
+                &work_table_source,
+                clause_columns,
+            )?,
+            None => recursive_plan,
+        };
+
         // ---------- Step 4: Create the final plan ------------------
         // Step 4.1: Compile the final plan
         let distinct = !Self::is_union_all(set_quantifier)?;
@@ -182,6 +253,294 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             .to_recursive_query(name, recursive_plan, distinct)?
             .build()
     }
+
+    /// Resolves the columns of the `SEARCH` and `CYCLE` clauses against the
+    /// columns of the CTE, and adds the columns the clauses define to the
+    /// column aliases of the CTE, if any
+    fn resolve_cte_clauses(
+        &self,
+        clauses: RecursiveCteClauses,
+        static_plan: &LogicalPlan,
+        alias: &mut TableAlias,
+        planner_context: &mut PlannerContext,
+    ) -> Result<CteClauseColumns> {
+        let column_names = if alias.columns.is_empty() {
+            static_plan
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| field.name().clone())
+                .collect::<Vec<_>>()
+        } else {
+            alias
+                .columns
+                .iter()
+                .map(|column| self.ident_normalizer.normalize(column.name.clone()))
+                .collect()
+        };
+        let resolve_columns = |columns: Vec<Ident>| {
+            columns
+                .into_iter()
+                .map(|column| {
+                    let name = self.ident_normalizer.normalize(column);
+                    match column_names.iter().position(|c| *c == name) {
+                        Some(index) => Ok((index, name)),
+                        None => plan_err!(
+                            "Column {name} of a SEARCH or CYCLE clause is not a column \
+                            of the CTE {}",
+                            alias.name
+                        ),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let search = clauses
+            .search
+            .map(|search| {
+                Ok::<_, DataFusionError>(SearchColumn {
+                    breadth_first: search.breadth_first,
+                    columns: resolve_columns(search.columns)?,
+                    name: self.ident_normalizer.normalize(search.sequence_column),
+                })
+            })
+            .transpose()?;
+        let cycle = clauses
+            .cycle
+            .map(|cycle| {
+                let empty_schema = DFSchema::empty();
+                let (mark_value, default_value) = match cycle.mark_values {
+                    Some((mark_value, default_value)) => (
+                        self.sql_to_expr(mark_value, &empty_schema, planner_context)?,
+                        self.sql_to_expr(default_value, &empty_schema, planner_context)?,
+                    ),
+                    None => (lit(true), lit(false)),
+                };
+                Ok::<_, DataFusionError>(CycleColumns {
+                    columns: resolve_columns(cycle.columns)?,
+                    mark_name: self.ident_normalizer.normalize(cycle.mark_column),
+                    mark_value,
+                    default_value,
+                    path_name: self.ident_normalizer.normalize(cycle.path_column),
+                })
+            })
+            .transpose()?;
+        let clause_columns = CteClauseColumns { search, cycle };
+
+        let mut names = column_names;
+        for name in clause_columns.names() {
+            if names.iter().any(|n| n == name) {
+                return plan_err!(
+                    "Column {name} added by a SEARCH or CYCLE clause is already a \
+                    column of the CTE {}",
+                    alias.name
+                );
+            }
+            names.push(name.to_string());
+        }
+        if !alias.columns.is_empty() {
+            alias.columns.extend(clause_columns.names().map(|name| {
+                TableAliasColumnDef {
+                    name: Ident::new(name),
+                    data_type: None,
+                }
+            }));
+        }
+        Ok(clause_columns)
+    }
+
+    /// Adds the columns of the `SEARCH` and `CYCLE` clauses to the final
+    /// projection of the recursive term, and skips the rows of the work table
+    /// that closed a cycle
+    fn extend_recursive_term(
+        &self,
+        recursive_plan: LogicalPlan,
+        work_table_source: &Arc<dyn TableSource>,
+        clause_columns: &CteClauseColumns,
+    ) -> Result<LogicalPlan> {
+        let projection = match recursive_plan {
+            LogicalPlan::Projection(projection) => projection,
+            other => {
+                return plan_err!(
+                    "SEARCH and CYCLE clauses require the recursive term to be a \
+                    plain SELECT, got {}",
+                    other.display()
+                );
+            }
+        };
+
+        let mut input = Arc::unwrap_or_clone(projection.input);
+        if let Some(cycle) = &clause_columns.cycle {
+            input = input
+                .transform_up(|node| match node {
+                    LogicalPlan::TableScan(ref scan)
+                        if Arc::ptr_eq(&scan.source, work_table_source) =>
+                    {
+                        let mark = Column::new(
+                            Some(scan.table_name.clone()),
+                            cycle.mark_name.as_str(),
+                        );
+                        let predicate =
+                            Expr::Column(mark).eq(cycle.default_value.clone());
+                        Ok(Transformed::yes(LogicalPlan::Filter(Filter::try_new(
+                            predicate,
+                            Arc::new(node),
+                        )?)))
+                    }
+                    _ => Ok(Transformed::no(node)),
+                })?
+                .data;
+        }
+
+        let input_schema = Arc::clone(input.schema());
+        let previous = |name: &str| match input_schema
+            .qualified_fields_with_unqualified_name(name)
+            .as_slice()
+        {
+            [qualified_field] => Ok(Expr::Column(Column::from(*qualified_field))),
+            _ => plan_err!(
+                "Column {name} added by a SEARCH or CYCLE clause must be \
+                    selected from the CTE exactly once in the recursive term"
+            ),
+        };
+        let values = projection
+            .expr
+            .iter()
+            .map(|expr| expr.clone().unalias())
+            .collect::<Vec<_>>();
+        let extra = self.cte_clause_exprs(clause_columns, &values, Some(&previous))?;
+        let expr = projection.expr.into_iter().chain(extra).collect();
+        Ok(LogicalPlan::Projection(Projection::try_new(
+            expr,
+            Arc::new(input),
+        )?))
+    }
+
+    /// Returns the expressions of the columns added by the `SEARCH` and
+    /// `CYCLE` clauses, given the `values` of the columns of the CTE. For the
+    /// recursive term, `previous` returns the column added to the row of the
+    /// previous iteration.
+    ///
+    /// * The sequence column of `SEARCH DEPTH FIRST` is the list of rows from
+    ///   the static term to the current row, so that ordering by it visits
+    ///   the rows depth-first.
+    /// * The sequence column of `SEARCH BREADTH FIRST` is a struct of the
+    ///   iteration and the current row, so that ordering by it visits the rows
+    ///   breadth-first.
+    /// * The path column of `CYCLE` is the list of rows from the static term
+    ///   to the current row, and a row closes a cycle if it is in the path of
+    ///   the previous row.
+    fn cte_clause_exprs(
+        &self,
+        clause_columns: &CteClauseColumns,
+        values: &[Expr],
+        previous: Option<PreviousColumn<'_>>,
+    ) -> Result<Vec<Expr>> {
+        let row = |columns: &[(usize, String)]| {
+            let mut args = vec![];
+            for (index, name) in columns {
+                let Some(value) = values.get(*index) else {
+                    return plan_err!(
+                        "Recursive term is missing the column {name} of a SEARCH or \
+                        CYCLE clause"
+                    );
+                };
+                args.extend([lit(name.as_str()), value.clone()]);
+            }
+            self.call_function("named_struct", args)
+        };
+        let append = |name: &str, row: Expr| match previous {
+            Some(previous) => {
+                self.call_function("array_append", vec![previous(name)?, row])
+            }
+            None => self.call_function("make_array", vec![row]),
+        };
+
+        let mut exprs = vec![];
+        if let Some(search) = &clause_columns.search {
+            let row = row(&search.columns)?;
+            let expr = if search.breadth_first {
+                let depth = match previous {
+                    Some(previous) => {
+                        let depth = self.call_function(
+                            "get_field",
+                            vec![previous(&search.name)?, lit("depth")],
+                        )?;
+                        depth + lit(1i64)
+                    }
+                    None => lit(0i64),
+                };
+                self.call_function(
+                    "named_struct",
+                    vec![lit("depth"), depth, lit("row"), row],
+                )?
+            } else {
+                append(&search.name, row)?
+            };
+            exprs.push(expr.alias(&search.name));
+        }
+        if let Some(cycle) = &clause_columns.cycle {
+            let row = row(&cycle.columns)?;
+            let mark = match previous {
+                Some(previous) => when(
+                    self.call_function(
+                        "array_has",
+                        vec![previous(&cycle.path_name)?, row.clone()],
+                    )?,
+                    cycle.mark_value.clone(),
+                )
+                .otherwise(cycle.default_value.clone())?,
+                None => cycle.default_value.clone(),
+            };
+            exprs.push(mark.alias(&cycle.mark_name));
+            exprs.push(append(&cycle.path_name, row)?.alias(&cycle.path_name));
+        }
+        Ok(exprs)
+    }
+
+    fn call_function(&self, name: &str, args: Vec<Expr>) -> Result<Expr> {
+        match self.context_provider.get_function_meta(name) {
+            Some(function) => Ok(function.call(args)),
+            None => plan_err!(
+                "SEARCH and CYCLE clauses require the {name} function to be registered"
+            ),
+        }
+    }
+}
+
+/// The columns of the `SEARCH` and `CYCLE` clauses of a recursive CTE
+struct CteClauseColumns {
+    search: Option<SearchColumn>,
+    cycle: Option<CycleColumns>,
+}
+
+impl CteClauseColumns {
+    /// Returns the names of the columns the clauses add to the CTE, in order
+    fn names(&self) -> impl Iterator<Item = &str> {
+        self.search.iter().map(|search| search.name.as_str()).chain(
+            self.cycle
+                .iter()
+                .flat_map(|cycle| [cycle.mark_name.as_str(), cycle.path_name.as_str()]),
+        )
+    }
+}
+
+/// The sequence column of a `SEARCH` clause
+struct SearchColumn {
+    breadth_first: bool,
+    /// The positions and names of the columns identifying a row
+    columns: Vec<(usize, String)>,
+    name: String,
+}
+
+/// The mark and path columns of a `CYCLE` clause
+struct CycleColumns {
+    /// The positions and names of the columns identifying a row
+    columns: Vec<(usize, String)>,
+    mark_name: String,
+    mark_value: Expr,
+    default_value: Expr,
+    path_name: String,
 }
 
 fn has_work_table_reference(
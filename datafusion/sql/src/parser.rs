@@ -27,12 +27,12 @@ use sqlparser::ast::{ExprWithAlias, Ident, OrderByOptions};
 use sqlparser::tokenizer::TokenWithSpan;
 use sqlparser::{
     ast::{
-        ColumnDef, ColumnOptionDef, ObjectName, OrderByExpr, Query,
+        ColumnDef, ColumnOptionDef, Expr, ObjectName, OrderByExpr, Query,
         Statement as SQLStatement, TableConstraint, Value,
     },
     dialect::{Dialect, GenericDialect, keywords::Keyword},
     parser::{Parser, ParserError},
-    tokenizer::{Token, Tokenizer, Word},
+    tokenizer::{Location, Token, Tokenizer, Word},
 };
 use std::collections::VecDeque;
 use std::fmt;
@@ -274,6 +274,138 @@ impl fmt::Display for ResetStatement {
     }
 }
 
+/// DataFusion extension for `REFRESH MATERIALIZED VIEW`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshMaterializedViewStatement {
+    /// The name of the materialized view
+    pub name: ObjectName,
+}
+
+impl fmt::Display for RefreshMaterializedViewStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "REFRESH MATERIALIZED VIEW {}", self.name)
+    }
+}
+
+/// `SEARCH` clause of a recursive CTE, which adds a column to order the rows
+/// of the CTE depth-first or breadth-first by.
+///
+/// Syntax:
+/// ```sql
+/// SEARCH { DEPTH | BREADTH } FIRST BY column [, ...] SET sequence_column
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CteSearchClause {
+    /// Whether the rows are ordered breadth-first, rather than depth-first
+    pub breadth_first: bool,
+    /// The columns identifying a row
+    pub columns: Vec<Ident>,
+    /// The name of the added column
+    pub sequence_column: Ident,
+}
+
+impl fmt::Display for CteSearchClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let order = if self.breadth_first {
+            "BREADTH"
+        } else {
+            "DEPTH"
+        };
+        write!(f, "SEARCH {order} FIRST BY ")?;
+        write!(f, "{} ", comma_separated(&self.columns))?;
+        write!(f, "SET {}", self.sequence_column)
+    }
+}
+
+/// `CYCLE` clause of a recursive CTE, which marks the rows that close a cycle
+/// and does not recurse into them.
+///
+/// Syntax:
+/// ```sql
+/// CYCLE column [, ...] SET mark_column [TO mark_value DEFAULT default_value]
+/// USING path_column
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CteCycleClause {
+    /// The columns identifying a row
+    pub columns: Vec<Ident>,
+    /// The name of the added column marking the rows that close a cycle
+    pub mark_column: Ident,
+    /// The values of the mark column for rows that do and do not close a
+    /// cycle. Defaults to `TRUE` and `FALSE`
+    pub mark_values: Option<(Expr, Expr)>,
+    /// The name of the added column holding the rows visited so far
+    pub path_column: Ident,
+}
+
+impl fmt::Display for CteCycleClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CYCLE {} ", comma_separated(&self.columns))?;
+        write!(f, "SET {} ", self.mark_column)?;
+        if let Some((mark_value, default_value)) = &self.mark_values {
+            write!(f, "TO {mark_value} DEFAULT {default_value} ")?;
+        }
+        write!(f, "USING {}", self.path_column)
+    }
+}
+
+/// The `SEARCH` and `CYCLE` clauses following the query of a recursive CTE.
+///
+/// `sqlparser` does not support these clauses, so [`DFParser`] removes them
+/// before parsing the statement, and identifies the CTE they belong to by the
+/// location of the closing parenthesis of its query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecursiveCteClauses {
+    /// The location of the closing parenthesis of the CTE query
+    pub location: Location,
+    /// The `SEARCH` clause, if any
+    pub search: Option<CteSearchClause>,
+    /// The `CYCLE` clause, if any
+    pub cycle: Option<CteCycleClause>,
+}
+
+impl fmt::Display for RecursiveCteClauses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let clauses = self
+            .search
+            .iter()
+            .map(ToString::to_string)
+            .chain(self.cycle.iter().map(ToString::to_string))
+            .collect::<Vec<_>>();
+        write!(f, "{}", clauses.join(" "))
+    }
+}
+
+/// ANSI SQL statement with `SEARCH` or `CYCLE` clauses on its recursive CTEs,
+/// see [`RecursiveCteClauses`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementWithCteClauses {
+    /// The statement, without the clauses
+    pub statement: Box<SQLStatement>,
+    /// The clauses of the recursive CTEs
+    pub cte_clauses: Vec<RecursiveCteClauses>,
+}
+
+impl fmt::Display for StatementWithCteClauses {
+    /// Displays the statement, followed by the clauses and the locations of
+    /// the CTEs they belong to
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.statement)?;
+        for clauses in &self.cte_clauses {
+            write!(f, " -- {clauses}{}", clauses.location)?;
+        }
+        Ok(())
+    }
+}
+
+fn comma_separated(idents: &[Ident]) -> String {
+    idents
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// DataFusion SQL Statement.
 ///
 /// This can either be a [`Statement`] from [`sqlparser`] from a
@@ -285,6 +417,8 @@ impl fmt::Display for ResetStatement {
 pub enum Statement {
     /// ANSI SQL AST node (from sqlparser-rs)
     Statement(Box<SQLStatement>),
+    /// ANSI SQL AST node with `SEARCH` / `CYCLE` clauses on recursive CTEs
+    StatementWithCteClauses(StatementWithCteClauses),
     /// Extension: `CREATE EXTERNAL TABLE`
     CreateExternalTable(CreateExternalTable),
     /// Extension: `COPY TO`
@@ -293,16 +427,20 @@ pub enum Statement {
     Explain(ExplainStatement),
     /// Extension: `RESET`
     Reset(ResetStatement),
+    /// Extension: `REFRESH MATERIALIZED VIEW`
+    RefreshMaterializedView(RefreshMaterializedViewStatement),
 }
 
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Statement::Statement(stmt) => write!(f, "{stmt}"),
+            Statement::StatementWithCteClauses(stmt) => write!(f, "{stmt}"),
             Statement::CreateExternalTable(stmt) => write!(f, "{stmt}"),
             Statement::CopyTo(stmt) => write!(f, "{stmt}"),
             Statement::Explain(stmt) => write!(f, "{stmt}"),
             Statement::Reset(stmt) => write!(f, "{stmt}"),
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}"),
         }
     }
 }
@@ -325,6 +463,8 @@ fn ensure_not_set<T>(field: &Option<T>, name: &str) -> Result<(), DataFusionErro
 pub struct DFParser<'a> {
     pub parser: Parser<'a>,
     options: SqlParserOptions,
+    /// `SEARCH` and `CYCLE` clauses removed from the tokens of `parser`
+    cte_clauses: Vec<RecursiveCteClauses>,
 }
 
 /// Same as `sqlparser`
@@ -401,6 +541,7 @@ impl<'a> DFParserBuilder<'a> {
         let tokens = tokenizer
             .tokenize_with_location()
             .map_err(ParserError::from)?;
+        let (tokens, cte_clauses) = extract_recursive_cte_clauses(self.dialect, &tokens)?;
 
         Ok(DFParser {
             parser: Parser::new(self.dialect)
@@ -410,6 +551,7 @@ impl<'a> DFParserBuilder<'a> {
                 recursion_limit: self.recursion_limit,
                 ..Default::default()
             },
+            cte_clauses,
         })
     }
 }
@@ -543,6 +685,10 @@ impl<'a> DFParser<'a> {
                         self.parser.next_token(); // RESET
                         self.parse_reset()
                     }
+                    Keyword::NoKeyword if w.value.eq_ignore_ascii_case("REFRESH") => {
+                        self.parser.next_token(); // REFRESH
+                        self.parse_refresh()
+                    }
                     _ => {
                         // use sqlparser-rs parser
                         self.parse_and_handle_statement()
@@ -583,7 +729,16 @@ impl<'a> DFParser<'a> {
     fn parse_and_handle_statement(&mut self) -> Result<Statement, DataFusionError> {
         self.parser
             .parse_statement()
-            .map(|stmt| Statement::Statement(Box::from(stmt)))
+            .map(|stmt| {
+                if self.cte_clauses.is_empty() {
+                    Statement::Statement(Box::from(stmt))
+                } else {
+                    Statement::StatementWithCteClauses(StatementWithCteClauses {
+                        statement: Box::from(stmt),
+                        cte_clauses: self.cte_clauses.clone(),
+                    })
+                }
+            })
             .map_err(|e| match e {
                 ParserError::RecursionLimitExceeded => DataFusionError::SQL(
                     Box::new(ParserError::RecursionLimitExceeded),
@@ -787,6 +942,16 @@ impl<'a> DFParser<'a> {
         Ok(Statement::Reset(ResetStatement::Variable(variable)))
     }
 
+    /// Parse a SQL `REFRESH MATERIALIZED VIEW`
+    pub fn parse_refresh(&mut self) -> Result<Statement, DataFusionError> {
+        self.parser
+            .expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+        let name = self.parser.parse_object_name(false)?;
+        Ok(Statement::RefreshMaterializedView(
+            RefreshMaterializedViewStatement { name },
+        ))
+    }
+
     pub fn parse_explain_format(&mut self) -> Result<Option<String>, DataFusionError> {
         if !self.parser.parse_keyword(Keyword::FORMAT) {
             return Ok(None);
@@ -1153,6 +1318,139 @@ impl<'a> DFParser<'a> {
     }
 }
 
+/// Removes the `SEARCH` and `CYCLE` clauses of recursive CTEs from `tokens`,
+/// and returns the remaining tokens with the removed clauses.
+///
+/// The clauses follow the closing parenthesis of a CTE query, i.e. of a
+/// parenthesis opened right after `AS` or `MATERIALIZED`, which keeps them
+/// apart from columns or tables named `search` or `cycle`.
+fn extract_recursive_cte_clauses(
+    dialect: &dyn Dialect,
+    tokens: &[TokenWithSpan],
+) -> Result<(Vec<TokenWithSpan>, Vec<RecursiveCteClauses>), DataFusionError> {
+    let mut remaining = Vec::with_capacity(tokens.len());
+    let mut cte_clauses = vec![];
+    // Whether the unmatched opening parentheses start a CTE query
+    let mut open_parens = vec![];
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = tokens[index].clone();
+        index += 1;
+        match &token.token {
+            Token::LParen => {
+                let after_as = remaining
+                    .iter()
+                    .rev()
+                    .find(|t: &&TokenWithSpan| !matches!(t.token, Token::Whitespace(_)))
+                    .is_some_and(|t| {
+                        matches!(
+                            &t.token,
+                            Token::Word(w) if matches!(w.keyword, Keyword::AS | Keyword::MATERIALIZED)
+                        )
+                    });
+                open_parens.push(after_as);
+            }
+            Token::RParen if open_parens.pop() == Some(true) => {
+                let location = token.span.start;
+                remaining.push(token);
+                let mut parser = Parser::new(dialect)
+                    .with_tokens_with_locations(tokens[index..].to_vec());
+                if let Some(clauses) = parse_recursive_cte_clauses(&mut parser, location)?
+                {
+                    cte_clauses.push(clauses);
+                    index += parser.index();
+                }
+                continue;
+            }
+            _ => {}
+        }
+        remaining.push(token);
+    }
+    Ok((remaining, cte_clauses))
+}
+
+/// Parses the `SEARCH` and `CYCLE` clauses of the CTE whose query ends at
+/// `location`, if `parser` starts with them
+fn parse_recursive_cte_clauses(
+    parser: &mut Parser,
+    location: Location,
+) -> Result<Option<RecursiveCteClauses>, DataFusionError> {
+    let mut clauses = RecursiveCteClauses {
+        location,
+        search: None,
+        cycle: None,
+    };
+    loop {
+        if clauses.search.is_none() && parse_word(parser, "SEARCH") {
+            let breadth_first = if parse_word(parser, "BREADTH") {
+                true
+            } else {
+                expect_word(parser, "DEPTH")?;
+                false
+            };
+            expect_word(parser, "FIRST")?;
+            expect_word(parser, "BY")?;
+            let columns = parser.parse_comma_separated(Parser::parse_identifier)?;
+            expect_word(parser, "SET")?;
+            clauses.search = Some(CteSearchClause {
+                breadth_first,
+                columns,
+                sequence_column: parser.parse_identifier()?,
+            });
+        } else if clauses.cycle.is_none() && parse_word(parser, "CYCLE") {
+            let columns = parser.parse_comma_separated(Parser::parse_identifier)?;
+            expect_word(parser, "SET")?;
+            let mark_column = parser.parse_identifier()?;
+            let mark_values = if parse_word(parser, "TO") {
+                let mark_value = parser.parse_expr()?;
+                expect_word(parser, "DEFAULT")?;
+                Some((mark_value, parser.parse_expr()?))
+            } else {
+                None
+            };
+            expect_word(parser, "USING")?;
+            clauses.cycle = Some(CteCycleClause {
+                columns,
+                mark_column,
+                mark_values,
+                path_column: parser.parse_identifier()?,
+            });
+        } else {
+            break;
+        }
+    }
+
+    if clauses.search.is_none() && clauses.cycle.is_none() {
+        Ok(None)
+    } else {
+        Ok(Some(clauses))
+    }
+}
+
+/// Consumes the next token if it is the (unquoted) word `word`
+fn parse_word(parser: &mut Parser, word: &str) -> bool {
+    let is_word = matches!(
+        &parser.peek_token_ref().token,
+        Token::Word(w) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(word)
+    );
+    if is_word {
+        parser.next_token();
+    }
+    is_word
+}
+
+/// Consumes the next token, which must be the (unquoted) word `word`
+fn expect_word(parser: &mut Parser, word: &str) -> Result<(), DataFusionError> {
+    if parse_word(parser, word) {
+        return Ok(());
+    }
+    let found = parser.peek_token();
+    parser_err!(format!(
+        "Expected: {word}, found: {found}{}",
+        found.span.start
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2047,6 +2345,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn refresh_materialized_view() -> Result<(), DataFusionError> {
+        let sql = "REFRESH MATERIALIZED VIEW foo.bar";
+        let expected =
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStatement {
+                name: ObjectName::from(vec![Ident::new("foo"), Ident::new("bar")]),
+            });
+        expect_parse_ok(sql, expected)?;
+        verified_stmt(sql);
+
+        expect_parse_error("REFRESH VIEW foo", "Expected: MATERIALIZED, found: VIEW");
+        Ok(())
+    }
+
+    #[test]
+    fn recursive_cte_clauses() -> Result<(), DataFusionError> {
+        let sql = "WITH RECURSIVE t AS (SELECT 1 AS n UNION ALL SELECT n + 1 FROM t) \
+            SEARCH BREADTH FIRST BY n SET seq \
+            CYCLE n SET is_cycle TO 'Y' DEFAULT 'N' USING path \
+            SELECT * FROM t";
+        let mut statements = DFParser::parse_sql(sql)?;
+        assert_eq!(statements.len(), 1);
+        let Statement::StatementWithCteClauses(statement) =
+            statements.pop_front().unwrap()
+        else {
+            panic!("Expected a statement with CTE clauses");
+        };
+        assert_eq!(
+            statement.statement.to_string(),
+            "WITH RECURSIVE t AS (SELECT 1 AS n UNION ALL SELECT n + 1 FROM t) \
+            SELECT * FROM t"
+        );
+        let [clauses] = statement.cte_clauses.as_slice() else {
+            panic!("Expected the clauses of a single CTE");
+        };
+        assert_eq!(
+            clauses.to_string(),
+            "SEARCH BREADTH FIRST BY n SET seq \
+            CYCLE n SET is_cycle TO 'Y' DEFAULT 'N' USING path"
+        );
+
+        // Parentheses that do not close a CTE query are left alone
+        let sql = "WITH RECURSIVE t AS (SELECT (1) AS n) SELECT (n) FROM t";
+        let statements = DFParser::parse_sql(sql)?;
+        assert!(matches!(statements[0], Statement::Statement(_)));
+
+        expect_parse_error(
+            "WITH RECURSIVE t AS (SELECT 1 AS n) SEARCH FIRST BY n SET seq SELECT 1",
+            "Expected: DEPTH, found: FIRST",
+        );
+        Ok(())
+    }
+
     #[test]
     fn literal() {
         expect_parse_expr_ok(
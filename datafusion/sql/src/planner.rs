@@ -21,6 +21,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::vec;
 
+use crate::parser::RecursiveCteClauses;
 use crate::utils::make_decimal_type;
 use arrow::datatypes::*;
 use datafusion_common::TableReference;
@@ -39,6 +40,7 @@ use datafusion_expr::{Expr, col};
 use sqlparser::ast::{ArrayElemTypeDef, ExactNumberInfo, TimezoneInfo};
 use sqlparser::ast::{ColumnDef as SQLColumnDef, ColumnOption};
 use sqlparser::ast::{DataType as SQLDataType, Ident, ObjectName, TableAlias};
+use sqlparser::tokenizer::Location;
 
 /// SQL parser options
 #[derive(Debug, Clone, Copy)]
@@ -268,6 +270,8 @@ pub struct PlannerContext {
     outer_from_schema: Option<DFSchemaRef>,
     /// The query schema defined by the table
     create_table_schema: Option<DFSchemaRef>,
+    /// The `SEARCH` and `CYCLE` clauses of the recursive CTEs of the statement
+    recursive_cte_clauses: Arc<Vec<RecursiveCteClauses>>,
 }
 
 impl Default for PlannerContext {
@@ -285,6 +289,7 @@ impl PlannerContext {
             outer_query_schema: None,
             outer_from_schema: None,
             create_table_schema: None,
+            recursive_cte_clauses: Arc::new(vec![]),
         }
     }
 
@@ -297,6 +302,27 @@ impl PlannerContext {
         self
     }
 
+    /// Update the PlannerContext with the `SEARCH` and `CYCLE` clauses of the
+    /// recursive CTEs of the statement
+    pub fn with_recursive_cte_clauses(
+        mut self,
+        recursive_cte_clauses: Vec<RecursiveCteClauses>,
+    ) -> Self {
+        self.recursive_cte_clauses = recursive_cte_clauses.into();
+        self
+    }
+
+    /// Return the `SEARCH` and `CYCLE` clauses of the recursive CTE whose
+    /// query ends at `location`
+    pub fn recursive_cte_clauses(
+        &self,
+        location: Location,
+    ) -> Option<&RecursiveCteClauses> {
+        self.recursive_cte_clauses
+            .iter()
+            .find(|clauses| clauses.location == location)
+    }
+
     // Return a reference to the outer query's schema
     pub fn outer_query_schema(&self) -> Option<&DFSchema> {
         self.outer_query_schema.as_ref().map(|s| s.as_ref())
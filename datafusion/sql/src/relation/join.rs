@@ -19,7 +19,8 @@ use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use datafusion_common::{Column, Result, not_impl_err, plan_datafusion_err};
 use datafusion_expr::{JoinType, LogicalPlan, LogicalPlanBuilder};
 use sqlparser::ast::{
-    Join, JoinConstraint, JoinOperator, ObjectName, TableFactor, TableWithJoins,
+    Expr as SQLExpr, Join, JoinConstraint, JoinOperator, ObjectName, TableFactor,
+    TableWithJoins,
 };
 use std::collections::HashSet;
 
@@ -98,6 +99,16 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             JoinOperator::CrossJoin(JoinConstraint::None) => {
                 self.parse_cross_join(left, right)
             }
+            JoinOperator::AsOf {
+                match_condition,
+                constraint,
+            } => self.parse_asof_join(
+                left,
+                right,
+                match_condition,
+                constraint,
+                planner_context,
+            ),
             other => not_impl_err!("Unsupported JOIN operator {other:?}"),
         }
     }
@@ -128,27 +139,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     .build()
             }
             JoinConstraint::Using(object_names) => {
-                let keys = object_names
-                    .into_iter()
-                    .map(|object_name| {
-                        let ObjectName(mut object_names) = object_name;
-                        if object_names.len() != 1 {
-                            not_impl_err!(
-                                "Invalid identifier in USING clause. Expected single identifier, got {}", ObjectName(object_names)
-                            )
-                        } else {
-                            let id = object_names.swap_remove(0);
-                            id.as_ident()
-                                .ok_or_else(|| {
-                                    plan_datafusion_err!(
-                                        "Expected identifier in USING clause"
-                                    )
-                                })
-                                .map(|ident| Column::from_name(self.ident_normalizer.normalize(ident.clone())))
-                        }
-                    })
-                    .collect::<Result<Vec<_>>>()?;
-
+                let keys = self.parse_using_columns(object_names)?;
                 LogicalPlanBuilder::from(left)
                     .join_using(right, join_type, keys)?
                     .build()
@@ -177,6 +168,68 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 .build(),
         }
     }
+
+    /// Plans `left ASOF JOIN right MATCH_CONDITION (...) [ON ... | USING (...)]`.
+    ///
+    /// As in Snowflake, every row of `left` is kept and joined with the
+    /// closest matching row of `right`, if any. This matches DuckDB's
+    /// `ASOF LEFT JOIN`; DuckDB's plain `ASOF JOIN` is an inner join instead.
+    fn parse_asof_join(
+        &self,
+        left: LogicalPlan,
+        right: LogicalPlan,
+        match_condition: SQLExpr,
+        constraint: JoinConstraint,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let join_schema = left.schema().join(right.schema())?;
+        let match_expr =
+            self.sql_to_expr(match_condition, &join_schema, planner_context)?;
+        let builder = LogicalPlanBuilder::from(left);
+        match constraint {
+            JoinConstraint::On(sql_expr) => {
+                let expr = self.sql_to_expr(sql_expr, &join_schema, planner_context)?;
+                builder.asof_join(right, JoinType::Left, match_expr, [expr])?
+            }
+            JoinConstraint::Using(object_names) => {
+                let keys = self.parse_using_columns(object_names)?;
+                builder.asof_join_using(right, JoinType::Left, match_expr, keys)?
+            }
+            JoinConstraint::None => {
+                builder.asof_join(right, JoinType::Left, match_expr, [])?
+            }
+            JoinConstraint::Natural => {
+                return not_impl_err!("NATURAL is not supported for ASOF JOIN");
+            }
+        }
+        .build()
+    }
+
+    fn parse_using_columns(&self, object_names: Vec<ObjectName>) -> Result<Vec<Column>> {
+        object_names
+            .into_iter()
+            .map(|object_name| {
+                let ObjectName(mut object_names) = object_name;
+                if object_names.len() != 1 {
+                    not_impl_err!(
+                        "Invalid identifier in USING clause. Expected single identifier, got {}",
+                        ObjectName(object_names)
+                    )
+                } else {
+                    let id = object_names.swap_remove(0);
+                    id.as_ident()
+                        .ok_or_else(|| {
+                            plan_datafusion_err!("Expected identifier in USING clause")
+                        })
+                        .map(|ident| {
+                            Column::from_name(
+                                self.ident_normalizer.normalize(ident.clone()),
+                            )
+                        })
+                }
+            })
+            .collect()
+    }
 }
 
 /// Return `true` iff the given [`TableFactor`] is lateral.
@@ -0,0 +1,653 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use crate::planner::{ContextProvider, IdentNormalizer, PlannerContext, SqlToRel};
+use crate::utils::normalize_ident;
+use arrow::datatypes::DataType;
+use datafusion_common::{
+    AfterMatchSkip, Column, DataFusionError, EmptyMatchesMode, MatchFunction, Result,
+    RowPattern, RowsPerMatch, not_impl_err, plan_err,
+};
+use datafusion_expr::expr::WindowFunction;
+use datafusion_expr::{
+    Expr, ExprSchemable, LogicalPlan, LogicalPlanBuilder, MatchMeasure, MatchRecognize,
+    SortExpr, WindowFunctionDefinition, lit,
+};
+use sqlparser::ast::{
+    self, Expr as SQLExpr, Function, FunctionArg, FunctionArgExpr, FunctionArguments,
+    Ident, MatchRecognizePattern, MatchRecognizeSymbol, Measure, OrderByExpr,
+    RepetitionQuantifier, SymbolDefinition, TableFactor, VisitMut, VisitorMut,
+};
+
+/// Prefix of the columns computed by `PREV` and `NEXT` in `DEFINE`
+const NAVIGATION_PREFIX: &str = "__match_recognize_prev_";
+/// Prefix of the columns computed for the measures of `MEASURES`
+const MEASURE_PREFIX: &str = "__match_recognize_value_";
+
+impl<S: ContextProvider> SqlToRel<'_, S> {
+    /// Plan `table MATCH_RECOGNIZE (...)`.
+    ///
+    /// The conditions of `DEFINE` may look at other rows of the partition
+    /// with `PREV(expr [, offset])` and `NEXT(expr [, offset])`, which are
+    /// planned as `lag` and `lead` window functions over the partitions of
+    /// the `MATCH_RECOGNIZE`, evaluated before matching.
+    ///
+    /// Every function of a pattern variable in `MEASURES`, such as
+    /// `FIRST(A.price)`, `COUNT(A.*)` or a column reference, which is the
+    /// value at the last row, becomes a [`MatchMeasure`] of the
+    /// [`MatchRecognize`] plan, and the measures are then computed from them
+    /// by a projection:
+    ///
+    /// ```text
+    /// MEASURES LAST(B.price) - FIRST(A.price) AS gain
+    /// ```
+    ///
+    /// is planned as
+    ///
+    /// ```text
+    /// Projection: __match_recognize_value_0 - __match_recognize_value_1 AS gain
+    ///   MatchRecognize: measures=[LAST(B.price) AS __match_recognize_value_0,
+    ///                             FIRST(A.price) AS __match_recognize_value_1]
+    /// ```
+    #[expect(clippy::too_many_arguments)]
+    pub(super) fn plan_match_recognize(
+        &self,
+        table: TableFactor,
+        partition_by: Vec<SQLExpr>,
+        order_by: Vec<OrderByExpr>,
+        measures: Vec<Measure>,
+        rows_per_match: Option<ast::RowsPerMatch>,
+        after_match_skip: Option<ast::AfterMatchSkip>,
+        pattern: MatchRecognizePattern,
+        definitions: Vec<SymbolDefinition>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let input = self.create_relation(table, planner_context)?;
+        let input_schema = Arc::clone(input.schema());
+
+        let partition_by = partition_by
+            .into_iter()
+            .map(|expr| self.sql_to_expr(expr, &input_schema, planner_context))
+            .collect::<Result<Vec<_>>>()?;
+        let order_by = self.order_by_to_sort_expr(
+            order_by,
+            &input_schema,
+            planner_context,
+            true,
+            None,
+        )?;
+
+        let pattern = self.row_pattern(pattern)?;
+        let symbols = pattern
+            .symbols()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        // Rewrite `DEFINE` before planning it, as `PREV` and `NEXT` refer to
+        // columns computed by window functions
+        let mut navigations = vec![];
+        let mut sql_definitions = vec![None; symbols.len()];
+        for SymbolDefinition {
+            symbol,
+            mut definition,
+        } in definitions
+        {
+            let symbol = self.ident_normalizer.normalize(symbol);
+            let Some(index) = symbols.iter().position(|s| *s == symbol) else {
+                return plan_err!(
+                    "Pattern variable {symbol} is defined but not used in PATTERN"
+                );
+            };
+            if sql_definitions[index].is_some() {
+                return plan_err!("Pattern variable {symbol} is defined more than once");
+            }
+            let mut rewriter = DefineRewriter {
+                symbol: &symbol,
+                symbols: &symbols,
+                normalizer: &self.ident_normalizer,
+                navigations: &mut navigations,
+                in_navigation: false,
+            };
+            if let ControlFlow::Break(e) = definition.visit(&mut rewriter) {
+                return Err(e);
+            }
+            sql_definitions[index] = Some(definition);
+        }
+
+        let input = self.plan_navigations(
+            input,
+            navigations,
+            &partition_by,
+            &order_by,
+            planner_context,
+        )?;
+        let schema = Arc::clone(input.schema());
+
+        // A pattern variable without a definition is mapped to every row
+        let definitions = sql_definitions
+            .into_iter()
+            .zip(&symbols)
+            .map(|(definition, symbol)| {
+                let Some(definition) = definition else {
+                    return Ok(lit(true));
+                };
+                let definition =
+                    self.sql_to_expr(definition, &schema, planner_context)?;
+                match definition.get_type(&schema)? {
+                    DataType::Boolean => Ok(definition),
+                    DataType::Null => definition.cast_to(&DataType::Boolean, &schema),
+                    data_type => plan_err!(
+                        "Definition of pattern variable {symbol} must be a boolean, \
+                        got {data_type}"
+                    ),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Rewrite `MEASURES` to refer to the columns of the match measures
+        let mut match_measures = vec![];
+        let mut sql_measures = vec![];
+        for Measure { mut expr, alias } in measures {
+            let mut rewriter = MeasureRewriter {
+                planner: self,
+                symbols: &symbols,
+                measures: &mut match_measures,
+            };
+            if let ControlFlow::Break(e) = expr.visit(&mut rewriter) {
+                return Err(e);
+            }
+            sql_measures.push((expr, self.ident_normalizer.normalize(alias)));
+        }
+        let match_measures = match_measures
+            .into_iter()
+            .enumerate()
+            .map(|(i, measure)| {
+                let arg = measure
+                    .arg
+                    .map(|arg| self.sql_to_expr(arg, &schema, planner_context))
+                    .transpose()?;
+                Ok(MatchMeasure {
+                    function: measure.function,
+                    symbol: measure.symbol,
+                    arg,
+                    name: format!("{MEASURE_PREFIX}{i}"),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let rows_per_match = match rows_per_match {
+            None | Some(ast::RowsPerMatch::OneRow) => RowsPerMatch::OneRow,
+            Some(ast::RowsPerMatch::AllRows(mode)) => RowsPerMatch::AllRows(match mode {
+                None | Some(ast::EmptyMatchesMode::Show) => EmptyMatchesMode::Show,
+                Some(ast::EmptyMatchesMode::Omit) => EmptyMatchesMode::Omit,
+                Some(ast::EmptyMatchesMode::WithUnmatched) => {
+                    EmptyMatchesMode::WithUnmatched
+                }
+            }),
+        };
+        let after_match_skip = match after_match_skip {
+            None | Some(ast::AfterMatchSkip::PastLastRow) => AfterMatchSkip::PastLastRow,
+            Some(ast::AfterMatchSkip::ToNextRow) => AfterMatchSkip::ToNextRow,
+            Some(ast::AfterMatchSkip::ToFirst(symbol)) => {
+                AfterMatchSkip::ToFirst(self.ident_normalizer.normalize(symbol))
+            }
+            Some(ast::AfterMatchSkip::ToLast(symbol)) => {
+                AfterMatchSkip::ToLast(self.ident_normalizer.normalize(symbol))
+            }
+        };
+
+        let num_partition_by = partition_by.len();
+        let plan = LogicalPlan::MatchRecognize(MatchRecognize::try_new(
+            Arc::new(input),
+            partition_by,
+            order_by,
+            symbols,
+            definitions,
+            pattern,
+            match_measures,
+            rows_per_match,
+            after_match_skip,
+        )?);
+
+        // Compute the measures from the match measures, after the partition
+        // keys or the input columns, leaving out the columns of `PREV` and
+        // `NEXT`
+        let num_columns = match rows_per_match {
+            RowsPerMatch::OneRow => num_partition_by,
+            RowsPerMatch::AllRows(_) => input_schema.fields().len(),
+        };
+        let schema = Arc::clone(plan.schema());
+        let mut exprs = schema
+            .iter()
+            .take(num_columns)
+            .map(|(qualifier, field)| Expr::Column(Column::from((qualifier, field))))
+            .collect::<Vec<_>>();
+        for (expr, alias) in sql_measures {
+            exprs.push(
+                self.sql_to_expr(expr, &schema, planner_context)?
+                    .alias(alias),
+            );
+        }
+        LogicalPlanBuilder::from(plan).project(exprs)?.build()
+    }
+
+    /// Adds the `lag` and `lead` window functions computing `PREV` and
+    /// `NEXT` to the input, as columns named after the navigations
+    fn plan_navigations(
+        &self,
+        input: LogicalPlan,
+        navigations: Vec<Navigation>,
+        partition_by: &[Expr],
+        order_by: &[SortExpr],
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        if navigations.is_empty() {
+            return Ok(input);
+        }
+        let schema = Arc::clone(input.schema());
+        let window_exprs = navigations
+            .into_iter()
+            .map(|Navigation { function, args }| {
+                let Some(udwf) = self.context_provider.get_window_meta(function) else {
+                    return plan_err!(
+                        "MATCH_RECOGNIZE requires the window function {function}"
+                    );
+                };
+                let args = args
+                    .into_iter()
+                    .map(|arg| self.sql_to_expr(arg, &schema, planner_context))
+                    .collect::<Result<Vec<_>>>()?;
+                let mut window_function =
+                    WindowFunction::new(WindowFunctionDefinition::WindowUDF(udwf), args);
+                window_function.params.partition_by = partition_by.to_vec();
+                window_function.params.order_by = order_by.to_vec();
+                Ok(Expr::from(window_function))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut exprs = schema
+            .iter()
+            .map(|(qualifier, field)| Expr::Column(Column::from((qualifier, field))))
+            .collect::<Vec<_>>();
+        for (i, window_expr) in window_exprs.iter().enumerate() {
+            let column = Column::from_name(window_expr.schema_name().to_string());
+            exprs.push(Expr::Column(column).alias(format!("{NAVIGATION_PREFIX}{i}")));
+        }
+        let plan = LogicalPlanBuilder::window_plan(input, window_exprs)?;
+        LogicalPlanBuilder::from(plan).project(exprs)?.build()
+    }
+
+    fn row_pattern(&self, pattern: MatchRecognizePattern) -> Result<RowPattern> {
+        let row_patterns = |patterns: Vec<MatchRecognizePattern>| {
+            patterns
+                .into_iter()
+                .map(|pattern| self.row_pattern(pattern))
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(match pattern {
+            MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Named(symbol)) => {
+                RowPattern::Symbol(self.ident_normalizer.normalize(symbol))
+            }
+            MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Start) => {
+                RowPattern::Start
+            }
+            MatchRecognizePattern::Symbol(MatchRecognizeSymbol::End) => RowPattern::End,
+            MatchRecognizePattern::Exclude(_) => {
+                return not_impl_err!(
+                    "Excluding rows from the output with {{- -}} is not supported"
+                );
+            }
+            MatchRecognizePattern::Permute(_) => {
+                return not_impl_err!("PERMUTE in PATTERN is not supported");
+            }
+            MatchRecognizePattern::Concat(patterns) => {
+                RowPattern::Concat(row_patterns(patterns)?)
+            }
+            MatchRecognizePattern::Group(pattern) => self.row_pattern(*pattern)?,
+            MatchRecognizePattern::Alternation(patterns) => {
+                RowPattern::Alternation(row_patterns(patterns)?)
+            }
+            MatchRecognizePattern::Repetition(pattern, quantifier) => {
+                let (min, max) = match quantifier {
+                    RepetitionQuantifier::ZeroOrMore => (0, None),
+                    RepetitionQuantifier::OneOrMore => (1, None),
+                    RepetitionQuantifier::AtMostOne => (0, Some(1)),
+                    RepetitionQuantifier::Exactly(n) => (n, Some(n)),
+                    RepetitionQuantifier::AtLeast(n) => (n, None),
+                    RepetitionQuantifier::AtMost(n) => (0, Some(n)),
+                    RepetitionQuantifier::Range(min, max) => {
+                        if max < min {
+                            return plan_err!(
+                                "Invalid quantifier {{{min},{max}}} in PATTERN"
+                            );
+                        }
+                        (min, Some(max))
+                    }
+                };
+                RowPattern::Repetition {
+                    pattern: Box::new(self.row_pattern(*pattern)?),
+                    min,
+                    max,
+                }
+            }
+        })
+    }
+}
+
+/// A `PREV` or `NEXT` of `DEFINE`, planned as a `lag` or `lead` window
+/// function with the same arguments
+struct Navigation {
+    function: &'static str,
+    args: Vec<SQLExpr>,
+}
+
+/// Rewrites the definition of a pattern variable: replaces `PREV` and
+/// `NEXT` with the columns computing them, and removes the pattern variable
+/// from column references qualified with it, such as `A.price` in the
+/// definition of `A`
+struct DefineRewriter<'a> {
+    symbol: &'a str,
+    symbols: &'a [String],
+    normalizer: &'a IdentNormalizer,
+    navigations: &'a mut Vec<Navigation>,
+    /// Whether the rewritten expression is an argument of `PREV` or `NEXT`
+    in_navigation: bool,
+}
+
+impl VisitorMut for DefineRewriter<'_> {
+    type Break = DataFusionError;
+
+    fn pre_visit_expr(&mut self, expr: &mut SQLExpr) -> ControlFlow<Self::Break> {
+        match self.rewrite(expr) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => ControlFlow::Break(e),
+        }
+    }
+}
+
+impl DefineRewriter<'_> {
+    fn rewrite(&mut self, expr: &mut SQLExpr) -> Result<()> {
+        match expr {
+            SQLExpr::Function(function) => {
+                let Some(name) = function_name(function) else {
+                    return Ok(());
+                };
+                let window_function = match name.as_str() {
+                    "prev" => "lag",
+                    "next" => "lead",
+                    "first" | "last" | "classifier" | "match_number" => {
+                        return not_impl_err!(
+                            "{} in DEFINE is not supported",
+                            name.to_uppercase()
+                        );
+                    }
+                    _ => return Ok(()),
+                };
+                if self.in_navigation {
+                    return not_impl_err!(
+                        "Nested {} in DEFINE is not supported",
+                        name.to_uppercase()
+                    );
+                }
+                let mut args = unnamed_args(function)?;
+                if args.is_empty() || args.len() > 2 {
+                    return plan_err!(
+                        "{} requires one or two arguments",
+                        name.to_uppercase()
+                    );
+                }
+                self.in_navigation = true;
+                let visited = args[0].visit(self);
+                self.in_navigation = false;
+                if let ControlFlow::Break(e) = visited {
+                    return Err(e);
+                }
+
+                let column = format!("{NAVIGATION_PREFIX}{}", self.navigations.len());
+                self.navigations.push(Navigation {
+                    function: window_function,
+                    args,
+                });
+                *expr = SQLExpr::Identifier(Ident::new(column));
+            }
+            SQLExpr::CompoundIdentifier(idents) if idents.len() > 1 => {
+                let qualifier = self.normalizer.normalize(idents[0].clone());
+                if qualifier == self.symbol {
+                    idents.remove(0);
+                    if let [ident] = idents.as_slice() {
+                        *expr = SQLExpr::Identifier(ident.clone());
+                    }
+                } else if self.symbols.contains(&qualifier) {
+                    return not_impl_err!(
+                        "Definition of pattern variable {} referring to pattern \
+                        variable {qualifier} is not supported",
+                        self.symbol
+                    );
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// A measure of `MEASURES` before its argument is planned
+struct SqlMatchMeasure {
+    function: MatchFunction,
+    symbol: Option<String>,
+    arg: Option<SQLExpr>,
+}
+
+/// Rewrites a measure of `MEASURES`, replacing every function of the rows
+/// of a match with the column of a [`SqlMatchMeasure`]
+struct MeasureRewriter<'a, 'b, S: ContextProvider> {
+    planner: &'a SqlToRel<'b, S>,
+    symbols: &'a [String],
+    measures: &'a mut Vec<SqlMatchMeasure>,
+}
+
+impl<S: ContextProvider> VisitorMut for MeasureRewriter<'_, '_, S> {
+    type Break = DataFusionError;
+
+    fn pre_visit_expr(&mut self, expr: &mut SQLExpr) -> ControlFlow<Self::Break> {
+        match self.rewrite(expr) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(e) => ControlFlow::Break(e),
+        }
+    }
+}
+
+impl<S: ContextProvider> MeasureRewriter<'_, '_, S> {
+    fn rewrite(&mut self, expr: &mut SQLExpr) -> Result<()> {
+        let measure = match expr {
+            SQLExpr::Function(function) => {
+                let Some(name) = function_name(function) else {
+                    return Ok(());
+                };
+                match name.as_str() {
+                    "first" | "last" => {
+                        let Ok([mut arg]) =
+                            <[SQLExpr; 1]>::try_from(unnamed_args(function)?)
+                        else {
+                            return plan_err!(
+                                "{} requires one argument",
+                                name.to_uppercase()
+                            );
+                        };
+                        let symbol = self.remove_symbol(&mut arg)?;
+                        SqlMatchMeasure {
+                            function: if name == "first" {
+                                MatchFunction::First
+                            } else {
+                                MatchFunction::Last
+                            },
+                            symbol,
+                            arg: Some(arg),
+                        }
+                    }
+                    "count" => SqlMatchMeasure {
+                        function: MatchFunction::Count,
+                        symbol: self.count_symbol(function)?,
+                        arg: None,
+                    },
+                    "match_number" | "classifier" => {
+                        if !unnamed_args(function)?.is_empty() {
+                            return plan_err!(
+                                "{} takes no arguments",
+                                name.to_uppercase()
+                            );
+                        }
+                        SqlMatchMeasure {
+                            function: if name == "match_number" {
+                                MatchFunction::MatchNumber
+                            } else {
+                                MatchFunction::Classifier
+                            },
+                            symbol: None,
+                            arg: None,
+                        }
+                    }
+                    _ if self
+                        .planner
+                        .context_provider
+                        .get_aggregate_meta(&name)
+                        .is_some() =>
+                    {
+                        return not_impl_err!(
+                            "Aggregate function {name} in MEASURES is not supported"
+                        );
+                    }
+                    _ => return Ok(()),
+                }
+            }
+            // A column outside of a function is the value at the last row
+            SQLExpr::Identifier(_) | SQLExpr::CompoundIdentifier(_) => {
+                let mut arg = expr.clone();
+                let symbol = self.remove_symbol(&mut arg)?;
+                SqlMatchMeasure {
+                    function: MatchFunction::Last,
+                    symbol,
+                    arg: Some(arg),
+                }
+            }
+            _ => return Ok(()),
+        };
+
+        let column = format!("{MEASURE_PREFIX}{}", self.measures.len());
+        self.measures.push(measure);
+        *expr = SQLExpr::Identifier(Ident::new(column));
+        Ok(())
+    }
+
+    /// Removes the pattern variable from the column references of the
+    /// argument of a measure, and returns it
+    fn remove_symbol(&self, arg: &mut SQLExpr) -> Result<Option<String>> {
+        let mut symbol: Option<String> = None;
+        let visited = ast::visit_expressions_mut(arg, |expr| {
+            let SQLExpr::CompoundIdentifier(idents) = expr else {
+                return ControlFlow::Continue(());
+            };
+            if idents.len() < 2 {
+                return ControlFlow::Continue(());
+            }
+            let qualifier = self.planner.ident_normalizer.normalize(idents[0].clone());
+            if !self.symbols.contains(&qualifier) {
+                return ControlFlow::Continue(());
+            }
+            if symbol.as_ref().is_some_and(|s| *s != qualifier) {
+                return ControlFlow::Break(qualifier);
+            }
+            symbol = Some(qualifier);
+            idents.remove(0);
+            if let [ident] = idents.as_slice() {
+                *expr = SQLExpr::Identifier(ident.clone());
+            }
+            ControlFlow::Continue(())
+        });
+        if let ControlFlow::Break(other) = visited {
+            return plan_err!(
+                "A measure cannot refer to both pattern variables {} and {other}",
+                symbol.unwrap_or_default()
+            );
+        }
+        Ok(symbol)
+    }
+
+    /// Returns the pattern variable of `COUNT(*)` or `COUNT(symbol.*)`
+    fn count_symbol(&self, function: &Function) -> Result<Option<String>> {
+        if let FunctionArguments::List(list) = &function.args
+            && list.duplicate_treatment.is_none()
+            && list.clauses.is_empty()
+        {
+            match list.args.as_slice() {
+                [FunctionArg::Unnamed(FunctionArgExpr::Wildcard)] => return Ok(None),
+                [FunctionArg::Unnamed(FunctionArgExpr::QualifiedWildcard(name))] => {
+                    if let [part] = name.0.as_slice()
+                        && let Some(ident) = part.as_ident()
+                    {
+                        let symbol =
+                            self.planner.ident_normalizer.normalize(ident.clone());
+                        if self.symbols.contains(&symbol) {
+                            return Ok(Some(symbol));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        not_impl_err!(
+            "COUNT in MEASURES is only supported as COUNT(*) or COUNT(<pattern variable>.*)"
+        )
+    }
+}
+
+/// Returns the normalized name of a function, unless it is qualified
+fn function_name(function: &Function) -> Option<String> {
+    match function.name.0.as_slice() {
+        [part] => part.as_ident().map(|ident| normalize_ident(ident.clone())),
+        _ => None,
+    }
+}
+
+/// Returns the arguments of a function, which must be unnamed expressions
+fn unnamed_args(function: &Function) -> Result<Vec<SQLExpr>> {
+    let args = match &function.args {
+        FunctionArguments::None => return Ok(vec![]),
+        FunctionArguments::List(list)
+            if list.duplicate_treatment.is_none() && list.clauses.is_empty() =>
+        {
+            &list.args
+        }
+        _ => {
+            return not_impl_err!(
+                "Unsupported arguments of {function} in MATCH_RECOGNIZE"
+            );
+        }
+    };
+    args.iter()
+        .map(|arg| match arg {
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => Ok(expr.clone()),
+            _ => not_impl_err!(
+                "Unsupported argument {arg} of {function} in MATCH_RECOGNIZE"
+            ),
+        })
+        .collect()
+}
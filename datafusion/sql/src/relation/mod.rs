@@ -21,17 +21,21 @@ use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 
 use datafusion_common::tree_node::{Transformed, TreeNode};
 use datafusion_common::{
-    DFSchema, Diagnostic, Result, Span, Spans, TableReference, not_impl_err, plan_err,
+    DFSchema, Diagnostic, Result, Span, Spans, TableReference, internal_datafusion_err,
+    internal_err, not_impl_err, plan_err,
 };
 use datafusion_expr::builder::subquery_alias;
 use datafusion_expr::planner::{
     PlannedRelation, RelationPlannerContext, RelationPlanning,
 };
-use datafusion_expr::{Expr, LogicalPlan, LogicalPlanBuilder, expr::Unnest};
+use datafusion_expr::{Expr, LogicalPlan, LogicalPlanBuilder, Projection, expr::Unnest};
 use datafusion_expr::{Subquery, SubqueryAlias};
 use sqlparser::ast::{FunctionArg, FunctionArgExpr, Spanned, TableFactor};
 
 mod join;
+mod match_recognize;
+pub(crate) mod pivot;
+mod sample;
 
 struct SqlToRelRelationContext<'a, 'b, S: ContextProvider> {
     planner: &'a SqlToRel<'b, S>,
@@ -84,7 +88,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
     ///
     /// First tries any registered extension planners. If no extension handles
     /// the relation, falls back to the default planner.
-    fn create_relation(
+    pub(crate) fn create_relation(
         &self,
         relation: TableFactor,
         planner_context: &mut PlannerContext,
@@ -137,9 +141,19 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
 
     fn create_default_relation(
         &self,
-        relation: TableFactor,
+        mut relation: TableFactor,
         planner_context: &mut PlannerContext,
     ) -> Result<PlannedRelation> {
+        // TABLESAMPLE is applied to the table before the alias
+        if let TableFactor::Table { sample, .. } = &mut relation
+            && let Some(sample) = sample.take()
+        {
+            let mut planned = self.create_default_relation(relation, planner_context)?;
+            planned.plan =
+                self.plan_table_sample(planned.plan, sample, planner_context)?;
+            return Ok(planned);
+        }
+
         let relation_span = relation.span();
         let (plan, alias) = match relation {
             TableFactor::Table {
@@ -225,10 +239,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 with_offset_alias: None,
                 with_ordinality,
             } => {
-                if with_ordinality {
-                    return not_impl_err!("UNNEST with ordinality is not supported yet");
-                }
-
                 // Unnest table factor has empty input
                 let schema = DFSchema::empty();
                 let input = LogicalPlanBuilder::empty(true).build()?;
@@ -249,7 +259,10 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 if unnest_exprs.is_empty() {
                     return plan_err!("UNNEST must have at least one argument");
                 }
-                let logical_plan = self.try_process_unnest(input, unnest_exprs)?;
+                let mut logical_plan = self.try_process_unnest(input, unnest_exprs)?;
+                if with_ordinality {
+                    logical_plan = add_unnest_ordinality(logical_plan)?;
+                }
                 (logical_plan, alias)
             }
             TableFactor::UNNEST { .. } => {
@@ -286,6 +299,66 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                         .build()?;
                 (plan, alias)
             }
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => (
+                self.plan_pivot(
+                    *table,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null,
+                    planner_context,
+                )?,
+                alias,
+            ),
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                null_inclusion,
+                alias,
+            } => (
+                self.plan_unpivot(
+                    *table,
+                    value,
+                    name,
+                    columns,
+                    null_inclusion.as_ref(),
+                    planner_context,
+                )?,
+                alias,
+            ),
+            TableFactor::MatchRecognize {
+                table,
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                symbols,
+                alias,
+            } => (
+                self.plan_match_recognize(
+                    *table,
+                    partition_by,
+                    order_by,
+                    measures,
+                    rows_per_match,
+                    after_match_skip,
+                    pattern,
+                    symbols,
+                    planner_context,
+                )?,
+                alias,
+            ),
             // @todo Support TableFactory::TableFunction?
             _ => {
                 return not_impl_err!(
@@ -378,3 +451,38 @@ fn optimize_subquery_sort(plan: LogicalPlan) -> Result<Transformed<LogicalPlan>>
         }
     })
 }
+
+/// Adds the `ordinality` column to the output of an `UNNEST` table factor,
+/// which is planned as a projection of the unnested columns.
+fn add_unnest_ordinality(plan: LogicalPlan) -> Result<LogicalPlan> {
+    let LogicalPlan::Projection(Projection { expr, input, .. }) = plan else {
+        return internal_err!("UNNEST table factor should be planned as a projection");
+    };
+    let LogicalPlan::Unnest(unnest) = Arc::unwrap_or_clone(input) else {
+        return internal_err!("UNNEST table factor should project an unnest");
+    };
+    // The position has to be relative to the single input row of the table
+    // factor, which it is not after unnesting multiple times (e.g. a struct
+    // within a list)
+    if let LogicalPlan::Projection(projection) = unnest.input.as_ref()
+        && matches!(projection.input.as_ref(), LogicalPlan::Unnest(_))
+    {
+        return not_impl_err!(
+            "UNNEST WITH ORDINALITY requiring multiple unnest stages is not supported yet"
+        );
+    }
+
+    let options = unnest.options.with_ordinality(true);
+    let unnest = LogicalPlanBuilder::from(Arc::unwrap_or_clone(unnest.input))
+        .unnest_columns_with_options(unnest.exec_columns, options)?
+        .build()?;
+    let LogicalPlan::Unnest(ordinality_unnest) = &unnest else {
+        return internal_err!("Expected an unnest");
+    };
+    let ordinality = ordinality_unnest.ordinality_column().ok_or_else(|| {
+        internal_datafusion_err!("Unnest should have an ordinality column")
+    })?;
+    LogicalPlanBuilder::from(unnest)
+        .project(expr.into_iter().chain([Expr::Column(ordinality)]))?
+        .build()
+}
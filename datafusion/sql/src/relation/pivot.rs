@@ -0,0 +1,258 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use datafusion_common::{Column, Result, ScalarValue, not_impl_err, plan_err};
+use datafusion_expr::utils::expr_to_columns;
+use datafusion_expr::{Expr, LogicalPlan, LogicalPlanBuilder, case, lit, when};
+use sqlparser::ast::{
+    Expr as SQLExpr, ExprWithAlias, Ident, NullInclusion, PivotValueSource, TableFactor,
+};
+
+impl<S: ContextProvider> SqlToRel<'_, S> {
+    /// Plan `table PIVOT (agg(x) FOR c IN (v1, v2, ...))`.
+    ///
+    /// The input is grouped by all of its columns that are referenced neither
+    /// by the aggregate functions nor by the pivot column, and every
+    /// (aggregate function, value) pair becomes an aggregate that only sees
+    /// the rows of that value:
+    ///
+    /// ```text
+    /// SELECT <group columns>,
+    ///        agg(CASE c WHEN v1 THEN x END) AS v1,
+    ///        agg(CASE c WHEN v2 THEN x END) AS v2
+    /// FROM table
+    /// GROUP BY <group columns>
+    /// ```
+    ///
+    /// With more than one aggregate function, the output columns are named
+    /// `<value>_<alias>`, where the alias defaults to the aggregate function.
+    ///
+    /// The `Unparser` turns this plan back into a `PIVOT` for dialects that
+    /// support it, and into the aggregate query above for the others.
+    pub(super) fn plan_pivot(
+        &self,
+        table: TableFactor,
+        aggregate_functions: Vec<ExprWithAlias>,
+        value_column: Vec<SQLExpr>,
+        value_source: PivotValueSource,
+        default_on_null: Option<SQLExpr>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let input = self.create_relation(table, planner_context)?;
+        let schema = Arc::clone(input.schema());
+
+        let Ok([pivot_column]) = <[SQLExpr; 1]>::try_from(value_column) else {
+            return not_impl_err!("PIVOT on multiple columns is not supported");
+        };
+        let pivot_column = self.sql_to_expr(pivot_column, &schema, planner_context)?;
+
+        let PivotValueSource::List(values) = value_source else {
+            return not_impl_err!("PIVOT is only supported with a list of values");
+        };
+        let values = values
+            .into_iter()
+            .map(|ExprWithAlias { expr, alias }| {
+                let value = self.sql_to_expr(expr, &schema, planner_context)?;
+                let name = match alias {
+                    Some(alias) => self.ident_normalizer.normalize(alias),
+                    None => pivot_value_name(&value),
+                };
+                Ok((value, name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let aggregates = aggregate_functions
+            .into_iter()
+            .map(|ExprWithAlias { expr, alias }| {
+                let expr = self.sql_to_expr(expr, &schema, planner_context)?;
+                let alias = match alias {
+                    Some(alias) => self.ident_normalizer.normalize(alias),
+                    None => expr.schema_name().to_string(),
+                };
+                // e.g. `count(*)` is planned as `count(1) AS "count(*)"`
+                match expr.unalias() {
+                    Expr::AggregateFunction(aggregate) => Ok((aggregate, alias)),
+                    expr => plan_err!("PIVOT expects an aggregate function, got {expr}"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let default_on_null = default_on_null
+            .map(|expr| self.sql_to_expr(expr, &schema, planner_context))
+            .transpose()?;
+
+        // The columns that are neither aggregated nor pivoted form the groups
+        let group_expr: Vec<Expr> = {
+            let mut referenced = HashSet::new();
+            expr_to_columns(&pivot_column, &mut referenced)?;
+            for (aggregate, _) in &aggregates {
+                let aggregate = Expr::AggregateFunction(aggregate.clone());
+                expr_to_columns(&aggregate, &mut referenced)?;
+            }
+            schema
+                .columns()
+                .into_iter()
+                .filter(|column| !referenced.contains(column))
+                .map(Expr::Column)
+                .collect()
+        };
+
+        let mut aggr_expr = Vec::with_capacity(aggregates.len() * values.len());
+        let mut names = Vec::with_capacity(aggregates.len() * values.len());
+        for (aggregate, alias) in &aggregates {
+            for (value, value_name) in &values {
+                let mut aggregate = aggregate.clone();
+                let Some(arg) = aggregate.params.args.first_mut() else {
+                    return plan_err!(
+                        "PIVOT expects an aggregate function with arguments, got {}",
+                        Expr::AggregateFunction(aggregate)
+                    );
+                };
+                // `count(*)` only counts the rows of the value
+                #[expect(deprecated)]
+                let then = match &*arg {
+                    Expr::Wildcard { .. } => lit(1i64),
+                    expr => expr.clone(),
+                };
+                *arg = case(pivot_column.clone()).when(value.clone(), then).end()?;
+                aggr_expr.push(Expr::AggregateFunction(aggregate));
+                names.push(if aggregates.len() == 1 {
+                    value_name.clone()
+                } else {
+                    format!("{value_name}_{alias}")
+                });
+            }
+        }
+
+        let mut projection = group_expr.clone();
+        for (expr, name) in aggr_expr.iter().zip(names) {
+            let column = Expr::Column(Column::from_name(expr.schema_name().to_string()));
+            let column = match &default_on_null {
+                Some(default) => {
+                    when(column.clone().is_null(), default.clone()).otherwise(column)?
+                }
+                None => column,
+            };
+            projection.push(column.alias(name));
+        }
+
+        LogicalPlanBuilder::from(input)
+            .aggregate(group_expr, aggr_expr)?
+            .project(projection)?
+            .build()
+    }
+
+    /// Plan `table UNPIVOT (value FOR name IN (c1, c2, ...))`.
+    ///
+    /// Every unpivoted column contributes one branch to a `UNION ALL`, which
+    /// keeps the remaining columns of the input and adds the column label and
+    /// its value:
+    ///
+    /// ```text
+    /// SELECT <other columns>, 'c1' AS name, c1 AS value FROM table WHERE c1 IS NOT NULL
+    /// UNION ALL
+    /// SELECT <other columns>, 'c2' AS name, c2 AS value FROM table WHERE c2 IS NOT NULL
+    /// ```
+    ///
+    /// The `IS NOT NULL` filters are omitted for `UNPIVOT INCLUDE NULLS`. Like
+    /// `PIVOT`, the plan is unparsed as an `UNPIVOT` for dialects that
+    /// support it, and as the `UNION ALL` query above for the others.
+    pub(super) fn plan_unpivot(
+        &self,
+        table: TableFactor,
+        value: SQLExpr,
+        name: Ident,
+        columns: Vec<ExprWithAlias>,
+        null_inclusion: Option<&NullInclusion>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let input = self.create_relation(table, planner_context)?;
+        let schema = Arc::clone(input.schema());
+
+        let SQLExpr::Identifier(value) = value else {
+            return not_impl_err!("UNPIVOT into multiple value columns is not supported");
+        };
+        let value = self.ident_normalizer.normalize(value);
+        let name = self.ident_normalizer.normalize(name);
+
+        let columns = columns
+            .into_iter()
+            .map(|ExprWithAlias { expr, alias }| {
+                match self.sql_to_expr(expr, &schema, planner_context)? {
+                    Expr::Column(column) => {
+                        let label = match alias {
+                            Some(alias) => self.ident_normalizer.normalize(alias),
+                            None => column.name.clone(),
+                        };
+                        Ok((column, label))
+                    }
+                    expr => plan_err!("UNPIVOT expects a column, got {expr}"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The columns that are not unpivoted are kept in every branch
+        let kept: Vec<Expr> = {
+            let unpivoted: HashSet<&Column> =
+                columns.iter().map(|(column, _)| column).collect();
+            schema
+                .columns()
+                .into_iter()
+                .filter(|column| !unpivoted.contains(column))
+                .map(Expr::Column)
+                .collect()
+        };
+
+        let include_nulls = matches!(null_inclusion, Some(NullInclusion::IncludeNulls));
+        let mut branches = columns.into_iter().map(|(column, label)| {
+            let mut builder = LogicalPlanBuilder::from(input.clone());
+            if !include_nulls {
+                builder = builder.filter(Expr::Column(column.clone()).is_not_null())?;
+            }
+            let projection = kept
+                .iter()
+                .cloned()
+                .chain([lit(label).alias(&name), Expr::Column(column).alias(&value)]);
+            builder.project(projection)?.build()
+        });
+
+        let Some(first) = branches.next() else {
+            return plan_err!("UNPIVOT requires at least one column");
+        };
+        branches.try_fold(first?, |plan, branch| {
+            LogicalPlanBuilder::from(plan).union(branch?)?.build()
+        })
+    }
+}
+
+/// The name of the output column of a PIVOT value without an alias
+pub(crate) fn pivot_value_name(value: &Expr) -> String {
+    match value {
+        Expr::Literal(
+            ScalarValue::Utf8(Some(s))
+            | ScalarValue::Utf8View(Some(s))
+            | ScalarValue::LargeUtf8(Some(s)),
+            _,
+        ) => s.clone(),
+        Expr::Literal(value, _) => value.to_string(),
+        _ => value.schema_name().to_string(),
+    }
+}
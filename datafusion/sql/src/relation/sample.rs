@@ -0,0 +1,140 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use arrow::datatypes::DataType;
+use datafusion_common::{
+    DFSchema, Result, SampleMethod, ScalarValue, not_impl_err, plan_err,
+};
+use datafusion_expr::{Expr, LogicalPlan, LogicalPlanBuilder};
+use sqlparser::ast::{
+    Expr as SQLExpr, TableSample, TableSampleKind, TableSampleMethod, TableSampleUnit,
+    Value,
+};
+
+impl<S: ContextProvider> SqlToRel<'_, S> {
+    /// Plan `TABLESAMPLE <method> (<size> [PERCENT | ROWS]) [REPEATABLE (<seed>)]`,
+    /// where the method is one of `BERNOULLI`, `ROW`, `SYSTEM` or `BLOCK`.
+    ///
+    /// A quantity without a unit is a percentage. Without a method, a
+    /// percentage samples individual rows (`BERNOULLI`).
+    pub(super) fn plan_table_sample(
+        &self,
+        input: LogicalPlan,
+        sample: TableSampleKind,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let (TableSampleKind::BeforeTableAlias(sample)
+        | TableSampleKind::AfterTableAlias(sample)) = sample;
+        let TableSample {
+            name,
+            quantity,
+            seed,
+            bucket,
+            offset,
+            ..
+        } = *sample;
+        if bucket.is_some() {
+            return not_impl_err!("TABLESAMPLE with BUCKET is not supported");
+        }
+        if offset.is_some() {
+            return not_impl_err!("TABLESAMPLE with OFFSET is not supported");
+        }
+        let Some(quantity) = quantity else {
+            return plan_err!("TABLESAMPLE requires a sample size");
+        };
+        let size = self.sample_size(quantity.value, planner_context)?;
+
+        let method = match (name, quantity.unit) {
+            (
+                None | Some(TableSampleMethod::Bernoulli | TableSampleMethod::Row),
+                Some(TableSampleUnit::Rows),
+            ) => {
+                if size < 0.0 || size.fract() != 0.0 {
+                    return plan_err!(
+                        "TABLESAMPLE row count must be a non-negative integer, got {size}"
+                    );
+                }
+                SampleMethod::Rows(size as usize)
+            }
+            (
+                Some(TableSampleMethod::System | TableSampleMethod::Block),
+                Some(TableSampleUnit::Rows),
+            ) => {
+                return not_impl_err!(
+                    "TABLESAMPLE SYSTEM with a number of rows is not supported"
+                );
+            }
+            (name, Some(TableSampleUnit::Percent) | None) => {
+                if !(0.0..=100.0).contains(&size) {
+                    return plan_err!(
+                        "TABLESAMPLE percentage must be between 0 and 100, got {size}"
+                    );
+                }
+                let fraction = size / 100.0;
+                match name {
+                    Some(TableSampleMethod::System | TableSampleMethod::Block) => {
+                        SampleMethod::System(fraction)
+                    }
+                    None
+                    | Some(TableSampleMethod::Bernoulli | TableSampleMethod::Row) => {
+                        SampleMethod::Bernoulli(fraction)
+                    }
+                }
+            }
+        };
+
+        let seed = match seed {
+            Some(seed) => match &seed.value {
+                Value::Number(n, _) => match n.parse::<u64>() {
+                    Ok(seed) => Some(seed),
+                    Err(_) => {
+                        return plan_err!(
+                            "TABLESAMPLE seed must be a non-negative integer, got {n}"
+                        );
+                    }
+                },
+                value => {
+                    return plan_err!(
+                        "TABLESAMPLE seed must be a non-negative integer, got {value}"
+                    );
+                }
+            },
+            None => None,
+        };
+
+        LogicalPlanBuilder::from(input)
+            .sample(method, seed)?
+            .build()
+    }
+
+    /// Evaluate the constant sample size of a `TABLESAMPLE`
+    fn sample_size(
+        &self,
+        expr: SQLExpr,
+        planner_context: &mut PlannerContext,
+    ) -> Result<f64> {
+        let expr = self.sql_to_expr(expr, &DFSchema::empty(), planner_context)?;
+        if let Expr::Literal(value, _) = &expr
+            && !value.is_null()
+            && let ScalarValue::Float64(Some(size)) = value.cast_to(&DataType::Float64)?
+        {
+            return Ok(size);
+        }
+        plan_err!("TABLESAMPLE size must be a numeric literal, got {expr}")
+    }
+}
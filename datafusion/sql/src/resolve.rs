@@ -135,6 +135,9 @@ fn visit_statement(statement: &DFStatement, visitor: &mut RelationVisitor) {
         DFStatement::Statement(s) => {
             let _ = s.as_ref().visit(visitor);
         }
+        DFStatement::StatementWithCteClauses(s) => {
+            let _ = s.statement.as_ref().visit(visitor);
+        }
         DFStatement::CreateExternalTable(table) => {
             visitor.relations.insert(table.name.clone());
         }
@@ -148,6 +151,7 @@ fn visit_statement(statement: &DFStatement, visitor: &mut RelationVisitor) {
         },
         DFStatement::Explain(explain) => visit_statement(&explain.statement, visitor),
         DFStatement::Reset(_) => {}
+        DFStatement::RefreshMaterializedView(_) => {}
     }
 }
 
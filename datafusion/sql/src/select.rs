@@ -23,10 +23,12 @@ use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use crate::query::to_order_by_exprs_with_select;
 use crate::utils::{
     CheckColumnsMustReferenceAggregatePurpose, CheckColumnsSatisfyExprsPurpose,
-    check_columns_satisfy_exprs, extract_aliases, rebase_expr, resolve_aliases_to_exprs,
-    resolve_columns, resolve_positions_to_exprs, rewrite_recursive_unnests_bottom_up,
+    UNNEST_PLACEHOLDER, check_columns_satisfy_exprs, extract_aliases, rebase_expr,
+    resolve_aliases_to_exprs, resolve_columns, resolve_positions_to_exprs,
+    rewrite_recursive_unnests_bottom_up,
 };
 
+use arrow::datatypes::DataType;
 use datafusion_common::error::DataFusionErrorBuilder;
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{Column, Result, not_impl_err, plan_err};
@@ -40,7 +42,7 @@ use datafusion_expr::utils::{
     expr_as_column_expr, expr_to_columns, find_aggregate_exprs, find_window_exprs,
 };
 use datafusion_expr::{
-    Aggregate, Expr, Filter, GroupingSet, LogicalPlan, LogicalPlanBuilder,
+    Aggregate, Expr, ExprSchemable, Filter, GroupingSet, LogicalPlan, LogicalPlanBuilder,
     LogicalPlanBuilderOptions, Partitioning, SortExpr,
 };
 
@@ -50,7 +52,9 @@ use sqlparser::ast::{
     SelectItemQualifiedWildcardKind, WildcardAdditionalOptions, WindowType,
     visit_expressions_mut,
 };
-use sqlparser::ast::{NamedWindowDefinition, Select, SelectItem, TableWithJoins};
+use sqlparser::ast::{
+    LateralView, NamedWindowDefinition, Select, SelectItem, TableWithJoins,
+};
 
 /// Result of the `aggregate` function, containing the aggregate plan and
 /// rewritten expressions that reference the aggregate output columns.
@@ -79,9 +83,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         if !select.cluster_by.is_empty() {
             return not_impl_err!("CLUSTER BY");
         }
-        if !select.lateral_views.is_empty() {
-            return not_impl_err!("LATERAL VIEWS");
-        }
 
         if select.top.is_some() {
             return not_impl_err!("TOP");
@@ -94,6 +95,10 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         let plan = self.plan_from_tables(select.from, planner_context)?;
         let empty_from = matches!(plan, LogicalPlan::EmptyRelation(_));
 
+        // Process `lateral view` clauses
+        let plan =
+            self.plan_lateral_views(plan, select.lateral_views, planner_context)?;
+
         // Process `where` clause
         let base_plan = self.plan_selection(select.selection, plan, planner_context)?;
 
@@ -708,6 +713,111 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         }
     }
 
+    /// Plans the `LATERAL VIEW`s of a query (Hive / Spark syntax) on top of
+    /// the plan of its `from` clause, in order
+    fn plan_lateral_views(
+        &self,
+        plan: LogicalPlan,
+        lateral_views: Vec<LateralView>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        lateral_views
+            .into_iter()
+            .try_fold(plan, |plan, lateral_view| {
+                self.plan_lateral_view(plan, lateral_view, planner_context)
+            })
+    }
+
+    /// Plans `LATERAL VIEW [OUTER] generator(...) view_name [AS alias, ...]`.
+    ///
+    /// The generator has to return a list, which is unnested, so that every
+    /// input row is joined with every element of its list. A list of structs,
+    /// as returned by the Spark generator functions like `explode`, is unnested
+    /// further into one column per struct field. `OUTER` keeps the input rows
+    /// whose list is null or empty, with nulls as generated columns.
+    fn plan_lateral_view(
+        &self,
+        plan: LogicalPlan,
+        lateral_view: LateralView,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let LateralView {
+            lateral_view: generator,
+            lateral_view_name,
+            lateral_col_alias,
+            outer,
+        } = lateral_view;
+        let view_name = self.object_name_to_table_reference(lateral_view_name)?;
+        let generator = normalize_col(
+            self.sql_to_expr(generator, plan.schema(), planner_context)?,
+            &plan,
+        )?;
+        let element_type = match generator.get_type(plan.schema())? {
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _) => field.data_type().clone(),
+            other => {
+                return plan_err!(
+                    "LATERAL VIEW {view_name} expects a generator returning a list, got {other}"
+                );
+            }
+        };
+
+        let input_columns = plan.schema().columns();
+        let placeholder = format!("{UNNEST_PLACEHOLDER}({view_name})");
+        let options = UnnestOptions::new()
+            .with_preserve_nulls(outer)
+            .with_preserve_empty(outer);
+        let mut builder = LogicalPlanBuilder::from(plan)
+            .project(
+                input_columns
+                    .iter()
+                    .cloned()
+                    .map(Expr::Column)
+                    .chain([generator.alias(&placeholder)]),
+            )?
+            .unnest_column_with_options(
+                Column::from_name(&placeholder),
+                options.clone(),
+            )?;
+        if matches!(element_type, DataType::Struct(_)) {
+            builder = builder
+                .unnest_column_with_options(Column::from_name(placeholder), options)?;
+        }
+        let plan = builder.build()?;
+
+        let generated_columns = plan.schema().columns().split_off(input_columns.len());
+        let names = if lateral_col_alias.is_empty() {
+            match &element_type {
+                DataType::Struct(fields) => {
+                    fields.iter().map(|field| field.name().clone()).collect()
+                }
+                _ => vec!["col".to_string()],
+            }
+        } else if lateral_col_alias.len() == generated_columns.len() {
+            lateral_col_alias
+                .into_iter()
+                .map(|alias| self.ident_normalizer.normalize(alias))
+                .collect::<Vec<_>>()
+        } else {
+            return plan_err!(
+                "LATERAL VIEW {view_name} generates {} columns, but {} column aliases were given",
+                generated_columns.len(),
+                lateral_col_alias.len()
+            );
+        };
+
+        let generated = generated_columns
+            .into_iter()
+            .zip(names)
+            .map(|(column, name)| {
+                Expr::Column(column).alias_qualified(Some(view_name.clone()), name)
+            });
+        LogicalPlanBuilder::from(plan)
+            .project(input_columns.into_iter().map(Expr::Column).chain(generated))?
+            .build()
+    }
+
     /// Returns the `Expr`'s corresponding to a SQL query's SELECT expressions.
     pub(crate) fn prepare_select_exprs(
         &self,
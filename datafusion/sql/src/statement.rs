@@ -22,7 +22,8 @@ use std::sync::Arc;
 
 use crate::parser::{
     CopyToSource, CopyToStatement, CreateExternalTable, DFParser, ExplainStatement,
-    LexOrdering, ResetStatement, Statement as DFStatement,
+    LexOrdering, RefreshMaterializedViewStatement, ResetStatement,
+    Statement as DFStatement,
 };
 use crate::planner::{
     ContextProvider, PlannerContext, SqlToRel, object_name_to_qualifier,
@@ -38,26 +39,31 @@ use datafusion_common::{
     internal_err, not_impl_err, plan_datafusion_err, plan_err, schema_err,
     unqualified_field_not_found,
 };
-use datafusion_expr::dml::{CopyTo, InsertOp};
+use datafusion_expr::dml::{
+    CopyTo, InsertOp, MERGE_ACTION_COLUMN, MERGE_TARGET_ROW_COLUMN, MergeAction,
+};
+use datafusion_expr::expr::{Case, WindowFunction};
 use datafusion_expr::expr_rewriter::normalize_col_with_schemas_and_ambiguity_check;
 use datafusion_expr::logical_plan::DdlStatement;
 use datafusion_expr::logical_plan::builder::project;
 use datafusion_expr::utils::expr_to_columns;
 use datafusion_expr::{
-    Analyze, CreateCatalog, CreateCatalogSchema,
+    AlterTable, AlterTableOperation, Analyze, CreateCatalog, CreateCatalogSchema,
     CreateExternalTable as PlanCreateExternalTable, CreateFunction, CreateFunctionBody,
-    CreateIndex as PlanCreateIndex, CreateMemoryTable, CreateView, Deallocate,
-    DescribeTable, DmlStatement, DropCatalogSchema, DropFunction, DropTable, DropView,
-    EmptyRelation, Execute, Explain, ExplainFormat, Expr, ExprSchemable, Filter,
-    LogicalPlan, LogicalPlanBuilder, OperateFunctionArg, PlanType, Prepare,
-    ResetVariable, SetVariable, SortExpr, Statement as PlanStatement, ToStringifiedPlan,
-    TransactionAccessMode, TransactionConclusion, TransactionEnd,
-    TransactionIsolationLevel, TransactionStart, Volatility, WriteOp, cast, col,
+    CreateIndex as PlanCreateIndex, CreateMaterializedView, CreateMemoryTable,
+    CreateView, Deallocate, DescribeTable, DmlStatement, DropCatalogSchema, DropFunction,
+    DropTable, DropView, EmptyRelation, Execute, Explain, ExplainFormat, Expr,
+    ExprSchemable, Filter, JoinType, LogicalPlan, LogicalPlanBuilder, OperateFunctionArg,
+    PlanType, Prepare, RefreshMaterializedView, ResetVariable, SetVariable, SortExpr,
+    Statement as PlanStatement, ToStringifiedPlan, TransactionAccessMode,
+    TransactionConclusion, TransactionEnd, TransactionIsolationLevel, TransactionStart,
+    TruncateTable, Volatility, WindowFunctionDefinition, WriteOp, cast, col, lit,
 };
 use sqlparser::ast::{
-    self, BeginTransactionKind, IndexColumn, IndexType, NullsDistinctOption, OrderByExpr,
-    OrderByOptions, Set, ShowStatementIn, ShowStatementOptions, SqliteOnConflict,
-    TableObject, UpdateTableFromKind, ValueWithSpan,
+    self, BeginTransactionKind, IndexColumn, IndexType, MergeClauseKind,
+    NullsDistinctOption, OrderByExpr, OrderByOptions, Set, ShowStatementIn,
+    ShowStatementOptions, SqliteOnConflict, TableObject, UpdateTableFromKind,
+    ValueWithSpan,
 };
 use sqlparser::ast::{
     Assignment, AssignmentTarget, ColumnDef, CreateIndex, CreateTable,
@@ -204,6 +210,11 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         match statement {
             DFStatement::CreateExternalTable(s) => self.external_table_to_plan(s),
             DFStatement::Statement(s) => self.sql_statement_to_plan(*s),
+            DFStatement::StatementWithCteClauses(s) => self
+                .sql_statement_to_plan_with_context_impl(
+                    *s.statement,
+                    &mut PlannerContext::new().with_recursive_cte_clauses(s.cte_clauses),
+                ),
             DFStatement::CopyTo(s) => self.copy_to_plan(s),
             DFStatement::Explain(ExplainStatement {
                 verbose,
@@ -212,6 +223,9 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 statement,
             }) => self.explain_to_plan(verbose, analyze, format, *statement),
             DFStatement::Reset(statement) => self.reset_statement_to_plan(statement),
+            DFStatement::RefreshMaterializedView(statement) => {
+                self.refresh_materialized_view_to_plan(statement)
+            }
         }
     }
 
@@ -321,9 +335,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 initialize,
                 require_user,
             }) => {
-                if temporary {
-                    return not_impl_err!("Temporary tables not supported")?;
-                }
                 if external {
                     return not_impl_err!("External tables not supported")?;
                 }
@@ -557,6 +568,9 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     }
                 }
             }
+            statement @ Statement::CreateView {
+                materialized: true, ..
+            } => self.create_materialized_view_to_plan(statement),
             Statement::CreateView {
                 or_replace,
                 materialized,
@@ -575,9 +589,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 secure,
                 name_before_not_exists,
             } => {
-                if materialized {
-                    return not_impl_err!("Materialized views not supported")?;
-                }
                 if !cluster_by.is_empty() {
                     return not_impl_err!("Cluster by not supported")?;
                 }
@@ -627,22 +638,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     return internal_err!("Unreachable code in create view");
                 };
 
-                let columns = columns
-                    .into_iter()
-                    .map(|view_column_def| {
-                        if let Some(options) = view_column_def.options {
-                            plan_err!(
-                                "Options not supported for view columns: {options:?}"
-                            )
-                        } else {
-                            Ok(view_column_def.name)
-                        }
-                    })
-                    .collect::<Result<Vec<_>>>()?;
-
-                let mut plan = self.query_to_plan(*query, &mut PlannerContext::new())?;
-                plan = self.apply_expr_alias(plan, columns)?;
-
+                let plan = self.view_query_to_plan(columns, *query)?;
                 Ok(LogicalPlan::Ddl(DdlStatement::CreateView(CreateView {
                     name: self.object_name_to_table_reference(name)?,
                     input: Arc::new(plan),
@@ -708,7 +704,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                             schema: DFSchemaRef::new(DFSchema::empty()),
                         })))
                     }
-                    ObjectType::View => {
+                    ObjectType::View | ObjectType::MaterializedView => {
                         Ok(LogicalPlan::Ddl(DdlStatement::DropView(DropView {
                             name,
                             if_exists,
@@ -1089,6 +1085,22 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 self.delete_to_plan(&table_name, selection)
             }
 
+            Statement::Merge {
+                into,
+                table,
+                source,
+                on,
+                clauses,
+                output,
+            } => {
+                if output.is_some() {
+                    plan_err!("Merge-output clause not yet supported")?;
+                }
+                // optional keywords don't change behavior
+                let _ = into;
+                self.merge_to_plan(table, source, *on, clauses)
+            }
+
             Statement::StartTransaction {
                 modes,
                 begin: false,
@@ -1362,6 +1374,51 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     exec_err!("Function name not provided")
                 }
             }
+            Statement::AlterTable {
+                name,
+                if_exists,
+                operations,
+                location,
+                on_cluster,
+                ..
+            } => {
+                if location.is_some() {
+                    return not_impl_err!("ALTER TABLE SET LOCATION is not supported");
+                }
+                if on_cluster.is_some() {
+                    return not_impl_err!("ALTER TABLE ON CLUSTER is not supported");
+                }
+                self.alter_table_to_plan(name, if_exists, operations, planner_context)
+            }
+            Statement::Truncate {
+                mut table_names,
+                partitions,
+                on_cluster,
+                ..
+            } => {
+                if partitions.is_some() {
+                    return not_impl_err!("TRUNCATE with PARTITION is not supported");
+                }
+                if on_cluster.is_some() {
+                    return not_impl_err!("TRUNCATE with ON CLUSTER is not supported");
+                }
+                // We don't support truncating multiple tables at once
+                let name = match table_names.len() {
+                    0 => Err(ParserError("Missing table name.".to_string()).into()),
+                    1 => self
+                        .object_name_to_table_reference(table_names.pop().unwrap().name),
+                    _ => {
+                        Err(ParserError("Multiple objects not supported".to_string())
+                            .into())
+                    }
+                }?;
+                Ok(LogicalPlan::Ddl(DdlStatement::TruncateTable(
+                    TruncateTable {
+                        name,
+                        schema: DFSchemaRef::new(DFSchema::empty()),
+                    },
+                )))
+            }
             Statement::CreateIndex(CreateIndex {
                 name,
                 table_name,
@@ -1631,13 +1688,13 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             .get("format.compression")
             .map(|c| CompressionTypeVariant::from_str(c))
             .transpose()?;
-        if (file_type == "PARQUET" || file_type == "AVRO" || file_type == "ARROW")
+        if matches!(file_type.as_str(), "PARQUET" | "AVRO" | "ARROW" | "ORC")
             && compression
                 .map(|c| c != CompressionTypeVariant::UNCOMPRESSED)
                 .unwrap_or(false)
         {
             plan_err!(
-                "File compression type cannot be set for PARQUET, AVRO, or ARROW files."
+                "File compression type cannot be set for PARQUET, AVRO, ARROW, or ORC files."
             )?;
         }
 
@@ -1974,6 +2031,244 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         }
     }
 
+    /// Plans the query of a view, renaming its output to the view columns
+    fn view_query_to_plan(
+        &self,
+        columns: Vec<ast::ViewColumnDef>,
+        query: Query,
+    ) -> Result<LogicalPlan> {
+        let columns = columns
+            .into_iter()
+            .map(|view_column_def| {
+                if let Some(options) = view_column_def.options {
+                    plan_err!("Options not supported for view columns: {options:?}")
+                } else {
+                    Ok(view_column_def.name)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = self.query_to_plan(query, &mut PlannerContext::new())?;
+        self.apply_expr_alias(plan, columns)
+    }
+
+    /// Plans a `CREATE MATERIALIZED VIEW` statement.
+    ///
+    /// The only supported option is `location`, the directory the result of
+    /// the view is stored at as Parquet files instead of in memory.
+    fn create_materialized_view_to_plan(
+        &self,
+        statement: Statement,
+    ) -> Result<LogicalPlan> {
+        let definition = statement.to_string();
+        let Statement::CreateView {
+            or_replace,
+            name,
+            columns,
+            query,
+            options,
+            cluster_by,
+            comment,
+            with_no_schema_binding,
+            if_not_exists,
+            temporary,
+            to,
+            ..
+        } = statement
+        else {
+            return internal_err!("Unreachable code in create materialized view");
+        };
+
+        if temporary {
+            return not_impl_err!("Temporary materialized views not supported");
+        }
+        if !cluster_by.is_empty() {
+            return not_impl_err!("Cluster by not supported");
+        }
+        if comment.is_some() {
+            return not_impl_err!("Comment not supported");
+        }
+        if with_no_schema_binding {
+            return not_impl_err!("With no schema binding not supported");
+        }
+        if to.is_some() {
+            return not_impl_err!("To not supported");
+        }
+
+        let mut location = None;
+        match options {
+            CreateTableOptions::None => {}
+            CreateTableOptions::With(options) => {
+                for option in options {
+                    match option {
+                        ast::SqlOption::KeyValue {
+                            key,
+                            value: SQLExpr::Value(value),
+                        } if key.value.eq_ignore_ascii_case("location") => {
+                            let Some(value) = value.into_string() else {
+                                return plan_err!(
+                                    "Location of a materialized view must be a string"
+                                );
+                            };
+                            location = Some(value);
+                        }
+                        option => {
+                            return not_impl_err!(
+                                "Option not supported for materialized views: {option}"
+                            );
+                        }
+                    }
+                }
+            }
+            options => {
+                return not_impl_err!(
+                    "Options not supported for materialized views: {options}"
+                );
+            }
+        }
+
+        let plan = self.view_query_to_plan(columns, *query)?;
+        Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+            CreateMaterializedView {
+                name: self.object_name_to_table_reference(name)?,
+                input: Arc::new(plan),
+                location,
+                if_not_exists,
+                or_replace,
+                definition: Some(definition),
+            },
+        )))
+    }
+
+    /// Plans a `REFRESH MATERIALIZED VIEW` statement.
+    fn refresh_materialized_view_to_plan(
+        &self,
+        statement: RefreshMaterializedViewStatement,
+    ) -> Result<LogicalPlan> {
+        Ok(LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(
+            RefreshMaterializedView {
+                name: self.object_name_to_table_reference(statement.name)?,
+                schema: DFSchemaRef::new(DFSchema::empty()),
+            },
+        )))
+    }
+
+    /// Plans an `ALTER TABLE` statement.
+    ///
+    /// The existence of the table and its columns is only checked when the
+    /// statement is executed, as `IF [NOT] EXISTS` may apply.
+    fn alter_table_to_plan(
+        &self,
+        name: ObjectName,
+        if_exists: bool,
+        operations: Vec<ast::AlterTableOperation>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let name = self.object_name_to_table_reference(name)?;
+
+        let mut plan_operations = Vec::with_capacity(operations.len());
+        for operation in operations {
+            match operation {
+                ast::AlterTableOperation::AddColumn {
+                    if_not_exists,
+                    column_def,
+                    column_position,
+                    ..
+                } => {
+                    if column_position.is_some() {
+                        return not_impl_err!(
+                            "ADD COLUMN with FIRST or AFTER is not supported"
+                        );
+                    }
+                    for ast::ColumnOptionDef { option, .. } in &column_def.options {
+                        if !matches!(
+                            option,
+                            ast::ColumnOption::Null
+                                | ast::ColumnOption::NotNull
+                                | ast::ColumnOption::Default(_)
+                        ) {
+                            return not_impl_err!(
+                                "ADD COLUMN with {option} is not supported"
+                            );
+                        }
+                    }
+
+                    let column_defs = vec![column_def];
+                    let default = self
+                        .build_column_defaults(&column_defs, planner_context)?
+                        .pop()
+                        .map(|(_, default)| default);
+                    let schema = self.build_schema(column_defs)?;
+                    let field = Arc::clone(&schema.fields()[0]);
+                    let default = default
+                        .map(|default| {
+                            default.cast_to(field.data_type(), &DFSchema::empty())
+                        })
+                        .transpose()?;
+                    plan_operations.push(AlterTableOperation::AddColumn {
+                        field,
+                        default,
+                        if_not_exists,
+                    });
+                }
+                ast::AlterTableOperation::DropColumn {
+                    column_names,
+                    if_exists,
+                    drop_behavior,
+                    ..
+                } => {
+                    if drop_behavior.is_some() {
+                        return not_impl_err!(
+                            "DROP COLUMN with CASCADE or RESTRICT is not supported"
+                        );
+                    }
+                    plan_operations.extend(column_names.iter().map(|column| {
+                        AlterTableOperation::DropColumn {
+                            name: self.ident_normalizer.normalize(column.clone()),
+                            if_exists,
+                        }
+                    }));
+                }
+                ast::AlterTableOperation::RenameColumn {
+                    old_column_name,
+                    new_column_name,
+                } => plan_operations.push(AlterTableOperation::RenameColumn {
+                    old_name: self.ident_normalizer.normalize(old_column_name),
+                    new_name: self.ident_normalizer.normalize(new_column_name),
+                }),
+                ast::AlterTableOperation::RenameTable { table_name } => {
+                    let (ast::RenameTableNameKind::As(new_name)
+                    | ast::RenameTableNameKind::To(new_name)) = table_name;
+                    plan_operations.push(AlterTableOperation::RenameTable {
+                        new_name: self.object_name_to_table_reference(new_name)?,
+                    });
+                }
+                operation => {
+                    return not_impl_err!(
+                        "Unsupported ALTER TABLE operation: {operation}"
+                    );
+                }
+            }
+        }
+
+        if plan_operations.len() > 1
+            && plan_operations
+                .iter()
+                .any(|op| matches!(op, AlterTableOperation::RenameTable { .. }))
+        {
+            return plan_err!(
+                "RENAME TO can not be combined with other ALTER TABLE operations"
+            );
+        }
+
+        Ok(LogicalPlan::Ddl(DdlStatement::AlterTable(AlterTable {
+            name,
+            if_exists,
+            operations: plan_operations,
+            schema: DFSchemaRef::new(DFSchema::empty()),
+        })))
+    }
+
     fn delete_to_plan(
         &self,
         table_name: &ObjectName,
@@ -2132,6 +2427,341 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         Ok(plan)
     }
 
+    /// Plans a `MERGE INTO` statement.
+    ///
+    /// The target table is outer joined with the source, and every joined row
+    /// is annotated with the [`MergeAction`] of the first `WHEN` clause that
+    /// applies to it, the number of its target row, and the new values of the
+    /// target columns:
+    ///
+    /// ```text
+    /// Projection: <action> AS __merge_action, __merge_target_row, <new value of each column>
+    ///   Filter: <target row present> OR <clause applies>
+    ///     Projection: <joined columns>, <index of first applicable clause>
+    ///       Left / Full Join: <ON expression>
+    ///         Projection: <target columns>, row_number() AS __merge_target_row
+    ///           WindowAggr: row_number()
+    ///         Projection: <source columns>, true AS __merge_source_row
+    /// ```
+    fn merge_to_plan(
+        &self,
+        table: TableFactor,
+        source: TableFactor,
+        on: SQLExpr,
+        clauses: Vec<ast::MergeClause>,
+    ) -> Result<LogicalPlan> {
+        const SOURCE_ROW_COLUMN: &str = "__merge_source_row";
+        const CLAUSE_COLUMN: &str = "__merge_clause";
+
+        let table_name = match &table {
+            TableFactor::Table { name, .. } => name.clone(),
+            _ => plan_err!("Cannot merge into non-table relation!")?,
+        };
+
+        // Do a table lookup to verify the table exists
+        let table_name = self.object_name_to_table_reference(table_name)?;
+        let table_source = self.context_provider.get_table_source(table_name.clone())?;
+        let table_schema = DFSchema::try_from(table_source.schema())?;
+
+        let mut planner_context = PlannerContext::new();
+
+        // Mark the rows of both sides so that unmatched rows can be told apart
+        // from NULL values after the outer join. The target rows are numbered,
+        // so that the table can detect target rows matched by several source rows
+        let Some(row_number) = self.context_provider.get_window_meta("row_number") else {
+            return plan_err!("MERGE requires the window function row_number");
+        };
+        let row_number = Expr::from(WindowFunction::new(
+            WindowFunctionDefinition::WindowUDF(row_number),
+            vec![],
+        ));
+        let target_row_number =
+            Expr::Column(Column::from_name(row_number.schema_name().to_string()))
+                .alias(MERGE_TARGET_ROW_COLUMN);
+        let target = self.create_relation(table, &mut planner_context)?;
+        let target_schema = Arc::clone(target.schema());
+        let target_columns = target_schema.columns();
+        let target = LogicalPlanBuilder::window_plan(target, vec![row_number])?;
+        let target = LogicalPlanBuilder::from(target)
+            .project(
+                target_columns
+                    .iter()
+                    .cloned()
+                    .map(Expr::Column)
+                    .chain([target_row_number]),
+            )?
+            .build()?;
+        let source = self.create_relation(source, &mut planner_context)?;
+        let source_schema = Arc::clone(source.schema());
+        let source_columns = source_schema.columns();
+        let source = LogicalPlanBuilder::from(source)
+            .project(
+                source_columns
+                    .into_iter()
+                    .map(Expr::Column)
+                    .chain([lit(true).alias(SOURCE_ROW_COLUMN)]),
+            )?
+            .build()?;
+
+        // Source rows without a match only need to be kept if they may be inserted
+        let join_type = if clauses.iter().any(|clause| {
+            matches!(
+                clause.clause_kind,
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+            )
+        }) {
+            JoinType::Full
+        } else {
+            JoinType::Left
+        };
+        // Resolve expressions without the markers, which are not visible to users
+        let join_schema = target_schema.join(&source_schema)?;
+        let on = self.sql_to_expr(on, &join_schema, &mut planner_context)?;
+        let join = LogicalPlanBuilder::from(target)
+            .join_on(source, join_type, Some(on))?
+            .build()?;
+
+        let target_row = col(MERGE_TARGET_ROW_COLUMN);
+        let source_row = col(SOURCE_ROW_COLUMN);
+
+        // For every clause, the condition under which it applies, its action,
+        // and the new value of each target column it modifies
+        let mut conditions = Vec::with_capacity(clauses.len());
+        let mut actions = Vec::with_capacity(clauses.len());
+        let mut values: Vec<Vec<(usize, Expr)>> =
+            vec![vec![]; table_schema.fields().len()];
+        for (index, clause) in clauses.into_iter().enumerate() {
+            let ast::MergeClause {
+                clause_kind,
+                predicate,
+                action,
+            } = clause;
+
+            let mut condition = match clause_kind {
+                MergeClauseKind::Matched => target_row
+                    .clone()
+                    .is_not_null()
+                    .and(source_row.clone().is_not_null()),
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                    target_row.clone().is_null()
+                }
+                MergeClauseKind::NotMatchedBySource => source_row.clone().is_null(),
+            };
+            if let Some(predicate) = predicate {
+                let predicate =
+                    self.sql_to_expr(predicate, &join_schema, &mut planner_context)?;
+                condition = condition.and(predicate);
+            }
+            conditions.push(condition);
+
+            let inserts = matches!(
+                clause_kind,
+                MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+            );
+            match action {
+                ast::MergeAction::Update { assignments } if !inserts => {
+                    actions.push(MergeAction::Update);
+                    for assignment in assignments {
+                        let column_index =
+                            self.merge_target_column(&assignment.target, &table_schema)?;
+                        if values[column_index].iter().any(|(i, _)| *i == index) {
+                            return plan_err!(
+                                "Multiple assignments to column '{}' in MERGE clause",
+                                table_schema.field(column_index).name()
+                            );
+                        }
+                        let value = self.sql_to_expr(
+                            assignment.value,
+                            &join_schema,
+                            &mut planner_context,
+                        )?;
+                        values[column_index].push((index, value));
+                    }
+                }
+                ast::MergeAction::Delete if !inserts => {
+                    actions.push(MergeAction::Delete);
+                }
+                ast::MergeAction::Insert(insert) if inserts => {
+                    actions.push(MergeAction::Insert);
+                    let ast::MergeInsertKind::Values(insert_values) = insert.kind else {
+                        return not_impl_err!("MERGE INSERT ROW is not supported");
+                    };
+                    let [row] = <[_; 1]>::try_from(insert_values.rows).map_err(|_| {
+                        plan_datafusion_err!("MERGE INSERT must insert a single row")
+                    })?;
+
+                    let column_indices = if insert.columns.is_empty() {
+                        (0..table_schema.fields().len()).collect::<Vec<_>>()
+                    } else {
+                        insert
+                            .columns
+                            .into_iter()
+                            .map(|column| {
+                                let column = self.ident_normalizer.normalize(column);
+                                table_schema
+                                    .index_of_column_by_name(None, &column)
+                                    .ok_or_else(|| {
+                                        unqualified_field_not_found(
+                                            &column,
+                                            &table_schema,
+                                        )
+                                    })
+                            })
+                            .collect::<Result<Vec<_>>>()?
+                    };
+                    if column_indices.len() != row.len() {
+                        return plan_err!(
+                            "Column count doesn't match MERGE INSERT values!"
+                        );
+                    }
+
+                    let mut row_values = vec![None; table_schema.fields().len()];
+                    for (column_index, value) in column_indices.into_iter().zip(row) {
+                        if row_values[column_index].is_some() {
+                            return schema_err!(SchemaError::DuplicateUnqualifiedField {
+                                name: table_schema.field(column_index).name().clone(),
+                            });
+                        }
+                        row_values[column_index] = Some(self.sql_to_expr(
+                            value,
+                            &join_schema,
+                            &mut planner_context,
+                        )?);
+                    }
+                    for (column_index, value) in row_values.into_iter().enumerate() {
+                        // Fill in the default value for columns without a value
+                        let value = value.unwrap_or_else(|| {
+                            table_source
+                                .get_column_default(
+                                    table_schema.field(column_index).name(),
+                                )
+                                .cloned()
+                                .unwrap_or(Expr::Literal(ScalarValue::Null, None))
+                        });
+                        values[column_index].push((index, value));
+                    }
+                }
+                action => {
+                    return plan_err!(
+                        "{action} is not allowed in a WHEN {clause_kind} clause of MERGE"
+                    );
+                }
+            }
+        }
+
+        // Index of the first clause that applies to each joined row
+        let clause_index = if conditions.is_empty() {
+            Expr::Literal(ScalarValue::UInt32(None), None)
+        } else {
+            Expr::Case(Case::new(
+                None,
+                conditions
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, condition)| {
+                        (Box::new(condition), Box::new(lit(index as u32)))
+                    })
+                    .collect(),
+                None,
+            ))
+        };
+        let join_columns = join.schema().columns();
+        let plan = LogicalPlanBuilder::from(join)
+            .project(
+                join_columns
+                    .into_iter()
+                    .map(Expr::Column)
+                    .chain([clause_index.alias(CLAUSE_COLUMN)]),
+            )?
+            // Drop unmatched source rows which are not inserted
+            .filter(
+                target_row
+                    .is_not_null()
+                    .or(col(CLAUSE_COLUMN).is_not_null()),
+            )?
+            .build()?;
+
+        let clause_case = |branches: Vec<(usize, Expr)>, otherwise: Expr| {
+            if branches.is_empty() {
+                return otherwise;
+            }
+            Expr::Case(Case::new(
+                Some(Box::new(col(CLAUSE_COLUMN))),
+                branches
+                    .into_iter()
+                    .map(|(index, value)| (Box::new(lit(index as u32)), Box::new(value)))
+                    .collect(),
+                Some(Box::new(otherwise)),
+            ))
+        };
+
+        let action = clause_case(
+            actions
+                .iter()
+                .enumerate()
+                .map(|(index, action)| (index, lit(action.as_u8())))
+                .collect(),
+            lit(MergeAction::Keep.as_u8()),
+        );
+        let markers = [
+            action.alias(MERGE_ACTION_COLUMN),
+            col(MERGE_TARGET_ROW_COLUMN),
+        ];
+        let exprs = markers
+            .into_iter()
+            .map(Ok)
+            .chain(
+                values
+                    .into_iter()
+                    .zip(target_columns)
+                    .zip(table_schema.fields())
+                    .map(|((values, target_column), field)| {
+                        // Cast to target column type, if necessary
+                        let values = values
+                            .into_iter()
+                            .map(|(index, value)| {
+                                Ok((
+                                    index,
+                                    value.cast_to(field.data_type(), plan.schema())?,
+                                ))
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        let value = clause_case(values, Expr::Column(target_column));
+                        Ok(value.alias(field.name()))
+                    }),
+            )
+            .collect::<Result<Vec<_>>>()?;
+        let plan = project(plan, exprs)?;
+
+        Ok(LogicalPlan::Dml(DmlStatement::new(
+            table_name,
+            table_source,
+            WriteOp::Merge,
+            Arc::new(plan),
+        )))
+    }
+
+    /// Returns the index of the target table column assigned by a `MERGE` update
+    fn merge_target_column(
+        &self,
+        target: &AssignmentTarget,
+        table_schema: &DFSchema,
+    ) -> Result<usize> {
+        let AssignmentTarget::ColumnName(cols) = target else {
+            return plan_err!("Tuples are not supported");
+        };
+        let col_name = cols
+            .0
+            .iter()
+            .last()
+            .and_then(|part| part.as_ident())
+            .ok_or_else(|| plan_datafusion_err!("Empty column id"))?;
+        let col_name = self.ident_normalizer.normalize(col_name.clone());
+        table_schema
+            .index_of_column_by_name(None, &col_name)
+            .ok_or_else(|| unqualified_field_not_found(&col_name, table_schema))
+    }
+
     fn insert_to_plan(
         &self,
         table_name: ObjectName,
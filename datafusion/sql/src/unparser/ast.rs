@@ -435,6 +435,8 @@ enum TableFactorBuilder {
     Table(TableRelationBuilder),
     Derived(DerivedRelationBuilder),
     Unnest(UnnestRelationBuilder),
+    Pivot(PivotRelationBuilder),
+    Unpivot(UnpivotRelationBuilder),
     Empty,
 }
 
@@ -456,6 +458,16 @@ impl RelationBuilder {
         self
     }
 
+    pub fn pivot(&mut self, value: PivotRelationBuilder) -> &mut Self {
+        self.relation = Some(TableFactorBuilder::Pivot(value));
+        self
+    }
+
+    pub fn unpivot(&mut self, value: UnpivotRelationBuilder) -> &mut Self {
+        self.relation = Some(TableFactorBuilder::Unpivot(value));
+        self
+    }
+
     pub fn empty(&mut self) -> &mut Self {
         self.relation = Some(TableFactorBuilder::Empty);
         self
@@ -472,6 +484,12 @@ impl RelationBuilder {
             Some(TableFactorBuilder::Unnest(ref mut rel_builder)) => {
                 rel_builder.alias = value;
             }
+            Some(TableFactorBuilder::Pivot(ref mut rel_builder)) => {
+                rel_builder.alias = value;
+            }
+            Some(TableFactorBuilder::Unpivot(ref mut rel_builder)) => {
+                rel_builder.alias = value;
+            }
             Some(TableFactorBuilder::Empty) => (),
             None => (),
         }
@@ -482,6 +500,8 @@ impl RelationBuilder {
             Some(TableFactorBuilder::Table(ref value)) => Some(value.build()?),
             Some(TableFactorBuilder::Derived(ref value)) => Some(value.build()?),
             Some(TableFactorBuilder::Unnest(ref value)) => Some(value.build()?),
+            Some(TableFactorBuilder::Pivot(ref value)) => Some(value.build()),
+            Some(TableFactorBuilder::Unpivot(ref value)) => Some(value.build()),
             Some(TableFactorBuilder::Empty) => None,
             None => return Err(Into::into(UninitializedFieldError::from("relation"))),
         })
@@ -685,6 +705,53 @@ impl Default for UnnestRelationBuilder {
     }
 }
 
+/// Builds a [`ast::TableFactor::Pivot`], whose parts are all required
+#[derive(Clone)]
+pub struct PivotRelationBuilder {
+    pub table: ast::TableFactor,
+    pub aggregate_functions: Vec<ast::ExprWithAlias>,
+    pub value_column: Vec<ast::Expr>,
+    pub value_source: ast::PivotValueSource,
+    pub alias: Option<ast::TableAlias>,
+}
+
+impl PivotRelationBuilder {
+    pub fn build(&self) -> ast::TableFactor {
+        ast::TableFactor::Pivot {
+            table: Box::new(self.table.clone()),
+            aggregate_functions: self.aggregate_functions.clone(),
+            value_column: self.value_column.clone(),
+            value_source: self.value_source.clone(),
+            default_on_null: None,
+            alias: self.alias.clone(),
+        }
+    }
+}
+
+/// Builds a [`ast::TableFactor::Unpivot`], whose parts are all required
+#[derive(Clone)]
+pub struct UnpivotRelationBuilder {
+    pub table: ast::TableFactor,
+    pub value: ast::Expr,
+    pub name: ast::Ident,
+    pub columns: Vec<ast::ExprWithAlias>,
+    pub null_inclusion: Option<ast::NullInclusion>,
+    pub alias: Option<ast::TableAlias>,
+}
+
+impl UnpivotRelationBuilder {
+    pub fn build(&self) -> ast::TableFactor {
+        ast::TableFactor::Unpivot {
+            table: Box::new(self.table.clone()),
+            value: self.value.clone(),
+            name: self.name.clone(),
+            columns: self.columns.clone(),
+            null_inclusion: self.null_inclusion.clone(),
+            alias: self.alias.clone(),
+        }
+    }
+}
+
 /// Runtime error when a `build()` method is called and one or more required fields
 /// do not have a value.
 #[derive(Debug, Clone)]
@@ -200,6 +200,16 @@ pub trait Dialect: Send + Sync {
         false
     }
 
+    /// Allow to unparse the plans of `PIVOT` and `UNPIVOT` table factors as
+    /// [ast::TableFactor::Pivot] and [ast::TableFactor::Unpivot].
+    ///
+    /// Otherwise, they are unparsed as the equivalent aggregate and `UNION ALL`
+    /// queries. `PIVOT` with `DEFAULT ON NULL` is always unparsed as an
+    /// aggregate query.
+    fn supports_pivot(&self) -> bool {
+        false
+    }
+
     /// Allows the dialect to override column alias unparsing if the dialect has specific rules.
     /// Returns None if the default unparsing should be used, or Some(String) if there is
     /// a custom implementation for the alias.
@@ -305,6 +315,10 @@ impl Dialect for DefaultDialect {
             || identifier.chars().any(|c| c.is_ascii_uppercase());
         if needs_quote { Some('"') } else { None }
     }
+
+    fn supports_pivot(&self) -> bool {
+        true
+    }
 }
 
 pub struct PostgreSqlDialect {}
@@ -467,6 +481,10 @@ impl Dialect for DuckDBDialect {
 
         dt.format(format).to_string()
     }
+
+    fn supports_pivot(&self) -> bool {
+        true
+    }
 }
 
 pub struct MySqlDialect {}
@@ -623,6 +641,10 @@ impl Dialect for BigQueryDialect {
     fn unnest_as_table_factor(&self) -> bool {
         true
     }
+
+    fn supports_pivot(&self) -> bool {
+        true
+    }
 }
 
 impl BigQueryDialect {
@@ -653,6 +675,7 @@ pub struct CustomDialect {
     window_func_support_window_frame: bool,
     full_qualified_col: bool,
     unnest_as_table_factor: bool,
+    supports_pivot: bool,
 }
 
 impl Default for CustomDialect {
@@ -681,6 +704,7 @@ impl Default for CustomDialect {
             window_func_support_window_frame: true,
             full_qualified_col: false,
             unnest_as_table_factor: false,
+            supports_pivot: false,
         }
     }
 }
@@ -791,6 +815,10 @@ impl Dialect for CustomDialect {
     fn unnest_as_table_factor(&self) -> bool {
         self.unnest_as_table_factor
     }
+
+    fn supports_pivot(&self) -> bool {
+        self.supports_pivot
+    }
 }
 
 /// `CustomDialectBuilder` to build `CustomDialect` using builder pattern
@@ -828,6 +856,7 @@ pub struct CustomDialectBuilder {
     window_func_support_window_frame: bool,
     full_qualified_col: bool,
     unnest_as_table_factor: bool,
+    supports_pivot: bool,
 }
 
 impl Default for CustomDialectBuilder {
@@ -862,6 +891,7 @@ impl CustomDialectBuilder {
             window_func_support_window_frame: true,
             full_qualified_col: false,
             unnest_as_table_factor: false,
+            supports_pivot: false,
         }
     }
 
@@ -888,6 +918,7 @@ impl CustomDialectBuilder {
             window_func_support_window_frame: self.window_func_support_window_frame,
             full_qualified_col: self.full_qualified_col,
             unnest_as_table_factor: self.unnest_as_table_factor,
+            supports_pivot: self.supports_pivot,
         }
     }
 
@@ -1028,4 +1059,10 @@ impl CustomDialectBuilder {
         self.unnest_as_table_factor = unnest_as_table_factor;
         self
     }
+
+    /// Customize the dialect to unparse `PIVOT` and `UNPIVOT` table factors
+    pub fn with_supports_pivot(mut self, supports_pivot: bool) -> Self {
+        self.supports_pivot = supports_pivot;
+        self
+    }
 }
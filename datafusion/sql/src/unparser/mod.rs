@@ -19,6 +19,7 @@
 
 pub mod ast;
 mod expr;
+mod pivot;
 mod plan;
 mod rewrite;
 mod utils;
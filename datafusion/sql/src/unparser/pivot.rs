@@ -0,0 +1,412 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Unparsing of the plans of `PIVOT` and `UNPIVOT` table factors, which the
+//! planner expands as described in `SqlToRel::plan_pivot` and
+//! `SqlToRel::plan_unpivot`
+
+use std::collections::HashSet;
+
+use super::Unparser;
+use super::ast::{
+    PivotRelationBuilder, QueryBuilder, RelationBuilder, SelectBuilder,
+    UnpivotRelationBuilder,
+};
+use crate::relation::pivot::pivot_value_name;
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::expr::{AggregateFunction, Alias};
+use datafusion_expr::utils::expr_to_columns;
+use datafusion_expr::{Aggregate, Case, Expr, LogicalPlan, Projection, Union};
+use sqlparser::ast;
+
+impl Unparser<'_> {
+    /// Sets `relation` to a `PIVOT` or `UNPIVOT` table factor if the dialect
+    /// supports them and `plan` is the plan of one. Returns false otherwise.
+    pub(super) fn try_pivot_to_relation(
+        &self,
+        plan: &LogicalPlan,
+        relation: &mut RelationBuilder,
+    ) -> Result<bool> {
+        if !self.dialect.supports_pivot() {
+            return Ok(false);
+        }
+        match plan {
+            LogicalPlan::Projection(projection) => {
+                let Some(pivot) = self.try_pivot(projection)? else {
+                    return Ok(false);
+                };
+                relation.pivot(pivot);
+            }
+            LogicalPlan::Union(union) => {
+                let Some(unpivot) = self.try_unpivot(union)? else {
+                    return Ok(false);
+                };
+                relation.unpivot(unpivot);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Unparses the input of a `PIVOT` or `UNPIVOT` as a table, or as a
+    /// derived table if it is not a plain table scan
+    fn pivot_input_to_sql(
+        &self,
+        input: &LogicalPlan,
+    ) -> Result<Option<ast::TableFactor>> {
+        let mut relation = RelationBuilder::default();
+        let scan = match input {
+            LogicalPlan::SubqueryAlias(alias) => alias.input.as_ref(),
+            plan => plan,
+        };
+        match scan {
+            LogicalPlan::TableScan(scan) if !Self::is_scan_with_pushdown(scan) => {
+                self.select_to_sql_recursively(
+                    input,
+                    &mut None::<QueryBuilder>,
+                    &mut SelectBuilder::default(),
+                    &mut relation,
+                )?;
+            }
+            // The aggregates and columns refer to the relations of the input
+            _ if self.dialect.requires_derived_table_alias() => return Ok(None),
+            _ => self.derive_with_dialect_alias(
+                "derived_pivot",
+                input,
+                &mut relation,
+                false,
+                vec![],
+            )?,
+        }
+        Ok(relation.build()?)
+    }
+
+    /// Recognizes the plan of `PIVOT (agg(x) FOR c IN (v1, v2, ...))`:
+    ///
+    /// ```text
+    /// Projection: <group columns>, agg(CASE c WHEN v1 THEN x END) AS v1, ...
+    ///   Aggregate: groupBy=[<group columns>], aggr=[agg(CASE c WHEN v1 THEN x END), ...]
+    /// ```
+    fn try_pivot(&self, projection: &Projection) -> Result<Option<PivotRelationBuilder>> {
+        let LogicalPlan::Aggregate(Aggregate {
+            input,
+            group_expr,
+            aggr_expr,
+            ..
+        }) = projection.input.as_ref()
+        else {
+            return Ok(None);
+        };
+        if aggr_expr.is_empty()
+            || projection.expr.len() != group_expr.len() + aggr_expr.len()
+            || projection.expr[..group_expr.len()] != group_expr[..]
+        {
+            return Ok(None);
+        }
+
+        // Every aggregate is `agg(CASE c WHEN v THEN x END)` of the same `c`,
+        // and is projected as a column named after `v` (and `agg`)
+        let mut pivot_column = None;
+        let mut cells = Vec::with_capacity(aggr_expr.len());
+        for (expr, projected) in
+            aggr_expr.iter().zip(&projection.expr[group_expr.len()..])
+        {
+            let Expr::AggregateFunction(aggregate) = expr else {
+                return Ok(None);
+            };
+            let Expr::Alias(Alias {
+                expr: column, name, ..
+            }) = projected
+            else {
+                return Ok(None);
+            };
+            if !matches!(column.as_ref(), Expr::Column(column)
+                if column.relation.is_none() && column.name == expr.schema_name().to_string())
+            {
+                return Ok(None);
+            }
+            let Some(Expr::Case(Case {
+                expr: Some(column),
+                when_then_expr,
+                else_expr: None,
+            })) = aggregate.params.args.first()
+            else {
+                return Ok(None);
+            };
+            let [(value, then)] = &when_then_expr[..] else {
+                return Ok(None);
+            };
+            if *pivot_column.get_or_insert(column) != column {
+                return Ok(None);
+            }
+            let mut aggregate = aggregate.clone();
+            aggregate.params.args[0] = then.as_ref().clone();
+            cells.push((aggregate, value.as_ref(), name));
+        }
+        let Some(pivot_column) = pivot_column else {
+            return Ok(None);
+        };
+
+        // The cells are ordered by aggregate, then by value
+        let num_values = cells
+            .iter()
+            .skip(1)
+            .position(|(_, value, _)| *value == cells[0].1)
+            .map_or(cells.len(), |position| position + 1);
+        if cells.len() % num_values != 0 {
+            return Ok(None);
+        }
+        let values: Vec<&Expr> = cells[..num_values]
+            .iter()
+            .map(|(_, value, _)| *value)
+            .collect();
+        let aggregates: Vec<&AggregateFunction> = cells
+            .iter()
+            .step_by(num_values)
+            .map(|(aggregate, _, _)| aggregate)
+            .collect();
+        for (index, (aggregate, value, _)) in cells.iter().enumerate() {
+            if aggregate != aggregates[index / num_values]
+                || *value != values[index % num_values]
+            {
+                return Ok(None);
+            }
+        }
+
+        // The planner groups by the columns that are not referenced
+        let mut referenced = HashSet::new();
+        expr_to_columns(pivot_column, &mut referenced)?;
+        for aggregate in &aggregates {
+            expr_to_columns(
+                &Expr::AggregateFunction((*aggregate).clone()),
+                &mut referenced,
+            )?;
+        }
+        let groups = input
+            .schema()
+            .columns()
+            .into_iter()
+            .filter(|column| !referenced.contains(column))
+            .map(Expr::Column);
+        if !groups.eq(group_expr.iter().cloned()) {
+            return Ok(None);
+        }
+
+        let names: Vec<&str> = cells.iter().map(|(_, _, name)| name.as_str()).collect();
+        let Some((value_names, aggregate_names)) = split_pivot_names(&names, num_values)
+        else {
+            return Ok(None);
+        };
+        let Some(table) = self.pivot_input_to_sql(input)? else {
+            return Ok(None);
+        };
+
+        let aggregate_functions = aggregates
+            .into_iter()
+            .zip(aggregate_names)
+            .map(|(aggregate, name)| {
+                Ok(ast::ExprWithAlias {
+                    expr: self
+                        .expr_to_sql(&Expr::AggregateFunction(aggregate.clone()))?,
+                    alias: name.map(|name| self.new_ident_quoted_if_needs(name)),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let values = values
+            .into_iter()
+            .zip(value_names)
+            .map(|(value, name)| {
+                let alias = (name != pivot_value_name(value))
+                    .then(|| self.new_ident_quoted_if_needs(name));
+                Ok(ast::ExprWithAlias {
+                    expr: self.expr_to_sql(value)?,
+                    alias,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(PivotRelationBuilder {
+            table,
+            aggregate_functions,
+            value_column: vec![self.expr_to_sql(pivot_column)?],
+            value_source: ast::PivotValueSource::List(values),
+            alias: None,
+        }))
+    }
+
+    /// Recognizes the plan of `UNPIVOT (value FOR name IN (c1, c2, ...))`:
+    ///
+    /// ```text
+    /// Union
+    ///   Projection: <other columns>, Utf8("c1") AS name, c1 AS value
+    ///     Filter: c1 IS NOT NULL
+    ///   Projection: <other columns>, Utf8("c2") AS name, c2 AS value
+    ///     Filter: c2 IS NOT NULL
+    /// ```
+    ///
+    /// without the filters for `UNPIVOT INCLUDE NULLS`.
+    fn try_unpivot(&self, union: &Union) -> Result<Option<UnpivotRelationBuilder>> {
+        let mut input = None;
+        let mut names = None;
+        let mut include_nulls = None;
+        let mut branches = vec![];
+        let mut columns = vec![];
+        for branch in union_branches(union) {
+            let LogicalPlan::Projection(projection) = branch else {
+                return Ok(None);
+            };
+            let [kept @ .., label, value] = &projection.expr[..] else {
+                return Ok(None);
+            };
+            let (
+                Expr::Alias(Alias {
+                    expr: label,
+                    name: name_column,
+                    ..
+                }),
+                Expr::Alias(Alias {
+                    expr: column,
+                    name: value_column,
+                    ..
+                }),
+            ) = (label, value)
+            else {
+                return Ok(None);
+            };
+            let (Expr::Literal(ScalarValue::Utf8(Some(label)), _), Expr::Column(column)) =
+                (label.as_ref(), column.as_ref())
+            else {
+                return Ok(None);
+            };
+
+            let (branch_input, filtered) = match projection.input.as_ref() {
+                LogicalPlan::Filter(filter)
+                    if filter.predicate == Expr::Column(column.clone()).is_not_null() =>
+                {
+                    (filter.input.as_ref(), true)
+                }
+                plan => (plan, false),
+            };
+            if *input.get_or_insert(branch_input) != branch_input
+                || *names.get_or_insert((name_column, value_column))
+                    != (name_column, value_column)
+                || *include_nulls.get_or_insert(!filtered) == filtered
+            {
+                return Ok(None);
+            }
+            branches.push(kept);
+            columns.push((column, label));
+        }
+        let (Some(input), Some((name, value)), Some(include_nulls)) =
+            (input, names, include_nulls)
+        else {
+            return Ok(None);
+        };
+
+        // The columns that are not unpivoted are kept in every branch
+        let unpivoted: HashSet<_> = columns.iter().map(|(column, _)| *column).collect();
+        let kept: Vec<Expr> = input
+            .schema()
+            .columns()
+            .into_iter()
+            .filter(|column| !unpivoted.contains(column))
+            .map(Expr::Column)
+            .collect();
+        if unpivoted.len() != columns.len()
+            || branches.iter().any(|branch| *branch != kept.as_slice())
+        {
+            return Ok(None);
+        }
+        let Some(table) = self.pivot_input_to_sql(input)? else {
+            return Ok(None);
+        };
+
+        let columns = columns
+            .into_iter()
+            .map(|(column, label)| {
+                let alias = (*label != column.name)
+                    .then(|| ast::Ident::with_quote('\'', label.as_str()));
+                Ok(ast::ExprWithAlias {
+                    expr: self.expr_to_sql(&Expr::Column(column.clone()))?,
+                    alias,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(UnpivotRelationBuilder {
+            table,
+            value: ast::Expr::Identifier(self.new_ident_quoted_if_needs(value.clone())),
+            name: self.new_ident_quoted_if_needs(name.clone()),
+            columns,
+            null_inclusion: include_nulls.then_some(ast::NullInclusion::IncludeNulls),
+            alias: None,
+        }))
+    }
+}
+
+/// The inputs of a `UNION ALL` and of the `UNION ALL`s among them, which the
+/// planner nests for more than two unpivoted columns
+fn union_branches(union: &Union) -> Vec<&LogicalPlan> {
+    union
+        .inputs
+        .iter()
+        .flat_map(|input| match input.as_ref() {
+            LogicalPlan::Union(union) => union_branches(union),
+            input => vec![input],
+        })
+        .collect()
+}
+
+/// Splits the names of the `PIVOT` output columns, ordered by aggregate and
+/// then by value, into the names of the values and of the aggregates.
+///
+/// With a single aggregate, the columns are named after the values. Otherwise,
+/// they are named `<value>_<aggregate>`.
+fn split_pivot_names(
+    names: &[&str],
+    num_values: usize,
+) -> Option<(Vec<String>, Vec<Option<String>>)> {
+    if names.len() == num_values {
+        let values = names.iter().map(|name| name.to_string()).collect();
+        return Some((values, vec![None]));
+    }
+    let rows: Vec<&[&str]> = names.chunks(num_values).collect();
+    // Try every split of the first name into a value and an aggregate name
+    rows[0][0].match_indices('_').find_map(|(index, _)| {
+        let (value, aggregate) = (&rows[0][0][..index], &rows[0][0][index + 1..]);
+        let values = rows[0]
+            .iter()
+            .map(|name| name.strip_suffix(aggregate)?.strip_suffix('_'))
+            .collect::<Option<Vec<_>>>()?;
+        let aggregates = rows
+            .iter()
+            .map(|row| row[0].strip_prefix(value)?.strip_prefix('_'))
+            .collect::<Option<Vec<_>>>()?;
+        let consistent = rows.iter().zip(&aggregates).all(|(row, aggregate)| {
+            row.iter()
+                .zip(&values)
+                .all(|(name, value)| *name == format!("{value}_{aggregate}"))
+        });
+        consistent.then(|| {
+            (
+                values.into_iter().map(String::from).collect(),
+                aggregates
+                    .into_iter()
+                    .map(|a| Some(a.to_string()))
+                    .collect(),
+            )
+        })
+    })
+}
@@ -131,6 +131,9 @@ impl Unparser<'_> {
             | LogicalPlan::Copy(_)
             | LogicalPlan::DescribeTable(_)
             | LogicalPlan::RecursiveQuery(_)
+            | LogicalPlan::Sample(_)
+            | LogicalPlan::AsOfJoin(_)
+            | LogicalPlan::MatchRecognize(_)
             | LogicalPlan::Unnest(_) => not_impl_err!("Unsupported plan: {plan:?}"),
         }
     }
@@ -310,7 +313,7 @@ impl Unparser<'_> {
         Ok(())
     }
 
-    fn derive_with_dialect_alias(
+    pub(super) fn derive_with_dialect_alias(
         &self,
         alias: &str,
         plan: &LogicalPlan,
@@ -331,7 +334,7 @@ impl Unparser<'_> {
     }
 
     #[cfg_attr(feature = "recursive_protection", recursive::recursive)]
-    fn select_to_sql_recursively(
+    pub(super) fn select_to_sql_recursively(
         &self,
         plan: &LogicalPlan,
         query: &mut Option<QueryBuilder>,
@@ -413,6 +416,9 @@ impl Unparser<'_> {
                 };
                 // Projection can be top-level plan for derived table
                 if select.already_projected() {
+                    if self.try_pivot_to_relation(plan, relation)? {
+                        return Ok(());
+                    }
                     return self.derive_with_dialect_alias(
                         "derived_projection",
                         plan,
@@ -825,6 +831,15 @@ impl Unparser<'_> {
                     )]);
                 }
                 let plan = unparsed_table_scan.unwrap_or_else(|| plan.clone());
+                if columns.is_empty() && self.try_pivot_to_relation(&plan, relation)? {
+                    relation.alias(Some(
+                        self.new_table_alias(
+                            plan_alias.alias.table().to_string(),
+                            vec![],
+                        ),
+                    ));
+                    return Ok(());
+                }
                 if !columns.is_empty()
                     && !self.dialect.supports_column_alias_in_table_alias()
                 {
@@ -860,6 +875,9 @@ impl Unparser<'_> {
             LogicalPlan::Union(union) => {
                 // Covers cases where the UNION is a subquery and the projection is at the top level
                 if select.already_projected() {
+                    if self.try_pivot_to_relation(plan, relation)? {
+                        return Ok(());
+                    }
                     return self.derive_with_dialect_alias(
                         "derived_union",
                         plan,
@@ -1045,7 +1063,7 @@ impl Unparser<'_> {
         Ok(Some(unnest_relation))
     }
 
-    fn is_scan_with_pushdown(scan: &TableScan) -> bool {
+    pub(super) fn is_scan_with_pushdown(scan: &TableScan) -> bool {
         scan.projection.is_some() || !scan.filters.is_empty() || scan.fetch.is_some()
     }
 
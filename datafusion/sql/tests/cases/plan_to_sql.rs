@@ -295,6 +295,87 @@ fn roundtrip_crossjoin() -> Result<()> {
     Ok(())
 }
 
+/// Unparses the plans of PIVOT and UNPIVOT queries with `dialect`, and checks
+/// that the unparsed queries result in the same plans
+fn roundtrip_pivot_unpivot_with_dialect(
+    dialect: &dyn UnparserDialect,
+) -> Result<Vec<String>> {
+    let tests = [
+        "SELECT * FROM (SELECT customer_id, o_item_id, qty FROM orders) \
+         PIVOT (sum(qty) FOR o_item_id IN ('a', 'b' AS other)) AS p",
+        "SELECT p.customer_id FROM (SELECT customer_id, o_item_id, qty FROM orders) \
+         PIVOT (sum(qty) AS total, count(*) AS cnt FOR o_item_id IN ('a', 'b')) AS p",
+        "SELECT * FROM orders PIVOT (max(price) FOR qty IN (1, 2) DEFAULT ON NULL (0.0)) AS p",
+        "SELECT * FROM orders PIVOT (max(price) FOR qty IN (1, 2))",
+        "SELECT * FROM orders AS o PIVOT (count(*) FOR o_item_id IN ('a')) AS p",
+        "SELECT id, part, name FROM person UNPIVOT (name FOR part IN (first_name, last_name, state))",
+        "SELECT * FROM j1 UNPIVOT (val FOR name IN (j1_id, j1_string)) AS u",
+        "SELECT * FROM j1 UNPIVOT INCLUDE NULLS (val FOR name IN (j1_id AS 'id', j1_string)) AS u",
+    ];
+    let state = MockSessionState::default()
+        .with_aggregate_function(sum_udaf())
+        .with_aggregate_function(count_udaf())
+        .with_aggregate_function(max_udaf())
+        .with_expr_planner(Arc::new(CoreFunctionPlanner::default()));
+    let context = MockContextProvider { state };
+    let sql_to_rel = SqlToRel::new(&context);
+    let unparser = Unparser::new(dialect);
+
+    let mut actual = vec![];
+    for query in tests {
+        let statement = Parser::new(&GenericDialect {})
+            .try_with_sql(query)?
+            .parse_statement()?;
+        let plan = sql_to_rel.sql_statement_to_plan(statement)?;
+
+        let roundtrip_statement = unparser.plan_to_sql(&plan)?;
+        actual.push(roundtrip_statement.to_string());
+
+        let plan_roundtrip = sql_to_rel.sql_statement_to_plan(roundtrip_statement)?;
+        assert_eq!(plan, plan_roundtrip);
+    }
+    Ok(actual)
+}
+
+#[test]
+fn roundtrip_pivot_unpivot() -> Result<()> {
+    let actual = roundtrip_pivot_unpivot_with_dialect(&DefaultDialect {})?;
+    assert_snapshot!(actual.join("\n"), @r#"
+    SELECT p.customer_id, p.a, p."other" FROM (SELECT orders.customer_id, orders.o_item_id, orders.qty FROM orders) PIVOT(sum(orders.qty) FOR orders.o_item_id IN ('a', 'b' AS "other")) AS p
+    SELECT p.customer_id FROM (SELECT orders.customer_id, orders.o_item_id, orders.qty FROM orders) PIVOT(sum(orders.qty) AS total, COUNT(1) AS cnt FOR orders.o_item_id IN ('a', 'b')) AS p
+    SELECT p.order_id, p.customer_id, p.o_item_id, p.delivered, p."1", p."2" FROM (SELECT orders.order_id, orders.customer_id, orders.o_item_id, orders.delivered, CASE WHEN max(CASE orders.qty WHEN 1 THEN orders.price END) IS NULL THEN 0.0 ELSE max(CASE orders.qty WHEN 1 THEN orders.price END) END AS "1", CASE WHEN max(CASE orders.qty WHEN 2 THEN orders.price END) IS NULL THEN 0.0 ELSE max(CASE orders.qty WHEN 2 THEN orders.price END) END AS "2" FROM orders GROUP BY orders.order_id, orders.customer_id, orders.o_item_id, orders.delivered) AS p
+    SELECT orders.order_id, orders.customer_id, orders.o_item_id, orders.delivered, "1", "2" FROM orders PIVOT(max(orders.price) FOR orders.qty IN (1, 2))
+    SELECT p.order_id, p.customer_id, p.qty, p.price, p.delivered, p.a FROM orders AS o PIVOT(COUNT(1) FOR o.o_item_id IN ('a')) AS p
+    SELECT id, "part", "name" FROM person UNPIVOT("name" FOR "part" IN (person.first_name, person.last_name, person.state))
+    SELECT u."name", u.val FROM j1 UNPIVOT(val FOR "name" IN (j1.j1_id, j1.j1_string)) AS u
+    SELECT u."name", u.val FROM j1 UNPIVOT INCLUDE NULLS (val FOR "name" IN (j1.j1_id AS 'id', j1.j1_string)) AS u
+    "#);
+
+    Ok(())
+}
+
+/// Dialects without PIVOT and UNPIVOT get the aggregate and UNION ALL queries
+/// they are planned as
+#[test]
+fn roundtrip_pivot_unpivot_as_expanded_query() -> Result<()> {
+    let dialect = CustomDialectBuilder::default()
+        .with_identifier_quote_style('"')
+        .build();
+    let actual = roundtrip_pivot_unpivot_with_dialect(&dialect)?;
+    assert_snapshot!(actual.join("\n"), @r#"
+    SELECT "p"."customer_id", "p"."a", "p"."other" FROM (SELECT "orders"."customer_id", sum(CASE "orders"."o_item_id" WHEN 'a' THEN "orders"."qty" END) AS "a", sum(CASE "orders"."o_item_id" WHEN 'b' THEN "orders"."qty" END) AS "other" FROM (SELECT "orders"."customer_id", "orders"."o_item_id", "orders"."qty" FROM "orders") GROUP BY "orders"."customer_id") AS "p"
+    SELECT "p"."customer_id" FROM (SELECT "orders"."customer_id", sum(CASE "orders"."o_item_id" WHEN 'a' THEN "orders"."qty" END) AS "a_total", sum(CASE "orders"."o_item_id" WHEN 'b' THEN "orders"."qty" END) AS "b_total", COUNT(CASE "orders"."o_item_id" WHEN 'a' THEN 1 END) AS "a_cnt", COUNT(CASE "orders"."o_item_id" WHEN 'b' THEN 1 END) AS "b_cnt" FROM (SELECT "orders"."customer_id", "orders"."o_item_id", "orders"."qty" FROM "orders") GROUP BY "orders"."customer_id") AS "p"
+    SELECT "p"."order_id", "p"."customer_id", "p"."o_item_id", "p"."delivered", "p"."1", "p"."2" FROM (SELECT "orders"."order_id", "orders"."customer_id", "orders"."o_item_id", "orders"."delivered", CASE WHEN max(CASE "orders"."qty" WHEN 1 THEN "orders"."price" END) IS NULL THEN 0.0 ELSE max(CASE "orders"."qty" WHEN 1 THEN "orders"."price" END) END AS "1", CASE WHEN max(CASE "orders"."qty" WHEN 2 THEN "orders"."price" END) IS NULL THEN 0.0 ELSE max(CASE "orders"."qty" WHEN 2 THEN "orders"."price" END) END AS "2" FROM "orders" GROUP BY "orders"."order_id", "orders"."customer_id", "orders"."o_item_id", "orders"."delivered") AS "p"
+    SELECT "orders"."order_id", "orders"."customer_id", "orders"."o_item_id", "orders"."delivered", "1", "2" FROM (SELECT "orders"."order_id", "orders"."customer_id", "orders"."o_item_id", "orders"."delivered", max(CASE "orders"."qty" WHEN 1 THEN "orders"."price" END) AS "1", max(CASE "orders"."qty" WHEN 2 THEN "orders"."price" END) AS "2" FROM "orders" GROUP BY "orders"."order_id", "orders"."customer_id", "orders"."o_item_id", "orders"."delivered")
+    SELECT "p"."order_id", "p"."customer_id", "p"."qty", "p"."price", "p"."delivered", "p"."a" FROM (SELECT "o"."order_id", "o"."customer_id", "o"."qty", "o"."price", "o"."delivered", COUNT(CASE "o"."o_item_id" WHEN 'a' THEN 1 END) AS "a" FROM "orders" AS "o" GROUP BY "o"."order_id", "o"."customer_id", "o"."qty", "o"."price", "o"."delivered") AS "p"
+    SELECT "id", "part", "name" FROM (SELECT "person"."id", "person"."age", "person"."salary", "person"."birth_date", "person"."😀", 'first_name' AS "part", "person"."first_name" AS "name" FROM "person" WHERE "person"."first_name" IS NOT NULL UNION ALL SELECT "person"."id", "person"."age", "person"."salary", "person"."birth_date", "person"."😀", 'last_name' AS "part", "person"."last_name" AS "name" FROM "person" WHERE "person"."last_name" IS NOT NULL UNION ALL SELECT "person"."id", "person"."age", "person"."salary", "person"."birth_date", "person"."😀", 'state' AS "part", "person"."state" AS "name" FROM "person" WHERE "person"."state" IS NOT NULL)
+    SELECT "u"."name", "u"."val" FROM (SELECT 'j1_id' AS "name", "j1"."j1_id" AS "val" FROM "j1" WHERE "j1"."j1_id" IS NOT NULL UNION ALL SELECT 'j1_string' AS "name", "j1"."j1_string" AS "val" FROM "j1" WHERE "j1"."j1_string" IS NOT NULL) AS "u"
+    SELECT "u"."name", "u"."val" FROM (SELECT 'id' AS "name", "j1"."j1_id" AS "val" FROM "j1" UNION ALL SELECT 'j1_string' AS "name", "j1"."j1_string" AS "val" FROM "j1") AS "u"
+    "#);
+
+    Ok(())
+}
+
 #[macro_export]
 macro_rules! roundtrip_statement_with_dialect_helper {
     (
@@ -27,7 +27,8 @@ use std::vec;
 
 use arrow::datatypes::{TimeUnit::Nanosecond, *};
 use common::MockContextProvider;
-use datafusion_common::{DataFusionError, Result, assert_contains};
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::{DataFusionError, Result, UnnestOptions, assert_contains};
 use datafusion_expr::{
     ColumnarValue, CreateIndex, DdlStatement, ScalarFunctionArgs, ScalarUDF,
     ScalarUDFImpl, Signature, Volatility, col, logical_plan::LogicalPlan,
@@ -780,6 +781,46 @@ fn plan_delete_quoted_identifier_case_sensitive() {
     );
 }
 
+#[test]
+fn plan_merge() {
+    let sql = "merge into j1 using j2 on j1_id = j2_id \
+               when matched and j2_id > 10 then delete \
+               when matched then update set j1_string = j2_string \
+               when not matched then insert values (j2_id, j2_string)";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r"
+    Dml: op=[Merge] table=[j1]
+      Projection: CASE __merge_clause WHEN UInt32(0) THEN UInt8(3) WHEN UInt32(1) THEN UInt8(2) WHEN UInt32(2) THEN UInt8(1) ELSE UInt8(0) END AS __merge_action, __merge_target_row, CASE __merge_clause WHEN UInt32(2) THEN j2.j2_id ELSE j1.j1_id END AS j1_id, CASE __merge_clause WHEN UInt32(1) THEN j2.j2_string WHEN UInt32(2) THEN j2.j2_string ELSE j1.j1_string END AS j1_string
+        Filter: __merge_target_row IS NOT NULL OR __merge_clause IS NOT NULL
+          Projection: j1.j1_id, j1.j1_string, __merge_target_row, j2.j2_id, j2.j2_string, __merge_source_row, CASE WHEN __merge_target_row IS NOT NULL AND __merge_source_row IS NOT NULL AND j2.j2_id > Int64(10) THEN UInt32(0) WHEN __merge_target_row IS NOT NULL AND __merge_source_row IS NOT NULL THEN UInt32(1) WHEN __merge_target_row IS NULL THEN UInt32(2) END AS __merge_clause
+            Full Join:  Filter: j1.j1_id = j2.j2_id
+              Projection: j1.j1_id, j1.j1_string, row_number() ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING AS __merge_target_row
+                WindowAggr: windowExpr=[[row_number() ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]
+                  TableScan: j1
+              Projection: j2.j2_id, j2.j2_string, Boolean(true) AS __merge_source_row
+                TableScan: j2
+    "
+    );
+}
+
+#[rstest]
+#[case::missing_assignment_target(
+    "MERGE INTO j1 USING j2 ON j1_id = j2_id WHEN MATCHED THEN UPDATE SET doesnotexist = 1"
+)]
+#[case::missing_on_expression(
+    "MERGE INTO j1 USING j2 ON j1_id = doesnotexist WHEN MATCHED THEN DELETE"
+)]
+#[case::missing_predicate_expression(
+    "MERGE INTO j1 USING j2 ON j1_id = j2_id WHEN MATCHED AND doesnotexist THEN DELETE"
+)]
+#[test]
+fn merge_column_does_not_exist(#[case] sql: &str) {
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_field_not_found(err, "doesnotexist");
+}
+
 #[test]
 fn select_column_does_not_exist() {
     let sql = "SELECT doesnotexist FROM person";
@@ -2331,6 +2372,7 @@ fn create_external_table_with_compression_type() {
         "CREATE EXTERNAL TABLE t STORED AS PARQUET LOCATION 'foo.parquet' OPTIONS ('format.compression' 'bzip2')",
         "CREATE EXTERNAL TABLE t STORED AS ARROW LOCATION 'foo.arrow' OPTIONS ('format.compression' 'gzip')",
         "CREATE EXTERNAL TABLE t STORED AS ARROW LOCATION 'foo.arrow' OPTIONS ('format.compression' 'bzip2')",
+        "CREATE EXTERNAL TABLE t STORED AS ORC LOCATION 'foo.orc' OPTIONS ('format.compression' 'gzip')",
     ];
 
     allow_duplicates! {
@@ -2339,7 +2381,7 @@ fn create_external_table_with_compression_type() {
 
             assert_snapshot!(
                 err.strip_backtrace(),
-                @"Error during planning: File compression type cannot be set for PARQUET, AVRO, or ARROW files."
+                @"Error during planning: File compression type cannot be set for PARQUET, AVRO, ARROW, or ORC files."
             );
 
         }
@@ -4277,10 +4319,6 @@ fn test_select_qualify_complex_condition() {
     "SELECT customer_name, sum(order_total) as total_order_amount FROM orders CLUSTER BY customer_name",
     "This feature is not implemented: CLUSTER BY"
 )]
-#[case::select_lateral_view_unsupported(
-    "SELECT id, number FROM person LATERAL VIEW explode(numbers) exploded_table AS number",
-    "This feature is not implemented: LATERAL VIEWS"
-)]
 #[case::select_top_unsupported(
     "SELECT TOP (5) * FROM person",
     "This feature is not implemented: TOP"
@@ -4295,6 +4333,49 @@ fn test_select_unsupported_syntax_errors(#[case] sql: &str, #[case] error: &str)
     assert_eq!(err.strip_backtrace(), error)
 }
 
+#[test]
+fn test_select_lateral_view() {
+    let sql =
+        "SELECT * FROM unnest_table LATERAL VIEW OUTER array_col t AS x WHERE t.x > 1";
+    let plan = logical_plan(sql).unwrap();
+    assert_eq!(
+        plan.schema().field_names(),
+        vec!["unnest_table.array_col", "unnest_table.struct_col", "t.x"]
+    );
+
+    let mut unnest_options = vec![];
+    plan.apply(|plan| {
+        if let LogicalPlan::Unnest(unnest) = plan {
+            unnest_options.push(unnest.options.clone());
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })
+    .unwrap();
+    assert_eq!(
+        unnest_options,
+        vec![
+            UnnestOptions::new()
+                .with_preserve_nulls(true)
+                .with_preserve_empty(true)
+        ]
+    );
+}
+
+#[rstest]
+#[case::lateral_view_alias_mismatch(
+    "SELECT * FROM unnest_table LATERAL VIEW array_col t AS x, y",
+    "Error during planning: LATERAL VIEW t generates 1 columns, but 2 column aliases were given"
+)]
+#[case::lateral_view_not_a_list(
+    "SELECT * FROM person LATERAL VIEW age t AS x",
+    "Error during planning: LATERAL VIEW t expects a generator returning a list, got Int32"
+)]
+#[test]
+fn test_select_lateral_view_errors(#[case] sql: &str, #[case] error: &str) {
+    let err = logical_plan(sql).unwrap_err();
+    assert_eq!(err.strip_backtrace(), error)
+}
+
 #[test]
 fn select_order_by_with_cast() {
     let sql = "SELECT first_name AS first_name FROM (SELECT first_name AS first_name FROM person) ORDER BY CAST(first_name as INT)";
@@ -4551,6 +4632,65 @@ fn plan_create_index() {
     }
 }
 
+#[test]
+fn plan_alter_table() {
+    let sql = "ALTER TABLE IF EXISTS test ADD COLUMN c INT NOT NULL DEFAULT 1, \
+               DROP COLUMN IF EXISTS a, DROP COLUMN b, RENAME COLUMN d TO e";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"AlterTable: Bare { table: "test" } if exists:=true operations=[AddColumn: c Int32 NOT NULL DEFAULT CAST(Int64(1) AS Int32), DropColumn: if exists a, DropColumn: b, RenameColumn: d to e]"#
+    );
+
+    let plan = logical_plan("ALTER TABLE test RENAME TO s.test2").unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"AlterTable: Bare { table: "test" } if exists:=false operations=[RenameTable: s.test2]"#
+    );
+}
+
+#[test]
+fn plan_truncate_table() {
+    let plan = logical_plan("TRUNCATE TABLE test").unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"TruncateTable: Bare { table: "test" }"#
+    );
+}
+
+#[test]
+fn plan_create_materialized_view() {
+    let sql =
+        "CREATE MATERIALIZED VIEW v WITH (location = '/tmp/v') AS SELECT id FROM person";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+    CreateMaterializedView: Bare { table: "v" } location=/tmp/v
+      Projection: person.id
+        TableScan: person
+    "#
+    );
+
+    let err = logical_plan(
+        "CREATE MATERIALIZED VIEW v WITH (format = 'csv') AS SELECT id FROM person",
+    )
+    .unwrap_err();
+    assert_snapshot!(
+        err.strip_backtrace(),
+        @"This feature is not implemented: Option not supported for materialized views: format = 'csv'"
+    );
+}
+
+#[test]
+fn plan_refresh_materialized_view() {
+    let plan = logical_plan("REFRESH MATERIALIZED VIEW v").unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"RefreshMaterializedView: Bare { table: "v" }"#
+    );
+}
+
 fn assert_field_not_found(mut err: DataFusionError, name: &str) {
     let err = loop {
         match err {
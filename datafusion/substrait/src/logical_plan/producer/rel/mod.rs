@@ -53,6 +53,10 @@ pub fn to_substrait_rel(
         LogicalPlan::Aggregate(plan) => producer.handle_aggregate(plan),
         LogicalPlan::Sort(plan) => producer.handle_sort(plan),
         LogicalPlan::Join(plan) => producer.handle_join(plan),
+        LogicalPlan::AsOfJoin(plan) => not_impl_err!("Unsupported plan type: {plan:?}")?,
+        LogicalPlan::MatchRecognize(plan) => {
+            not_impl_err!("Unsupported plan type: {plan:?}")?
+        }
         LogicalPlan::Repartition(plan) => producer.handle_repartition(plan),
         LogicalPlan::Union(plan) => producer.handle_union(plan),
         LogicalPlan::TableScan(plan) => producer.handle_table_scan(plan),
@@ -60,6 +64,7 @@ pub fn to_substrait_rel(
         LogicalPlan::Subquery(plan) => not_impl_err!("Unsupported plan type: {plan:?}")?,
         LogicalPlan::SubqueryAlias(plan) => producer.handle_subquery_alias(plan),
         LogicalPlan::Limit(plan) => producer.handle_limit(plan),
+        LogicalPlan::Sample(plan) => not_impl_err!("Unsupported plan type: {plan:?}")?,
         LogicalPlan::Statement(plan) => not_impl_err!("Unsupported plan type: {plan:?}")?,
         LogicalPlan::Values(plan) => producer.handle_values(plan),
         LogicalPlan::Explain(plan) => not_impl_err!("Unsupported plan type: {plan:?}")?,